@@ -0,0 +1,123 @@
+//! Arena: reuse memory across documents.
+//!
+//! Parsing makes lots of lists of events: one for the document, and one for
+//! each piece of content, text, and string in it, which are tokenized on
+//! their own and then merged into the document.
+//! Normally, they are all dropped after parsing.
+//! An arena keeps them around, so that the next document can use them,
+//! instead of allocating them again.
+
+use crate::event::Event;
+use crate::message;
+use crate::parser::parse_with_spare;
+use crate::tokenizer::Spare;
+use crate::util::event_transform;
+use crate::{to_html, Options, ParseOptions};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Memory to parse many documents with, one after another.
+///
+/// This is useful for servers that render lots of small documents, such as
+/// comments, where allocating memory for each of them adds up.
+/// Results are the same as those of [`to_events()`][crate::to_events] and
+/// [`to_html_with_options()`][crate::to_html_with_options].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{Arena, Options, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut arena = Arena::new();
+///
+/// // Parse a document:
+/// let events = arena.parse_into("*a*", &ParseOptions::default())?;
+/// assert_eq!(events.len(), 12);
+///
+/// // Forget it, keeping the memory, and parse the next one:
+/// arena.reset();
+/// assert_eq!(arena.events().len(), 0);
+/// assert_eq!(arena.to_html("**b**", &Options::default())?, "<p><strong>b</strong></p>");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Arena {
+    /// Events of the last document.
+    events: Vec<Event>,
+    /// Memory that is no longer used.
+    spare: Vec<Spare>,
+}
+
+impl Arena {
+    /// Create an arena.
+    ///
+    /// It starts out empty, and keeps the memory of the first documents
+    /// parsed with it.
+    pub fn new() -> Self {
+        Arena {
+            events: vec![],
+            spare: vec![],
+        }
+    }
+
+    /// Turn markdown into events, reusing memory.
+    ///
+    /// The events of a previous document are forgotten.
+    ///
+    /// ## Errors
+    ///
+    /// `parse_into()` never errors with normal markdown because markdown
+    /// does not have syntax errors, so feel free to `unwrap()`.
+    /// However, MDX does have syntax errors.
+    pub fn parse_into(
+        &mut self,
+        value: &str,
+        options: &ParseOptions,
+    ) -> Result<&[Event], message::Message> {
+        self.reset();
+        let (events, parse_state) =
+            parse_with_spare(value, options, core::mem::take(&mut self.spare))?;
+        self.spare = parse_state.spare.into_inner();
+        self.events = events;
+        Ok(&self.events)
+    }
+
+    /// Turn markdown into HTML, reusing memory.
+    ///
+    /// The events of this document are kept, like with
+    /// [`parse_into()`][Arena::parse_into].
+    ///
+    /// ## Errors
+    ///
+    /// `to_html()` never errors with normal markdown because markdown does
+    /// not have syntax errors, so feel free to `unwrap()`.
+    /// However, MDX does have syntax errors.
+    pub fn to_html(&mut self, value: &str, options: &Options) -> Result<String, message::Message> {
+        self.parse_into(value, &options.parse)?;
+        event_transform::transform(&mut self.events, value.as_bytes(), &options.compile);
+        Ok(to_html::compile(
+            &self.events,
+            value.as_bytes(),
+            &options.parse,
+            &options.compile,
+        ))
+    }
+
+    /// Events of the last document.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Forget the events of the last document, keeping their memory to
+    /// reuse.
+    ///
+    /// To free the memory, drop the arena instead.
+    pub fn reset(&mut self) {
+        let events = core::mem::take(&mut self.events);
+
+        if events.capacity() > 0 {
+            self.spare.push(Spare::new(events));
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Options in the shape of `comrak`, to ease migrating from it.
+
+use crate::configuration::{CompileOptions, Constructs, Options, ParseOptions};
+use crate::message;
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// Options in the shape of [`comrak`][comrak], another markdown parser.
+///
+/// These are grouped like the options of `comrak`, and are turned into
+/// [`Options`][] with [`ComrakOptions::to_options`][].
+/// Options that `comrak` has, but which only affect how it serializes
+/// markdown, are not included.
+///
+/// [comrak]: https://github.com/kivikakk/comrak
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_options, ComrakOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut comrak = ComrakOptions::default();
+/// comrak.extension.strikethrough = true;
+/// comrak.render.unsafe_ = true;
+///
+/// let (options, unsupported) = comrak.to_options();
+///
+/// assert!(unsupported.is_empty());
+/// assert_eq!(
+///     to_html_with_options("~~a~~ <b>c</b>", &options)?,
+///     "<p><del>a</del> <b>c</b></p>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ComrakOptions {
+    /// Extensions (syntax).
+    pub extension: ComrakExtensionOptions,
+    /// Parsing.
+    pub parse: ComrakParseOptions,
+    /// Rendering.
+    pub render: ComrakRenderOptions,
+}
+
+/// Extension options in the shape of `comrak`.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default)]
+pub struct ComrakExtensionOptions {
+    /// GFM strikethrough (`~~a~~`).
+    pub strikethrough: bool,
+    /// GFM tagfilter.
+    pub tagfilter: bool,
+    /// GFM table.
+    pub table: bool,
+    /// GFM autolink literal (`www.example.com`).
+    pub autolink: bool,
+    /// GFM task list item (`* [x] a`).
+    pub tasklist: bool,
+    /// Superscript (`^a^`).
+    pub superscript: bool,
+    /// Prefix for IDs on headings.
+    ///
+    /// Turns on heading ids, with the prefix as `id_prefix` (which also
+    /// prefixes the ids of footnotes).
+    pub header_ids: Option<String>,
+    /// GFM footnote (`[^a]`).
+    pub footnotes: bool,
+    /// Description lists.
+    ///
+    /// Not supported.
+    pub description_lists: bool,
+    /// Frontmatter, with the given delimiter.
+    ///
+    /// Only `---` is supported (which also turns on `+++`).
+    pub front_matter_delimiter: Option<String>,
+    /// Fenced block quotes (`>>>`).
+    pub multiline_block_quotes: bool,
+    /// Math with dollars (`$a$`, `$$a$$`).
+    pub math_dollars: bool,
+    /// Math with code (`` $`a`$ ``, ```` ```math ````).
+    ///
+    /// Math (text) with code only works if `math_dollars` is also on.
+    pub math_code: bool,
+    /// Shortcodes (`:rocket:`).
+    ///
+    /// Not supported.
+    pub shortcodes: bool,
+    /// Wiki links, with the title after the pipe (`[[a|b]]`).
+    pub wikilinks_title_after_pipe: bool,
+    /// Wiki links, with the title before the pipe (`[[b|a]]`).
+    ///
+    /// Not supported.
+    pub wikilinks_title_before_pipe: bool,
+    /// Underline (`__a__`).
+    pub underline: bool,
+    /// Spoiler (`||a||`).
+    pub spoiler: bool,
+    /// Greentext (`>a` is not a block quote).
+    ///
+    /// Not supported.
+    pub greentext: bool,
+}
+
+/// Parse options in the shape of `comrak`.
+#[derive(Clone, Debug, Default)]
+pub struct ComrakParseOptions {
+    /// Smart punctuation.
+    ///
+    /// Not supported.
+    pub smart: bool,
+    /// Default info string for code (fenced).
+    ///
+    /// Not supported.
+    pub default_info_string: Option<String>,
+    /// Whether any character can be used to check task list items.
+    ///
+    /// Not supported.
+    pub relaxed_tasklist_matching: bool,
+    /// Whether to allow more in autolink literals.
+    ///
+    /// Not supported.
+    pub relaxed_autolinks: bool,
+}
+
+/// Render options in the shape of `comrak`.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default)]
+pub struct ComrakRenderOptions {
+    /// Whether soft line breaks are hard breaks.
+    pub hardbreaks: bool,
+    /// Whether to use GitHub style `<pre lang>` for code (fenced).
+    ///
+    /// Not supported.
+    pub github_pre_lang: bool,
+    /// Whether to include the meta of code (fenced) in `data-meta`.
+    ///
+    /// Not supported.
+    pub full_info_string: bool,
+    /// Whether to allow dangerous HTML and protocols.
+    ///
+    /// When off, HTML is dropped (`strip_html`), unless `escape` is on.
+    pub unsafe_: bool,
+    /// Whether to escape HTML instead of dropping it.
+    ///
+    /// Escaped HTML is encoded, so that it is shown as text.
+    pub escape: bool,
+    /// Whether to add `data-sourcepos` attributes.
+    pub sourcepos: bool,
+    /// Whether to wrap escaped characters in spans.
+    ///
+    /// Not supported.
+    pub escaped_char_spans: bool,
+    /// Whether to not support heading (setext).
+    pub ignore_setext: bool,
+    /// Whether to not support links with an empty label.
+    ///
+    /// Not supported.
+    pub ignore_empty_links: bool,
+    /// Whether to render like GitHub in some edge cases.
+    ///
+    /// Not supported.
+    pub gfm_quirks: bool,
+    /// Whether to render images with a title as figures.
+    ///
+    /// Not supported.
+    pub figure_with_caption: bool,
+    /// Whether to add classes to task list items.
+    ///
+    /// Not supported.
+    pub tasklist_classes: bool,
+}
+
+impl ComrakOptions {
+    /// Turn `comrak` options into options.
+    ///
+    /// Returns the options, and a message for each option that is set but
+    /// not supported.
+    pub fn to_options(&self) -> (Options, Vec<message::Message>) {
+        let extension = &self.extension;
+        let parse = &self.parse;
+        let render = &self.render;
+        let mut unsupported = vec![];
+
+        let frontmatter = match extension.front_matter_delimiter.as_deref() {
+            None => false,
+            Some("---") => true,
+            Some(_) => {
+                unsupported.push("extension.front_matter_delimiter");
+                false
+            }
+        };
+
+        for (name, on) in [
+            ("extension.description_lists", extension.description_lists),
+            ("extension.shortcodes", extension.shortcodes),
+            (
+                "extension.wikilinks_title_before_pipe",
+                extension.wikilinks_title_before_pipe,
+            ),
+            ("extension.greentext", extension.greentext),
+            ("parse.smart", parse.smart),
+            (
+                "parse.default_info_string",
+                parse.default_info_string.is_some(),
+            ),
+            (
+                "parse.relaxed_tasklist_matching",
+                parse.relaxed_tasklist_matching,
+            ),
+            ("parse.relaxed_autolinks", parse.relaxed_autolinks),
+            ("render.github_pre_lang", render.github_pre_lang),
+            ("render.full_info_string", render.full_info_string),
+            ("render.escaped_char_spans", render.escaped_char_spans),
+            ("render.ignore_empty_links", render.ignore_empty_links),
+            ("render.gfm_quirks", render.gfm_quirks),
+            ("render.figure_with_caption", render.figure_with_caption),
+            ("render.tasklist_classes", render.tasklist_classes),
+        ] {
+            if on {
+                unsupported.push(name);
+            }
+        }
+
+        let options = Options {
+            parse: ParseOptions {
+                constructs: Constructs {
+                    block_quote_fenced: extension.multiline_block_quotes,
+                    frontmatter,
+                    gfm_autolink_literal: extension.autolink,
+                    gfm_footnote_definition: extension.footnotes,
+                    gfm_label_start_footnote: extension.footnotes,
+                    gfm_strikethrough: extension.strikethrough,
+                    gfm_table: extension.table,
+                    gfm_task_list_item: extension.tasklist,
+                    heading_setext: !render.ignore_setext,
+                    math_flow: extension.math_dollars,
+                    math_text: extension.math_dollars,
+                    spoiler_pipes: extension.spoiler,
+                    superscript: extension.superscript,
+                    underline: extension.underline,
+                    wiki_link: extension.wikilinks_title_after_pipe,
+                    ..Constructs::default()
+                },
+                math_gitlab: extension.math_code,
+                ..ParseOptions::default()
+            },
+            compile: CompileOptions {
+                allow_dangerous_html: render.unsafe_,
+                allow_dangerous_protocol: render.unsafe_,
+                gfm_tagfilter: extension.tagfilter,
+                hard_breaks: render.hardbreaks,
+                heading_ids: extension.header_ids.is_some(),
+                id_prefix: extension.header_ids.clone(),
+                sourcepos: render.sourcepos,
+                strip_html: !render.unsafe_ && !render.escape,
+                ..CompileOptions::default()
+            },
+        };
+
+        let messages = unsupported
+            .into_iter()
+            .map(|name| message::Message {
+                place: None,
+                reason: format!("Unexpected unsupported comrak option `{}`", name),
+                rule_id: Box::new("comrak-unsupported".into()),
+                source: Box::new("markdown-rs".into()),
+                fix: None,
+            })
+            .collect();
+
+        (options, messages)
+    }
+}
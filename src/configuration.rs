@@ -1,8 +1,30 @@
+use crate::to_markdown::ListNumbering;
+#[cfg(feature = "syntect")]
+use crate::util::syntax_highlight::SyntaxHighlight;
 use crate::util::{
+    bibliography::Bibliography,
+    citation::CitationResolve,
+    code_block_handler::CodeBlockHandler,
+    column_unit::ColumnUnit,
+    constant::LABEL_START_COUNT_MAX,
+    critic_markup::CriticMarkupMode,
+    custom_block::CustomBlock,
+    custom_inline::CustomInline,
+    element_render::ElementRender,
+    event_transform::EventTransform,
+    external_link::ExternalLinkCheck,
+    github_reference::GithubReferenceResolve,
+    image_proxy::ImageProxy,
+    image_resolve::ImageResolve,
     line_ending::LineEnding,
+    link_resolve::LinkResolve,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
+    mention::MentionResolve,
+    sanitize_uri::UrlSanitize,
+    slug::SlugKind,
+    wiki_link::{WikiEmbedResolve, WikiLinkResolve},
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{boxed::Box, fmt, string::String, vec, vec::Vec};
 
 /// Control which constructs are enabled.
 ///
@@ -32,6 +54,16 @@ use alloc::{boxed::Box, fmt, string::String};
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Constructs {
+    /// Abbreviation (PHP Markdown Extra).
+    ///
+    /// ```markdown
+    /// > | a HTML b
+    ///       ^^^^
+    ///   |
+    /// > | *[HTML]: c
+    ///     ^^^^^^^^^^
+    /// ```
+    pub abbreviation: bool,
     /// Attention.
     ///
     /// ```markdown
@@ -39,6 +71,15 @@ pub struct Constructs {
     ///       ^^^   ^^^^^
     /// ```
     pub attention: bool,
+    /// Attribute list (kramdown).
+    ///
+    /// ```markdown
+    /// > | a *b*{: .c}
+    ///          ^^^^^^
+    /// > | {: #d}
+    ///     ^^^^^^
+    /// ```
+    pub attribute_list: bool,
     /// Autolink.
     ///
     /// ```markdown
@@ -46,6 +87,13 @@ pub struct Constructs {
     ///       ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^^^^^^^^^^
     /// ```
     pub autolink: bool,
+    /// Block id (Obsidian).
+    ///
+    /// ```markdown
+    /// > | a ^b
+    ///       ^^
+    /// ```
+    pub block_id: bool,
     /// Block quote.
     ///
     /// ```markdown
@@ -53,6 +101,24 @@ pub struct Constructs {
     ///     ^^^
     /// ```
     pub block_quote: bool,
+    /// Block quote (fenced) (GitLab).
+    ///
+    /// ```markdown
+    /// > | >>>
+    ///     ^^^
+    /// > | a
+    ///     ^
+    /// > | >>>
+    ///     ^^^
+    /// ```
+    pub block_quote_fenced: bool,
+    /// Bracketed span (Pandoc).
+    ///
+    /// ```markdown
+    /// > | a [b]{.c} d
+    ///       ^^^^^^^
+    /// ```
+    pub bracketed_span: bool,
     /// Character escape.
     ///
     /// ```markdown
@@ -67,6 +133,13 @@ pub struct Constructs {
     ///       ^^^^^
     /// ```
     pub character_reference: bool,
+    /// Citation (Pandoc).
+    ///
+    /// ```markdown
+    /// > | a [see @b, p. 1] @c d
+    ///       ^^^^^^^^^^^^^^ ^^
+    /// ```
+    pub citation: bool,
     /// Code (indented).
     ///
     /// ```markdown
@@ -92,6 +165,13 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub code_text: bool,
+    /// Critic markup.
+    ///
+    /// ```markdown
+    /// > | a {++b++} {--c--} {~~d~>e~~} {==f==}{>>g<<}
+    ///       ^^^^^^^ ^^^^^^^ ^^^^^^^^^^ ^^^^^^^^^^^^^^
+    /// ```
+    pub critic_markup: bool,
     /// Definition.
     ///
     /// ```markdown
@@ -99,6 +179,42 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Directive (container).
+    ///
+    /// ```markdown
+    /// > | :::a[b]{c=d}
+    ///     ^^^^^^^^^^^^
+    /// > | e
+    ///     ^
+    /// > | :::
+    ///     ^^^
+    /// ```
+    pub directive_container: bool,
+    /// Directive (leaf).
+    ///
+    /// ```markdown
+    /// > | ::a[b]{c=d}
+    ///     ^^^^^^^^^^^
+    /// ```
+    pub directive_leaf: bool,
+    /// Directive (text).
+    ///
+    /// ```markdown
+    /// > | a :b[c]{d=e} f
+    ///       ^^^^^^^^^^
+    /// ```
+    pub directive_text: bool,
+    /// Fenced div (Pandoc).
+    ///
+    /// ```markdown
+    /// > | ::: {#a .b}
+    ///     ^^^^^^^^^^^
+    /// > | c
+    ///     ^
+    /// > | :::
+    ///     ^^^
+    /// ```
+    pub fenced_div: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -110,6 +226,16 @@ pub struct Constructs {
     ///     ^^^
     /// ````
     pub frontmatter: bool,
+    /// GFM: alert.
+    ///
+    /// This is GitHub’s alert syntax, which is not part of the GFM spec.
+    ///
+    /// ```markdown
+    /// > | > [!NOTE]
+    ///       ^^^^^^^
+    ///   | > a
+    /// ```
+    pub gfm_alert: bool,
     /// GFM: autolink literal.
     ///
     /// ```markdown
@@ -155,6 +281,13 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub gfm_task_list_item: bool,
+    /// GitHub reference.
+    ///
+    /// ```markdown
+    /// > | a #1, b/c#2, and 1a2b3c4.
+    ///       ^^  ^^^^^      ^^^^^^^
+    /// ```
+    pub github_reference: bool,
     /// Hard break (escape).
     ///
     /// ```markdown
@@ -201,6 +334,13 @@ pub struct Constructs {
     ///       ^^^
     /// ```
     pub html_text: bool,
+    /// Insert.
+    ///
+    /// ```markdown
+    /// > | a ++b++ c
+    ///       ^^^^^
+    /// ```
+    pub insert: bool,
     /// Label start (image).
     ///
     /// ```markdown
@@ -229,6 +369,13 @@ pub struct Constructs {
     ///     ^^^
     /// ```
     pub list_item: bool,
+    /// Mark (highlight).
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^^^^
+    /// ```
+    pub mark: bool,
     /// Math (flow).
     ///
     /// ```markdown
@@ -320,6 +467,51 @@ pub struct Constructs {
     /// > Otherwise, expressions are parsed with a basic algorithm that only
     /// > cares about braces.
     pub mdx_jsx_text: bool,
+    /// Mention.
+    ///
+    /// ```markdown
+    /// > | a @b c
+    ///       ^^
+    /// ```
+    pub mention: bool,
+    /// Ruby.
+    ///
+    /// ```markdown
+    /// > | a {漢字|かんじ} b
+    ///       ^^^^^^^^^^^^^
+    /// ```
+    pub ruby: bool,
+    /// Spoiler.
+    ///
+    /// ```markdown
+    /// > | a >!b!< c
+    ///       ^^^^^
+    /// ```
+    pub spoiler: bool,
+    /// Spoiler (pipes).
+    ///
+    /// ```markdown
+    /// > | a ||b|| c
+    ///       ^^^^^
+    /// ```
+    pub spoiler_pipes: bool,
+    /// Subscript.
+    ///
+    /// When on, one tilde forms subscript instead of GFM strikethrough,
+    /// and two tildes are still GFM strikethrough (if turned on).
+    ///
+    /// ```markdown
+    /// > | H~2~O
+    ///      ^^^
+    /// ```
+    pub subscript: bool,
+    /// Superscript.
+    ///
+    /// ```markdown
+    /// > | 2^10^
+    ///      ^^^^
+    /// ```
+    pub superscript: bool,
     /// Thematic break.
     ///
     /// ```markdown
@@ -327,6 +519,29 @@ pub struct Constructs {
     ///     ^^^
     /// ```
     pub thematic_break: bool,
+    /// Underline.
+    ///
+    /// When on, two underscores form underline instead of strong.
+    ///
+    /// ```markdown
+    /// > | a __b__ c
+    ///       ^^^^^
+    /// ```
+    pub underline: bool,
+    /// Wiki embed (Obsidian).
+    ///
+    /// ```markdown
+    /// > | a ![[b#c]] d
+    ///       ^^^^^^^^
+    /// ```
+    pub wiki_embed: bool,
+    /// Wiki link (Obsidian).
+    ///
+    /// ```markdown
+    /// > | a [[b|c]] d
+    ///       ^^^^^^^
+    /// ```
+    pub wiki_link: bool,
 }
 
 impl Default for Constructs {
@@ -340,32 +555,47 @@ impl Default for Constructs {
     /// <https://spec.commonmark.org>.
     fn default() -> Self {
         Self {
+            abbreviation: false,
             attention: true,
+            attribute_list: false,
             autolink: true,
+            block_id: false,
             block_quote: true,
+            block_quote_fenced: false,
+            bracketed_span: false,
             character_escape: true,
             character_reference: true,
+            citation: false,
             code_indented: true,
             code_fenced: true,
             code_text: true,
+            critic_markup: false,
             definition: true,
+            directive_container: false,
+            directive_leaf: false,
+            directive_text: false,
+            fenced_div: false,
             frontmatter: false,
+            gfm_alert: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
             gfm_footnote_definition: false,
             gfm_strikethrough: false,
             gfm_table: false,
             gfm_task_list_item: false,
+            github_reference: false,
             hard_break_escape: true,
             hard_break_trailing: true,
             heading_atx: true,
             heading_setext: true,
             html_flow: true,
             html_text: true,
+            insert: false,
             label_start_image: true,
             label_start_link: true,
             label_end: true,
             list_item: true,
+            mark: false,
             math_flow: false,
             math_text: false,
             mdx_esm: false,
@@ -373,7 +603,16 @@ impl Default for Constructs {
             mdx_expression_text: false,
             mdx_jsx_flow: false,
             mdx_jsx_text: false,
+            mention: false,
+            ruby: false,
+            spoiler: false,
+            spoiler_pipes: false,
+            subscript: false,
+            superscript: false,
             thematic_break: true,
+            underline: false,
+            wiki_embed: false,
+            wiki_link: false,
         }
     }
 }
@@ -432,6 +671,46 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Chat.
+    ///
+    /// This turns on `CommonMark`, turns off constructs that chat apps don’t
+    /// support (headings and HTML), and turns on GFM strikethrough, spoilers
+    /// with pipes, and underline, to match how markdown works in chat apps
+    /// such as Discord and Telegram.
+    pub fn chat() -> Self {
+        Self {
+            gfm_strikethrough: true,
+            heading_atx: false,
+            heading_setext: false,
+            html_flow: false,
+            html_text: false,
+            spoiler_pipes: true,
+            underline: true,
+            ..Self::default()
+        }
+    }
+
+    /// Slack.
+    ///
+    /// This turns on `CommonMark`, turns off constructs that Slack doesn’t
+    /// support (headings, HTML, and images), and turns on GFM strikethrough,
+    /// to match how markdown (mrkdwn) works in Slack.
+    ///
+    /// > 👉 **Note**: to match Slack, you also want `autolink_label` and
+    /// > `strong_single_asterisk` in [`ParseOptions`][], which
+    /// > [`ParseOptions::slack`][] turns on.
+    pub fn slack() -> Self {
+        Self {
+            gfm_strikethrough: true,
+            heading_atx: false,
+            heading_setext: false,
+            html_flow: false,
+            html_text: false,
+            label_start_image: false,
+            ..Self::default()
+        }
+    }
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -521,6 +800,9 @@ pub struct CompileOptions {
     /// (for images: `http`, `https`; for links: `http`, `https`, `irc`,
     /// `ircs`, `mailto`, `xmpp`), are safe.
     /// All other URLs are dangerous and dropped.
+    /// Which protocols are safe can be changed with
+    /// [`image_protocols`][CompileOptions::image_protocols] and
+    /// [`link_protocols`][CompileOptions::link_protocols].
     ///
     /// ## Examples
     ///
@@ -553,291 +835,431 @@ pub struct CompileOptions {
     /// ```
     pub allow_dangerous_protocol: bool,
 
-    /// Default line ending to use when compiling to HTML, for line endings not
-    /// in `value`.
+    /// URL to resolve relative destinations of links and images against.
     ///
-    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
-    /// the markdown document over to the compiled HTML.
-    /// In some cases, such as `> a`, CommonMark requires that extra line
-    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
-    ///
-    /// To create that line ending, the document is checked for the first line
-    /// ending that is used.
-    /// If there is no line ending, `default_line_ending` is used.
-    /// If that isn’t configured, `\n` is used.
+    /// The default is `None`, which uses destinations as they are.
+    /// Pass an absolute URL, such as `"https://example.com/blog/"`, to turn
+    /// relative destinations (such as `a`, `../a`, `/a`, or `#a`) into
+    /// absolute ones, which is needed when the HTML is shown somewhere else,
+    /// such as in RSS feeds or embedded previews.
+    /// Destinations are joined as defined in RFC 3986, after
+    /// [`link_resolve`][CompileOptions::link_resolve] and
+    /// [`image_resolve`][CompileOptions::image_resolve].
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `markdown-rs` uses `\n` by default:
+    /// // `markdown-rs` uses destinations as they are by default:
     /// assert_eq!(
-    ///     to_html("> a"),
-    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    ///     to_html_with_options("[a](../b) ![c](d.png)", &Options::default())?,
+    ///     "<p><a href=\"../b\">a</a> <img src=\"d.png\" alt=\"c\" /></p>"
     /// );
     ///
-    /// // Define `default_line_ending` to configure the default:
+    /// // Pass `base_url` to resolve them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "> a",
+    ///         "[a](../b) ![c](d.png)",
     ///         &Options {
     ///             compile: CompileOptions {
-    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               base_url: Some("https://example.com/blog/post/".into()),
     ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    ///     "<p><a href=\"https://example.com/blog/b\">a</a> <img src=\"https://example.com/blog/post/d.png\" alt=\"c\" /></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub default_line_ending: LineEnding,
+    pub base_url: Option<String>,
 
-    /// Textual label to use for the footnotes section.
+    /// Function to turn citations into HTML.
     ///
-    /// The default value is `"Footnotes"`.
-    /// Change it when the markdown is not in English.
+    /// This option does nothing if `citation` is not turned on in
+    /// [`Constructs`][crate::Constructs].
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// The default is `None`, which compiles citations like Pandoc does when
+    /// it does not process them: to `<span>`s with a `citation` class and a
+    /// `data-cites` attribute with the keys, with the citation as it is in
+    /// the source in them.
+    /// Pass a function to format citations against a bibliography.
+    /// When the function returns `None`, the default is used.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{mdast::Citation, to_html_with_options, CitationResolve, CompileOptions, Constructs, Options, ParseOptions};
     /// # fn main() -> Result<(), markdown::message::Message> {
-    ///
-    /// // `"Footnotes"` is used by default:
+    /// let citation = || ParseOptions {
+    ///     constructs: Constructs {
+    ///         citation: true,
+    ///         ..Constructs::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // `markdown-rs` keeps citations as they are by default:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
+    ///         "a [see @doe99, p. 3]",
+    ///         &Options {
+    ///             parse: citation(),
+    ///             ..Options::default()
+    ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a <span class=\"citation\" data-cites=\"doe99\">[see @doe99, p. 3]</span></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label` to use something else:
+    /// // Pass `citation_resolve` to format them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "a [see @doe99, p. 3] @roe01",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
+    ///             parse: citation(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
-    ///               ..CompileOptions::gfm()
+    ///               citation_resolve: Some(CitationResolve(std::rc::Rc::new(|citation: &Citation| {
+    ///                   let item = &citation.items[0];
+    ///                   if item.key == "doe99" {
+    ///                       Some(format!(
+    ///                           "({} Doe 1999, {})",
+    ///                           item.prefix.as_deref().unwrap_or(""),
+    ///                           item.locator.as_deref().unwrap_or("")
+    ///                       ))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
     ///             }
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a (see Doe 1999, p. 3) <span class=\"citation\" data-cites=\"roe01\">@roe01</span></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label: Option<String>,
+    pub citation_resolve: Option<CitationResolve>,
 
-    /// HTML tag name to use for the footnote label element.
-    ///
-    /// The default value is `"h2"`.
-    /// Change it to match your document structure.
-    ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// Bibliography to format citations with.
+    ///
+    /// The default is `None`, which keeps citations as they are.
+    /// Pass a [`Bibliography`][], such as from CSL-JSON, to format citations
+    /// in author-date style, linking to their reference, and to add a list
+    /// of the cited references.
+    /// The list is added in a fenced div with the id `refs`
+    /// (`::: {#refs}`), if there is one, or at the end of the document.
+    /// Keys that are not in the bibliography are shown in bold with a
+    /// question mark, like Pandoc; use [`lint()`][crate::lint] with
+    /// [`bibliography`][LintOptions::bibliography] to report them.
+    /// [`citation_resolve`][CompileOptions::citation_resolve] is used first,
+    /// if it is passed.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, Bibliography, CompileOptions, Constructs, Options, ParseOptions};
     /// # fn main() -> Result<(), markdown::message::Message> {
+    /// let bibliography = Bibliography::from_csl_json(
+    ///     r#"[{"id": "doe99", "type": "book", "title": "Markdown", "author": [{"family": "Doe", "given": "Jane"}], "issued": {"date-parts": [[1999]]}}]"#,
+    /// )?;
     ///
-    /// // `"h2"` is used by default:
+    /// // Pass `bibliography` to format citations and add references:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
+    ///         "a [see @doe99, p. 3]",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///                 constructs: Constructs {
+    ///                     citation: true,
+    ///                     ..Constructs::default()
+    ///                 },
+    ///                 ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               bibliography: Some(bibliography),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a <span class=\"citation\" data-cites=\"doe99\">(see <a href=\"#ref-doe99\" role=\"doc-biblioref\">Doe 1999</a>, p. 3)</span></p>\n<div id=\"refs\" class=\"references csl-bib-body\" role=\"list\">\n<div id=\"ref-doe99\" class=\"csl-entry\" role=\"listitem\">Doe, Jane. 1999. <em>Markdown</em>.</div>\n</div>\n"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub bibliography: Option<Bibliography>,
+
+    /// Prefix to use before classes that `markdown-rs` generates.
+    ///
+    /// The default is `None`, which does not prefix classes.
+    /// Pass a string such as `"md-"` to prefix the classes that the compiler
+    /// itself adds (such as `language-js`, `math-display`, `spoiler`,
+    /// `footnotes`, `data-footnote-backref`, and the default `sr-only` of the
+    /// footnote label), to prevent them from clashing with other CSS when
+    /// embedding the HTML in an app.
+    /// Classes from [`element_classes`][CompileOptions::element_classes] and
+    /// attribute lists are not prefixed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // Pass `gfm_footnote_label_tag_name` to use something else:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "```js\na\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_tag_name: Some("h1".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               class_prefix: Some("md-".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<pre><code class=\"md-language-js\">a\n</code></pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_tag_name: Option<String>,
+    pub class_prefix: Option<String>,
 
-    /// Attributes to use on the footnote label.
-    ///
-    /// The default value is `"class=\"sr-only\""`.
-    /// Change it to show the label and add other attributes.
+    /// Function to compile code blocks with.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass an empty string.
-    /// You can also add different attributes.
+    /// The default is `None`, which compiles code (fenced and indented) to
+    /// `<pre><code>`, with the info string as a `language-*` class.
+    /// Pass a function to plug in server-side syntax highlighting: it gets
+    /// the info string, the meta string, and the code, as text, and returns
+    /// trusted HTML, which is used as it is.
+    /// See [`CodeBlockHandler`][] for more info.
     ///
-    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
-    /// > calls use it with `aria-describedby` to provide an accessible label.
+    /// When [`element_render`][CompileOptions::element_render] is also
+    /// passed, it gets the code first, and this function is used if it
+    /// returns `None`.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CodeBlockHandler, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"class=\"sr-only\""` is used by default:
+    /// // `markdown-rs` compiles code blocks like normal by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html_with_options("```js\na\n```", &Options::default())?,
+    ///     "<pre><code class=\"language-js\">a\n</code></pre>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// // Pass `code_block_handler` to compile them yourself:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "```js\na\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               code_block_handler: Some(CodeBlockHandler(std::rc::Rc::new(
+    ///                   |lang: Option<&str>, _: Option<&str>, code: &str| {
+    ///                       format!("<pre data-lang=\"{}\">{}</pre>", lang.unwrap_or(""), code)
+    ///                   }
+    ///               ))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<pre data-lang=\"js\">a\n</pre>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_attributes: Option<String>,
+    pub code_block_handler: Option<CodeBlockHandler>,
 
-    /// Textual label to describe the backreference back to footnote calls.
+    /// How to compile critic markup.
     ///
-    /// The default value is `"Back to content"`.
-    /// Change it when the markdown is not in English.
+    /// The default is [`CriticMarkupMode::Visualize`][], which shows changes
+    /// with `<ins>`, `<del>`, and `<mark>` elements.
+    /// Pass [`CriticMarkupMode::Accept`][] or [`CriticMarkupMode::Reject`][]
+    /// to compile the document with all changes accepted or rejected.
     ///
-    /// This label is used in the `aria-label` attribute on each backreference
-    /// (the `↩` links).
-    /// It affects users of assistive technology.
+    /// This option does nothing if
+    /// [`critic_markup`][Constructs::critic_markup] is not turned on.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, CriticMarkupMode, Options, ParseOptions};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"Back to content"` is used by default:
+    /// let critic = || ParseOptions {
+    ///     constructs: Constructs {
+    ///         critic_markup: true,
+    ///         ..Constructs::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // `markdown-rs` shows changes by default:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
+    ///         "a {~~b~>c~~}",
+    ///         &Options {
+    ///             parse: critic(),
+    ///             ..Options::default()
+    ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a <del>b</del><ins>c</ins></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// // Pass `critic_markup_mode: CriticMarkupMode::Accept` to accept them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "a {~~b~>c~~}",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
+    ///             parse: critic(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_back_label: Some("Arrière".into()),
-    ///               ..CompileOptions::gfm()
+    ///               critic_markup_mode: CriticMarkupMode::Accept,
+    ///               ..CompileOptions::default()
     ///             }
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p>a c</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_back_label: Option<String>,
+    pub critic_markup_mode: CriticMarkupMode,
 
-    /// Prefix to use before the `id` attribute on footnotes to prevent them
-    /// from *clobbering*.
+    /// Default line ending to use when compiling to HTML, for line endings not
+    /// in `value`.
     ///
-    /// The default is `"user-content-"`.
-    /// Pass `Some("".into())` for trusted markdown and when you are careful
-    /// with polyfilling.
-    /// You could pass a different prefix.
+    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
+    /// the markdown document over to the compiled HTML.
+    /// In some cases, such as `> a`, `CommonMark` requires that extra line
+    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
     ///
-    /// DOM clobbering is this:
+    /// To create that line ending, the document is checked for the first line
+    /// ending that is used.
+    /// If there is no line ending, `default_line_ending` is used.
+    /// If that isn’t configured, `\n` is used.
+    ///
+    /// ## Examples
     ///
-    /// ```html
-    /// <p id="x"></p>
-    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
     /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// The above example shows that elements are made available by browsers,
-    /// by their ID, on the `window` object.
-    /// This is a security risk because you might be expecting some other
-    /// variable at that place.
-    /// It can also break polyfills.
-    /// Using a prefix solves these problems.
+    /// // `markdown-rs` uses `\n` by default:
+    /// assert_eq!(
+    ///     to_html("> a"),
+    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    /// );
+    ///
+    /// // Define `default_line_ending` to configure the default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_line_ending: LineEnding,
+
+    /// Extra classes to add to elements, by tag name.
+    ///
+    /// The default is `[]`.
+    /// Each pair is a tag name (such as `table`, `a`, or `h2`) and the classes
+    /// to add to every such element in the output (such as
+    /// `table table-striped`), which is useful for utility CSS frameworks.
+    /// These classes are joined with the classes that `markdown-rs` generates
+    /// (such as `language-js`) and those from attribute lists.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"user-content-"` is used by default:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
+    ///         "[a](b)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               element_classes: vec![("a".into(), "link".into())],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href=\"b\" class=\"link\">a</a></p>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub element_classes: Vec<(String, String)>,
+
+    /// Function to render headings, links, images, and code with.
     ///
-    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// The default is `None`, which compiles them like normal.
+    /// Pass a function to emit other HTML for some of them, while the rest
+    /// of the document is compiled like normal.
+    /// See [`ElementRender`][] and [`Element`][crate::Element] for more
+    /// info.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Element, ElementRender, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` compiles links like normal by default:
+    /// assert_eq!(
+    ///     to_html_with_options("[a](b)", &Options::default())?,
+    ///     "<p><a href=\"b\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `element_render` to render them yourself:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "[a](b)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_clobber_prefix: Some("".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               element_render: Some(ElementRender(std::rc::Rc::new(|element: &Element| {
+    ///                   if let Element::Link { destination, children, .. } = element {
+    ///                       Some(format!("<a href=\"{}\" rel=\"nofollow\">{}</a>", destination.unwrap_or(""), children))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href=\"b\" rel=\"nofollow\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_clobber_prefix: Option<String>,
+    pub element_render: Option<ElementRender>,
 
-    /// Whether or not GFM task list html `<input>` items are enabled.
+    /// Whether to add EPUB semantics.
     ///
-    /// This determines whether or not the user of the browser is able
-    /// to click and toggle generated checkbox items. The default is false.
+    /// The default is `false`.
+    /// Pass `true` to add `epub:type` attributes to GFM footnotes (calls,
+    /// the section, its items, and backreferences), which e-readers use to
+    /// show footnotes as pop-ups.
+    /// The HTML that `markdown-rs` generates is already valid XHTML.
+    /// To get XHTML documents for an EPUB, use [`to_epub()`][crate::to_epub].
     ///
     /// ## Examples
     ///
@@ -845,428 +1267,3356 @@ pub struct CompileOptions {
     /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
-    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
-    /// // browser users.
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "* [x] y.",
+    ///         "[^a]\n\n[^a]: b",
     ///         &Options {
     ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///                 gfm_task_list_item_checkable: true,
-    ///                 ..CompileOptions::gfm()
+    ///               epub: true,
+    ///               ..CompileOptions::gfm()
     ///             }
     ///         }
     ///     )?,
-    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" epub:type=\"noteref\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\" epub:type=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\" epub:type=\"footnote\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\" epub:type=\"backlink\">↩</a></p>\n</li>\n</ol>\n</section>\n"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_task_list_item_checkable: bool,
+    pub epub: bool,
 
-    /// Whether to support the GFM tagfilter.
+    /// Rank of headings that start a new chapter in
+    /// [`to_epub()`][crate::to_epub].
     ///
-    /// This option does nothing if `allow_dangerous_html` is not turned on.
-    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
-    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    /// The default is `0`, which does not split documents into chapters.
+    /// Pass `1` to start a new chapter at each heading of rank 1 (`# a`), `2`
+    /// to also start one at each heading of rank 2 (`## a`), and so on.
+    /// Only headings that are not in containers (such as block quotes) start
+    /// chapters.
     ///
-    /// The tagfilter is kinda weird and kinda useless.
-    /// The tag filter is a naïve attempt at XSS protection.
-    /// You should use a proper HTML sanitizing algorithm instead.
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_epub, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let chapters = to_epub(
+    ///     "# a\n\nb\n\n# c\n\nd",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           epub_chapter_rank: 1,
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(chapters.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub epub_chapter_rank: u8,
+
+    /// Function to change events after parsing, before compiling to HTML.
+    ///
+    /// The default is `None`, which compiles the events as they are.
+    /// Pass a function to drop, retype, or inject events, such as to remove
+    /// images, without compiling markdown yourself.
+    /// The function also runs before [`toc()`][crate::toc()].
+    /// See [`EventTransform`][] for more info.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{event::{Event, Kind, Name}, to_html_with_options, CompileOptions, EventTransform, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// // `markdown-rs` compiles all events by default:
+    /// assert_eq!(
+    ///     to_html_with_options("a ![b](c) *d*", &Options::default())?,
+    ///     "<p>a <img src=\"c\" alt=\"b\" /> <em>d</em></p>"
+    /// );
+    ///
+    /// // Pass `event_transform` to change them, such as to turn emphasis
+    /// // into strong:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "a ![b](c) *d*",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
+    ///               event_transform: Some(EventTransform(std::rc::Rc::new(|events: &mut Vec<Event>, _: &[u8]| {
+    ///                   for event in events.iter_mut() {
+    ///                       if event.name == Name::Emphasis {
+    ///                           event.name = Name::Strong;
+    ///                       }
+    ///                   }
+    ///               }))),
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<iframe>"
+    ///     "<p>a <img src=\"c\" alt=\"b\" /> <strong>d</strong></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub event_transform: Option<EventTransform>,
+
+    /// Function to check whether links are external.
+    ///
+    /// The default is `None`, which treats links whose destination has a
+    /// host other than that of [`origin`][CompileOptions::origin] as
+    /// external.
+    /// Pass a function to decide yourself.
+    /// This is used by
+    /// [`external_link_rel`][CompileOptions::external_link_rel] and
+    /// [`external_link_target_blank`][CompileOptions::external_link_target_blank].
+    /// See [`ExternalLinkCheck`][] for more info.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, ExternalLinkCheck, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` treats links with a host as external by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://a.com) [b](/b.pdf)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_target_blank: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://a.com\" target=\"_blank\">a</a> <a href=\"/b.pdf\">b</a></p>"
     /// );
     ///
-    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// // Pass `external_link_check` to decide yourself:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "[a](https://a.com) [b](/b.pdf)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
-    ///               gfm_tagfilter: true,
+    ///               external_link_check: Some(ExternalLinkCheck(std::rc::Rc::new(|url: &str| {
+    ///                   url.ends_with(".pdf")
+    ///               }))),
+    ///               external_link_target_blank: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "&lt;iframe>"
+    ///     "<p><a href=\"https://a.com\">a</a> <a href=\"/b.pdf\" target=\"_blank\">b</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
+    pub external_link_check: Option<ExternalLinkCheck>,
+
+    /// Values of the `rel` attribute to add to external links.
     ///
-    /// ## References
+    /// The default is empty, which adds no `rel`.
+    /// Pass values such as `nofollow`, `noopener`, and `noreferrer` to
+    /// add them to links (and autolinks) whose destination is on another
+    /// site than [`origin`][CompileOptions::origin].
     ///
-    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
-    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
-    pub gfm_tagfilter: bool,
-}
-
-impl CompileOptions {
-    /// GFM.
+    /// ## Examples
     ///
-    /// GFM stands for **GitHub flavored markdown**.
-    /// On the compilation side, GFM turns on the GFM tag filter.
-    /// The tagfilter is useless, but it’s included here for consistency, and
-    /// this method exists for parity to parse options.
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>.
-    pub fn gfm() -> Self {
-        Self {
-            gfm_tagfilter: true,
-            ..Self::default()
-        }
-    }
-}
+    /// // `markdown-rs` adds no `rel` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("[a](https://a.com) [b](/b)", &Options::default())?,
+    ///     "<p><a href=\"https://a.com\">a</a> <a href=\"/b\">b</a></p>"
+    /// );
+    ///
+    /// // Pass `external_link_rel` to add it to external links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://a.com) [b](/b)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_rel: vec!["nofollow".into(), "noopener".into(), "noreferrer".into()],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://a.com\" rel=\"nofollow noopener noreferrer\">a</a> <a href=\"/b\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_link_rel: Vec<String>,
 
-/// Configuration that describes how to parse from markdown.
-///
-/// You can use this:
-///
-/// *   To control what markdown constructs are turned on and off
-/// *   To control some of those constructs
-/// *   To add support for certain programming languages when parsing MDX
-///
-/// In most cases, you will want to use the default trait or `gfm` method.
-///
-/// ## Examples
-///
-/// ```
-/// use markdown::ParseOptions;
-/// # fn main() {
-///
-/// // Use the default trait to parse markdown according to `CommonMark`:
-/// let commonmark = ParseOptions::default();
-///
-/// // Use the `gfm` method to parse markdown according to GFM:
-/// let gfm = ParseOptions::gfm();
-/// # }
-/// ```
-#[allow(clippy::struct_excessive_bools)]
-pub struct ParseOptions {
-    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
-    /// Which constructs to enable and disable.
+    /// Whether to add `target="_blank"` to external links.
     ///
-    /// The default is to follow `CommonMark`.
+    /// The default is `false`, which does not add `target`.
+    /// Pass `true` to open links (and autolinks) whose destination is on
+    /// another site than [`origin`][CompileOptions::origin] in a new tab.
+    /// Which links are external can be changed with
+    /// [`external_link_check`][CompileOptions::external_link_check].
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `markdown-rs` follows CommonMark by default:
+    /// // `markdown-rs` adds no `target` by default:
     /// assert_eq!(
-    ///     to_html("    indented code?"),
-    ///     "<pre><code>indented code?\n</code></pre>"
+    ///     to_html_with_options("[a](https://a.com) [b](/b)", &Options::default())?,
+    ///     "<p><a href=\"https://a.com\">a</a> <a href=\"/b\">b</a></p>"
     /// );
     ///
-    /// // Pass `constructs` to choose what to enable and disable:
+    /// // Pass `external_link_target_blank` to add it to external links:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "    indented code?",
+    ///         "[a](https://a.com) [b](/b)",
     ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 code_indented: false,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               ..ParseOptions::default()
+    ///             compile: CompileOptions {
+    ///               external_link_target_blank: true,
+    ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>indented code?</p>"
+    ///     "<p><a href=\"https://a.com\" target=\"_blank\">a</a> <a href=\"/b\">b</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub constructs: Constructs,
+    pub external_link_target_blank: bool,
 
-    /// Whether to support GFM strikethrough with a single tilde
+    /// Textual label to use for the footnotes section.
     ///
-    /// This option does nothing if `gfm_strikethrough` is not turned on in
-    /// `constructs`.
-    /// This option does not affect strikethrough with double tildes.
+    /// The default value is `"Footnotes"`.
+    /// Change it when the markdown is not in English.
     ///
-    /// The default is `true`, which follows how markdown on `github.com`
-    /// works, as strikethrough with single tildes is supported.
-    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
-    /// strikethrough with single tildes.
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"Footnotes"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label: Option<String>,
+
+    /// HTML tag name to use for the footnote label element.
+    ///
+    /// The default value is `"h2"`.
+    /// Change it to match your document structure.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"h2"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_tag_name: Option<String>,
+
+    /// Attributes to use on the footnote label.
+    ///
+    /// The default value is `"class=\"sr-only\""`.
+    /// Change it to show the label and add other attributes.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass an empty string.
+    /// You can also add different attributes.
+    ///
+    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
+    /// > calls use it with `aria-describedby` to provide an accessible label.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"class=\"sr-only\""` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_attributes: Option<String>,
+
+    /// Textual label to describe the backreference back to footnote calls.
+    ///
+    /// The default value is `"Back to content"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is used in the `aria-label` attribute on each backreference
+    /// (the `↩` links).
+    /// It affects users of assistive technology.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"Back to content"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_back_label: Some("Arrière".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_back_label: Option<String>,
+
+    /// Prefix to use before the `id` attribute on footnotes to prevent them
+    /// from *clobbering*.
+    ///
+    /// The default is `"user-content-"`.
+    /// Pass `Some("".into())` for trusted markdown and when you are careful
+    /// with polyfilling.
+    /// You could pass a different prefix.
+    ///
+    /// DOM clobbering is this:
+    ///
+    /// ```html
+    /// <p id="x"></p>
+    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
+    /// ```
+    ///
+    /// The above example shows that elements are made available by browsers,
+    /// by their ID, on the `window` object.
+    /// This is a security risk because you might be expecting some other
+    /// variable at that place.
+    /// It can also break polyfills.
+    /// Using a prefix solves these problems.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"user-content-"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_clobber_prefix: Some("".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_clobber_prefix: Option<String>,
+
+    /// Whether or not GFM task list html `<input>` items are enabled.
+    ///
+    /// This determines whether or not the user of the browser is able
+    /// to click and toggle generated checkbox items. The default is false.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
+    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
+    /// // browser users.
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* [x] y.",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///                 gfm_task_list_item_checkable: true,
+    ///                 ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_task_list_item_checkable: bool,
+
+    /// Whether to support the GFM tagfilter.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
+    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    ///
+    /// The tagfilter is kinda weird and kinda useless.
+    /// The tag filter is a naïve attempt at XSS protection.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<iframe>"
+    /// );
+    ///
+    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               gfm_tagfilter: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "&lt;iframe>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## References
+    ///
+    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
+    pub gfm_tagfilter: bool,
+
+    /// Function to turn GitHub references into links.
+    ///
+    /// This option does nothing if `github_reference` is not turned on in
+    /// [`Constructs`][crate::Constructs].
+    ///
+    /// The default is `None`, which compiles references as plain text.
+    /// Pass a function to turn references into URLs (`Some(url)`), or to
+    /// leave them as plain text (`None`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, GithubReferenceResolve, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// let github_reference = || ParseOptions {
+    ///     constructs: Constructs {
+    ///         github_reference: true,
+    ///         ..Constructs::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // `markdown-rs` compiles references as plain text by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a #1",
+    ///         &Options {
+    ///             parse: github_reference(),
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a #1</p>"
+    /// );
+    ///
+    /// // Pass `github_reference_resolve` to link them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a #1 and b/c#2",
+    ///         &Options {
+    ///             parse: github_reference(),
+    ///             compile: CompileOptions {
+    ///               github_reference_resolve: Some(GithubReferenceResolve(std::rc::Rc::new(|reference| {
+    ///                   if reference.repository.is_none() {
+    ///                       Some(format!("/issues/{}", reference.value))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p>a <a href=\"/issues/1\" class=\"github-reference\">#1</a> and b/c#2</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub github_reference_resolve: Option<GithubReferenceResolve>,
+
+    /// Whether to turn soft line breaks into hard breaks.
+    ///
+    /// The default is `false`, which keeps line endings in paragraphs as they
+    /// are, which browsers display as spaces.
+    ///
+    /// Pass `true` to turn them into `<br />` elements, like in comments on
+    /// GitHub and in chat apps.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` keeps soft line breaks by default:
+    /// assert_eq!(
+    ///     to_html("a\nb"),
+    ///     "<p>a\nb</p>"
+    /// );
+    ///
+    /// // Turn `hard_breaks` on to turn them into hard breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               hard_breaks: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub hard_breaks: bool,
+
+    /// Whether to add `id` attributes to headings.
+    ///
+    /// The default is `false`, which does not add ids, which is how
+    /// `CommonMark` works.
+    /// Pass `true` to generate ids from the text of headings, with the
+    /// algorithm in [`slug`][CompileOptions::slug], so that headings can be
+    /// linked to.
+    /// Headings that get an `id` from an attribute list keep it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not add ids by default:
+    /// assert_eq!(
+    ///     to_html("# Hello, world!"),
+    ///     "<h1>Hello, world!</h1>"
+    /// );
+    ///
+    /// // Turn `heading_ids` on to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# Hello, world!\n\n# Hello, world!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"hello-world\">Hello, world!</h1>\n<h1 id=\"hello-world-1\">Hello, world!</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_ids: bool,
+
+    /// Namespace to use for ids that are generated.
+    ///
+    /// The default is `None`, which does not namespace ids.
+    /// Pass a string to prefix every id that `markdown-rs` makes up:
+    /// the ids of headings (see [`heading_ids`][CompileOptions::heading_ids]),
+    /// of footnote calls, definitions, and their label, and of block ids.
+    /// Links to those ids that are generated, such as the links between
+    /// footnote calls and definitions, are prefixed too.
+    /// Ids that are given by authors in attribute lists are not prefixed.
+    ///
+    /// Generated ids only depend on the document and the options, so when
+    /// several documents are rendered into the same page, passing a different
+    /// namespace for each one prevents their ids from clashing.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not namespace ids by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"a\">a</h1>"
+    /// );
+    ///
+    /// // Pass `id_prefix` to namespace them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               id_prefix: Some("doc-1-".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"doc-1-a\">a</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub id_prefix: Option<String>,
+
+    /// Function to turn images into sources and attributes.
+    ///
+    /// The default is `None`, which uses sources as they are.
+    /// Pass a function to rewrite sources and add attributes (such as
+    /// `width`, `height`, or `srcset`), so that asset pipelines can
+    /// fingerprint images or serve them from a CDN while compiling.
+    /// What it returns is sanitized and encoded.
+    /// See [`ImageResolve`][] for more info.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, ImageResolve, Options, ResolvedImage};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses sources as they are by default:
+    /// assert_eq!(
+    ///     to_html_with_options("![a](b.png)", &Options::default())?,
+    ///     "<p><img src=\"b.png\" alt=\"a\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_resolve` to rewrite them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](b.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_resolve: Some(ImageResolve(std::rc::Rc::new(|src: &str, _: &str, _: Option<&str>| {
+    ///                   Some(ResolvedImage {
+    ///                       src: format!("https://cdn.example.com/{}", src),
+    ///                       attributes: vec![("width".into(), "640".into())],
+    ///                   })
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"https://cdn.example.com/b.png\" alt=\"a\" width=\"640\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_resolve: Option<ImageResolve>,
+
+    /// Proxy to route external images through, like GitHub’s camo.
+    ///
+    /// The default is `None`, which uses sources as they are.
+    /// Pass an [`ImageProxy`][] to replace the sources of images that are on
+    /// another site than [`origin`][CompileOptions::origin] with signed
+    /// URLs to the proxy, which prevents mixed content and leaking the IP
+    /// addresses of readers to other sites, such as for user generated
+    /// content.
+    /// This happens after [`image_resolve`][CompileOptions::image_resolve]
+    /// and [`base_url`][CompileOptions::base_url].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, ImageProxy, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses sources as they are by default:
+    /// assert_eq!(
+    ///     to_html_with_options("![a](http://a.com/b.png) ![c](/d.png)", &Options::default())?,
+    ///     "<p><img src=\"http://a.com/b.png\" alt=\"a\" /> <img src=\"/d.png\" alt=\"c\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_proxy` to route external images through a proxy:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](http://a.com/b.png) ![c](/d.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_proxy: Some(ImageProxy {
+    ///                   url: "https://camo.example.com/{digest}/{url}".into(),
+    ///                   key: "secret".into(),
+    ///               }),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"https://camo.example.com/b22b240e9023cbb499028f4e33906cc4c47f331b/687474703a2f2f612e636f6d2f622e706e67\" alt=\"a\" /> <img src=\"/d.png\" alt=\"c\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_proxy: Option<ImageProxy>,
+
+    /// Protocols to allow in the sources of images.
+    ///
+    /// The default is `None`, which allows `http` and `https`.
+    /// Pass a list of (lowercase) protocols to allow others, such as
+    /// `data`, or fewer, such as only `https`.
+    /// Sources with other protocols are dropped.
+    /// Sources without a protocol (such as `./a.png`) are always allowed.
+    /// This option does nothing if
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` allows `http` and `https` in images by default:
+    /// assert_eq!(
+    ///     to_html_with_options("![a](http://a.com/b.png) ![c](data:image/png;base64,d)", &Options::default())?,
+    ///     "<p><img src=\"http://a.com/b.png\" alt=\"a\" /> <img src=\"\" alt=\"c\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_protocols` to allow other protocols:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](http://a.com/b.png) ![c](data:image/png;base64,d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_protocols: Some(vec!["https".into(), "data".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"\" alt=\"a\" /> <img src=\"data:image/png;base64,d\" alt=\"c\" /></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_protocols: Option<Vec<String>>,
+
+    /// Protocols to allow in the destinations of links.
+    ///
+    /// The default is `None`, which allows `http`, `https`, `irc`, `ircs`,
+    /// `mailto`, and `xmpp`.
+    /// Pass a list of (lowercase) protocols to allow others, such as `tel`,
+    /// or fewer, such as only `https`.
+    /// Destinations with other protocols are dropped.
+    /// Destinations without a protocol (such as `./a` or `#a`) are always
+    /// allowed.
+    /// This applies to links, autolinks, mentions, and GitHub references,
+    /// but not images, see
+    /// [`image_protocols`][CompileOptions::image_protocols].
+    /// This option does nothing if
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` allows some protocols in links by default:
+    /// assert_eq!(
+    ///     to_html_with_options("[a](tel:123) [b](mailto:c@d.e)", &Options::default())?,
+    ///     "<p><a href=\"\">a</a> <a href=\"mailto:c@d.e\">b</a></p>"
+    /// );
+    ///
+    /// // Pass `link_protocols` to allow other protocols:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](tel:123) [b](mailto:c@d.e)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_protocols: Some(vec!["https".into(), "tel".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"tel:123\">a</a> <a href=\"\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_protocols: Option<Vec<String>>,
+
+    /// Function to turn the destinations of links into URLs.
+    ///
+    /// The default is `None`, which uses destinations as they are.
+    /// Pass a function to rewrite them (`Some(url)`), such as to map
+    /// `a.md` to `/a/` in a wiki, or to add tracking parameters, or to drop
+    /// links (`None`), which keeps their content.
+    /// The function is called for links (resource and reference) and
+    /// autolinks (including GFM autolink literals), but not images.
+    /// What it returns is sanitized like any other destination.
+    /// See [`LinkResolve`][] for more info.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, LinkKind, LinkResolve, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses destinations as they are by default:
+    /// assert_eq!(
+    ///     to_html_with_options("[a](a.md) [b](https://b.com)", &Options::default())?,
+    ///     "<p><a href=\"a.md\">a</a> <a href=\"https://b.com\">b</a></p>"
+    /// );
+    ///
+    /// // Pass `link_resolve` to rewrite them, or to drop links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](a.md) [b](https://b.com)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               link_resolve: Some(LinkResolve(std::rc::Rc::new(|destination: &str, _: LinkKind| {
+    ///                   destination.strip_suffix(".md").map(|name| format!("/{}/", name))
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"/a/\">a</a> b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub link_resolve: Option<LinkResolve>,
+
+    /// Whether to compile math to `MathML`.
+    ///
+    /// The default is `false`, which compiles math (flow) and math (text) to
+    /// `<pre><code class="language-math math-display">` and
+    /// `<code class="language-math math-inline">`, for scripts such as `KaTeX`
+    /// or `MathJax` to render.
+    /// Pass `true` to compile them to `<math display="block">` and `<math>`
+    /// instead, which browsers render without scripts.
+    ///
+    /// Only a small subset of TeX is turned into `MathML`: letters, numbers,
+    /// operators, groups, superscripts and subscripts, fractions, roots, text,
+    /// spacing, and common commands, such as Greek letters and arrows.
+    /// Unknown commands are turned into `<merror>`.
+    /// The TeX is kept in an `<annotation>`.
+    ///
+    /// This option does nothing if `math_flow` or `math_text` are not turned
+    /// on in [`Constructs`][crate::Constructs].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` compiles math to code by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$x^2$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><code class=\"language-math math-inline\">x^2</code></p>"
+    /// );
+    ///
+    /// // Pass `math_mathml: true` to compile math to `MathML`:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$x^2$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               math_mathml: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><math><semantics><mrow><msup><mi>x</mi><mn>2</mn></msup></mrow><annotation encoding=\"application/x-tex\">x^2</annotation></semantics></math></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_mathml: bool,
+
+    /// Function to turn mentions into links.
+    ///
+    /// This option does nothing if `mention` is not turned on in
+    /// [`Constructs`][crate::Constructs].
+    ///
+    /// The default is `None`, which compiles mentions to `<span>`s with a
+    /// `mention` class and a `data-mention` attribute with the name, with
+    /// the mention as it is in the source in them.
+    /// Pass a function to turn names into links (`Some((url, label))`), or
+    /// to reject them (`None`), which compiles them as plain text.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, MentionResolve, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// let mention = || ParseOptions {
+    ///     constructs: Constructs {
+    ///         mention: true,
+    ///         ..Constructs::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // `markdown-rs` compiles mentions to spans by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a @b",
+    ///         &Options {
+    ///             parse: mention(),
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a <span class=\"mention\" data-mention=\"b\">@b</span></p>"
+    /// );
+    ///
+    /// // Pass `mention_resolve` to link them, or to reject them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a @b @c",
+    ///         &Options {
+    ///             parse: mention(),
+    ///             compile: CompileOptions {
+    ///               mention_resolve: Some(MentionResolve(std::rc::Rc::new(|name: &str| {
+    ///                   if name == "b" {
+    ///                       Some(("/u/b".into(), "Bea".into()))
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p>a <a href=\"/u/b\" class=\"mention\">Bea</a> @c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mention_resolve: Option<MentionResolve>,
+
+    /// URL of the site that the HTML is shown on.
+    ///
+    /// The default is `None`, which treats all links with a host (such as
+    /// `https://example.com/a` or `//example.com/a`) as external.
+    /// Pass a URL such as `"https://example.com"` to treat links to that
+    /// host as internal.
+    /// Links without a host (such as `/a`, `#a`, or `mailto:a@b.c`) are
+    /// always internal.
+    /// This is used by
+    /// [`external_link_rel`][CompileOptions::external_link_rel],
+    /// [`external_link_target_blank`][CompileOptions::external_link_target_blank],
+    /// and [`image_proxy`][CompileOptions::image_proxy].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` treats all links with a host as external by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://a.com) [b](https://b.com)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_rel: vec!["nofollow".into()],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://a.com\" rel=\"nofollow\">a</a> <a href=\"https://b.com\" rel=\"nofollow\">b</a></p>"
+    /// );
+    ///
+    /// // Pass `origin` to treat links to your site as internal:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://a.com) [b](https://b.com)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_rel: vec!["nofollow".into()],
+    ///               origin: Some("https://a.com".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://a.com\">a</a> <a href=\"https://b.com\" rel=\"nofollow\">b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub origin: Option<String>,
+
+    /// Whether to sanitize HTML.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which passes HTML through untouched.
+    /// Pass `true` to keep only tags and attributes for benign formatting,
+    /// such as `<b>`, `<sub>`, or `<img src>`, and to drop the rest, which
+    /// lets users write some HTML in comments and other user generated
+    /// content.
+    /// Content between dropped tags is kept, except for in tags such as
+    /// `<script>` and `<style>`.
+    /// URLs in attributes are checked for safe protocols, just like
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// does by default.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div onclick=\"a()\">\n<b>c</b><script>d()</script>\n</div>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div onclick=\"a()\">\n<b>c</b><script>d()</script>\n</div>"
+    /// );
+    ///
+    /// // Pass `sanitize_html: true` to make it safe:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div onclick=\"a()\">\n<b>c</b><script>d()</script>\n</div>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               sanitize_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div>\n<b>c</b>\n</div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub sanitize_html: bool,
+
+    /// Algorithm to turn the text of headings into ids.
+    ///
+    /// This option does nothing if `heading_ids` is not turned on.
+    ///
+    /// The default is [`SlugKind::GitHub`][], which generates the same ids as
+    /// GitHub.
+    /// Pass another kind to match another platform, or a custom function.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, SlugKind};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` generates ids like GitHub by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# 1. Über - uns",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"1-über---uns\">1. Über - uns</h1>"
+    /// );
+    ///
+    /// // Pass `slug: SlugKind::Kramdown` to generate them like kramdown:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# 1. Über - uns",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_ids: true,
+    ///               slug: SlugKind::Kramdown,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 id=\"ber---uns\">1. Über - uns</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub slug: SlugKind,
+
+    /// Whether to add `data-sourcepos` attributes to blocks.
+    ///
+    /// The default is `false`, which does not add them.
+    /// Pass `true` to add the place where each block came from in markdown
+    /// to its element, like `cmark --sourcepos` does, which lets editors
+    /// with a live preview synchronize scrolling and map clicks in the
+    /// preview back to the source.
+    /// The value is the start line and column, and the end line and column
+    /// (inclusive), such as `1:1-2:5`.
+    /// Blocks are paragraphs, headings, thematic breaks, block quotes,
+    /// lists, list items, code (fenced and indented), and tables, their rows
+    /// and cells.
+    /// See [`sourcepos_inline`][CompileOptions::sourcepos_inline] to add
+    /// them to inline elements too.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not add source positions by default:
+    /// assert_eq!(
+    ///     to_html_with_options("# a\n\n> b\n> *c*", &Options::default())?,
+    ///     "<h1>a</h1>\n<blockquote>\n<p>b\n<em>c</em></p>\n</blockquote>"
+    /// );
+    ///
+    /// // Pass `sourcepos: true` to add them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\n> b\n> *c*",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               sourcepos: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1 data-sourcepos=\"1:1-1:3\">a</h1>\n<blockquote data-sourcepos=\"3:1-4:5\">\n<p data-sourcepos=\"3:3-4:5\">b\n<em>c</em></p>\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub sourcepos: bool,
+
+    /// Whether to add `data-sourcepos` attributes to inline elements too.
+    ///
+    /// This option does nothing if
+    /// [`sourcepos`][CompileOptions::sourcepos] is not turned on.
+    /// The default is `false`, which only adds them to blocks.
+    /// Pass `true` to also add them to emphasis, strong, strikethrough,
+    /// code (text), links, autolinks, and images.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` only adds source positions to blocks by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a *b* [c](d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               sourcepos: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p data-sourcepos=\"1:1-1:12\">a <em>b</em> <a href=\"d\">c</a></p>"
+    /// );
+    ///
+    /// // Pass `sourcepos_inline: true` to add them to inline elements too:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a *b* [c](d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               sourcepos: true,
+    ///               sourcepos_inline: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p data-sourcepos=\"1:1-1:12\">a <em data-sourcepos=\"1:3-1:5\">b</em> <a href=\"d\" data-sourcepos=\"1:7-1:12\">c</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub sourcepos_inline: bool,
+
+    /// Whether to drop HTML.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is turned on.
+    /// The default is `false`, which encodes HTML, so that it is shown as
+    /// text.
+    /// Pass `true` to drop HTML instead, which is what comment systems
+    /// and other user generated content prefer over showing `&lt;div&gt;`.
+    /// HTML (flow) is dropped entirely.
+    /// HTML (text) is only the tags themselves, so text between them is
+    /// kept.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` encodes HTML by default:
+    /// assert_eq!(
+    ///     to_html_with_options("<div>\na\n</div>\n\nb <i>c</i>", &Options::default())?,
+    ///     "&lt;div&gt;\na\n&lt;/div&gt;\n<p>b &lt;i&gt;c&lt;/i&gt;</p>"
+    /// );
+    ///
+    /// // Pass `strip_html: true` to drop it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div>\na\n</div>\n\nb <i>c</i>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               strip_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>b c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strip_html: bool,
+
+    /// Whether to highlight code with syntect.
+    ///
+    /// The default is `None`, which does not highlight code.
+    /// Pass a [`SyntaxHighlight`][] to highlight fenced code whose info
+    /// string is a language that syntect knows, such as `rust` or `js`,
+    /// with inline styles or classes.
+    /// The `<pre>` and `<code>` elements stay the same.
+    ///
+    /// A [`code_block_handler`][CompileOptions::code_block_handler] takes
+    /// precedence over this.
+    ///
+    /// Only available with the `syntect` feature.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, SyntaxHighlight, SyntaxHighlightStyle};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not highlight code by default:
+    /// assert_eq!(
+    ///     to_html_with_options("```rust\nfn\n```", &Options::default())?,
+    ///     "<pre><code class=\"language-rust\">fn\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `syntax_highlight` to highlight it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```rust\nfn\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               syntax_highlight: Some(SyntaxHighlight {
+    ///                   style: SyntaxHighlightStyle::Classes,
+    ///                   ..SyntaxHighlight::default()
+    ///               }),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-rust\"><span class=\"source rust\"><span class=\"storage type function rust\">fn</span>\n</span></code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "syntect")]
+    pub syntax_highlight: Option<SyntaxHighlight>,
+
+    /// Function to make URLs safe, instead of the default.
+    ///
+    /// The default is `None`, which encodes URLs and drops those with
+    /// protocols that are not allowed (see
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol],
+    /// [`image_protocols`][CompileOptions::image_protocols], and
+    /// [`link_protocols`][CompileOptions::link_protocols]).
+    /// Pass a function to handle some URLs yourself (`Some(url)`), such as
+    /// to allow `data:` images from trusted sources or to drop URLs with
+    /// confusable hosts, and to sanitize the rest like normal (`None`).
+    /// What it returns is still encoded, but its protocol is not checked.
+    /// See [`UrlSanitize`][] for more info.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, UrlKind, UrlSanitize};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` drops `data:` images by default:
+    /// assert_eq!(
+    ///     to_html_with_options("![a](data:image/png;base64,b) [c](data:d)", &Options::default())?,
+    ///     "<p><img src=\"\" alt=\"a\" /> <a href=\"\">c</a></p>"
+    /// );
+    ///
+    /// // Pass `url_sanitize` to allow them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![a](data:image/png;base64,b) [c](data:d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               url_sanitize: Some(UrlSanitize(std::rc::Rc::new(|url: &str, kind: UrlKind| {
+    ///                   if kind == UrlKind::Image && url.starts_with("data:image/png;") {
+    ///                       Some(url.into())
+    ///                   } else {
+    ///                       None
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"data:image/png;base64,b\" alt=\"a\" /> <a href=\"\">c</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub url_sanitize: Option<UrlSanitize>,
+
+    /// Function to turn the targets of wiki links into URLs.
+    ///
+    /// This option does nothing if `wiki_link` is not turned on in
+    /// [`Constructs`][crate::Constructs].
+    ///
+    /// The default is `None`, which uses the target as it is as the URL.
+    /// Pass a function to link to where your pages live.
+    /// When the function returns `None`, the page does not exist: the link is
+    /// compiled without `href` and with a `missing` class.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions, WikiLinkResolve};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses targets as URLs by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[[a b|c]]",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 wiki_link: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"a%20b\" class=\"wikilink\">c</a></p>"
+    /// );
+    ///
+    /// // Pass `wiki_link_resolve` to map them to URLs, or to mark them as missing:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[[a b|c]] [[d]]",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 wiki_link: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               wiki_link_resolve: Some(WikiLinkResolve(std::rc::Rc::new(|target: &str| {
+    ///                   if target == "d" {
+    ///                       None
+    ///                   } else {
+    ///                       Some(format!("/wiki/{}", target.replace(' ', "_")))
+    ///                   }
+    ///               }))),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"/wiki/a_b\" class=\"wikilink\">c</a> <a class=\"wikilink missing\">d</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub wiki_link_resolve: Option<WikiLinkResolve>,
+
+    /// Function to turn wiki embeds into HTML.
+    ///
+    /// This option does nothing if `wiki_embed` is not turned on in
+    /// [`Constructs`][crate::Constructs].
+    ///
+    /// The default is `None`, which compiles embeds to placeholders:
+    /// `<span>`s with a `wikiembed` class, the target and fragment in
+    /// `data-target` and `data-fragment` attributes, and the alias (or
+    /// target) as text.
+    /// Pass a function to inline what embeds refer to instead.
+    /// When the function returns `None`, a placeholder is used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions, WikiEmbedResolve};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// let embed = || ParseOptions {
+    ///     constructs: Constructs {
+    ///         wiki_embed: true,
+    ///         ..Constructs::default()
+    ///     },
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // `markdown-rs` compiles embeds to placeholders by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![[a#b]]",
+    ///         &Options {
+    ///             parse: embed(),
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><span class=\"wikiembed\" data-target=\"a\" data-fragment=\"b\">a#b</span></p>"
+    /// );
+    ///
+    /// // Pass `wiki_embed_resolve` to inline what they refer to:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![[a#b]] ![[c]]",
+    ///         &Options {
+    ///             parse: embed(),
+    ///             compile: CompileOptions {
+    ///               wiki_embed_resolve: Some(WikiEmbedResolve(std::rc::Rc::new(
+    ///                   |target: &str, fragment: Option<&str>, _alias: Option<&str>| {
+    ///                       if target == "a" {
+    ///                           Some(format!("<q>{}</q>", fragment.unwrap_or("")))
+    ///                       } else {
+    ///                           None
+    ///                       }
+    ///                   }
+    ///               ))),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><q>b</q> <span class=\"wikiembed\" data-target=\"c\">c</span></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub wiki_embed_resolve: Option<WikiEmbedResolve>,
+}
+
+impl CompileOptions {
+    /// GFM.
+    ///
+    /// GFM stands for **GitHub flavored markdown**.
+    /// On the compilation side, GFM turns on the GFM tag filter.
+    /// The tagfilter is useless, but it’s included here for consistency, and
+    /// this method exists for parity to parse options.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>.
+    pub fn gfm() -> Self {
+        Self {
+            gfm_tagfilter: true,
+            ..Self::default()
+        }
+    }
+
+    /// Chat.
+    ///
+    /// On the compilation side, chat turns soft line breaks into hard breaks,
+    /// like how line endings work in chat apps such as Discord and Telegram.
+    pub fn chat() -> Self {
+        Self {
+            hard_breaks: true,
+            ..Self::default()
+        }
+    }
+
+    /// Slack.
+    ///
+    /// On the compilation side, Slack turns soft line breaks into hard
+    /// breaks, like how line endings work in Slack.
+    pub fn slack() -> Self {
+        Self::chat()
+    }
+}
+
+/// Configuration that describes how to parse from markdown.
+///
+/// You can use this:
+///
+/// *   To control what markdown constructs are turned on and off
+/// *   To control some of those constructs
+/// *   To add support for certain programming languages when parsing MDX
+///
+/// In most cases, you will want to use the default trait or `gfm` method.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ParseOptions;
+/// # fn main() {
+///
+/// // Use the default trait to parse markdown according to `CommonMark`:
+/// let commonmark = ParseOptions::default();
+///
+/// // Use the `gfm` method to parse markdown according to GFM:
+/// let gfm = ParseOptions::gfm();
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+    /// Which constructs to enable and disable.
+    ///
+    /// The default is to follow `CommonMark`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("    indented code?"),
+    ///     "<pre><code>indented code?\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `constructs` to choose what to enable and disable:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "    indented code?",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 code_indented: false,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>indented code?</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub constructs: Constructs,
+
+    /// Whether to support GFM strikethrough with a single tilde
+    ///
+    /// This option does nothing if `gfm_strikethrough` is not turned on in
+    /// `constructs`.
+    /// This option does not affect strikethrough with double tildes.
+    /// It also does nothing if `subscript` is turned on in `constructs`, as
+    /// single tildes then form subscript.
+    ///
+    /// The default is `true`, which follows how markdown on `github.com`
+    /// works, as strikethrough with single tildes is supported.
+    /// Pass `false`, to follow the GFM spec more strictly, by not allowing
+    /// strikethrough with single tildes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` supports single tildes by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_strikethrough_single_tilde: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>~a~</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_strikethrough_single_tilde: bool,
+
+    /// Whether to support GFM tables without head row.
+    ///
+    /// This option does nothing if `gfm_table` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how GFM works: tables start with a
+    /// head row, followed by a delimiter row.
+    /// Pass `true` to also allow tables that start with a delimiter row, as
+    /// several other markdown dialects do.
+    /// Such tables only have a body in HTML (`<tbody>`).
+    /// They cannot interrupt paragraphs.
+    /// In syntax trees, the first body row is the first row of the table.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows GFM by default:
+    /// assert_eq!(
+    ///     to_html_with_options("| - |\n| a |", &Options::gfm())?,
+    ///     "<p>| - |\n| a |</p>"
+    /// );
+    ///
+    /// // Pass `gfm_table_headless: true` to support tables without head row:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "| - |\n| a |",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_table_headless: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<table>\n<tbody>\n<tr>\n<td>a</td>\n</tr>\n</tbody>\n</table>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_table_headless: bool,
+
+    /// Whether to support GFM table rows continued by a backslash.
+    ///
+    /// This option does nothing if `gfm_table` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how GFM works: each line is a row.
+    /// Pass `true` to join a body row that ends in a backslash (`\`) with the
+    /// next row, as `MultiMarkdown` and pandoc do.
+    /// The content of their cells is joined with a line ending.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows GFM by default:
+    /// assert_eq!(
+    ///     to_html_with_options("| a |\n| - |\n| b \\\n| c |", &Options::gfm())?,
+    ///     "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b \\</td>\n</tr>\n<tr>\n<td>c</td>\n</tr>\n</tbody>\n</table>"
+    /// );
+    ///
+    /// // Pass `gfm_table_row_continuation: true` to continue rows:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "| a |\n| - |\n| b \\\n| c |",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_table_row_continuation: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b\nc</td>\n</tr>\n</tbody>\n</table>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_table_row_continuation: bool,
+
+    /// Whether to support GFM task list items in other states than checked
+    /// and unchecked.
+    ///
+    /// This option does nothing if `gfm_task_list_item` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how GFM works: only `[ ]` and `[x]`
+    /// form checks.
+    /// Pass `true` to also support `[-]` (cancelled), `[~]` (in progress),
+    /// and `[>]` (deferred), as several task managers and note apps do.
+    /// Such items are neither checked nor unchecked: they get a class for
+    /// their state instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows GFM by default:
+    /// assert_eq!(
+    ///     to_html_with_options("* [-] a", &Options::gfm())?,
+    ///     "<ul>\n<li>[-] a</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `gfm_task_list_item_states: true` to support other states:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* [-] a",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_task_list_item_states: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li class=\"task-list-item-cancelled\"><input type=\"checkbox\" disabled=\"\" /> a</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_task_list_item_states: bool,
+
+    /// Whether to support math (text) with a single dollar
+    ///
+    /// This option does nothing if `math_text` is not turned on in
+    /// `constructs`.
+    /// This option does not affect math (text) with two or more dollars.
+    ///
+    /// The default is `true`, which is more close to how code (text) and
+    /// Pandoc work, as it allows math with a single dollar to form.
+    /// However, single dollars can interfere with “normal” dollars in text.
+    /// Pass `false`, to only allow math (text) to form when two or more
+    /// dollars are used.
+    /// If you pass `false`, you can still use two or more dollars for text
+    /// math.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` supports single dollars by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    /// );
+    ///
+    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$a$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               math_text_single_dollar: false,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>$a$</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_text_single_dollar: bool,
+
+    /// Whether to support math the way GitLab does.
+    ///
+    /// The default is `false`.
+    ///
+    /// GitLab supports code (fenced) with a `math` info string as math (flow),
+    /// and code (text) in dollars (`` $`a`$ ``) as math (text).
+    /// Pass `true` to turn them into the same events as math, so that
+    /// they are compiled the same way.
+    /// Math (flow) from code (fenced) works even if the `math_flow`
+    /// construct is off, but math (text) from code (text) only works if the
+    /// `math_text` construct is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` treats a `math` info string as just a language:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```math\na\n```",
+    ///         &Options::default()
+    ///     )?,
+    ///     "<pre><code class=\"language-math\">a\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `math_gitlab: true` to turn it into math:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```math\na\n```\n\n$`b`$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_text: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               math_gitlab: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-math math-display\">a\n</code></pre>\n<p><code class=\"language-math math-inline\">b</code></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_gitlab: bool,
+
+    /// Max number of label starts (`[`, `![`, and `[^`) that are looking for
+    /// a label end at the same time.
+    ///
+    /// Label starts are kept around until they are matched by a `]`.
+    /// Every link that is found has to mark all open label starts before it,
+    /// so when a paragraph contains a huge number of unmatched brackets,
+    /// parsing becomes slow.
+    /// To keep parsing linear, when this number is reached, the earliest label
+    /// start is no longer tracked, and instead treated as literal text.
+    ///
+    /// The default is `1000`.
+    /// Pass a higher number if you really need deeply nested labels, or a
+    /// lower number if you need to parse untrusted input faster.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` tracks many label starts by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a ![b](c)](d)",
+    ///         &Options::default()
+    ///     )?,
+    ///     "<p><a href=\"d\">a <img src=\"c\" alt=\"b\" /></a></p>"
+    /// );
+    ///
+    /// // Pass `label_start_count_max` to track fewer:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a ![b](c)](d)",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               label_start_count_max: 1,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>[a <img src=\"c\" alt=\"b\" />](d)</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub label_start_count_max: usize,
+
+    /// Whether to support labels in autolinks (`<https://example.com|a>`).
+    ///
+    /// This option does nothing if `autolink` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how `CommonMark` works: the `|` and
+    /// what comes after it are part of the URL.
+    /// Pass `true` to use what comes after `|` as the text of the link
+    /// instead, which is how links work in Slack.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<https://example.com|a>",
+    ///         &Options::default()
+    ///     )?,
+    ///     "<p><a href=\"https://example.com%7Ca\">https://example.com|a</a></p>"
+    /// );
+    ///
+    /// // Pass `autolink_label: true` to support labels:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<https://example.com|a b>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               autolink_label: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\">a b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub autolink_label: bool,
+
+    /// Whether to relax the grammar of email autolinks
+    /// (`<user@intranet_host>`).
+    ///
+    /// This option does nothing if `autolink` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how `CommonMark` works: the labels
+    /// of domains can only contain ASCII alphanumerics and dashes, and are at
+    /// most 63 characters.
+    /// Pass `true` to also allow underscores in labels (though not at their
+    /// start or end), and labels of any size, which is useful for intranet
+    /// hostnames.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<user@build_server.corp>",
+    ///         &Options::default()
+    ///     )?,
+    ///     "<p>&lt;user@build_server.corp&gt;</p>"
+    /// );
+    ///
+    /// // Pass `autolink_relaxed: true` to allow more domains:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<user@build_server.corp>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               autolink_relaxed: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"mailto:user@build_server.corp\">user@build_server.corp</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub autolink_relaxed: bool,
+
+    /// Whether to form strong with a single asterisk (`*a*`).
+    ///
+    /// This option does nothing if `attention` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how `CommonMark` works: one asterisk
+    /// forms emphasis.
+    /// Pass `true` to form strong instead, which is how bold works in Slack.
+    /// Emphasis can then still be formed with underscores.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("*a* _b_", &Options::default())?,
+    ///     "<p><em>a</em> <em>b</em></p>"
+    /// );
+    ///
+    /// // Pass `strong_single_asterisk: true` to form strong:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "*a* _b_",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               strong_single_asterisk: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><strong>a</strong> <em>b</em></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strong_single_asterisk: bool,
+
+    /// Whether to support markdown in HTML (flow) with a `markdown="1"`
+    /// attribute.
+    ///
+    /// This option does nothing if `html_flow` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `false`, which is how `CommonMark` works: HTML (flow)
+    /// continues until a blank line.
+    /// Pass `true` to end HTML (flow) after an opening tag of a block element
+    /// (such as `<div>`) that is alone on its line and has a `markdown="1"`
+    /// attribute, so that what follows is markdown, which is how PHP Markdown
+    /// Extra works.
+    /// The attribute itself is dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div markdown=\"1\">\n*a*\n</div>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div markdown=\"1\">\n*a*\n</div>"
+    /// );
+    ///
+    /// // Pass `html_flow_markdown: true` to support markdown in HTML:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<div markdown=\"1\">\n*a*\n</div>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               html_flow_markdown: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<div>\n<p><em>a</em></p>\n</div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub html_flow_markdown: bool,
+
+    /// Markers of frontmatter fences.
+    ///
+    /// The default is `vec![b'+', b'-']`, which supports TOML frontmatter
+    /// (`+++`) and YAML frontmatter (`---`).
+    /// Frontmatter fences are three of one of these markers.
+    /// Pass other ASCII punctuation to support other fences, such as `;;;`,
+    /// or leave markers out to not support their fences.
+    ///
+    /// This option does nothing if `frontmatter` is not turned on in
+    /// [`constructs`][ParseOptions::constructs].
+    /// In the syntax tree, frontmatter with `+++` fences is [`Toml`][crate::mdast::Toml],
+    /// and frontmatter with other fences is [`Yaml`][crate::mdast::Yaml].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` supports `+++` and `---` by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ";;;\na: b\n;;;\n# c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>;;;\na: b\n;;;</p>\n<h1>c</h1>"
+    /// );
+    ///
+    /// // Pass `frontmatter_markers` to support other fences:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ";;;\na: b\n;;;\n# c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               frontmatter_markers: vec![b'+', b'-', b';'],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>c</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub frontmatter_markers: Vec<u8>,
+
+    /// Unit to count the columns of points in syntax trees in.
+    ///
+    /// The default is [`ColumnUnit::Byte`][], which counts bytes, where tabs
+    /// count as the spaces they expand to.
+    /// Pass [`ColumnUnit::Utf16`][] to count UTF-16 code units, which is what
+    /// the language server protocol and JavaScript use, or
+    /// [`ColumnUnit::Char`][] to count Unicode scalar values.
+    /// Offsets are always counted in bytes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, ColumnUnit, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` counts bytes by default:
+    /// let tree = to_mdast("é *a*", &ParseOptions::default())?;
+    /// let emphasis = &tree.children().unwrap()[0].children().unwrap()[1];
+    /// assert_eq!(emphasis.position().unwrap().start.column, 4);
+    ///
+    /// // Pass `column_unit: ColumnUnit::Utf16` to count UTF-16 code units:
+    /// let tree = to_mdast(
+    ///     "é *a*",
+    ///     &ParseOptions {
+    ///       column_unit: ColumnUnit::Utf16,
+    ///       ..ParseOptions::default()
+    ///     }
+    /// )?;
+    /// let emphasis = &tree.children().unwrap()[0].children().unwrap()[1];
+    /// assert_eq!(emphasis.position().unwrap().start.column, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub column_unit: ColumnUnit,
+
+    /// Custom inline constructs.
+    ///
+    /// The default is `vec![]`, which does not support custom constructs.
+    /// Pass pairs of delimiters, with a name, to support constructs that are
+    /// not built in, without forking this project.
+    /// Their delimiters take precedence over the constructs that are built
+    /// in.
+    /// See [`CustomInline`][] for more info.
+    ///
+    /// In HTML, they are `<span>`s with their name as the class.
+    /// In the syntax tree, they are
+    /// [`CustomInline`][crate::mdast::CustomInline]s.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CustomInline, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not support custom constructs by default:
+    /// assert_eq!(
+    ///     to_html_with_options("a %%*b*%%", &Options::default())?,
+    ///     "<p>a %%<em>b</em>%%</p>"
+    /// );
+    ///
+    /// // Pass `custom_inlines` to support them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a %%*b*%%",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               custom_inlines: vec![CustomInline {
+    ///                 name: "c".into(),
+    ///                 open: "%%".into(),
+    ///                 close: "%%".into(),
+    ///               }],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a <span class=\"c\"><em>b</em></span></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub custom_inlines: Vec<CustomInline>,
+
+    /// Custom block constructs.
+    ///
+    /// The default is `vec![]`, which does not support custom constructs.
+    /// Pass markers, with a name and a kind, to support containers that are
+    /// not built in, without forking this project.
+    /// Fenced ones work like fenced block quotes (`>>>`), and prefixed ones
+    /// like block quotes (`>`).
+    /// Their markers take precedence over the containers that are built in.
+    /// See [`CustomBlock`][] for more info.
+    ///
+    /// In HTML, they are `<div>`s with their name as the class.
+    /// In the syntax tree, they are
+    /// [`CustomBlock`][crate::mdast::CustomBlock]s.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CustomBlock, CustomBlockKind, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not support custom constructs by default:
+    /// assert_eq!(
+    ///     to_html_with_options("!!! note\na\n!!!", &Options::default())?,
+    ///     "<p>!!! note\na\n!!!</p>"
+    /// );
+    ///
+    /// // Pass `custom_blocks` to support them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "!!! note\na\n!!!",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               custom_blocks: vec![CustomBlock {
+    ///                 name: "admonition".into(),
+    ///                 kind: CustomBlockKind::Fence,
+    ///                 marker: "!!!".into(),
+    ///               }],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<div class=\"admonition\" data-info=\"note\">\n<p>a</p>\n</div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub custom_blocks: Vec<CustomBlock>,
+
+    /// Function to parse expressions with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within expressions.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
+
+    /// Function to parse ESM with.
+    ///
+    /// This function can be used to add support for arbitrary programming
+    /// languages within ESM blocks, however, the keywords (`export`,
+    /// `import`) are currently hardcoded JavaScript-specific.
+    ///
+    /// > 👉 **Note**: please raise an issue if you’re interested in working on
+    /// > MDX that is aware of, say, Rust, or other programming languages.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// For an example that adds support for JavaScript with SWC, see
+    /// `tests/test_utils/mod.rs`.
+    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+}
+
+impl fmt::Debug for ParseOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("constructs", &self.constructs)
+            .field(
+                "gfm_strikethrough_single_tilde",
+                &self.gfm_strikethrough_single_tilde,
+            )
+            .field("gfm_table_headless", &self.gfm_table_headless)
+            .field(
+                "gfm_table_row_continuation",
+                &self.gfm_table_row_continuation,
+            )
+            .field("gfm_task_list_item_states", &self.gfm_task_list_item_states)
+            .field("math_text_single_dollar", &self.math_text_single_dollar)
+            .field("math_gitlab", &self.math_gitlab)
+            .field("label_start_count_max", &self.label_start_count_max)
+            .field("autolink_label", &self.autolink_label)
+            .field("autolink_relaxed", &self.autolink_relaxed)
+            .field("strong_single_asterisk", &self.strong_single_asterisk)
+            .field("html_flow_markdown", &self.html_flow_markdown)
+            .field("frontmatter_markers", &self.frontmatter_markers)
+            .field("column_unit", &self.column_unit)
+            .field("custom_inlines", &self.custom_inlines)
+            .field("custom_blocks", &self.custom_blocks)
+            .field(
+                "mdx_expression_parse",
+                &self.mdx_expression_parse.as_ref().map(|_d| "[Function]"),
+            )
+            .field(
+                "mdx_esm_parse",
+                &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
+            )
+            .finish()
+    }
+}
+
+impl Default for ParseOptions {
+    /// `CommonMark` defaults.
+    fn default() -> Self {
+        Self {
+            constructs: Constructs::default(),
+            gfm_strikethrough_single_tilde: true,
+            gfm_table_headless: false,
+            gfm_table_row_continuation: false,
+            gfm_task_list_item_states: false,
+            math_text_single_dollar: true,
+            math_gitlab: false,
+            label_start_count_max: LABEL_START_COUNT_MAX,
+            autolink_label: false,
+            autolink_relaxed: false,
+            strong_single_asterisk: false,
+            html_flow_markdown: false,
+            frontmatter_markers: vec![b'+', b'-'],
+            column_unit: ColumnUnit::default(),
+            custom_inlines: vec![],
+            custom_blocks: vec![],
+            mdx_expression_parse: None,
+            mdx_esm_parse: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// GFM.
+    ///
+    /// GFM stands for GitHub flavored markdown.
+    /// GFM extends `CommonMark` and adds support for autolink literals,
+    /// footnotes, strikethrough, tables, and tasklists.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>
+    pub fn gfm() -> Self {
+        Self {
+            constructs: Constructs::gfm(),
+            ..Self::default()
+        }
+    }
+
+    /// MDX.
+    ///
+    /// This turns on `CommonMark`, turns off some conflicting constructs
+    /// (autolinks, code (indented), and HTML), and turns on MDX (ESM,
+    /// expressions, and JSX).
+    ///
+    /// For more information, see the MDX website:
+    /// <https://mdxjs.com>.
+    ///
+    /// > 👉 **Note**: to support ESM, you *must* pass
+    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] too.
+    /// > Otherwise, ESM is treated as normal markdown.
+    /// >
+    /// > You *can* pass
+    /// > [`mdx_expression_parse`][MdxExpressionParse]
+    /// > to parse expressions according to a certain grammar (typically, a
+    /// > programming language).
+    /// > Otherwise, expressions are parsed with a basic algorithm that only
+    /// > cares about braces.
+    pub fn mdx() -> Self {
+        Self {
+            constructs: Constructs::mdx(),
+            ..Self::default()
+        }
+    }
+
+    /// Chat.
+    ///
+    /// This turns off headings and HTML, and turns on strikethrough (with two
+    /// tildes), spoilers with pipes, and underline, to match how markdown
+    /// works in chat apps such as Discord and Telegram.
+    pub fn chat() -> Self {
+        Self {
+            constructs: Constructs::chat(),
+            gfm_strikethrough_single_tilde: false,
+            ..Self::default()
+        }
+    }
+
+    /// Slack.
+    ///
+    /// This turns off headings, HTML, and images, and turns on strikethrough,
+    /// labels in autolinks (`<https://example.com|a>`), and strong with a
+    /// single asterisk (`*a*`), to match how markdown (mrkdwn) works in
+    /// Slack.
+    pub fn slack() -> Self {
+        Self {
+            constructs: Constructs::slack(),
+            autolink_label: true,
+            strong_single_asterisk: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// Configuration that describes how to parse from markdown and compile to
+/// HTML.
+///
+/// In most cases, you will want to use the default trait or `gfm` method.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::Options;
+/// # fn main() {
+///
+/// // Use the default trait to compile markdown to HTML according to `CommonMark`:
+/// let commonmark = Options::default();
+///
+/// // Use the `gfm` method to compile markdown to HTML according to GFM:
+/// let gfm = Options::gfm();
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Configuration that describes how to parse from markdown.
+    pub parse: ParseOptions,
+    /// Configuration that describes how to compile to HTML.
+    pub compile: CompileOptions,
+}
+
+impl Options {
+    /// GFM.
+    ///
+    /// GFM stands for GitHub flavored markdown.
+    /// GFM extends `CommonMark` and adds support for autolink literals,
+    /// footnotes, strikethrough, tables, and tasklists.
+    /// On the compilation side, GFM turns on the GFM tag filter.
+    /// The tagfilter is useless, but it’s included here for consistency.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>
+    pub fn gfm() -> Self {
+        Self {
+            parse: ParseOptions::gfm(),
+            compile: CompileOptions::gfm(),
+        }
+    }
+
+    /// Chat.
+    ///
+    /// This matches how markdown works in chat apps such as Discord and
+    /// Telegram, so that bots and bridges can render messages faithfully.
+    /// Headings and HTML are turned off, and strikethrough (with two tildes),
+    /// spoilers with pipes (`||a||`), and underline (`__a__`) are turned on.
+    /// On the compilation side, soft line breaks are turned into hard breaks.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options("# a __b__ ||c||\n~~d~~", &Options::chat())?,
+    ///     "<p># a <u>b</u> <span class=\"spoiler\">c</span><br />\n<del>d</del></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chat() -> Self {
+        Self {
+            parse: ParseOptions::chat(),
+            compile: CompileOptions::chat(),
+        }
+    }
+
+    /// Slack.
+    ///
+    /// This matches how markdown (mrkdwn) works in Slack.
+    /// Headings, HTML, and images are turned off, and strikethrough, labels
+    /// in autolinks (`<https://example.com|a>`), and strong with a single
+    /// asterisk (`*a*`) are turned on.
+    /// On the compilation side, soft line breaks are turned into hard breaks.
+    ///
+    /// To turn markdown into mrkdwn instead, see
+    /// [`to_mrkdwn()`][crate::to_mrkdwn].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options("*a* _b_ ~c~ <https://example.com|d>", &Options::slack())?,
+    ///     "<p><strong>a</strong> <em>b</em> <del>c</del> <a href=\"https://example.com\">d</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slack() -> Self {
+        Self {
+            parse: ParseOptions::slack(),
+            compile: CompileOptions::slack(),
+        }
+    }
+}
+
+/// Configuration that describes how to format markdown.
+///
+/// The defaults are the canonical style of [`format()`][crate::format]:
+/// `*` for bullets, emphasis, and strong, grave accents for fences, ATX
+/// headings, no wrapping, and counting ordered lists up.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{FormatOptions, ListNumbering};
+/// # fn main() {
+///
+/// // Use the default trait to get the canonical style:
+/// let canonical = FormatOptions::default();
+///
+/// // Use a house style:
+/// let house = FormatOptions {
+///     bullet: '-',
+///     emphasis: '_',
+///     list_numbering: ListNumbering::Same,
+///     line_width: Some(80),
+///     ..FormatOptions::default()
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FormatOptions {
+    /// Marker to use for bullets.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'-'` or `'+'` to use those instead.
+    ///
+    /// Adjacent lists, which would otherwise be one list, use `'-'` (or
+    /// `'*'`, if `'-'` is used), as do lists with items that start with a
+    /// thematic break.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses asterisks by default:
+    /// assert_eq!(format("- a", &ParseOptions::default())?, "* a\n");
+    ///
+    /// // Pass `bullet` to use another marker:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "- a",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             bullet: '-',
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "- a\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub bullet: char,
+
+    /// Marker to use for emphasis.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'_'` to use underscores instead.
+    ///
+    /// Underscores do not work in words, so emphasis there uses asterisks.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses asterisks by default:
+    /// assert_eq!(format("_a_", &ParseOptions::default())?, "*a*\n");
+    ///
+    /// // Pass `emphasis` to use underscores:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "*a*",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             emphasis: '_',
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "_a_\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub emphasis: char,
+
+    /// Marker to use for strong.
+    ///
+    /// The default is `'*'`.
+    /// Pass `'_'` to use underscores instead.
+    ///
+    /// Underscores do not work in words, so strong there uses asterisks.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses asterisks by default:
+    /// assert_eq!(format("__a__", &ParseOptions::default())?, "**a**\n");
+    ///
+    /// // Pass `strong` to use underscores:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "**a**",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             strong: '_',
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "__a__\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strong: char,
+
+    /// Marker to use for fenced code.
+    ///
+    /// The default is `` '`' ``.
+    /// Pass `'~'` to use tildes instead.
+    ///
+    /// Code with an info string that contains grave accents uses tildes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses grave accents by default:
+    /// assert_eq!(format("~~~\na\n~~~", &ParseOptions::default())?, "```\na\n```\n");
+    ///
+    /// // Pass `fence` to use tildes:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "```\na\n```",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             fence: '~',
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "~~~\na\n~~~\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fence: char,
+
+    /// Size of fences of fenced code.
+    ///
+    /// The default is `3`.
+    /// Pass a bigger number for longer fences.
+    ///
+    /// Fences are longer if the code contains a sequence of markers of that
+    /// size or longer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses three markers by default:
+    /// assert_eq!(format("`````\na\n`````", &ParseOptions::default())?, "```\na\n```\n");
+    ///
+    /// // Pass `fence_length` to use more:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "```\na\n```",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             fence_length: 4,
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "````\na\n````\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fence_length: usize,
+
+    /// Whether to use setext headings.
+    ///
+    /// The default is `false`, which uses ATX headings (`# a`), unless a
+    /// heading contains line endings.
+    ///
+    /// Pass `true` to use setext headings (`a` followed by `===`) for
+    /// headings of rank 1 and 2.
+    /// Other headings cannot be setext, so they are ATX.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses ATX headings by default:
+    /// assert_eq!(format("a\n=", &ParseOptions::default())?, "# a\n");
+    ///
+    /// // Pass `setext` to use setext headings:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "# abcd",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             setext: true,
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "abcd\n====\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub setext: bool,
+
+    /// Width to wrap paragraphs at.
+    ///
+    /// The default is `None`, which keeps line endings in paragraphs as they
+    /// are.
+    ///
+    /// Pass a width to reflow paragraphs: line endings are removed, and
+    /// lines are broken between words so that they are at most that many
+    /// characters wide, if they can be.
+    /// The indent of containers, such as list items, is not counted.
+    /// Lines are not broken where the next word would be markdown at the
+    /// start of a line, such as `-` or `1.`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not wrap by default:
+    /// assert_eq!(format("aa\nbb cc", &ParseOptions::default())?, "aa\nbb cc\n");
+    ///
+    /// // Pass `line_width` to wrap:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "aa\nbb cc",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             line_width: Some(5),
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "aa bb\ncc\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub line_width: Option<usize>,
+
+    /// How to number the items of ordered lists.
+    ///
+    /// The default is [`ListNumbering::Increment`][], which counts up from
+    /// the start of the list.
+    ///
+    /// Pass [`ListNumbering::Same`][] to use the start of the list for all
+    /// items.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{format, format_with_options, FormatOptions, ListNumbering, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` counts up by default:
+    /// assert_eq!(format("1. a\n1. b", &ParseOptions::default())?, "1. a\n2. b\n");
+    ///
+    /// // Pass `list_numbering` to number items differently:
+    /// assert_eq!(
+    ///     format_with_options(
+    ///         "1. a\n2. b",
+    ///         &ParseOptions::default(),
+    ///         &FormatOptions {
+    ///             list_numbering: ListNumbering::Same,
+    ///             ..FormatOptions::default()
+    ///         }
+    ///     )?,
+    ///     "1. a\n1. b\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub list_numbering: ListNumbering,
+}
+
+impl Default for FormatOptions {
+    /// Canonical style.
+    fn default() -> Self {
+        Self {
+            bullet: '*',
+            emphasis: '*',
+            strong: '*',
+            fence: '`',
+            fence_length: 3,
+            setext: false,
+            line_width: None,
+            list_numbering: ListNumbering::default(),
+        }
+    }
+}
+
+/// Configuration that describes how to lint markdown.
+///
+/// By default, all rules are turned on, and lines can be 80 characters.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{LintOptions, ParseOptions};
+/// # fn main() {
+///
+/// // Use the default trait to check everything in `CommonMark`:
+/// let commonmark = LintOptions::default();
+///
+/// // Check GFM, allowing long lines:
+/// let gfm = LintOptions {
+///     parse: ParseOptions::gfm(),
+///     line_length: None,
+///     ..LintOptions::default()
+/// };
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct LintOptions {
+    /// Configuration that describes how to parse from markdown.
+    pub parse: ParseOptions,
+    /// Whether to check for whitespace at the end of lines
+    /// (`no-trailing-spaces`).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to allow trailing whitespace.
+    ///
+    /// Two spaces that form a hard break are fine, and code is not checked.
+    /// The fix removes the whitespace.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{lint, LintOptions};
+    ///
+    /// // `markdown-rs` checks trailing whitespace by default:
+    /// assert_eq!(
+    ///     lint("a \nb  \nc", &LintOptions::default())[0].reason,
+    ///     "Unexpected trailing whitespace"
+    /// );
+    ///
+    /// // Pass `trailing_spaces: false` to allow it:
+    /// assert_eq!(
+    ///     lint(
+    ///         "a \nb  \nc",
+    ///         &LintOptions {
+    ///             trailing_spaces: false,
+    ///             ..LintOptions::default()
+    ///         }
+    ///     ),
+    ///     vec![]
+    /// );
+    /// ```
+    pub trailing_spaces: bool,
+    /// Whether to check for tabs (`no-hard-tabs`).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to allow tabs.
+    ///
+    /// The fix replaces tabs with spaces up to the next tab stop.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{lint, LintOptions};
+    ///
+    /// // `markdown-rs` checks tabs by default:
+    /// assert_eq!(
+    ///     lint("a\tb", &LintOptions::default())[0].reason,
+    ///     "Unexpected hard tab, expected spaces"
+    /// );
+    ///
+    /// // Pass `hard_tabs: false` to allow them:
+    /// assert_eq!(
+    ///     lint(
+    ///         "a\tb",
+    ///         &LintOptions {
+    ///             hard_tabs: false,
+    ///             ..LintOptions::default()
+    ///         }
+    ///     ),
+    ///     vec![]
+    /// );
+    /// ```
+    pub hard_tabs: bool,
+    /// Maximum number of characters in a line (`line-length`).
+    ///
+    /// The default is `Some(80)`.
+    /// Pass another size to allow shorter or longer lines, or `None` to
+    /// allow lines of any size.
+    ///
+    /// Code and tables are not checked, and neither are lines that cannot be
+    /// wrapped because there is no whitespace after the maximum, such as
+    /// those that end in a long URL.
+    /// There is no fix.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{lint, LintOptions};
+    ///
+    /// // `markdown-rs` allows 80 characters by default:
+    /// assert_eq!(lint(&["a"; 40].join(" "), &LintOptions::default()), vec![]);
+    ///
+    /// // Pass `line_length` to allow fewer:
+    /// assert_eq!(
+    ///     lint(
+    ///         "aaa bbb ccc",
+    ///         &LintOptions {
+    ///             line_length: Some(5),
+    ///             ..LintOptions::default()
+    ///         }
+    ///     )[0]
+    ///     .reason,
+    ///     "Unexpected line of 11 characters, expected at most 5"
+    /// );
+    /// ```
+    pub line_length: Option<usize>,
+    /// Whether to check for URLs in text that are not in autolinks
+    /// (`no-bare-urls`).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to allow bare URLs.
+    ///
+    /// URLs with `http:` and `https:`, and, with GFM autolink literals,
+    /// emails, are checked.
+    /// The fix turns them into autolinks.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{lint, LintOptions};
+    ///
+    /// // `markdown-rs` checks bare URLs by default:
+    /// assert_eq!(
+    ///     lint("a https://b.c d", &LintOptions::default())[0].reason,
+    ///     "Unexpected bare URL `https://b.c`, expected an autolink"
+    /// );
+    ///
+    /// // Pass `bare_urls: false` to allow them:
+    /// assert_eq!(
+    ///     lint(
+    ///         "a https://b.c d",
+    ///         &LintOptions {
+    ///             bare_urls: false,
+    ///             ..LintOptions::default()
+    ///         }
+    ///     ),
+    ///     vec![]
+    /// );
+    /// ```
+    pub bare_urls: bool,
+    /// Whether to check that markers are consistent (`ul-style`,
+    /// `emphasis-style`, `strong-style`, `code-fence-style`).
+    ///
+    /// The default is `true`.
+    /// Pass `false` to allow different markers.
+    ///
+    /// The first bullet, emphasis marker, strong marker, and code fence
+    /// marker in the document are the expected ones.
+    /// The fix changes other markers to the expected one, except where that
+    /// would not work, such as underscores in words.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
-    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// use markdown::{lint, LintOptions};
     ///
-    /// // `markdown-rs` supports single tildes by default:
+    /// // `markdown-rs` checks markers by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "~a~",
-    ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               ..ParseOptions::default()
-    ///             },
-    ///             ..Options::default()
-    ///         }
-    ///     )?,
-    ///     "<p><del>a</del></p>"
+    ///     lint("*a* _b_", &LintOptions::default())[0].reason,
+    ///     "Unexpected emphasis marker `_`, expected `*`"
     /// );
     ///
-    /// // Pass `gfm_strikethrough_single_tilde: false` to turn that off:
+    /// // Pass `consistent_markers: false` to allow different ones:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "~a~",
-    ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs::gfm(),
-    ///               gfm_strikethrough_single_tilde: false,
-    ///               ..ParseOptions::default()
-    ///             },
-    ///             ..Options::default()
+    ///     lint(
+    ///         "*a* _b_",
+    ///         &LintOptions {
+    ///             consistent_markers: false,
+    ///             ..LintOptions::default()
     ///         }
-    ///     )?,
-    ///     "<p>~a~</p>"
+    ///     ),
+    ///     vec![]
     /// );
-    /// # Ok(())
-    /// # }
     /// ```
-    pub gfm_strikethrough_single_tilde: bool,
-
-    /// Whether to support math (text) with a single dollar
+    pub consistent_markers: bool,
+    /// Whether to check for references to definitions that do not exist
+    /// (`reference-links-images`).
     ///
-    /// This option does nothing if `math_text` is not turned on in
-    /// `constructs`.
-    /// This option does not affect math (text) with two or more dollars.
+    /// The default is `true`.
+    /// Pass `false` to allow them.
     ///
-    /// The default is `true`, which is more close to how code (text) and
-    /// Pandoc work, as it allows math with a single dollar to form.
-    /// However, single dollars can interfere with “normal” dollars in text.
-    /// Pass `false`, to only allow math (text) to form when two or more
-    /// dollars are used.
-    /// If you pass `false`, you can still use two or more dollars for text
-    /// math.
+    /// Such references are not links, but text with brackets.
+    /// Full (`[a][b]`) and collapsed (`[a][]`) references are checked, and,
+    /// with GFM footnotes, footnote calls (`[^a]`).
+    /// Shortcut references (`[a]`) are not checked, as brackets are often
+    /// just brackets.
+    /// There is no fix.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
-    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// use markdown::{lint, LintOptions};
     ///
-    /// // `markdown-rs` supports single dollars by default:
+    /// // `markdown-rs` checks references by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "$a$",
-    ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 math_text: true,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               ..ParseOptions::default()
-    ///             },
-    ///             ..Options::default()
-    ///         }
-    ///     )?,
-    ///     "<p><code class=\"language-math math-inline\">a</code></p>"
+    ///     lint("[a][b]", &LintOptions::default())[0].reason,
+    ///     "Unexpected reference to undefined definition `b`"
     /// );
     ///
-    /// // Pass `math_text_single_dollar: false` to turn that off:
+    /// // Pass `undefined_references: false` to allow them:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "$a$",
-    ///         &Options {
-    ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 math_text: true,
-    ///                 ..Constructs::default()
-    ///               },
-    ///               math_text_single_dollar: false,
-    ///               ..ParseOptions::default()
-    ///             },
-    ///             ..Options::default()
+    ///     lint(
+    ///         "[a][b]",
+    ///         &LintOptions {
+    ///             undefined_references: false,
+    ///             ..LintOptions::default()
     ///         }
-    ///     )?,
-    ///     "<p>$a$</p>"
+    ///     ),
+    ///     vec![]
     /// );
-    /// # Ok(())
-    /// # }
     /// ```
-    pub math_text_single_dollar: bool,
-
-    /// Function to parse expressions with.
-    ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within expressions.
-    ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
+    pub undefined_references: bool,
+    /// Whether to check for definitions that are not used
+    /// (`link-image-reference-definitions`).
     ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
-
-    /// Function to parse ESM with.
+    /// The default is `true`.
+    /// Pass `false` to allow them.
     ///
-    /// This function can be used to add support for arbitrary programming
-    /// languages within ESM blocks, however, the keywords (`export`,
-    /// `import`) are currently hardcoded JavaScript-specific.
+    /// Definitions and footnote definitions are checked.
+    /// Only the first definition of an identifier can be used, so others are
+    /// reported as duplicates.
+    /// Definitions labeled `//` are not checked, as they are used for
+    /// comments (`[//]: # (a)`).
+    /// The fix removes the definition, if it is on its own lines.
     ///
-    /// > 👉 **Note**: please raise an issue if you’re interested in working on
-    /// > MDX that is aware of, say, Rust, or other programming languages.
+    /// ## Examples
     ///
-    /// It only makes sense to pass this when compiling to a syntax tree
-    /// with [`to_mdast()`][crate::to_mdast()].
+    /// ```
+    /// use markdown::{lint, LintOptions};
     ///
-    /// For an example that adds support for JavaScript with SWC, see
-    /// `tests/test_utils/mod.rs`.
-    pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
-    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
-}
-
-impl fmt::Debug for ParseOptions {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ParseOptions")
-            .field("constructs", &self.constructs)
-            .field(
-                "gfm_strikethrough_single_tilde",
-                &self.gfm_strikethrough_single_tilde,
-            )
-            .field("math_text_single_dollar", &self.math_text_single_dollar)
-            .field(
-                "mdx_expression_parse",
-                &self.mdx_expression_parse.as_ref().map(|_d| "[Function]"),
-            )
-            .field(
-                "mdx_esm_parse",
-                &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
-            )
-            .finish()
-    }
-}
-
-impl Default for ParseOptions {
-    /// `CommonMark` defaults.
-    fn default() -> Self {
-        Self {
-            constructs: Constructs::default(),
-            gfm_strikethrough_single_tilde: true,
-            math_text_single_dollar: true,
-            mdx_expression_parse: None,
-            mdx_esm_parse: None,
-        }
-    }
-}
-
-impl ParseOptions {
-    /// GFM.
+    /// // `markdown-rs` checks definitions by default:
+    /// assert_eq!(
+    ///     lint("[a]: b", &LintOptions::default())[0].reason,
+    ///     "Unexpected unused definition `a`"
+    /// );
     ///
-    /// GFM stands for GitHub flavored markdown.
-    /// GFM extends `CommonMark` and adds support for autolink literals,
-    /// footnotes, strikethrough, tables, and tasklists.
+    /// // Pass `unused_definitions: false` to allow them:
+    /// assert_eq!(
+    ///     lint(
+    ///         "[a]: b",
+    ///         &LintOptions {
+    ///             unused_definitions: false,
+    ///             ..LintOptions::default()
+    ///         }
+    ///     ),
+    ///     vec![]
+    /// );
+    /// ```
+    pub unused_definitions: bool,
+    /// Bibliography to check citation keys against (`citation-keys`).
     ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>
-    pub fn gfm() -> Self {
-        Self {
-            constructs: Constructs::gfm(),
-            ..Self::default()
-        }
-    }
-
-    /// MDX.
+    /// The default is `None`, which does not check citations.
+    /// Pass a [`Bibliography`][] to report citations of keys that are not
+    /// in it.
+    /// Citations are only found if the `citation` construct is turned on in
+    /// [`parse`][LintOptions::parse].
     ///
-    /// This turns on `CommonMark`, turns off some conflicting constructs
-    /// (autolinks, code (indented), and HTML), and turns on MDX (ESM,
-    /// expressions, and JSX).
+    /// ## Examples
     ///
-    /// For more information, see the MDX website:
-    /// <https://mdxjs.com>.
+    /// ```
+    /// use markdown::{lint, Bibliography, Constructs, LintOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// > 👉 **Note**: to support ESM, you *must* pass
-    /// > [`mdx_esm_parse`][MdxEsmParse] in [`ParseOptions`][] too.
-    /// > Otherwise, ESM is treated as normal markdown.
-    /// >
-    /// > You *can* pass
-    /// > [`mdx_expression_parse`][MdxExpressionParse]
-    /// > to parse expressions according to a certain grammar (typically, a
-    /// > programming language).
-    /// > Otherwise, expressions are parsed with a basic algorithm that only
-    /// > cares about braces.
-    pub fn mdx() -> Self {
-        Self {
-            constructs: Constructs::mdx(),
-            ..Self::default()
-        }
-    }
-}
-
-/// Configuration that describes how to parse from markdown and compile to
-/// HTML.
-///
-/// In most cases, you will want to use the default trait or `gfm` method.
-///
-/// ## Examples
-///
-/// ```
-/// use markdown::Options;
-/// # fn main() {
-///
-/// // Use the default trait to compile markdown to HTML according to `CommonMark`:
-/// let commonmark = Options::default();
-///
-/// // Use the `gfm` method to compile markdown to HTML according to GFM:
-/// let gfm = Options::gfm();
-/// # }
-/// ```
-#[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Default)]
-pub struct Options {
-    /// Configuration that describes how to parse from markdown.
-    pub parse: ParseOptions,
-    /// Configuration that describes how to compile to HTML.
-    pub compile: CompileOptions,
+    /// assert_eq!(
+    ///     lint(
+    ///         "[@doe99; @roe01]\n",
+    ///         &LintOptions {
+    ///             parse: ParseOptions {
+    ///                 constructs: Constructs {
+    ///                     citation: true,
+    ///                     ..Constructs::default()
+    ///                 },
+    ///                 ..ParseOptions::default()
+    ///             },
+    ///             bibliography: Some(Bibliography::from_csl_json(r#"[{"id": "doe99"}]"#)?),
+    ///             ..LintOptions::default()
+    ///         }
+    ///     )[0].reason,
+    ///     "Unexpected citation of `roe01`, which is not in the bibliography"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub bibliography: Option<Bibliography>,
 }
 
-impl Options {
-    /// GFM.
-    ///
-    /// GFM stands for GitHub flavored markdown.
-    /// GFM extends `CommonMark` and adds support for autolink literals,
-    /// footnotes, strikethrough, tables, and tasklists.
-    /// On the compilation side, GFM turns on the GFM tag filter.
-    /// The tagfilter is useless, but it’s included here for consistency.
-    ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>
-    pub fn gfm() -> Self {
+impl Default for LintOptions {
+    /// All rules, lines of at most 80 characters, and `CommonMark`.
+    fn default() -> Self {
         Self {
-            parse: ParseOptions::gfm(),
-            compile: CompileOptions::gfm(),
+            parse: ParseOptions::default(),
+            trailing_spaces: true,
+            hard_tabs: true,
+            line_length: Some(80),
+            bare_urls: true,
+            consistent_markers: true,
+            undefined_references: true,
+            unused_definitions: true,
+            bibliography: None,
         }
     }
 }
@@ -1310,6 +4660,30 @@ mod tests {
         );
         assert!(!constructs.frontmatter, "should support `gfm` shortcut (4)");
 
+        let constructs = Constructs::chat();
+        assert!(constructs.attention, "should support `chat` shortcut (1)");
+        assert!(
+            !constructs.heading_atx,
+            "should support `chat` shortcut (2)"
+        );
+        assert!(constructs.underline, "should support `chat` shortcut (3)");
+        assert!(!constructs.gfm_table, "should support `chat` shortcut (4)");
+
+        let constructs = Constructs::slack();
+        assert!(constructs.attention, "should support `slack` shortcut (1)");
+        assert!(
+            !constructs.heading_atx,
+            "should support `slack` shortcut (2)"
+        );
+        assert!(
+            constructs.gfm_strikethrough,
+            "should support `slack` shortcut (3)"
+        );
+        assert!(
+            !constructs.label_start_image,
+            "should support `slack` shortcut (4)"
+        );
+
         let constructs = Constructs::mdx();
         assert!(constructs.attention, "should support `gfm` shortcut (1)");
         assert!(
@@ -1368,9 +4742,23 @@ mod tests {
             "should support `mdx` shortcut (3)"
         );
 
+        let options = ParseOptions::slack();
+        assert!(
+            options.constructs.gfm_strikethrough,
+            "should support `slack` shortcut (1)"
+        );
+        assert!(
+            options.autolink_label,
+            "should support `slack` shortcut (2)"
+        );
+        assert!(
+            options.strong_single_asterisk,
+            "should support `slack` shortcut (3)"
+        );
+
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, attribute_list: false, autolink: true, block_id: false, block_quote: true, block_quote_fenced: false, bracketed_span: false, character_escape: true, character_reference: true, citation: false, code_indented: true, code_fenced: true, code_text: true, critic_markup: false, definition: true, directive_container: false, directive_leaf: false, directive_text: false, fenced_div: false, frontmatter: false, gfm_alert: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, github_reference: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, insert: false, label_start_image: true, label_start_link: true, label_end: true, list_item: true, mark: false, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, mention: false, ruby: false, spoiler: false, spoiler_pipes: false, subscript: false, superscript: false, thematic_break: true, underline: false, wiki_embed: false, wiki_link: false }, gfm_strikethrough_single_tilde: true, gfm_table_headless: false, gfm_table_row_continuation: false, gfm_task_list_item_states: false, math_text_single_dollar: true, math_gitlab: false, label_start_count_max: 1000, autolink_label: false, autolink_relaxed: false, strong_single_asterisk: false, html_flow_markdown: false, frontmatter_markers: [43, 45], column_unit: Byte, custom_inlines: [], custom_blocks: [], mdx_expression_parse: None, mdx_esm_parse: None }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1383,7 +4771,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, attribute_list: false, autolink: true, block_id: false, block_quote: true, block_quote_fenced: false, bracketed_span: false, character_escape: true, character_reference: true, citation: false, code_indented: true, code_fenced: true, code_text: true, critic_markup: false, definition: true, directive_container: false, directive_leaf: false, directive_text: false, fenced_div: false, frontmatter: false, gfm_alert: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, github_reference: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, insert: false, label_start_image: true, label_start_link: true, label_end: true, list_item: true, mark: false, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, mention: false, ruby: false, spoiler: false, spoiler_pipes: false, subscript: false, superscript: false, thematic_break: true, underline: false, wiki_embed: false, wiki_link: false }, gfm_strikethrough_single_tilde: true, gfm_table_headless: false, gfm_table_row_continuation: false, gfm_task_list_item_states: false, math_text_single_dollar: true, math_gitlab: false, label_start_count_max: 1000, autolink_label: false, autolink_relaxed: false, strong_single_asterisk: false, html_flow_markdown: false, frontmatter_markers: [43, 45], column_unit: Byte, custom_inlines: [], custom_blocks: [], mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -1412,6 +4800,16 @@ mod tests {
             options.gfm_tagfilter,
             "should support safe `gfm` shortcut (1)"
         );
+
+        let options = CompileOptions::chat();
+        assert!(
+            !options.allow_dangerous_html,
+            "should support safe `chat` shortcut (1)"
+        );
+        assert!(
+            options.hard_breaks,
+            "should support safe `chat` shortcut (2)"
+        );
     }
 
     #[test]
@@ -0,0 +1,460 @@
+//! Abbreviation occurs in the [flow][] and [text][] content types.
+//!
+//! ## Grammar
+//!
+//! Abbreviation definitions form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! abbreviation_definition ::= *space_or_tab '*[' label ']:' *space_or_tab title *space_or_tab
+//!
+//! label ::= 1*(byte - eol - ']')
+//! ; Restriction: must contain at least one byte that is not whitespace.
+//! title ::= 1*(byte - eol)
+//! ```
+//!
+//! As abbreviation definitions occur in flow, like all flow constructs, they
+//! must be followed by an eol (line ending) or eof (end of file).
+//!
+//! This construct is the abbreviation of PHP Markdown Extra.
+//! Abbreviation definitions can be anywhere in the document, and apply to
+//! the whole document.
+//! Every occurrence of a label in text, that is not part of a bigger word, is
+//! an abbreviation.
+//! Labels are matched case-sensitively; when labels overlap, the longest
+//! wins.
+//! When a label is defined more than once, the first definition wins.
+//!
+//! Abbreviations are found in text by a resolver, after the whole document
+//! is parsed up to the level of definitions.
+//!
+//! ## HTML
+//!
+//! Abbreviation definitions do not, on their own, relate to anything in
+//! HTML.
+//! Abbreviations relate to the `<abbr>` element in HTML.
+//! See [*§ 4.5.9 The `abbr` element*][html_abbr] in the HTML spec for more
+//! info.
+//! The title of the definition is used as the `title` attribute.
+//!
+//! ## Recommendation
+//!
+//! Abbreviations are not part of `CommonMark` or GFM.
+//! They are mostly found in documents written for PHP Markdown Extra,
+//! Python-Markdown, and kramdown.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`Abbreviation`][Name::Abbreviation]
+//! *   [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+//! *   [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+//! *   [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker]
+//! *   [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
+//! *   [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker]
+//! *   [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Abbreviations* in PHP Markdown Extra](https://michelf.ca/projects/php-markdown/extra/#abbr)
+//!
+//! [flow]: crate::construct::flow
+//! [text]: crate::construct::text
+//! [html_abbr]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::{Event, Kind, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    char::{after_index, before_index},
+    constant::TAB_SIZE,
+    slice::{Position, Slice},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Start of abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.abbreviation {
+        tokenizer.enter(Name::AbbreviationDefinition);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::AbbreviationDefinitionBefore),
+                State::Nok,
+            );
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::AbbreviationDefinitionBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `*`.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'*') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `*`, at `[`.
+///
+/// ```markdown
+/// > | *[a]: b
+///      ^
+/// ```
+pub fn label_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::AbbreviationDefinitionLabel);
+        tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+        State::Next(StateName::AbbreviationDefinitionLabelBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at label.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b']') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::AbbreviationDefinitionLabelString);
+            State::Retry(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | *[a]: b
+///       ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b']') => {
+            if !tokenizer.tokenize_state.seen {
+                return State::Nok;
+            }
+
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.exit(Name::AbbreviationDefinitionLabelString);
+            tokenizer.enter(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AbbreviationDefinitionLabelMarker);
+            tokenizer.exit(Name::AbbreviationDefinitionLabel);
+            State::Next(StateName::AbbreviationDefinitionLabelAfter)
+        }
+        Some(byte) => {
+            if !matches!(byte, b'\t' | b' ') {
+                tokenizer.tokenize_state.seen = true;
+            }
+
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionLabelInside)
+        }
+    }
+}
+
+/// After label, at `:`.
+///
+/// ```markdown
+/// > | *[a]: b
+///         ^
+/// ```
+pub fn label_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationDefinitionMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:`, at optional whitespace.
+///
+/// ```markdown
+/// > | *[a]: b
+///          ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::AbbreviationDefinitionTitleBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AbbreviationDefinitionTitleBefore)
+    }
+}
+
+/// After whitespace, at title.
+///
+/// ```markdown
+/// > | *[a]: b
+///           ^
+/// ```
+pub fn title_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        State::Nok
+    } else {
+        tokenizer.enter(Name::AbbreviationDefinitionTitle);
+        State::Retry(StateName::AbbreviationDefinitionTitleInside)
+    }
+}
+
+/// In title.
+///
+/// ```markdown
+/// > | *[a]: b
+///           ^
+/// ```
+pub fn title_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Retry(StateName::AbbreviationDefinitionTitleEnd),
+        // Whitespace is part of the title, unless it is trailing.
+        Some(b'\t' | b' ') => {
+            tokenizer.check(
+                State::Next(StateName::AbbreviationDefinitionTitleEnd),
+                State::Next(StateName::AbbreviationDefinitionTitleWhitespace),
+            );
+            State::Retry(StateName::BlankLineStart)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationDefinitionTitleInside)
+        }
+    }
+}
+
+/// In title, at whitespace that is not trailing.
+///
+/// ```markdown
+/// > | *[a]: b c
+///            ^
+/// ```
+pub fn title_whitespace(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.consume();
+    State::Next(StateName::AbbreviationDefinitionTitleInside)
+}
+
+/// At end of title, at optional trailing whitespace.
+///
+/// ```markdown
+/// > | *[a]: b
+///            ^
+/// ```
+pub fn title_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::AbbreviationDefinitionTitle);
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::AbbreviationDefinitionAfter),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AbbreviationDefinitionAfter)
+    }
+}
+
+/// After abbreviation definition, after optional whitespace.
+///
+/// ```markdown
+/// > | *[a]: b
+///            ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::AbbreviationDefinition);
+
+        let mut index = tokenizer.events.len() - 1;
+        while tokenizer.events[index].name != Name::AbbreviationDefinitionLabelString {
+            index -= 1;
+        }
+
+        tokenizer.tokenize_state.abbreviations.push(
+            Slice::from_position(
+                tokenizer.parse_state.bytes,
+                &Position::from_exit_event(&tokenizer.events, index),
+            )
+            .as_str()
+            .into(),
+        );
+
+        // Feel free to interrupt.
+        tokenizer.interrupt = false;
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// Resolve abbreviations in text.
+///
+/// Turns occurrences of the labels of abbreviation definitions in data into
+/// abbreviations.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    // Longer labels win.
+    let mut labels = tokenizer
+        .parse_state
+        .abbreviations
+        .iter()
+        .map(String::as_bytes)
+        .collect::<Vec<_>>();
+    labels.sort_by_key(|d| core::cmp::Reverse(d.len()));
+
+    let bytes = tokenizer.parse_state.bytes;
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        // Skip data that is linked to other content, such as destinations,
+        // which is parsed later.
+        if event.kind == Kind::Exit
+            && event.name == Name::Data
+            && tokenizer.events[index - 1].link.is_none()
+        {
+            let mut point = tokenizer.events[index - 1].point.clone();
+            let end = event.point.index;
+            let mut byte_index = point.index;
+            let mut min = byte_index;
+            let mut replace = Vec::new();
+
+            while byte_index < end {
+                let label = labels.iter().find(|label| {
+                    bytes[byte_index..end].starts_with(label)
+                        && !is_word(before_index(bytes, byte_index))
+                        && !is_word(after_index(bytes, byte_index + label.len()))
+                });
+
+                if let Some(label) = label {
+                    // If there is something between the last abbreviation
+                    // (or the start) and this one.
+                    if min != byte_index {
+                        replace.push(Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                        point = point.shift_to(bytes, byte_index);
+                        replace.push(Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                    }
+
+                    let start = point.clone();
+                    byte_index += label.len();
+                    point = point.shift_to(bytes, byte_index);
+                    replace.append(&mut vec![
+                        Event {
+                            kind: Kind::Enter,
+                            name: Name::Abbreviation,
+                            point: start.clone(),
+                            link: None,
+                        },
+                        Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: start,
+                            link: None,
+                        },
+                        Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        },
+                        Event {
+                            kind: Kind::Exit,
+                            name: Name::Abbreviation,
+                            point: point.clone(),
+                            link: None,
+                        },
+                    ]);
+                    min = byte_index;
+                } else {
+                    byte_index += 1;
+                }
+            }
+
+            // If there were abbreviations.
+            if !replace.is_empty() {
+                // If there are more bytes left.
+                if min < end {
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Data,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Data,
+                        point: event.point.clone(),
+                        link: None,
+                    });
+                }
+
+                tokenizer.map.add(index - 1, 2, replace);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check whether `char` is part of a word.
+///
+/// Underscores are not, so that abbreviations can be emphasized with them.
+fn is_word(char: Option<char>) -> bool {
+    matches!(char, Some(char) if char.is_alphanumeric())
+}
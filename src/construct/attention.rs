@@ -1,5 +1,6 @@
-//! Attention (emphasis, strong, optionally GFM strikethrough) occurs in the
-//! [text][] content type.
+//! Attention (emphasis, strong, optionally GFM strikethrough, insert, mark,
+//! spoiler, subscript, superscript, underline) occurs in the [text][] content
+//! type.
 //!
 //! ## Grammar
 //!
@@ -9,6 +10,10 @@
 //! ```bnf
 //! attention_sequence ::= 1*'*' | 1*'_'
 //! gfm_attention_sequence ::= 1*'~'
+//! insert_attention_sequence ::= 1*'+'
+//! mark_attention_sequence ::= 1*'='
+//! spoiler_attention_sequence ::= 1*'|'
+//! superscript_attention_sequence ::= 1*'^'
 //! ```
 //!
 //! Sequences are matched together to form attention based on which character
@@ -29,6 +34,35 @@
 //! HTML.
 //! See [*§ 4.7.2 The `del` element*][html-del] in the HTML spec for more info.
 //!
+//! When `strong_single_asterisk` is on, one marker taken from asterisk
+//! sequences also relates to the `<strong>` element.
+//!
+//! When underline is turned on, and two markers can be taken from underscore
+//! sequences, they together relate to the `<u>` element in HTML instead of
+//! `<strong>`.
+//! See [*§ 4.5.22 The `u` element*][html-u] in the HTML spec for more info.
+//!
+//! When pipe sequences of two markers match, they together form a
+//! [spoiler][crate::construct::spoiler], which is compiled to a
+//! `<span class="spoiler">` element.
+//!
+//! When insert is turned on, and plus sequences of two markers match, they
+//! together relate to the `<ins>` element in HTML.
+//! See [*§ 4.7.1 The `ins` element*][html-ins] in the HTML spec for more info.
+//!
+//! When mark is turned on, and equals sequences of two markers match, they
+//! together relate to the `<mark>` element in HTML.
+//! See [*§ 4.5.23 The `mark` element*][html-mark] in the HTML spec for more
+//! info.
+//!
+//! When subscript is turned on, and tilde sequences of one marker match,
+//! they together relate to the `<sub>` element in HTML instead of `<del>`.
+//! When caret sequences of one marker match, they together relate to the
+//! `<sup>` element.
+//! Subscript and superscript cannot contain whitespace.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html-sub-sup] in the HTML
+//! spec for more info.
+//!
 //! ## Recommendation
 //!
 //! It is recommended to use asterisks for emphasis/strong attention when
@@ -49,6 +83,18 @@
 //! While `github.com` allows single tildes too, it technically prohibits it in
 //! their spec.
 //!
+//! Underline and spoiler (pipes) attention are not part of `CommonMark` or
+//! GFM, but are used in chat apps such as Discord and Telegram.
+//!
+//! Subscript and superscript attention are not part of `CommonMark` or GFM
+//! either, but are supported by Pandoc and markdown-it plugins.
+//!
+//! Insert attention, which complements strikethrough, is not part of
+//! `CommonMark` or GFM either, but is supported by markdown-it plugins.
+//!
+//! Mark attention is not part of `CommonMark` or GFM either, but is used to
+//! highlight text in note-taking apps such as Obsidian.
+//!
 //! ## Tokens
 //!
 //! *   [`Emphasis`][Name::Emphasis]
@@ -57,9 +103,27 @@
 //! *   [`GfmStrikethrough`][Name::GfmStrikethrough]
 //! *   [`GfmStrikethroughSequence`][Name::GfmStrikethroughSequence]
 //! *   [`GfmStrikethroughText`][Name::GfmStrikethroughText]
+//! *   [`Insert`][Name::Insert]
+//! *   [`InsertSequence`][Name::InsertSequence]
+//! *   [`InsertText`][Name::InsertText]
+//! *   [`Mark`][Name::Mark]
+//! *   [`MarkSequence`][Name::MarkSequence]
+//! *   [`MarkText`][Name::MarkText]
+//! *   [`Spoiler`][Name::Spoiler]
+//! *   [`SpoilerSequence`][Name::SpoilerSequence]
+//! *   [`SpoilerText`][Name::SpoilerText]
 //! *   [`Strong`][Name::Strong]
 //! *   [`StrongSequence`][Name::StrongSequence]
 //! *   [`StrongText`][Name::StrongText]
+//! *   [`Subscript`][Name::Subscript]
+//! *   [`SubscriptSequence`][Name::SubscriptSequence]
+//! *   [`SubscriptText`][Name::SubscriptText]
+//! *   [`Superscript`][Name::Superscript]
+//! *   [`SuperscriptSequence`][Name::SuperscriptSequence]
+//! *   [`SuperscriptText`][Name::SuperscriptText]
+//! *   [`Underline`][Name::Underline]
+//! *   [`UnderlineSequence`][Name::UnderlineSequence]
+//! *   [`UnderlineText`][Name::UnderlineText]
 //!
 //! > 👉 **Note**: while parsing, [`AttentionSequence`][Name::AttentionSequence]
 //! > is used, which is later compiled away.
@@ -70,11 +134,18 @@
 //! *   [`micromark-extension-gfm-strikethrough`](https://github.com/micromark/micromark-extension-gfm-strikethrough)
 //! *   [*§ 6.2 Emphasis and strong emphasis* in `CommonMark`](https://spec.commonmark.org/0.31/#emphasis-and-strong-emphasis)
 //! *   [*§ 6.5 Strikethrough (extension)* in `GFM`](https://github.github.com/gfm/#strikethrough-extension-)
+//! *   [`markdown-it-ins`](https://github.com/markdown-it/markdown-it-ins)
+//! *   [*Superscripts and subscripts* in Pandoc](https://pandoc.org/MANUAL.html#superscripts-and-subscripts)
+//! *   [*Highlights* in Obsidian](https://help.obsidian.md/Editing+and+formatting/Basic+formatting+syntax)
 //!
 //! [text]: crate::construct::text
 //! [html-em]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-em-element
 //! [html-strong]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-strong-element
 //! [html-del]: https://html.spec.whatwg.org/multipage/edits.html#the-del-element
+//! [html-u]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-u-element
+//! [html-ins]: https://html.spec.whatwg.org/multipage/edits.html#the-ins-element
+//! [html-mark]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-mark-element
+//! [html-sub-sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
 
 use crate::event::{Event, Kind, Name, Point};
 use crate::resolve::Name as ResolveName;
@@ -120,8 +191,18 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Emphasis/strong:
     if (tokenizer.parse_state.options.constructs.attention
         && matches!(tokenizer.current, Some(b'*' | b'_')))
-        // GFM strikethrough:
-        || (tokenizer.parse_state.options.constructs.gfm_strikethrough && tokenizer.current == Some(b'~'))
+        // GFM strikethrough, subscript:
+        || ((tokenizer.parse_state.options.constructs.gfm_strikethrough
+            || tokenizer.parse_state.options.constructs.subscript)
+            && tokenizer.current == Some(b'~'))
+        // Superscript:
+        || (tokenizer.parse_state.options.constructs.superscript && tokenizer.current == Some(b'^'))
+        // Spoiler:
+        || (tokenizer.parse_state.options.constructs.spoiler_pipes && tokenizer.current == Some(b'|'))
+        // Insert:
+        || (tokenizer.parse_state.options.constructs.insert && tokenizer.current == Some(b'+'))
+        // Mark:
+        || (tokenizer.parse_state.options.constructs.mark && tokenizer.current == Some(b'='))
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.enter(Name::AttentionSequence);
@@ -187,12 +268,31 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         continue;
                     }
 
+                    let subscript = is_subscript(tokenizer, sequence_open)
+                        && is_subscript(tokenizer, sequence_close);
+
+                    // For subscript and superscript:
+                    // * both sequences must have one marker
+                    // * there must be no whitespace between them
+                    if (sequence_close.marker == b'^' || subscript)
+                        && (sequence_close.size != 1
+                            || sequence_open.size != 1
+                            || tokenizer.parse_state.bytes
+                                [sequence_open.end_point.index..sequence_close.start_point.index]
+                                .iter()
+                                .any(u8::is_ascii_whitespace))
+                    {
+                        continue;
+                    }
+
                     // For GFM strikethrough:
                     // * both sequences must have the same size
                     // * more than 2 markers don’t work
                     // * one marker is prohibited by the spec, but supported by GH
                     if sequence_close.marker == b'~'
-                        && (sequence_close.size != sequence_open.size
+                        && !subscript
+                        && (!tokenizer.parse_state.options.constructs.gfm_strikethrough
+                            || sequence_close.size != sequence_open.size
                             || sequence_close.size > 2
                             || sequence_close.size == 1
                                 && !tokenizer.parse_state.options.gfm_strikethrough_single_tilde)
@@ -200,6 +300,14 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         continue;
                     }
 
+                    // For inserts, marks, and spoilers, both sequences must
+                    // have two markers.
+                    if matches!(sequence_close.marker, b'+' | b'=' | b'|')
+                        && (sequence_close.size != 2 || sequence_open.size != 2)
+                    {
+                        continue;
+                    }
+
                     // We found a match!
                     next_index = match_sequences(tokenizer, &mut sequences, open, close);
 
@@ -247,12 +355,13 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                     || (after == CharacterKind::Punctuation && before != CharacterKind::Other)
                     // For regular attention markers (not strikethrough), the
                     // other attention markers can be used around them
-                    || (marker != b'~' && matches!(after_char, Some('*' | '_')))
-                    || (marker != b'~' && tokenizer.parse_state.options.constructs.gfm_strikethrough && matches!(after_char, Some('~')));
+                    || (!matches!(marker, b'+' | b'=' | b'^' | b'~' | b'|') && matches!(after_char, Some('*' | '_')))
+                    || (!matches!(marker, b'+' | b'=' | b'^' | b'~' | b'|') && tokenizer.parse_state.options.constructs.gfm_strikethrough && matches!(after_char, Some('~')));
                 let close = before == CharacterKind::Other
                     || (before == CharacterKind::Punctuation && after != CharacterKind::Other)
-                    || (marker != b'~' && matches!(before_char, Some('*' | '_')))
-                    || (marker != b'~'
+                    || (!matches!(marker, b'+' | b'=' | b'^' | b'~' | b'|')
+                        && matches!(before_char, Some('*' | '_')))
+                    || (!matches!(marker, b'+' | b'=' | b'^' | b'~' | b'|')
                         && tokenizer.parse_state.options.constructs.gfm_strikethrough
                         && matches!(before_char, Some('~')));
 
@@ -327,14 +436,40 @@ fn match_sequences(
         between += 1;
     }
 
-    let (group_name, seq_name, text_name) = if sequences[open].marker == b'~' {
+    let (group_name, seq_name, text_name) = if is_subscript(tokenizer, &sequences[open]) {
+        (
+            Name::Subscript,
+            Name::SubscriptSequence,
+            Name::SubscriptText,
+        )
+    } else if sequences[open].marker == b'^' {
+        (
+            Name::Superscript,
+            Name::SuperscriptSequence,
+            Name::SuperscriptText,
+        )
+    } else if sequences[open].marker == b'~' {
         (
             Name::GfmStrikethrough,
             Name::GfmStrikethroughSequence,
             Name::GfmStrikethroughText,
         )
-    } else if take == 1 {
+    } else if sequences[open].marker == b'+' {
+        (Name::Insert, Name::InsertSequence, Name::InsertText)
+    } else if sequences[open].marker == b'=' {
+        (Name::Mark, Name::MarkSequence, Name::MarkText)
+    } else if sequences[open].marker == b'|' {
+        (Name::Spoiler, Name::SpoilerSequence, Name::SpoilerText)
+    } else if take == 1
+        && !(sequences[open].marker == b'*' && tokenizer.parse_state.options.strong_single_asterisk)
+    {
         (Name::Emphasis, Name::EmphasisSequence, Name::EmphasisText)
+    } else if sequences[open].marker == b'_' && tokenizer.parse_state.options.constructs.underline {
+        (
+            Name::Underline,
+            Name::UnderlineSequence,
+            Name::UnderlineText,
+        )
     } else {
         (Name::Strong, Name::StrongSequence, Name::StrongText)
     };
@@ -438,3 +573,11 @@ fn match_sequences(
 
     next
 }
+
+/// Check whether `sequence` can form subscript: a single tilde, when
+/// subscript is turned on.
+fn is_subscript(tokenizer: &Tokenizer, sequence: &Sequence) -> bool {
+    sequence.marker == b'~'
+        && sequence.size == 1
+        && tokenizer.parse_state.options.constructs.subscript
+}
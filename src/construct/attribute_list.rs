@@ -0,0 +1,554 @@
+//! Attribute list occurs in the [flow][] and [text][] content types.
+//!
+//! ## Grammar
+//!
+//! Attribute lists form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! attribute_list_flow ::= *space_or_tab attribute_list *space_or_tab
+//! attribute_list_text ::= attribute_list | '{' *space_or_tab attribute *(1*space_or_tab attribute) *space_or_tab '}'
+//!
+//! attribute_list ::= '{:' *space_or_tab [attribute *(1*space_or_tab attribute) *space_or_tab] '}'
+//! attribute ::= id | class | property
+//! id ::= '#' 1*name_byte
+//! class ::= '.' 1*name_byte
+//! property ::= 1*name_byte '=' value
+//! value ::= '"' *(byte - '"' - eol) '"' | "'" *(byte - "'" - eol) "'" | 1*name_byte
+//!
+//! name_byte ::= byte - space_or_tab - eol - '"' - "'" - '<' - '=' - '>' - '`' - '{' - '}'
+//! ```
+//!
+//! As attribute list (flow) occurs in flow, like all flow constructs, it must
+//! be followed by an eol (line ending) or eof (end of file).
+//!
+//! This construct is the inline attribute list (IAL) of kramdown.
+//! An attribute list in flow applies to the block directly before it.
+//! When there is no such block (because the attribute list is at the start
+//! of its container, or after a blank line), it applies to the block directly
+//! after it.
+//! An attribute list in text applies to the span (such as emphasis, code, or
+//! a link) directly before it.
+//! In text, the colon after the opening brace can be left out (as in
+//! `*a*{.b}`), but then there must be at least one attribute, and there must
+//! be a span directly before it: otherwise it is just text.
+//! Attribute lists that do not apply to anything are ignored.
+//!
+//! The attributes of [directives][directive] are parsed here too, without
+//! the colon after the opening brace, and also allowing properties without
+//! a value.
+//! So are the attributes of [bracketed spans][label_end] and
+//! [fenced divs][fenced_div], without the colon after the opening brace.
+//!
+//! Attribute list definitions (ALDs) and references to them are not
+//! supported.
+//!
+//! ## HTML
+//!
+//! Attribute lists do not relate to anything in HTML.
+//! Their attributes are added to the element they apply to.
+//! Multiple classes are joined together.
+//!
+//! ## Recommendation
+//!
+//! Attribute lists are not part of `CommonMark` or GFM.
+//! They are mostly found in documents written for kramdown, such as those
+//! of Jekyll sites.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`AttributeList`][Name::AttributeList]
+//! *   [`AttributeListClass`][Name::AttributeListClass]
+//! *   [`AttributeListClassMarker`][Name::AttributeListClassMarker]
+//! *   [`AttributeListClassValue`][Name::AttributeListClassValue]
+//! *   [`AttributeListId`][Name::AttributeListId]
+//! *   [`AttributeListIdMarker`][Name::AttributeListIdMarker]
+//! *   [`AttributeListIdValue`][Name::AttributeListIdValue]
+//! *   [`AttributeListMarker`][Name::AttributeListMarker]
+//! *   [`AttributeListProperty`][Name::AttributeListProperty]
+//! *   [`AttributeListPropertyInitializerMarker`][Name::AttributeListPropertyInitializerMarker]
+//! *   [`AttributeListPropertyName`][Name::AttributeListPropertyName]
+//! *   [`AttributeListPropertyValue`][Name::AttributeListPropertyValue]
+//! *   [`AttributeListPropertyValueMarker`][Name::AttributeListPropertyValueMarker]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Inline attribute lists* in kramdown](https://kramdown.gettalong.org/syntax.html#inline-attribute-lists)
+//!
+//! [flow]: crate::construct::flow
+//! [text]: crate::construct::text
+//! [directive]: crate::construct::partial_directive
+//! [fenced_div]: crate::construct::fenced_div
+//! [label_end]: crate::construct::label_end
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::{Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    attribute_list::{exit, span_before},
+    constant::TAB_SIZE,
+};
+use alloc::vec;
+
+/// Start of attribute list (flow).
+///
+/// ```markdown
+/// > | {: .a}
+///     ^
+/// ```
+pub fn flow_start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.attribute_list {
+        tokenizer.tokenize_state.seen = true;
+        tokenizer.tokenize_state.token_3 = Name::AttributeList;
+        tokenizer.enter(Name::AttributeList);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::AttributeListStart), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::AttributeListStart)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// Start of attribute list (text).
+///
+/// ```markdown
+/// > | *a*{: .b}
+///        ^
+/// ```
+pub fn text_start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.attribute_list {
+        tokenizer.tokenize_state.token_3 = Name::AttributeList;
+        tokenizer.enter(Name::AttributeList);
+        State::Retry(StateName::AttributeListStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// Start of attributes of a directive.
+///
+/// ```markdown
+/// > | :a{.b}
+///       ^
+/// ```
+pub fn directive_start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'{'), "expected `{{`");
+    tokenizer.tokenize_state.token_3 = Name::DirectiveAttributes;
+    tokenizer.enter(Name::DirectiveAttributes);
+    tokenizer.enter(Name::AttributeListMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::AttributeListMarker);
+    State::Next(StateName::AttributeListBetween)
+}
+
+/// Start of attributes of a bracketed span.
+///
+/// ```markdown
+/// > | [a]{.b}
+///        ^
+/// ```
+pub fn bracketed_span_start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'{'), "expected `{{`");
+    tokenizer.tokenize_state.token_3 = Name::BracketedSpanAttributes;
+    tokenizer.enter(Name::BracketedSpanAttributes);
+    tokenizer.enter(Name::AttributeListMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::AttributeListMarker);
+    State::Next(StateName::AttributeListBetween)
+}
+
+/// Start of attributes of a fenced div.
+///
+/// ```markdown
+/// > | ::: {.a}
+///         ^
+///   | b
+///   | :::
+/// ```
+pub fn fenced_div_start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'{'), "expected `{{`");
+    tokenizer.tokenize_state.token_3 = Name::FencedDivAttributes;
+    tokenizer.enter(Name::FencedDivAttributes);
+    tokenizer.enter(Name::AttributeListMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::AttributeListMarker);
+    State::Next(StateName::AttributeListBetween)
+}
+
+/// At `{`.
+///
+/// ```markdown
+/// > | {: .a}
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'{') {
+        tokenizer.enter(Name::AttributeListMarker);
+        tokenizer.consume();
+        State::Next(StateName::AttributeListOpen)
+    } else {
+        nok(tokenizer)
+    }
+}
+
+/// After `{`, at `:`.
+///
+/// ```markdown
+/// > | {: .a}
+///      ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.consume();
+        tokenizer.exit(Name::AttributeListMarker);
+        State::Next(StateName::AttributeListBetween)
+    }
+    // The colon can be left out in text.
+    else if !tokenizer.tokenize_state.seen {
+        tokenizer.exit(Name::AttributeListMarker);
+        tokenizer.register_resolver_after(ResolveName::AttributeList);
+        State::Retry(StateName::AttributeListBetween)
+    } else {
+        nok(tokenizer)
+    }
+}
+
+/// Before an attribute or the closing marker.
+///
+/// ```markdown
+/// > | {: .a}
+///       ^^ ^
+/// ```
+pub fn between(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b' ') => {
+            tokenizer.attempt(State::Next(StateName::AttributeListBetween), State::Nok);
+            State::Retry(space_or_tab(tokenizer))
+        }
+        Some(b'#' | b'.') => {
+            let (wrapper, marker, value) = if tokenizer.current == Some(b'#') {
+                (
+                    Name::AttributeListId,
+                    Name::AttributeListIdMarker,
+                    Name::AttributeListIdValue,
+                )
+            } else {
+                (
+                    Name::AttributeListClass,
+                    Name::AttributeListClassMarker,
+                    Name::AttributeListClassValue,
+                )
+            };
+            tokenizer.enter(wrapper.clone());
+            tokenizer.enter(marker.clone());
+            tokenizer.consume();
+            tokenizer.exit(marker);
+            tokenizer.tokenize_state.token_1 = wrapper;
+            tokenizer.tokenize_state.token_2 = value;
+            State::Next(StateName::AttributeListShorthandBefore)
+        }
+        Some(b'}') => {
+            tokenizer.enter(Name::AttributeListMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AttributeListMarker);
+
+            if tokenizer.tokenize_state.seen {
+                State::Next(StateName::AttributeListFlowAfter)
+            } else {
+                tokenizer.exit(tokenizer.tokenize_state.token_3.clone());
+                tokenizer.tokenize_state.token_3 = Name::Data;
+                State::Ok
+            }
+        }
+        Some(byte) if name_byte(byte) => {
+            tokenizer.enter(Name::AttributeListProperty);
+            tokenizer.enter(Name::AttributeListPropertyName);
+            tokenizer.consume();
+            State::Next(StateName::AttributeListPropertyName)
+        }
+        _ => nok(tokenizer),
+    }
+}
+
+/// After an attribute.
+///
+/// ```markdown
+/// > | {: .a #b}
+///          ^  ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ' | b'}')) {
+        State::Retry(StateName::AttributeListBetween)
+    } else {
+        nok(tokenizer)
+    }
+}
+
+/// After `#` or `.`, before a value.
+///
+/// ```markdown
+/// > | {: .a}
+///         ^
+/// ```
+pub fn shorthand_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if name_byte(byte) => {
+            tokenizer.enter(tokenizer.tokenize_state.token_2.clone());
+            tokenizer.consume();
+            State::Next(StateName::AttributeListShorthandInside)
+        }
+        _ => nok(tokenizer),
+    }
+}
+
+/// In an id or class value.
+///
+/// ```markdown
+/// > | {: .ab}
+///          ^
+/// ```
+pub fn shorthand_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if name_byte(byte) => {
+            tokenizer.consume();
+            State::Next(StateName::AttributeListShorthandInside)
+        }
+        _ => {
+            tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
+            tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
+            tokenizer.tokenize_state.token_1 = Name::Data;
+            tokenizer.tokenize_state.token_2 = Name::Data;
+            State::Retry(StateName::AttributeListAfter)
+        }
+    }
+}
+
+/// In a property name.
+///
+/// ```markdown
+/// > | {: ab="c"}
+///         ^
+/// ```
+pub fn property_name(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'=') => {
+            tokenizer.exit(Name::AttributeListPropertyName);
+            tokenizer.enter(Name::AttributeListPropertyInitializerMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AttributeListPropertyInitializerMarker);
+            State::Next(StateName::AttributeListPropertyValueBefore)
+        }
+        Some(byte) if name_byte(byte) => {
+            tokenizer.consume();
+            State::Next(StateName::AttributeListPropertyName)
+        }
+        // Properties without a value, in directives.
+        Some(b'\t' | b' ' | b'}')
+            if tokenizer.tokenize_state.token_3 == Name::DirectiveAttributes =>
+        {
+            tokenizer.exit(Name::AttributeListPropertyName);
+            tokenizer.exit(Name::AttributeListProperty);
+            State::Retry(StateName::AttributeListAfter)
+        }
+        _ => nok(tokenizer),
+    }
+}
+
+/// After `=`, before a property value.
+///
+/// ```markdown
+/// > | {: a="b"}
+///          ^
+/// ```
+pub fn property_value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'"' | b'\'') => {
+            tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+            tokenizer.enter(Name::AttributeListPropertyValueMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AttributeListPropertyValueMarker);
+            State::Next(StateName::AttributeListPropertyValueQuotedStart)
+        }
+        Some(byte) if name_byte(byte) => {
+            tokenizer.enter(Name::AttributeListPropertyValue);
+            tokenizer.consume();
+            State::Next(StateName::AttributeListPropertyValueUnquoted)
+        }
+        _ => nok(tokenizer),
+    }
+}
+
+/// After an opening quote, at a value or the closing quote.
+///
+/// ```markdown
+/// > | {: a="b"}
+///           ^
+/// ```
+pub fn property_value_quoted_start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+        tokenizer.enter(Name::AttributeListPropertyValueMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AttributeListPropertyValueMarker);
+        tokenizer.exit(Name::AttributeListProperty);
+        tokenizer.tokenize_state.marker = 0;
+        State::Next(StateName::AttributeListAfter)
+    } else {
+        tokenizer.enter(Name::AttributeListPropertyValue);
+        State::Retry(StateName::AttributeListPropertyValueQuoted)
+    }
+}
+
+/// In a quoted property value.
+///
+/// ```markdown
+/// > | {: a="b"}
+///           ^
+/// ```
+pub fn property_value_quoted(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => nok(tokenizer),
+        Some(byte) if byte == tokenizer.tokenize_state.marker => {
+            tokenizer.exit(Name::AttributeListPropertyValue);
+            State::Retry(StateName::AttributeListPropertyValueQuotedStart)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::AttributeListPropertyValueQuoted)
+        }
+    }
+}
+
+/// In an unquoted property value.
+///
+/// ```markdown
+/// > | {: a=bc}
+///           ^
+/// ```
+pub fn property_value_unquoted(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if name_byte(byte) => {
+            tokenizer.consume();
+            State::Next(StateName::AttributeListPropertyValueUnquoted)
+        }
+        _ => {
+            tokenizer.exit(Name::AttributeListPropertyValue);
+            tokenizer.exit(Name::AttributeListProperty);
+            State::Retry(StateName::AttributeListAfter)
+        }
+    }
+}
+
+/// After attribute list (flow), at optional whitespace.
+///
+/// ```markdown
+/// > | {: .a}
+///           ^
+/// ```
+pub fn flow_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::AttributeListFlowEnd), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AttributeListFlowEnd)
+    }
+}
+
+/// After attribute list (flow), after optional whitespace.
+///
+/// ```markdown
+/// > | {: .a}
+///           ^
+/// ```
+pub fn flow_end(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.tokenize_state.seen = false;
+        tokenizer.tokenize_state.token_3 = Name::Data;
+        tokenizer.exit(Name::AttributeList);
+        // Feel free to interrupt.
+        tokenizer.interrupt = false;
+        State::Ok
+    } else {
+        nok(tokenizer)
+    }
+}
+
+/// Resolve attribute lists (text) without colon.
+///
+/// Those without attributes, or without a span directly before them, are
+/// turned into data.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        if tokenizer.events[index].kind == Kind::Enter
+            && tokenizer.events[index].name == Name::AttributeList
+        {
+            let end = exit(&tokenizer.events, index);
+            // The opening marker is only `{`.
+            let bare = tokenizer.events[index + 2].point.index
+                - tokenizer.events[index + 1].point.index
+                == 1;
+
+            if bare
+                && !(span_before(&tokenizer.events, index)
+                    && tokenizer.events[index..end].iter().any(|event| {
+                        matches!(
+                            event.name,
+                            Name::AttributeListClass
+                                | Name::AttributeListId
+                                | Name::AttributeListProperty
+                        )
+                    }))
+            {
+                let mut enter = tokenizer.events[index].clone();
+                let mut exit = tokenizer.events[end].clone();
+                enter.name = Name::Data;
+                exit.name = Name::Data;
+                tokenizer.map.add(index, end - index + 1, vec![enter, exit]);
+            }
+
+            index = end;
+        }
+
+        index += 1;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Not an attribute list: reset state.
+fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.seen = false;
+    tokenizer.tokenize_state.marker = 0;
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    tokenizer.tokenize_state.token_2 = Name::Data;
+    tokenizer.tokenize_state.token_3 = Name::Data;
+    State::Nok
+}
+
+/// Whether `byte` can occur in a name or unquoted value.
+fn name_byte(byte: u8) -> bool {
+    !matches!(
+        byte,
+        b'\t' | b'\n' | b'\r' | b' ' | b'"' | b'\'' | b'<' | b'=' | b'>' | b'`' | b'{' | b'}'
+    )
+}
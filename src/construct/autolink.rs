@@ -20,6 +20,22 @@
 //! ascii_atext ::= ascii_alphanumeric | '!' | '"' | '#' | '$' | '%' | '&' | '\'' | '*' | '+' | '-' | '/' | '=' | '?' | '^' | '_' | '`' | '{' | '|' | '}' | '~'
 //! ```
 //!
+//! When `autolink_label` is on in [`ParseOptions`][crate::ParseOptions], URLs
+//! can be followed by a label, like in Slack:
+//!
+//! ```bnf
+//! url ::= protocol *(url_byte - '|') ['|' 1*label_byte]
+//! label_byte ::= byte - ascii_control - '<' - '>'
+//! ```
+//!
+//! When `autolink_relaxed` is on in [`ParseOptions`][crate::ParseOptions],
+//! domains in emails can also contain underscores, and are not restricted in
+//! size:
+//!
+//! ```bnf
+//! email_domain ::= ascii_alphanumeric *(ascii_alphanumeric | 1*('-' | '_') ascii_alphanumeric)
+//! ```
+//!
 //! The maximum allowed size of a scheme is `31` (inclusive), which is defined
 //! in [`AUTOLINK_SCHEME_SIZE_MAX`][].
 //! The maximum allowed size of a domain is `63` (inclusive), which is defined
@@ -105,6 +121,8 @@
 //! *   [`Autolink`][Name::Autolink]
 //! *   [`AutolinkEmail`][Name::AutolinkEmail]
 //! *   [`AutolinkMarker`][Name::AutolinkMarker]
+//! *   [`AutolinkLabel`][Name::AutolinkLabel]
+//! *   [`AutolinkLabelMarker`][Name::AutolinkLabelMarker]
 //! *   [`AutolinkProtocol`][Name::AutolinkProtocol]
 //!
 //! ## References
@@ -233,6 +251,13 @@ pub fn url_inside(tokenizer: &mut Tokenizer) -> State {
             tokenizer.exit(Name::Autolink);
             State::Ok
         }
+        Some(b'|') if tokenizer.parse_state.options.autolink_label => {
+            tokenizer.exit(Name::AutolinkProtocol);
+            tokenizer.enter(Name::AutolinkLabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AutolinkLabelMarker);
+            State::Next(StateName::AutolinkUrlLabelBefore)
+        }
         // ASCII control, space, or `<`.
         None | Some(b'\0'..=0x1F | b' ' | b'<' | 0x7F) => State::Nok,
         Some(_) => {
@@ -242,6 +267,48 @@ pub fn url_inside(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// After `|`, at label.
+///
+/// ```markdown
+/// > | a<https://example.com|b>c
+///                           ^
+/// ```
+pub fn url_label_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // ASCII control, `<`, or `>`.
+        None | Some(b'\0'..=0x1F | b'<' | b'>' | 0x7F) => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::AutolinkLabel);
+            State::Retry(StateName::AutolinkUrlLabelInside)
+        }
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | a<https://example.com|b>c
+///                           ^
+/// ```
+pub fn url_label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'>') => {
+            tokenizer.exit(Name::AutolinkLabel);
+            tokenizer.enter(Name::AutolinkMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AutolinkMarker);
+            tokenizer.exit(Name::Autolink);
+            State::Ok
+        }
+        // ASCII control or `<`.
+        None | Some(b'\0'..=0x1F | b'<' | 0x7F) => State::Nok,
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::AutolinkUrlLabelInside)
+        }
+    }
+}
+
 /// In email atext.
 ///
 /// ```markdown
@@ -336,12 +403,15 @@ pub fn email_label(tokenizer: &mut Tokenizer) -> State {
 ///                    ^
 /// ```
 pub fn email_value(tokenizer: &mut Tokenizer) -> State {
+    let relaxed = tokenizer.parse_state.options.autolink_relaxed;
+
     match tokenizer.current {
-        // ASCII alphanumeric or `-`.
-        Some(b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z')
-            if tokenizer.tokenize_state.size < AUTOLINK_DOMAIN_SIZE_MAX =>
+        // ASCII alphanumeric, `-`, or, when relaxed, `_`.
+        Some(byte)
+            if (byte == b'-' || byte.is_ascii_alphanumeric() || (relaxed && byte == b'_'))
+                && (relaxed || tokenizer.tokenize_state.size < AUTOLINK_DOMAIN_SIZE_MAX) =>
         {
-            let name = if matches!(tokenizer.current, Some(b'-')) {
+            let name = if matches!(byte, b'-' | b'_') {
                 StateName::AutolinkEmailValue
             } else {
                 StateName::AutolinkEmailLabel
@@ -0,0 +1,126 @@
+//! Block id occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Block id forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must be preceded by `space_or_tab`.
+//! ; Restriction: must be followed by `*space_or_tab` and the end of the text.
+//! block_id ::= '^' 1*(ascii_alphanumeric | '-')
+//! ```
+//!
+//! This construct is the block identifier of Obsidian.
+//! A block id must be at the end of the block it applies to, so at the end
+//! of a paragraph or heading.
+//! When the paragraph is the first block in a list item, the block id
+//! applies to that list item instead.
+//! Whitespace before a block id is ignored.
+//!
+//! Block ids on their own line, after a block, are not supported.
+//!
+//! ## HTML
+//!
+//! Block ids do not relate to anything in HTML.
+//! They are compiled to an `id` attribute on the element they apply to, with
+//! a caret (`^`) before it, so that links to `#^some-id` work, like in
+//! Obsidian.
+//!
+//! ## Recommendation
+//!
+//! Block ids are not part of `CommonMark` or GFM.
+//! They are mostly found in notes written with Obsidian.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`BlockId`][Name::BlockId]
+//! *   [`BlockIdMarker`][Name::BlockIdMarker]
+//! *   [`BlockIdValue`][Name::BlockIdValue]
+//!
+//! ## References
+//!
+//! *   [*Link to a block in a note* in Obsidian](https://help.obsidian.md/Linking+notes+and+files/Internal+links#Link+to+a+block+in+a+note)
+//!
+//! [text]: crate::construct::text
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of block id.
+///
+/// ```markdown
+/// > | a ^b
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.block_id
+        && tokenizer.current == Some(b'^')
+        && matches!(tokenizer.previous, Some(b'\t' | b' '))
+    {
+        tokenizer.enter(Name::BlockId);
+        tokenizer.enter(Name::BlockIdMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::BlockIdMarker);
+        State::Next(StateName::BlockIdValueBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `^`, at value.
+///
+/// ```markdown
+/// > | a ^b
+///        ^
+/// ```
+pub fn value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z') => {
+            tokenizer.enter(Name::BlockIdValue);
+            State::Retry(StateName::BlockIdValueInside)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In value.
+///
+/// ```markdown
+/// > | a ^b
+///        ^
+/// ```
+pub fn value_inside(tokenizer: &mut Tokenizer) -> State {
+    if let Some(b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z') = tokenizer.current {
+        tokenizer.consume();
+        return State::Next(StateName::BlockIdValueInside);
+    }
+
+    tokenizer.exit(Name::BlockIdValue);
+    tokenizer.exit(Name::BlockId);
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::BlockIdAfter), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::BlockIdAfter)
+    }
+}
+
+/// After block id, at end of text.
+///
+/// ```markdown
+/// > | a ^b
+///         ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current.is_none() {
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
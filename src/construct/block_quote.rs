@@ -30,6 +30,9 @@
 //! Always use a single space after a block quote marker (`>`).
 //! Never use lazy continuation.
 //!
+//! When [spoilers][crate::construct::spoiler] are turned on, `>!` does not
+//! form a block quote marker.
+//!
 //! ## Tokens
 //!
 //! *   [`BlockQuote`][Name::BlockQuote]
@@ -124,6 +127,11 @@ pub fn cont_before(tokenizer: &mut Tokenizer) -> State {
 ///      ^
 /// ```
 pub fn cont_after(tokenizer: &mut Tokenizer) -> State {
+    // `>!` starts a spoiler instead.
+    if tokenizer.parse_state.options.constructs.spoiler && tokenizer.current == Some(b'!') {
+        return State::Nok;
+    }
+
     if let Some(b'\t' | b' ') = tokenizer.current {
         tokenizer.enter(Name::SpaceOrTab);
         tokenizer.consume();
@@ -0,0 +1,258 @@
+//! Block quote (fenced) occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Block quote (fenced) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! block_quote_fenced ::= fence_open *( eol *line ) [ eol fence_close ]
+//!
+//! fence_open ::= sequence *space_or_tab
+//! ; Restriction: the closing fence is the first line that is only a fence.
+//! fence_close ::= sequence *space_or_tab
+//! sequence ::= '>>>'
+//! ```
+//!
+//! This is the multiline block quote that GitLab supports.
+//! The lines between the fences can contain any flow or containers, without
+//! `>` markers.
+//! As with [block quotes][block_quote], a fenced block quote is a container:
+//! it takes the fences, while the rest of the document between them is flow.
+//! Fenced block quotes do not nest: the first line that is only a fence closes
+//! the fenced block quote.
+//! If there is no closing fence, the fenced block quote continues to the end
+//! of the document or the container it is in.
+//!
+//! The sequence is exactly three greater than signs (`>`).
+//! More or fewer, or other characters on the fence lines, form a
+//! [block quote][block_quote] instead.
+//! An opening fence cannot directly follow a block quote marker, so `>>>>`
+//! is four block quotes, not a block quote and a fence.
+//!
+//! ## HTML
+//!
+//! Block quote (fenced) relates to the `<blockquote>` element in HTML.
+//! See [*§ 4.4.4 The `blockquote` element*][html-blockquote] in the HTML spec
+//! for more info.
+//!
+//! ## Recommendation
+//!
+//! It is recommended to use [block quotes][block_quote] instead, which work
+//! everywhere.
+//!
+//! ## Tokens
+//!
+//! *   [`BlockQuote`][Name::BlockQuote]
+//! *   [`BlockQuoteFencedFence`][Name::BlockQuoteFencedFence]
+//! *   [`BlockQuoteFencedFenceSequence`][Name::BlockQuoteFencedFenceSequence]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Multiline blockquote* in GitLab Flavored Markdown](https://docs.gitlab.com/ee/user/markdown.html#multiline-blockquote)
+//!
+//! [document]: crate::construct::document
+//! [block_quote]: crate::construct::block_quote
+//! [html-blockquote]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-blockquote-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::{BLOCK_QUOTE_FENCED_SEQUENCE_SIZE, TAB_SIZE};
+
+/// Start of fenced block quote.
+///
+/// ```markdown
+/// > | >>>
+///     ^
+///   | a
+///   | >>>
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    // Not directly after a block quote marker: `>>>>` is four block quotes.
+    if tokenizer.parse_state.options.constructs.block_quote_fenced
+        && tokenizer.previous != Some(b'>')
+    {
+        tokenizer.enter(Name::BlockQuote);
+        tokenizer.attempt(
+            State::Next(StateName::BlockQuoteFencedStartAfter),
+            State::Nok,
+        );
+        State::Retry(StateName::BlockQuoteFencedFenceStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening fence.
+///
+/// ```markdown
+/// > | >>>
+///        ^
+///   | a
+///   | >>>
+/// ```
+pub fn start_after(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as open.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = BLOCK_QUOTE_FENCED_SEQUENCE_SIZE;
+    State::Ok
+}
+
+/// Start of fenced block quote continuation.
+///
+/// ```markdown
+///   | >>>
+/// > | a
+///     ^
+/// > | >>>
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    // Closed on the previous line.
+    if tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size
+        == 0
+    {
+        State::Nok
+    } else {
+        tokenizer.attempt(
+            State::Next(StateName::BlockQuoteFencedContClose),
+            State::Next(StateName::BlockQuoteFencedContAfter),
+        );
+        State::Retry(StateName::BlockQuoteFencedFenceStart)
+    }
+}
+
+/// After closing fence.
+///
+/// ```markdown
+///   | >>>
+///   | a
+/// > | >>>
+///        ^
+/// ```
+pub fn cont_close(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as closed, so that the next line exits it.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = 0;
+    State::Ok
+}
+
+/// At continuation that is not a closing fence.
+///
+/// ```markdown
+///   | >>>
+/// > | a
+///     ^
+///   | >>>
+/// ```
+pub fn cont_after(_tokenizer: &mut Tokenizer) -> State {
+    State::Ok
+}
+
+/// Start of fence, at optional whitespace.
+///
+/// ```markdown
+/// > | >>>
+///     ^
+///   | a
+/// > | >>>
+///     ^
+/// ```
+pub fn fence_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::BlockQuoteFencedFence);
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::BlockQuoteFencedFenceBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab_min_max(
+            tokenizer,
+            1,
+            if tokenizer.parse_state.options.constructs.code_indented {
+                TAB_SIZE - 1
+            } else {
+                usize::MAX
+            },
+        ))
+    } else {
+        State::Retry(StateName::BlockQuoteFencedFenceBefore)
+    }
+}
+
+/// At sequence, after optional whitespace.
+///
+/// ```markdown
+/// > | >>>
+///     ^
+///   | a
+/// > | >>>
+///     ^
+/// ```
+pub fn fence_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'>') {
+        tokenizer.enter(Name::BlockQuoteFencedFenceSequence);
+        State::Retry(StateName::BlockQuoteFencedFenceSequence)
+    } else {
+        State::Nok
+    }
+}
+
+/// In sequence.
+///
+/// ```markdown
+/// > | >>>
+///     ^
+///   | a
+/// > | >>>
+///     ^
+/// ```
+pub fn fence_sequence(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'>') {
+        tokenizer.tokenize_state.size += 1;
+        tokenizer.consume();
+        State::Next(StateName::BlockQuoteFencedFenceSequence)
+    } else if tokenizer.tokenize_state.size == BLOCK_QUOTE_FENCED_SEQUENCE_SIZE {
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.exit(Name::BlockQuoteFencedFenceSequence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::BlockQuoteFencedFenceAfter),
+                State::Nok,
+            );
+            State::Retry(space_or_tab(tokenizer))
+        } else {
+            State::Retry(StateName::BlockQuoteFencedFenceAfter)
+        }
+    } else {
+        tokenizer.tokenize_state.size = 0;
+        State::Nok
+    }
+}
+
+/// After sequence, after optional whitespace.
+///
+/// ```markdown
+/// > | >>>
+///        ^
+///   | a
+/// > | >>>
+///        ^
+/// ```
+pub fn fence_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::BlockQuoteFencedFence);
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
@@ -0,0 +1,539 @@
+//! Citation occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Citation forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: a bracketed citation or locator cannot be followed by `(`
+//! ; or `[`.
+//! citation ::= bracketed | bare
+//!
+//! bracketed ::= '[' item *( ';' item ) ']'
+//! item ::= *space_or_tab ( '-' '@' key | [ prefix ] '@' key ) [ [ ',' ] *space_or_tab [ locator ] ]
+//! ; Restriction: cannot end in `\`.
+//! prefix ::= 1*( byte - eol - '[' - ']' - ';' - '@' )
+//! locator ::= 1*( byte - eol - '[' - ']' - ';' )
+//!
+//! ; Restriction: must not be preceded by an ASCII alphanumeric or `_`.
+//! bare ::= '@' key [ *space_or_tab '[' 1*( byte - eol - '[' - ']' ) ']' ]
+//!
+//! ; Restriction: punctuation in a key must be followed by an ASCII
+//! ; alphanumeric, `_`, or a non-ASCII byte.
+//! key ::= key_byte *( *key_punctuation key_byte )
+//! key_byte ::= ascii_alphanumeric | '_' | %x80-FF
+//! key_punctuation ::= ':' | '.' | '#' | '$' | '%' | '&' | '-' | '+' | '?' | '<' | '>' | '~' | '/'
+//! ```
+//!
+//! This construct is the citation syntax of Pandoc.
+//! A key (such as `doe99` in `@doe99`) refers to an entry in a
+//! bibliography.
+//! Bracketed citations can cite several works, separated by `;`, each with
+//! some text before it (the prefix, such as `see`), and a locator after it
+//! (such as `p. 33`), which is typically separated from the key by a comma.
+//! A `-` before `@` suppresses the name of the author.
+//! Bare citations occur in the text (such as in `@doe99 says`) and can have
+//! a locator in brackets after them (such as in `@doe99 [p. 33] says`).
+//!
+//! Prefixes and locators are taken as they are: they cannot contain markdown.
+//!
+//! ## HTML
+//!
+//! Citations do not relate to elements in HTML.
+//! By default, they are compiled to `<span>`s with a `citation` class and a
+//! `data-cites` attribute with the keys, which contain the citation as it is
+//! in the source, like Pandoc does when it does not process citations.
+//! Pass [`citation_resolve`][crate::CompileOptions::citation_resolve] to
+//! format citations against a bibliography.
+//!
+//! ## Recommendation
+//!
+//! Citations are not part of `CommonMark` or GFM.
+//! They are mostly found in academic documents written for Pandoc.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`Citation`][Name::Citation]
+//! *   [`CitationItem`][Name::CitationItem]
+//! *   [`CitationKey`][Name::CitationKey]
+//! *   [`CitationKeyMarker`][Name::CitationKeyMarker]
+//! *   [`CitationLocator`][Name::CitationLocator]
+//! *   [`CitationLocatorMarker`][Name::CitationLocatorMarker]
+//! *   [`CitationMarker`][Name::CitationMarker]
+//! *   [`CitationPrefix`][Name::CitationPrefix]
+//! *   [`CitationSuppressAuthorMarker`][Name::CitationSuppressAuthorMarker]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Citation syntax* in Pandoc](https://pandoc.org/MANUAL.html#citation-syntax)
+//!
+//! [text]: crate::construct::text
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::{Kind, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of citation.
+///
+/// ```markdown
+/// > | a [@b] c
+///       ^
+/// > | a @b c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if !tokenizer.parse_state.options.constructs.citation {
+        return State::Nok;
+    }
+
+    match tokenizer.current {
+        Some(b'[') => {
+            tokenizer.enter(Name::Citation);
+            tokenizer.enter(Name::CitationMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::CitationMarker);
+            State::Next(StateName::CitationItemBefore)
+        }
+        Some(b'@')
+            if !matches!(
+                tokenizer.previous,
+                Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | 0x80..=0xFF)
+            ) =>
+        {
+            tokenizer.enter(Name::Citation);
+            tokenizer.enter(Name::CitationItem);
+            State::Retry(StateName::CitationKeyMarker)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After `[` or `;`, before item.
+///
+/// ```markdown
+/// > | a [@b; @c] d
+///        ^    ^
+/// ```
+pub fn item_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::CitationItemStart), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::CitationItemStart)
+    }
+}
+
+/// At item.
+///
+/// ```markdown
+/// > | a [@b; -@c] d
+///        ^    ^
+/// ```
+pub fn item_start(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']' | b';') => State::Nok,
+        Some(b'@') => {
+            tokenizer.enter(Name::CitationItem);
+            State::Retry(StateName::CitationKeyMarker)
+        }
+        Some(b'-') => {
+            tokenizer.enter(Name::CitationItem);
+            tokenizer.attempt(
+                State::Next(StateName::CitationKeyMarker),
+                State::Next(StateName::CitationPrefixStart),
+            );
+            State::Retry(StateName::CitationSuppressAuthor)
+        }
+        Some(_) => {
+            tokenizer.enter(Name::CitationItem);
+            State::Retry(StateName::CitationPrefixStart)
+        }
+    }
+}
+
+/// At `-`, which suppresses the author if `@` follows.
+///
+/// ```markdown
+/// > | a [-@b] c
+///        ^
+/// ```
+pub fn suppress_author(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::CitationSuppressAuthorMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::CitationSuppressAuthorMarker);
+    State::Next(StateName::CitationSuppressAuthorAfter)
+}
+
+/// After `-`, at `@`.
+///
+/// ```markdown
+/// > | a [-@b] c
+///         ^
+/// ```
+pub fn suppress_author_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'@') {
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// At prefix.
+///
+/// ```markdown
+/// > | a [see @b] c
+///        ^
+/// ```
+pub fn prefix_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::CitationPrefix);
+    State::Retry(StateName::CitationPrefixInside)
+}
+
+/// In prefix.
+///
+/// ```markdown
+/// > | a [see @b] c
+///        ^^^^
+/// ```
+pub fn prefix_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']' | b';') => State::Nok,
+        // An escaped `@` is not a citation.
+        Some(b'@') if tokenizer.previous == Some(b'\\') => State::Nok,
+        Some(b'@') => {
+            tokenizer.exit(Name::CitationPrefix);
+            State::Retry(StateName::CitationKeyMarker)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::CitationPrefixInside)
+        }
+    }
+}
+
+/// At `@`.
+///
+/// ```markdown
+/// > | a [@b] c
+///        ^
+/// ```
+pub fn key_marker(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::CitationKeyMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::CitationKeyMarker);
+    State::Next(StateName::CitationKeyStart)
+}
+
+/// After `@`, at key.
+///
+/// ```markdown
+/// > | a [@b] c
+///         ^
+/// ```
+pub fn key_start(tokenizer: &mut Tokenizer) -> State {
+    if matches!(
+        tokenizer.current,
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | 0x80..=0xFF)
+    ) {
+        tokenizer.enter(Name::CitationKey);
+        tokenizer.consume();
+        State::Next(StateName::CitationKeyInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In key.
+///
+/// ```markdown
+/// > | a [@b:c] d
+///         ^^^
+/// ```
+pub fn key_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | 0x80..=0xFF) => {
+            tokenizer.consume();
+            State::Next(StateName::CitationKeyInside)
+        }
+        Some(
+            b'#' | b'$' | b'%' | b'&' | b'+' | b'-' | b'.' | b'/' | b':' | b'<' | b'>' | b'?'
+            | b'~',
+        ) => {
+            tokenizer.attempt(
+                State::Next(StateName::CitationKeyInside),
+                State::Next(StateName::CitationKeyEnd),
+            );
+            State::Retry(StateName::CitationKeyPunctuation)
+        }
+        _ => State::Retry(StateName::CitationKeyEnd),
+    }
+}
+
+/// In punctuation in key.
+///
+/// ```markdown
+/// > | a [@b:c] d
+///          ^
+/// ```
+pub fn key_punctuation(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(
+            b'#' | b'$' | b'%' | b'&' | b'+' | b'-' | b'.' | b'/' | b':' | b'<' | b'>' | b'?'
+            | b'~',
+        ) => {
+            tokenizer.consume();
+            State::Next(StateName::CitationKeyPunctuation)
+        }
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | 0x80..=0xFF) => State::Ok,
+        _ => State::Nok,
+    }
+}
+
+/// After key.
+///
+/// ```markdown
+/// > | a [@b] c
+///          ^
+/// > | a @b c
+///         ^
+/// ```
+pub fn key_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::CitationKey);
+
+    if bracketed(tokenizer) {
+        State::Retry(StateName::CitationKeyAfter)
+    } else if matches!(tokenizer.current, Some(b'\t' | b' ' | b'[')) {
+        tokenizer.attempt(
+            State::Next(StateName::CitationBareEnd),
+            State::Next(StateName::CitationBareEnd),
+        );
+        State::Retry(StateName::CitationBareLocatorBefore)
+    } else {
+        State::Retry(StateName::CitationBareEnd)
+    }
+}
+
+/// After key in a bracketed citation, at `,`, locator, or end of item.
+///
+/// ```markdown
+/// > | a [@b, c] d
+///          ^
+/// ```
+pub fn key_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b',') => {
+            tokenizer.enter(Name::CitationLocatorMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::CitationLocatorMarker);
+            State::Next(StateName::CitationLocatorBefore)
+        }
+        Some(b';' | b']') => State::Retry(StateName::CitationItemEnd),
+        Some(_) => State::Retry(StateName::CitationLocatorBefore),
+    }
+}
+
+/// Before locator.
+///
+/// ```markdown
+/// > | a [@b, c] d
+///           ^
+/// ```
+pub fn locator_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::CitationLocatorStart), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::CitationLocatorStart)
+    }
+}
+
+/// At locator.
+///
+/// ```markdown
+/// > | a [@b, c] d
+///            ^
+/// ```
+pub fn locator_start(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b';' | b']') => State::Retry(StateName::CitationItemEnd),
+        Some(_) => {
+            tokenizer.enter(Name::CitationLocator);
+            State::Retry(StateName::CitationLocatorInside)
+        }
+    }
+}
+
+/// In locator.
+///
+/// ```markdown
+/// > | a [@b, c] d
+///            ^
+/// ```
+pub fn locator_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b';' | b']') => {
+            tokenizer.exit(Name::CitationLocator);
+            State::Retry(StateName::CitationItemEnd)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::CitationLocatorInside)
+        }
+    }
+}
+
+/// At `;` or `]`, after item.
+///
+/// ```markdown
+/// > | a [@b; @c] d
+///          ^    ^
+/// ```
+pub fn item_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::CitationItem);
+    tokenizer.enter(Name::CitationMarker);
+    let more = tokenizer.current == Some(b';');
+    tokenizer.consume();
+    tokenizer.exit(Name::CitationMarker);
+
+    if more {
+        State::Next(StateName::CitationItemBefore)
+    } else {
+        State::Next(StateName::CitationAfter)
+    }
+}
+
+/// After `]`.
+///
+/// ```markdown
+/// > | a [@b] c
+///           ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'(' | b'[')) {
+        State::Nok
+    } else {
+        tokenizer.exit(Name::Citation);
+        State::Ok
+    }
+}
+
+/// After key in a bare citation, before locator.
+///
+/// ```markdown
+/// > | a @b [c] d
+///          ^
+/// ```
+pub fn bare_locator_before(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::CitationBareLocatorOpen), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::CitationBareLocatorOpen)
+    }
+}
+
+/// At `[` of locator in a bare citation.
+///
+/// ```markdown
+/// > | a @b [c] d
+///          ^
+/// ```
+pub fn bare_locator_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::CitationMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::CitationMarker);
+        State::Next(StateName::CitationBareLocatorStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at locator in a bare citation.
+///
+/// ```markdown
+/// > | a @b [c] d
+///           ^
+/// ```
+pub fn bare_locator_start(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::CitationLocator);
+            State::Retry(StateName::CitationBareLocatorInside)
+        }
+    }
+}
+
+/// In locator in a bare citation.
+///
+/// ```markdown
+/// > | a @b [c] d
+///           ^
+/// ```
+pub fn bare_locator_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b']') => {
+            tokenizer.exit(Name::CitationLocator);
+            tokenizer.enter(Name::CitationMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::CitationMarker);
+            State::Next(StateName::CitationBareLocatorAfter)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::CitationBareLocatorInside)
+        }
+    }
+}
+
+/// After locator in a bare citation.
+///
+/// ```markdown
+/// > | a @b [c] d
+///             ^
+/// ```
+pub fn bare_locator_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'(' | b'[')) {
+        State::Nok
+    } else {
+        State::Ok
+    }
+}
+
+/// At end of bare citation.
+///
+/// ```markdown
+/// > | a @b c
+///         ^
+/// ```
+pub fn bare_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::CitationItem);
+    tokenizer.exit(Name::Citation);
+    State::Ok
+}
+
+/// Check whether the current citation is bracketed (not bare).
+fn bracketed(tokenizer: &Tokenizer) -> bool {
+    let mut index = tokenizer.events.len();
+
+    while index > 0 {
+        index -= 1;
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Enter && event.name == Name::Citation {
+            return tokenizer.parse_state.bytes[event.point.index] == b'[';
+        }
+    }
+
+    unreachable!("expected citation")
+}
@@ -146,15 +146,15 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
 ///   |     bbb
 /// ```
 pub fn further_start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.lazy || tokenizer.pierce {
-        return State::Nok;
-    }
-
+    // Whether a line is lazy or pierced is only known at its start, not at
+    // the eol before it.
     if tokenizer.current == Some(b'\n') {
         tokenizer.enter(Name::LineEnding);
         tokenizer.consume();
         tokenizer.exit(Name::LineEnding);
         State::Next(StateName::CodeIndentedFurtherStart)
+    } else if tokenizer.lazy || tokenizer.pierce {
+        State::Nok
     } else {
         tokenizer.attempt(State::Ok, State::Next(StateName::CodeIndentedFurtherBegin));
         State::Retry(space_or_tab_min_max(tokenizer, TAB_SIZE, TAB_SIZE))
@@ -71,6 +71,9 @@ pub fn chunk_inside(tokenizer: &mut Tokenizer) -> State {
         }
         _ => {
             tokenizer.consume();
+            // Content is tokenized again when it is subtokenized, so here
+            // only the line ending matters.
+            tokenizer.consume_while(|_| true);
             State::Next(StateName::ContentChunkInside)
         }
     }
@@ -182,7 +185,7 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Result<Option<Subresult>, message::
     let result = subtokenize(
         &mut tokenizer.events,
         tokenizer.parse_state,
-        &Some(Content::Content),
+        Some(&Content::Content),
     )?;
 
     Ok(Some(result))
@@ -0,0 +1,404 @@
+//! Critic markup occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Critic markup sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! critic_markup_sequence_open ::= '{' ('++' | '--' | '~~' | '==' | '>>')
+//! critic_markup_sequence_close ::= ('++' | '--' | '~~' | '==' | '<<') '}'
+//! critic_markup_sequence_separator ::= '~>'
+//! ```
+//!
+//! A closing sequence is matched with the closest opening sequence of the
+//! same kind before it, as long as there is something between them, to form
+//! an addition (`{++a++}`), deletion (`{--a--}`), substitution
+//! (`{~~a~>b~~}`), highlight (`{==a==}`), or comment (`{>>a<<}`).
+//! A substitution must contain a separator, with something before and after
+//! it.
+//! Otherwise sequences are turned into data.
+//!
+//! ## HTML
+//!
+//! Critic markup does not relate to elements in HTML.
+//! How it is compiled depends on
+//! [`critic_markup_mode`][crate::CompileOptions::critic_markup_mode].
+//! By default, changes are shown: additions are compiled to
+//! [`<ins>`][html-ins], deletions to [`<del>`][html-del], substitutions to
+//! both, highlights to [`<mark>`][html-mark], and comments to a
+//! `<span class="critic-comment">` element.
+//! Changes can also be accepted or rejected, in which case only the
+//! resulting text is compiled, and comments are dropped.
+//!
+//! ## Recommendation
+//!
+//! Critic markup is not part of `CommonMark` or GFM.
+//! It is meant for editing and reviewing documents, and is not supported
+//! widely, so it is recommended to accept or reject the changes in documents
+//! that are published.
+//!
+//! ## Tokens
+//!
+//! *   [`CriticMarkupAddition`][Name::CriticMarkupAddition]
+//! *   [`CriticMarkupComment`][Name::CriticMarkupComment]
+//! *   [`CriticMarkupDeletion`][Name::CriticMarkupDeletion]
+//! *   [`CriticMarkupHighlight`][Name::CriticMarkupHighlight]
+//! *   [`CriticMarkupSequence`][Name::CriticMarkupSequence]
+//! *   [`CriticMarkupSubstitution`][Name::CriticMarkupSubstitution]
+//! *   [`CriticMarkupText`][Name::CriticMarkupText]
+//!
+//! ## References
+//!
+//! *   [*CriticMarkup* spec](https://github.com/CriticMarkup/CriticMarkup-toolkit/blob/master/README.md)
+//!
+//! [text]: crate::construct::text
+//! [html-ins]: https://html.spec.whatwg.org/multipage/edits.html#the-ins-element
+//! [html-del]: https://html.spec.whatwg.org/multipage/edits.html#the-del-element
+//! [html-mark]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-mark-element
+
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use alloc::{vec, vec::Vec};
+
+/// Kind of sequence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Sequence {
+    /// Opening sequence, with its marker.
+    Open(u8),
+    /// Closing sequence, with the marker of the opening sequence it matches.
+    Close(u8),
+    /// Separator (`~>`).
+    Separator,
+}
+
+/// At start of sequence.
+///
+/// ```markdown
+/// > | {++a++}
+///     ^   ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.critic_markup {
+        match tokenizer.current {
+            Some(b'{') => {
+                tokenizer.enter(Name::CriticMarkupSequence);
+                tokenizer.consume();
+                State::Next(StateName::CriticMarkupOpen)
+            }
+            Some(b'+' | b'-' | b'<' | b'=' | b'~') => {
+                tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+                tokenizer.enter(Name::CriticMarkupSequence);
+                tokenizer.consume();
+                State::Next(StateName::CriticMarkupClose)
+            }
+            _ => State::Nok,
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After `{`, at first marker.
+///
+/// ```markdown
+/// > | {++a++}
+///      ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if let Some(b'+' | b'-' | b'=' | b'>' | b'~') = tokenizer.current {
+        tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+        tokenizer.consume();
+        State::Next(StateName::CriticMarkupOpenInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// After first marker, at second marker.
+///
+/// ```markdown
+/// > | {++a++}
+///       ^
+/// ```
+pub fn open_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
+        tokenizer.tokenize_state.marker = 0;
+        tokenizer.consume();
+        tokenizer.exit(Name::CriticMarkupSequence);
+        tokenizer.register_resolver(ResolveName::CriticMarkup);
+        State::Ok
+    } else {
+        tokenizer.tokenize_state.marker = 0;
+        State::Nok
+    }
+}
+
+/// After first marker of a closing sequence or separator, at second marker.
+///
+/// ```markdown
+/// > | {++a++}
+///          ^
+/// > | {~~a~>b~~}
+///          ^
+/// ```
+pub fn close(tokenizer: &mut Tokenizer) -> State {
+    let marker = tokenizer.tokenize_state.marker;
+    tokenizer.tokenize_state.marker = 0;
+
+    if marker == b'~' && tokenizer.current == Some(b'>') {
+        tokenizer.consume();
+        tokenizer.exit(Name::CriticMarkupSequence);
+        tokenizer.register_resolver(ResolveName::CriticMarkup);
+        State::Ok
+    } else if tokenizer.current == Some(marker) {
+        tokenizer.consume();
+        State::Next(StateName::CriticMarkupCloseInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// After markers of a closing sequence, at `}`.
+///
+/// ```markdown
+/// > | {++a++}
+///           ^
+/// ```
+pub fn close_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'}') {
+        tokenizer.consume();
+        tokenizer.exit(Name::CriticMarkupSequence);
+        tokenizer.register_resolver(ResolveName::CriticMarkup);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// Resolve sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    // Opening sequences and separators that can still be matched, with the
+    // events they are in, so that say, an addition doesn’t start in one link,
+    // and end in another.
+    let mut openers: Vec<(usize, u8, Vec<usize>)> = vec![];
+    let mut separators: Vec<(usize, Vec<usize>)> = vec![];
+    let mut stack = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.name == Name::CriticMarkupSequence {
+            // Skip the exit.
+            index += 1;
+
+            match kind(tokenizer.parse_state.bytes, event.point.index) {
+                Sequence::Open(marker) => openers.push((index - 1, marker, stack.clone())),
+                Sequence::Separator => separators.push((index - 1, stack.clone())),
+                Sequence::Close(marker) => {
+                    let close = index - 1;
+                    let position = openers.iter().rposition(|d| d.1 == marker && d.2 == stack);
+
+                    if let Some(position) = position {
+                        let open = openers[position].0;
+                        // Substitutions need a separator, with something on
+                        // either side.
+                        let separator = if marker == b'~' {
+                            separators
+                                .iter()
+                                .find(|d| d.0 > open && d.1 == stack)
+                                .map(|d| d.0)
+                                .filter(|d| open + 2 < *d && d + 2 < close)
+                        } else {
+                            None
+                        };
+
+                        // There must be something in it.
+                        if open + 2 < close && (marker != b'~' || separator.is_some()) {
+                            // Openers and separators in it can no longer
+                            // match.
+                            for (unused, _, _) in openers.split_off(position + 1) {
+                                to_data(tokenizer, unused);
+                            }
+
+                            openers.pop();
+                            separators.retain(|d| {
+                                if d.0 < open {
+                                    return true;
+                                }
+
+                                if Some(d.0) != separator {
+                                    to_data(tokenizer, d.0);
+                                }
+
+                                false
+                            });
+                            match_sequences(tokenizer, open, separator, close, marker);
+                        } else {
+                            to_data(tokenizer, close);
+                        }
+                    } else {
+                        to_data(tokenizer, close);
+                    }
+                }
+            }
+        } else if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            stack.pop();
+        }
+
+        index += 1;
+    }
+
+    // Mark remaining sequences as data.
+    for (open, _, _) in openers {
+        to_data(tokenizer, open);
+    }
+
+    for (separator, _) in separators {
+        to_data(tokenizer, separator);
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Get the kind of the sequence that starts at `index` in `bytes`.
+fn kind(bytes: &[u8], index: usize) -> Sequence {
+    match (bytes[index], bytes[index + 1]) {
+        (b'{', marker) => Sequence::Open(marker),
+        (b'~', b'>') => Sequence::Separator,
+        (b'<', _) => Sequence::Close(b'>'),
+        (marker, _) => Sequence::Close(marker),
+    }
+}
+
+/// Turn a sequence, of which the enter is at `index`, into data.
+fn to_data(tokenizer: &mut Tokenizer, index: usize) {
+    tokenizer.events[index].name = Name::Data;
+    tokenizer.events[index + 1].name = Name::Data;
+}
+
+/// Match sequences.
+fn match_sequences(
+    tokenizer: &mut Tokenizer,
+    open: usize,
+    separator: Option<usize>,
+    close: usize,
+    marker: u8,
+) {
+    let name = match marker {
+        b'+' => Name::CriticMarkupAddition,
+        b'-' => Name::CriticMarkupDeletion,
+        b'~' => Name::CriticMarkupSubstitution,
+        b'=' => Name::CriticMarkupHighlight,
+        _ => Name::CriticMarkupComment,
+    };
+    let open_enter = tokenizer.events[open].point.clone();
+    let open_exit = tokenizer.events[open + 1].point.clone();
+    let close_enter = tokenizer.events[close].point.clone();
+    let close_exit = tokenizer.events[close + 1].point.clone();
+
+    tokenizer.map.add(
+        open,
+        2,
+        vec![
+            Event {
+                kind: Kind::Enter,
+                name: name.clone(),
+                point: open_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CriticMarkupSequence,
+                point: open_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::CriticMarkupSequence,
+                point: open_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CriticMarkupText,
+                point: open_exit,
+                link: None,
+            },
+        ],
+    );
+
+    if let Some(separator) = separator {
+        let separator_enter = tokenizer.events[separator].point.clone();
+        let separator_exit = tokenizer.events[separator + 1].point.clone();
+
+        tokenizer.map.add(
+            separator,
+            2,
+            vec![
+                Event {
+                    kind: Kind::Exit,
+                    name: Name::CriticMarkupText,
+                    point: separator_enter.clone(),
+                    link: None,
+                },
+                Event {
+                    kind: Kind::Enter,
+                    name: Name::CriticMarkupSequence,
+                    point: separator_enter,
+                    link: None,
+                },
+                Event {
+                    kind: Kind::Exit,
+                    name: Name::CriticMarkupSequence,
+                    point: separator_exit.clone(),
+                    link: None,
+                },
+                Event {
+                    kind: Kind::Enter,
+                    name: Name::CriticMarkupText,
+                    point: separator_exit,
+                    link: None,
+                },
+            ],
+        );
+    }
+
+    tokenizer.map.add(
+        close,
+        2,
+        vec![
+            Event {
+                kind: Kind::Exit,
+                name: Name::CriticMarkupText,
+                point: close_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CriticMarkupSequence,
+                point: close_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::CriticMarkupSequence,
+                point: close_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name,
+                point: close_exit,
+                link: None,
+            },
+        ],
+    );
+}
@@ -0,0 +1,360 @@
+//! Custom block occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Custom blocks form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! custom_block ::= custom_block_fenced | custom_block_prefixed
+//!
+//! custom_block_fenced ::= fence_open *( eol *line ) [ eol fence_close ]
+//! fence_open ::= marker [ 1*space_or_tab info ]
+//! ; Restriction: the closing fence is the first line that is only a fence.
+//! fence_close ::= marker *space_or_tab
+//! ; Restriction: no line endings.
+//! info ::= 1*byte
+//!
+//! custom_block_prefixed ::= prefix *line *( eol prefix *line )
+//! prefix ::= marker [ space_or_tab ]
+//!
+//! ; Restriction: one of the registered markers.
+//! marker ::= 1*line
+//! ```
+//!
+//! Custom blocks are registered with
+//! [`custom_blocks`][crate::ParseOptions::custom_blocks], each as a marker
+//! and a [kind][crate::CustomBlockKind].
+//! At the start of a line, the longest registered marker is used.
+//!
+//! As with [block quotes][block_quote], custom blocks are containers: they
+//! take the fences or prefixes, while the rest of the document is flow.
+//! Fenced custom blocks work like [fenced block quotes][block_quote_fenced]:
+//! they do not nest, as the first line that is only a fence closes them.
+//! If there is no closing fence, the fenced custom block continues to the
+//! end of the document or the container it is in.
+//! Prefixed custom blocks work like block quotes: they can nest, and the
+//! prefix can be missing on lazy paragraph lines.
+//!
+//! Registered markers take precedence over the containers that are built in.
+//!
+//! ## HTML
+//!
+//! Custom blocks do not relate to elements in HTML.
+//! They are compiled to a `<div>` element with the name of the construct as
+//! its class, and the info string of the opening fence, if any, as its
+//! `data-info` attribute.
+//!
+//! ## Recommendation
+//!
+//! Custom blocks are not part of `CommonMark` or GFM.
+//! They are meant to support the syntax extensions of a platform without
+//! forking this project, so it is recommended to only use them in documents
+//! written for that platform.
+//!
+//! ## Tokens
+//!
+//! *   [`CustomBlock`][Name::CustomBlock]
+//! *   [`CustomBlockFence`][Name::CustomBlockFence]
+//! *   [`CustomBlockFenceInfo`][Name::CustomBlockFenceInfo]
+//! *   [`CustomBlockMarker`][Name::CustomBlockMarker]
+//! *   [`CustomBlockPrefix`][Name::CustomBlockPrefix]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! [document]: crate::construct::document
+//! [block_quote]: crate::construct::block_quote
+//! [block_quote_fenced]: crate::construct::block_quote_fenced
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::{Container, Tokenizer};
+use crate::util::{
+    constant::TAB_SIZE,
+    custom_block::{find, CustomBlockKind},
+};
+
+/// Start of custom block.
+///
+/// ```markdown
+/// > | !!! a
+///     ^
+///   | b
+///   | !!!
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.custom_blocks.is_empty() {
+        State::Nok
+    } else {
+        tokenizer.enter(Name::CustomBlock);
+        tokenizer.attempt(State::Next(StateName::CustomBlockStartAfter), State::Nok);
+        indent(tokenizer, StateName::CustomBlockOpenBefore)
+    }
+}
+
+/// At marker of opening, after optional whitespace.
+///
+/// ```markdown
+/// > | !!! a
+///     ^
+///   | b
+///   | !!!
+/// ```
+pub fn open_before(tokenizer: &mut Tokenizer) -> State {
+    if let Some(custom_block) = find(
+        &tokenizer.parse_state.options.custom_blocks,
+        tokenizer.parse_state.bytes,
+        tokenizer.point.index,
+    ) {
+        tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .kind = Container::CustomBlock(custom_block);
+        State::Retry(StateName::CustomBlockMarkerStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening.
+///
+/// ```markdown
+/// > | !!! a
+///          ^
+///   | b
+///   | !!!
+/// ```
+pub fn start_after(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as open.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = 1;
+    State::Ok
+}
+
+/// Start of custom block continuation.
+///
+/// ```markdown
+///   | !!! a
+/// > | b
+///     ^
+/// > | !!!
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    let container = &tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued];
+
+    // Closed on the previous line.
+    if container.size == 0 {
+        State::Nok
+    } else if kind(tokenizer) == CustomBlockKind::Fence {
+        tokenizer.attempt(
+            State::Next(StateName::CustomBlockContClose),
+            State::Next(StateName::CustomBlockContAfter),
+        );
+        indent(tokenizer, StateName::CustomBlockContBefore)
+    } else {
+        indent(tokenizer, StateName::CustomBlockContBefore)
+    }
+}
+
+/// At marker of continuation, after optional whitespace.
+///
+/// ```markdown
+///   | !!! a
+///   | b
+/// > | !!!
+///     ^
+/// ```
+pub fn cont_before(tokenizer: &mut Tokenizer) -> State {
+    let options = &tokenizer.parse_state.options;
+
+    if let Container::CustomBlock(custom_block) = tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .kind
+    {
+        if tokenizer.parse_state.bytes[tokenizer.point.index..]
+            .starts_with(options.custom_blocks[custom_block].marker.as_bytes())
+        {
+            return State::Retry(StateName::CustomBlockMarkerStart);
+        }
+    }
+
+    State::Nok
+}
+
+/// After closing fence.
+///
+/// ```markdown
+///   | !!! a
+///   | b
+/// > | !!!
+///        ^
+/// ```
+pub fn cont_close(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as closed, so that the next line exits it.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = 0;
+    State::Ok
+}
+
+/// At continuation of a fenced custom block that is not a closing fence.
+///
+/// ```markdown
+///   | !!! a
+/// > | b
+///     ^
+///   | !!!
+/// ```
+pub fn cont_after(_tokenizer: &mut Tokenizer) -> State {
+    State::Ok
+}
+
+/// At marker.
+///
+/// ```markdown
+/// > | !!! a
+///     ^
+/// > | % b
+///     ^
+/// ```
+pub fn marker_start(tokenizer: &mut Tokenizer) -> State {
+    let kind = kind(tokenizer);
+
+    tokenizer.enter(if kind == CustomBlockKind::Fence {
+        Name::CustomBlockFence
+    } else {
+        Name::CustomBlockPrefix
+    });
+    tokenizer.enter(Name::CustomBlockMarker);
+
+    if let Container::CustomBlock(custom_block) = tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .kind
+    {
+        tokenizer.tokenize_state.size = tokenizer.parse_state.options.custom_blocks[custom_block]
+            .marker
+            .len();
+    }
+
+    State::Retry(StateName::CustomBlockMarkerInside)
+}
+
+/// In marker.
+///
+/// ```markdown
+/// > | !!! a
+///     ^^^
+/// > | % b
+///     ^
+/// ```
+pub fn marker_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.tokenize_state.size > 0 {
+        tokenizer.tokenize_state.size -= 1;
+        tokenizer.consume();
+        State::Next(StateName::CustomBlockMarkerInside)
+    } else {
+        tokenizer.exit(Name::CustomBlockMarker);
+
+        if kind(tokenizer) == CustomBlockKind::Fence {
+            if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+                tokenizer.attempt(State::Next(StateName::CustomBlockFenceAfter), State::Nok);
+                State::Retry(space_or_tab(tokenizer))
+            } else {
+                State::Retry(StateName::CustomBlockFenceAfter)
+            }
+        } else {
+            if let Some(b'\t' | b' ') = tokenizer.current {
+                tokenizer.enter(Name::SpaceOrTab);
+                tokenizer.consume();
+                tokenizer.exit(Name::SpaceOrTab);
+            }
+
+            tokenizer.exit(Name::CustomBlockPrefix);
+            State::Ok
+        }
+    }
+}
+
+/// After marker of fence, after optional whitespace.
+///
+/// ```markdown
+/// > | !!! a
+///         ^
+///   | b
+/// > | !!!
+///        ^
+/// ```
+pub fn fence_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::CustomBlockFence);
+            State::Ok
+        }
+        // Only opening fences, of containers that are not open yet, can have
+        // an info string, after whitespace.
+        _ if tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size
+            == 0
+            && matches!(tokenizer.previous, Some(b'\t' | b' ')) =>
+        {
+            tokenizer.enter(Name::CustomBlockFenceInfo);
+            State::Retry(StateName::CustomBlockFenceInfo)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In info string.
+///
+/// ```markdown
+/// > | !!! a
+///         ^
+///   | b
+///   | !!!
+/// ```
+pub fn fence_info(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::CustomBlockFenceInfo);
+            tokenizer.exit(Name::CustomBlockFence);
+            State::Ok
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::CustomBlockFenceInfo)
+        }
+    }
+}
+
+/// Parse optional whitespace before a marker, then continue at `name`.
+fn indent(tokenizer: &mut Tokenizer, name: StateName) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(name), State::Nok);
+        State::Retry(space_or_tab_min_max(
+            tokenizer,
+            1,
+            if tokenizer.parse_state.options.constructs.code_indented {
+                TAB_SIZE - 1
+            } else {
+                usize::MAX
+            },
+        ))
+    } else {
+        State::Retry(name)
+    }
+}
+
+/// Get the kind of the current custom block.
+fn kind(tokenizer: &Tokenizer) -> CustomBlockKind {
+    if let Container::CustomBlock(custom_block) = tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .kind
+    {
+        tokenizer.parse_state.options.custom_blocks[custom_block].kind
+    } else {
+        unreachable!("expected custom block container")
+    }
+}
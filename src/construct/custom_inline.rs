@@ -0,0 +1,238 @@
+//! Custom inline occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Custom inline sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: one of the registered delimiters.
+//! custom_inline_sequence ::= 1*line
+//! ```
+//!
+//! Custom inline constructs are registered with
+//! [`custom_inlines`][crate::ParseOptions::custom_inlines], each as a pair
+//! of an opening and a closing delimiter.
+//! At each place in text, the longest registered delimiter is a sequence.
+//! A closing sequence is matched with the closest opening sequence of the
+//! same construct before it to form that construct, as long as there is
+//! something between them.
+//! When the opening and closing delimiters are the same, a sequence closes
+//! if it can, and opens otherwise.
+//! Otherwise sequences are turned into data.
+//!
+//! Registered delimiters take precedence over the constructs that are built
+//! in.
+//!
+//! ## HTML
+//!
+//! Custom inline constructs do not relate to elements in HTML.
+//! They are compiled to a `<span>` element with the name of the construct
+//! as its class.
+//!
+//! ## Recommendation
+//!
+//! Custom inline constructs are not part of `CommonMark` or GFM.
+//! They are meant to support the syntax extensions of a platform without
+//! forking this project, so it is recommended to only use them in documents
+//! written for that platform.
+//!
+//! ## Tokens
+//!
+//! *   [`CustomInline`][Name::CustomInline]
+//! *   [`CustomInlineSequence`][Name::CustomInlineSequence]
+//! *   [`CustomInlineText`][Name::CustomInlineText]
+//!
+//! [text]: crate::construct::text
+
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    custom_inline::sequence_size,
+    slice::{Position, Slice},
+};
+use alloc::{vec, vec::Vec};
+
+/// At start of sequence.
+///
+/// ```markdown
+/// > | %%a%%
+///     ^  ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    let size = sequence_size(
+        &tokenizer.parse_state.options.custom_inlines,
+        tokenizer.parse_state.bytes,
+        tokenizer.point.index,
+    );
+
+    if size == 0 {
+        State::Nok
+    } else {
+        tokenizer.tokenize_state.size = size;
+        tokenizer.enter(Name::CustomInlineSequence);
+        State::Retry(StateName::CustomInlineInside)
+    }
+}
+
+/// In sequence.
+///
+/// ```markdown
+/// > | %%a%%
+///     ^^ ^^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.tokenize_state.size > 0 {
+        tokenizer.tokenize_state.size -= 1;
+        tokenizer.consume();
+        State::Next(StateName::CustomInlineInside)
+    } else {
+        tokenizer.exit(Name::CustomInlineSequence);
+        tokenizer.register_resolver(ResolveName::CustomInline);
+        State::Ok
+    }
+}
+
+/// Resolve sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let parse_state = tokenizer.parse_state;
+    let custom_inlines = &parse_state.options.custom_inlines;
+    // Opening sequences that can still be matched, with the construct they
+    // open, and the events they are in, so that a construct doesn’t start
+    // in say, one link, and end in another.
+    let mut openers: Vec<(usize, usize, Vec<usize>)> = vec![];
+    let mut stack = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.name == Name::CustomInlineSequence {
+            let slice = Slice::from_position(
+                parse_state.bytes,
+                &Position::from_exit_event(&tokenizer.events, index + 1),
+            );
+            let value = slice.as_str();
+            // Skip the exit.
+            index += 1;
+
+            if let Some(position) = openers
+                .iter()
+                .rposition(|d| d.2 == stack && custom_inlines[d.1].close == value)
+            {
+                let open = openers[position].0;
+                let close = index - 1;
+
+                // There must be something in the construct.
+                if open + 2 < close {
+                    // Openers in other events can no longer match.
+                    for (unused, _, _) in openers.split_off(position + 1) {
+                        to_data(tokenizer, unused);
+                    }
+
+                    openers.pop();
+                    match_sequences(tokenizer, open, close);
+                } else {
+                    to_data(tokenizer, close);
+                }
+            } else if let Some(custom_inline) = custom_inlines.iter().position(|d| d.open == value)
+            {
+                openers.push((index - 1, custom_inline, stack.clone()));
+            } else {
+                to_data(tokenizer, index - 1);
+            }
+        } else if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            stack.pop();
+        }
+
+        index += 1;
+    }
+
+    // Mark remaining sequences as data.
+    for (open, _, _) in openers {
+        to_data(tokenizer, open);
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Turn a sequence, of which the enter is at `index`, into data.
+fn to_data(tokenizer: &mut Tokenizer, index: usize) {
+    tokenizer.events[index].name = Name::Data;
+    tokenizer.events[index + 1].name = Name::Data;
+}
+
+/// Match two sequences.
+fn match_sequences(tokenizer: &mut Tokenizer, open: usize, close: usize) {
+    let open_enter = tokenizer.events[open].point.clone();
+    let open_exit = tokenizer.events[open + 1].point.clone();
+    let close_enter = tokenizer.events[close].point.clone();
+    let close_exit = tokenizer.events[close + 1].point.clone();
+
+    tokenizer.map.add(
+        open,
+        2,
+        vec![
+            Event {
+                kind: Kind::Enter,
+                name: Name::CustomInline,
+                point: open_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CustomInlineSequence,
+                point: open_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::CustomInlineSequence,
+                point: open_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CustomInlineText,
+                point: open_exit,
+                link: None,
+            },
+        ],
+    );
+    tokenizer.map.add(
+        close,
+        2,
+        vec![
+            Event {
+                kind: Kind::Exit,
+                name: Name::CustomInlineText,
+                point: close_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::CustomInlineSequence,
+                point: close_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::CustomInlineSequence,
+                point: close_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::CustomInline,
+                point: close_exit,
+                link: None,
+            },
+        ],
+    );
+}
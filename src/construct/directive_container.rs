@@ -0,0 +1,391 @@
+//! Directive (container) occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (container) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! directive_container ::= fence_open *( eol *line ) [ eol fence_close ]
+//!
+//! fence_open ::= sequence directive *space_or_tab
+//! ; Restriction: the number of markers in the closing fence sequence must be
+//! ; equal to or greater than the number of markers in the opening fence
+//! ; sequence.
+//! fence_close ::= sequence *space_or_tab
+//! sequence ::= 3*':'
+//!
+//! ; See the `partial_directive` construct for the BNF of `directive`.
+//! ```
+//!
+//! This is the container block directive of the generic directives
+//! proposal, as supported by `remark-directive`.
+//! See [`directive`][directive] for more info on names, labels, and
+//! attributes.
+//!
+//! As with [block quotes (fenced)][block_quote_fenced], a directive
+//! (container) is a container: it takes the fences, while the rest of the
+//! document between them is flow.
+//! To nest directives (container), use more markers for the outer fences:
+//! the first line that is only a fence with enough markers closes the
+//! directive.
+//! If there is no closing fence, the directive (container) continues to the
+//! end of the document or the container it is in.
+//!
+//! ## HTML
+//!
+//! Directives do not relate to anything in HTML.
+//! A directive (container) is compiled to a `<div>` element, with the name
+//! in a `data-directive` attribute, and its attributes.
+//! The label, if there is one, is compiled to a `<p>` element before the
+//! content.
+//!
+//! ## Recommendation
+//!
+//! Directives are not part of `CommonMark` or GFM.
+//! They are meant to be handled by tools that know what each name means.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveContainer`][Name::DirectiveContainer]
+//! *   [`DirectiveContainerFence`][Name::DirectiveContainerFence]
+//! *   [`DirectiveContainerSequence`][Name::DirectiveContainerSequence]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-container.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-container.js)
+//!
+//! [document]: crate::construct::document
+//! [directive]: crate::construct::partial_directive
+//! [block_quote_fenced]: crate::construct::block_quote_fenced
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::{DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN, TAB_SIZE};
+
+/// Start of directive (container).
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+///   | :::
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_container {
+        tokenizer.enter(Name::DirectiveContainer);
+        tokenizer.enter(Name::DirectiveContainerFence);
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerStartAfter),
+            State::Next(StateName::DirectiveContainerNok),
+        );
+        State::Retry(StateName::DirectiveContainerSequenceStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening sequence.
+///
+/// ```markdown
+/// > | :::a
+///        ^
+///   | b
+///   | :::
+/// ```
+pub fn start_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_4 = Name::DirectiveContainer;
+    tokenizer.attempt(
+        State::Next(StateName::DirectiveContainerAfterName),
+        State::Next(StateName::DirectiveContainerNok),
+    );
+    State::Retry(StateName::DirectiveNameStart)
+}
+
+/// After name.
+///
+/// ```markdown
+/// > | :::a[b]
+///         ^
+///   | c
+///   | :::
+/// ```
+pub fn after_name(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerAfterLabel),
+            State::Next(StateName::DirectiveContainerAfterLabel),
+        );
+        State::Retry(StateName::DirectiveLabelStart)
+    } else {
+        State::Retry(StateName::DirectiveContainerAfterLabel)
+    }
+}
+
+/// After label.
+///
+/// ```markdown
+/// > | :::a[b]{c}
+///            ^
+///   | d
+///   | :::
+/// ```
+pub fn after_label(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'{') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerAfterAttributes),
+            State::Next(StateName::DirectiveContainerAfterAttributes),
+        );
+        State::Retry(StateName::AttributeListDirectiveStart)
+    } else {
+        State::Retry(StateName::DirectiveContainerAfterAttributes)
+    }
+}
+
+/// After attributes, at optional whitespace.
+///
+/// ```markdown
+/// > | :::a[b]{c}
+///               ^
+///   | d
+///   | :::
+/// ```
+pub fn after_attributes(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerOpenEnd),
+            State::Next(StateName::DirectiveContainerNok),
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::DirectiveContainerOpenEnd)
+    }
+}
+
+/// After opening fence, after optional whitespace.
+///
+/// ```markdown
+/// > | :::a[b]{c}
+///               ^
+///   | d
+///   | :::
+/// ```
+pub fn open_end(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::DirectiveContainerFence);
+        tokenizer.tokenize_state.token_4 = Name::Data;
+        // Mark the container as open, with the size of its sequence.
+        tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size = tokenizer.tokenize_state.size_c;
+        tokenizer.tokenize_state.size_c = 0;
+        State::Ok
+    } else {
+        State::Retry(StateName::DirectiveContainerNok)
+    }
+}
+
+/// At something that wasn’t a directive (container).
+///
+/// ```markdown
+/// > | :::1
+///        ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_4 = Name::Data;
+    tokenizer.tokenize_state.size_c = 0;
+    State::Nok
+}
+
+/// Start of directive (container) continuation.
+///
+/// ```markdown
+///   | :::a
+/// > | b
+///     ^
+/// > | :::
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    // Closed on the previous line.
+    if tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size
+        == 0
+    {
+        State::Nok
+    } else {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerContClose),
+            State::Next(StateName::DirectiveContainerContAfter),
+        );
+        State::Retry(StateName::DirectiveContainerCloseStart)
+    }
+}
+
+/// After closing fence.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn cont_close(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as closed, so that the next line exits it.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = 0;
+    State::Ok
+}
+
+/// At continuation that is not a closing fence.
+///
+/// ```markdown
+///   | :::a
+/// > | b
+///     ^
+///   | :::
+/// ```
+pub fn cont_after(_tokenizer: &mut Tokenizer) -> State {
+    State::Ok
+}
+
+/// Start of closing fence.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn close_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::DirectiveContainerFence);
+    tokenizer.attempt(
+        State::Next(StateName::DirectiveContainerCloseSequenceAfter),
+        State::Nok,
+    );
+    State::Retry(StateName::DirectiveContainerSequenceStart)
+}
+
+/// After closing sequence, at optional whitespace.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn close_sequence_after(tokenizer: &mut Tokenizer) -> State {
+    let size = tokenizer.tokenize_state.size_c;
+    tokenizer.tokenize_state.size_c = 0;
+
+    if size
+        < tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size
+    {
+        State::Nok
+    } else if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerCloseEnd),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::DirectiveContainerCloseEnd)
+    }
+}
+
+/// After closing fence, after optional whitespace.
+///
+/// ```markdown
+///   | :::a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn close_end(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::DirectiveContainerFence);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// Start of sequence, at optional whitespace.
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_start(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveContainerSequenceBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab_min_max(
+            tokenizer,
+            1,
+            if tokenizer.parse_state.options.constructs.code_indented {
+                TAB_SIZE - 1
+            } else {
+                usize::MAX
+            },
+        ))
+    } else {
+        State::Retry(StateName::DirectiveContainerSequenceBefore)
+    }
+}
+
+/// At sequence, after optional whitespace.
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DirectiveContainerSequence);
+        State::Retry(StateName::DirectiveContainerSequenceInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In sequence.
+///
+/// ```markdown
+/// > | :::a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.tokenize_state.size_c += 1;
+        tokenizer.consume();
+        State::Next(StateName::DirectiveContainerSequenceInside)
+    } else if tokenizer.tokenize_state.size_c >= DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN {
+        tokenizer.exit(Name::DirectiveContainerSequence);
+        State::Ok
+    } else {
+        tokenizer.tokenize_state.size_c = 0;
+        State::Nok
+    }
+}
@@ -0,0 +1,200 @@
+//! Directive (leaf) occurs in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (leaf) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! directive_leaf ::= *space_or_tab '::' directive *space_or_tab
+//!
+//! ; See the `partial_directive` construct for the BNF of that part.
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! This is the leaf block directive of the generic directives proposal, as
+//! supported by `remark-directive`.
+//! See [`directive`][directive] for more info on names, labels, and
+//! attributes.
+//!
+//! ## HTML
+//!
+//! Directives do not relate to anything in HTML.
+//! A directive (leaf) is compiled to a `<div>` element, with the name in a
+//! `data-directive` attribute, its attributes, and its label as content.
+//!
+//! ## Recommendation
+//!
+//! Directives are not part of `CommonMark` or GFM.
+//! They are meant to be handled by tools that know what each name means.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveLeaf`][Name::DirectiveLeaf]
+//! *   [`DirectiveMarker`][Name::DirectiveMarker]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-leaf.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-leaf.js)
+//!
+//! [flow]: crate::construct::flow
+//! [directive]: crate::construct::partial_directive
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::TAB_SIZE;
+
+/// Start of directive (leaf).
+///
+/// ```markdown
+/// > | ::a[b]
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_leaf {
+        tokenizer.enter(Name::DirectiveLeaf);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DirectiveLeafBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.options.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DirectiveLeafBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `:`.
+///
+/// ```markdown
+/// > | ::a[b]
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DirectiveMarker);
+        tokenizer.consume();
+        State::Next(StateName::DirectiveLeafMarker)
+    } else {
+        State::Nok
+    }
+}
+
+/// After first `:`, at second.
+///
+/// ```markdown
+/// > | ::a[b]
+///      ^
+/// ```
+pub fn marker(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.consume();
+        tokenizer.exit(Name::DirectiveMarker);
+        tokenizer.tokenize_state.token_4 = Name::DirectiveLeaf;
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveLeafAfterName),
+            State::Next(StateName::DirectiveLeafNok),
+        );
+        State::Next(StateName::DirectiveNameStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After name.
+///
+/// ```markdown
+/// > | ::a[b]
+///        ^
+/// ```
+pub fn after_name(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveLeafAfterLabel),
+            State::Next(StateName::DirectiveLeafAfterLabel),
+        );
+        State::Retry(StateName::DirectiveLabelStart)
+    } else {
+        State::Retry(StateName::DirectiveLeafAfterLabel)
+    }
+}
+
+/// After label.
+///
+/// ```markdown
+/// > | ::a[b]{c}
+///           ^
+/// ```
+pub fn after_label(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'{') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveLeafAfterAttributes),
+            State::Next(StateName::DirectiveLeafAfterAttributes),
+        );
+        State::Retry(StateName::AttributeListDirectiveStart)
+    } else {
+        State::Retry(StateName::DirectiveLeafAfterAttributes)
+    }
+}
+
+/// After attributes, at optional whitespace.
+///
+/// ```markdown
+/// > | ::a[b]{c}
+///              ^
+/// ```
+pub fn after_attributes(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::DirectiveLeafEnd), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::DirectiveLeafEnd)
+    }
+}
+
+/// After directive (leaf), after optional whitespace.
+///
+/// ```markdown
+/// > | ::a[b]{c}
+///              ^
+/// ```
+pub fn end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_4 = Name::Data;
+
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::DirectiveLeaf);
+        // Feel free to interrupt.
+        tokenizer.interrupt = false;
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// At something that wasn’t a directive (leaf).
+///
+/// ```markdown
+/// > | ::1
+///       ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_4 = Name::Data;
+    State::Nok
+}
@@ -0,0 +1,136 @@
+//! Directive (text) occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Directive (text) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: cannot be preceded by `:`.
+//! ; Restriction: cannot be followed by `:`.
+//! directive_text ::= ':' directive
+//!
+//! ; See the `partial_directive` construct for the BNF of that part.
+//! ```
+//!
+//! This is the text directive of the generic directives proposal, as
+//! supported by `remark-directive`.
+//! See [`directive`][directive] for more info on names, labels, and
+//! attributes.
+//!
+//! ## HTML
+//!
+//! Directives do not relate to anything in HTML.
+//! A directive (text) is compiled to a `<span>` element, with the name in a
+//! `data-directive` attribute, its attributes, and its label as content.
+//!
+//! ## Recommendation
+//!
+//! Directives are not part of `CommonMark` or GFM.
+//! They are meant to be handled by tools that know what each name means.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`DirectiveText`][Name::DirectiveText]
+//! *   [`DirectiveMarker`][Name::DirectiveMarker]
+//! *   see [`directive`][directive] for more
+//!
+//! ## References
+//!
+//! *   [`directive-text.js` in `micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive/blob/main/dev/lib/directive-text.js)
+//!
+//! [text]: crate::construct::text
+//! [directive]: crate::construct::partial_directive
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of directive (text).
+///
+/// ```markdown
+/// > | a :b[c] d
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.directive_text
+        && tokenizer.current == Some(b':')
+        && tokenizer.previous != Some(b':')
+    {
+        tokenizer.tokenize_state.token_4 = Name::DirectiveText;
+        tokenizer.enter(Name::DirectiveText);
+        tokenizer.enter(Name::DirectiveMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DirectiveMarker);
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveTextAfterName),
+            State::Next(StateName::DirectiveTextNok),
+        );
+        State::Next(StateName::DirectiveNameStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After name.
+///
+/// ```markdown
+/// > | a :b[c] d
+///         ^
+/// ```
+pub fn after_name(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b':') => State::Retry(StateName::DirectiveTextNok),
+        Some(b'[') => {
+            tokenizer.attempt(
+                State::Next(StateName::DirectiveTextAfterLabel),
+                State::Next(StateName::DirectiveTextAfterLabel),
+            );
+            State::Retry(StateName::DirectiveLabelStart)
+        }
+        _ => State::Retry(StateName::DirectiveTextAfterLabel),
+    }
+}
+
+/// After label.
+///
+/// ```markdown
+/// > | a :b[c]{d} e
+///            ^
+/// ```
+pub fn after_label(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'{') {
+        tokenizer.attempt(
+            State::Next(StateName::DirectiveTextAfter),
+            State::Next(StateName::DirectiveTextAfter),
+        );
+        State::Retry(StateName::AttributeListDirectiveStart)
+    } else {
+        State::Retry(StateName::DirectiveTextAfter)
+    }
+}
+
+/// After directive (text).
+///
+/// ```markdown
+/// > | a :b[c]{d} e
+///               ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::DirectiveText);
+    tokenizer.tokenize_state.token_4 = Name::Data;
+    State::Ok
+}
+
+/// At something that wasn’t a directive (text).
+///
+/// ```markdown
+/// > | a :1
+///        ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.token_4 = Name::Data;
+    State::Nok
+}
@@ -6,6 +6,10 @@
 //! The constructs found in flow are:
 //!
 //! *   [Block quote][crate::construct::block_quote]
+//! *   [Block quote (fenced)][crate::construct::block_quote_fenced]
+//! *   [Custom block][crate::construct::custom_block]
+//! *   [Directive (container)][crate::construct::directive_container]
+//! *   [Fenced div][crate::construct::fenced_div]
 //! *   [List item][crate::construct::list_item]
 //! *   [GFM: Footnote definition][crate::construct::gfm_footnote_definition]
 
@@ -68,6 +72,26 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::BomStart)
 }
 
+/// Start of document, not at the start of the input.
+///
+/// This is used when parsing a part of a document again: the part starts at
+/// a line where no containers are open, and there is no BOM or frontmatter.
+///
+/// ```markdown
+///   | * a
+///   |
+/// > | b
+///     ^
+/// ```
+pub fn resume(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.document_child = Some(Box::new(Tokenizer::new(
+        tokenizer.point.clone(),
+        tokenizer.parse_state,
+    )));
+
+    State::Retry(StateName::DocumentContainerNewBefore)
+}
+
 /// At optional frontmatter.
 ///
 /// ```markdown
@@ -101,6 +125,10 @@ pub fn container_existing_before(tokenizer: &mut Tokenizer) -> State {
 
         let name = match container.kind {
             Container::BlockQuote => StateName::BlockQuoteContStart,
+            Container::BlockQuoteFenced => StateName::BlockQuoteFencedContStart,
+            Container::CustomBlock(_) => StateName::CustomBlockContStart,
+            Container::Directive => StateName::DirectiveContainerContStart,
+            Container::FencedDiv => StateName::FencedDivContStart,
             Container::GfmFootnoteDefinition => StateName::GfmFootnoteDefinitionContStart,
             Container::ListItem => StateName::ListItemContStart,
         };
@@ -156,14 +184,14 @@ pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
     }
 
     // Check for a new container.
-    // Block quote?
+    // Custom block?
     // Add a new container at the end of the stack.
     let tail = tokenizer.tokenize_state.document_container_stack.len();
     tokenizer
         .tokenize_state
         .document_container_stack
         .push(ContainerState {
-            kind: Container::BlockQuote,
+            kind: Container::CustomBlock(0),
             blank_initial: false,
             size: 0,
         });
@@ -173,6 +201,54 @@ pub fn container_new_before(tokenizer: &mut Tokenizer) -> State {
         .document_container_stack
         .swap(tokenizer.tokenize_state.document_continued, tail);
 
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotCustomBlock),
+    );
+    State::Retry(StateName::CustomBlockStart)
+}
+
+/// At new container, but not a custom block.
+//
+/// ```markdown
+/// > | >>>
+///     ^
+/// ```
+pub fn container_new_before_not_custom_block(tokenizer: &mut Tokenizer) -> State {
+    // Block quote (fenced)?
+    // We replace the empty custom block container for this new fenced block
+    // quote one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::BlockQuoteFenced,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotBlockQuoteFenced),
+    );
+    State::Retry(StateName::BlockQuoteFencedStart)
+}
+
+/// At new container, but not a fenced block quote.
+//
+/// ```markdown
+/// > | > a
+///     ^
+/// ```
+pub fn container_new_before_not_block_quote_fenced(tokenizer: &mut Tokenizer) -> State {
+    // Block quote?
+    // We replace the empty fenced block quote container for this new block
+    // quote one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::BlockQuote,
+        blank_initial: false,
+        size: 0,
+    };
+
     tokenizer.attempt(
         State::Next(StateName::DocumentContainerNewAfter),
         State::Next(StateName::DocumentContainerNewBeforeNotBlockQuote),
@@ -230,11 +306,61 @@ pub fn container_new_before_not_list(tokenizer: &mut Tokenizer) -> State {
 /// At new container, but not a block quote, list item, or footnote definition.
 //
 /// ```markdown
-/// > | a
+/// > | :::a
 ///     ^
 /// ```
 pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -> State {
-    // It wasn’t a new block quote, list item, or footnote definition.
+    // Directive (container)?
+    // We replace the empty footnote definition container for this new
+    // directive one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::Directive,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotDirectiveContainer),
+    );
+    State::Retry(StateName::DirectiveContainerStart)
+}
+
+/// At new container, but not a block quote, list item, footnote definition,
+/// or directive.
+//
+/// ```markdown
+/// > | ::: a
+///     ^
+/// ```
+pub fn container_new_before_not_directive_container(tokenizer: &mut Tokenizer) -> State {
+    // Fenced div?
+    // We replace the empty directive container for this new fenced div one.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued] = ContainerState {
+        kind: Container::FencedDiv,
+        blank_initial: false,
+        size: 0,
+    };
+
+    tokenizer.attempt(
+        State::Next(StateName::DocumentContainerNewAfter),
+        State::Next(StateName::DocumentContainerNewBeforeNotFencedDiv),
+    );
+    State::Retry(StateName::FencedDivStart)
+}
+
+/// At new container, but not a block quote, list item, footnote definition,
+/// directive, or fenced div.
+//
+/// ```markdown
+/// > | a
+///     ^
+/// ```
+pub fn container_new_before_not_fenced_div(tokenizer: &mut Tokenizer) -> State {
+    // It wasn’t a new custom block, block quote, list item, footnote
+    // definition, directive, or fenced div.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Drop what was in the middle.
     tokenizer
@@ -254,7 +380,8 @@ pub fn container_new_before_not_footnote_definition(tokenizer: &mut Tokenizer) -
 ///       ^
 /// ```
 pub fn container_new_after(tokenizer: &mut Tokenizer) -> State {
-    // It was a new block quote, list item, or footnote definition.
+    // It was a new custom block, block quote, list item, footnote definition,
+    // directive, or fenced div.
     // Swap the new container (in the middle) with the existing one (at the end).
     // Take the new container.
     let container = tokenizer
@@ -304,26 +431,25 @@ pub fn containers_after(tokenizer: &mut Tokenizer) -> State {
         != tokenizer.tokenize_state.document_container_stack.len();
     child.define_skip(tokenizer.point.clone());
 
-    match tokenizer.current {
-        // Note: EOL is part of data.
-        None => State::Retry(StateName::DocumentFlowEnd),
-        Some(_) => {
-            let current = tokenizer.events.len();
-            let previous = tokenizer.tokenize_state.document_data_index;
-            if let Some(previous) = previous {
-                tokenizer.events[previous].link.as_mut().unwrap().next = Some(current);
-            }
-            tokenizer.tokenize_state.document_data_index = Some(current);
-            tokenizer.enter_link(
-                Name::Data,
-                Link {
-                    previous,
-                    next: None,
-                    content: Content::Flow,
-                },
-            );
-            State::Retry(StateName::DocumentFlowInside)
+    // Note: EOL is part of data.
+    if tokenizer.current.is_none() {
+        State::Retry(StateName::DocumentFlowEnd)
+    } else {
+        let current = tokenizer.events.len();
+        let previous = tokenizer.tokenize_state.document_data_index;
+        if let Some(previous) = previous {
+            tokenizer.events[previous].link.as_mut().unwrap().next = Some(current);
         }
+        tokenizer.tokenize_state.document_data_index = Some(current);
+        tokenizer.enter_link(
+            Name::Data,
+            Link {
+                previous,
+                next: None,
+                content: Content::Flow,
+            },
+        );
+        State::Retry(StateName::DocumentFlowInside)
     }
 }
 
@@ -450,23 +576,20 @@ pub fn flow_end(tokenizer: &mut Tokenizer) -> State {
         debug_assert!(result.is_ok(), "did not expect error when exiting");
     }
 
-    match tokenizer.current {
-        None => {
-            tokenizer.tokenize_state.document_continued = 0;
-            if let Err(message) = exit_containers(tokenizer, &Phase::Eof) {
-                return State::Error(message);
-            }
-            resolve(tokenizer);
-            State::Ok
-        }
-        Some(_) => {
-            tokenizer.tokenize_state.document_continued = 0;
-            tokenizer.tokenize_state.document_lazy_accepting_before =
-                document_lazy_continuation_current;
-            // Containers would only be interrupting if we’ve continued.
-            tokenizer.interrupt = false;
-            State::Retry(StateName::DocumentContainerExistingBefore)
+    if tokenizer.current.is_none() {
+        tokenizer.tokenize_state.document_continued = 0;
+        if let Err(message) = exit_containers(tokenizer, &Phase::Eof) {
+            return State::Error(message);
         }
+        resolve(tokenizer);
+        State::Ok
+    } else {
+        tokenizer.tokenize_state.document_continued = 0;
+        tokenizer.tokenize_state.document_lazy_accepting_before =
+            document_lazy_continuation_current;
+        // Containers would only be interrupting if we’ve continued.
+        tokenizer.interrupt = false;
+        State::Retry(StateName::DocumentContainerExistingBefore)
     }
 }
 
@@ -487,7 +610,14 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), messa
             .take()
             .unwrap_or(State::Next(StateName::FlowStart));
 
-        child.flush(state, false)?;
+        let mut result = child.flush(state, false)?;
+        tokenizer
+            .tokenize_state
+            .abbreviations
+            .append(&mut result.abbreviations);
+        // Only when flow is done, as after a line, its content could still
+        // be interrupted by the next line.
+        child.interrupt = false;
     }
 
     if !stack_close.is_empty() {
@@ -497,7 +627,10 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), messa
 
         while let Some(container) = stack_close.pop() {
             let name = match container.kind {
-                Container::BlockQuote => Name::BlockQuote,
+                Container::BlockQuote | Container::BlockQuoteFenced => Name::BlockQuote,
+                Container::CustomBlock(_) => Name::CustomBlock,
+                Container::Directive => Name::DirectiveContainer,
+                Container::FencedDiv => Name::FencedDiv,
                 Container::GfmFootnoteDefinition => Name::GfmFootnoteDefinition,
                 Container::ListItem => Name::ListItem,
             };
@@ -532,8 +665,6 @@ fn exit_containers(tokenizer: &mut Tokenizer, phase: &Phase) -> Result<(), messa
         tokenizer.tokenize_state.document_exits[index] = Some(exits);
     }
 
-    child.interrupt = false;
-
     Ok(())
 }
 
@@ -623,6 +754,9 @@ fn resolve(tokenizer: &mut Tokenizer) {
     tokenizer
         .resolvers
         .append(&mut child.resolvers.split_off(0));
+    tokenizer
+        .resolvers_after
+        .append(&mut child.resolvers_after.split_off(0));
 
     tokenizer
         .tokenize_state
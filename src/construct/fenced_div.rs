@@ -0,0 +1,443 @@
+//! Fenced div occurs in the [document][] content type.
+//!
+//! ## Grammar
+//!
+//! Fenced div forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! fenced_div ::= fence_open *( eol *line ) [ eol fence_close ]
+//!
+//! fence_open ::= sequence *space_or_tab ( attribute_list | class ) *space_or_tab [ 1*':' *space_or_tab ]
+//! ; Restriction: the closing fence is the first line that is only a fence,
+//! ; and it closes the innermost open fenced div.
+//! fence_close ::= sequence *space_or_tab
+//! sequence ::= 3*':'
+//! class ::= (byte - space_or_tab - eol - '{' - ':') *(byte - space_or_tab - eol)
+//!
+//! ; See the `attribute_list` construct for the BNF of `attribute_list`, which
+//! ; here does not have a colon after the opening brace.
+//! ```
+//!
+//! This is the fenced div of Pandoc.
+//! The attributes are either an attribute list, such as `{#a .b c=d}`, or a
+//! single word, which is used as a class.
+//! The opening fence can be followed by more colons, which do not mean
+//! anything.
+//!
+//! As with [directives (container)][directive_container], a fenced div is a
+//! container: it takes the fences, while the rest of the document between
+//! them is flow.
+//! Unlike directives, fenced divs nest without needing more markers for the
+//! outer fences: a line that is only a fence closes the innermost open fenced
+//! div.
+//! If there is no closing fence, the fenced div continues to the end of the
+//! document or the container it is in.
+//!
+//! Directives and fenced divs both start with colons.
+//! When [directives (container)][directive_container] are turned on, a
+//! name directly after the colons (as in `:::a`) forms a directive; with
+//! whitespace before it (as in `::: a`), or with an attribute list, it forms
+//! a fenced div.
+//!
+//! ## HTML
+//!
+//! Fenced div relates to the `<div>` element in HTML.
+//! See [*§ 4.4.15 The `div` element*][html-div] in the HTML spec for more
+//! info.
+//!
+//! ## Recommendation
+//!
+//! Fenced divs are not part of `CommonMark` or GFM.
+//! They are mostly found in documents written for Pandoc.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`FencedDiv`][Name::FencedDiv]
+//! *   [`FencedDivAttributes`][Name::FencedDivAttributes]
+//! *   [`FencedDivClass`][Name::FencedDivClass]
+//! *   [`FencedDivFence`][Name::FencedDivFence]
+//! *   [`FencedDivSequence`][Name::FencedDivSequence]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//! *   see [`attribute_list`][attribute_list] for more
+//!
+//! ## References
+//!
+//! *   [*Divs and Spans* in the Pandoc manual](https://pandoc.org/MANUAL.html#divs-and-spans)
+//!
+//! [document]: crate::construct::document
+//! [attribute_list]: crate::construct::attribute_list
+//! [directive_container]: crate::construct::directive_container
+//! [html-div]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-div-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::{Container, Tokenizer};
+use crate::util::constant::{FENCED_DIV_SEQUENCE_SIZE_MIN, TAB_SIZE};
+
+/// Start of fenced div.
+///
+/// ```markdown
+/// > | ::: a
+///     ^
+///   | b
+///   | :::
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.fenced_div {
+        tokenizer.enter(Name::FencedDiv);
+        tokenizer.enter(Name::FencedDivFence);
+        tokenizer.attempt(
+            State::Next(StateName::FencedDivStartAfter),
+            State::Next(StateName::FencedDivNok),
+        );
+        State::Retry(StateName::FencedDivSequenceStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening sequence, at optional whitespace.
+///
+/// ```markdown
+/// > | ::: a
+///        ^
+///   | b
+///   | :::
+/// ```
+pub fn start_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::FencedDivAttributesBefore),
+            State::Next(StateName::FencedDivNok),
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::FencedDivAttributesBefore)
+    }
+}
+
+/// At attribute list or class.
+///
+/// ```markdown
+/// > | ::: a
+///         ^
+///   | b
+///   | :::
+/// ```
+pub fn attributes_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'{') => {
+            tokenizer.attempt(
+                State::Next(StateName::FencedDivAttributesAfter),
+                State::Next(StateName::FencedDivNok),
+            );
+            State::Retry(StateName::AttributeListFencedDivStart)
+        }
+        None | Some(b'\t' | b'\n' | b' ' | b':') => State::Retry(StateName::FencedDivNok),
+        Some(_) => {
+            tokenizer.enter(Name::FencedDivClass);
+            tokenizer.consume();
+            State::Next(StateName::FencedDivClassInside)
+        }
+    }
+}
+
+/// In class.
+///
+/// ```markdown
+/// > | ::: ab
+///          ^
+///   | c
+///   | :::
+/// ```
+pub fn class_inside(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\t' | b'\n' | b' ')) {
+        tokenizer.exit(Name::FencedDivClass);
+        State::Retry(StateName::FencedDivAttributesAfter)
+    } else {
+        tokenizer.consume();
+        State::Next(StateName::FencedDivClassInside)
+    }
+}
+
+/// After attribute list or class, at optional whitespace.
+///
+/// ```markdown
+/// > | ::: a :::
+///          ^
+///   | b
+///   | :::
+/// ```
+pub fn attributes_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::FencedDivTrailerBefore),
+            State::Next(StateName::FencedDivNok),
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::FencedDivTrailerBefore)
+    }
+}
+
+/// After attribute list or class, after optional whitespace, at optional
+/// closing colons.
+///
+/// ```markdown
+/// > | ::: a :::
+///           ^
+///   | b
+///   | :::
+/// ```
+pub fn trailer_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::FencedDivSequence);
+        State::Retry(StateName::FencedDivTrailerInside)
+    } else {
+        State::Retry(StateName::FencedDivOpenEnd)
+    }
+}
+
+/// In closing colons.
+///
+/// ```markdown
+/// > | ::: a :::
+///           ^
+///   | b
+///   | :::
+/// ```
+pub fn trailer_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.consume();
+        State::Next(StateName::FencedDivTrailerInside)
+    } else {
+        tokenizer.exit(Name::FencedDivSequence);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::FencedDivOpenEnd),
+                State::Next(StateName::FencedDivNok),
+            );
+            State::Retry(space_or_tab(tokenizer))
+        } else {
+            State::Retry(StateName::FencedDivOpenEnd)
+        }
+    }
+}
+
+/// After opening fence, after optional whitespace.
+///
+/// ```markdown
+/// > | ::: a
+///          ^
+///   | b
+///   | :::
+/// ```
+pub fn open_end(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::FencedDivFence);
+        // Mark the container as open.
+        tokenizer.tokenize_state.document_container_stack
+            [tokenizer.tokenize_state.document_continued]
+            .size = tokenizer.tokenize_state.size_c;
+        tokenizer.tokenize_state.size_c = 0;
+        State::Ok
+    } else {
+        State::Retry(StateName::FencedDivNok)
+    }
+}
+
+/// At something that wasn’t a fenced div.
+///
+/// ```markdown
+/// > | :::
+///        ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.size_c = 0;
+    State::Nok
+}
+
+/// Start of fenced div continuation.
+///
+/// ```markdown
+///   | ::: a
+/// > | b
+///     ^
+/// > | :::
+///     ^
+/// ```
+pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
+    let stack = &tokenizer.tokenize_state.document_container_stack;
+    let index = tokenizer.tokenize_state.document_continued;
+
+    // Closed on the previous line.
+    if stack[index].size == 0 {
+        State::Nok
+    }
+    // A closing fence closes the innermost open fenced div.
+    else if stack[index + 1..]
+        .iter()
+        .any(|d| d.kind == Container::FencedDiv && d.size != 0)
+    {
+        State::Ok
+    } else {
+        tokenizer.attempt(
+            State::Next(StateName::FencedDivContClose),
+            State::Next(StateName::FencedDivContAfter),
+        );
+        State::Retry(StateName::FencedDivCloseStart)
+    }
+}
+
+/// After closing fence.
+///
+/// ```markdown
+///   | ::: a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn cont_close(tokenizer: &mut Tokenizer) -> State {
+    // Mark the container as closed, so that the next line exits it.
+    tokenizer.tokenize_state.document_container_stack
+        [tokenizer.tokenize_state.document_continued]
+        .size = 0;
+    State::Ok
+}
+
+/// At continuation that is not a closing fence.
+///
+/// ```markdown
+///   | ::: a
+/// > | b
+///     ^
+///   | :::
+/// ```
+pub fn cont_after(_tokenizer: &mut Tokenizer) -> State {
+    State::Ok
+}
+
+/// Start of closing fence.
+///
+/// ```markdown
+///   | ::: a
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn close_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::FencedDivFence);
+    tokenizer.attempt(
+        State::Next(StateName::FencedDivCloseSequenceAfter),
+        State::Nok,
+    );
+    State::Retry(StateName::FencedDivSequenceStart)
+}
+
+/// After closing sequence, at optional whitespace.
+///
+/// ```markdown
+///   | ::: a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn close_sequence_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.size_c = 0;
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::FencedDivCloseEnd), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::FencedDivCloseEnd)
+    }
+}
+
+/// After closing fence, after optional whitespace.
+///
+/// ```markdown
+///   | ::: a
+///   | b
+/// > | :::
+///        ^
+/// ```
+pub fn close_end(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        tokenizer.exit(Name::FencedDivFence);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// Start of sequence, at optional whitespace.
+///
+/// ```markdown
+/// > | ::: a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_start(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::FencedDivSequenceBefore), State::Nok);
+        State::Retry(space_or_tab_min_max(
+            tokenizer,
+            1,
+            if tokenizer.parse_state.options.constructs.code_indented {
+                TAB_SIZE - 1
+            } else {
+                usize::MAX
+            },
+        ))
+    } else {
+        State::Retry(StateName::FencedDivSequenceBefore)
+    }
+}
+
+/// At sequence, after optional whitespace.
+///
+/// ```markdown
+/// > | ::: a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::FencedDivSequence);
+        State::Retry(StateName::FencedDivSequenceInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In sequence.
+///
+/// ```markdown
+/// > | ::: a
+///     ^
+///   | b
+/// > | :::
+///     ^
+/// ```
+pub fn sequence_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.tokenize_state.size_c += 1;
+        tokenizer.consume();
+        State::Next(StateName::FencedDivSequenceInside)
+    } else if tokenizer.tokenize_state.size_c >= FENCED_DIV_SEQUENCE_SIZE_MIN {
+        tokenizer.exit(Name::FencedDivSequence);
+        State::Ok
+    } else {
+        tokenizer.tokenize_state.size_c = 0;
+        State::Nok
+    }
+}
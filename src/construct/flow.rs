@@ -10,8 +10,11 @@
 //!
 //! The constructs found in flow are:
 //!
+//! *   [Abbreviation (definition)][crate::construct::abbreviation]
+//! *   [Attribute list][crate::construct::attribute_list]
 //! *   [Blank line][crate::construct::blank_line]
 //! *   [Code (indented)][crate::construct::code_indented]
+//! *   [Directive (leaf)][crate::construct::directive_leaf]
 //! *   [Heading (atx)][crate::construct::heading_atx]
 //! *   [Heading (setext)][crate::construct::heading_setext]
 //! *   [HTML (flow)][crate::construct::html_flow]
@@ -56,7 +59,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         Some(b'*' | b'_') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
+                State::Next(StateName::FlowBeforeAbbreviationDefinition),
             );
             State::Retry(StateName::ThematicBreakStart)
         }
@@ -67,6 +70,13 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::HtmlFlowStart)
         }
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::FlowAfter),
+                State::Next(StateName::FlowBeforeGfmTable),
+            );
+            State::Retry(StateName::DirectiveLeafStart)
+        }
         Some(b'e' | b'i') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
@@ -77,9 +87,9 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
+                State::Next(StateName::FlowBeforeMdxExpression),
             );
-            State::Retry(StateName::MdxExpressionFlowStart)
+            State::Retry(StateName::AttributeListFlowStart)
         }
         // Actual parsing: blank line? Indented code? Indented anything?
         // Tables, setext heading underlines, definitions, and Contents are
@@ -196,11 +206,53 @@ pub fn before_heading_setext(tokenizer: &mut Tokenizer) -> State {
 pub fn before_thematic_break(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeMdxExpression),
+        State::Next(StateName::FlowBeforeAbbreviationDefinition),
     );
     State::Retry(StateName::ThematicBreakStart)
 }
 
+/// At abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^
+/// ```
+pub fn before_abbreviation_definition(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeDirectiveLeaf),
+    );
+    State::Retry(StateName::AbbreviationDefinitionStart)
+}
+
+/// At directive (leaf).
+///
+/// ```markdown
+/// > | ::a
+///     ^
+/// ```
+pub fn before_directive_leaf(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeAttributeList),
+    );
+    State::Retry(StateName::DirectiveLeafStart)
+}
+
+/// At attribute list (flow).
+///
+/// ```markdown
+/// > | {: .a}
+///     ^
+/// ```
+pub fn before_attribute_list(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::FlowAfter),
+        State::Next(StateName::FlowBeforeMdxExpression),
+    );
+    State::Retry(StateName::AttributeListFlowStart)
+}
+
 /// At MDX expression (flow).
 ///
 /// ```markdown
@@ -10,7 +10,9 @@
 //! fence_open ::= sequence *space_or_tab
 //! ; Restriction: markers in `sequence` must match markers in opening sequence.
 //! fence_close ::= sequence *space_or_tab
-//! sequence ::= 3'+' | 3'-'
+//! ; Restriction: `marker` must be one of `frontmatter_markers`, which
+//! ; defaults to `'+'` and `'-'`.
+//! sequence ::= 3marker
 //! ```
 //!
 //! Frontmatter can only occur once.
@@ -29,6 +31,8 @@
 //! As there is no spec for frontmatter in markdown, this extension follows how
 //! YAML frontmatter works on `github.com`.
 //! It also parses TOML frontmatter, just like YAML except that it uses a `+`.
+//! Other markers can be configured with
+//! [`frontmatter_markers`][crate::ParseOptions::frontmatter_markers].
 //!
 //! ## Recommendation
 //!
@@ -73,7 +77,13 @@ use crate::util::constant::FRONTMATTER_SEQUENCE_SIZE;
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Indent not allowed.
     if tokenizer.parse_state.options.constructs.frontmatter
-        && matches!(tokenizer.current, Some(b'+' | b'-'))
+        && tokenizer.current.map_or(false, |byte| {
+            tokenizer
+                .parse_state
+                .options
+                .frontmatter_markers
+                .contains(&byte)
+        })
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.enter(Name::Frontmatter);
@@ -0,0 +1,157 @@
+//! GFM: Alert occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Alerts form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! gfm_alert ::= '[' '!' gfm_alert_type ']' *space_or_tab (eol | eof)
+//!
+//! ; Restriction: case-insensitive.
+//! gfm_alert_type ::= 'caution' | 'important' | 'note' | 'tip' | 'warning'
+//! ```
+//!
+//! The alert is only allowed at the start of the first paragraph in a
+//! [block quote][block_quote], and must be on its own line.
+//! It turns that block quote into an alert.
+//!
+//! ## HTML
+//!
+//! Alerts have no specific element in HTML.
+//! Like on `github.com`, the block quote they are in is compiled to a `<div>`
+//! with the classes `markdown-alert` and `markdown-alert-TYPE` (such as
+//! `markdown-alert-note`) instead of a `<blockquote>`.
+//! The alert itself is compiled to a `<p>` with the class
+//! `markdown-alert-title`, which contains the capitalized type (such as
+//! `Note`).
+//!
+//! ## Recommendation
+//!
+//! It is recommended to use uppercase types (such as `NOTE`), as that is what
+//! GitHub documents.
+//! It is also recommended to use alerts sparingly: they are meant for crucial
+//! information.
+//!
+//! ## Tokens
+//!
+//! *   [`GfmAlert`][Name::GfmAlert]
+//! *   [`GfmAlertMarker`][Name::GfmAlertMarker]
+//! *   [`GfmAlertType`][Name::GfmAlertType]
+//!
+//! ## References
+//!
+//! *   [*Alerts* on `docs.github.com`](https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#alerts)
+//!
+//! [text]: crate::construct::text
+//! [block_quote]: crate::construct::block_quote
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    constant::{GFM_ALERT_TYPES, GFM_ALERT_TYPE_SIZE_MAX},
+    slice::Slice,
+};
+
+/// At start of alert.
+///
+/// ```markdown
+/// > | > [!NOTE]
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.gfm_alert
+        && tokenizer
+            .tokenize_state
+            .document_at_first_paragraph_of_block_quote
+        && tokenizer.current == Some(b'[')
+        && tokenizer.previous.is_none()
+    {
+        tokenizer.enter(Name::GfmAlert);
+        tokenizer.enter(Name::GfmAlertMarker);
+        tokenizer.consume();
+        State::Next(StateName::GfmAlertOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at `!`.
+///
+/// ```markdown
+/// > | > [!NOTE]
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'!') {
+        tokenizer.consume();
+        tokenizer.exit(Name::GfmAlertMarker);
+        tokenizer.enter(Name::GfmAlertType);
+        tokenizer.tokenize_state.start = tokenizer.point.index;
+        State::Next(StateName::GfmAlertInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In type.
+///
+/// ```markdown
+/// > | > [!NOTE]
+///         ^^^^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b']') => {
+            let slice = Slice::from_indices(
+                tokenizer.parse_state.bytes,
+                tokenizer.tokenize_state.start,
+                tokenizer.point.index,
+            );
+            let name = slice.as_str().to_ascii_lowercase();
+            tokenizer.tokenize_state.start = 0;
+
+            if GFM_ALERT_TYPES.contains(&name.as_str()) {
+                tokenizer.exit(Name::GfmAlertType);
+                tokenizer.enter(Name::GfmAlertMarker);
+                tokenizer.consume();
+                tokenizer.exit(Name::GfmAlertMarker);
+                State::Next(StateName::GfmAlertAfter)
+            } else {
+                State::Nok
+            }
+        }
+        Some(b'A'..=b'Z' | b'a'..=b'z')
+            if tokenizer.point.index - tokenizer.tokenize_state.start < GFM_ALERT_TYPE_SIZE_MAX =>
+        {
+            tokenizer.consume();
+            State::Next(StateName::GfmAlertInside)
+        }
+        _ => {
+            tokenizer.tokenize_state.start = 0;
+            State::Nok
+        }
+    }
+}
+
+/// After alert, optionally after whitespace.
+///
+/// ```markdown
+/// > | > [!NOTE]
+///              ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::GfmAlert);
+            State::Ok
+        }
+        Some(b'\t' | b' ') => {
+            tokenizer.attempt(State::Next(StateName::GfmAlertAfter), State::Nok);
+            State::Retry(space_or_tab(tokenizer))
+        }
+        _ => State::Nok,
+    }
+}
@@ -79,7 +79,7 @@ pub fn open(tokenizer: &mut Tokenizer) -> State {
             tokenizer.consume();
             tokenizer.exit(Name::GfmFootnoteCallMarker);
             tokenizer.exit(Name::GfmFootnoteCallLabel);
-            tokenizer.tokenize_state.label_starts.push(LabelStart {
+            tokenizer.push_label_start(LabelStart {
                 kind: LabelKind::GfmFootnote,
                 start: (tokenizer.events.len() - 6, tokenizer.events.len() - 1),
                 inactive: false,
@@ -30,6 +30,43 @@
 //! It is not required for a table to have a body: it can end right after the
 //! delimiter row.
 //!
+//! With the `gfm_table_headless` option, it is also not required for a table
+//! to have a head row: it can start with the delimiter row.
+//! Such tables cannot interrupt paragraphs.
+//!
+//! With the `gfm_table_row_continuation` option, a body row that ends in a
+//! backslash (`\`) is continued by the next row: their cells are joined,
+//! with a line ending between them.
+//! This allows long cells to be spread over several lines:
+//!
+//! ```markdown
+//! | a | b |
+//! | - | - |
+//! | c | d \
+//! | e | f |
+//! ```
+//!
+//! Yields:
+//!
+//! ```html
+//! <table>
+//! <thead>
+//! <tr>
+//! <th>a</th>
+//! <th>b</th>
+//! </tr>
+//! </thead>
+//! <tbody>
+//! <tr>
+//! <td>c
+//! e</td>
+//! <td>d
+//! f</td>
+//! </tr>
+//! </tbody>
+//! </table>
+//! ```
+//!
 //! Each column can be marked with an alignment.
 //! The alignment marker is a colon (`:`) used before and/or after delimiter row
 //! filler.
@@ -207,6 +244,7 @@
 //! *   [`GfmTableDelimiterRow`][Name::GfmTableDelimiterRow]
 //! *   [`GfmTableHead`][Name::GfmTableHead]
 //! *   [`GfmTableRow`][Name::GfmTableRow]
+//! *   [`GfmTableRowContinuation`][Name::GfmTableRowContinuation]
 //! *   [`LineEnding`][Name::LineEnding]
 //!
 //! ## References
@@ -263,6 +301,11 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         {
             State::Retry(StateName::GfmTableBodyRowStart)
         } else {
+            // Header-less tables cannot interrupt paragraphs.
+            if tokenizer.parse_state.options.gfm_table_headless && !tokenizer.interrupt {
+                tokenizer.attempt(State::Ok, State::Next(StateName::GfmTableHeadlessBefore));
+            }
+
             State::Retry(StateName::GfmTableHeadRowBefore)
         }
     } else {
@@ -429,6 +472,18 @@ pub fn head_row_escape(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// Before delimiter row, in a table without head row.
+///
+/// ```markdown
+/// > | | - |
+///     ^
+///   | | a |
+/// ```
+pub fn headless_before(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::GfmTableHead);
+    State::Retry(StateName::GfmTableHeadDelimiterStart)
+}
+
 /// Before delimiter row.
 ///
 /// ```markdown
@@ -625,9 +680,11 @@ pub fn head_delimiter_cell_after(tokenizer: &mut Tokenizer) -> State {
             // Exit when:
             // * there was no `:` or `|` at all (it’s a thematic break or setext
             //   underline instead)
-            // * the header cell count is not the delimiter cell count
+            // * the header cell count is not the delimiter cell count (and
+            //   there is a head row)
             if !tokenizer.tokenize_state.seen
-                || tokenizer.tokenize_state.size != tokenizer.tokenize_state.size_b
+                || (tokenizer.tokenize_state.size != tokenizer.tokenize_state.size_b
+                    && !headless(tokenizer))
             {
                 State::Retry(StateName::GfmTableHeadDelimiterNok)
             } else {
@@ -646,6 +703,23 @@ pub fn head_delimiter_cell_after(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// Whether the current head has no head row, only a delimiter row.
+fn headless(tokenizer: &Tokenizer) -> bool {
+    let mut index = tokenizer.events.len();
+
+    while index > 0 {
+        index -= 1;
+
+        if tokenizer.events[index].kind == Kind::Enter
+            && tokenizer.events[index].name == Name::GfmTableDelimiterRow
+        {
+            break;
+        }
+    }
+
+    index > 0 && tokenizer.events[index - 1].name == Name::GfmTableHead
+}
+
 /// In delimiter row, at a disallowed byte.
 ///
 /// ```markdown
@@ -715,6 +789,13 @@ pub fn body_row_break(tokenizer: &mut Tokenizer) -> State {
             tokenizer.exit(Name::GfmTableCellDivider);
             State::Next(StateName::GfmTableBodyRowBreak)
         }
+        Some(b'\\') if tokenizer.parse_state.options.gfm_table_row_continuation => {
+            tokenizer.check(
+                State::Next(StateName::GfmTableBodyRowContinuation),
+                State::Next(StateName::GfmTableBodyRowBackslash),
+            );
+            State::Retry(StateName::GfmTableBodyRowContinuationBefore)
+        }
         // Anything else is cell content.
         _ => {
             tokenizer.enter(Name::Data);
@@ -737,6 +818,11 @@ pub fn body_row_data(tokenizer: &mut Tokenizer) -> State {
             tokenizer.exit(Name::Data);
             State::Retry(StateName::GfmTableBodyRowBreak)
         }
+        // Check whether a backslash continues the row at the break.
+        Some(b'\\') if tokenizer.parse_state.options.gfm_table_row_continuation => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::GfmTableBodyRowBreak)
+        }
         _ => {
             let name = if tokenizer.current == Some(b'\\') {
                 StateName::GfmTableBodyRowEscape
@@ -767,6 +853,70 @@ pub fn body_row_escape(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// At a backslash in table body row data, which does not continue the row.
+///
+/// ```markdown
+///   | | a    |
+///   | | ---- |
+/// > | | b\-c |
+///          ^
+/// ```
+pub fn body_row_backslash(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::Data);
+    tokenizer.consume();
+    State::Next(StateName::GfmTableBodyRowEscape)
+}
+
+/// At a backslash in table body row, which might continue the row.
+///
+/// ```markdown
+///   | | a |
+///   | | - |
+/// > | | b \
+///           ^
+///   | | c |
+/// ```
+pub fn body_row_continuation_before(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.consume();
+    State::Next(StateName::GfmTableBodyRowContinuationAfter)
+}
+
+/// After a backslash in table body row, which might continue the row.
+///
+/// ```markdown
+///   | | a |
+///   | | - |
+/// > | | b \
+///            ^
+///   | | c |
+/// ```
+pub fn body_row_continuation_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Ok,
+        Some(b'\t' | b' ') => {
+            tokenizer.consume();
+            State::Next(StateName::GfmTableBodyRowContinuationAfter)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// At a backslash that continues a table body row.
+///
+/// ```markdown
+///   | | a |
+///   | | - |
+/// > | | b \
+///           ^
+///   | | c |
+/// ```
+pub fn body_row_continuation(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::GfmTableRowContinuation);
+    tokenizer.consume();
+    tokenizer.exit(Name::GfmTableRowContinuation);
+    State::Next(StateName::GfmTableBodyRowBreak)
+}
+
 /// Resolve GFM table.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut index = 0;
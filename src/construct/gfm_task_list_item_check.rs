@@ -9,6 +9,15 @@
 //! gfm_task_list_item_check ::= '[' (0x09 | ' ' | 'X' | 'x') ']'
 //! ```
 //!
+//! When `gfm_task_list_item_states` is on in
+//! [`ParseOptions`][crate::ParseOptions], checks can also be in other states:
+//!
+//! ```bnf
+//! gfm_task_list_item_check ::= '[' (0x09 | ' ' | 'X' | 'x' | '-' | '~' | '>') ']'
+//! ```
+//!
+//! Those are cancelled (`-`), in progress (`~`), and deferred (`>`).
+//!
 //! The check is only allowed at the start of the first paragraph, optionally
 //! following zero or more definitions or a blank line, in a list item.
 //! The check must be followed by whitespace, which is in turn followed by
@@ -20,6 +29,9 @@
 //! (`type=checkbox`), in HTML.
 //! See [*§ 4.10.5.1.15 Checkbox state (`type=checkbox`)*][html-input-checkbox]
 //! in the HTML spec for more info.
+//! Checks in other states are unchecked checkboxes, and the list item they
+//! are in gets a class for the state: `task-list-item-cancelled`,
+//! `task-list-item-in-progress`, or `task-list-item-deferred`.
 //!
 //! ## Recommendation
 //!
@@ -34,6 +46,7 @@
 //! *   [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
 //! *   [`GfmTaskListItemMarker`][Name::GfmTaskListItemMarker]
 //! *   [`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked]
+//! *   [`GfmTaskListItemValueOther`][Name::GfmTaskListItemValueOther]
 //! *   [`GfmTaskListItemValueUnchecked`][Name::GfmTaskListItemValueUnchecked]
 //!
 //! ## References
@@ -93,6 +106,12 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
             tokenizer.exit(Name::GfmTaskListItemValueChecked);
             State::Next(StateName::GfmTaskListItemCheckClose)
         }
+        Some(b'-' | b'>' | b'~') if tokenizer.parse_state.options.gfm_task_list_item_states => {
+            tokenizer.enter(Name::GfmTaskListItemValueOther);
+            tokenizer.consume();
+            tokenizer.exit(Name::GfmTaskListItemValueOther);
+            State::Next(StateName::GfmTaskListItemCheckClose)
+        }
         _ => State::Nok,
     }
 }
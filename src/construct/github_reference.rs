@@ -0,0 +1,300 @@
+//! GitHub reference occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! GitHub references form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must not be preceded by a unicode alphanumeric, `#`, `-`,
+//! ; `.`, `/`, `@`, or `_`.
+//! ; Restriction: must not be followed by a unicode alphanumeric or `_`.
+//! github_reference ::= issue | commit | repository ( '#' number | '@' sha )
+//!
+//! issue ::= ( '#' | 'GH-' ) number
+//! commit ::= sha
+//!
+//! repository ::= owner '/' name
+//! owner ::= 1*( ascii_alphanumeric | '-' )
+//! name ::= 1*( ascii_alphanumeric | '-' | '.' | '_' )
+//!
+//! number ::= 1*ascii_digit
+//! ; Restriction: must contain at least one digit and at least one letter.
+//! ; Restriction: at most 40 bytes are allowed.
+//! sha ::= 7*( ascii_digit | 'a' | 'b' | 'c' | 'd' | 'e' | 'f' )
+//! ```
+//!
+//! This construct is the reference to an issue, pull request, or commit, as
+//! found on GitHub.
+//! Issues and pull requests are referenced by number (`#1` or `GH-1`), and
+//! commits by SHA (`1a2b3c4`), optionally in another repository (`a/b#1` or
+//! `a/b@1a2b3c4`).
+//! As SHAs must contain a letter and a digit, plain numbers and words are
+//! not references.
+//!
+//! GitHub references are found in text by a resolver, after the whole
+//! document is parsed.
+//! They are not found in links or in images.
+//!
+//! ## HTML
+//!
+//! GitHub references do not relate to elements in HTML.
+//! By default, they are compiled as plain text.
+//! Pass [`github_reference_resolve`][crate::CompileOptions::github_reference_resolve]
+//! to turn them into links.
+//!
+//! ## Recommendation
+//!
+//! GitHub references are not part of `CommonMark` or GFM: the GFM spec
+//! leaves them to the platform.
+//! What a reference refers to depends on where the document is, so it is
+//! recommended to only use them in documents written for that repository.
+//!
+//! ## Tokens
+//!
+//! *   [`GithubReference`][Name::GithubReference]
+//!
+//! ## References
+//!
+//! *   [*Autolinked references and URLs* on GitHub](https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls)
+//!
+//! [text]: crate::construct::text
+
+use crate::event::{Event, Kind, Name};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    char::{after_index, before_index},
+    constant::{GITHUB_REFERENCE_SHA_SIZE_MAX, GITHUB_REFERENCE_SHA_SIZE_MIN},
+};
+use alloc::vec::Vec;
+
+/// Resolve GitHub references.
+///
+/// Find references in data, outside of links and images, and split the data
+/// around them.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    let bytes = tokenizer.parse_state.bytes;
+    let mut index = 0;
+    let mut media = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if matches!(event.name, Name::Image | Name::Link) {
+            if event.kind == Kind::Enter {
+                media += 1;
+            } else {
+                media -= 1;
+            }
+        }
+        // Skip data that is linked to other content, such as destinations,
+        // which is parsed later.
+        else if event.kind == Kind::Exit
+            && event.name == Name::Data
+            && media == 0
+            && tokenizer.events[index - 1].link.is_none()
+        {
+            let mut point = tokenizer.events[index - 1].point.clone();
+            let end = event.point.index;
+            let mut byte_index = point.index;
+            let mut min = byte_index;
+            let mut replace = Vec::new();
+
+            while byte_index < end {
+                if let Some(reference_end) = peek(bytes, byte_index, end) {
+                    // If there is something between the last reference (or
+                    // the start) and this one.
+                    if min != byte_index {
+                        replace.push(Event {
+                            kind: Kind::Enter,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                        point = point.shift_to(bytes, byte_index);
+                        replace.push(Event {
+                            kind: Kind::Exit,
+                            name: Name::Data,
+                            point: point.clone(),
+                            link: None,
+                        });
+                    }
+
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::GithubReference,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    point = point.shift_to(bytes, reference_end);
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::GithubReference,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    byte_index = reference_end;
+                    min = byte_index;
+                } else {
+                    byte_index += 1;
+                }
+            }
+
+            // If there were references.
+            if !replace.is_empty() {
+                // If there are more bytes left.
+                if min < end {
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Data,
+                        point: point.clone(),
+                        link: None,
+                    });
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Data,
+                        point: event.point.clone(),
+                        link: None,
+                    });
+                }
+
+                tokenizer.map.add(index - 1, 2, replace);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Check if there is a reference at `start`, and if so, get where it ends.
+///
+/// ```markdown
+/// > | a b/c#1 d
+///       ^-- from
+///            ^-- to
+/// ```
+fn peek(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    if matches!(
+        before_index(bytes, start),
+        Some(char) if char.is_alphanumeric() || matches!(char, '#' | '-' | '.' | '/' | '@' | '_')
+    ) {
+        return None;
+    }
+
+    let index = if bytes[start] == b'#' {
+        number(bytes, start + 1, end)
+    } else {
+        let issue = if bytes[start..end].starts_with(b"GH-") {
+            number(bytes, start + 3, end)
+        } else {
+            None
+        };
+
+        issue
+            .or_else(|| repository(bytes, start, end))
+            .or_else(|| sha(bytes, start, end))
+    }?;
+
+    if matches!(
+        after_index(bytes, index),
+        Some(char) if char.is_alphanumeric() || char == '_'
+    ) {
+        None
+    } else {
+        Some(index)
+    }
+}
+
+/// Move past a repository, and the number or SHA after it.
+///
+/// ```markdown
+/// > | a b/c#1 d
+///       ^-- from
+///            ^-- to
+/// ```
+fn repository(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut index = start;
+
+    while index < end && matches!(bytes[index], b'-' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z') {
+        index += 1;
+    }
+
+    if index == start || index == end || bytes[index] != b'/' {
+        return None;
+    }
+
+    index += 1;
+    let name = index;
+
+    while index < end
+        && matches!(bytes[index], b'-' | b'.' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z')
+    {
+        index += 1;
+    }
+
+    if index == name || index == end {
+        return None;
+    }
+
+    match bytes[index] {
+        b'#' => number(bytes, index + 1, end),
+        b'@' => sha(bytes, index + 1, end),
+        _ => None,
+    }
+}
+
+/// Move past a number.
+///
+/// ```markdown
+/// > | a #12 b
+///        ^-- from
+///          ^-- to
+/// ```
+fn number(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut index = start;
+
+    while index < end && bytes[index].is_ascii_digit() {
+        index += 1;
+    }
+
+    if index == start {
+        None
+    } else {
+        Some(index)
+    }
+}
+
+/// Move past a SHA.
+///
+/// ```markdown
+/// > | a 1a2b3c4 b
+///       ^-- from
+///              ^-- to
+/// ```
+fn sha(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut index = start;
+    let mut digit = false;
+    let mut letter = false;
+
+    while index < end && matches!(bytes[index], b'0'..=b'9' | b'a'..=b'f') {
+        if bytes[index].is_ascii_digit() {
+            digit = true;
+        } else {
+            letter = true;
+        }
+
+        index += 1;
+    }
+
+    if digit
+        && letter
+        && (GITHUB_REFERENCE_SHA_SIZE_MIN..=GITHUB_REFERENCE_SHA_SIZE_MAX)
+            .contains(&(index - start))
+    {
+        Some(index)
+    } else {
+        None
+    }
+}
@@ -192,6 +192,7 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             enter + 1,
             &[Name::HeadingSetextUnderline],
         );
+        let mut next = exit + 1;
 
         // Find paragraph before
         let paragraph_exit_before = skip::opt_back(
@@ -243,6 +244,10 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                 tokenizer.map.add(enter + 1, exit - enter, vec![]);
                 // Remove old Paragraph:Enter.
                 tokenizer.map.add(exit + 3, 1, vec![]);
+                // Apply these edits now, as an underline after the paragraph
+                // must find where it starts.
+                tokenizer.map.consume(&mut tokenizer.events);
+                next = enter + 1;
             } else {
                 // Swap type.
                 tokenizer.events[enter].name = Name::Paragraph;
@@ -273,7 +278,7 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             }
         }
 
-        enter = skip::to(&tokenizer.events, exit + 1, &[Name::HeadingSetextUnderline]);
+        enter = skip::to(&tokenizer.events, next, &[Name::HeadingSetextUnderline]);
     }
 
     tokenizer.map.consume(&mut tokenizer.events);
@@ -80,10 +80,24 @@
 //! We *can* interrupt and don’t have to care too much about it being
 //! well-formed.
 //!
+//! With the `html_flow_markdown` option, markdown can also be used in
+//! **basic** elements without blank lines, as in PHP Markdown Extra, by
+//! placing a `markdown="1"` attribute on an opening tag that is alone on its
+//! line.
+//! Such HTML (flow) ends at the end of that line, and the attribute is not
+//! part of the HTML:
+//!
+//! ```markdown
+//! <div markdown="1">
+//! This is a paragraph in a `div` and with `code` and *emphasis*.
+//! </div>
+//! ```
+//!
 //! ## Tokens
 //!
 //! *   [`HtmlFlow`][Name::HtmlFlow]
 //! *   [`HtmlFlowData`][Name::HtmlFlowData]
+//! *   [`HtmlFlowMarkdownAttribute`][Name::HtmlFlowMarkdownAttribute]
 //! *   [`LineEnding`][Name::LineEnding]
 //!
 //! ## References
@@ -347,6 +361,16 @@ pub fn tag_name(tokenizer: &mut Tokenizer) -> State {
                     tokenizer.consume();
                     State::Next(StateName::HtmlFlowBasicSelfClosing)
                 } else {
+                    if !closing_tag && tokenizer.parse_state.options.html_flow_markdown {
+                        if let Some((start, end)) = find_markdown_attribute(
+                            tokenizer.parse_state.bytes,
+                            tokenizer.point.index,
+                        ) {
+                            tokenizer.tokenize_state.size_b = start;
+                            tokenizer.tokenize_state.size_c = end;
+                        }
+                    }
+
                     // Do not form containers.
                     tokenizer.concrete = true;
                     State::Retry(StateName::HtmlFlowContinuation)
@@ -640,6 +664,20 @@ pub fn continuation(tokenizer: &mut Tokenizer) -> State {
     } else if tokenizer.tokenize_state.marker == CDATA && tokenizer.current == Some(b']') {
         tokenizer.consume();
         State::Next(StateName::HtmlFlowContinuationCdataInside)
+    } else if tokenizer.tokenize_state.size_c != 0 {
+        if tokenizer.point.index == tokenizer.tokenize_state.size_b {
+            tokenizer.exit(Name::HtmlFlowData);
+            tokenizer.enter(Name::HtmlFlowMarkdownAttribute);
+            State::Retry(StateName::HtmlFlowMarkdownAttribute)
+        } else if matches!(tokenizer.current, None | Some(b'\n')) {
+            tokenizer.exit(Name::HtmlFlowData);
+            tokenizer.tokenize_state.size_b = 0;
+            tokenizer.tokenize_state.size_c = 0;
+            State::Retry(StateName::HtmlFlowContinuationAfter)
+        } else {
+            tokenizer.consume();
+            State::Next(StateName::HtmlFlowContinuation)
+        }
     } else if matches!(tokenizer.tokenize_state.marker, BASIC | COMPLETE)
         && tokenizer.current == Some(b'\n')
     {
@@ -658,6 +696,23 @@ pub fn continuation(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// In markdown attribute.
+///
+/// ```markdown
+/// > | <div markdown="1">
+///         ^^^^^^^^^^^^^
+/// ```
+pub fn markdown_attribute(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.point.index == tokenizer.tokenize_state.size_c {
+        tokenizer.exit(Name::HtmlFlowMarkdownAttribute);
+        tokenizer.enter(Name::HtmlFlowData);
+        State::Retry(StateName::HtmlFlowContinuation)
+    } else {
+        tokenizer.consume();
+        State::Next(StateName::HtmlFlowMarkdownAttribute)
+    }
+}
+
 /// In continuation, at eol.
 ///
 /// ```markdown
@@ -871,3 +926,111 @@ pub fn blank_line_before(tokenizer: &mut Tokenizer) -> State {
     tokenizer.exit(Name::LineEnding);
     State::Next(StateName::BlankLineStart)
 }
+
+/// Find a `markdown="1"` attribute in an opening tag that is alone on its
+/// line, from `index` after its tag name.
+///
+/// Returns the range of the attribute, including the whitespace before it.
+fn find_markdown_attribute(bytes: &[u8], mut index: usize) -> Option<(usize, usize)> {
+    let mut result = None;
+
+    loop {
+        let before = index;
+
+        while matches!(bytes.get(index), Some(b'\t' | b' ')) {
+            index += 1;
+        }
+
+        match bytes.get(index) {
+            Some(b'>') => {
+                index += 1;
+                break;
+            }
+            // Attribute names must be preceded by whitespace.
+            Some(b':' | b'_' | b'A'..=b'Z' | b'a'..=b'z') if index > before => {
+                let name_start = index;
+
+                while let Some(
+                    b'-' | b'.' | b':' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z',
+                ) = bytes.get(index)
+                {
+                    index += 1;
+                }
+
+                let name = &bytes[name_start..index];
+                let mut value: &[u8] = &[];
+                let mut after_name = index;
+
+                while matches!(bytes.get(after_name), Some(b'\t' | b' ')) {
+                    after_name += 1;
+                }
+
+                if bytes.get(after_name) == Some(&b'=') {
+                    index = after_name + 1;
+
+                    while matches!(bytes.get(index), Some(b'\t' | b' ')) {
+                        index += 1;
+                    }
+
+                    let value_start = index;
+
+                    match bytes.get(index) {
+                        Some(&quote @ (b'"' | b'\'')) => {
+                            index += 1;
+
+                            while bytes.get(index) != Some(&quote) {
+                                if matches!(bytes.get(index), None | Some(b'\n' | b'\r')) {
+                                    return None;
+                                }
+
+                                index += 1;
+                            }
+
+                            index += 1;
+                            value = &bytes[value_start + 1..index - 1];
+                        }
+                        None | Some(b'\t' | b'\n' | b'\r' | b' ' | b'<' | b'=' | b'>' | b'`') => {
+                            return None
+                        }
+                        Some(_) => {
+                            while !matches!(
+                                bytes.get(index),
+                                None | Some(
+                                    b'\t'
+                                        | b'\n'
+                                        | b'\r'
+                                        | b' '
+                                        | b'"'
+                                        | b'\''
+                                        | b'<'
+                                        | b'='
+                                        | b'>'
+                                        | b'`'
+                                )
+                            ) {
+                                index += 1;
+                            }
+
+                            value = &bytes[value_start..index];
+                        }
+                    }
+                }
+
+                if name.eq_ignore_ascii_case(b"markdown") && value == b"1" {
+                    result = Some((before, index));
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    while matches!(bytes.get(index), Some(b'\t' | b' ')) {
+        index += 1;
+    }
+
+    if matches!(bytes.get(index), None | Some(b'\n' | b'\r')) {
+        result
+    } else {
+        None
+    }
+}
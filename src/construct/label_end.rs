@@ -6,7 +6,7 @@
 //! (<small>see [construct][crate::construct] for character groups</small>):
 //!
 //! ```bnf
-//! label_end ::= ']' [resource | reference_full | reference_collapsed]
+//! label_end ::= ']' [resource | reference_full | reference_collapsed | attributes]
 //!
 //! resource ::= '(' [space_or_tab_eol] destination [space_or_tab_eol title] [space_or_tab_eol] ')'
 //! reference_full ::= '[' label ']'
@@ -14,6 +14,8 @@
 //!
 //! ; See the `destination`, `title`, and `label` constructs for the BNF of
 //! ; those parts.
+//! ; See the `attribute_list` construct for the BNF of `attributes`, which
+//! ; here do not have a colon after the opening brace.
 //! ```
 //!
 //! See [`destination`][destination], [`label`][label], and [`title`][title]
@@ -100,6 +102,14 @@
 //! using an [autolink][] in a link.
 //! You definitely should not do that.
 //!
+//! When bracketed spans are turned on, a [label start (link)][label_start_link]
+//! and a label end that is directly followed by attributes (as in
+//! `[a]{.b}`) form a bracketed span, as supported by Pandoc.
+//! Bracketed spans take precedence over shortcut references.
+//! Unlike links, they can contain links, and they can be in links.
+//! Without attributes, the brackets are what they would otherwise be: a
+//! link or just brackets.
+//!
 //! ## HTML
 //!
 //! Label end does not, on its own, relate to anything in HTML.
@@ -113,6 +123,9 @@
 //! in which case they form `<sup>` and `<a>` elements in HTML.
 //! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sup] and
 //! [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//! A bracketed span relates to the `<span>` element in HTML.
+//! See [*§ 4.5.26 The `span` element*][html_span] in the HTML spec for more
+//! info.
 //!
 //! ## Recommendation
 //!
@@ -127,6 +140,8 @@
 //!
 //! ## Tokens
 //!
+//! *   [`BracketedSpan`][Name::BracketedSpan]
+//! *   [`BracketedSpanAttributes`][Name::BracketedSpanAttributes]
 //! *   [`Data`][Name::Data]
 //! *   [`GfmFootnoteCall`][Name::GfmFootnoteCall]
 //! *   [`Image`][Name::Image]
@@ -158,6 +173,7 @@
 //! *   [*§ 4.7 Link reference definitions* in `CommonMark`](https://spec.commonmark.org/0.31/#link-reference-definitions)
 //! *   [*§ 6.3 Links* in `CommonMark`](https://spec.commonmark.org/0.31/#links)
 //! *   [*§ 6.4 Images* in `CommonMark`](https://spec.commonmark.org/0.31/#images)
+//! *   [*Divs and Spans* in the Pandoc manual](https://pandoc.org/MANUAL.html#divs-and-spans)
 //!
 //! > 👉 **Note**: Footnotes are not specified in GFM yet.
 //! > See [`github/cmark-gfm#270`](https://github.com/github/cmark-gfm/issues/270)
@@ -178,6 +194,7 @@
 //! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
 //! [html_img]: https://html.spec.whatwg.org/multipage/embedded-content.html#the-img-element
 //! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
+//! [html_span]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-span-element
 
 use crate::construct::partial_space_or_tab_eol::space_or_tab_eol;
 use crate::event::{Event, Kind, Name};
@@ -221,7 +238,12 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             // ```
             //
             // We can’t have that, so it’s just balanced brackets.
-            if label_start.inactive {
+            // Unless it’s a bracketed span, which can contain links.
+            if label_start.inactive
+                && !(tokenizer.parse_state.options.constructs.bracketed_span
+                    && label_start.kind == LabelKind::Link
+                    && tokenizer.parse_state.bytes.get(tokenizer.point.index + 1) == Some(&b'{'))
+            {
                 return State::Retry(StateName::LabelEndNok);
             }
 
@@ -248,11 +270,46 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///       ^
 /// > | [a] b
 ///       ^
+/// > | [a]{.b} c
+///       ^
 /// ```
 pub fn after(tokenizer: &mut Tokenizer) -> State {
+    // Bracketed span (`[asd]{.fgh}`)?
+    if tokenizer.current == Some(b'{')
+        && tokenizer.parse_state.options.constructs.bracketed_span
+        && tokenizer.tokenize_state.label_starts.last().unwrap().kind == LabelKind::Link
+    {
+        tokenizer.attempt(
+            State::Next(StateName::LabelEndBracketedSpanAfter),
+            State::Next(StateName::LabelEndNotBracketedSpan),
+        );
+        State::Retry(StateName::AttributeListBracketedSpanStart)
+    } else {
+        State::Retry(StateName::LabelEndNotBracketedSpan)
+    }
+}
+
+/// After `]`, not at a bracketed span.
+///
+/// ```markdown
+/// > | [a](b) c
+///       ^
+/// > | [a][b] c
+///       ^
+/// > | [a][] b
+///       ^
+/// > | [a] b
+///       ^
+/// ```
+pub fn not_bracketed_span(tokenizer: &mut Tokenizer) -> State {
     let start_index = tokenizer.tokenize_state.label_starts.len() - 1;
     let start = &tokenizer.tokenize_state.label_starts[start_index];
 
+    // Only bracketed spans can contain links.
+    if start.inactive {
+        return State::Retry(StateName::LabelEndNok);
+    }
+
     let indices = (
         tokenizer.events[start.start.1].point.index,
         tokenizer.events[tokenizer.tokenize_state.end].point.index,
@@ -313,6 +370,22 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// After bracketed span attributes.
+///
+/// ```markdown
+/// > | [a]{.b} c
+///            ^
+/// ```
+pub fn bracketed_span_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer
+        .tokenize_state
+        .label_starts
+        .last_mut()
+        .unwrap()
+        .kind = LabelKind::BracketedSpan;
+    State::Retry(StateName::LabelEndOk)
+}
+
 /// After `]`, at `[`, but not at a full reference.
 ///
 /// > 👉 **Note**: we only get here if the label is defined.
@@ -351,7 +424,10 @@ pub fn ok(tokenizer: &mut Tokenizer) -> State {
     // longer viable for use (as they would otherwise contain a link).
     // These link starts are still looking for balanced closing brackets, so
     // we can’t remove them, but we can mark them.
-    if label_start.kind != LabelKind::Image {
+    if !matches!(
+        label_start.kind,
+        LabelKind::Image | LabelKind::BracketedSpan
+    ) {
         let mut index = 0;
         while index < tokenizer.tokenize_state.label_starts.len() {
             let label_start = &mut tokenizer.tokenize_state.label_starts[index];
@@ -685,20 +761,45 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     None
 }
 
-/// Inject links/images/footnotes.
+/// Inject links/images/footnotes/bracketed spans.
 fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
     // Add grouping events.
     let mut index = 0;
     while index < labels.len() {
         let label = &labels[index];
-        let group_name = if label.kind == LabelKind::GfmFootnote {
-            Name::GfmFootnoteCall
-        } else if label.kind == LabelKind::Image {
-            Name::Image
-        } else {
-            Name::Link
+        let group_name = match label.kind {
+            LabelKind::BracketedSpan => Name::BracketedSpan,
+            LabelKind::GfmFootnote => Name::GfmFootnoteCall,
+            LabelKind::Image => Name::Image,
+            _ => Name::Link,
         };
 
+        // Bracketed spans have no label: their content is directly in them.
+        if label.kind == LabelKind::BracketedSpan {
+            tokenizer.map.add(
+                label.start.0,
+                0,
+                vec![Event {
+                    kind: Kind::Enter,
+                    name: group_name.clone(),
+                    point: tokenizer.events[label.start.0].point.clone(),
+                    link: None,
+                }],
+            );
+            tokenizer.map.add(
+                label.end.1 + 1,
+                0,
+                vec![Event {
+                    kind: Kind::Exit,
+                    name: group_name,
+                    point: tokenizer.events[label.end.1].point.clone(),
+                    link: None,
+                }],
+            );
+            index += 1;
+            continue;
+        }
+
         // If this is a fine link, which starts with a footnote start that did
         // not match, we need to inject the caret as data.
         let mut caret = vec![];
@@ -762,6 +863,13 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
                     link: None,
                 }],
             );
+
+            // Before the exit, which is at the same index if there is no
+            // other text.
+            if !caret.is_empty() {
+                tokenizer.map.add(label.start.1 + 1, 0, caret);
+            }
+
             tokenizer.map.add(
                 label.end.0,
                 0,
@@ -774,10 +882,6 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
             );
         }
 
-        if !caret.is_empty() {
-            tokenizer.map.add(label.start.1 + 1, 0, caret);
-        }
-
         // Insert a label exit.
         tokenizer.map.add(
             label.end.0 + 4,
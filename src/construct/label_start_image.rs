@@ -108,7 +108,7 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     {
         State::Nok
     } else {
-        tokenizer.tokenize_state.label_starts.push(LabelStart {
+        tokenizer.push_label_start(LabelStart {
             kind: LabelKind::Image,
             start: (tokenizer.events.len() - 6, tokenizer.events.len() - 1),
             inactive: false,
@@ -51,7 +51,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
         tokenizer.consume();
         tokenizer.exit(Name::LabelMarker);
         tokenizer.exit(Name::LabelLink);
-        tokenizer.tokenize_state.label_starts.push(LabelStart {
+        tokenizer.push_label_start(LabelStart {
             kind: LabelKind::Link,
             start: (start, tokenizer.events.len() - 1),
             inactive: false,
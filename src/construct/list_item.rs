@@ -383,7 +383,26 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
 
         if event.name == Name::ListItem {
             if event.kind == Kind::Enter {
-                let end = skip::opt(&tokenizer.events, index, &[Name::ListItem]) - 1;
+                // Find the exit of this item.
+                // Not `skip::opt`, which also skips items that start right
+                // where this one ends (such as after fenced code, which
+                // includes its line ending).
+                let mut end = index + 1;
+                let mut depth = 0;
+                while !(tokenizer.events[end].name == Name::ListItem
+                    && tokenizer.events[end].kind == Kind::Exit
+                    && depth == 0)
+                {
+                    if tokenizer.events[end].name == Name::ListItem {
+                        if tokenizer.events[end].kind == Kind::Enter {
+                            depth += 1;
+                        } else {
+                            depth -= 1;
+                        }
+                    }
+
+                    end += 1;
+                }
                 let marker = skip::to(&tokenizer.events, index, &[Name::ListItemMarker]);
                 // Guaranteed to be a valid ASCII byte.
                 let marker = tokenizer.parse_state.bytes[tokenizer.events[marker].point.index];
@@ -402,7 +421,12 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                             Name::SpaceOrTab,
                             Name::LineEnding,
                             Name::BlankLineEnding,
+                            Name::BlockQuoteFencedFence,
                             Name::BlockQuotePrefix,
+                            Name::CustomBlockFence,
+                            Name::CustomBlockPrefix,
+                            Name::DirectiveContainerFence,
+                            Name::FencedDivFence,
                         ],
                     );
 
@@ -219,6 +219,7 @@ fn parse_esm(tokenizer: &mut Tokenizer) -> State {
                 reason: message,
                 source,
                 rule_id,
+                fix: None,
             })
         }
         MdxSignal::Eof(message, source, rule_id) => {
@@ -228,6 +229,7 @@ fn parse_esm(tokenizer: &mut Tokenizer) -> State {
                     reason: message,
                     source,
                     rule_id,
+                    fix: None,
                 })
             } else {
                 tokenizer.tokenize_state.mdx_last_parse_error = Some((message, *source, *rule_id));
@@ -0,0 +1,153 @@
+//! Mention occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Mention forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must not be preceded by an ASCII alphanumeric, `+`, `-`,
+//! ; `.`, `_`, or a non-ASCII byte.
+//! mention ::= '@' name
+//!
+//! ; Restriction: punctuation in a name must be followed by an ASCII
+//! ; alphanumeric or `_`.
+//! name ::= name_byte *( *name_punctuation name_byte )
+//! name_byte ::= ascii_alphanumeric | '_'
+//! name_punctuation ::= '-' | '.'
+//! ```
+//!
+//! This construct is the mention of a user (such as `@alice`), as found on
+//! forums, in chat, and on code hosting platforms.
+//! As a name cannot start with punctuation or end in it, `@alice.` and
+//! `@alice-` mention `alice`.
+//! As it cannot be preceded by what can occur at the end of the user part
+//! of an email address, email addresses (such as `alice@example.com`) are
+//! not mentions.
+//!
+//! ## HTML
+//!
+//! Mentions do not relate to elements in HTML.
+//! By default, they are compiled to `<span>`s with a `mention` class and a
+//! `data-mention` attribute with the name, which contain the mention as it
+//! is in the source.
+//! Pass [`mention_resolve`][crate::CompileOptions::mention_resolve] to turn
+//! mentions into links, or to reject them, which compiles them as plain
+//! text.
+//!
+//! ## Recommendation
+//!
+//! Mentions are not part of `CommonMark` or GFM.
+//! What a name refers to depends on the platform, so it is recommended to
+//! only use them in documents written for that platform.
+//!
+//! ## Tokens
+//!
+//! *   [`Mention`][Name::Mention]
+//! *   [`MentionMarker`][Name::MentionMarker]
+//! *   [`MentionName`][Name::MentionName]
+//!
+//! ## References
+//!
+//! *   [*Mentioning people and teams* on GitHub](https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#mentioning-people-and-teams)
+//!
+//! [text]: crate::construct::text
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of mention.
+///
+/// ```markdown
+/// > | a @b c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.mention
+        && tokenizer.current == Some(b'@')
+        && !matches!(
+            tokenizer.previous,
+            Some(b'+' | b'-' | b'.' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | 0x80..=0xFF)
+        )
+    {
+        tokenizer.enter(Name::Mention);
+        tokenizer.enter(Name::MentionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::MentionMarker);
+        State::Next(StateName::MentionNameStart)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `@`, at name.
+///
+/// ```markdown
+/// > | a @b c
+///        ^
+/// ```
+pub fn name_start(tokenizer: &mut Tokenizer) -> State {
+    if matches!(
+        tokenizer.current,
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z')
+    ) {
+        tokenizer.enter(Name::MentionName);
+        tokenizer.consume();
+        State::Next(StateName::MentionNameInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In name.
+///
+/// ```markdown
+/// > | a @b-c d
+///        ^^^
+/// ```
+pub fn name_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z') => {
+            tokenizer.consume();
+            State::Next(StateName::MentionNameInside)
+        }
+        Some(b'-' | b'.') => {
+            tokenizer.attempt(
+                State::Next(StateName::MentionNameInside),
+                State::Next(StateName::MentionNameEnd),
+            );
+            State::Retry(StateName::MentionNamePunctuation)
+        }
+        _ => State::Retry(StateName::MentionNameEnd),
+    }
+}
+
+/// In punctuation in name.
+///
+/// ```markdown
+/// > | a @b-c d
+///         ^
+/// ```
+pub fn name_punctuation(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'-' | b'.') => {
+            tokenizer.consume();
+            State::Next(StateName::MentionNamePunctuation)
+        }
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z') => State::Ok,
+        _ => State::Nok,
+    }
+}
+
+/// After name.
+///
+/// ```markdown
+/// > | a @b c
+///         ^
+/// ```
+pub fn name_end(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::MentionName);
+    tokenizer.exit(Name::Mention);
+    State::Ok
+}
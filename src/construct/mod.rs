@@ -62,23 +62,42 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [abbreviation][]
+//! *   [attribute list][attribute_list]
+//! *   [block id][block_id]
+//! *   [block quote (fenced)][block_quote_fenced]
+//! *   [citation][]
+//! *   [critic markup][critic_markup]
+//! *   [custom block][custom_block]
+//! *   [custom inline][custom_inline]
+//! *   [directive (container)][directive_container]
+//! *   [directive (leaf)][directive_leaf]
+//! *   [directive (text)][directive_text]
+//! *   [fenced div][fenced_div]
 //! *   [frontmatter][]
+//! *   [gfm alert][gfm_alert]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
 //! *   [gfm label start footnote][gfm_label_start_footnote]
 //! *   [gfm table][gfm_table]
 //! *   [gfm task list item check][gfm_task_list_item_check]
+//! *   [github reference][github_reference]
 //! *   [mdx esm][mdx_esm]
 //! *   [mdx expression (flow)][mdx_expression_flow]
 //! *   [mdx expression (text)][mdx_expression_text]
 //! *   [mdx jsx (flow)][mdx_jsx_flow]
 //! *   [mdx jsx (text)][mdx_jsx_text]
+//! *   [mention][]
+//! *   [ruby][]
+//! *   [spoiler][]
+//! *   [wiki link][wiki_link]
 //!
 //! There are also several small subroutines typically used in different places:
 //!
 //! *   [bom][partial_bom]
 //! *   [data][partial_data]
 //! *   [destination][partial_destination]
+//! *   [directive][partial_directive]
 //! *   [label][partial_label]
 //! *   [mdx expression][partial_mdx_expression]
 //! *   [mdx jsx][partial_mdx_jsx]
@@ -148,23 +167,37 @@
 //!
 //! [bnf]: http://trevorjim.com/a-specification-for-markdown/
 
+pub mod abbreviation;
 pub mod attention;
+pub mod attribute_list;
 pub mod autolink;
 pub mod blank_line;
+pub mod block_id;
 pub mod block_quote;
+pub mod block_quote_fenced;
 pub mod character_escape;
 pub mod character_reference;
+pub mod citation;
 pub mod code_indented;
 pub mod content;
+pub mod critic_markup;
+pub mod custom_block;
+pub mod custom_inline;
 pub mod definition;
+pub mod directive_container;
+pub mod directive_leaf;
+pub mod directive_text;
 pub mod document;
+pub mod fenced_div;
 pub mod flow;
 pub mod frontmatter;
+pub mod gfm_alert;
 pub mod gfm_autolink_literal;
 pub mod gfm_footnote_definition;
 pub mod gfm_label_start_footnote;
 pub mod gfm_table;
 pub mod gfm_task_list_item_check;
+pub mod github_reference;
 pub mod hard_break_escape;
 pub mod heading_atx;
 pub mod heading_setext;
@@ -179,10 +212,12 @@ pub mod mdx_expression_flow;
 pub mod mdx_expression_text;
 pub mod mdx_jsx_flow;
 pub mod mdx_jsx_text;
+pub mod mention;
 pub mod paragraph;
 pub mod partial_bom;
 pub mod partial_data;
 pub mod partial_destination;
+pub mod partial_directive;
 pub mod partial_label;
 pub mod partial_mdx_expression;
 pub mod partial_mdx_jsx;
@@ -193,6 +228,9 @@ pub mod partial_title;
 pub mod partial_whitespace;
 pub mod raw_flow;
 pub mod raw_text;
+pub mod ruby;
+pub mod spoiler;
 pub mod string;
 pub mod text;
 pub mod thematic_break;
+pub mod wiki_link;
@@ -109,6 +109,7 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
         }
         _ => {
             tokenizer.consume();
+            tokenizer.consume_while(|_| true);
             State::Next(StateName::ParagraphInside)
         }
     }
@@ -62,8 +62,11 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn inside(tokenizer: &mut Tokenizer) -> State {
     if let Some(byte) = tokenizer.current {
-        if byte != b'\n' && !tokenizer.tokenize_state.markers.contains(&byte) {
+        let markers = tokenizer.tokenize_state.markers;
+
+        if byte != b'\n' && !markers.contains(&byte) {
             tokenizer.consume();
+            tokenizer.consume_while(|d| !markers.contains(&d));
             return State::Next(StateName::DataInside);
         }
     }
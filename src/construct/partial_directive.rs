@@ -0,0 +1,249 @@
+//! Directive occurs in [directive (container)][directive_container],
+//! [directive (leaf)][directive_leaf], and [directive (text)][directive_text].
+//!
+//! ## Grammar
+//!
+//! Directive forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! directive ::= name [label] [attributes]
+//!
+//! ; Restriction: the name cannot end in `-` or `_`.
+//! name ::= ascii_alphabetic *(ascii_alphanumeric | '-' | '_')
+//! ; Restriction: brackets must be balanced.
+//! ; Restriction: line endings are only allowed in directives (text).
+//! label ::= '[' *(label_byte | label_escape) ']'
+//! label_byte ::= byte - '\\'
+//! label_escape ::= '\\' ['[' | '\\' | ']']
+//!
+//! ; See the `attribute_list` construct for the BNF of `attribute`.
+//! attributes ::= '{' *space_or_tab [attribute *(1*space_or_tab attribute) *space_or_tab] '}'
+//! ```
+//!
+//! The label is interpreted as the [text][] content type.
+//! That means that, for example, [attention][] and [raw (text)][raw_text]
+//! are allowed.
+//! The label of a directive (text) can contain line endings, but not blank
+//! lines.
+//!
+//! The attributes are as in [attribute lists][attribute_list], without
+//! the colon after the opening brace, and with one addition: properties
+//! without a value (such as `{a}`) are allowed too.
+//! When a label or the attributes are not valid, the directive ends before
+//! them.
+//!
+//! ## References
+//!
+//! *   [`micromark-extension-directive`](https://github.com/micromark/micromark-extension-directive)
+//! *   [*Generic syntax for custom directives*](https://talk.commonmark.org/t/generic-directives-plugins-syntax/444)
+//!
+//! [directive_container]: crate::construct::directive_container
+//! [directive_leaf]: crate::construct::directive_leaf
+//! [directive_text]: crate::construct::directive_text
+//! [text]: crate::construct::text
+//! [attention]: crate::construct::attention
+//! [raw_text]: crate::construct::raw_text
+//! [attribute_list]: crate::construct::attribute_list
+
+use crate::construct::partial_space_or_tab_eol::{space_or_tab_eol_with_options, Options};
+use crate::event::{Content, Link, Name};
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::link;
+use crate::tokenizer::Tokenizer;
+
+/// Start of name.
+///
+/// ```markdown
+/// > | :ab
+///      ^
+/// ```
+pub fn name_start(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if byte.is_ascii_alphabetic() => {
+            tokenizer.enter(Name::DirectiveName);
+            tokenizer.consume();
+            State::Next(StateName::DirectiveNameInside)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// In name.
+///
+/// ```markdown
+/// > | :ab
+///       ^
+/// ```
+pub fn name_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_') => {
+            tokenizer.consume();
+            State::Next(StateName::DirectiveNameInside)
+        }
+        _ if matches!(tokenizer.previous, Some(b'-' | b'_')) => State::Nok,
+        _ => {
+            tokenizer.exit(Name::DirectiveName);
+            State::Ok
+        }
+    }
+}
+
+/// Start of label.
+///
+/// ```markdown
+/// > | :a[b]
+///       ^
+/// ```
+pub fn label_start(tokenizer: &mut Tokenizer) -> State {
+    debug_assert_eq!(tokenizer.current, Some(b'['), "expected `[`");
+    tokenizer.enter(Name::DirectiveLabel);
+    tokenizer.enter(Name::DirectiveLabelMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::DirectiveLabelMarker);
+    State::Next(StateName::DirectiveLabelBefore)
+}
+
+/// After `[`, at label data or the closing marker.
+///
+/// ```markdown
+/// > | :a[b]
+///        ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b']') {
+        State::Retry(StateName::DirectiveLabelAtBreak)
+    } else {
+        tokenizer.enter(Name::DirectiveLabelString);
+        State::Retry(StateName::DirectiveLabelAtBreak)
+    }
+}
+
+/// In label, at something, before something else.
+///
+/// ```markdown
+/// > | :a[b]
+///        ^^
+/// ```
+pub fn label_at_break(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\n') if tokenizer.tokenize_state.token_4 == Name::DirectiveText => {
+            tokenizer.attempt(
+                State::Next(StateName::DirectiveLabelEolAfter),
+                State::Next(StateName::DirectiveLabelNok),
+            );
+            State::Retry(space_or_tab_eol_with_options(
+                tokenizer,
+                Options {
+                    content: Some(Content::Text),
+                    connect: tokenizer.tokenize_state.connect,
+                },
+            ))
+        }
+        None | Some(b'\n') => State::Retry(StateName::DirectiveLabelNok),
+        Some(b']') if tokenizer.tokenize_state.size_b == 0 => {
+            if tokenizer.events[tokenizer.events.len() - 1].name != Name::DirectiveLabelMarker {
+                tokenizer.exit(Name::DirectiveLabelString);
+            }
+
+            tokenizer.enter(Name::DirectiveLabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::DirectiveLabelMarker);
+            tokenizer.exit(Name::DirectiveLabel);
+            tokenizer.tokenize_state.connect = false;
+            State::Ok
+        }
+        _ => {
+            tokenizer.enter_link(
+                Name::Data,
+                Link {
+                    previous: None,
+                    next: None,
+                    content: Content::Text,
+                },
+            );
+
+            if tokenizer.tokenize_state.connect {
+                let index = tokenizer.events.len() - 1;
+                link(&mut tokenizer.events, index);
+            } else {
+                tokenizer.tokenize_state.connect = true;
+            }
+
+            State::Retry(StateName::DirectiveLabelInside)
+        }
+    }
+}
+
+/// In label, after whitespace.
+///
+/// ```markdown
+///   | :a[b␊
+/// > | c]
+///     ^
+/// ```
+pub fn label_eol_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.connect = true;
+    State::Retry(StateName::DirectiveLabelAtBreak)
+}
+
+/// In label, on something disallowed.
+///
+/// ```markdown
+/// > | :a[b
+///         ^
+/// ```
+pub fn label_nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.connect = false;
+    tokenizer.tokenize_state.size_b = 0;
+    State::Nok
+}
+
+/// In label, in text.
+///
+/// ```markdown
+/// > | :a[b]
+///        ^
+/// ```
+pub fn label_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::DirectiveLabelAtBreak)
+        }
+        Some(b']') if tokenizer.tokenize_state.size_b == 0 => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::DirectiveLabelAtBreak)
+        }
+        Some(byte) => {
+            if byte == b'[' {
+                tokenizer.tokenize_state.size_b += 1;
+            } else if byte == b']' {
+                tokenizer.tokenize_state.size_b -= 1;
+            }
+
+            tokenizer.consume();
+            State::Next(if byte == b'\\' {
+                StateName::DirectiveLabelEscape
+            } else {
+                StateName::DirectiveLabelInside
+            })
+        }
+    }
+}
+
+/// After `\`, at a special character.
+///
+/// ```markdown
+/// > | :a[b\]c]
+///          ^
+/// ```
+pub fn label_escape(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'[' | b'\\' | b']') => {
+            tokenizer.consume();
+            State::Next(StateName::DirectiveLabelInside)
+        }
+        _ => State::Retry(StateName::DirectiveLabelInside),
+    }
+}
@@ -98,6 +98,7 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
                 reason: problem.0,
                 rule_id: Box::new(problem.2),
                 source: Box::new(problem.1),
+                fix: None,
             })
         }
         Some(b'\n') => {
@@ -176,6 +177,7 @@ pub fn eol_after(tokenizer: &mut Tokenizer) -> State {
                 place: Some(Box::new(message::Place::Point(tokenizer.point.to_unist()))),
                 reason: "Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc".into(),
                 source: Box::new("markdown-rs".into()),
+                fix: None,
                 rule_id: Box::new("unexpected-lazy".into()),
             }
         )
@@ -242,6 +244,7 @@ fn parse_expression(tokenizer: &mut Tokenizer, parse: &MdxExpressionParse) -> St
                 reason,
                 rule_id,
                 source,
+                fix: None,
             })
         }
         MdxSignal::Eof(reason, source, rule_id) => {
@@ -1099,6 +1099,7 @@ pub fn es_whitespace_eol_after(tokenizer: &mut Tokenizer) -> State {
                 reason: "Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc".into(),
                 rule_id: Box::new("unexpected-lazy".into()),
                 source: Box::new("markdown-rs".into()),
+                fix: None,
             }
         )
     } else {
@@ -1140,5 +1141,6 @@ fn crash(tokenizer: &Tokenizer, at: &str, expect: &str) -> State {
             }
         )),
         source: Box::new("markdown-rs".into()),
+        fix: None,
     })
 }
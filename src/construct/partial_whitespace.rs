@@ -11,7 +11,8 @@
 //! ```
 //!
 //! It occurs around line endings and, in the case of text content, it also
-//! occurs at the start or end of the whole.
+//! occurs at the start or end of the whole, and before a
+//! [block id][crate::construct::block_id].
 //!
 //! Normally this whitespace is ignored.
 //! In the case of text content, whitespace before a line ending that
@@ -75,11 +76,20 @@ pub fn resolve_whitespace(tokenizer: &mut Tokenizer, hard_break: bool, trim_whol
         if event.kind == Kind::Exit && event.name == Name::Data {
             let trim_start = (trim_whole && index == 1)
                 || (index > 1 && tokenizer.events[index - 2].name == Name::LineEnding);
+            let before_block_id = index + 1 < tokenizer.events.len()
+                && tokenizer.events[index + 1].name == Name::BlockId;
             let trim_end = (trim_whole && index == tokenizer.events.len() - 1)
+                || before_block_id
                 || (index + 1 < tokenizer.events.len()
                     && tokenizer.events[index + 1].name == Name::LineEnding);
 
-            trim_data(tokenizer, index, trim_start, trim_end, hard_break);
+            trim_data(
+                tokenizer,
+                index,
+                trim_start,
+                trim_end,
+                hard_break && !before_block_id,
+            );
         }
 
         index += 1;
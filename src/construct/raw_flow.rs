@@ -71,6 +71,8 @@
 //! For that, the math is compiled as a `<pre>`, and a `<code>` element with two
 //! classes: `language-math` and `math-display`.
 //! Client side JavaScript can look for these classes to process them further.
+//! With [`math_mathml`][crate::CompileOptions::math_mathml], a subset of
+//! TeX is instead compiled to a `<math display="block">` element.
 //!
 //! The `info` is, when rendering to HTML, typically exposed as a class.
 //! This behavior stems from the HTML spec ([*§ 4.5.15 The `code`
@@ -103,6 +105,9 @@
 //! But on your own (math-heavy?) site it can be great!
 //! You can use code (fenced) with an info string of `math` to improve this, as
 //! that works in many places.
+//! GitLab treats that as math, which you can do too by setting
+//! [`parse_options.math_gitlab: true`][parse_options]: the info string is
+//! then dropped and the events are those of math (flow).
 //!
 //! ## Tokens
 //!
@@ -135,6 +140,7 @@
 //! [character_reference]: crate::construct::character_reference
 //! [code_indented]: crate::construct::code_indented
 //! [raw_text]: crate::construct::raw_text
+//! [parse_options]: crate::ParseOptions
 //! [html_code]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-code-element
 //! [html_pre]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-pre-element
 
@@ -337,11 +343,13 @@ pub fn info(tokenizer: &mut Tokenizer) -> State {
         None | Some(b'\n') => {
             tokenizer.exit(Name::Data);
             tokenizer.exit(tokenizer.tokenize_state.token_4.clone());
+            info_math(tokenizer);
             State::Retry(StateName::RawFlowInfoBefore)
         }
         Some(b'\t' | b' ') => {
             tokenizer.exit(Name::Data);
             tokenizer.exit(tokenizer.tokenize_state.token_4.clone());
+            info_math(tokenizer);
             tokenizer.attempt(State::Next(StateName::RawFlowMetaBefore), State::Nok);
             State::Retry(space_or_tab(tokenizer))
         }
@@ -661,3 +669,58 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     tokenizer.concrete = false;
     State::Ok
 }
+
+/// Turn code (fenced) with a `math` info string into math (flow), when
+/// GitLab math is turned on.
+///
+/// ````markdown
+/// > | ```math
+///        ^^^^
+///   | a
+///   | ```
+/// ````
+fn info_math(tokenizer: &mut Tokenizer) {
+    let len = tokenizer.events.len();
+
+    if !tokenizer.parse_state.options.math_gitlab
+        || tokenizer.tokenize_state.token_4 != Name::CodeFencedFenceInfo
+        || Slice::from_position(
+            tokenizer.parse_state.bytes,
+            &Position::from_exit_event(&tokenizer.events, len - 1),
+        )
+        .as_str()
+            != "math"
+    {
+        return;
+    }
+
+    // Math (flow) does not have an `info` part: drop it.
+    tokenizer.events.truncate(len - 4);
+
+    let mut index = tokenizer.events.len();
+
+    while index > 0 {
+        index -= 1;
+        let event = &mut tokenizer.events[index];
+        event.name = match event.name {
+            Name::CodeFenced => Name::MathFlow,
+            Name::CodeFencedFence => Name::MathFlowFence,
+            Name::CodeFencedFenceSequence => Name::MathFlowFenceSequence,
+            _ => event.name.clone(),
+        };
+
+        if event.name == Name::MathFlow {
+            break;
+        }
+    }
+
+    let len = tokenizer.stack.len();
+    tokenizer.stack[len - 2] = Name::MathFlow;
+    tokenizer.stack[len - 1] = Name::MathFlowFence;
+    tokenizer.tokenize_state.token_1 = Name::MathFlow;
+    tokenizer.tokenize_state.token_2 = Name::MathFlowFence;
+    tokenizer.tokenize_state.token_3 = Name::MathFlowFenceSequence;
+    tokenizer.tokenize_state.token_4 = Name::Data;
+    tokenizer.tokenize_state.token_5 = Name::MathFlowFenceMeta;
+    tokenizer.tokenize_state.token_6 = Name::MathFlowChunk;
+}
@@ -75,6 +75,8 @@
 //! For that, the math is compiled as a `<code>` element with two classes:
 //! `language-math` and `math-inline`.
 //! Client side JavaScript can look for these classes to process them further.
+//! With [`math_mathml`][crate::CompileOptions::math_mathml], a subset of
+//! TeX is instead compiled to a `<math>` element.
 //!
 //! When turning markdown into HTML, each line ending in raw (text) is turned
 //! into a space.
@@ -90,6 +92,12 @@
 //! to improve this, as it prevents single dollars from being seen as math, and
 //! thus prevents normal dollars in text from being seen as math.
 //!
+//! GitLab uses code (text) in dollars (`` $`a`$ ``) for math (text).
+//! You can set [`parse_options.math_gitlab: true`][parse_options] to support
+//! that too.
+//! The dollars and grave accents then together form the sequences of math
+//! (text).
+//!
 //! ## Tokens
 //!
 //! *   [`CodeText`][Name::CodeText]
@@ -168,6 +176,17 @@ pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
         tokenizer.consume();
         State::Next(StateName::RawTextSequenceOpen)
     }
+    // GitLab math (text): a dollar and then grave accents (`` $`a`$ ``).
+    else if tokenizer.parse_state.options.math_gitlab
+        && tokenizer.tokenize_state.marker == b'$'
+        && tokenizer.tokenize_state.size == 1
+        && tokenizer.current == Some(b'`')
+    {
+        tokenizer.tokenize_state.marker = b'`';
+        tokenizer.tokenize_state.size = 0;
+        tokenizer.tokenize_state.seen = true;
+        State::Retry(StateName::RawTextSequenceOpen)
+    }
     // Not enough markers in the sequence.
     else if tokenizer.tokenize_state.marker == b'$'
         && tokenizer.tokenize_state.size == 1
@@ -196,6 +215,7 @@ pub fn between(tokenizer: &mut Tokenizer) -> State {
         None => {
             tokenizer.tokenize_state.marker = 0;
             tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.seen = false;
             tokenizer.tokenize_state.token_1 = Name::Data;
             tokenizer.tokenize_state.token_2 = Name::Data;
             tokenizer.tokenize_state.token_3 = Name::Data;
@@ -248,24 +268,53 @@ pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
         tokenizer.tokenize_state.size_b += 1;
         tokenizer.consume();
         State::Next(StateName::RawTextSequenceClose)
-    } else {
-        tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
-        if tokenizer.tokenize_state.size == tokenizer.tokenize_state.size_b {
-            tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
+    }
+    // GitLab math (text): grave accents and then a dollar (`` $`a`$ ``).
+    // Without dollar, this is code (text), so this is not math.
+    else if tokenizer.tokenize_state.seen
+        && tokenizer.tokenize_state.size == tokenizer.tokenize_state.size_b
+    {
+        if tokenizer.current == Some(b'$') {
+            tokenizer.consume();
+            State::Next(StateName::RawTextSequenceCloseAfter)
+        } else {
             tokenizer.tokenize_state.marker = 0;
             tokenizer.tokenize_state.size = 0;
             tokenizer.tokenize_state.size_b = 0;
+            tokenizer.tokenize_state.seen = false;
             tokenizer.tokenize_state.token_1 = Name::Data;
             tokenizer.tokenize_state.token_2 = Name::Data;
             tokenizer.tokenize_state.token_3 = Name::Data;
-            State::Ok
-        } else {
-            // More or less accents: mark as data.
-            let len = tokenizer.events.len();
-            tokenizer.events[len - 2].name = tokenizer.tokenize_state.token_3.clone();
-            tokenizer.events[len - 1].name = tokenizer.tokenize_state.token_3.clone();
-            tokenizer.tokenize_state.size_b = 0;
-            State::Retry(StateName::RawTextBetween)
+            State::Nok
         }
+    } else if tokenizer.tokenize_state.size == tokenizer.tokenize_state.size_b {
+        State::Retry(StateName::RawTextSequenceCloseAfter)
+    } else {
+        tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
+        // More or less accents: mark as data.
+        let len = tokenizer.events.len();
+        tokenizer.events[len - 2].name = tokenizer.tokenize_state.token_3.clone();
+        tokenizer.events[len - 1].name = tokenizer.tokenize_state.token_3.clone();
+        tokenizer.tokenize_state.size_b = 0;
+        State::Retry(StateName::RawTextBetween)
     }
 }
+
+/// After closing sequence.
+///
+/// ```markdown
+/// > | `a`
+///        ^
+/// ```
+pub fn sequence_close_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
+    tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
+    tokenizer.tokenize_state.marker = 0;
+    tokenizer.tokenize_state.size = 0;
+    tokenizer.tokenize_state.size_b = 0;
+    tokenizer.tokenize_state.seen = false;
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    tokenizer.tokenize_state.token_2 = Name::Data;
+    tokenizer.tokenize_state.token_3 = Name::Data;
+    State::Ok
+}
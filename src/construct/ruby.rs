@@ -0,0 +1,180 @@
+//! Ruby occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Ruby forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ruby ::= '{' base '|' text '}'
+//!
+//! ; Restriction: base and text must each contain at least one byte that is
+//! ; not whitespace.
+//! base ::= 1*( byte - eol - '{' - '}' - '|' )
+//! text ::= 1*( byte - eol - '{' - '}' - '|' )
+//! ```
+//!
+//! Ruby annotates a run of text (the base) with a small text (the ruby
+//! text), which is shown above or next to it.
+//! It is mostly used for the pronunciation of East Asian characters, such
+//! as the reading of kanji in Japanese (`{漢字|かんじ}`), or pinyin in
+//! Chinese.
+//! Neither the base nor the text can contain markdown: they are taken as
+//! they are.
+//!
+//! ## HTML
+//!
+//! Ruby relates to the `<ruby>`, `<rb>`, and `<rt>` elements in HTML.
+//! See [*§ 4.5.10 The `ruby` element*][html_ruby] and
+//! [*§ 4.5.11 The `rt` element*][html_rt] in the HTML spec for more info.
+//! The base is compiled to `<rb>`, and the text to `<rt>`, in a `<ruby>`.
+//!
+//! ## Recommendation
+//!
+//! Ruby is not part of `CommonMark` or GFM.
+//! This syntax is also used by markdown-it and Denden Markdown.
+//! As it is not widely supported, it is recommended to not use it in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`Ruby`][Name::Ruby]
+//! *   [`RubyBase`][Name::RubyBase]
+//! *   [`RubyMarker`][Name::RubyMarker]
+//! *   [`RubyText`][Name::RubyText]
+//!
+//! ## References
+//!
+//! *   [`markdown-it-ruby`](https://github.com/lostandfound/markdown-it-ruby)
+//! *   [*Ruby* in Denden Markdown](https://conv.denshochan.com/markdown)
+//!
+//! [text]: crate::construct::text
+//! [html_ruby]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-ruby-element
+//! [html_rt]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-rt-element
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of ruby.
+///
+/// ```markdown
+/// > | a {b|c} d
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.ruby && tokenizer.current == Some(b'{') {
+        tokenizer.enter(Name::Ruby);
+        tokenizer.enter(Name::RubyMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::RubyMarker);
+        State::Next(StateName::RubyBaseBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `{`, at base.
+///
+/// ```markdown
+/// > | a {b|c} d
+///        ^
+/// ```
+pub fn base_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'{' | b'|' | b'}') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::RubyBase);
+            State::Retry(StateName::RubyBaseInside)
+        }
+    }
+}
+
+/// In base.
+///
+/// ```markdown
+/// > | a {b|c} d
+///        ^
+/// ```
+pub fn base_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'{' | b'}') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b'|') => {
+            tokenizer.exit(Name::RubyBase);
+
+            if !tokenizer.tokenize_state.seen {
+                return State::Nok;
+            }
+
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.enter(Name::RubyMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::RubyMarker);
+            State::Next(StateName::RubyTextBefore)
+        }
+        Some(byte) => {
+            if !matches!(byte, b'\t' | b' ') {
+                tokenizer.tokenize_state.seen = true;
+            }
+
+            tokenizer.consume();
+            State::Next(StateName::RubyBaseInside)
+        }
+    }
+}
+
+/// After `|`, at text.
+///
+/// ```markdown
+/// > | a {b|c} d
+///          ^
+/// ```
+pub fn text_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'{' | b'|' | b'}') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::RubyText);
+            State::Retry(StateName::RubyTextInside)
+        }
+    }
+}
+
+/// In text.
+///
+/// ```markdown
+/// > | a {b|c} d
+///          ^
+/// ```
+pub fn text_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'{' | b'|') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b'}') => {
+            tokenizer.exit(Name::RubyText);
+
+            if !tokenizer.tokenize_state.seen {
+                return State::Nok;
+            }
+
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.enter(Name::RubyMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::RubyMarker);
+            tokenizer.exit(Name::Ruby);
+            State::Ok
+        }
+        Some(byte) => {
+            if !matches!(byte, b'\t' | b' ') {
+                tokenizer.tokenize_state.seen = true;
+            }
+
+            tokenizer.consume();
+            State::Next(StateName::RubyTextInside)
+        }
+    }
+}
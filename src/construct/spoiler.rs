@@ -0,0 +1,228 @@
+//! Spoiler occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Spoiler sequences form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! spoiler_sequence_open ::= '>' '!'
+//! spoiler_sequence_close ::= '!' '<'
+//! ```
+//!
+//! Spoilers can also be formed with pipes (`||a||`), which is done by
+//! [attention][crate::construct::attention].
+//!
+//! A closing sequence is matched with the closest opening sequence before it
+//! to form a spoiler, as long as there is something between them.
+//! Otherwise they are turned into data.
+//!
+//! When spoilers are turned on, a `>` that is followed by `!` does not
+//! form a [block quote][crate::construct::block_quote] marker, so that
+//! spoilers can start at the start of a line.
+//!
+//! ## HTML
+//!
+//! Spoiler does not relate to an element in HTML.
+//! It is compiled to a `<span class="spoiler">` element, which can be hidden
+//! and revealed with CSS and/or JavaScript.
+//!
+//! ## Recommendation
+//!
+//! Spoilers are not part of `CommonMark` or GFM.
+//! They are typically used on forums and in chat apps, such as Reddit and
+//! Discord.
+//!
+//! ## Tokens
+//!
+//! *   [`Spoiler`][Name::Spoiler]
+//! *   [`SpoilerSequence`][Name::SpoilerSequence]
+//! *   [`SpoilerText`][Name::SpoilerText]
+//!
+//! [text]: crate::construct::text
+
+use crate::event::{Event, Kind, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use alloc::{vec, vec::Vec};
+
+/// At start of spoiler sequence.
+///
+/// ```markdown
+/// > | >!a!<
+///     ^  ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.spoiler
+        && matches!(tokenizer.current, Some(b'!' | b'>'))
+    {
+        tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
+        tokenizer.enter(Name::SpoilerSequence);
+        tokenizer.consume();
+        State::Next(StateName::SpoilerInside)
+    } else {
+        State::Nok
+    }
+}
+
+/// In sequence, after its first marker.
+///
+/// ```markdown
+/// > | >!a!<
+///      ^  ^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    let expected = if tokenizer.tokenize_state.marker == b'>' {
+        b'!'
+    } else {
+        b'<'
+    };
+    tokenizer.tokenize_state.marker = 0;
+
+    if tokenizer.current == Some(expected) {
+        tokenizer.consume();
+        tokenizer.exit(Name::SpoilerSequence);
+        tokenizer.register_resolver(ResolveName::Spoiler);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// Resolve sequences.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    // Opening sequences that can still be matched, with the events they are
+    // in, so that a spoiler doesn’t start in say, one link, and end in
+    // another.
+    let mut openers: Vec<(usize, Vec<usize>)> = vec![];
+    let mut stack = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        // Spoilers with pipes are formed by attention, their sequences
+        // start with `|`.
+        // Only enters are checked: exits can be at the end of the input.
+        if event.kind == Kind::Enter
+            && event.name == Name::SpoilerSequence
+            && tokenizer.parse_state.bytes[event.point.index] != b'|'
+        {
+            // Skip the exit.
+            index += 1;
+
+            if tokenizer.parse_state.bytes[event.point.index] == b'>' {
+                openers.push((index - 1, stack.clone()));
+            } else if let Some(position) = openers.iter().rposition(|d| d.1 == stack) {
+                let open = openers[position].0;
+                let close = index - 1;
+
+                // There must be something in the spoiler.
+                if open + 2 < close {
+                    // Openers in other events can no longer match.
+                    for (unused, _) in openers.split_off(position + 1) {
+                        to_data(tokenizer, unused);
+                    }
+
+                    openers.pop();
+                    match_sequences(tokenizer, open, close);
+                } else {
+                    to_data(tokenizer, close);
+                }
+            } else {
+                to_data(tokenizer, index - 1);
+            }
+        } else if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            stack.pop();
+        }
+
+        index += 1;
+    }
+
+    // Mark remaining sequences as data.
+    for (open, _) in openers {
+        to_data(tokenizer, open);
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
+
+/// Turn a sequence, of which the enter is at `index`, into data.
+fn to_data(tokenizer: &mut Tokenizer, index: usize) {
+    tokenizer.events[index].name = Name::Data;
+    tokenizer.events[index + 1].name = Name::Data;
+}
+
+/// Match two sequences.
+fn match_sequences(tokenizer: &mut Tokenizer, open: usize, close: usize) {
+    let open_enter = tokenizer.events[open].point.clone();
+    let open_exit = tokenizer.events[open + 1].point.clone();
+    let close_enter = tokenizer.events[close].point.clone();
+    let close_exit = tokenizer.events[close + 1].point.clone();
+
+    tokenizer.map.add(
+        open,
+        2,
+        vec![
+            Event {
+                kind: Kind::Enter,
+                name: Name::Spoiler,
+                point: open_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::SpoilerSequence,
+                point: open_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::SpoilerSequence,
+                point: open_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::SpoilerText,
+                point: open_exit,
+                link: None,
+            },
+        ],
+    );
+    tokenizer.map.add(
+        close,
+        2,
+        vec![
+            Event {
+                kind: Kind::Exit,
+                name: Name::SpoilerText,
+                point: close_enter.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Enter,
+                name: Name::SpoilerSequence,
+                point: close_enter,
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::SpoilerSequence,
+                point: close_exit.clone(),
+                link: None,
+            },
+            Event {
+                kind: Kind::Exit,
+                name: Name::Spoiler,
+                point: close_exit,
+                link: None,
+            },
+        ],
+    );
+}
@@ -6,13 +6,22 @@
 //!
 //! The constructs found in text are:
 //!
-//! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, strong)
+//! *   [Abbreviation][crate::construct::abbreviation]
+//! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, insert, mark, spoiler, strong, subscript, superscript, underline)
+//! *   [Attribute list][crate::construct::attribute_list]
 //! *   [Autolink][crate::construct::autolink]
+//! *   [Block id][crate::construct::block_id]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
+//! *   [Citation][crate::construct::citation]
+//! *   [Critic markup][crate::construct::critic_markup]
+//! *   [Custom inline][crate::construct::custom_inline]
+//! *   [Directive (text)][crate::construct::directive_text]
+//! *   [GFM: Alert][crate::construct::gfm_alert]
 //! *   [Raw (text)][crate::construct::raw_text] (code (text), math (text))
 //! *   [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote]
 //! *   [GFM: Task list item check][crate::construct::gfm_task_list_item_check]
+//! *   [GitHub reference][crate::construct::github_reference]
 //! *   [Hard break (escape)][crate::construct::hard_break_escape]
 //! *   [HTML (text)][crate::construct::html_text]
 //! *   [Label start (image)][crate::construct::label_start_image]
@@ -20,11 +29,17 @@
 //! *   [Label end][crate::construct::label_end]
 //! *   [MDX: expression (text)][crate::construct::mdx_expression_text]
 //! *   [MDX: JSX (text)][crate::construct::mdx_jsx_text]
+//! *   [Mention][crate::construct::mention]
+//! *   [Ruby][crate::construct::ruby]
+//! *   [Spoiler][crate::construct::spoiler]
+//! *   [Wiki link][crate::construct::wiki_link]
 //!
 //! > 👉 **Note**: for performance reasons, hard break (trailing) is formed by
 //! > [whitespace][crate::construct::partial_whitespace].
 
+use crate::construct::abbreviation::resolve as resolve_abbreviation;
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
+use crate::construct::github_reference::resolve as resolve_github_reference;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
@@ -32,37 +47,63 @@ use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 
 /// Characters that can start something in text.
-const MARKERS: [u8; 16] = [
-    b'!',  // `label_start_image`
+///
+/// The first bytes of custom inline delimiters are added to these, see
+/// [`ParseState`][crate::parser::ParseState].
+pub const MARKERS: [u8; 24] = [
+    b'!',  // `label_start_image`, `spoiler`, `wiki_link`
     b'$',  // `raw_text` (math (text))
     b'&',  // `character_reference`
     b'*',  // `attention` (emphasis, strong)
-    b'<',  // `autolink`, `html_text`, `mdx_jsx_text`
+    b'+',  // `attention` (insert), `critic_markup`
+    b'-',  // `critic_markup`
+    b':',  // `directive_text`
+    b'<',  // `autolink`, `critic_markup`, `html_text`, `mdx_jsx_text`
+    b'=',  // `attention` (mark), `critic_markup`
+    b'>',  // `spoiler`
+    b'@',  // `citation`, `mention`
     b'H',  // `gfm_autolink_literal` (`protocol` kind)
     b'W',  // `gfm_autolink_literal` (`www.` kind)
-    b'[',  // `label_start_link`
+    b'[',  // `citation`, `label_start_link`, `gfm_label_start_footnote`, `wiki_link`
     b'\\', // `character_escape`, `hard_break_escape`
     b']',  // `label_end`, `gfm_label_start_footnote`
-    b'_',  // `attention` (emphasis, strong)
+    b'^',  // `block_id`, `attention` (superscript)
+    b'_',  // `attention` (emphasis, strong, underline)
     b'`',  // `raw_text` (code (text))
     b'h',  // `gfm_autolink_literal` (`protocol` kind)
     b'w',  // `gfm_autolink_literal` (`www.` kind)
-    b'{',  // `mdx_expression_text`
-    b'~',  // `attention` (gfm strikethrough)
+    b'{',  // `attribute_list`, `critic_markup`, `mdx_expression_text`, `ruby`
+    b'|',  // `attention` (spoiler)
+    b'~',  // `attention` (gfm strikethrough, subscript), `critic_markup`
 ];
 
 /// Start of text.
 ///
-/// There is a slightly weird case where task list items have their check at
-/// the start of the first paragraph.
-/// So we start by checking for that.
+/// There are slightly weird cases where block quotes have an alert, and
+/// task list items have their check, at the start of the first paragraph.
+/// So we start by checking for those.
 ///
 /// ```markdown
 /// > | abc
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.tokenize_state.markers = &MARKERS;
+    let parse_state = tokenizer.parse_state;
+    tokenizer.tokenize_state.markers = &parse_state.text_markers;
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeGfmTaskListItemCheck),
+    );
+    State::Retry(StateName::GfmAlertStart)
+}
+
+/// At start of text, at a task list item check.
+///
+/// ```markdown
+/// > | abc
+///     ^
+/// ```
+pub fn before_gfm_task_list_item_check(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::TextBefore),
         State::Next(StateName::TextBefore),
@@ -83,12 +124,33 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             tokenizer.register_resolver(ResolveName::Text);
             State::Ok
         }
+        // `custom_inline`, which goes before the constructs that are built in.
+        Some(_) if !tokenizer.parse_state.options.custom_inlines.is_empty() => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeBuiltin),
+            );
+            State::Retry(StateName::CustomInlineStart)
+        }
+        _ => State::Retry(StateName::TextBeforeBuiltin),
+    }
+}
+
+/// Before text, at something that is not a custom inline construct.
+///
+/// ```markdown
+/// > | abc
+///     ^
+/// ```
+pub fn before_builtin(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // `wiki_link` (embed), `label_start_image`, `spoiler` (order matters).
         Some(b'!') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeData),
+                State::Next(StateName::TextBeforeLabelStartImage),
             );
-            State::Retry(StateName::LabelStartImageStart)
+            State::Retry(StateName::WikiLinkStart)
         }
         // raw (text) (code (text), math (text))
         Some(b'$' | b'`') => {
@@ -105,21 +167,50 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::CharacterReferenceStart)
         }
-        // attention (emphasis, gfm strikethrough, strong)
-        Some(b'*' | b'_' | b'~') => {
+        // attention (emphasis, spoiler, strong, underline)
+        Some(b'*' | b'_' | b'|') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
                 State::Next(StateName::TextBeforeData),
             );
             State::Retry(StateName::AttentionStart)
         }
-        // `autolink`, `html_text` (order does not matter), `mdx_jsx_text` (order matters).
+        // `critic_markup`, attention (gfm strikethrough, insert, mark, subscript) (order matters).
+        Some(b'+' | b'=' | b'~') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeAttention),
+            );
+            State::Retry(StateName::CriticMarkupStart)
+        }
+        Some(b'-') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::CriticMarkupStart)
+        }
+        // `critic_markup`, `autolink`, `html_text` (order does not matter), `mdx_jsx_text` (order matters).
         Some(b'<') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeHtml),
+                State::Next(StateName::TextBeforeAutolink),
+            );
+            State::Retry(StateName::CriticMarkupStart)
+        }
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::DirectiveTextStart)
+        }
+        Some(b'>') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
             );
-            State::Retry(StateName::AutolinkStart)
+            State::Retry(StateName::SpoilerStart)
         }
         Some(b'H' | b'h') => {
             tokenizer.attempt(
@@ -135,12 +226,21 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::GfmAutolinkLiteralWwwStart)
         }
+        // `citation`, `mention` (order matters).
+        Some(b'@') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeMention),
+            );
+            State::Retry(StateName::CitationStart)
+        }
+        // `citation`, `wiki_link`, `gfm_label_start_footnote`, `label_start_link` (order matters).
         Some(b'[') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeLabelStartLink),
+                State::Next(StateName::TextBeforeWikiLink),
             );
-            State::Retry(StateName::GfmLabelStartFootnoteStart)
+            State::Retry(StateName::CitationStart)
         }
         Some(b'\\') => {
             tokenizer.attempt(
@@ -156,17 +256,42 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::LabelEndStart)
         }
+        // `block_id`, `attention` (superscript) (order matters).
+        Some(b'^') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeAttention),
+            );
+            State::Retry(StateName::BlockIdStart)
+        }
+        // `critic_markup`, `ruby`, `attribute_list`, `mdx_expression_text` (order matters).
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
-                State::Next(StateName::TextBeforeData),
+                State::Next(StateName::TextBeforeRuby),
             );
-            State::Retry(StateName::MdxExpressionTextStart)
+            State::Retry(StateName::CriticMarkupStart)
         }
         _ => State::Retry(StateName::TextBeforeData),
     }
 }
 
+/// Before autolink.
+///
+/// At `<`, which wasn’t critic markup.
+///
+/// ```markdown
+/// > | a <https://b>
+///       ^
+/// ```
+pub fn before_autolink(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeHtml),
+    );
+    State::Retry(StateName::AutolinkStart)
+}
+
 /// Before html (text).
 ///
 /// At `<`, which wasn’t an autolink.
@@ -215,6 +340,54 @@ pub fn before_hard_break_escape(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::HardBreakEscapeStart)
 }
 
+/// Before mention.
+///
+/// At `@`, which wasn’t a citation.
+///
+/// ```markdown
+/// > | a @b
+///       ^
+/// ```
+pub fn before_mention(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::MentionStart)
+}
+
+/// Before wiki link.
+///
+/// At `[`, which wasn’t a citation.
+///
+/// ```markdown
+/// > | [[a]]
+///     ^
+/// ```
+pub fn before_wiki_link(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeGfmLabelStartFootnote),
+    );
+    State::Retry(StateName::WikiLinkStart)
+}
+
+/// Before GFM label start (footnote).
+///
+/// At `[`, which wasn’t a wiki link.
+///
+/// ```markdown
+/// > | [^a]
+///     ^
+/// ```
+pub fn before_gfm_label_start_footnote(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeLabelStartLink),
+    );
+    State::Retry(StateName::GfmLabelStartFootnoteStart)
+}
+
 /// Before label start (link).
 ///
 /// At `[`, which wasn’t a GFM label start (footnote).
@@ -231,6 +404,103 @@ pub fn before_label_start_link(tokenizer: &mut Tokenizer) -> State {
     State::Retry(StateName::LabelStartLinkStart)
 }
 
+/// Before ruby.
+///
+/// At `{`, which wasn’t critic markup.
+///
+/// ```markdown
+/// > | a {b|c} d
+///       ^
+/// ```
+pub fn before_ruby(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeAttributeList),
+    );
+    State::Retry(StateName::RubyStart)
+}
+
+/// Before attribute list (text).
+///
+/// At `{`, which wasn’t ruby.
+///
+/// ```markdown
+/// > | *a*{: .b}
+///        ^
+/// ```
+pub fn before_attribute_list(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeMdxExpression),
+    );
+    State::Retry(StateName::AttributeListTextStart)
+}
+
+/// Before mdx expression (text).
+///
+/// At `{`, which wasn’t an attribute list.
+///
+/// ```markdown
+/// > | a {b}
+///       ^
+/// ```
+pub fn before_mdx_expression(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::MdxExpressionTextStart)
+}
+
+/// Before label start (image).
+///
+/// At `!`, which wasn’t a wiki embed.
+///
+/// ```markdown
+/// > | a ![b](c)
+///       ^
+/// ```
+pub fn before_label_start_image(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeSpoiler),
+    );
+    State::Retry(StateName::LabelStartImageStart)
+}
+
+/// Before spoiler.
+///
+/// At `!`, which wasn’t a label start (image).
+///
+/// ```markdown
+/// > | >!a!<
+///        ^
+/// ```
+pub fn before_spoiler(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::SpoilerStart)
+}
+
+/// Before attention.
+///
+/// At `^`, which wasn’t a block id, or at `+`, `=`, or `~`, which wasn’t
+/// critic markup.
+///
+/// ```markdown
+/// > | 2^10^
+///      ^
+/// ```
+pub fn before_attention(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.attempt(
+        State::Next(StateName::TextBefore),
+        State::Next(StateName::TextBeforeData),
+    );
+    State::Retry(StateName::AttentionStart)
+}
+
 /// Before data.
 ///
 /// ```markdown
@@ -259,6 +529,16 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         resolve_gfm_autolink_literal(tokenizer);
     }
 
+    if tokenizer.parse_state.options.constructs.github_reference {
+        resolve_github_reference(tokenizer);
+    }
+
+    if tokenizer.parse_state.options.constructs.abbreviation
+        && !tokenizer.parse_state.abbreviations.is_empty()
+    {
+        resolve_abbreviation(tokenizer);
+    }
+
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
@@ -0,0 +1,308 @@
+//! Wiki link occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Wiki link forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! wiki_link ::= [ '!' ] '[[' target [ fragment ] [ '|' alias ] ']]'
+//!
+//! ; Restriction: target and fragment together must contain at least one
+//! ; byte that is not whitespace.
+//! target ::= *( byte - eol - '[' - ']' - '|' - '#' )
+//! fragment ::= '#' *( byte - eol - '[' - ']' - '|' )
+//! alias ::= 1*( byte - eol - '[' - ']' )
+//! ```
+//!
+//! This construct is the internal link of Obsidian (and other tools for
+//! notes, such as Foam and Notion).
+//! The target is the name of a page, optionally followed by a fragment: a
+//! heading (`#b`) or block id (`#^b`) in that page.
+//! The alias, if there is one, is shown instead of the target.
+//! Neither the target nor the alias can contain markdown: they are taken as
+//! they are.
+//!
+//! With a `!` before it, and [`wiki_embed`][crate::Constructs::wiki_embed]
+//! turned on, the construct is an embed instead: the (part of the) page or
+//! file is meant to be shown in place.
+//!
+//! ## HTML
+//!
+//! Wiki links relate to the `<a>` element in HTML.
+//! See [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//! They are compiled to links with a `wikilink` class.
+//! By default, the target is used as the URL.
+//! Pass [`wiki_link_resolve`][crate::CompileOptions::wiki_link_resolve] to
+//! turn targets into URLs, or to mark pages as missing, which compiles them
+//! to links without `href` and with a `missing` class.
+//!
+//! Embeds do not relate to a particular element, as what they are depends
+//! on what they refer to.
+//! By default, they are compiled to placeholder `<span>`s with a
+//! `wikiembed` class, and `data-target` and `data-fragment` attributes, for
+//! scripts to replace later, which contain the alias or target.
+//! Pass [`wiki_embed_resolve`][crate::CompileOptions::wiki_embed_resolve]
+//! to compile them to HTML (such as the content of a page, or an image)
+//! while compiling.
+//!
+//! ## Recommendation
+//!
+//! Wiki links are not part of `CommonMark` or GFM.
+//! They are mostly found in notes written with Obsidian.
+//! As they are not widely supported, it is recommended to not use them in
+//! documents that are meant to be portable.
+//!
+//! ## Tokens
+//!
+//! *   [`WikiEmbed`][Name::WikiEmbed]
+//! *   [`WikiLink`][Name::WikiLink]
+//! *   [`WikiLinkAlias`][Name::WikiLinkAlias]
+//! *   [`WikiLinkAliasMarker`][Name::WikiLinkAliasMarker]
+//! *   [`WikiLinkFragment`][Name::WikiLinkFragment]
+//! *   [`WikiLinkMarker`][Name::WikiLinkMarker]
+//! *   [`WikiLinkTarget`][Name::WikiLinkTarget]
+//!
+//! ## References
+//!
+//! *   [*Internal links* in Obsidian](https://help.obsidian.md/Linking+notes+and+files/Internal+links)
+//! *   [*Embed files* in Obsidian](https://help.obsidian.md/Linking+notes+and+files/Embed+files)
+//!
+//! [text]: crate::construct::text
+//! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of wiki link or wiki embed.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///       ^
+/// > | a ![[b|c]] d
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    let constructs = &tokenizer.parse_state.options.constructs;
+
+    match tokenizer.current {
+        Some(b'!') if constructs.wiki_embed => {
+            tokenizer.enter(Name::WikiEmbed);
+            tokenizer.enter(Name::WikiLinkMarker);
+            tokenizer.consume();
+            State::Next(StateName::WikiLinkEmbedOpen)
+        }
+        Some(b'[') if constructs.wiki_link => {
+            tokenizer.enter(Name::WikiLink);
+            tokenizer.enter(Name::WikiLinkMarker);
+            tokenizer.consume();
+            State::Next(StateName::WikiLinkOpen)
+        }
+        _ => State::Nok,
+    }
+}
+
+/// After `!`, at first `[`.
+///
+/// ```markdown
+/// > | a ![[b|c]] d
+///        ^
+/// ```
+pub fn embed_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.consume();
+        State::Next(StateName::WikiLinkOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After first `[`, at second.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.consume();
+        tokenizer.exit(Name::WikiLinkMarker);
+        State::Next(StateName::WikiLinkTargetBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening marker, at target.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///         ^
+/// ```
+pub fn target_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']' | b'|') => State::Nok,
+        Some(b'#') => State::Retry(StateName::WikiLinkFragmentStart),
+        Some(_) => {
+            tokenizer.enter(Name::WikiLinkTarget);
+            State::Retry(StateName::WikiLinkTargetInside)
+        }
+    }
+}
+
+/// In target.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///         ^
+/// ```
+pub fn target_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b'#') => {
+            tokenizer.exit(Name::WikiLinkTarget);
+            State::Retry(StateName::WikiLinkFragmentStart)
+        }
+        Some(b']' | b'|') => {
+            tokenizer.exit(Name::WikiLinkTarget);
+            State::Retry(StateName::WikiLinkTargetAfter)
+        }
+        Some(byte) => {
+            if !matches!(byte, b'\t' | b' ') {
+                tokenizer.tokenize_state.seen = true;
+            }
+
+            tokenizer.consume();
+            State::Next(StateName::WikiLinkTargetInside)
+        }
+    }
+}
+
+/// At `#` of fragment.
+///
+/// ```markdown
+/// > | a [[b#c|d]] e
+///          ^
+/// ```
+pub fn fragment_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.seen = true;
+    tokenizer.enter(Name::WikiLinkFragment);
+    tokenizer.consume();
+    State::Next(StateName::WikiLinkFragmentInside)
+}
+
+/// In fragment.
+///
+/// ```markdown
+/// > | a [[b#c|d]] e
+///           ^
+/// ```
+pub fn fragment_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => {
+            tokenizer.tokenize_state.seen = false;
+            State::Nok
+        }
+        Some(b']' | b'|') => {
+            tokenizer.exit(Name::WikiLinkFragment);
+            State::Retry(StateName::WikiLinkTargetAfter)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::WikiLinkFragmentInside)
+        }
+    }
+}
+
+/// After target and fragment, at `|` or closing marker.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///          ^
+/// ```
+pub fn target_after(tokenizer: &mut Tokenizer) -> State {
+    if !tokenizer.tokenize_state.seen {
+        return State::Nok;
+    }
+
+    tokenizer.tokenize_state.seen = false;
+
+    if tokenizer.current == Some(b'|') {
+        tokenizer.enter(Name::WikiLinkAliasMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::WikiLinkAliasMarker);
+        State::Next(StateName::WikiLinkAliasBefore)
+    } else {
+        State::Retry(StateName::WikiLinkCloseStart)
+    }
+}
+
+/// After `|`, at alias.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///           ^
+/// ```
+pub fn alias_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[' | b']') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::WikiLinkAlias);
+            State::Retry(StateName::WikiLinkAliasInside)
+        }
+    }
+}
+
+/// In alias.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///           ^
+/// ```
+pub fn alias_inside(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'[') => State::Nok,
+        Some(b']') => {
+            tokenizer.exit(Name::WikiLinkAlias);
+            State::Retry(StateName::WikiLinkCloseStart)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::WikiLinkAliasInside)
+        }
+    }
+}
+
+/// At closing marker.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///            ^
+/// ```
+pub fn close_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::WikiLinkMarker);
+    tokenizer.consume();
+    State::Next(StateName::WikiLinkCloseInside)
+}
+
+/// After first `]`, at second.
+///
+/// ```markdown
+/// > | a [[b|c]] d
+///             ^
+/// ```
+pub fn close_inside(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b']') {
+        tokenizer.consume();
+        tokenizer.exit(Name::WikiLinkMarker);
+        let name = tokenizer.stack.last().unwrap().clone();
+        tokenizer.exit(name);
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
@@ -0,0 +1,182 @@
+//! Concrete syntax tree: a lossless tree of events.
+//!
+//! Unlike [mdast][crate::mdast], which keeps what markdown means, the
+//! concrete syntax tree keeps how it is written: every byte of the input,
+//! including markers, whitespace, and line endings, is in some node.
+//! This is what formatters and refactoring tools need to reconstruct the
+//! exact original text of any node.
+//! It is also what editors need to find out what is at the cursor, with
+//! [`Node::path()`][] and [`offset()`][].
+
+use crate::event::Name;
+use crate::util::constant::TAB_SIZE;
+use alloc::vec::Vec;
+
+/// Node in a concrete syntax tree.
+///
+/// Each node is an event (see [`Name`][]), except for the root and for bytes
+/// that are not in an event, which have no name.
+/// The children of a node are in order and together span exactly the bytes
+/// of the node, so the text of all leaves together is the input.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::Name, to_cst, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let value = "# *a*";
+/// let tree = to_cst(value, &ParseOptions::default())?;
+/// let heading = &tree.children[0];
+///
+/// assert_eq!(heading.name, Some(Name::HeadingAtx));
+/// assert_eq!(heading.text(value), "# *a*");
+/// assert_eq!(heading.children[1].name, Some(Name::SpaceOrTab));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Node {
+    /// Name of the event, if this is an event.
+    pub name: Option<Name>,
+    /// Byte offset in the input where the node starts.
+    pub start: usize,
+    /// Byte offset in the input where the node ends.
+    pub end: usize,
+    /// Child nodes.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Get the original text of the node in `value`, which must be the
+    /// input the tree was made from.
+    #[must_use]
+    pub fn text<'a>(&self, value: &'a str) -> &'a str {
+        &value[self.start..self.end]
+    }
+
+    /// Get the nodes without children, in order.
+    ///
+    /// Their text together is the input.
+    #[must_use]
+    pub fn leaves(&self) -> Vec<&Node> {
+        let mut leaves = Vec::new();
+        self.push_leaves(&mut leaves);
+        leaves
+    }
+
+    /// Get the innermost node with the byte at `offset`, and its ancestors.
+    ///
+    /// The first node is this node and the last is the innermost node.
+    /// Nodes do not overlap, so there is one path.
+    /// The path is empty if `offset` is not in this node, such as when it is
+    /// at the end of the input.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{event::Name, to_cst, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let value = "a [b](c)";
+    /// let tree = to_cst(value, &ParseOptions::default())?;
+    /// let path = tree.path(6);
+    ///
+    /// assert_eq!(path.last().unwrap().text(value), "c");
+    /// assert!(path
+    ///     .iter()
+    ///     .any(|d| d.name == Some(Name::ResourceDestination)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn path(&self, offset: usize) -> Vec<&Node> {
+        let mut path = Vec::new();
+        let mut node = self;
+
+        if offset < self.start || offset >= self.end {
+            return path;
+        }
+
+        loop {
+            path.push(node);
+            // Children are in order and do not overlap.
+            let index = node.children.partition_point(|d| d.end <= offset);
+
+            match node.children.get(index) {
+                Some(child) if child.start <= offset => node = child,
+                _ => break,
+            }
+        }
+
+        path
+    }
+
+    /// Push the nodes without children to `leaves`.
+    fn push_leaves<'a>(&'a self, leaves: &mut Vec<&'a Node>) {
+        if self.children.is_empty() {
+            leaves.push(self);
+        } else {
+            for child in &self.children {
+                child.push_leaves(leaves);
+            }
+        }
+    }
+}
+
+/// Get the byte offset of a 1-indexed `line` and `column` in `value`.
+///
+/// Columns are like those of [points][crate::unist::Point]: they count
+/// bytes, and tabs count up to the next tab stop, so a column in a tab is at
+/// the offset of that tab.
+/// The column right after a line, before its line ending, is fine.
+/// Returns `None` if the line or column is not in `value`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::cst::offset;
+///
+/// assert_eq!(offset("a\nbc", 2, 2), Some(3));
+/// assert_eq!(offset("a\n\tb", 2, 5), Some(3));
+/// assert_eq!(offset("a\nbc", 3, 1), None);
+/// ```
+#[must_use]
+pub fn offset(value: &str, line: usize, column: usize) -> Option<usize> {
+    let bytes = value.as_bytes();
+    let mut index = 0;
+    let mut current = 1;
+
+    while current < line {
+        match bytes.get(index)? {
+            b'\r' if bytes.get(index + 1) == Some(&b'\n') => {
+                index += 2;
+                current += 1;
+            }
+            b'\n' | b'\r' => {
+                index += 1;
+                current += 1;
+            }
+            _ => index += 1,
+        }
+    }
+
+    let mut current = 1;
+
+    while current < column {
+        let next = match bytes.get(index)? {
+            b'\n' | b'\r' => return None,
+            b'\t' => current + TAB_SIZE - (current - 1) % TAB_SIZE,
+            _ => current + 1,
+        };
+
+        if next > column {
+            break;
+        }
+
+        current = next;
+        index += 1;
+    }
+
+    Some(index)
+}
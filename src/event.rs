@@ -6,3385 +6,5524 @@ use crate::util::constant::TAB_SIZE;
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Name {
-    /// Attention sequence.
-    ///
-    /// > 👉 **Note**: this is used while parsing but compiled away.
-    AttentionSequence,
-    /// Whole autolink.
+    /// PHP Markdown Extra extension: abbreviation.
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`AutolinkEmail`][Name::AutolinkEmail],
-    ///     [`AutolinkMarker`][Name::AutolinkMarker],
-    ///     [`AutolinkProtocol`][Name::AutolinkProtocol]
+    ///     [`Data`][Name::Data]
     /// *   **Construct**:
-    ///     [`autolink`][crate::construct::autolink]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <https://example.com> and <admin@example.com>
-    ///     ^^^^^^^^^^^^^^^^^^^^^     ^^^^^^^^^^^^^^^^^^^
+    /// > | a b
+    ///       ^
+    ///   |
+    ///   | *[b]: c
     /// ```
-    Autolink,
-    /// Email autolink w/o markers.
+    Abbreviation,
+    /// PHP Markdown Extra extension: abbreviation definition.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Autolink`][Name::Autolink]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel],
+    ///     [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker],
+    ///     [`AbbreviationDefinitionTitle`][Name::AbbreviationDefinitionTitle],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`autolink`][crate::construct::autolink]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <admin@example.com>
-    ///      ^^^^^^^^^^^^^^^^^
+    /// > | *[a]: b
+    ///     ^^^^^^^
     /// ```
-    AutolinkEmail,
-    /// Marker of an autolink.
+    AbbreviationDefinition,
+    /// PHP Markdown Extra extension: abbreviation definition label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Autolink`][Name::Autolink]
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
     /// *   **Content model**:
-    ///     void
+    ///     [`AbbreviationDefinitionLabelMarker`][Name::AbbreviationDefinitionLabelMarker],
+    ///     [`AbbreviationDefinitionLabelString`][Name::AbbreviationDefinitionLabelString]
     /// *   **Construct**:
-    ///     [`autolink`][crate::construct::autolink]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <https://example.com>
-    ///     ^                   ^
+    /// > | *[a]: b
+    ///      ^^^
     /// ```
-    AutolinkMarker,
-    /// Protocol autolink w/o markers.
+    AbbreviationDefinitionLabel,
+    /// PHP Markdown Extra extension: abbreviation definition label marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Autolink`][Name::Autolink]
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`autolink`][crate::construct::autolink]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <https://example.com>
-    ///      ^^^^^^^^^^^^^^^^^^^
+    /// > | *[a]: b
+    ///      ^ ^
     /// ```
-    AutolinkProtocol,
-    /// Line ending preceded only by whitespace or nothing at all.
+    AbbreviationDefinitionLabelMarker,
+    /// PHP Markdown Extra extension: abbreviation definition label string.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`blank_line`][crate::construct::blank_line]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ␠␠␊
+    /// > | *[a]: b
     ///       ^
     /// ```
-    BlankLineEnding,
-    /// Whole block quote.
+    AbbreviationDefinitionLabelString,
+    /// PHP Markdown Extra extension: abbreviation definition marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [document content][crate::construct::document]
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
     /// *   **Content model**:
-    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
-    ///     [flow content][crate::construct::flow]
+    ///     void
     /// *   **Construct**:
-    ///     [`block_quote`][crate::construct::block_quote]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | > a
-    ///     ^^^
-    /// > | b
-    ///     ^
+    /// > | *[a]: b
+    ///     ^   ^
     /// ```
-    BlockQuote,
-    /// Block quote marker.
+    AbbreviationDefinitionMarker,
+    /// PHP Markdown Extra extension: abbreviation definition title.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix]
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`block_quote`][crate::construct::block_quote]
+    ///     [`abbreviation`][crate::construct::abbreviation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | > a
-    ///     ^
-    ///   | b
+    /// > | *[a]: b
+    ///           ^
     /// ```
-    BlockQuoteMarker,
-    /// Block quote prefix.
+    AbbreviationDefinitionTitle,
+    /// Attention sequence.
+    ///
+    /// > 👉 **Note**: this is used while parsing but compiled away.
+    AttentionSequence,
+    /// Kramdown extension: attribute list.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`BlockQuote`][Name::BlockQuote]
+    ///     [flow content][crate::construct::flow],
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`BlockQuoteMarker`][Name::BlockQuoteMarker],
+    ///     [`AttributeListClass`][Name::AttributeListClass],
+    ///     [`AttributeListId`][Name::AttributeListId],
+    ///     [`AttributeListMarker`][Name::AttributeListMarker],
+    ///     [`AttributeListProperty`][Name::AttributeListProperty],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`block_quote`][crate::construct::block_quote]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | > a
-    ///     ^^
-    ///   | b
+    /// > | {: #b .c d="e"}
+    ///     ^^^^^^^^^^^^^^^
     /// ```
-    BlockQuotePrefix,
-    /// Byte order mark.
-    ///
-    /// ## Info
-    ///
-    /// *   **Context**:
-    ///     optional first event
-    /// *   **Content model**:
-    ///     void
-    /// *   **Construct**:
-    ///     [`document`][crate::construct::document]
-    ByteOrderMark,
-    /// Whole character escape.
+    AttributeList,
+    /// Kramdown extension: attribute list class.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [string content][crate::construct::string] or
-    ///     [text content][crate::construct::text]
+    ///     [`AttributeList`][Name::AttributeList]
     /// *   **Content model**:
-    ///     [`CharacterEscapeMarker`][Name::CharacterEscapeMarker],
-    ///     [`CharacterEscapeValue`][Name::CharacterEscapeValue]
+    ///     [`AttributeListClassMarker`][Name::AttributeListClassMarker],
+    ///     [`AttributeListClassValue`][Name::AttributeListClassValue]
     /// *   **Construct**:
-    ///     [`character_escape`][crate::construct::character_escape]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a \- b
-    ///       ^^
+    /// > | {: #b .c d="e"}
+    ///            ^^
     /// ```
-    CharacterEscape,
-    /// Character escape marker.
+    AttributeListClass,
+    /// Kramdown extension: attribute list class marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterEscape`][Name::CharacterEscape]
+    ///     [`AttributeListClass`][Name::AttributeListClass]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_escape`][crate::construct::character_escape]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a \- b
-    ///       ^
+    /// > | {: #b .c d="e"}
+    ///            ^
     /// ```
-    CharacterEscapeMarker,
-    /// Character escape value.
+    AttributeListClassMarker,
+    /// Kramdown extension: attribute list class value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterEscape`][Name::CharacterEscape]
+    ///     [`AttributeListClass`][Name::AttributeListClass]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_escape`][crate::construct::character_escape]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a \- b
-    ///        ^
+    /// > | {: #b .c d="e"}
+    ///             ^
     /// ```
-    CharacterEscapeValue,
-    /// Whole character reference.
+    AttributeListClassValue,
+    /// Kramdown extension: attribute list id.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [string content][crate::construct::string] or
-    ///     [text content][crate::construct::text]
+    ///     [`AttributeList`][Name::AttributeList]
     /// *   **Content model**:
-    ///     [`CharacterReferenceMarker`][Name::CharacterReferenceMarker],
-    ///     [`CharacterReferenceMarkerHexadecimal`][Name::CharacterReferenceMarkerHexadecimal],
-    ///     [`CharacterReferenceMarkerNumeric`][Name::CharacterReferenceMarkerNumeric],
-    ///     [`CharacterReferenceMarkerSemi`][Name::CharacterReferenceMarkerSemi],
-    ///     [`CharacterReferenceValue`][Name::CharacterReferenceValue]
+    ///     [`AttributeListIdMarker`][Name::AttributeListIdMarker],
+    ///     [`AttributeListIdValue`][Name::AttributeListIdValue]
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///       ^^^^^   ^^^^^^^   ^^^^^^^^^
+    /// > | {: #b .c d="e"}
+    ///         ^^
     /// ```
-    CharacterReference,
-    /// Character reference opening marker.
+    AttributeListId,
+    /// Kramdown extension: attribute list id marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterReference`][Name::CharacterReference]
+    ///     [`AttributeListId`][Name::AttributeListId]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///       ^       ^         ^
+    /// > | {: #b .c d="e"}
+    ///         ^
     /// ```
-    CharacterReferenceMarker,
-    /// Character reference hexadecimal numeric marker.
+    AttributeListIdMarker,
+    /// Kramdown extension: attribute list id value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterReference`][Name::CharacterReference]
+    ///     [`AttributeListId`][Name::AttributeListId]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///                           ^
+    /// > | {: #b .c d="e"}
+    ///          ^
     /// ```
-    CharacterReferenceMarkerHexadecimal,
-    /// Character reference numeric marker.
+    AttributeListIdValue,
+    /// Kramdown extension: attribute list marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterReference`][Name::CharacterReference]
+    ///     [`AttributeList`][Name::AttributeList]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///                ^         ^
+    /// > | {: #b .c d="e"}
+    ///     ^^            ^
     /// ```
-    CharacterReferenceMarkerNumeric,
-    /// Character reference closing marker.
+    AttributeListMarker,
+    /// Kramdown extension: attribute list property.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterReference`][Name::CharacterReference]
+    ///     [`AttributeList`][Name::AttributeList]
     /// *   **Content model**:
-    ///     void
+    ///     [`AttributeListPropertyInitializerMarker`][Name::AttributeListPropertyInitializerMarker],
+    ///     [`AttributeListPropertyName`][Name::AttributeListPropertyName],
+    ///     [`AttributeListPropertyValue`][Name::AttributeListPropertyValue],
+    ///     [`AttributeListPropertyValueMarker`][Name::AttributeListPropertyValueMarker]
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///           ^         ^           ^
+    /// > | {: #b .c d="e"}
+    ///               ^^^^^
     /// ```
-    CharacterReferenceMarkerSemi,
-    /// Character reference value.
+    AttributeListProperty,
+    /// Kramdown extension: attribute list property initializer marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CharacterReference`][Name::CharacterReference]
+    ///     [`AttributeListProperty`][Name::AttributeListProperty]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`character_reference`][crate::construct::character_reference]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a &amp; b &#8800; c &#x1D306; d
-    ///        ^^^      ^^^^       ^^^^^
+    /// > | {: #b .c d="e"}
+    ///                ^
     /// ```
-    CharacterReferenceValue,
-    /// Whole code (fenced).
+    AttributeListPropertyInitializerMarker,
+    /// Kramdown extension: attribute list property name.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`AttributeListProperty`][Name::AttributeListProperty]
     /// *   **Content model**:
-    ///     [`CodeFencedFence`][Name::CodeFencedFence],
-    ///     [`CodeFlowChunk`][Name::CodeFlowChunk],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    /// > | ```js
-    ///     ^^^^^
-    /// > | console.log(1)
-    ///     ^^^^^^^^^^^^^^
-    /// > | ```
-    ///     ^^^
-    /// ````
-    CodeFenced,
-    /// A code (fenced) fence.
+    /// ```markdown
+    /// > | {: #b .c d="e"}
+    ///               ^
+    /// ```
+    AttributeListPropertyName,
+    /// Kramdown extension: attribute list property value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFenced`][Name::CodeFenced]
+    ///     [`AttributeListProperty`][Name::AttributeListProperty]
     /// *   **Content model**:
-    ///     [`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo],
-    ///     [`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],
-    ///     [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    /// > | ```js
-    ///     ^^^^^
-    ///   | console.log(1)
-    /// > | ```
-    ///     ^^^
-    /// ````
-    CodeFencedFence,
-    /// A code (fenced) fence info word.
+    /// ```markdown
+    /// > | {: #b .c d="e"}
+    ///                  ^
+    /// ```
+    AttributeListPropertyValue,
+    /// Kramdown extension: attribute list property value marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFencedFence`][Name::CodeFencedFence]
+    ///     [`AttributeListProperty`][Name::AttributeListProperty]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    /// > | ```js
-    ///        ^^
-    ///   | console.log(1)
-    ///   | ```
-    /// ````
-    CodeFencedFenceInfo,
-    /// A code (fenced) fence meta string.
+    /// ```markdown
+    /// > | {: #b .c d="e"}
+    ///                 ^ ^
+    /// ```
+    AttributeListPropertyValueMarker,
+    /// Whole autolink.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFencedFence`][Name::CodeFencedFence]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     [`AutolinkEmail`][Name::AutolinkEmail],
+    ///     [`AutolinkMarker`][Name::AutolinkMarker],
+    ///     [`AutolinkProtocol`][Name::AutolinkProtocol]
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    /// > | ```js highlight="1"
-    ///           ^^^^^^^^^^^^^
-    ///   | console.log(1)
-    ///   | ```
-    /// ````
-    CodeFencedFenceMeta,
-    /// A code (fenced) fence sequence.
+    /// ```markdown
+    /// > | <https://example.com> and <admin@example.com>
+    ///     ^^^^^^^^^^^^^^^^^^^^^     ^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Autolink,
+    /// Email autolink w/o markers.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence]
+    ///     [`Autolink`][Name::Autolink]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    /// > | ```js
-    ///     ^^^
-    ///   | console.log(1)
-    /// > | ```
-    ///     ^^^
-    /// ````
-    CodeFencedFenceSequence,
-    /// A code (fenced, indented) chunk.
+    /// ```markdown
+    /// > | <admin@example.com>
+    ///      ^^^^^^^^^^^^^^^^^
+    /// ```
+    AutolinkEmail,
+    /// Marker of an autolink.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeFenced`][Name::CodeFenced],
-    ///     [`CodeIndented`][Name::CodeIndented]
+    ///     [`Autolink`][Name::Autolink]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow],
-    ///     [`code_indented`][crate::construct::code_indented]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
-    /// ````markdown
-    ///   | ```js
-    /// > | console.log(1)
-    ///     ^^^^^^^^^^^^^^
-    ///   | ```
-    /// ````
-    ///
     /// ```markdown
-    /// > | ␠␠␠␠console.log(1)
-    ///         ^^^^^^^^^^^^^^
+    /// > | <https://example.com>
+    ///     ^                   ^
     /// ```
-    CodeFlowChunk,
-    /// Whole code (indented).
+    AutolinkMarker,
+    /// Autolink label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`Autolink`][Name::Autolink]
     /// *   **Content model**:
-    ///     [`CodeFlowChunk`][Name::CodeFlowChunk],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// ␠␠␠␠console.log(1)
-    /// ^^^^^^^^^^^^^^^^^^
+    /// > | <https://example.com|a>
+    ///                          ^
     /// ```
-    CodeIndented,
-    /// Whole code (text).
+    AutolinkLabel,
+    /// Autolink label marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`Autolink`][Name::Autolink]
     /// *   **Content model**:
-    ///     [`CodeTextData`][Name::CodeTextData],
-    ///     [`CodeTextSequence`][Name::CodeTextSequence],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a `b` c
-    ///       ^^^
+    /// > | <https://example.com|a>
+    ///                         ^
     /// ```
-    CodeText,
-    /// Code (text) data.
+    AutolinkLabelMarker,
+    /// Protocol autolink w/o markers.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeText`][Name::CodeText]
+    ///     [`Autolink`][Name::Autolink]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`autolink`][crate::construct::autolink]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a `b` c
-    ///        ^
+    /// > | <https://example.com>
+    ///      ^^^^^^^^^^^^^^^^^^^
     /// ```
-    CodeTextData,
-    /// Code (text) sequence.
+    AutolinkProtocol,
+    /// Line ending preceded only by whitespace or nothing at all.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`CodeText`][Name::CodeText]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`blank_line`][crate::construct::blank_line]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a `b` c
-    ///       ^ ^
+    /// > | ␠␠␊
+    ///       ^
     /// ```
-    CodeTextSequence,
-    /// Content.
+    BlankLineEnding,
+    /// Obsidian extension: block id.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [content][crate::construct::content]
+    ///     [`BlockIdMarker`][Name::BlockIdMarker],
+    ///     [`BlockIdValue`][Name::BlockIdValue]
     /// *   **Construct**:
-    ///     [`content`][crate::construct::content]
+    ///     [`block_id`][crate::construct::block_id]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b
-    ///     ^^^^^^
-    /// > | c.
-    ///     ^^
+    /// > | a ^b
+    ///       ^^
     /// ```
-    Content,
-    /// Data.
+    BlockId,
+    /// Obsidian extension: block id marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [string content][crate::construct::string],
-    ///     [text content][crate::construct::text]
+    ///     [`BlockId`][Name::BlockId]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`data`][crate::construct::partial_data]
+    ///     [`block_id`][crate::construct::block_id]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | aa *bb* cc
-    ///     ^^^ ^^ ^^^
+    /// > | a ^b
+    ///       ^
     /// ```
-    Data,
-    /// Whole definition.
+    BlockIdMarker,
+    /// Obsidian extension: block id value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`BlockId`][Name::BlockId]
     /// *   **Content model**:
-    ///     [`DefinitionMarker`][Name::DefinitionMarker],
-    ///     [`DefinitionLabel`][Name::DefinitionLabel],
-    ///     [`DefinitionDestination`][Name::DefinitionDestination],
-    ///     [`DefinitionTitle`][Name::DefinitionTitle],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`definition`][crate::construct::definition]
+    ///     [`block_id`][crate::construct::block_id]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^^^^^^^^^^
+    /// > | a ^b
+    ///        ^
     /// ```
-    Definition,
-    /// Whole definition destination.
+    BlockIdValue,
+    /// Whole block quote.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
-    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    ///     [`BlockQuoteFencedFence`][Name::BlockQuoteFencedFence],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [flow content][crate::construct::flow]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`block_quote`][crate::construct::block_quote],
+    ///     [`block_quote_fenced`][crate::construct::block_quote_fenced]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
-    /// > | [a]: <b> "c"
-    ///          ^^^
+    /// > | > a
+    ///     ^^^
+    /// > | b
+    ///     ^
     /// ```
-    DefinitionDestination,
-    /// Definition destination literal.
+    BlockQuote,
+    /// Block quote (fenced) fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    ///     [`BlockQuote`][Name::BlockQuote]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationLiteralMarker`][Name::DefinitionDestinationLiteralMarker],
-    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    ///     [`BlockQuoteFencedFenceSequence`][Name::BlockQuoteFencedFenceSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`block_quote_fenced`][crate::construct::block_quote_fenced]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: <b> "c"
-    ///          ^^^
+    /// > | >>>
+    ///     ^^^
+    ///   | a
+    /// > | >>>
+    ///     ^^^
     /// ```
-    DefinitionDestinationLiteral,
-    /// Definition destination literal marker.
+    BlockQuoteFencedFence,
+    /// Block quote (fenced) fence sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral]
+    ///     [`BlockQuoteFencedFence`][Name::BlockQuoteFencedFence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`block_quote_fenced`][crate::construct::block_quote_fenced]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: <b> "c"
-    ///          ^ ^
+    /// > | >>>
+    ///     ^^^
+    ///   | a
+    /// > | >>>
+    ///     ^^^
     /// ```
-    DefinitionDestinationLiteralMarker,
-    /// Definition destination raw.
+    BlockQuoteFencedFenceSequence,
+    /// Block quote marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestination`][Name::DefinitionDestination]
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix]
     /// *   **Content model**:
-    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
+    ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`block_quote`][crate::construct::block_quote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
+    /// > | > a
+    ///     ^
+    ///   | b
     /// ```
-    DefinitionDestinationRaw,
-    /// Definition destination data.
+    BlockQuoteMarker,
+    /// Block quote prefix.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
-    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
+    ///     [`BlockQuote`][Name::BlockQuote]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     [`BlockQuoteMarker`][Name::BlockQuoteMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`block_quote`][crate::construct::block_quote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///          ^
-    /// > | [a]: <b> "c"
-    ///           ^
+    /// > | > a
+    ///     ^^
+    ///   | b
     /// ```
-    DefinitionDestinationString,
-    /// Whole definition label.
+    BlockQuotePrefix,
+    /// Whole bracketed span.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`DefinitionLabelMarker`][Name::DefinitionLabelMarker],
-    ///     [`DefinitionLabelString`][Name::DefinitionLabelString],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`BracketedSpanAttributes`][Name::BracketedSpanAttributes],
+    ///     [`LabelEnd`][Name::LabelEnd],
+    ///     [`LabelLink`][Name::LabelLink],
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`label_end`][crate::construct::label_end]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^^^
+    /// > | a [b]{.c} d
+    ///       ^^^^^^^
     /// ```
-    DefinitionLabel,
-    /// Definition label marker.
+    BracketedSpan,
+    /// Bracketed span attributes.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    ///     [`BracketedSpan`][Name::BracketedSpan]
     /// *   **Content model**:
-    ///     void
+    ///     [`AttributeListClass`][Name::AttributeListClass],
+    ///     [`AttributeListId`][Name::AttributeListId],
+    ///     [`AttributeListMarker`][Name::AttributeListMarker],
+    ///     [`AttributeListProperty`][Name::AttributeListProperty],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///     ^ ^
+    /// > | a [b]{#c .d e=f} g
+    ///          ^^^^^^^^^^^
     /// ```
-    DefinitionLabelMarker,
-    /// Definition label data.
+    BracketedSpanAttributes,
+    /// Byte order mark.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionLabel`][Name::DefinitionLabel]
+    ///     optional first event
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
-    ///
-    /// ## Example
-    ///
-    /// ```markdown
-    /// > | [a]: b "c"
-    ///      ^
-    /// ```
-    DefinitionLabelString,
-    /// Definition marker.
+    ///     [`document`][crate::construct::document]
+    ByteOrderMark,
+    /// Whole character escape.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition],
-    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
+    ///     [string content][crate::construct::string] or
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`CharacterEscapeMarker`][Name::CharacterEscapeMarker],
+    ///     [`CharacterEscapeValue`][Name::CharacterEscapeValue]
     /// *   **Construct**:
-    ///     [`definition`][crate::construct::definition]
+    ///     [`character_escape`][crate::construct::character_escape]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///        ^
+    /// > | a \- b
+    ///       ^^
     /// ```
-    DefinitionMarker,
-    /// Whole definition title.
+    CharacterEscape,
+    /// Character escape marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Definition`][Name::Definition]
+    ///     [`CharacterEscape`][Name::CharacterEscape]
     /// *   **Content model**:
-    ///     [`DefinitionTitleMarker`][Name::DefinitionTitleMarker],
-    ///     [`DefinitionTitleString`][Name::DefinitionTitleString],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`character_escape`][crate::construct::character_escape]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///            ^^^
+    /// > | a \- b
+    ///       ^
     /// ```
-    DefinitionTitle,
-    /// Definition title marker.
+    CharacterEscapeMarker,
+    /// Character escape value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    ///     [`CharacterEscape`][Name::CharacterEscape]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`character_escape`][crate::construct::character_escape]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///            ^ ^
+    /// > | a \- b
+    ///        ^
     /// ```
-    DefinitionTitleMarker,
-    /// Definition title data.
+    CharacterEscapeValue,
+    /// Whole character reference.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`DefinitionTitle`][Name::DefinitionTitle]
+    ///     [string content][crate::construct::string] or
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     [`CharacterReferenceMarker`][Name::CharacterReferenceMarker],
+    ///     [`CharacterReferenceMarkerHexadecimal`][Name::CharacterReferenceMarkerHexadecimal],
+    ///     [`CharacterReferenceMarkerNumeric`][Name::CharacterReferenceMarkerNumeric],
+    ///     [`CharacterReferenceMarkerSemi`][Name::CharacterReferenceMarkerSemi],
+    ///     [`CharacterReferenceValue`][Name::CharacterReferenceValue]
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [a]: b "c"
-    ///             ^
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///       ^^^^^   ^^^^^^^   ^^^^^^^^^
     /// ```
-    DefinitionTitleString,
-    /// Emphasis.
+    CharacterReference,
+    /// Character reference opening marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CharacterReference`][Name::CharacterReference]
     /// *   **Content model**:
-    ///     [`EmphasisSequence`][Name::EmphasisSequence],
-    ///     [`EmphasisText`][Name::EmphasisText]
+    ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | *a*
-    ///     ^^^
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///       ^       ^         ^
     /// ```
-    Emphasis,
-    /// Emphasis sequence.
+    CharacterReferenceMarker,
+    /// Character reference hexadecimal numeric marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Emphasis`][Name::Emphasis]
+    ///     [`CharacterReference`][Name::CharacterReference]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | *a*
-    ///     ^ ^
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///                           ^
     /// ```
-    EmphasisSequence,
-    /// Emphasis text.
+    CharacterReferenceMarkerHexadecimal,
+    /// Character reference numeric marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Emphasis`][Name::Emphasis]
+    ///     [`CharacterReference`][Name::CharacterReference]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | *a*
-    ///      ^
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///                ^         ^
     /// ```
-    EmphasisText,
-    /// Whole frontmatter.
+    CharacterReferenceMarkerNumeric,
+    /// Character reference closing marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [document content][crate::construct::document]
+    ///     [`CharacterReference`][Name::CharacterReference]
     /// *   **Content model**:
-    ///     [`FrontmatterFence`][Name::FrontmatterFence],
-    ///     [`FrontmatterChunk`][Name::FrontmatterChunk],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     void
     /// *   **Construct**:
-    ///     [`frontmatter`][crate::construct::frontmatter]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ---
-    ///     ^^^
-    /// > | title: Neptune
-    ///     ^^^^^^^^^^^^^^
-    /// > | ---
-    ///     ^^^
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///           ^         ^           ^
     /// ```
-    Frontmatter,
-    /// Frontmatter chunk.
+    CharacterReferenceMarkerSemi,
+    /// Character reference value.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Frontmatter`][Name::Frontmatter]
+    ///     [`CharacterReference`][Name::CharacterReference]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`frontmatter`][crate::construct::frontmatter]
+    ///     [`character_reference`][crate::construct::character_reference]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | ---
-    /// > | title: Neptune
-    ///     ^^^^^^^^^^^^^^
-    ///   | ---
+    /// > | a &amp; b &#8800; c &#x1D306; d
+    ///        ^^^      ^^^^       ^^^^^
     /// ```
-    FrontmatterChunk,
-    /// Frontmatter fence.
+    CharacterReferenceValue,
+    /// Pandoc extension: citation.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Frontmatter`][Name::Frontmatter]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`FrontmatterSequence`][Name::FrontmatterSequence],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`CitationItem`][Name::CitationItem],
+    ///     [`CitationMarker`][Name::CitationMarker]
     /// *   **Construct**:
-    ///     [`frontmatter`][crate::construct::frontmatter]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ---
-    ///     ^^^
-    ///   | title: Neptune
-    /// > | ---
-    ///     ^^^
+    /// > | a [see @b, c; @d] e
+    ///       ^^^^^^^^^^^^^^^
+    /// > | a @b [c] d
+    ///       ^^^^^^
     /// ```
-    FrontmatterFence,
-    /// Frontmatter sequence.
+    Citation,
+    /// Pandoc extension: citation item.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`FrontmatterFence`][Name::FrontmatterFence]
+    ///     [`Citation`][Name::Citation]
     /// *   **Content model**:
-    ///     void
+    ///     [`CitationKey`][Name::CitationKey],
+    ///     [`CitationKeyMarker`][Name::CitationKeyMarker],
+    ///     [`CitationLocator`][Name::CitationLocator],
+    ///     [`CitationLocatorMarker`][Name::CitationLocatorMarker],
+    ///     [`CitationMarker`][Name::CitationMarker],
+    ///     [`CitationPrefix`][Name::CitationPrefix],
+    ///     [`CitationSuppressAuthorMarker`][Name::CitationSuppressAuthorMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`frontmatter`][crate::construct::frontmatter]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ---
-    ///     ^^^
-    ///   | title: Neptune
-    /// > | ---
-    ///     ^^^
+    /// > | a [see @b, c; @d] e
+    ///        ^^^^^^^^^  ^^
     /// ```
-    FrontmatterSequence,
-    /// GFM extension: email autolink.
+    CitationItem,
+    /// Pandoc extension: citation key.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     void.
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | context@example.com
-    ///     ^^^^^^^^^^^^^^^^^^^
+    /// > | a [@b] c
+    ///         ^
     /// ```
-    GfmAutolinkLiteralEmail,
-    /// GFM extension: email autolink w/ explicit `mailto`.
+    CitationKey,
+    /// Pandoc extension: citation key marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     void.
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | mailto:context@example.com
-    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// > | a [@b] c
+    ///        ^
     /// ```
-    GfmAutolinkLiteralMailto,
-    /// GFM extension: autolink w/ protocol.
+    CitationKeyMarker,
+    /// Pandoc extension: citation locator.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     void.
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | https://example.com
-    ///     ^^^^^^^^^^^^^^^^^^^
+    /// > | a [@b, c] d
+    ///            ^
+    /// > | a @b [c] d
+    ///           ^
     /// ```
-    GfmAutolinkLiteralProtocol,
-    /// GFM extension: autolink w/ www.
+    CitationLocator,
+    /// Pandoc extension: citation locator marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     void.
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | www.example.com
-    ///     ^^^^^^^^^^^^^^^
+    /// > | a [@b, c] d
+    ///          ^
     /// ```
-    GfmAutolinkLiteralWww,
-    /// GFM extension: email autolink w/ explicit `xmpp`.
+    CitationLocatorMarker,
+    /// Pandoc extension: citation marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`Citation`][Name::Citation],
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     void.
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | mailto:a@b.c/d
-    ///     ^^^^^^^^^^^^^^
+    /// > | a [@b; @c] d
+    ///       ^  ^   ^
+    /// > | a @b [c] d
+    ///          ^ ^
     /// ```
-    GfmAutolinkLiteralXmpp,
-    /// GFM extension: whole footnote call.
+    CitationMarker,
+    /// Pandoc extension: citation prefix.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     [`Label`][Name::Label]
+    ///     void
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [^b] c
-    ///       ^^^^
+    /// > | a [see @b] c
+    ///        ^^^^
     /// ```
-    GfmFootnoteCall,
-    /// GFM extension: label start (footnote).
+    CitationPrefix,
+    /// Pandoc extension: citation suppress author marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Label`][Name::Label]
+    ///     [`CitationItem`][Name::CitationItem]
     /// *   **Content model**:
-    ///     [`GfmFootnoteCallMarker`][Name::GfmFootnoteCallMarker],
-    ///     [`LabelMarker`][Name::LabelMarker]
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_label_start_footnote`][crate::construct::gfm_label_start_footnote]
+    ///     [`citation`][crate::construct::citation]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [^b] c
-    ///       ^^
+    /// > | a [-@b] c
+    ///        ^
     /// ```
-    GfmFootnoteCallLabel,
-    /// GFM extension: label start (footnote) marker.
+    CitationSuppressAuthorMarker,
+    /// Whole code (fenced).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteCallLabel`][Name::GfmFootnoteCallLabel]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`CodeFencedFence`][Name::CodeFencedFence],
+    ///     [`CodeFlowChunk`][Name::CodeFlowChunk],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`gfm_label_start_footnote`][crate::construct::gfm_label_start_footnote]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
-    /// ```markdown
-    /// > | a [^b] c
-    ///        ^
-    /// ```
-    GfmFootnoteCallMarker,
-    /// GFM extension: whole footnote definition.
+    /// ````markdown
+    /// > | ```js
+    ///     ^^^^^
+    /// > | console.log(1)
+    ///     ^^^^^^^^^^^^^^
+    /// > | ```
+    ///     ^^^
+    /// ````
+    CodeFenced,
+    /// A code (fenced) fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [document content][crate::construct::document]
+    ///     [`CodeFenced`][Name::CodeFenced]
     /// *   **Content model**:
-    ///     [`GfmFootnoteDefinitionPrefix`][Name::GfmFootnoteDefinitionPrefix],
-    ///     [document content][crate::construct::flow]
+    ///     [`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo],
+    ///     [`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],
+    ///     [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
-    /// ```markdown
-    /// > | [^a]: b
-    ///     ^^^^^^^
-    /// ```
-    GfmFootnoteDefinition,
-    /// GFM extension: footnote definition prefix.
+    /// ````markdown
+    /// > | ```js
+    ///     ^^^^^
+    ///   | console.log(1)
+    /// > | ```
+    ///     ^^^
+    /// ````
+    CodeFencedFence,
+    /// A code (fenced) fence info word.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
+    ///     [`CodeFencedFence`][Name::CodeFencedFence]
     /// *   **Content model**:
-    ///     [`DefinitionMarker`][Name::DefinitionMarker],
-    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
-    /// ```markdown
-    /// > | [^a]: b
-    ///     ^^^^^^
-    /// ```
-    GfmFootnoteDefinitionPrefix,
-    /// GFM extension: footnote definition label.
+    /// ````markdown
+    /// > | ```js
+    ///        ^^
+    ///   | console.log(1)
+    ///   | ```
+    /// ````
+    CodeFencedFenceInfo,
+    /// A code (fenced) fence meta string.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteDefinitionPrefix`][Name::GfmFootnoteDefinitionPrefix]
+    ///     [`CodeFencedFence`][Name::CodeFencedFence]
     /// *   **Content model**:
-    ///     [`GfmFootnoteDefinitionLabelMarker`][Name::GfmFootnoteDefinitionLabelMarker],
-    ///     [`GfmFootnoteDefinitionLabelString`][Name::GfmFootnoteDefinitionLabelString],
-    ///     [`GfmFootnoteDefinitionMarker`][Name::GfmFootnoteDefinitionMarker]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
-    /// ```markdown
-    /// > | [^a]: b
-    ///     ^^^^
-    /// ```
-    GfmFootnoteDefinitionLabel,
-    /// GFM extension: footnote definition label marker.
+    /// ````markdown
+    /// > | ```js highlight="1"
+    ///           ^^^^^^^^^^^^^
+    ///   | console.log(1)
+    ///   | ```
+    /// ````
+    CodeFencedFenceMeta,
+    /// A code (fenced) fence sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    ///     [`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
-    /// ```markdown
-    /// > | [^a]: b
-    ///     ^  ^
-    GfmFootnoteDefinitionLabelMarker,
-    /// GFM extension: footnote definition label string.
+    /// ````markdown
+    /// > | ```js
+    ///     ^^^
+    ///   | console.log(1)
+    /// > | ```
+    ///     ^^^
+    /// ````
+    CodeFencedFenceSequence,
+    /// A code (fenced, indented) chunk.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    ///     [`CodeFenced`][Name::CodeFenced],
+    ///     [`CodeIndented`][Name::CodeIndented]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow],
+    ///     [`code_indented`][crate::construct::code_indented]
     ///
     /// ## Example
     ///
+    /// ````markdown
+    ///   | ```js
+    /// > | console.log(1)
+    ///     ^^^^^^^^^^^^^^
+    ///   | ```
+    /// ````
+    ///
     /// ```markdown
-    /// > | [^a]: b
-    ///       ^
-    GfmFootnoteDefinitionLabelString,
-    /// GFM extension: footnote definition marker.
+    /// > | ␠␠␠␠console.log(1)
+    ///         ^^^^^^^^^^^^^^
+    /// ```
+    CodeFlowChunk,
+    /// Whole code (indented).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`CodeFlowChunk`][Name::CodeFlowChunk],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///     [`raw_flow`][crate::construct::raw_flow]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | [^a]: b
-    ///      ^
-    GfmFootnoteDefinitionMarker,
-    /// GFM extension: Strikethrough.
+    /// ␠␠␠␠console.log(1)
+    /// ^^^^^^^^^^^^^^^^^^
+    /// ```
+    CodeIndented,
+    /// Whole code (text).
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`GfmStrikethroughSequence`][Name::GfmStrikethroughSequence],
-    ///     [`GfmStrikethroughText`][Name::GfmStrikethroughText]
+    ///     [`CodeTextData`][Name::CodeTextData],
+    ///     [`CodeTextSequence`][Name::CodeTextSequence],
+    ///     [`LineEnding`][Name::LineEnding]
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`raw_text`][crate::construct::raw_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ~a~
-    ///     ^^^
+    /// > | a `b` c
+    ///       ^^^
     /// ```
-    GfmStrikethrough,
-    /// GFM extension: Strikethrough sequence.
+    CodeText,
+    /// Code (text) data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmStrikethrough`][Name::GfmStrikethrough]
+    ///     [`CodeText`][Name::CodeText]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`raw_text`][crate::construct::raw_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ~a~
-    ///     ^ ^
+    /// > | a `b` c
+    ///        ^
     /// ```
-    GfmStrikethroughSequence,
-    /// GFM extension: Strikethrough text.
+    CodeTextData,
+    /// Code (text) sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmStrikethrough`][Name::GfmStrikethrough]
+    ///     [`CodeText`][Name::CodeText]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`raw_text`][crate::construct::raw_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ~a~
-    ///      ^
+    /// > | a `b` c
+    ///       ^ ^
     /// ```
-    GfmStrikethroughText,
-    /// GFM extension: Table.
+    CodeTextSequence,
+    /// Content.
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     [`GfmTableBody`][Name::GfmTableBody],
-    ///     [`GfmTableHead`][Name::GfmTableHead],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     [content][crate::construct::content]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`content`][crate::construct::content]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///     ^^^^^
-    /// > | | - |
-    ///     ^^^^^
-    /// > | | b |
-    ///     ^^^^^
+    /// > | [a]: b
+    ///     ^^^^^^
+    /// > | c.
+    ///     ^^
     /// ```
-    GfmTable,
-    /// GFM extension: Table body.
+    Content,
+    /// Critic markup addition.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTable`][Name::GfmTable]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`GfmTableRow`][Name::GfmTableRow],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     [`CriticMarkupSequence`][Name::CriticMarkupSequence],
+    ///     [`CriticMarkupText`][Name::CriticMarkupText]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a |
-    ///   | | - |
-    /// > | | b |
-    ///     ^^^^^
+    /// > | {++a++}
+    ///     ^^^^^^^
     /// ```
-    GfmTableBody,
-    /// GFM extension: Table cell.
+    CriticMarkupAddition,
+    /// Critic markup comment.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableRow`][Name::GfmTableRow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`GfmTableCellDivider`][Name::GfmTableCellDivider],
-    ///     [`GfmTableCellText`][Name::GfmTableCellText],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`CriticMarkupSequence`][Name::CriticMarkupSequence],
+    ///     [`CriticMarkupText`][Name::CriticMarkupText]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///     ^^^^^
-    ///   | | - |
-    /// > | | b |
-    ///     ^^^^^
+    /// > | {>>a<<}
+    ///     ^^^^^^^
     /// ```
-    GfmTableCell,
-    /// GFM extension: Table cell text.
+    CriticMarkupComment,
+    /// Critic markup deletion.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableCell`][Name::GfmTableCell]
-    /// *   **Content model**:
     ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`CriticMarkupSequence`][Name::CriticMarkupSequence],
+    ///     [`CriticMarkupText`][Name::CriticMarkupText]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///       ^
-    ///   | | - |
-    /// > | | b |
-    ///       ^
+    /// > | {--a--}
+    ///     ^^^^^^^
     /// ```
-    GfmTableCellText,
-    /// GFM extension: Table cell divider.
+    CriticMarkupDeletion,
+    /// Critic markup highlight.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableCell`][Name::GfmTableCell]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`CriticMarkupSequence`][Name::CriticMarkupSequence],
+    ///     [`CriticMarkupText`][Name::CriticMarkupText]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///     ^   ^
-    /// > | | - |
-    ///     ^   ^
-    /// > | | b |
-    ///     ^   ^
+    /// > | {==a==}
+    ///     ^^^^^^^
     /// ```
-    GfmTableCellDivider,
-    /// GFM extension: Table delimiter row.
+    CriticMarkupHighlight,
+    /// Critic markup sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableHead`][Name::GfmTableHead]
+    ///     [`CriticMarkupAddition`][Name::CriticMarkupAddition],
+    ///     [`CriticMarkupComment`][Name::CriticMarkupComment],
+    ///     [`CriticMarkupDeletion`][Name::CriticMarkupDeletion],
+    ///     [`CriticMarkupHighlight`][Name::CriticMarkupHighlight],
+    ///     [`CriticMarkupSubstitution`][Name::CriticMarkupSubstitution]
     /// *   **Content model**:
-    ///     [`GfmTableDelimiterCell`][Name::GfmTableDelimiterCell]
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a |
-    /// > | | - |
-    ///     ^^^^^
-    ///   | | b |
+    /// > | {~~a~>b~~}
+    ///     ^^^ ^^ ^^^
     /// ```
-    GfmTableDelimiterRow,
-    /// GFM extension: Table delimiter alignment marker.
+    CriticMarkupSequence,
+    /// Critic markup substitution.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`CriticMarkupSequence`][Name::CriticMarkupSequence],
+    ///     [`CriticMarkupText`][Name::CriticMarkupText]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a  |
-    /// > | | :- |
-    ///       ^
-    ///   | | b  |
+    /// > | {~~a~>b~~}
+    ///     ^^^^^^^^^^
     /// ```
-    GfmTableDelimiterMarker,
-    /// GFM extension: Table delimiter cell.
+    CriticMarkupSubstitution,
+    /// Critic markup text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableDelimiterRow`][Name::GfmTableDelimiterRow]
+    ///     [`CriticMarkupAddition`][Name::CriticMarkupAddition],
+    ///     [`CriticMarkupComment`][Name::CriticMarkupComment],
+    ///     [`CriticMarkupDeletion`][Name::CriticMarkupDeletion],
+    ///     [`CriticMarkupHighlight`][Name::CriticMarkupHighlight],
+    ///     [`CriticMarkupSubstitution`][Name::CriticMarkupSubstitution]
     /// *   **Content model**:
-    ///     [`GfmTableCellDivider`][Name::GfmTableCellDivider],
-    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`critic_markup`][crate::construct::critic_markup]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a |
-    /// > | | - |
-    ///     ^^^^^
-    ///   | | b |
+    /// > | {~~a~>b~~}
+    ///        ^  ^
     /// ```
-    GfmTableDelimiterCell,
-    /// GFM extension: Table delimiter cell alignment.
+    CriticMarkupText,
+    /// Whole custom block.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableDelimiterCell`][Name::GfmTableDelimiterCell]
+    ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     [`GfmTableDelimiterMarker`][Name::GfmTableDelimiterMarker],
-    ///     [`GfmTableDelimiterFiller`][Name::GfmTableDelimiterFiller]
+    ///     [`CustomBlockFence`][Name::CustomBlockFence],
+    ///     [`CustomBlockPrefix`][Name::CustomBlockPrefix],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [flow content][crate::construct::flow]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`custom_block`][crate::construct::custom_block]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a |
-    /// > | | - |
-    ///       ^
-    ///   | | b |
+    /// > | !!! a
+    ///     ^^^^^
+    /// > | b
+    ///     ^
+    /// > | !!!
+    ///     ^^^
     /// ```
-    GfmTableDelimiterCellValue,
-    /// GFM extension: Table delimiter filler.
+    CustomBlock,
+    /// Custom block fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue]
+    ///     [`CustomBlock`][Name::CustomBlock]
     /// *   **Content model**:
-    ///     void
+    ///     [`CustomBlockFenceInfo`][Name::CustomBlockFenceInfo],
+    ///     [`CustomBlockMarker`][Name::CustomBlockMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`custom_block`][crate::construct::custom_block]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | | a |
-    /// > | | - |
-    ///       ^
-    ///   | | b |
+    /// > | !!! a
+    ///     ^^^^^
+    ///   | b
+    /// > | !!!
+    ///     ^^^
     /// ```
-    GfmTableDelimiterFiller,
-    /// GFM extension: Table head.
+    CustomBlockFence,
+    /// Custom block fence info.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTable`][Name::GfmTable]
+    ///     [`CustomBlockFence`][Name::CustomBlockFence]
     /// *   **Content model**:
-    ///     [`GfmTableRow`][Name::GfmTableRow],
-    ///     [`GfmTableDelimiterRow`][Name::GfmTableDelimiterRow],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`custom_block`][crate::construct::custom_block]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///     ^^^^^
-    /// > | | - |
-    ///     ^^^^^
-    ///   | | b |
+    /// > | !!! a
+    ///         ^
+    ///   | b
+    ///   | !!!
     /// ```
-    GfmTableHead,
-    /// GFM extension: Table row.
+    CustomBlockFenceInfo,
+    /// Custom block marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTableBody`][Name::GfmTableBody],
-    ///     [`GfmTableHead`][Name::GfmTableHead]
+    ///     [`CustomBlockFence`][Name::CustomBlockFence],
+    ///     [`CustomBlockPrefix`][Name::CustomBlockPrefix]
     /// *   **Content model**:
-    ///     [`GfmTableCell`][Name::GfmTableCell]
+    ///     void
     /// *   **Construct**:
-    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///     [`custom_block`][crate::construct::custom_block]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | | a |
-    ///     ^^^^^
-    ///   | | - |
-    /// > | | b |
-    ///     ^^^^^
+    /// > | !!! a
+    ///     ^^^
+    /// > | % b
+    ///     ^
     /// ```
-    GfmTableRow,
-    /// GFM extension: task list item check.
+    CustomBlockMarker,
+    /// Custom block prefix.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`CustomBlock`][Name::CustomBlock]
     /// *   **Content model**:
-    ///     [`GfmTaskListItemMarker`][Name::GfmTaskListItemMarker],
-    ///     [`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked],
-    ///     [`GfmTaskListItemValueUnchecked`][Name::GfmTaskListItemValueUnchecked]
+    ///     [`CustomBlockMarker`][Name::CustomBlockMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///     [`custom_block`][crate::construct::custom_block]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * [x] y.
-    ///       ^^^
+    /// > | % a
+    ///     ^^
     /// ```
-    GfmTaskListItemCheck,
-    /// GFM extension: task list item check marker.
+    CustomBlockPrefix,
+    /// Custom inline.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`CustomInlineSequence`][Name::CustomInlineSequence],
+    ///     [`CustomInlineText`][Name::CustomInlineText]
     /// *   **Construct**:
-    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///     [`custom_inline`][crate::construct::custom_inline]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * [x] y.
-    ///       ^ ^
+    /// > | %%a%%
+    ///     ^^^^^
     /// ```
-    GfmTaskListItemMarker,
-    /// GFM extension: task list item value: checked.
+    CustomInline,
+    /// Custom inline sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    ///     [`CustomInline`][Name::CustomInline]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///     [`custom_inline`][crate::construct::custom_inline]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * [x] y.
-    ///        ^
+    /// > | %%a%%
+    ///     ^^ ^^
     /// ```
-    GfmTaskListItemValueChecked,
-    /// GFM extension: task list item value: unchecked.
+    CustomInlineSequence,
+    /// Custom inline text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    ///     [`CustomInline`][Name::CustomInline]
     /// *   **Content model**:
-    ///     void
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///     [`custom_inline`][crate::construct::custom_inline]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * [ ] z.
-    ///        ^
+    /// > | %%a%%
+    ///       ^
     /// ```
-    GfmTaskListItemValueUnchecked,
-    /// Whole hard break (escape).
+    CustomInlineText,
+    /// Data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
+    ///     [string content][crate::construct::string],
     ///     [text content][crate::construct::text]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`hard_break_escape`][crate::construct::hard_break_escape]
+    ///     [`data`][crate::construct::partial_data]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a\␊
-    ///      ^
-    /// > | b
+    /// > | aa *bb* cc
+    ///     ^^^ ^^ ^^^
     /// ```
-    HardBreakEscape,
-    /// Whole hard break (trailing).
+    Data,
+    /// Whole definition.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`DefinitionMarker`][Name::DefinitionMarker],
+    ///     [`DefinitionLabel`][Name::DefinitionLabel],
+    ///     [`DefinitionDestination`][Name::DefinitionDestination],
+    ///     [`DefinitionTitle`][Name::DefinitionTitle],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`whitespace`][crate::construct::partial_whitespace]
+    ///     [`definition`][crate::construct::definition]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a␠␠␊
-    ///      ^^
-    /// > | b
+    /// > | [a]: b "c"
+    ///     ^^^^^^^^^^
     /// ```
-    HardBreakTrailing,
-    /// Whole heading (atx).
+    Definition,
+    /// Whole definition destination.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`Definition`][Name::Definition]
     /// *   **Content model**:
-    ///     [`HeadingAtxSequence`][Name::HeadingAtxSequence],
-    ///     [`HeadingAtxText`][Name::HeadingAtxText],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
+    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
     /// *   **Construct**:
-    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | # alpha
-    ///     ^^^^^^^
+    /// > | [a]: b "c"
+    ///          ^
+    /// > | [a]: <b> "c"
+    ///          ^^^
     /// ```
-    HeadingAtx,
-    /// Heading (atx) sequence.
+    DefinitionDestination,
+    /// Definition destination literal.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HeadingAtx`][Name::HeadingAtx]
+    ///     [`DefinitionDestination`][Name::DefinitionDestination]
     /// *   **Content model**:
-    ///     void
+    ///     [`DefinitionDestinationLiteralMarker`][Name::DefinitionDestinationLiteralMarker],
+    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
     /// *   **Construct**:
-    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | # alpha
-    ///     ^
+    /// > | [a]: <b> "c"
+    ///          ^^^
     /// ```
-    HeadingAtxSequence,
-    /// Heading (atx) data.
+    DefinitionDestinationLiteral,
+    /// Definition destination literal marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HeadingAtx`][Name::HeadingAtx]
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     void
     /// *   **Construct**:
-    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | # alpha
-    ///       ^^^^^
+    /// > | [a]: <b> "c"
+    ///          ^ ^
     /// ```
-    HeadingAtxText,
-    /// Whole heading (setext).
+    DefinitionDestinationLiteralMarker,
+    /// Definition destination raw.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`DefinitionDestination`][Name::DefinitionDestination]
     /// *   **Content model**:
-    ///     [`HeadingSetextText`][Name::HeadingSetextText],
-    ///     [`HeadingSetextUnderline`][Name::HeadingSetextUnderline],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`DefinitionDestinationString`][Name::DefinitionDestinationString]
     /// *   **Construct**:
-    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | alpha
-    ///     ^^^^^
-    /// > | =====
-    ///     ^^^^^
+    /// > | [a]: b "c"
+    ///          ^
     /// ```
-    HeadingSetext,
-    /// Heading (setext) data.
+    DefinitionDestinationRaw,
+    /// Definition destination data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HeadingSetext`][Name::HeadingSetext]
+    ///     [`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],
+    ///     [`DefinitionDestinationRaw`][Name::DefinitionDestinationRaw]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | alpha
-    ///     ^^^^^
-    ///   | =====
+    /// > | [a]: b "c"
+    ///          ^
+    /// > | [a]: <b> "c"
+    ///           ^
     /// ```
-    HeadingSetextText,
-    /// Heading (setext) underline.
+    DefinitionDestinationString,
+    /// Whole definition label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HeadingSetext`][Name::HeadingSetext]
+    ///     [`Definition`][Name::Definition]
     /// *   **Content model**:
-    ///     [`HeadingSetextUnderlineSequence`][Name::HeadingSetextUnderlineSequence],
+    ///     [`DefinitionLabelMarker`][Name::DefinitionLabelMarker],
+    ///     [`DefinitionLabelString`][Name::DefinitionLabelString],
+    ///     [`LineEnding`][Name::LineEnding],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///     [`label`][crate::construct::partial_label]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | alpha
-    /// > | =====
-    ///     ^^^^^
+    /// > | [a]: b "c"
+    ///     ^^^
     /// ```
-    HeadingSetextUnderline,
-    /// Heading (setext) underline sequence.
+    DefinitionLabel,
+    /// Definition label marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HeadingSetext`][Name::HeadingSetext]
+    ///     [`DefinitionLabel`][Name::DefinitionLabel]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///     [`label`][crate::construct::partial_label]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | alpha
-    /// > | =====
-    ///     ^^^^^
+    /// > | [a]: b "c"
+    ///     ^ ^
     /// ```
-    HeadingSetextUnderlineSequence,
-    /// Whole html (flow).
+    DefinitionLabelMarker,
+    /// Definition label data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`DefinitionLabel`][Name::DefinitionLabel]
     /// *   **Content model**:
-    ///     [`HtmlFlowData`][Name::HtmlFlowData],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`html_flow`][crate::construct::html_flow]
+    ///     [`label`][crate::construct::partial_label]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <div>
-    ///     ^^^^^
+    /// > | [a]: b "c"
+    ///      ^
     /// ```
-    HtmlFlow,
-    /// HTML (flow) data.
+    DefinitionLabelString,
+    /// Definition marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HtmlFlow`][Name::HtmlFlow]
+    ///     [`Definition`][Name::Definition],
+    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`html_flow`][crate::construct::html_flow]
+    ///     [`definition`][crate::construct::definition]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <div>
-    ///     ^^^^^
+    /// > | [a]: b "c"
+    ///        ^
     /// ```
-    HtmlFlowData,
-    /// Whole html (text).
+    DefinitionMarker,
+    /// Whole definition title.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`Definition`][Name::Definition]
     /// *   **Content model**:
-    ///     [`HtmlTextData`][Name::HtmlTextData],
+    ///     [`DefinitionTitleMarker`][Name::DefinitionTitleMarker],
+    ///     [`DefinitionTitleString`][Name::DefinitionTitleString],
     ///     [`LineEnding`][Name::LineEnding],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`html_text`][crate::construct::html_text]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///       ^^^
+    /// > | [a]: b "c"
+    ///            ^^^
     /// ```
-    HtmlText,
-    /// HTML (text) data.
+    DefinitionTitle,
+    /// Definition title marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`HtmlText`][Name::HtmlText]
+    ///     [`DefinitionTitle`][Name::DefinitionTitle]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`html_text`][crate::construct::html_text]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///       ^^^
+    /// > | [a]: b "c"
+    ///            ^ ^
     /// ```
-    HtmlTextData,
-    /// Image.
+    DefinitionTitleMarker,
+    /// Definition title data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`DefinitionTitle`][Name::DefinitionTitle]
     /// *   **Content model**:
-    ///     [`Label`][Name::Label],
-    ///     [`Resource`][Name::Resource],
-    ///     [`Reference`][Name::Reference]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b] c
-    ///       ^^^^
-    /// > | a ![b][c] d
-    ///       ^^^^^^^
-    /// > | a ![b](c) d
-    ///       ^^^^^^^
+    /// > | [a]: b "c"
+    ///             ^
     /// ```
-    Image,
-    /// Label.
+    DefinitionTitleString,
+    /// Directive attributes.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Image`][Name::Image],
-    ///     [`Link`][Name::Link]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     [`LabelImage`][Name::LabelImage],
-    ///     [`LabelLink`][Name::LabelLink],
-    ///     [`LabelEnd`][Name::LabelEnd],
-    ///     [`LabelText`][Name::LabelText]
+    ///     [`AttributeListClass`][Name::AttributeListClass],
+    ///     [`AttributeListId`][Name::AttributeListId],
+    ///     [`AttributeListMarker`][Name::AttributeListMarker],
+    ///     [`AttributeListProperty`][Name::AttributeListProperty],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [b] c
-    ///       ^^^
-    /// > | a ![b][c] d
-    ///       ^^^^
-    /// > | a [b](c) d
-    ///       ^^^
+    /// > | :a{#b .c d=e}
+    ///       ^^^^^^^^^^^
     /// ```
-    Label,
-    /// Label end.
+    DirectiveAttributes,
+    /// Whole directive (container).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Label`][Name::Label]
+    ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     [`LabelMarker`][Name::LabelMarker]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [flow content][crate::construct::flow]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c) d
-    ///          ^
-    /// > | a [b](c) d
-    ///         ^
+    /// > | :::a
+    ///     ^^^^
+    /// > | b
+    ///     ^
+    /// > | :::
+    ///     ^^^
     /// ```
-    LabelEnd,
-    /// Label start (image).
+    DirectiveContainer,
+    /// Directive (container) fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Label`][Name::Label]
+    ///     [`DirectiveContainer`][Name::DirectiveContainer]
     /// *   **Content model**:
-    ///     [`LabelImageMarker`][Name::LabelImageMarker],
-    ///     [`LabelMarker`][Name::LabelMarker]
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveContainerSequence`][Name::DirectiveContainerSequence],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveName`][Name::DirectiveName],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`label_start_image`][crate::construct::label_start_image]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c) d
-    ///       ^^
+    /// > | :::a[b]
+    ///     ^^^^^^^
+    ///   | c
+    /// > | :::
+    ///     ^^^
     /// ```
-    LabelImage,
-    /// Label start (image) marker.
+    DirectiveContainerFence,
+    /// Directive (container) fence sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`LabelImage`][Name::LabelImage]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`label_start_image`][crate::construct::label_start_image]
+    ///     [`directive_container`][crate::construct::directive_container]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c) d
-    ///       ^
+    /// > | :::a
+    ///     ^^^
+    ///   | b
+    /// > | :::
+    ///     ^^^
     /// ```
-    LabelImageMarker,
-    /// Label start (link).
+    DirectiveContainerSequence,
+    /// Whole directive label.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Label`][Name::Label]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     [`LabelMarker`][Name::LabelMarker]
+    ///     [`DirectiveLabelMarker`][Name::DirectiveLabelMarker],
+    ///     [`DirectiveLabelString`][Name::DirectiveLabelString]
     /// *   **Construct**:
-    ///     [`label_start_link`][crate::construct::label_start_link]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [b](c) d
-    ///       ^
+    /// > | :a[b]
+    ///       ^^^
     /// ```
-    LabelLink,
-    /// Label marker.
+    DirectiveLabel,
+    /// Directive label marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`LabelImage`][Name::LabelImage],
-    ///     [`LabelLink`][Name::LabelLink],
-    ///     [`LabelEnd`][Name::LabelEnd]
+    ///     [`DirectiveLabel`][Name::DirectiveLabel]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`label_start_image`][crate::construct::label_start_image],
-    ///     [`label_start_link`][crate::construct::label_start_link],
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c) d
-    ///        ^ ^
-    /// > | a [b](c) d
+    /// > | :a[b]
     ///       ^ ^
     /// ```
-    LabelMarker,
-    /// Label text.
+    DirectiveLabelMarker,
+    /// Directive label data.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Label`][Name::Label]
+    ///     [`DirectiveLabel`][Name::DirectiveLabel]
     /// *   **Content model**:
     ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [b] c
-    ///        ^
-    /// > | a ![b][c] d
-    ///         ^
-    /// > | a [b](c) d
+    /// > | :a[b]
     ///        ^
     /// ```
-    LabelText,
-    /// Line ending.
+    DirectiveLabelString,
+    /// Whole directive (leaf).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     basically everywhere
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     void
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveMarker`][Name::DirectiveMarker],
+    ///     [`DirectiveName`][Name::DirectiveName],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     n/a
+    ///     [`directive_leaf`][crate::construct::directive_leaf]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a␊
-    ///      ^
-    ///   | b
+    /// > | ::a[b]{c=d}
+    ///     ^^^^^^^^^^^
     /// ```
-    LineEnding,
-    /// Link.
+    DirectiveLeaf,
+    /// Directive marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     [`Label`][Name::Label],
-    ///     [`Resource`][Name::Resource],
-    ///     [`Reference`][Name::Reference]
+    ///     void
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`directive_leaf`][crate::construct::directive_leaf],
+    ///     [`directive_text`][crate::construct::directive_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a [b] c
-    ///       ^^^
-    /// > | a [b][c] d
-    ///       ^^^^^^
-    /// > | a [b](c) d
-    ///       ^^^^^^
+    /// > | ::a
+    ///     ^^
     /// ```
-    Link,
-    /// List item.
+    DirectiveMarker,
+    /// Directive name.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ListOrdered`][Name::ListOrdered],
-    ///     [`ListUnordered`][Name::ListUnordered]
+    ///     [`DirectiveContainerFence`][Name::DirectiveContainerFence],
+    ///     [`DirectiveLeaf`][Name::DirectiveLeaf],
+    ///     [`DirectiveText`][Name::DirectiveText]
     /// *   **Content model**:
-    ///     [`ListItemPrefix`][Name::ListItemPrefix],
-    ///     [flow content][crate::construct::flow]
+    ///     void
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`directive`][crate::construct::partial_directive]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * a
-    ///     ^^^
-    /// > | 1. b
-    ///     ^^^^
+    /// > | :ab[c]
+    ///      ^^
     /// ```
-    ListItem,
-    /// List item (marker).
+    DirectiveName,
+    /// Whole directive (text).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ListItemPrefix`][Name::ListItemPrefix]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`DirectiveAttributes`][Name::DirectiveAttributes],
+    ///     [`DirectiveLabel`][Name::DirectiveLabel],
+    ///     [`DirectiveMarker`][Name::DirectiveMarker],
+    ///     [`DirectiveName`][Name::DirectiveName]
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`directive_text`][crate::construct::directive_text]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * a
-    ///     ^
-    /// > | 1. b
-    ///      ^
+    /// > | a :b[c]{d=e} f
+    ///       ^^^^^^^^^^
     /// ```
-    ListItemMarker,
-    /// List item (prefix).
+    DirectiveText,
+    /// Emphasis.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ListItem`][Name::ListItem]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`ListItemMarker`][Name::ListItemMarker],
-    ///     [`ListItemValue`][Name::ListItemValue],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`EmphasisSequence`][Name::EmphasisSequence],
+    ///     [`EmphasisText`][Name::EmphasisText]
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * a
-    ///     ^^
-    /// > |   b
-    ///     ^^
+    /// > | *a*
+    ///     ^^^
     /// ```
-    ListItemPrefix,
-    /// List item (value).
+    Emphasis,
+    /// Emphasis sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ListItemPrefix`][Name::ListItemPrefix]
+    ///     [`Emphasis`][Name::Emphasis]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | 1. b
-    ///     ^
+    /// > | *a*
+    ///     ^ ^
     /// ```
-    ListItemValue,
-    /// List (ordered).
+    EmphasisSequence,
+    /// Emphasis text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [document content][crate::construct::document]
+    ///     [`Emphasis`][Name::Emphasis]
     /// *   **Content model**:
-    ///     [`BlankLineEnding`][Name::BlankLineEnding],
-    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
-    ///     [`ListItem`][Name::ListItem],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | 1. a
-    ///     ^^^^
-    /// > | 2. b
-    ///     ^^^^
+    /// > | *a*
+    ///      ^
     /// ```
-    ListOrdered,
-    /// List (unordered).
+    EmphasisText,
+    /// Whole fenced div.
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     [`BlankLineEnding`][Name::BlankLineEnding],
-    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
-    ///     [`ListItem`][Name::ListItem],
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     [`FencedDivFence`][Name::FencedDivFence],
+    ///     [flow content][crate::construct::flow]
     /// *   **Construct**:
-    ///     [`list item`][crate::construct::list_item]
+    ///     [`fenced_div`][crate::construct::fenced_div]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * a
-    ///     ^^^
-    /// > | * b
+    /// > | ::: a
+    ///     ^^^^^
+    /// > | b
+    ///     ^
+    /// > | :::
     ///     ^^^
     /// ```
-    ListUnordered,
-    /// Whole math (flow).
+    FencedDiv,
+    /// Fenced div attributes.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`FencedDivFence`][Name::FencedDivFence]
     /// *   **Content model**:
-    ///     [`MathFlowFence`][Name::MathFlowFence],
-    ///     [`MathFlowChunk`][Name::MathFlowChunk],
-    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`AttributeListClass`][Name::AttributeListClass],
+    ///     [`AttributeListId`][Name::AttributeListId],
+    ///     [`AttributeListMarker`][Name::AttributeListMarker],
+    ///     [`AttributeListProperty`][Name::AttributeListProperty],
     ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`attribute_list`][crate::construct::attribute_list]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | $$
-    ///     ^^
-    /// > | \frac{1}{2}
-    ///     ^^^^^^^^^^^
-    /// > | $$
-    ///     ^^
+    /// > | ::: {#a .b c=d}
+    ///         ^^^^^^^^^^^
+    ///   | e
+    ///   | :::
     /// ```
-    MathFlow,
-    /// A math (flow) fence.
+    FencedDivAttributes,
+    /// Fenced div class.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathFlow`][Name::MathFlow]
+    ///     [`FencedDivFence`][Name::FencedDivFence]
     /// *   **Content model**:
-    ///     [`MathFlowFenceMeta`][Name::MathFlowFenceMeta],
-    ///     [`MathFlowFenceSequence`][Name::MathFlowFenceSequence],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`fenced_div`][crate::construct::fenced_div]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | $$
-    ///     ^^
-    ///   | \frac{1}{2}
-    /// > | $$
-    ///     ^^
+    /// > | ::: a
+    ///         ^
+    ///   | b
+    ///   | :::
     /// ```
-    MathFlowFence,
-    /// A math (flow) fence meta string.
+    FencedDivClass,
+    /// Fenced div fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathFlowFence`][Name::MathFlowFence]
+    ///     [`FencedDiv`][Name::FencedDiv]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     [`FencedDivAttributes`][Name::FencedDivAttributes],
+    ///     [`FencedDivClass`][Name::FencedDivClass],
+    ///     [`FencedDivSequence`][Name::FencedDivSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`fenced_div`][crate::construct::fenced_div]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | $$alpha bravo
-    ///       ^^^^^^^^^^^
-    ///   | \frac{1}{2}
-    ///   | $$
+    /// > | ::: a
+    ///     ^^^^^
+    ///   | b
+    /// > | :::
+    ///     ^^^
     /// ```
-    MathFlowFenceMeta,
-    /// A math (flow) fence sequence.
+    FencedDivFence,
+    /// Fenced div fence sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathFlowFenceSequence`][Name::MathFlowFenceSequence]
+    ///     [`FencedDivFence`][Name::FencedDivFence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`fenced_div`][crate::construct::fenced_div]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | $$
-    ///     ^^
-    ///   | \frac{1}{2}
-    /// > | $$
-    ///     ^^
+    /// > | ::: a :::
+    ///     ^^^   ^^^
+    ///   | b
+    /// > | :::
+    ///     ^^^
     /// ```
-    MathFlowFenceSequence,
-    /// A math (flow) chunk.
+    FencedDivSequence,
+    /// Whole frontmatter.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathFlow`][Name::MathFlow]
+    ///     [document content][crate::construct::document]
     /// *   **Content model**:
-    ///     void
+    ///     [`FrontmatterFence`][Name::FrontmatterFence],
+    ///     [`FrontmatterChunk`][Name::FrontmatterChunk],
+    ///     [`LineEnding`][Name::LineEnding]
     /// *   **Construct**:
-    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///     [`frontmatter`][crate::construct::frontmatter]
     ///
     /// ## Example
     ///
     /// ```markdown
-    ///   | $$
-    /// > | \frac{1}{2}
-    ///     ^^^^^^^^^^^
-    ///   | $$
+    /// > | ---
+    ///     ^^^
+    /// > | title: Neptune
+    ///     ^^^^^^^^^^^^^^
+    /// > | ---
+    ///     ^^^
     /// ```
-    MathFlowChunk,
-    /// Whole math (text).
+    Frontmatter,
+    /// Frontmatter chunk.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`Frontmatter`][Name::Frontmatter]
     /// *   **Content model**:
-    ///     [`MathTextData`][Name::MathTextData],
-    ///     [`MathTextSequence`][Name::MathTextSequence],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     void
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`frontmatter`][crate::construct::frontmatter]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a $b$ c
-    ///       ^^^
+    ///   | ---
+    /// > | title: Neptune
+    ///     ^^^^^^^^^^^^^^
+    ///   | ---
     /// ```
-    MathText,
-    /// Math (text) data.
+    FrontmatterChunk,
+    /// Frontmatter fence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathText`][Name::MathText]
+    ///     [`Frontmatter`][Name::Frontmatter]
     /// *   **Content model**:
-    ///     void
+    ///     [`FrontmatterSequence`][Name::FrontmatterSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`frontmatter`][crate::construct::frontmatter]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a `b` c
-    ///        ^
+    /// > | ---
+    ///     ^^^
+    ///   | title: Neptune
+    /// > | ---
+    ///     ^^^
     /// ```
-    MathTextData,
-    /// Math (text) sequence.
+    FrontmatterFence,
+    /// Frontmatter sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MathText`][Name::MathText]
+    ///     [`FrontmatterFence`][Name::FrontmatterFence]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`raw_text`][crate::construct::raw_text]
+    ///     [`frontmatter`][crate::construct::frontmatter]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a $b$ c
-    ///       ^ ^
+    /// > | ---
+    ///     ^^^
+    ///   | title: Neptune
+    /// > | ---
+    ///     ^^^
     /// ```
-    MathTextSequence,
-    /// MDX extension: ESM.
+    FrontmatterSequence,
+    /// GFM extension: alert.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
-    ///     [`MdxEsmData`][Name::MdxEsmData],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     [`GfmAlertMarker`][Name::GfmAlertMarker],
+    ///     [`GfmAlertType`][Name::GfmAlertType],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`mdx_esm`][crate::construct::mdx_esm]
+    ///     [`gfm_alert`][crate::construct::gfm_alert]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | import a from 'b'
-    ///     ^^^^^^^^^^^^^^^^^
+    /// > | > [!NOTE]
+    ///       ^^^^^^^
+    ///   | > a
     /// ```
-    MdxEsm,
-    /// MDX extension: ESM data.
+    GfmAlert,
+    /// GFM extension: alert marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxEsm`][Name::MdxEsm]
+    ///     [`GfmAlert`][Name::GfmAlert]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`mdx_esm`][crate::construct::mdx_esm]
+    ///     [`gfm_alert`][crate::construct::gfm_alert]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | import a from 'b'
-    ///     ^^^^^^^^^^^^^^^^^
+    /// > | > [!NOTE]
+    ///       ^^     ^
+    ///   | > a
     /// ```
-    MdxEsmData,
-    /// MDX extension: expression marker.
+    GfmAlertMarker,
+    /// GFM extension: alert type.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxFlowExpression`][Name::MdxFlowExpression],
-    ///     [`MdxTextExpression`][Name::MdxTextExpression],
-    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
-    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    ///     [`GfmAlert`][Name::GfmAlert]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_expression`][crate::construct::partial_mdx_expression]
+    ///     [`gfm_alert`][crate::construct::gfm_alert]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | {Math.PI}
-    ///     ^       ^
+    /// > | > [!NOTE]
+    ///         ^^^^
+    ///   | > a
     /// ```
-    MdxExpressionMarker,
-    /// MDX extension: expression data.
+    GfmAlertType,
+    /// GFM extension: email autolink.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxFlowExpression`][Name::MdxFlowExpression],
-    ///     [`MdxTextExpression`][Name::MdxTextExpression],
-    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
-    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     void.
     /// *   **Construct**:
-    ///     [`partial_mdx_expression`][crate::construct::partial_mdx_expression]
+    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | {Math.PI}
-    ///      ^^^^^^^
+    /// > | context@example.com
+    ///     ^^^^^^^^^^^^^^^^^^^
     /// ```
-    MdxExpressionData,
-    /// MDX extension: expression (flow).
+    GfmAutolinkLiteralEmail,
+    /// GFM extension: email autolink w/ explicit `mailto`.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
-    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    ///     void.
     /// *   **Construct**:
-    ///     [`mdx_expression_flow`][crate::construct::mdx_expression_flow]
+    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | {Math.PI}
-    ///     ^^^^^^^^^
+    /// > | mailto:context@example.com
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^
     /// ```
-    MdxFlowExpression,
-    /// MDX extension: expression (text).
+    GfmAutolinkLiteralMailto,
+    /// GFM extension: autolink w/ protocol.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
-    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    ///     void.
     /// *   **Construct**:
-    ///     [`mdx_expression_text`][crate::construct::mdx_expression_text]
+    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a {Math.PI} b
-    ///       ^^^^^^^^^
+    /// > | https://example.com
+    ///     ^^^^^^^^^^^^^^^^^^^
     /// ```
-    MdxTextExpression,
-    /// MDX extension: JSX (flow).
+    GfmAutolinkLiteralProtocol,
+    /// GFM extension: autolink w/ www.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagMarker`][Name::MdxJsxTagMarker],
-    ///     [`MdxJsxTagClosingMarker`][Name::MdxJsxTagClosingMarker],
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
-    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
-    ///     [`MdxJsxTagSelfClosingMarker`][Name::MdxJsxTagSelfClosingMarker]
+    ///     void.
     /// *   **Construct**:
-    ///     [`mdx_jsx_flow`][crate::construct::mdx_jsx_flow]
+    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | <B />
-    ///     ^^^^^
+    /// > | www.example.com
+    ///     ^^^^^^^^^^^^^^^
     /// ```
-    MdxJsxFlowTag,
-    /// MDX extension: JSX (text).
+    GfmAutolinkLiteralWww,
+    /// GFM extension: email autolink w/ explicit `xmpp`.
     ///
     /// ## Info
     ///
     /// *   **Context**:
     ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagMarker`][Name::MdxJsxTagMarker],
-    ///     [`MdxJsxTagClosingMarker`][Name::MdxJsxTagClosingMarker],
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
-    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
-    ///     [`MdxJsxTagSelfClosingMarker`][Name::MdxJsxTagSelfClosingMarker]
+    ///     void.
     /// *   **Construct**:
-    ///     [`mdx_jsx_text`][crate::construct::mdx_jsx_text]
+    ///     [`gfm_autolink_literal`][crate::construct::gfm_autolink_literal]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <B /> c
-    ///       ^^^^^
+    /// > | mailto:a@b.c/d
+    ///     ^^^^^^^^^^^^^^
     /// ```
-    MdxJsxTextTag,
-    /// MDX extension: JSX: ECMAScript whitespace.
+    GfmAutolinkLiteralXmpp,
+    /// GFM extension: whole footnote call.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag],
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
-    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`Label`][Name::Label]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`label_end`][crate::construct::label_end]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <B /> c
-    ///         ^
+    /// > | a [^b] c
+    ///       ^^^^
     /// ```
-    MdxJsxEsWhitespace,
-    /// MDX extension: JSX: tag marker.
+    GfmFootnoteCall,
+    /// GFM extension: label start (footnote).
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [`Label`][Name::Label]
     /// *   **Content model**:
-    ///     void
+    ///     [`GfmFootnoteCallMarker`][Name::GfmFootnoteCallMarker],
+    ///     [`LabelMarker`][Name::LabelMarker]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`gfm_label_start_footnote`][crate::construct::gfm_label_start_footnote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <B /> c
-    ///       ^   ^
+    /// > | a [^b] c
+    ///       ^^
     /// ```
-    MdxJsxTagMarker,
-    /// MDX extension: JSX: closing tag marker.
+    GfmFootnoteCallLabel,
+    /// GFM extension: label start (footnote) marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [`GfmFootnoteCallLabel`][Name::GfmFootnoteCallLabel]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`gfm_label_start_footnote`][crate::construct::gfm_label_start_footnote]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a </B> c
+    /// > | a [^b] c
     ///        ^
     /// ```
-    MdxJsxTagClosingMarker,
-    /// MDX extension: JSX: tag name.
+    GfmFootnoteCallMarker,
+    /// GFM extension: whole footnote definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`GfmFootnoteDefinitionPrefix`][Name::GfmFootnoteDefinitionPrefix],
+    ///     [document content][crate::construct::flow]
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///     ^^^^^^^
+    /// ```
+    GfmFootnoteDefinition,
+    /// GFM extension: footnote definition prefix.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition]
+    /// *   **Content model**:
+    ///     [`DefinitionMarker`][Name::DefinitionMarker],
+    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///     ^^^^^^
+    /// ```
+    GfmFootnoteDefinitionPrefix,
+    /// GFM extension: footnote definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmFootnoteDefinitionPrefix`][Name::GfmFootnoteDefinitionPrefix]
+    /// *   **Content model**:
+    ///     [`GfmFootnoteDefinitionLabelMarker`][Name::GfmFootnoteDefinitionLabelMarker],
+    ///     [`GfmFootnoteDefinitionLabelString`][Name::GfmFootnoteDefinitionLabelString],
+    ///     [`GfmFootnoteDefinitionMarker`][Name::GfmFootnoteDefinitionMarker]
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///     ^^^^
+    /// ```
+    GfmFootnoteDefinitionLabel,
+    /// GFM extension: footnote definition label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///     ^  ^
+    GfmFootnoteDefinitionLabelMarker,
+    /// GFM extension: footnote definition label string.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///       ^
+    GfmFootnoteDefinitionLabelString,
+    /// GFM extension: footnote definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmFootnoteDefinitionLabel`][Name::GfmFootnoteDefinitionLabel]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_footnote_definition`][crate::construct::gfm_footnote_definition]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | [^a]: b
+    ///      ^
+    GfmFootnoteDefinitionMarker,
+    /// GFM extension: Strikethrough.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`GfmStrikethroughSequence`][Name::GfmStrikethroughSequence],
+    ///     [`GfmStrikethroughText`][Name::GfmStrikethroughText]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ~a~
+    ///     ^^^
+    /// ```
+    GfmStrikethrough,
+    /// GFM extension: Strikethrough sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmStrikethrough`][Name::GfmStrikethrough]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ~a~
+    ///     ^ ^
+    /// ```
+    GfmStrikethroughSequence,
+    /// GFM extension: Strikethrough text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmStrikethrough`][Name::GfmStrikethrough]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | ~a~
+    ///      ^
+    /// ```
+    GfmStrikethroughText,
+    /// GFM extension: Table.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`GfmTableBody`][Name::GfmTableBody],
+    ///     [`GfmTableHead`][Name::GfmTableHead],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    /// > | | - |
+    ///     ^^^^^
+    /// > | | b |
+    ///     ^^^^^
+    /// ```
+    GfmTable,
+    /// GFM extension: Table body.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTable`][Name::GfmTable]
+    /// *   **Content model**:
+    ///     [`GfmTableRow`][Name::GfmTableRow],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    ///   | | - |
+    /// > | | b |
+    ///     ^^^^^
+    /// ```
+    GfmTableBody,
+    /// GFM extension: Table cell.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableRow`][Name::GfmTableRow]
+    /// *   **Content model**:
+    ///     [`GfmTableCellDivider`][Name::GfmTableCellDivider],
+    ///     [`GfmTableCellText`][Name::GfmTableCellText],
+    ///     [`GfmTableRowContinuation`][Name::GfmTableRowContinuation],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    ///   | | - |
+    /// > | | b |
+    ///     ^^^^^
+    /// ```
+    GfmTableCell,
+    /// GFM extension: Table cell text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableCell`][Name::GfmTableCell]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///       ^
+    ///   | | - |
+    /// > | | b |
+    ///       ^
+    /// ```
+    GfmTableCellText,
+    /// GFM extension: Table cell divider.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableCell`][Name::GfmTableCell]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^   ^
+    /// > | | - |
+    ///     ^   ^
+    /// > | | b |
+    ///     ^   ^
+    /// ```
+    GfmTableCellDivider,
+    /// GFM extension: Table delimiter row.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableHead`][Name::GfmTableHead]
+    /// *   **Content model**:
+    ///     [`GfmTableDelimiterCell`][Name::GfmTableDelimiterCell]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    /// > | | - |
+    ///     ^^^^^
+    ///   | | b |
+    /// ```
+    GfmTableDelimiterRow,
+    /// GFM extension: Table delimiter alignment marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a  |
+    /// > | | :- |
+    ///       ^
+    ///   | | b  |
+    /// ```
+    GfmTableDelimiterMarker,
+    /// GFM extension: Table delimiter cell.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableDelimiterRow`][Name::GfmTableDelimiterRow]
+    /// *   **Content model**:
+    ///     [`GfmTableCellDivider`][Name::GfmTableCellDivider],
+    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    /// > | | - |
+    ///     ^^^^^
+    ///   | | b |
+    /// ```
+    GfmTableDelimiterCell,
+    /// GFM extension: Table delimiter cell alignment.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableDelimiterCell`][Name::GfmTableDelimiterCell]
+    /// *   **Content model**:
+    ///     [`GfmTableDelimiterMarker`][Name::GfmTableDelimiterMarker],
+    ///     [`GfmTableDelimiterFiller`][Name::GfmTableDelimiterFiller]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    /// > | | - |
+    ///       ^
+    ///   | | b |
+    /// ```
+    GfmTableDelimiterCellValue,
+    /// GFM extension: Table delimiter filler.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableDelimiterCellValue`][Name::GfmTableDelimiterCellValue]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    /// > | | - |
+    ///       ^
+    ///   | | b |
+    /// ```
+    GfmTableDelimiterFiller,
+    /// GFM extension: Table head.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTable`][Name::GfmTable]
+    /// *   **Content model**:
+    ///     [`GfmTableRow`][Name::GfmTableRow],
+    ///     [`GfmTableDelimiterRow`][Name::GfmTableDelimiterRow],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    /// > | | - |
+    ///     ^^^^^
+    ///   | | b |
+    /// ```
+    GfmTableHead,
+    /// GFM extension: Table row.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableBody`][Name::GfmTableBody],
+    ///     [`GfmTableHead`][Name::GfmTableHead]
+    /// *   **Content model**:
+    ///     [`GfmTableCell`][Name::GfmTableCell],
+    ///     [`GfmTableRowContinuation`][Name::GfmTableRowContinuation]
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    ///   | | - |
+    /// > | | b |
+    ///     ^^^^^
+    /// ```
+    GfmTableRow,
+    /// GFM extension: Table row continuation.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTableCell`][Name::GfmTableCell],
+    ///     [`GfmTableRow`][Name::GfmTableRow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_table`][crate::construct::gfm_table]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | | a |
+    ///   | | - |
+    /// > | | b \
+    ///           ^
+    ///   | c |
+    /// ```
+    GfmTableRowContinuation,
+    /// GFM extension: task list item check.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`GfmTaskListItemMarker`][Name::GfmTaskListItemMarker],
+    ///     [`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked],
+    ///     [`GfmTaskListItemValueOther`][Name::GfmTaskListItemValueOther],
+    ///     [`GfmTaskListItemValueUnchecked`][Name::GfmTaskListItemValueUnchecked]
+    /// *   **Construct**:
+    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * [x] y.
+    ///       ^^^
+    /// ```
+    GfmTaskListItemCheck,
+    /// GFM extension: task list item check marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * [x] y.
+    ///       ^ ^
+    /// ```
+    GfmTaskListItemMarker,
+    /// GFM extension: task list item value: checked.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * [x] y.
+    ///        ^
+    /// ```
+    GfmTaskListItemValueChecked,
+    /// GFM extension: task list item value: other state.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * [-] y.
+    ///        ^
+    /// ```
+    GfmTaskListItemValueOther,
+    /// GFM extension: task list item value: unchecked.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`gfm_task_list_item_check`][crate::construct::gfm_task_list_item_check]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * [ ] z.
+    ///        ^
+    /// ```
+    GfmTaskListItemValueUnchecked,
+    /// GitHub reference.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`github_reference`][crate::construct::github_reference]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a #1 b
+    ///       ^^
+    /// ```
+    GithubReference,
+    /// Whole hard break (escape).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`hard_break_escape`][crate::construct::hard_break_escape]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a\␊
+    ///      ^
+    /// > | b
+    /// ```
+    HardBreakEscape,
+    /// Whole hard break (trailing).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`whitespace`][crate::construct::partial_whitespace]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a␠␠␊
+    ///      ^^
+    /// > | b
+    /// ```
+    HardBreakTrailing,
+    /// Whole heading (atx).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`HeadingAtxSequence`][Name::HeadingAtxSequence],
+    ///     [`HeadingAtxText`][Name::HeadingAtxText],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha
+    ///     ^^^^^^^
+    /// ```
+    HeadingAtx,
+    /// Heading (atx) sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HeadingAtx`][Name::HeadingAtx]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha
+    ///     ^
+    /// ```
+    HeadingAtxSequence,
+    /// Heading (atx) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HeadingAtx`][Name::HeadingAtx]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`heading_atx`][crate::construct::heading_atx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha
+    ///       ^^^^^
+    /// ```
+    HeadingAtxText,
+    /// Whole heading (setext).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`HeadingSetextText`][Name::HeadingSetextText],
+    ///     [`HeadingSetextUnderline`][Name::HeadingSetextUnderline],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | alpha
+    ///     ^^^^^
+    /// > | =====
+    ///     ^^^^^
+    /// ```
+    HeadingSetext,
+    /// Heading (setext) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HeadingSetext`][Name::HeadingSetext]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | alpha
+    ///     ^^^^^
+    ///   | =====
+    /// ```
+    HeadingSetextText,
+    /// Heading (setext) underline.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HeadingSetext`][Name::HeadingSetext]
+    /// *   **Content model**:
+    ///     [`HeadingSetextUnderlineSequence`][Name::HeadingSetextUnderlineSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | alpha
+    /// > | =====
+    ///     ^^^^^
+    /// ```
+    HeadingSetextUnderline,
+    /// Heading (setext) underline sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HeadingSetext`][Name::HeadingSetext]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`heading_setext`][crate::construct::heading_setext]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | alpha
+    /// > | =====
+    ///     ^^^^^
+    /// ```
+    HeadingSetextUnderlineSequence,
+    /// Whole html (flow).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`HtmlFlowData`][Name::HtmlFlowData],
+    ///     [`HtmlFlowMarkdownAttribute`][Name::HtmlFlowMarkdownAttribute],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`html_flow`][crate::construct::html_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | <div>
+    ///     ^^^^^
+    /// ```
+    HtmlFlow,
+    /// HTML (flow) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HtmlFlow`][Name::HtmlFlow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`html_flow`][crate::construct::html_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | <div>
+    ///     ^^^^^
+    /// ```
+    HtmlFlowData,
+    /// HTML (flow) markdown attribute.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HtmlFlow`][Name::HtmlFlow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`html_flow`][crate::construct::html_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | <div markdown="1">
+    ///         ^^^^^^^^^^^^^
+    /// ```
+    HtmlFlowMarkdownAttribute,
+    /// Whole html (text).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`HtmlTextData`][Name::HtmlTextData],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`html_text`][crate::construct::html_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///       ^^^
+    /// ```
+    HtmlText,
+    /// HTML (text) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`HtmlText`][Name::HtmlText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`html_text`][crate::construct::html_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///       ^^^
+    /// ```
+    HtmlTextData,
+    /// Image.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Label`][Name::Label],
+    ///     [`Resource`][Name::Resource],
+    ///     [`Reference`][Name::Reference]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b] c
+    ///       ^^^^
+    /// > | a ![b][c] d
+    ///       ^^^^^^^
+    /// > | a ![b](c) d
+    ///       ^^^^^^^
+    /// ```
+    Image,
+    /// Insert.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`InsertSequence`][Name::InsertSequence],
+    ///     [`InsertText`][Name::InsertText]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ++b++ c
+    ///       ^^^^^
+    /// ```
+    Insert,
+    /// Insert sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Insert`][Name::Insert]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ++b++ c
+    ///       ^^   ^^
+    /// ```
+    InsertSequence,
+    /// Insert text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Insert`][Name::Insert]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ++b++ c
+    ///         ^
+    /// ```
+    InsertText,
+    /// Label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Image`][Name::Image],
+    ///     [`Link`][Name::Link]
+    /// *   **Content model**:
+    ///     [`LabelImage`][Name::LabelImage],
+    ///     [`LabelLink`][Name::LabelLink],
+    ///     [`LabelEnd`][Name::LabelEnd],
+    ///     [`LabelText`][Name::LabelText]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [b] c
+    ///       ^^^
+    /// > | a ![b][c] d
+    ///       ^^^^
+    /// > | a [b](c) d
+    ///       ^^^
+    /// ```
+    Label,
+    /// Label end.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`BracketedSpan`][Name::BracketedSpan],
+    ///     [`Label`][Name::Label]
+    /// *   **Content model**:
+    ///     [`LabelMarker`][Name::LabelMarker]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b](c) d
+    ///          ^
+    /// > | a [b](c) d
+    ///         ^
+    /// ```
+    LabelEnd,
+    /// Label start (image).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Label`][Name::Label]
+    /// *   **Content model**:
+    ///     [`LabelImageMarker`][Name::LabelImageMarker],
+    ///     [`LabelMarker`][Name::LabelMarker]
+    /// *   **Construct**:
+    ///     [`label_start_image`][crate::construct::label_start_image]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b](c) d
+    ///       ^^
+    /// ```
+    LabelImage,
+    /// Label start (image) marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`LabelImage`][Name::LabelImage]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`label_start_image`][crate::construct::label_start_image]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b](c) d
+    ///       ^
+    /// ```
+    LabelImageMarker,
+    /// Label start (link).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`BracketedSpan`][Name::BracketedSpan],
+    ///     [`Label`][Name::Label]
+    /// *   **Content model**:
+    ///     [`LabelMarker`][Name::LabelMarker]
+    /// *   **Construct**:
+    ///     [`label_start_link`][crate::construct::label_start_link]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [b](c) d
+    ///       ^
+    /// ```
+    LabelLink,
+    /// Label marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`LabelImage`][Name::LabelImage],
+    ///     [`LabelLink`][Name::LabelLink],
+    ///     [`LabelEnd`][Name::LabelEnd]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`label_start_image`][crate::construct::label_start_image],
+    ///     [`label_start_link`][crate::construct::label_start_link],
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b](c) d
+    ///        ^ ^
+    /// > | a [b](c) d
+    ///       ^ ^
+    /// ```
+    LabelMarker,
+    /// Label text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Label`][Name::Label]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [b] c
+    ///        ^
+    /// > | a ![b][c] d
+    ///         ^
+    /// > | a [b](c) d
+    ///        ^
+    /// ```
+    LabelText,
+    /// Line ending.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     basically everywhere
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     n/a
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a␊
+    ///      ^
+    ///   | b
+    /// ```
+    LineEnding,
+    /// Link.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Label`][Name::Label],
+    ///     [`Resource`][Name::Resource],
+    ///     [`Reference`][Name::Reference]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a [b] c
+    ///       ^^^
+    /// > | a [b][c] d
+    ///       ^^^^^^
+    /// > | a [b](c) d
+    ///       ^^^^^^
+    /// ```
+    Link,
+    /// List item.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`ListOrdered`][Name::ListOrdered],
+    ///     [`ListUnordered`][Name::ListUnordered]
+    /// *   **Content model**:
+    ///     [`ListItemPrefix`][Name::ListItemPrefix],
+    ///     [flow content][crate::construct::flow]
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^^^
+    /// > | 1. b
+    ///     ^^^^
+    /// ```
+    ListItem,
+    /// List item (marker).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`ListItemPrefix`][Name::ListItemPrefix]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^
+    /// > | 1. b
+    ///      ^
+    /// ```
+    ListItemMarker,
+    /// List item (prefix).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`ListItem`][Name::ListItem]
+    /// *   **Content model**:
+    ///     [`ListItemMarker`][Name::ListItemMarker],
+    ///     [`ListItemValue`][Name::ListItemValue],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^^
+    /// > |   b
+    ///     ^^
+    /// ```
+    ListItemPrefix,
+    /// List item (value).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`ListItemPrefix`][Name::ListItemPrefix]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | 1. b
+    ///     ^
+    /// ```
+    ListItemValue,
+    /// List (ordered).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`BlankLineEnding`][Name::BlankLineEnding],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [`ListItem`][Name::ListItem],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | 1. a
+    ///     ^^^^
+    /// > | 2. b
+    ///     ^^^^
+    /// ```
+    ListOrdered,
+    /// List (unordered).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [document content][crate::construct::document]
+    /// *   **Content model**:
+    ///     [`BlankLineEnding`][Name::BlankLineEnding],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [`ListItem`][Name::ListItem],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`list item`][crate::construct::list_item]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^^^
+    /// > | * b
+    ///     ^^^
+    /// ```
+    ListUnordered,
+    /// Mark.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`MarkSequence`][Name::MarkSequence],
+    ///     [`MarkText`][Name::MarkText]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^^^^
+    /// ```
+    Mark,
+    /// Mark sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Mark`][Name::Mark]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^   ^^
+    /// ```
+    MarkSequence,
+    /// Mark text.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Mark`][Name::Mark]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`attention`][crate::construct::attention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///         ^
+    /// ```
+    MarkText,
+    /// Whole math (flow).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`MathFlowFence`][Name::MathFlowFence],
+    ///     [`MathFlowChunk`][Name::MathFlowChunk],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | $$
+    ///     ^^
+    /// > | \frac{1}{2}
+    ///     ^^^^^^^^^^^
+    /// > | $$
+    ///     ^^
+    /// ```
+    MathFlow,
+    /// A math (flow) fence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathFlow`][Name::MathFlow]
+    /// *   **Content model**:
+    ///     [`MathFlowFenceMeta`][Name::MathFlowFenceMeta],
+    ///     [`MathFlowFenceSequence`][Name::MathFlowFenceSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | $$
+    ///     ^^
+    ///   | \frac{1}{2}
+    /// > | $$
+    ///     ^^
+    /// ```
+    MathFlowFence,
+    /// A math (flow) fence meta string.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathFlowFence`][Name::MathFlowFence]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | $$alpha bravo
+    ///       ^^^^^^^^^^^
+    ///   | \frac{1}{2}
+    ///   | $$
+    /// ```
+    MathFlowFenceMeta,
+    /// A math (flow) fence sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathFlowFenceSequence`][Name::MathFlowFenceSequence]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | $$
+    ///     ^^
+    ///   | \frac{1}{2}
+    /// > | $$
+    ///     ^^
+    /// ```
+    MathFlowFenceSequence,
+    /// A math (flow) chunk.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathFlow`][Name::MathFlow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_flow`][crate::construct::raw_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | $$
+    /// > | \frac{1}{2}
+    ///     ^^^^^^^^^^^
+    ///   | $$
+    /// ```
+    MathFlowChunk,
+    /// Whole math (text).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`MathTextData`][Name::MathTextData],
+    ///     [`MathTextSequence`][Name::MathTextSequence],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`raw_text`][crate::construct::raw_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a $b$ c
+    ///       ^^^
+    /// ```
+    MathText,
+    /// Math (text) data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathText`][Name::MathText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_text`][crate::construct::raw_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a `b` c
+    ///        ^
+    /// ```
+    MathTextData,
+    /// Math (text) sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MathText`][Name::MathText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`raw_text`][crate::construct::raw_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a $b$ c
+    ///       ^ ^
+    /// ```
+    MathTextSequence,
+    /// MDX extension: ESM.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     void
+    ///     [`MdxEsmData`][Name::MdxEsmData],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`mdx_esm`][crate::construct::mdx_esm]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | import a from 'b'
+    ///     ^^^^^^^^^^^^^^^^^
+    /// ```
+    MdxEsm,
+    /// MDX extension: ESM data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxEsm`][Name::MdxEsm]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`mdx_esm`][crate::construct::mdx_esm]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | import a from 'b'
+    ///     ^^^^^^^^^^^^^^^^^
+    /// ```
+    MdxEsmData,
+    /// MDX extension: expression marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxFlowExpression`][Name::MdxFlowExpression],
+    ///     [`MdxTextExpression`][Name::MdxTextExpression],
+    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
+    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_expression`][crate::construct::partial_mdx_expression]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | {Math.PI}
+    ///     ^       ^
+    /// ```
+    MdxExpressionMarker,
+    /// MDX extension: expression data.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxFlowExpression`][Name::MdxFlowExpression],
+    ///     [`MdxTextExpression`][Name::MdxTextExpression],
+    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
+    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_expression`][crate::construct::partial_mdx_expression]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | {Math.PI}
+    ///      ^^^^^^^
+    /// ```
+    MdxExpressionData,
+    /// MDX extension: expression (flow).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
+    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    /// *   **Construct**:
+    ///     [`mdx_expression_flow`][crate::construct::mdx_expression_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | {Math.PI}
+    ///     ^^^^^^^^^
+    /// ```
+    MdxFlowExpression,
+    /// MDX extension: expression (text).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
+    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    /// *   **Construct**:
+    ///     [`mdx_expression_text`][crate::construct::mdx_expression_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a {Math.PI} b
+    ///       ^^^^^^^^^
+    /// ```
+    MdxTextExpression,
+    /// MDX extension: JSX (flow).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagMarker`][Name::MdxJsxTagMarker],
+    ///     [`MdxJsxTagClosingMarker`][Name::MdxJsxTagClosingMarker],
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
+    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
+    ///     [`MdxJsxTagSelfClosingMarker`][Name::MdxJsxTagSelfClosingMarker]
+    /// *   **Construct**:
+    ///     [`mdx_jsx_flow`][crate::construct::mdx_jsx_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | <B />
+    ///     ^^^^^
+    /// ```
+    MdxJsxFlowTag,
+    /// MDX extension: JSX (text).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagMarker`][Name::MdxJsxTagMarker],
+    ///     [`MdxJsxTagClosingMarker`][Name::MdxJsxTagClosingMarker],
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
+    ///     [`MdxJsxTagAttributeExpression`][Name::MdxJsxTagAttributeExpression],
+    ///     [`MdxJsxTagSelfClosingMarker`][Name::MdxJsxTagSelfClosingMarker]
+    /// *   **Construct**:
+    ///     [`mdx_jsx_text`][crate::construct::mdx_jsx_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <B /> c
+    ///       ^^^^^
+    /// ```
+    MdxJsxTextTag,
+    /// MDX extension: JSX: ECMAScript whitespace.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag],
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName],
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute],
+    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <B /> c
+    ///         ^
+    /// ```
+    MdxJsxEsWhitespace,
+    /// MDX extension: JSX: tag marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <B /> c
+    ///       ^   ^
+    /// ```
+    MdxJsxTagMarker,
+    /// MDX extension: JSX: closing tag marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a </B> c
+    ///        ^
+    /// ```
+    MdxJsxTagClosingMarker,
+    /// MDX extension: JSX: tag name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagNamePrimary`][Name::MdxJsxTagNamePrimary],
+    ///     [`MdxJsxTagNameMember`][Name::MdxJsxTagNameMember],
+    ///     [`MdxJsxTagNameMemberMarker`][Name::MdxJsxTagNameMemberMarker],
+    ///     [`MdxJsxTagNamePrefixMarker`][Name::MdxJsxTagNamePrefixMarker],
+    ///     [`MdxJsxTagNameLocal`][Name::MdxJsxTagNameLocal]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///        ^
+    /// > | a <b:c> d
+    ///        ^^^
+    /// > | a <b.c> d
+    ///        ^^^
+    /// ```
+    #[allow(clippy::enum_variant_names)]
+    MdxJsxTagName,
+    /// MDX extension: JSX: primary tag name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b> c
+    ///        ^
+    /// > | a <b:c> d
+    ///        ^
+    /// > | a <b.c> d
+    ///        ^
+    /// ```
+    MdxJsxTagNamePrimary,
+    /// MDX extension: JSX: tag name member marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b.c> d
+    ///         ^
+    /// ```
+    MdxJsxTagNameMemberMarker,
+    /// MDX extension: JSX: tag name prefix marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b:c> d
+    ///         ^
+    /// ```
+    MdxJsxTagNamePrefixMarker,
+    /// MDX extension: JSX: tag name member.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b.c> d
+    ///          ^
+    /// ```
+    MdxJsxTagNameMember,
+    /// MDX extension: JSX: tag name local.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b:c> d
+    ///          ^
+    /// ```
+    MdxJsxTagNameLocal,
+    /// MDX extension: JSX: attribute.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName],
+    ///     [`MdxJsxTagAttributeInitializerMarker`][Name::MdxJsxTagAttributeInitializerMarker],
+    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral],
+    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c> d
+    ///          ^
+    /// > | a <b c="d"> e
+    ///          ^^^^^
+    /// > | a <b c={d}> e
+    ///          ^^^^^
+    /// ```
+    MdxJsxTagAttribute,
+    /// MDX extension: JSX tag attribute expression.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
+    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b {Math.PI} /> c
+    ///          ^^^^^^^^^
+    /// ```
+    MdxJsxTagAttributeExpression,
+    /// MDX extension: JSX: attribute name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagAttributePrimaryName`][Name::MdxJsxTagAttributePrimaryName],
+    ///     [`MdxJsxTagAttributeNamePrefixMarker`][Name::MdxJsxTagAttributeNamePrefixMarker],
+    ///     [`MdxJsxTagAttributeNameLocal`][Name::MdxJsxTagAttributeNameLocal]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c> d
+    ///          ^
+    /// > | a <b c:d="e"> f
+    ///          ^^^
+    /// ```
+    #[allow(clippy::enum_variant_names)]
+    MdxJsxTagAttributeName,
+    /// MDX extension: JSX: primary attribute name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c> d
+    ///          ^
+    /// > | a <b c:d="e"> f
+    ///          ^
+    /// ```
+    #[allow(clippy::enum_variant_names)]
+    MdxJsxTagAttributePrimaryName,
+    /// MDX extension: JSX: attribute name prefix marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c:d="e"> f
+    ///           ^
+    /// ```
+    MdxJsxTagAttributeNamePrefixMarker,
+    /// MDX extension: JSX: local attribute name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c:d="e"> f
+    ///            ^
+    /// ```
+    MdxJsxTagAttributeNameLocal,
+    /// MDX extension: JSX: attribute initializer marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c="d"> e
+    ///           ^
+    /// ```
+    MdxJsxTagAttributeInitializerMarker,
+    /// MDX extension: JSX tag attribute value expression.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
+    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c={Math.PI} /> d
+    ///            ^^^^^^^^^
+    /// ```
+    MdxJsxTagAttributeValueExpression,
+    /// MDX extension: JSX: attribute value literal.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    /// *   **Content model**:
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
+    ///     [`MdxJsxTagAttributeValueLiteralMarker`][Name::MdxJsxTagAttributeValueLiteralMarker],
+    ///     [`MdxJsxTagAttributeValueLiteralValue`][Name::MdxJsxTagAttributeValueLiteralValue]
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c="d"> e
+    ///            ^^^
+    /// ```
+    MdxJsxTagAttributeValueLiteral,
+    /// MDX extension: JSX: attribute value literal marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c="d"> e
+    ///            ^ ^
+    /// ```
+    MdxJsxTagAttributeValueLiteralMarker,
+    /// MDX extension: JSX: attribute value literal value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b c="d"> e
+    ///             ^
+    /// ```
+    MdxJsxTagAttributeValueLiteralValue,
+    /// MDX extension: JSX: self-closing tag marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
+    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a <b /> c
+    ///          ^
+    /// ```
+    MdxJsxTagSelfClosingMarker,
+    /// Mention.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`MentionMarker`][Name::MentionMarker],
+    ///     [`MentionName`][Name::MentionName]
+    /// *   **Construct**:
+    ///     [`mention`][crate::construct::mention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a @b c
+    ///       ^^
+    /// ```
+    Mention,
+    /// Mention marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Mention`][Name::Mention]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`mention`][crate::construct::mention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a @b c
+    ///       ^
+    /// ```
+    MentionMarker,
+    /// Mention name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Mention`][Name::Mention]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`mention`][crate::construct::mention]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a @b c
+    ///        ^
+    /// ```
+    MentionName,
+    /// Paragraph.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [content][crate::construct::content]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`paragraph`][crate::construct::paragraph]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a b
+    ///     ^^^
+    /// > | c.
+    ///     ^^
+    /// ```
+    Paragraph,
+    /// Reference.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Image`][Name::Image],
+    ///     [`Link`][Name::Link]
+    /// *   **Content model**:
+    ///     [`ReferenceMarker`][Name::ReferenceMarker],
+    ///     [`ReferenceString`][Name::ReferenceString]
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b][c] d
+    ///           ^^^
+    /// ```
+    Reference,
+    /// Reference marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Reference`][Name::Reference]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b][c] d
+    ///           ^ ^
+    /// ```
+    ReferenceMarker,
+    /// Reference string.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Reference`][Name::Reference]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`label`][crate::construct::partial_label]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b][c] d
+    ///            ^
+    /// ```
+    ReferenceString,
+    /// Resource.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [`Image`][Name::Image],
+    ///     [`Link`][Name::Link]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagNamePrimary`][Name::MdxJsxTagNamePrimary],
-    ///     [`MdxJsxTagNameMember`][Name::MdxJsxTagNameMember],
-    ///     [`MdxJsxTagNameMemberMarker`][Name::MdxJsxTagNameMemberMarker],
-    ///     [`MdxJsxTagNamePrefixMarker`][Name::MdxJsxTagNamePrefixMarker],
-    ///     [`MdxJsxTagNameLocal`][Name::MdxJsxTagNameLocal]
+    ///     [`ResourceMarker`][Name::ResourceMarker],
+    ///     [`ResourceDestination`][Name::ResourceDestination],
+    ///     [`ResourceTitle`][Name::ResourceTitle],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`LineEnding`][Name::LineEnding]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`label_end`][crate::construct::label_end]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///        ^
-    /// > | a <b:c> d
-    ///        ^^^
-    /// > | a <b.c> d
-    ///        ^^^
+    /// > | a ![b](c "d") e
+    ///           ^^^^^^^
+    /// > | a [b](c) d
+    ///          ^^^
     /// ```
-    #[allow(clippy::enum_variant_names)]
-    MdxJsxTagName,
-    /// MDX extension: JSX: primary tag name.
+    Resource,
+    /// Resource destination.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    ///     [`Resource`][Name::Resource]
     /// *   **Content model**:
-    ///     void
+    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral],
+    ///     [`ResourceDestinationRaw`][Name::ResourceDestinationRaw]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b> c
-    ///        ^
-    /// > | a <b:c> d
-    ///        ^
-    /// > | a <b.c> d
-    ///        ^
+    /// > | a ![b](c "d") e
+    ///            ^
     /// ```
-    MdxJsxTagNamePrimary,
-    /// MDX extension: JSX: tag name member marker.
+    ResourceDestination,
+    /// Resource destination literal.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    ///     [`ResourceDestination`][Name::ResourceDestination]
     /// *   **Content model**:
-    ///     void
+    ///     [`ResourceDestinationLiteralMarker`][Name::ResourceDestinationLiteralMarker],
+    ///     [`ResourceDestinationString`][Name::ResourceDestinationString]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b.c> d
-    ///         ^
+    /// > | a ![b](<c> "d") e
+    ///            ^^^
     /// ```
-    MdxJsxTagNameMemberMarker,
-    /// MDX extension: JSX: tag name prefix marker.
+    ResourceDestinationLiteral,
+    /// Resource destination literal marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b:c> d
-    ///         ^
+    /// > | a ![b](<c> "d") e
+    ///            ^ ^
     /// ```
-    MdxJsxTagNamePrefixMarker,
-    /// MDX extension: JSX: tag name member.
+    ResourceDestinationLiteralMarker,
+    /// Resource destination raw.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    ///     [`ResourceDestination`][Name::ResourceDestination]
     /// *   **Content model**:
-    ///     void
+    ///     [`ResourceDestinationString`][Name::ResourceDestinationString]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`destination`][crate::construct::partial_destination]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b.c> d
-    ///          ^
+    /// > | a ![b](c "d") e
+    ///            ^
     /// ```
-    MdxJsxTagNameMember,
-    /// MDX extension: JSX: tag name local.
+    ResourceDestinationRaw,
+    /// Resource destination raw.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagName`][Name::MdxJsxTagName]
+    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral],
+    ///     [`ResourceDestinationRaw`][Name::ResourceDestinationRaw]
+    /// *   **Content model**:
+    ///     [string content][crate::construct::string]
+    /// *   **Construct**:
+    ///     [`destination`][crate::construct::partial_destination]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ![b](<c> "d") e
+    ///             ^
+    /// > | a ![b](c "d") e
+    ///            ^
+    /// ```
+    ResourceDestinationString,
+    /// Resource marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Resource`][Name::Resource]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`label_end`][crate::construct::label_end]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b:c> d
-    ///          ^
+    /// > | a ![b](c "d") e
+    ///           ^     ^
     /// ```
-    MdxJsxTagNameLocal,
-    /// MDX extension: JSX: attribute.
+    ResourceMarker,
+    /// Resource title.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [`Resource`][Name::Resource]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName],
-    ///     [`MdxJsxTagAttributeInitializerMarker`][Name::MdxJsxTagAttributeInitializerMarker],
-    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral],
-    ///     [`MdxJsxTagAttributeValueExpression`][Name::MdxJsxTagAttributeValueExpression]
+    ///     [`ResourceTitleMarker`][Name::ResourceTitleMarker],
+    ///     [`ResourceTitleString`][Name::ResourceTitleString]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c> d
-    ///          ^
-    /// > | a <b c="d"> e
-    ///          ^^^^^
-    /// > | a <b c={d}> e
-    ///          ^^^^^
+    /// > | a ![b](<c> "d") e
+    ///                ^^^
     /// ```
-    MdxJsxTagAttribute,
-    /// MDX extension: JSX tag attribute expression.
+    ResourceTitle,
+    /// Resource title marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [`ResourceTitle`][Name::ResourceTitle]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
-    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b {Math.PI} /> c
-    ///          ^^^^^^^^^
+    /// > | a ![b](<c> "d") e
+    ///                ^ ^
     /// ```
-    MdxJsxTagAttributeExpression,
-    /// MDX extension: JSX: attribute name.
+    ResourceTitleMarker,
+    /// Resource title string.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    ///     [`ResourceTitle`][Name::ResourceTitle]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagAttributePrimaryName`][Name::MdxJsxTagAttributePrimaryName],
-    ///     [`MdxJsxTagAttributeNamePrefixMarker`][Name::MdxJsxTagAttributeNamePrefixMarker],
-    ///     [`MdxJsxTagAttributeNameLocal`][Name::MdxJsxTagAttributeNameLocal]
+    ///     [string content][crate::construct::string]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`title`][crate::construct::partial_title]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c> d
-    ///          ^
-    /// > | a <b c:d="e"> f
-    ///          ^^^
+    /// > | a ![b](<c> "d") e
+    ///                 ^
     /// ```
-    #[allow(clippy::enum_variant_names)]
-    MdxJsxTagAttributeName,
-    /// MDX extension: JSX: primary attribute name.
+    ResourceTitleString,
+    /// Ruby.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`RubyBase`][Name::RubyBase],
+    ///     [`RubyMarker`][Name::RubyMarker],
+    ///     [`RubyText`][Name::RubyText]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`ruby`][crate::construct::ruby]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c> d
-    ///          ^
-    /// > | a <b c:d="e"> f
-    ///          ^
+    /// > | a {b|c} d
+    ///       ^^^^^
     /// ```
-    #[allow(clippy::enum_variant_names)]
-    MdxJsxTagAttributePrimaryName,
-    /// MDX extension: JSX: attribute name prefix marker.
+    Ruby,
+    /// Ruby base.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    ///     [`Ruby`][Name::Ruby]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`ruby`][crate::construct::ruby]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c:d="e"> f
-    ///           ^
+    /// > | a {b|c} d
+    ///        ^
     /// ```
-    MdxJsxTagAttributeNamePrefixMarker,
-    /// MDX extension: JSX: local attribute name.
+    RubyBase,
+    /// Ruby marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttributeName`][Name::MdxJsxTagAttributeName]
+    ///     [`Ruby`][Name::Ruby]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`ruby`][crate::construct::ruby]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c:d="e"> f
-    ///            ^
+    /// > | a {b|c} d
+    ///       ^ ^ ^
     /// ```
-    MdxJsxTagAttributeNameLocal,
-    /// MDX extension: JSX: attribute initializer marker.
+    RubyMarker,
+    /// Ruby text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    ///     [`Ruby`][Name::Ruby]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`ruby`][crate::construct::ruby]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c="d"> e
-    ///           ^
+    /// > | a {b|c} d
+    ///          ^
     /// ```
-    MdxJsxTagAttributeInitializerMarker,
-    /// MDX extension: JSX tag attribute value expression.
+    RubyText,
+    /// `SpaceOrTab`.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     basically everywhere
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`MdxExpressionMarker`][Name::MdxExpressionMarker],
-    ///     [`MdxExpressionData`][Name::MdxExpressionData]
+    ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     n/a
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c={Math.PI} /> d
-    ///            ^^^^^^^^^
+    /// > | ␠* * *␠
+    ///     ^ ^ ^ ^
     /// ```
-    MdxJsxTagAttributeValueExpression,
-    /// MDX extension: JSX: attribute value literal.
+    SpaceOrTab,
+    /// Spoiler.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`LineEnding`][Name::LineEnding],
-    ///     [`MdxJsxEsWhitespace`][Name::MdxJsxEsWhitespace],
-    ///     [`MdxJsxTagAttributeValueLiteralMarker`][Name::MdxJsxTagAttributeValueLiteralMarker],
-    ///     [`MdxJsxTagAttributeValueLiteralValue`][Name::MdxJsxTagAttributeValueLiteralValue]
+    ///     [`SpoilerSequence`][Name::SpoilerSequence],
+    ///     [`SpoilerText`][Name::SpoilerText]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`spoiler`][crate::construct::spoiler],
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c="d"> e
-    ///            ^^^
+    /// > | >!a!<
+    ///     ^^^^^
     /// ```
-    MdxJsxTagAttributeValueLiteral,
-    /// MDX extension: JSX: attribute value literal marker.
+    Spoiler,
+    /// Spoiler sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral]
+    ///     [`Spoiler`][Name::Spoiler]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`spoiler`][crate::construct::spoiler],
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c="d"> e
-    ///            ^ ^
+    /// > | >!a!<
+    ///     ^^ ^^
     /// ```
-    MdxJsxTagAttributeValueLiteralMarker,
-    /// MDX extension: JSX: attribute value literal value.
+    SpoilerSequence,
+    /// Spoiler text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral]
+    ///     [`Spoiler`][Name::Spoiler]
     /// *   **Content model**:
-    ///     void
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`spoiler`][crate::construct::spoiler],
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b c="d"> e
-    ///             ^
+    /// > | >!a!<
+    ///       ^
     /// ```
-    MdxJsxTagAttributeValueLiteralValue,
-    /// MDX extension: JSX: self-closing tag marker.
+    SpoilerText,
+    /// Strong.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`MdxJsxFlowTag`][Name::MdxJsxFlowTag],
-    ///     [`MdxJsxTextTag`][Name::MdxJsxTextTag]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`StrongSequence`][Name::StrongSequence],
+    ///     [`StrongText`][Name::StrongText]
     /// *   **Construct**:
-    ///     [`partial_mdx_jsx`][crate::construct::partial_mdx_jsx]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a <b /> c
-    ///          ^
+    /// > | **a**
+    ///     ^^^^^
     /// ```
-    MdxJsxTagSelfClosingMarker,
-    /// Paragraph.
+    Strong,
+    /// Strong sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [content][crate::construct::content]
+    ///     [`Strong`][Name::Strong]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     void
     /// *   **Construct**:
-    ///     [`paragraph`][crate::construct::paragraph]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a b
-    ///     ^^^
-    /// > | c.
-    ///     ^^
+    /// > | **a**
+    ///     ^^ ^^
     /// ```
-    Paragraph,
-    /// Reference.
+    StrongSequence,
+    /// Strong text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Image`][Name::Image],
-    ///     [`Link`][Name::Link]
+    ///     [`Strong`][Name::Strong]
     /// *   **Content model**:
-    ///     [`ReferenceMarker`][Name::ReferenceMarker],
-    ///     [`ReferenceString`][Name::ReferenceString]
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b][c] d
-    ///           ^^^
+    /// > | **a**
+    ///       ^
     /// ```
-    Reference,
-    /// Reference marker.
+    StrongText,
+    /// Subscript.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Reference`][Name::Reference]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`SubscriptSequence`][Name::SubscriptSequence],
+    ///     [`SubscriptText`][Name::SubscriptText]
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b][c] d
-    ///           ^ ^
+    /// > | H~2~O
+    ///      ^^^
     /// ```
-    ReferenceMarker,
-    /// Reference string.
+    Subscript,
+    /// Subscript sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Reference`][Name::Reference]
+    ///     [`Subscript`][Name::Subscript]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`label`][crate::construct::partial_label]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b][c] d
-    ///            ^
+    /// > | H~2~O
+    ///      ^ ^
     /// ```
-    ReferenceString,
-    /// Resource.
+    SubscriptSequence,
+    /// Subscript text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Image`][Name::Image],
-    ///     [`Link`][Name::Link]
+    ///     [`Subscript`][Name::Subscript]
     /// *   **Content model**:
-    ///     [`ResourceMarker`][Name::ResourceMarker],
-    ///     [`ResourceDestination`][Name::ResourceDestination],
-    ///     [`ResourceTitle`][Name::ResourceTitle],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab],
-    ///     [`LineEnding`][Name::LineEnding]
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c "d") e
-    ///           ^^^^^^^
-    /// > | a [b](c) d
-    ///          ^^^
+    /// > | H~2~O
+    ///       ^
     /// ```
-    Resource,
-    /// Resource destination.
+    SubscriptText,
+    /// Superscript.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Resource`][Name::Resource]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral],
-    ///     [`ResourceDestinationRaw`][Name::ResourceDestinationRaw]
+    ///     [`SuperscriptSequence`][Name::SuperscriptSequence],
+    ///     [`SuperscriptText`][Name::SuperscriptText]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c "d") e
-    ///            ^
+    /// > | 2^10^
+    ///      ^^^^
     /// ```
-    ResourceDestination,
-    /// Resource destination literal.
+    Superscript,
+    /// Superscript sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceDestination`][Name::ResourceDestination]
+    ///     [`Superscript`][Name::Superscript]
     /// *   **Content model**:
-    ///     [`ResourceDestinationLiteralMarker`][Name::ResourceDestinationLiteralMarker],
-    ///     [`ResourceDestinationString`][Name::ResourceDestinationString]
+    ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///            ^^^
+    /// > | 2^10^
+    ///      ^  ^
     /// ```
-    ResourceDestinationLiteral,
-    /// Resource destination literal marker.
+    SuperscriptSequence,
+    /// Superscript text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral]
+    ///     [`Superscript`][Name::Superscript]
     /// *   **Content model**:
-    ///     void
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///            ^ ^
+    /// > | 2^10^
+    ///       ^^
     /// ```
-    ResourceDestinationLiteralMarker,
-    /// Resource destination raw.
+    SuperscriptText,
+    /// Whole thematic break.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceDestination`][Name::ResourceDestination]
+    ///     [flow content][crate::construct::flow]
     /// *   **Content model**:
-    ///     [`ResourceDestinationString`][Name::ResourceDestinationString]
+    ///     [`ThematicBreakSequence`][Name::ThematicBreakSequence],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`thematic_break`][crate::construct::thematic_break]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c "d") e
-    ///            ^
+    /// > | * * *
+    ///     ^^^^^
     /// ```
-    ResourceDestinationRaw,
-    /// Resource destination raw.
+    ThematicBreak,
+    /// Thematic break sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral],
-    ///     [`ResourceDestinationRaw`][Name::ResourceDestinationRaw]
+    ///     [`ThematicBreak`][Name::ThematicBreak]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     void
     /// *   **Construct**:
-    ///     [`destination`][crate::construct::partial_destination]
+    ///     [`thematic_break`][crate::construct::thematic_break]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///             ^
-    /// > | a ![b](c "d") e
-    ///            ^
+    /// > | * * *
+    ///     ^ ^ ^
     /// ```
-    ResourceDestinationString,
-    /// Resource marker.
+    ThematicBreakSequence,
+    /// Underline.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Resource`][Name::Resource]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`UnderlineSequence`][Name::UnderlineSequence],
+    ///     [`UnderlineText`][Name::UnderlineText]
     /// *   **Construct**:
-    ///     [`label_end`][crate::construct::label_end]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](c "d") e
-    ///           ^     ^
+    /// > | __a__
+    ///     ^^^^^
     /// ```
-    ResourceMarker,
-    /// Resource title.
+    Underline,
+    /// Underline sequence.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Resource`][Name::Resource]
+    ///     [`Underline`][Name::Underline]
     /// *   **Content model**:
-    ///     [`ResourceTitleMarker`][Name::ResourceTitleMarker],
-    ///     [`ResourceTitleString`][Name::ResourceTitleString]
+    ///     void
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///                ^^^
+    /// > | __a__
+    ///     ^^ ^^
     /// ```
-    ResourceTitle,
-    /// Resource title marker.
+    UnderlineSequence,
+    /// Underline text.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceTitle`][Name::ResourceTitle]
+    ///     [`Underline`][Name::Underline]
     /// *   **Content model**:
-    ///     void
+    ///     [text content][crate::construct::text]
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`attention`][crate::construct::attention]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///                ^ ^
+    /// > | __a__
+    ///       ^
     /// ```
-    ResourceTitleMarker,
-    /// Resource title string.
+    UnderlineText,
+    /// Obsidian extension: wiki embed.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ResourceTitle`][Name::ResourceTitle]
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     [string content][crate::construct::string]
+    ///     [`WikiLinkAlias`][Name::WikiLinkAlias],
+    ///     [`WikiLinkAliasMarker`][Name::WikiLinkAliasMarker],
+    ///     [`WikiLinkFragment`][Name::WikiLinkFragment],
+    ///     [`WikiLinkMarker`][Name::WikiLinkMarker],
+    ///     [`WikiLinkTarget`][Name::WikiLinkTarget]
     /// *   **Construct**:
-    ///     [`title`][crate::construct::partial_title]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | a ![b](<c> "d") e
-    ///                 ^
+    /// > | a ![[b#c|d]] e
+    ///       ^^^^^^^^^^
     /// ```
-    ResourceTitleString,
-    /// SpaceOrTab.
+    WikiEmbed,
+    /// Obsidian extension: wiki link.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     basically everywhere
+    ///     [text content][crate::construct::text]
     /// *   **Content model**:
-    ///     void
+    ///     [`WikiLinkAlias`][Name::WikiLinkAlias],
+    ///     [`WikiLinkAliasMarker`][Name::WikiLinkAliasMarker],
+    ///     [`WikiLinkFragment`][Name::WikiLinkFragment],
+    ///     [`WikiLinkMarker`][Name::WikiLinkMarker],
+    ///     [`WikiLinkTarget`][Name::WikiLinkTarget]
     /// *   **Construct**:
-    ///     n/a
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | ␠* * *␠
-    ///     ^ ^ ^ ^
+    /// > | a [[b|c]] d
+    ///       ^^^^^^^
     /// ```
-    SpaceOrTab,
-    /// Strong.
+    WikiLink,
+    /// Obsidian extension: wiki link alias.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [text content][crate::construct::text]
+    ///     [`WikiEmbed`][Name::WikiEmbed],
+    ///     [`WikiLink`][Name::WikiLink]
     /// *   **Content model**:
-    ///     [`StrongSequence`][Name::StrongSequence],
-    ///     [`StrongText`][Name::StrongText]
+    ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | **a**
-    ///     ^^^^^
+    /// > | a [[b|c]] d
+    ///           ^
     /// ```
-    Strong,
-    /// Strong sequence.
+    WikiLinkAlias,
+    /// Obsidian extension: wiki link alias marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Strong`][Name::Strong]
+    ///     [`WikiEmbed`][Name::WikiEmbed],
+    ///     [`WikiLink`][Name::WikiLink]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | **a**
-    ///     ^^ ^^
+    /// > | a [[b|c]] d
+    ///          ^
     /// ```
-    StrongSequence,
-    /// Strong text.
+    WikiLinkAliasMarker,
+    /// Obsidian extension: wiki link fragment.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`Strong`][Name::Strong]
+    ///     [`WikiEmbed`][Name::WikiEmbed],
+    ///     [`WikiLink`][Name::WikiLink]
     /// *   **Content model**:
-    ///     [text content][crate::construct::text]
+    ///     void
     /// *   **Construct**:
-    ///     [`attention`][crate::construct::attention]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | **a**
-    ///       ^
+    /// > | a [[b#c|d]] e
+    ///          ^^
     /// ```
-    StrongText,
-    /// Whole thematic break.
+    WikiLinkFragment,
+    /// Obsidian extension: wiki link marker.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [flow content][crate::construct::flow]
+    ///     [`WikiEmbed`][Name::WikiEmbed],
+    ///     [`WikiLink`][Name::WikiLink]
     /// *   **Content model**:
-    ///     [`ThematicBreakSequence`][Name::ThematicBreakSequence],
-    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    ///     void
     /// *   **Construct**:
-    ///     [`thematic_break`][crate::construct::thematic_break]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * * *
-    ///     ^^^^^
+    /// > | a [[b|c]] d
+    ///       ^^   ^^
     /// ```
-    ThematicBreak,
-    /// Thematic break sequence.
+    WikiLinkMarker,
+    /// Obsidian extension: wiki link target.
     ///
     /// ## Info
     ///
     /// *   **Context**:
-    ///     [`ThematicBreak`][Name::ThematicBreak]
+    ///     [`WikiEmbed`][Name::WikiEmbed],
+    ///     [`WikiLink`][Name::WikiLink]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
-    ///     [`thematic_break`][crate::construct::thematic_break]
+    ///     [`wiki_link`][crate::construct::wiki_link]
     ///
     /// ## Example
     ///
     /// ```markdown
-    /// > | * * *
-    ///     ^ ^ ^
+    /// > | a [[b|c]] d
+    ///         ^
     /// ```
-    ThematicBreakSequence,
+    WikiLinkTarget,
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 133] = [
+    Name::AbbreviationDefinitionLabelMarker,
+    Name::AbbreviationDefinitionLabelString,
+    Name::AbbreviationDefinitionMarker,
+    Name::AbbreviationDefinitionTitle,
     Name::AttentionSequence,
+    Name::AttributeListClassMarker,
+    Name::AttributeListClassValue,
+    Name::AttributeListIdMarker,
+    Name::AttributeListIdValue,
+    Name::AttributeListMarker,
+    Name::AttributeListPropertyInitializerMarker,
+    Name::AttributeListPropertyName,
+    Name::AttributeListPropertyValue,
+    Name::AttributeListPropertyValueMarker,
     Name::AutolinkEmail,
+    Name::AutolinkLabel,
+    Name::AutolinkLabelMarker,
     Name::AutolinkMarker,
     Name::AutolinkProtocol,
     Name::BlankLineEnding,
+    Name::BlockIdMarker,
+    Name::BlockIdValue,
+    Name::BlockQuoteFencedFenceSequence,
     Name::BlockQuoteMarker,
     Name::ByteOrderMark,
     Name::CharacterEscapeMarker,
@@ -3394,17 +5533,36 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::CharacterReferenceMarkerNumeric,
     Name::CharacterReferenceMarkerSemi,
     Name::CharacterReferenceValue,
+    Name::CitationKey,
+    Name::CitationKeyMarker,
+    Name::CitationLocator,
+    Name::CitationLocatorMarker,
+    Name::CitationMarker,
+    Name::CitationPrefix,
+    Name::CitationSuppressAuthorMarker,
     Name::CodeFencedFenceSequence,
     Name::CodeFlowChunk,
     Name::CodeTextData,
     Name::CodeTextSequence,
+    Name::CriticMarkupSequence,
+    Name::CustomBlockFenceInfo,
+    Name::CustomBlockMarker,
+    Name::CustomInlineSequence,
     Name::Data,
     Name::DefinitionDestinationLiteralMarker,
     Name::DefinitionLabelMarker,
     Name::DefinitionMarker,
     Name::DefinitionTitleMarker,
+    Name::DirectiveContainerSequence,
+    Name::DirectiveLabelMarker,
+    Name::DirectiveMarker,
+    Name::DirectiveName,
     Name::EmphasisSequence,
+    Name::FencedDivClass,
+    Name::FencedDivSequence,
     Name::FrontmatterChunk,
+    Name::GfmAlertMarker,
+    Name::GfmAlertType,
     Name::GfmAutolinkLiteralEmail,
     Name::GfmAutolinkLiteralProtocol,
     Name::GfmAutolinkLiteralWww,
@@ -3415,21 +5573,27 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::GfmTableCellDivider,
     Name::GfmTableDelimiterMarker,
     Name::GfmTableDelimiterFiller,
+    Name::GfmTableRowContinuation,
     Name::GfmTaskListItemMarker,
     Name::GfmTaskListItemValueChecked,
+    Name::GfmTaskListItemValueOther,
     Name::GfmTaskListItemValueUnchecked,
+    Name::GithubReference,
     Name::FrontmatterSequence,
     Name::HardBreakEscape,
     Name::HardBreakTrailing,
     Name::HeadingAtxSequence,
     Name::HeadingSetextUnderlineSequence,
     Name::HtmlFlowData,
+    Name::HtmlFlowMarkdownAttribute,
     Name::HtmlTextData,
+    Name::InsertSequence,
     Name::LabelImageMarker,
     Name::LabelMarker,
     Name::LineEnding,
     Name::ListItemMarker,
     Name::ListItemValue,
+    Name::MarkSequence,
     Name::MathFlowFenceSequence,
     Name::MathFlowChunk,
     Name::MathTextData,
@@ -3450,12 +5614,26 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::MdxJsxTagAttributeNameLocal,
     Name::MdxJsxTagAttributeValueLiteralMarker,
     Name::MdxJsxEsWhitespace,
+    Name::MentionMarker,
+    Name::MentionName,
     Name::ReferenceMarker,
     Name::ResourceMarker,
     Name::ResourceTitleMarker,
+    Name::RubyBase,
+    Name::RubyMarker,
+    Name::RubyText,
     Name::SpaceOrTab,
+    Name::SpoilerSequence,
     Name::StrongSequence,
+    Name::SubscriptSequence,
+    Name::SuperscriptSequence,
     Name::ThematicBreakSequence,
+    Name::UnderlineSequence,
+    Name::WikiLinkAlias,
+    Name::WikiLinkAliasMarker,
+    Name::WikiLinkFragment,
+    Name::WikiLinkMarker,
+    Name::WikiLinkTarget,
 ];
 
 /// Embedded content type.
@@ -3517,6 +5695,7 @@ impl Point {
 
     /// Create a new point, that is shifted from the close earlier current
     /// point, to `index`.
+    #[must_use]
     pub fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
         let mut next = self.clone();
         debug_assert!(index > next.index, "expected to shift forward");
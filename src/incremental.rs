@@ -0,0 +1,357 @@
+//! Incremental parsing: parse a document again after an edit, without
+//! parsing all of it.
+//!
+//! Blocks that are separated by blank lines, outside of containers, are
+//! mostly parsed on their own.
+//! So after an edit, only the blocks around the edit are parsed again, and
+//! their events are spliced into the events of the rest of the document.
+//!
+//! Some edits affect the whole document, such as changing a definition,
+//! which changes what references mean everywhere.
+//! Others affect the blocks after them, such as opening fenced code that is
+//! not closed.
+//! Those are detected, and then the whole document is parsed again.
+
+use crate::event::{Event, Kind, Name, Point};
+use crate::message;
+use crate::parser::{parse, parse_range};
+use crate::util::slice::Position;
+use crate::ParseOptions;
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::Range;
+
+/// Parsed document, which can be edited and parsed again incrementally.
+///
+/// This is useful for editors, where documents change a little at a time,
+/// and parsing all of a large document on every keystroke is slow.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::Name, Document, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = ParseOptions::default();
+/// let mut document = Document::new("# a\n\nb\n\nc", &options)?;
+///
+/// // Turn `b` into a heading:
+/// let changed = document.edit(5..5, "## ")?;
+///
+/// assert_eq!(document.value(), "# a\n\n## b\n\nc");
+/// assert!(document.events().iter().any(|d| d.name == Name::HeadingAtx && d.point.index == 5));
+/// // Only the start of the document was parsed again:
+/// assert_eq!(changed, 0..11);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Document<'a> {
+    /// Configuration.
+    options: &'a ParseOptions,
+    /// Markdown.
+    value: String,
+    /// Events.
+    events: Vec<Event>,
+    /// Defined definition identifiers.
+    definitions: Vec<String>,
+    /// Defined GFM footnote definition identifiers.
+    gfm_footnote_definitions: Vec<String>,
+    /// Defined abbreviation labels.
+    abbreviations: Vec<String>,
+}
+
+impl<'a> Document<'a> {
+    /// Parse `value`.
+    ///
+    /// ## Errors
+    ///
+    /// `Document::new()` never errors with normal markdown because markdown
+    /// does not have syntax errors, so feel free to `unwrap()`.
+    /// However, MDX does have syntax errors.
+    pub fn new(value: &str, options: &'a ParseOptions) -> Result<Self, message::Message> {
+        let mut document = Document {
+            options,
+            value: String::new(),
+            events: vec![],
+            definitions: vec![],
+            gfm_footnote_definitions: vec![],
+            abbreviations: vec![],
+        };
+        document.parse(value.into())?;
+        Ok(document)
+    }
+
+    /// Get the markdown.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the events.
+    #[must_use]
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Replace the bytes in `range` with `value`, and parse again.
+    ///
+    /// Returns the byte range in the new markdown that was parsed again.
+    /// Events outside of it are unchanged, except that their places are
+    /// moved by the edit.
+    ///
+    /// ## Errors
+    ///
+    /// `edit()` never errors with normal markdown because markdown does not
+    /// have syntax errors, so feel free to `unwrap()`.
+    /// However, MDX does have syntax errors.
+    /// When the new markdown has errors, the edit is not made.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the start or end of `range` is not on a `char` boundary, or
+    /// is out of bounds.
+    pub fn edit(
+        &mut self,
+        range: Range<usize>,
+        value: &str,
+    ) -> Result<Range<usize>, message::Message> {
+        let mut next = String::with_capacity(self.value.len() - range.len() + value.len());
+        next.push_str(&self.value[..range.start]);
+        next.push_str(value);
+        next.push_str(&self.value[range.end..]);
+
+        if let Some(changed) = self.edit_range(&range, value.len(), &next) {
+            self.value = next;
+            return Ok(changed);
+        }
+
+        let len = next.len();
+        self.parse(next)?;
+        Ok(0..len)
+    }
+
+    /// Parse all of `value`.
+    fn parse(&mut self, value: String) -> Result<(), message::Message> {
+        let (events, parse_state) = parse(&value, self.options)?;
+        self.definitions = parse_state.definitions.into_iter().collect();
+        self.gfm_footnote_definitions = parse_state.gfm_footnote_definitions.into_iter().collect();
+        self.abbreviations = parse_state.abbreviations;
+        self.events = events;
+        self.value = value;
+        Ok(())
+    }
+
+    /// Parse the blocks around an edit of `range` to `size` bytes, which
+    /// turned the markdown into `next`, and splice their events in.
+    ///
+    /// Returns the range that was parsed again, or `None` if the edit could
+    /// affect other blocks, in which case nothing is changed.
+    fn edit_range(
+        &mut self,
+        range: &Range<usize>,
+        size: usize,
+        next: &str,
+    ) -> Option<Range<usize>> {
+        let places = boundaries(&self.events);
+        // Start at the block before the one with the edit, which is not
+        // changed, because the edit could make the changed block part of
+        // the block before it (such as by indenting it into a list item).
+        let before = places.partition_point(|d| d.0 <= range.start);
+        let (from, from_event) = places[before.saturating_sub(2)];
+        // End after the block after the edit, which is not changed either,
+        // to check that the edit does not affect blocks after it.
+        let after = places.partition_point(|d| d.0 <= range.end);
+        let check = places.get(after).copied();
+        let (to, to_event) = places
+            .get(after + 1)
+            .copied()
+            .unwrap_or((self.value.len(), self.events.len()));
+        let to = to - range.len() + size;
+
+        let point = if from_event < self.events.len() {
+            self.events[from_event].point.clone()
+        } else {
+            Point {
+                line: 1,
+                column: 1,
+                index: 0,
+                vs: 0,
+            }
+        };
+
+        let (mut events, _) = parse_range(
+            next,
+            self.options,
+            point,
+            to,
+            (
+                &self.definitions,
+                &self.gfm_footnote_definitions,
+                &self.abbreviations,
+            ),
+        )
+        .ok()?;
+
+        // Definitions change references everywhere.
+        if labels(&self.events[from_event..to_event], self.value.as_bytes())
+            != labels(&events, next.as_bytes())
+        {
+            return None;
+        }
+
+        let mut changed = from..to;
+        let mut lines = (0, 0);
+
+        // The block after the edit must be the same, but moved.
+        if let Some((check, check_event)) = check {
+            let check = check - range.len() + size;
+            let index = boundaries(&events).into_iter().find(|d| d.0 == check)?.1;
+            let old = &self.events[check_event..to_event];
+            let new = &events[index..];
+
+            if old.len() != new.len()
+                || old.iter().zip(new).any(|(a, b)| {
+                    a.kind != b.kind
+                        || a.name != b.name
+                        || a.point.index - range.end != b.point.index - (range.start + size)
+                        || a.point.line + new[0].point.line != b.point.line + old[0].point.line
+                        || a.point.column != b.point.column
+                        || a.point.vs != b.point.vs
+                })
+            {
+                return None;
+            }
+
+            changed.end = check;
+            lines = (old[0].point.line, new[0].point.line);
+        }
+
+        let mut tail = self.events.split_off(to_event);
+        self.events.truncate(from_event);
+        shift(&mut events, (0, from_event), (0, 0), (0, 0));
+        self.events.append(&mut events);
+        shift(
+            &mut tail,
+            (to_event, self.events.len()),
+            (range.end, range.start + size),
+            lines,
+        );
+        self.events.append(&mut tail);
+
+        Some(changed)
+    }
+}
+
+/// Get the places of blocks outside of containers that come after blank
+/// lines, and the first block, as byte offsets and indices of their first
+/// events.
+///
+/// Parsing can start again at these places.
+pub fn boundaries(events: &[Event]) -> Vec<(usize, usize)> {
+    let mut boundaries = vec![(0, 0)];
+    let mut depth = 0;
+    let mut blank = false;
+    // Whether nothing but whitespace is on the current line.
+    // A line with only a container (such as `>`) also ends in a blank line
+    // ending, but that is not a blank line outside of containers.
+    let mut empty = true;
+    let mut whitespace = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            depth -= 1;
+        } else {
+            if depth == 0 {
+                match event.name {
+                    Name::BlankLineEnding => {
+                        blank = empty;
+                        whitespace = None;
+                    }
+                    Name::LineEnding => {
+                        blank = false;
+                        whitespace = None;
+                    }
+                    // Whitespace could be on a blank line, or before a block.
+                    Name::SpaceOrTab => {
+                        if blank && whitespace.is_none() {
+                            whitespace = Some((event.point.index, index));
+                        }
+                    }
+                    _ => {
+                        if blank && index > 0 {
+                            boundaries.push(whitespace.unwrap_or((event.point.index, index)));
+                        }
+
+                        blank = false;
+                        empty = false;
+                        whitespace = None;
+                    }
+                }
+            }
+
+            if matches!(event.name, Name::BlankLineEnding | Name::LineEnding) {
+                empty = true;
+            }
+
+            depth += 1;
+        }
+
+        index += 1;
+    }
+
+    boundaries
+}
+
+/// Get the labels of definitions, GFM footnote definitions, and
+/// abbreviations in `events`, sorted.
+fn labels<'b>(events: &[Event], bytes: &'b [u8]) -> Vec<(Name, &'b [u8])> {
+    let mut labels = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit
+            && matches!(
+                event.name,
+                Name::AbbreviationDefinitionLabelString
+                    | Name::DefinitionLabelString
+                    | Name::GfmFootnoteDefinitionLabelString
+            )
+        {
+            let position = Position::from_exit_event(events, index);
+            labels.push((
+                event.name.clone(),
+                &bytes[position.start.index..position.end.index],
+            ));
+        }
+
+        index += 1;
+    }
+
+    labels.sort_by(|a, b| a.1.cmp(b.1));
+    labels
+}
+
+/// Move `events` from one index in the list of events to another (`index`),
+/// and their places from one offset to another (`offset`) and from one line
+/// to another (`line`).
+fn shift(
+    events: &mut [Event],
+    index: (usize, usize),
+    offset: (usize, usize),
+    line: (usize, usize),
+) {
+    for event in events {
+        event.point.index = event.point.index - offset.0 + offset.1;
+        event.point.line = event.point.line - line.0 + line.1;
+
+        if let Some(link) = &mut event.link {
+            link.previous = link.previous.map(|d| d - index.0 + index.1);
+            link.next = link.next.map(|d| d - index.0 + index.1);
+        }
+    }
+}
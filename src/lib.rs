@@ -9,8 +9,59 @@
 //!     — like `to_html` but lets you configure how markdown is turned into
 //!     HTML, such as allowing dangerous HTML or turning on/off different
 //!     constructs (GFM, MDX, and the like)
+//! *   [`to_html_inline()`][]
+//!     — like `to_html_with_options` but without block constructs, such as
+//!     for titles and chat messages
+//! *   [`to_html_fmt_writer()`][]
+//!     — like `to_html_with_options` but writes to a [`core::fmt::Write`][],
+//!     one top-level block at a time, such as for large documents
+//! *   [`to_html_with_blocks()`][]
+//!     — like `to_html_with_options` but also returns where top-level blocks
+//!     are in the HTML and in markdown, such as for scroll sync in editors
+//! *   [`to_epub()`][]
+//!     — turn markdown into XHTML documents for an EPUB
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_markdown()`][]
+//!     — turn a syntax tree into markdown, with [`format()`][] to format
+//!     markdown, such as for `fmt`-style tools
+//! *   [`lint()`][]
+//!     — check markdown for common problems, such as trailing whitespace
+//!     and inconsistent markers, with fixes
+//! *   [`to_mrkdwn()`][]
+//!     — turn markdown into Slack markdown (mrkdwn)
+//! *   [`to_markdown_it()`][]
+//!     — turn markdown into markdown-it tokens, as JSON
+//! *   [`to_gemtext()`][]
+//!     — turn markdown into gemtext, for Gemini
+//! *   [`to_man()`][]
+//!     — turn markdown into roff, for man pages
+//! *   [`to_xml()`][]
+//!     — turn markdown into `CommonMark` XML
+//! *   [`task_lists()`][]
+//!     — count checked and unchecked GFM task list items, such as for
+//!     progress bars
+//! *   [`toc()`][]
+//!     — collect headings into a table of contents, with
+//!     [`toc_to_html()`][] to turn it into nested lists
+//! *   [`outline()`][]
+//!     — collect headings, definitions, and footnote definitions into a
+//!     nested outline, such as for document symbols in editors
+//! *   [`semantic_tokens()`][]
+//!     — collect markers, destinations, code, and other syntax into a flat
+//!     list, such as for semantic highlighting in editors
+//! *   [`to_events()`][]
+//!     — turn markdown into events, which is what the compilers work on
+//!     internally
+//! *   [`Parser`][]
+//!     — pull events one at a time, with the markdown they span, such as for
+//!     custom renderers and filters
+//! *   [`Document`][]
+//!     — keep the events of a document, and parse only the blocks around
+//!     edits again, such as for editors
+//! *   [`Arena`][]
+//!     — reuse memory when parsing many documents, such as for servers
+//!     that render lots of comments
 //!
 //! ## Features
 //!
@@ -21,6 +72,16 @@
 //! *   **`log`**
 //!     — enable logging (includes `dep:log`);
 //!     you can show logs with `RUST_LOG=debug`
+//! *   **`std`**
+//!     — enable [`to_html_writer()`][], to write HTML to a
+//!     [`std::io::Write`][], and [`Stream`][], to parse markdown from a
+//!     [`std::io::Read`][] a few blocks at a time
+//! *   **`syntect`**
+//!     — enable [`syntax_highlight`][CompileOptions::syntax_highlight], to
+//!     highlight code with syntect (includes `dep:syntect` and `std`)
+//! *   **`trace`**
+//!     — enable [`trace()`][], to write how markdown is tokenized to a
+//!     writer
 
 #![no_std]
 #![deny(clippy::pedantic)]
@@ -34,18 +95,36 @@
 )]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+mod arena;
+mod comrak;
 mod configuration;
 mod construct;
-mod event;
+pub mod event;
+mod incremental;
+mod lint;
 mod parser;
+mod pull;
 mod resolve;
 mod state;
+#[cfg(feature = "std")]
+mod stream;
 mod subtokenize;
+mod to_cst;
+mod to_epub;
+mod to_gemtext;
 mod to_html;
+mod to_man;
+mod to_markdown;
+mod to_markdown_it;
 mod to_mdast;
+mod to_mrkdwn;
+mod to_xml;
 mod tokenizer;
 mod util;
 
+pub mod cst;
 pub mod mdast; // To do: externalize?
 pub mod message; // To do: externalize.
 pub mod unist; // To do: externalize.
@@ -61,14 +140,73 @@ pub use util::location::Location;
 
 pub use util::line_ending::LineEnding;
 
+pub use util::column_unit::ColumnUnit;
+
+pub use util::bibliography::{Bibliography, Reference, ReferenceName};
+
+pub use util::citation::CitationResolve;
+
+pub use util::code_block_handler::CodeBlockHandler;
+
+pub use util::critic_markup::CriticMarkupMode;
+
+pub use util::custom_block::{CustomBlock, CustomBlockKind};
+
+pub use util::custom_inline::CustomInline;
+
+pub use util::element_render::{Element, ElementRender};
+
+pub use util::event_transform::EventTransform;
+
+pub use util::external_link::ExternalLinkCheck;
+
+pub use util::github_reference::GithubReferenceResolve;
+
+pub use util::image_proxy::ImageProxy;
+
+pub use util::image_resolve::{ImageResolve, ResolvedImage};
+
+pub use util::link_resolve::{LinkKind, LinkResolve};
+
+pub use util::mention::MentionResolve;
+
+pub use util::outline::{Symbol, SymbolKind};
+
+pub use util::sanitize_uri::{UrlKind, UrlSanitize};
+
+pub use util::semantic_token::{SemanticToken, SemanticTokenKind};
+
+pub use util::slug::SlugKind;
+
+#[cfg(feature = "syntect")]
+pub use util::syntax_highlight::{SyntaxHighlight, SyntaxHighlightStyle};
+
+pub use util::task_list::{TaskList, TaskListItem, TaskListStats};
+
+pub use util::toc::TocEntry;
+
+pub use util::validate::validate_events;
+
+pub use util::wiki_link::{WikiEmbedResolve, WikiLinkResolve};
+
 pub use util::mdx::{
     EsmParse as MdxEsmParse, ExpressionKind as MdxExpressionKind,
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use arena::Arena;
+pub use comrak::{ComrakExtensionOptions, ComrakOptions, ComrakParseOptions, ComrakRenderOptions};
+pub use configuration::{
+    CompileOptions, Constructs, FormatOptions, LintOptions, Options, ParseOptions,
+};
+pub use incremental::Document;
+pub use pull::{EventIter, Parser, PullEvent};
+#[cfg(feature = "std")]
+pub use stream::{Chunk, Stream};
+pub use to_html::{HtmlBlock, SourceMapping};
+pub use to_markdown::ListNumbering;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 /// Turn markdown into HTML.
 ///
@@ -123,14 +261,341 @@ pub fn to_html(value: &str) -> String {
 /// # }
 /// ```
 pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, message::Message> {
-    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
     Ok(to_html::compile(
         &events,
         parse_state.bytes,
+        &options.parse,
+        &options.compile,
+    ))
+}
+
+/// Turn markdown into HTML, with configuration, without block constructs.
+///
+/// Parses `value` as if it was the content of a paragraph: only phrasing
+/// constructs, such as emphasis, code (text), and links, are recognized.
+/// Block constructs, such as headings, lists, and definitions, are not, and
+/// there is no `<p>` around the result.
+/// Blank lines are kept, but like between paragraphs, phrasing constructs
+/// cannot span them.
+/// This is useful for fragments of markdown where `# a` must not become a
+/// heading, such as titles, chat messages, and table cells.
+///
+/// ## Errors
+///
+/// `to_html_inline()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions and JSX are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_inline, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_html_inline("# *Hello*, world!", &Options::default())?,
+///     "# <em>Hello</em>, world!"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_inline(value: &str, options: &Options) -> Result<String, message::Message> {
+    let (mut events, parse_state) = parser::parse_inline(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    Ok(to_html::compile(
+        &events,
+        parse_state.bytes,
+        &options.parse,
+        &options.compile,
+    ))
+}
+
+/// Turn markdown into HTML, with configuration, written to `writer`.
+///
+/// Like [`to_html_with_options()`][], but instead of returning one string,
+/// the HTML of each top-level block is written to `writer` when it is
+/// compiled.
+/// This is useful for large documents, which can then be compiled into a
+/// file or a socket without the whole HTML in memory.
+///
+/// ## Errors
+///
+/// `to_html_fmt_writer()` errors when `writer` errors.
+/// Like [`to_html_with_options()`][], it also errors when MDX is turned on
+/// and the MDX is invalid, in which case nothing is written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_fmt_writer, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut value = String::new();
+/// to_html_fmt_writer("# Hello, world!", &Options::default(), &mut value)?;
+///
+/// assert_eq!(value, "<h1>Hello, world!</h1>");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_fmt_writer(
+    value: &str,
+    options: &Options,
+    writer: &mut impl core::fmt::Write,
+) -> Result<(), message::Message> {
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    to_html::compile_to_writer(
+        &events,
+        parse_state.bytes,
+        &options.parse,
+        &options.compile,
+        writer,
+    )
+    .map_err(|_| message::Message {
+        place: None,
+        reason: "Cannot write HTML".into(),
+        rule_id: alloc::boxed::Box::new("write".into()),
+        source: alloc::boxed::Box::new("markdown-rs".into()),
+        fix: None,
+    })
+}
+
+/// Turn markdown into HTML, with configuration, written to `writer`.
+///
+/// Like [`to_html_fmt_writer()`][], but writes to a [`std::io::Write`][],
+/// such as a file or a socket.
+///
+/// ## Errors
+///
+/// `to_html_writer()` errors when `writer` errors.
+/// Like [`to_html_with_options()`][], it also errors when MDX is turned on
+/// and the MDX is invalid, in which case nothing is written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_writer, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut value = Vec::new();
+/// to_html_writer("# Hello, world!", &Options::default(), &mut value)?;
+///
+/// assert_eq!(value, b"<h1>Hello, world!</h1>");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_html_writer(
+    value: &str,
+    options: &Options,
+    writer: &mut impl std::io::Write,
+) -> Result<(), message::Message> {
+    /// Adapter to write to an `io::Write` through `fmt::Write`.
+    struct Adapter<'a, W: std::io::Write> {
+        /// Writer.
+        inner: &'a mut W,
+        /// Error from `inner`, if any.
+        error: Option<std::io::Error>,
+    }
+
+    impl<W: std::io::Write> core::fmt::Write for Adapter<'_, W> {
+        fn write_str(&mut self, value: &str) -> core::fmt::Result {
+            self.inner.write_all(value.as_bytes()).map_err(|error| {
+                self.error = Some(error);
+                core::fmt::Error
+            })
+        }
+    }
+
+    let mut adapter = Adapter {
+        inner: writer,
+        error: None,
+    };
+
+    to_html_fmt_writer(value, options, &mut adapter).map_err(|mut message| {
+        if let Some(error) = adapter.error {
+            message.reason = alloc::format!("Cannot write HTML: {}", error);
+        }
+        message
+    })
+}
+
+/// Turn markdown into HTML, with configuration, and get its top-level blocks.
+///
+/// Parses and compiles markdown with `options`, like
+/// [`to_html_with_options()`][], and also returns an [`HtmlBlock`][] for each
+/// top-level block in the HTML, in order, with its byte offsets in the HTML
+/// and its place in markdown.
+/// This is useful to map between a markdown editor and a live preview, such
+/// as to synchronize their scroll positions, without having to add source
+/// positions to the HTML itself.
+///
+/// ## Errors
+///
+/// `to_html_with_blocks()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions, ESM, and JSX are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_blocks, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let (html, blocks) = to_html_with_blocks("# a\n\n> b\n> c", &Options::default())?;
+///
+/// assert_eq!(html, "<h1>a</h1>\n<blockquote>\n<p>b\nc</p>\n</blockquote>");
+/// assert_eq!(blocks.len(), 2);
+/// assert_eq!(blocks[1].start, 11);
+/// assert_eq!(blocks[1].position.start.line, 3);
+/// assert_eq!(blocks[1].position.end.line, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_blocks(
+    value: &str,
+    options: &Options,
+) -> Result<(String, Vec<HtmlBlock>), message::Message> {
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    Ok(to_html::compile_with_blocks(
+        &events,
+        parse_state.bytes,
+        &options.parse,
+        &options.compile,
+    ))
+}
+
+/// Turn markdown into HTML, with configuration, and get a source map.
+///
+/// Parses and compiles markdown with `options`, like
+/// [`to_html_with_options()`][], and also returns a [`SourceMapping`][] for
+/// each event that produced HTML, in the order that the events end, with
+/// its byte offsets in markdown and in the HTML.
+/// Events that produce nothing (such as definitions) are not included, and
+/// neither are events whose HTML is moved around while compiling (such as
+/// the text of setext headings or of link labels): their parents are.
+/// This is useful for precise synchronization between a markdown editor and
+/// a live preview, beyond what [`to_html_with_blocks()`][] or
+/// [`CompileOptions::sourcepos`][] offer.
+///
+/// ## Errors
+///
+/// `to_html_with_source_map()` never errors with normal markdown because
+/// markdown does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions, ESM, and JSX are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::Name, to_html_with_source_map, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let (html, map) = to_html_with_source_map("# a\n\nb", &Options::default())?;
+///
+/// assert_eq!(html, "<h1>a</h1>\n<p>b</p>");
+/// assert_eq!(map.last().unwrap().name, Name::Paragraph);
+/// assert_eq!(map.last().unwrap().source_start, 5);
+/// assert_eq!(map.last().unwrap().start, 11);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_source_map(
+    value: &str,
+    options: &Options,
+) -> Result<(String, Vec<SourceMapping>), message::Message> {
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    Ok(to_html::compile_with_source_map(
+        &events,
+        parse_state.bytes,
+        &options.parse,
         &options.compile,
     ))
 }
 
+/// Turn markdown into XHTML documents for an EPUB.
+///
+/// Parses and compiles markdown with `options`, like
+/// [`to_html_with_options()`][], with [`CompileOptions::epub`][] turned on.
+/// The HTML is split into chapters at headings of
+/// [`CompileOptions::epub_chapter_rank`][], and each chapter is wrapped in an
+/// XHTML document, with the text of its heading as its title.
+/// GFM footnotes are placed in the last chapter.
+///
+/// ## Errors
+///
+/// `to_epub()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions, ESM, and JSX are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_epub, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_epub("# a\n\nb", &Options::default())?,
+///     vec!["<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\">\n<head>\n<title>a</title>\n</head>\n<body>\n<h1>a</h1>\n<p>b</p>\n</body>\n</html>\n"]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_epub(value: &str, options: &Options) -> Result<Vec<String>, message::Message> {
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    to_epub::compile(&events, parse_state.bytes, &options.parse, &options.compile)
+}
+
+/// Turn markdown into a concrete syntax tree.
+///
+/// The concrete syntax tree is made from events (see [`to_events()`][]).
+/// Unlike the syntax tree from [`to_mdast()`][], it is lossless: every byte
+/// of `value` is in some node, so the original text of any node can be
+/// reconstructed exactly, which is what formatters and refactoring tools
+/// need.
+///
+/// ## Errors
+///
+/// `to_cst()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_cst, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let value = "> a\n> *b*";
+/// let tree = to_cst(value, &ParseOptions::default())?;
+///
+/// assert_eq!(tree.children[0].text(value), "> a\n> *b*");
+/// assert_eq!(
+///     tree.leaves().iter().map(|d| d.text(value)).collect::<String>(),
+///     value
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_cst(value: &str, options: &ParseOptions) -> Result<cst::Node, message::Message> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(to_cst::compile(&events, parse_state.bytes))
+}
+
 /// Turn markdown into a syntax tree.
 ///
 /// ## Errors
@@ -156,6 +621,673 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, me
 /// ```
 pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, message::Message> {
     let (events, parse_state) = parser::parse(value, options)?;
-    let node = to_mdast::compile(&events, parse_state.bytes)?;
+    let mut node = to_mdast::compile(&events, parse_state.bytes, options)?;
+    util::column_unit::convert(&mut node, value, &options.column_unit);
     Ok(node)
 }
+
+/// Turn a syntax tree into markdown.
+///
+/// The markdown is canonical: emphasis, strong, headings, lists, and so on
+/// are always written in the same way, and text is escaped where it would
+/// otherwise be markdown.
+/// Use [`to_markdown_with_options()`][] to configure that way.
+/// Turning the result into a syntax tree again, with the same options as
+/// the tree was made with, gives the same tree (except for positions).
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown, to_mdast, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let tree = to_mdast("Hey\n===\n\n+ _a_ __b__", &ParseOptions::default())?;
+///
+/// assert_eq!(to_markdown(&tree), "# Hey\n\n* *a* **b**\n");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown(tree: &mdast::Node) -> String {
+    to_markdown::compile(tree, &FormatOptions::default())
+}
+
+/// Turn a syntax tree into markdown, with configuration.
+///
+/// See [`FormatOptions`][] for what can be configured, such as the markers
+/// of bullets and emphasis, and wrapping.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown_with_options, to_mdast, FormatOptions, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let tree = to_mdast("* *a*", &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     to_markdown_with_options(
+///         &tree,
+///         &FormatOptions {
+///             bullet: '-',
+///             emphasis: '_',
+///             ..FormatOptions::default()
+///         }
+///     ),
+///     "- _a_\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown_with_options(tree: &mdast::Node, options: &FormatOptions) -> String {
+    to_markdown::compile(tree, options)
+}
+
+/// Format markdown.
+///
+/// Parses markdown with `options` and turns it into canonical markdown again
+/// (see [`to_markdown()`][]), such as for `fmt`-style tools.
+/// Use [`format_with_options()`][] to configure the style.
+///
+/// ## Errors
+///
+/// `format()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{format, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     format("Hey\n---\n- a\n- b\n\n___", &ParseOptions::default())?,
+///     "## Hey\n\n* a\n* b\n\n***\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn format(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    format_with_options(value, options, &FormatOptions::default())
+}
+
+/// Format markdown, with configuration.
+///
+/// Parses markdown with `options` and turns it into markdown again in the
+/// style described by `format_options`, such as to enforce a house style.
+///
+/// ## Errors
+///
+/// `format_with_options()` never errors with normal markdown because
+/// markdown does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{format_with_options, FormatOptions, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     format_with_options(
+///         "Hey\n===\n\n* **a**",
+///         &ParseOptions::default(),
+///         &FormatOptions {
+///             bullet: '-',
+///             strong: '_',
+///             setext: true,
+///             ..FormatOptions::default()
+///         }
+///     )?,
+///     "Hey\n===\n\n- __a__\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn format_with_options(
+    value: &str,
+    options: &ParseOptions,
+    format_options: &FormatOptions,
+) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_markdown::compile(&tree, format_options))
+}
+
+/// Check markdown for common problems.
+///
+/// The rules are like those of [markdownlint][], such as for whitespace at
+/// the end of lines, tabs, long lines, bare URLs, inconsistent markers,
+/// references to definitions that do not exist, and unused definitions.
+/// See [`LintOptions`][] for which rules there are and how to configure them.
+///
+/// Each message has the rule as its `rule_id`, and its `place` is where the
+/// problem is.
+/// Most messages have a [`fix`][message::Message::fix], which can be applied
+/// with [`Fix::apply`][message::Fix::apply].
+/// Messages are sorted by place.
+///
+/// When MDX is turned on and the MDX is invalid, the only message is the
+/// syntax error.
+///
+/// [markdownlint]: https://github.com/DavidAnson/markdownlint
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{lint, LintOptions};
+///
+/// let messages = lint("* a \n- b", &LintOptions::default());
+///
+/// assert_eq!(messages.len(), 2);
+/// assert_eq!(*messages[0].rule_id, "no-trailing-spaces");
+/// assert_eq!(
+///     messages[1].to_string(),
+///     "2:1-2:2: Unexpected bullet `-`, expected `*` (markdown-rs:ul-style)"
+/// );
+/// assert_eq!(
+///     messages[1].fix.as_ref().unwrap().apply("* a \n- b"),
+///     "* a \n* b"
+/// );
+/// ```
+pub fn lint(value: &str, options: &LintOptions) -> Vec<message::Message> {
+    lint::lint(value, options)
+}
+
+/// Turn markdown into Slack markdown (mrkdwn).
+///
+/// Parses markdown with `options` and serializes it as the markup that Slack
+/// uses in messages.
+/// Things that mrkdwn does not support, such as headings and tables, are
+/// turned into the closest thing that it does support.
+/// To parse mrkdwn itself, use [`ParseOptions::slack()`][].
+///
+/// ## Errors
+///
+/// `to_mrkdwn()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_mrkdwn, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_mrkdwn("# Hi\n\n**a** *b* [c](https://example.com)", &ParseOptions::default())?,
+///     "*Hi*\n\n*a* _b_ <https://example.com|c>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_mrkdwn(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_mrkdwn::compile(&tree))
+}
+
+/// Turn markdown into markdown-it tokens, as JSON.
+///
+/// Parses markdown with `options` and serializes it as the tokens that
+/// [markdown-it][] generates: a JSON array of block tokens, where inline
+/// tokens have child tokens.
+/// This lets renderers and tests written for markdown-it be used with this
+/// parser.
+///
+/// [markdown-it]: https://github.com/markdown-it/markdown-it
+///
+/// ## Errors
+///
+/// `to_markdown_it()` never errors with normal markdown because markdown does
+/// not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown_it, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_markdown_it("---", &ParseOptions::default())?,
+///     "[{\"type\":\"hr\",\"tag\":\"hr\",\"attrs\":null,\"map\":[0,1],\"nesting\":0,\"level\":0,\"children\":null,\"content\":\"\",\"markup\":\"---\",\"info\":\"\",\"meta\":null,\"block\":true,\"hidden\":false}]"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown_it(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_markdown_it::compile(&tree, value))
+}
+
+/// Turn markdown into gemtext, for Gemini.
+///
+/// Parses markdown with `options` and serializes it as gemtext, the markup of
+/// the Gemini protocol.
+/// Gemtext has no phrasing formatting or inline links, so links are turned
+/// into their text, and link lines are added after each block that has
+/// links.
+/// Code is turned into preformatted blocks.
+///
+/// ## Errors
+///
+/// `to_gemtext()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_gemtext, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_gemtext("# Hi\n\n**a** [b](https://example.com)", &ParseOptions::default())?,
+///     "# Hi\n\na b\n=> https://example.com b"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_gemtext(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_gemtext::compile(&tree))
+}
+
+/// Turn markdown into roff, for man pages.
+///
+/// Parses markdown with `options` and serializes it with the `man` macros of
+/// groff.
+/// The first heading of rank 1 becomes the title of the page.
+/// When it looks like `name(1) -- description`, that name and section are
+/// used, and a `NAME` section is added.
+/// Headings of rank 2 become sections, and deeper headings subsections.
+///
+/// ## Errors
+///
+/// `to_man()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_man, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_man("# a(1) -- b\n\n## Options\n\n**c**", &ParseOptions::default())?,
+///     ".TH \"A\" \"1\"\n.SH NAME\na \\- b\n.SH \"Options\"\n.PP\n\\f[B]c\\f[R]\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_man(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_man::compile(&tree))
+}
+
+/// Turn markdown into `CommonMark` XML.
+///
+/// Parses markdown with `options` and serializes it as the XML that
+/// `cmark -t xml` generates, which follows the
+/// [`CommonMark` DTD][dtd].
+/// This is useful to compare with other `CommonMark` parsers.
+///
+/// [dtd]: https://github.com/commonmark/commonmark-spec/blob/master/CommonMark.dtd
+///
+/// ## Errors
+///
+/// `to_xml()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_xml, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// assert_eq!(
+///     to_xml("*a*", &ParseOptions::default())?,
+///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE document SYSTEM \"CommonMark.dtd\">\n<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <paragraph>\n    <emph>\n      <text xml:space=\"preserve\">a</text>\n    </emph>\n  </paragraph>\n</document>\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_xml(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_xml::compile(&tree, value))
+}
+
+/// Count checked and unchecked GFM task list items in markdown.
+///
+/// Parses markdown with `options` and counts the task list items in the whole
+/// document and in each list, with their positions.
+/// Task list items are only recognized when `gfm_task_list_item` is turned on
+/// in `options.constructs`.
+///
+/// ## Errors
+///
+/// `task_lists()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{task_lists, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let stats = task_lists("* [x] a\n* [ ] b\n\n1. [x] c", &ParseOptions::gfm())?;
+///
+/// assert_eq!((stats.checked, stats.total()), (2, 3));
+/// assert_eq!((stats.lists[0].checked, stats.lists[0].total()), (1, 2));
+/// assert_eq!(stats.lists[1].items[0].position.as_ref().unwrap().start.line, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn task_lists(value: &str, options: &ParseOptions) -> Result<TaskListStats, message::Message> {
+    let tree = to_mdast(value, options)?;
+    Ok(util::task_list::stats(&tree))
+}
+
+/// Collect the headings in markdown into a table of contents.
+///
+/// Each entry has the rank of the heading, its plain text, its slug, and
+/// where it is.
+/// Slugs are generated with [`slug`][CompileOptions::slug] and made unique
+/// in the same way as ids are when
+/// [`heading_ids`][CompileOptions::heading_ids] is turned on, so they can be
+/// used to link to the headings in the HTML.
+/// Headings that have an `id` from an attribute list use that instead.
+///
+/// Pass the result to [`toc_to_html()`][] to turn it into nested lists.
+///
+/// ## Errors
+///
+/// `toc()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{toc, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let entries = toc("# Hello, *world*!\n\n## a\n\n## a", &Options::default())?;
+///
+/// assert_eq!(entries.len(), 3);
+/// assert_eq!((entries[0].depth, entries[0].text.as_str()), (1, "Hello, world!"));
+/// assert_eq!(entries[0].slug, "hello-world");
+/// assert_eq!(entries[2].slug, "a-1");
+/// # Ok(())
+/// # }
+/// ```
+pub fn toc(value: &str, options: &Options) -> Result<Vec<TocEntry>, message::Message> {
+    let (mut events, parse_state) = parser::parse(value, &options.parse)?;
+    util::event_transform::transform(&mut events, parse_state.bytes, &options.compile);
+    Ok(util::toc::entries(
+        &events,
+        parse_state.bytes,
+        &options.compile,
+    ))
+}
+
+/// Turn a table of contents into HTML.
+///
+/// The entries are serialized as nested `<ul>`s of links to the headings.
+/// [`id_prefix`][CompileOptions::id_prefix] is used in the links.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{toc, toc_to_html, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = Options::default();
+/// let entries = toc("# a\n\n## *b*\n\n# c", &options)?;
+///
+/// assert_eq!(
+///     toc_to_html(&entries, &options),
+///     "<ul>\n<li><a href=\"#a\">a</a>\n<ul>\n<li><a href=\"#b\">b</a></li>\n</ul>\n</li>\n<li><a href=\"#c\">c</a></li>\n</ul>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn toc_to_html(entries: &[TocEntry], options: &Options) -> String {
+    util::toc::to_html(entries, &options.compile)
+}
+
+/// Collect the headings, definitions, and footnote definitions in markdown
+/// into an outline.
+///
+/// Symbols are nested: headings contain the symbols in their section (up to
+/// the next heading of the same or a higher rank), and footnote definitions
+/// contain the symbols in them.
+/// Each symbol has its kind, name, where it is, and where its name is, so
+/// the outline can back document symbols in editors (such as for the
+/// `textDocument/documentSymbol` request of the language server protocol).
+///
+/// ## Errors
+///
+/// `outline()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{outline, ParseOptions, SymbolKind};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let symbols = outline("# a\n\n## *b*\n\nc\n\n# d", &ParseOptions::default())?;
+///
+/// assert_eq!(symbols.len(), 2);
+/// assert_eq!(symbols[0].children[0].kind, SymbolKind::Heading(2));
+/// assert_eq!(symbols[0].children[0].name, "b");
+/// // The section of `b` ends after `c`:
+/// assert_eq!(symbols[0].children[0].range.end.line, 5);
+/// // Its name is after `## `:
+/// assert_eq!(symbols[0].children[0].selection_range.start.column, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn outline(value: &str, options: &ParseOptions) -> Result<Vec<Symbol>, message::Message> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(util::outline::outline(&events, parse_state.bytes))
+}
+
+/// Collect the syntax in markdown into semantic tokens, to highlight.
+///
+/// Tokens are a flat list of the kind of syntax (such as heading markers,
+/// emphasis markers, link destinations, or code) and where it is, in order.
+/// They do not overlap and each is on one line, so they can back semantic
+/// highlighting in editors (such as for the
+/// `textDocument/semanticTokens/full` request of the language server
+/// protocol).
+/// Pass [`column_unit`][ParseOptions::column_unit] to count columns like
+/// editors do.
+///
+/// ## Errors
+///
+/// `semantic_tokens()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{semantic_tokens, ParseOptions, SemanticTokenKind};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let tokens = semantic_tokens("[a](b)", &ParseOptions::default())?;
+///
+/// // `[`, `]`, `(`, `b`, and `)`:
+/// assert_eq!(tokens.len(), 5);
+/// assert_eq!(tokens[0].kind, SemanticTokenKind::LinkMarker);
+/// assert_eq!(tokens[3].kind, SemanticTokenKind::LinkDestination);
+/// assert_eq!(tokens[3].position.start.offset, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn semantic_tokens(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<SemanticToken>, message::Message> {
+    let (events, _) = parser::parse(value, options)?;
+    let mut tokens = util::semantic_token::semantic_tokens(&events);
+
+    if options.column_unit != ColumnUnit::Byte {
+        let line_starts = util::column_unit::line_starts(value);
+
+        for token in &mut tokens {
+            let position = &mut token.position;
+            util::column_unit::convert_point(
+                &mut position.start,
+                value,
+                &options.column_unit,
+                &line_starts,
+            );
+            util::column_unit::convert_point(
+                &mut position.end,
+                value,
+                &options.column_unit,
+                &line_starts,
+            );
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Turn markdown into events.
+///
+/// Events are a flat list of enters and exits of semantic labels (see
+/// [`event::Name`][]), with their places in `value`.
+/// They are what the HTML and syntax tree compilers work on internally.
+///
+/// ## Errors
+///
+/// `to_events()` never errors with normal markdown because markdown does not
+/// have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// JSX, expressions, or ESM are written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::{Kind, Name}, to_events, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let events = to_events("a", &ParseOptions::default())?;
+///
+/// assert_eq!(events[0].kind, Kind::Enter);
+/// assert_eq!(events[0].name, Name::Paragraph);
+/// assert_eq!(events.len(), 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_events(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<event::Event>, message::Message> {
+    let (events, _) = parser::parse(value, options)?;
+    Ok(events)
+}
+
+/// Turn markdown into events, writing a trace of how that happens.
+///
+/// Writes one line per step of the state machine to `writer`: each byte that
+/// is fed to a state, states that are retried, attempts that succeed or fail,
+/// events that are entered and exited, embedded content that is
+/// subtokenized, and resolvers that run.
+/// Lines are written as they are made, so the trace of a parse that hangs or
+/// panics is written up to that point.
+/// Finally, the resulting events are written.
+/// Places are written as `index:vs`, where `index` is a byte offset into
+/// `value` and `vs` is the virtual space inside a tab.
+///
+/// This is useful to reproduce and understand bugs in the tokenizer from a
+/// single dump.
+///
+/// ## Errors
+///
+/// `trace()` errors when `writer` errors.
+/// Like [`to_mdast()`][], it also errors when MDX is turned on and the MDX is
+/// invalid, in which case the trace up to the error is still written.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{trace, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut value = String::new();
+/// trace("*a*", &ParseOptions::default(), &mut value)?;
+///
+/// assert!(value.contains("enter:   `AttentionSequence` at 0:0"));
+/// assert!(value.contains("event:   Enter `Emphasis` at 0:0"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "trace")]
+pub fn trace(
+    value: &str,
+    options: &ParseOptions,
+    writer: &mut dyn core::fmt::Write,
+) -> Result<(), message::Message> {
+    let writer = core::cell::RefCell::new(writer);
+    let failed = core::cell::Cell::new(false);
+    // Write each line as it is made, and stop at the first error.
+    let trace = |args: core::fmt::Arguments| {
+        if !failed.get() {
+            let mut writer = writer.borrow_mut();
+            failed.set(writer.write_fmt(args).is_err() || writer.write_char('\n').is_err());
+        }
+    };
+    let result = parser::parse_with_trace(value, options, &trace);
+
+    if let Ok((events, parse_state)) = &result {
+        for event in events {
+            parse_state.trace(format_args!(
+                "event:   {:?} `{:?}` at {}:{}",
+                event.kind, event.name, event.point.index, event.point.vs
+            ));
+        }
+    }
+
+    if failed.get() {
+        return Err(message::Message {
+            place: None,
+            reason: "Cannot write trace".into(),
+            rule_id: alloc::boxed::Box::new("trace".into()),
+            source: alloc::boxed::Box::new("markdown-rs".into()),
+            fix: None,
+        });
+    }
+
+    result.map(|_| ())
+}
@@ -0,0 +1,948 @@
+//! Check markdown for common problems.
+//!
+//! The rules are like those of [markdownlint][], whose names are used as
+//! rule IDs:
+//!
+//! *   `no-trailing-spaces` — whitespace at the end of lines, other than
+//!     two spaces for a hard break
+//! *   `no-hard-tabs` — tabs
+//! *   `line-length` — lines that are too long
+//! *   `no-bare-urls` — URLs in text that are not autolinks
+//! *   `ul-style`, `emphasis-style`, `strong-style`, `code-fence-style` —
+//!     markers that are different from the first marker of their kind
+//! *   `reference-links-images` — references and footnote calls to
+//!     definitions that do not exist
+//! *   `link-image-reference-definitions` — definitions and footnote
+//!     definitions that are not used
+//! *   `citation-keys` — citations of keys that are not in the bibliography,
+//!     if one is given
+//!
+//! [markdownlint]: https://github.com/DavidAnson/markdownlint
+
+use crate::configuration::LintOptions;
+use crate::event::{Event, Kind, Name};
+use crate::message::{Edit, Fix, Message, Place};
+use crate::parser;
+use crate::unist::{Point, Position};
+use crate::util::{
+    bibliography::Bibliography, constant::TAB_SIZE, normalize_identifier::normalize_identifier,
+};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// Context used to lint.
+#[derive(Debug)]
+struct LintContext<'a> {
+    /// Document.
+    value: &'a str,
+    /// Byte indices where each line starts and where its content ends
+    /// (before the line ending).
+    lines: Vec<(usize, usize)>,
+    /// Messages found so far.
+    messages: Vec<Message>,
+}
+
+/// Definition (or footnote definition) in the document.
+#[derive(Debug)]
+struct DefinitionInfo {
+    /// Whether this is a footnote definition.
+    footnote: bool,
+    /// Identifier, normalized.
+    identifier: String,
+    /// Label, as it is in the document.
+    label: String,
+    /// Byte index where the definition starts.
+    start: usize,
+    /// Byte index where the definition ends.
+    end: usize,
+}
+
+/// Check markdown for common problems.
+pub fn lint(value: &str, options: &LintOptions) -> Vec<Message> {
+    let events = match parser::parse(value, &options.parse) {
+        Ok((events, _)) => events,
+        Err(message) => return vec![message],
+    };
+    let mut context = LintContext {
+        value,
+        lines: lines(value),
+        messages: vec![],
+    };
+
+    if options.trailing_spaces {
+        trailing_spaces(&mut context, &events);
+    }
+
+    if options.hard_tabs {
+        hard_tabs(&mut context);
+    }
+
+    if let Some(size) = options.line_length {
+        line_length(&mut context, &events, size);
+    }
+
+    if options.bare_urls {
+        bare_urls(&mut context, &events);
+    }
+
+    if options.consistent_markers {
+        markers(&mut context, &events);
+    }
+
+    if options.undefined_references {
+        let footnotes = options.parse.constructs.gfm_label_start_footnote;
+        undefined_references(&mut context, &events, footnotes);
+    }
+
+    if options.unused_definitions {
+        unused_definitions(&mut context, &events);
+    }
+
+    if let Some(ref bibliography) = options.bibliography {
+        citation_keys(&mut context, &events, bibliography);
+    }
+
+    let mut messages = context.messages;
+    messages.sort_by_key(|d| match d.place.as_deref() {
+        Some(Place::Position(position)) => position.start.offset,
+        Some(Place::Point(point)) => point.offset,
+        None => 0,
+    });
+    messages
+}
+
+/// Check for whitespace at the end of lines.
+///
+/// Whitespace in code is content, so it is not checked.
+fn trailing_spaces(context: &mut LintContext, events: &[Event]) {
+    let code = ranges(
+        events,
+        &[Name::CodeFenced, Name::CodeIndented, Name::MathFlow],
+    );
+    let mut index = 0;
+
+    while index < context.lines.len() {
+        let (start, end) = context.lines[index];
+        let bytes = &context.value.as_bytes()[start..end];
+        let size = bytes
+            .iter()
+            .rev()
+            .take_while(|d| matches!(d, b' ' | b'\t'))
+            .count();
+        let whitespace = end - size;
+        index += 1;
+
+        if size == 0 || inside(&code, whitespace) {
+            continue;
+        }
+
+        let hard_break = events.iter().any(|d| {
+            d.kind == Kind::Enter
+                && d.name == Name::HardBreakTrailing
+                && d.point.index == whitespace
+        });
+
+        // Two spaces are a hard break, more are useless.
+        let replacement = if hard_break {
+            if size == 2 {
+                continue;
+            }
+
+            "  "
+        } else {
+            ""
+        };
+
+        let edit = edit(context, whitespace, end, replacement);
+        message(
+            context,
+            whitespace,
+            end,
+            "Unexpected trailing whitespace".into(),
+            "no-trailing-spaces",
+            Some(vec![edit]),
+        );
+    }
+}
+
+/// Check for tabs.
+///
+/// Tabs are replaced by spaces up to the next tab stop.
+fn hard_tabs(context: &mut LintContext) {
+    let bytes = context.value.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] != b'\t' {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+
+        while index < bytes.len() && bytes[index] == b'\t' {
+            index += 1;
+        }
+
+        let size = point(context, index).column - point(context, start).column;
+        let edit = edit(context, start, index, &" ".repeat(size));
+        message(
+            context,
+            start,
+            index,
+            "Unexpected hard tab, expected spaces".into(),
+            "no-hard-tabs",
+            Some(vec![edit]),
+        );
+    }
+}
+
+/// Check for lines longer than `max` characters.
+///
+/// Code and tables are not checked, and neither are lines that cannot be
+/// wrapped because there is no whitespace after `max`, such as those that
+/// end in a long URL.
+fn line_length(context: &mut LintContext, events: &[Event], max: usize) {
+    let skip = ranges(
+        events,
+        &[
+            Name::CodeFenced,
+            Name::CodeIndented,
+            Name::MathFlow,
+            Name::GfmTable,
+        ],
+    );
+    let mut index = 0;
+
+    while index < context.lines.len() {
+        let (start, end) = context.lines[index];
+        let line = &context.value[start..end];
+        let size = line.chars().count();
+        index += 1;
+
+        if size <= max || inside(&skip, start) {
+            continue;
+        }
+
+        let after = start + line.char_indices().nth(max).map_or(0, |d| d.0);
+
+        if !context.value[after..end].contains([' ', '\t']) {
+            continue;
+        }
+
+        message(
+            context,
+            after,
+            end,
+            format!(
+                "Unexpected line of {} characters, expected at most {}",
+                size, max
+            ),
+            "line-length",
+            None,
+        );
+    }
+}
+
+/// Check for URLs in text that are not in autolinks.
+fn bare_urls(context: &mut LintContext, events: &[Event]) {
+    let mut stack: Vec<&Name> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            stack.pop();
+            index += 1;
+            continue;
+        }
+
+        let in_link = stack
+            .iter()
+            .any(|d| matches!(d, Name::Link | Name::Image | Name::Autolink));
+
+        match event.name {
+            // `www.` links cannot be autolinks.
+            Name::GfmAutolinkLiteralEmail
+            | Name::GfmAutolinkLiteralMailto
+            | Name::GfmAutolinkLiteralProtocol
+            | Name::GfmAutolinkLiteralXmpp
+                if !in_link =>
+            {
+                let end = events[exit(events, index)].point.index;
+                bare_url(context, event.point.index, end);
+            }
+            Name::Data if !in_link && in_text(&stack) => {
+                let end = events[exit(events, index)].point.index;
+                bare_urls_in_data(context, event.point.index, end);
+            }
+            _ => {}
+        }
+
+        stack.push(&event.name);
+        index += 1;
+    }
+}
+
+/// Check whether `stack` is in text, where URLs would be bare.
+fn in_text(stack: &[&Name]) -> bool {
+    stack.iter().any(|d| {
+        matches!(
+            d,
+            Name::Paragraph
+                | Name::HeadingAtxText
+                | Name::HeadingSetextText
+                | Name::GfmTableCellText
+        )
+    }) && !stack.iter().any(|d| {
+        matches!(
+            d,
+            Name::CodeText
+                | Name::HtmlText
+                | Name::MathText
+                | Name::MdxJsxTextTag
+                | Name::MdxTextExpression
+                | Name::WikiLink
+        )
+    })
+}
+
+/// Check for `http:` and `https:` URLs in data from `start` to `end`.
+fn bare_urls_in_data(context: &mut LintContext, start: usize, end: usize) {
+    let bytes = context.value.as_bytes();
+    let mut index = start;
+
+    while index < end {
+        // Look at bytes, as `index` can be in the middle of a character.
+        let size = if bytes[index..end].starts_with(b"https://") {
+            8
+        } else if bytes[index..end].starts_with(b"http://") {
+            7
+        } else {
+            0
+        };
+
+        if size == 0 || (index > 0 && bytes[index - 1].is_ascii_alphanumeric()) {
+            index += 1;
+            continue;
+        }
+
+        let rest = &context.value[index..end];
+        let mut url_end = index
+            + rest
+                .find(|d: char| d.is_whitespace() || matches!(d, '<' | '>' | '"'))
+                .unwrap_or(rest.len());
+
+        // Trailing punctuation is probably not part of the URL.
+        loop {
+            let url = &context.value[index..url_end];
+
+            if url.ends_with(['.', ',', ':', ';', '!', '?', '\'', '*', '_', '~'])
+                || (url.ends_with(')') && url.matches(')').count() > url.matches('(').count())
+            {
+                url_end -= 1;
+            } else {
+                break;
+            }
+        }
+
+        if url_end > index + size {
+            bare_url(context, index, url_end);
+        }
+
+        index = url_end.max(index + 1);
+    }
+}
+
+/// Add a message for a bare URL from `start` to `end`.
+fn bare_url(context: &mut LintContext, start: usize, end: usize) {
+    let url = &context.value[start..end];
+    let reason = format!("Unexpected bare URL `{}`, expected an autolink", url);
+    let edit = edit(context, start, end, &format!("<{}>", url));
+    message(
+        context,
+        start,
+        end,
+        reason,
+        "no-bare-urls",
+        Some(vec![edit]),
+    );
+}
+
+/// Check that markers are the same as the first marker of their kind.
+fn markers(context: &mut LintContext, events: &[Event]) {
+    let bytes = context.value.as_bytes();
+    let mut bullet = None;
+    let mut emphasis = None;
+    let mut strong = None;
+    let mut fence = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+        let start = event.point.index;
+
+        if event.kind == Kind::Exit {
+            index += 1;
+            continue;
+        }
+
+        match event.name {
+            Name::ListItemMarker if matches!(bytes[start], b'*' | b'+' | b'-') => {
+                let expected = *bullet.get_or_insert(bytes[start]);
+
+                if bytes[start] != expected {
+                    let edit = edit(context, start, start + 1, &sequence(expected, 1));
+                    message(
+                        context,
+                        start,
+                        start + 1,
+                        format!(
+                            "Unexpected bullet {}, expected {}",
+                            code(bytes[start]),
+                            code(expected)
+                        ),
+                        "ul-style",
+                        Some(vec![edit]),
+                    );
+                }
+            }
+            Name::Emphasis | Name::Strong => {
+                let (kind, name, marker) = if event.name == Name::Emphasis {
+                    ("emphasis", Name::EmphasisSequence, &mut emphasis)
+                } else {
+                    ("strong", Name::StrongSequence, &mut strong)
+                };
+                let expected = *marker.get_or_insert(bytes[start]);
+                let end_exit = exit(events, index);
+                let end = events[end_exit].point.index;
+
+                if bytes[start] != expected
+                    && events[index + 1].name == name
+                    && events[end_exit - 1].name == name
+                {
+                    let open = (start, events[index + 2].point.index);
+                    let close = (events[end_exit - 2].point.index, end);
+                    // Underscores do not work in words.
+                    let intraword = expected == b'_'
+                        && ((start > 0 && bytes[start - 1].is_ascii_alphanumeric())
+                            || (end < bytes.len() && bytes[end].is_ascii_alphanumeric()));
+                    let edits = if intraword {
+                        None
+                    } else {
+                        Some(vec![
+                            edit(
+                                context,
+                                open.0,
+                                open.1,
+                                &sequence(expected, open.1 - open.0),
+                            ),
+                            edit(
+                                context,
+                                close.0,
+                                close.1,
+                                &sequence(expected, close.1 - close.0),
+                            ),
+                        ])
+                    };
+                    message(
+                        context,
+                        start,
+                        end,
+                        format!(
+                            "Unexpected {} marker {}, expected {}",
+                            kind,
+                            code(bytes[start]),
+                            code(expected)
+                        ),
+                        &format!("{}-style", kind),
+                        edits,
+                    );
+                }
+            }
+            Name::CodeFenced => {
+                let end_exit = exit(events, index);
+                let sequences: Vec<(usize, usize)> = (index..end_exit)
+                    .filter(|d| {
+                        events[*d].kind == Kind::Enter
+                            && events[*d].name == Name::CodeFencedFenceSequence
+                    })
+                    .map(|d| (events[d].point.index, events[d + 1].point.index))
+                    .collect();
+                let expected = *fence.get_or_insert(bytes[start]);
+
+                if bytes[start] != expected && !sequences.is_empty() {
+                    let line_end = context.value[sequences[0].1..]
+                        .find(['\n', '\r'])
+                        .map_or(context.value.len(), |d| sequences[0].1 + d);
+                    // Info cannot contain grave accents if the fence is made
+                    // of them.
+                    let edits = if expected == b'`'
+                        && context.value[sequences[0].1..line_end].contains('`')
+                    {
+                        None
+                    } else {
+                        Some(
+                            sequences
+                                .iter()
+                                .map(|d| edit(context, d.0, d.1, &sequence(expected, d.1 - d.0)))
+                                .collect(),
+                        )
+                    };
+                    message(
+                        context,
+                        sequences[0].0,
+                        sequences[0].1,
+                        format!(
+                            "Unexpected code fence marker {}, expected {}",
+                            code(bytes[start]),
+                            code(expected)
+                        ),
+                        "code-fence-style",
+                        edits,
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+}
+
+/// Check for references to definitions that do not exist.
+///
+/// Such references are not links but text, so they are found by looking for
+/// brackets in data: full (`[a][b]`) and collapsed (`[a][]`) references,
+/// and footnote calls (`[^a]`) if `footnotes`.
+/// Shortcut references (`[a]`) are not checked, as brackets are often just
+/// brackets.
+fn undefined_references(context: &mut LintContext, events: &[Event], footnotes: bool) {
+    let bytes = context.value.as_bytes();
+    let defined = definitions(context, events);
+    // Brackets, with the index of the event of the text they are in.
+    let mut brackets: Vec<(usize, usize)> = vec![];
+    let mut stack: Vec<&Name> = vec![];
+    let mut container = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            stack.pop();
+            index += 1;
+            continue;
+        }
+
+        if matches!(
+            event.name,
+            Name::Paragraph
+                | Name::HeadingAtxText
+                | Name::HeadingSetextText
+                | Name::GfmTableCellText
+        ) && !in_text(&stack)
+        {
+            container = index;
+        }
+
+        if event.name == Name::Data
+            && in_text(&stack)
+            && !stack
+                .iter()
+                .any(|d| matches!(d, Name::Autolink | Name::Resource | Name::Reference))
+        {
+            let end = events[exit(events, index)].point.index;
+            let mut position = event.point.index;
+
+            while position < end {
+                if matches!(bytes[position], b'[' | b']') {
+                    brackets.push((container, position));
+                }
+
+                position += 1;
+            }
+        }
+
+        stack.push(&event.name);
+        index += 1;
+    }
+
+    // Whether the brackets at `index` form a label, with an opening bracket
+    // at `start` (if given).
+    let label = |index: usize, start: Option<usize>| {
+        index + 1 < brackets.len()
+            && brackets[index].0 == brackets[index + 1].0
+            && bytes[brackets[index].1] == b'['
+            && bytes[brackets[index + 1].1] == b']'
+            && start.map_or(true, |d| brackets[index].1 == d)
+    };
+    let mut index = 0;
+
+    while index < brackets.len() {
+        if !label(index, None) {
+            index += 1;
+            continue;
+        }
+
+        let start = brackets[index].1;
+        let text_end = brackets[index + 1].1;
+        let mut end = text_end + 1;
+        let mut found = None;
+
+        if label(index + 2, Some(end)) {
+            let reference = &context.value[(end + 1)..brackets[index + 3].1];
+            end = brackets[index + 3].1 + 1;
+            index += 4;
+
+            // Collapsed (`[a][]`) or full.
+            let value = if reference.is_empty() {
+                &context.value[(start + 1)..text_end]
+            } else {
+                reference
+            };
+
+            if !value.trim().is_empty() {
+                found = Some((false, value));
+            }
+        } else {
+            let value = &context.value[(start + 1)..text_end];
+            index += 2;
+
+            if footnotes && value.len() > 1 && value.starts_with('^') {
+                found = Some((true, &value[1..]));
+            }
+        }
+
+        if let Some((footnote, value)) = found {
+            let identifier = normalize_identifier(value).to_lowercase();
+
+            if !defined
+                .iter()
+                .any(|d| d.footnote == footnote && d.identifier == identifier)
+            {
+                let reason = format!(
+                    "Unexpected {} undefined {}definition `{}`",
+                    if footnote { "call to" } else { "reference to" },
+                    if footnote { "footnote " } else { "" },
+                    value
+                );
+                message(context, start, end, reason, "reference-links-images", None);
+            }
+        }
+    }
+}
+
+/// Check for definitions that are not used.
+///
+/// Only the first definition of an identifier can be used, so others are
+/// reported as duplicates.
+/// Definitions labeled `//` are not checked, as they are used for comments
+/// (`[//]: # (a)`).
+fn unused_definitions(context: &mut LintContext, events: &[Event]) {
+    let defined = definitions(context, events);
+    let used = references(context, events);
+    let mut index = 0;
+
+    while index < defined.len() {
+        let definition = &defined[index];
+        let duplicate = defined[..index]
+            .iter()
+            .any(|d| d.footnote == definition.footnote && d.identifier == definition.identifier);
+        index += 1;
+
+        if definition.label == "//"
+            || (!duplicate && used.contains(&(definition.footnote, definition.identifier.clone())))
+        {
+            continue;
+        }
+
+        let reason = format!(
+            "Unexpected {} {}definition `{}`",
+            if duplicate { "duplicate" } else { "unused" },
+            if definition.footnote { "footnote " } else { "" },
+            definition.label
+        );
+        let (start, end) = (definition.start, definition.end);
+        // Remove whole lines, if the definition is on its own lines.
+        let edits = if context.lines.iter().any(|d| d.0 == start) {
+            let end = context
+                .lines
+                .iter()
+                .map(|d| d.0)
+                .find(|d| *d > end)
+                .unwrap_or(context.value.len());
+            Some(vec![edit(context, start, end, "")])
+        } else {
+            None
+        };
+        message(
+            context,
+            start,
+            end,
+            reason,
+            "link-image-reference-definitions",
+            edits,
+        );
+    }
+}
+
+/// Check for citations of keys that are not in the bibliography.
+fn citation_keys(context: &mut LintContext, events: &[Event], bibliography: &Bibliography) {
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter && event.name == Name::CitationKey {
+            let start = event.point.index;
+            let end = events[index + 1].point.index;
+            let key = &context.value[start..end];
+
+            if bibliography.get(key).is_none() {
+                let reason = format!(
+                    "Unexpected citation of `{}`, which is not in the bibliography",
+                    key
+                );
+                message(context, start, end, reason, "citation-keys", None);
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Get the definitions and footnote definitions in the document.
+fn definitions(context: &LintContext, events: &[Event]) -> Vec<DefinitionInfo> {
+    let mut definitions = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter
+            && matches!(event.name, Name::Definition | Name::GfmFootnoteDefinition)
+        {
+            let end = exit(events, index);
+
+            if let Some(label) = (index..end).find(|d| {
+                events[*d].kind == Kind::Enter
+                    && matches!(
+                        events[*d].name,
+                        Name::DefinitionLabelString | Name::GfmFootnoteDefinitionLabelString
+                    )
+            }) {
+                let label = &context.value
+                    [events[label].point.index..events[exit(events, label)].point.index];
+                definitions.push(DefinitionInfo {
+                    footnote: event.name == Name::GfmFootnoteDefinition,
+                    identifier: normalize_identifier(label).to_lowercase(),
+                    label: label.into(),
+                    start: event.point.index,
+                    end: events[end].point.index,
+                });
+            }
+        }
+
+        index += 1;
+    }
+
+    definitions
+}
+
+/// Get the identifiers that references and footnote calls use, and whether
+/// they are footnotes.
+fn references(context: &LintContext, events: &[Event]) -> Vec<(bool, String)> {
+    let mut references = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter
+            && matches!(event.name, Name::Link | Name::Image | Name::GfmFootnoteCall)
+        {
+            let parts = children(events, index);
+            let mut string = None;
+
+            // The reference string of full references, or the label text of
+            // collapsed and shortcut references (and footnote calls).
+            if let Some(reference) = parts.iter().find(|d| events[**d].name == Name::Reference) {
+                string = children(events, *reference)
+                    .into_iter()
+                    .find(|d| events[*d].name == Name::ReferenceString);
+            }
+
+            if string.is_none() && !parts.iter().any(|d| events[*d].name == Name::Resource) {
+                string = parts
+                    .iter()
+                    .find(|d| events[**d].name == Name::Label)
+                    .and_then(|d| {
+                        children(events, *d)
+                            .into_iter()
+                            .find(|d| events[*d].name == Name::LabelText)
+                    });
+            }
+
+            if let Some(string) = string {
+                let value = &context.value
+                    [events[string].point.index..events[exit(events, string)].point.index];
+                references.push((
+                    event.name == Name::GfmFootnoteCall,
+                    normalize_identifier(value).to_lowercase(),
+                ));
+            }
+        }
+
+        index += 1;
+    }
+
+    references
+}
+
+/// Get the byte indices where each line starts and where its content ends.
+fn lines(value: &str) -> Vec<(usize, usize)> {
+    let bytes = value.as_bytes();
+    let mut lines = vec![];
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if matches!(bytes[index], b'\n' | b'\r') {
+            lines.push((start, index));
+
+            if bytes[index] == b'\r' && index + 1 < bytes.len() && bytes[index + 1] == b'\n' {
+                index += 1;
+            }
+
+            start = index + 1;
+        }
+
+        index += 1;
+    }
+
+    lines.push((start, bytes.len()));
+    lines
+}
+
+/// Get the byte ranges of the outermost events named `names`.
+fn ranges(events: &[Event], names: &[Name]) -> Vec<(usize, usize)> {
+    let mut ranges = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == Kind::Enter && names.contains(&events[index].name) {
+            let end = exit(events, index);
+            ranges.push((events[index].point.index, events[end].point.index));
+            index = end;
+        }
+
+        index += 1;
+    }
+
+    ranges
+}
+
+/// Get the indices of the enters of the children of the enter at `index`.
+fn children(events: &[Event], index: usize) -> Vec<usize> {
+    let end = exit(events, index);
+    let mut children = vec![];
+    let mut index = index + 1;
+
+    while index < end {
+        children.push(index);
+        index = exit(events, index) + 1;
+    }
+
+    children
+}
+
+/// Check whether `index` is in one of `ranges`.
+fn inside(ranges: &[(usize, usize)], index: usize) -> bool {
+    ranges.iter().any(|d| d.0 <= index && index < d.1)
+}
+
+/// Get the index of the exit of the enter at `index`.
+fn exit(events: &[Event], index: usize) -> usize {
+    let mut depth = 0;
+    let mut index = index;
+
+    loop {
+        if events[index].kind == Kind::Enter {
+            depth += 1;
+        } else {
+            depth -= 1;
+
+            if depth == 0 {
+                return index;
+            }
+        }
+
+        index += 1;
+    }
+}
+
+/// Get the point of a byte index.
+///
+/// Columns count tabs up to the next tab stop, like the points of events.
+fn point(context: &LintContext, offset: usize) -> Point {
+    let line = context.lines.partition_point(|d| d.0 <= offset) - 1;
+    let mut column = 1;
+
+    for byte in &context.value.as_bytes()[context.lines[line].0..offset] {
+        column += if *byte == b'\t' {
+            TAB_SIZE - (column - 1) % TAB_SIZE
+        } else {
+            1
+        };
+    }
+
+    Point::new(line + 1, column, offset)
+}
+
+/// Create an edit that replaces from `start` to `end` with `value`.
+fn edit(context: &LintContext, start: usize, end: usize, value: &str) -> Edit {
+    Edit {
+        position: Position {
+            start: point(context, start),
+            end: point(context, end),
+        },
+        value: value.into(),
+    }
+}
+
+/// Add a message from `start` to `end`.
+fn message(
+    context: &mut LintContext,
+    start: usize,
+    end: usize,
+    reason: String,
+    rule_id: &str,
+    edits: Option<Vec<Edit>>,
+) {
+    let position = Position {
+        start: point(context, start),
+        end: point(context, end),
+    };
+    context.messages.push(Message {
+        place: Some(Box::new(Place::Position(position))),
+        reason,
+        rule_id: Box::new(rule_id.into()),
+        source: Box::new("markdown-rs".into()),
+        fix: edits.map(|edits| Box::new(Fix { edits })),
+    });
+}
+
+/// Create a sequence of `size` markers.
+fn sequence(marker: u8, size: usize) -> String {
+    String::from(char::from(marker)).repeat(size)
+}
+
+/// Format a marker as inline code, for in a reason.
+fn code(marker: u8) -> String {
+    if marker == b'`' {
+        "`` ` ``".into()
+    } else {
+        format!("`{}`", char::from(marker))
+    }
+}
@@ -29,6 +29,63 @@ pub enum ReferenceKind {
     Full,
 }
 
+/// Style of a destination (URL).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum DestinationKind {
+    /// The destination is enclosed in angle brackets.
+    ///
+    /// ```markdown
+    /// > | [a](<b>)
+    ///         ^^^
+    /// ```
+    Literal,
+    /// The destination is not enclosed, or there is no written destination,
+    /// such as in autolinks.
+    ///
+    /// ```markdown
+    /// > | [a](b)
+    ///         ^
+    /// ```
+    #[default]
+    Raw,
+}
+
+/// Style of a title.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum TitleKind {
+    /// The title is enclosed in double quotes.
+    ///
+    /// ```markdown
+    /// > | [a](b "c")
+    ///           ^^^
+    /// ```
+    Double,
+    /// The title is enclosed in single quotes.
+    ///
+    /// ```markdown
+    /// > | [a](b 'c')
+    ///           ^^^
+    /// ```
+    Single,
+    /// The title is enclosed in parentheses.
+    ///
+    /// ```markdown
+    /// > | [a](b (c))
+    ///           ^^^
+    /// ```
+    Paren,
+}
+
 /// GFM: alignment of phrasing content.
 ///
 /// Used to align the contents of table cells within a table.
@@ -81,6 +138,30 @@ pub enum AlignKind {
     None,
 }
 
+/// GitHub: kind of a reference.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum GithubReferenceKind {
+    /// Reference to an issue or pull request, by number.
+    ///
+    /// ```markdown
+    /// > | #1, GH-1, a/b#1
+    ///      ^     ^     ^
+    /// ```
+    Issue,
+    /// Reference to a commit, by SHA.
+    ///
+    /// ```markdown
+    /// > | 1a2b3c4, a/b@1a2b3c4
+    ///     ^^^^^^^      ^^^^^^^
+    /// ```
+    Commit,
+}
+
 /// Nodes.
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(
@@ -102,6 +183,12 @@ pub enum Node {
     MdxJsxFlowElement(MdxJsxFlowElement),
     /// List.
     List(List),
+    /// Directive (container).
+    ContainerDirective(ContainerDirective),
+    /// Div.
+    Div(Div),
+    /// Custom block.
+    CustomBlock(CustomBlock),
 
     // Frontmatter:
     /// MDX.js ESM.
@@ -128,6 +215,10 @@ pub enum Node {
     FootnoteReference(FootnoteReference),
     /// Html (phrasing).
     Html(Html),
+    /// Attribute list (flow or phrasing).
+    AttributeList(AttributeList),
+    /// Block id (phrasing).
+    BlockId(BlockId),
     /// Image.
     Image(Image),
     /// Image reference.
@@ -138,8 +229,42 @@ pub enum Node {
     Link(Link),
     /// Link reference.
     LinkReference(LinkReference),
+    /// Abbreviation.
+    Abbreviation(Abbreviation),
+    /// Spoiler.
+    Spoiler(Spoiler),
+    /// Span.
+    Span(Span),
+    /// Custom inline.
+    CustomInline(CustomInline),
     /// Strong
     Strong(Strong),
+    /// Directive (text).
+    TextDirective(TextDirective),
+    /// Insert.
+    Insert(Insert),
+    /// Mark.
+    Mark(Mark),
+    /// Critic markup comment.
+    CriticMarkupComment(CriticMarkupComment),
+    /// Subscript.
+    Subscript(Subscript),
+    /// Superscript.
+    Superscript(Superscript),
+    /// Underline.
+    Underline(Underline),
+    /// Citation.
+    Citation(Citation),
+    /// GitHub reference.
+    GithubReference(GithubReference),
+    /// Mention.
+    Mention(Mention),
+    /// Ruby.
+    Ruby(Ruby),
+    /// Wiki embed.
+    WikiEmbed(WikiEmbed),
+    /// Wiki link.
+    WikiLink(WikiLink),
     /// Text.
     Text(Text),
 
@@ -152,6 +277,8 @@ pub enum Node {
     MdxFlowExpression(MdxFlowExpression),
     /// Heading.
     Heading(Heading),
+    /// Directive (leaf).
+    LeafDirective(LeafDirective),
     /// Html (flow).
     // Html(Html),
     /// Table.
@@ -172,6 +299,8 @@ pub enum Node {
     ListItem(ListItem),
 
     // Content.
+    /// Abbreviation definition.
+    AbbreviationDefinition(AbbreviationDefinition),
     /// Definition.
     Definition(Definition),
     /// Paragraph.
@@ -184,6 +313,9 @@ impl fmt::Debug for Node {
         match self {
             Node::Root(x) => x.fmt(f),
             Node::BlockQuote(x) => x.fmt(f),
+            Node::ContainerDirective(x) => x.fmt(f),
+            Node::Div(x) => x.fmt(f),
+            Node::CustomBlock(x) => x.fmt(f),
             Node::FootnoteDefinition(x) => x.fmt(f),
             Node::MdxJsxFlowElement(x) => x.fmt(f),
             Node::List(x) => x.fmt(f),
@@ -198,12 +330,32 @@ impl fmt::Debug for Node {
             Node::MdxTextExpression(x) => x.fmt(f),
             Node::FootnoteReference(x) => x.fmt(f),
             Node::Html(x) => x.fmt(f),
+            Node::AttributeList(x) => x.fmt(f),
+            Node::BlockId(x) => x.fmt(f),
             Node::Image(x) => x.fmt(f),
             Node::ImageReference(x) => x.fmt(f),
             Node::MdxJsxTextElement(x) => x.fmt(f),
             Node::Link(x) => x.fmt(f),
             Node::LinkReference(x) => x.fmt(f),
+            Node::Abbreviation(x) => x.fmt(f),
+            Node::Spoiler(x) => x.fmt(f),
+            Node::Span(x) => x.fmt(f),
+            Node::CustomInline(x) => x.fmt(f),
+            Node::TextDirective(x) => x.fmt(f),
+            Node::LeafDirective(x) => x.fmt(f),
             Node::Strong(x) => x.fmt(f),
+            Node::Insert(x) => x.fmt(f),
+            Node::Mark(x) => x.fmt(f),
+            Node::CriticMarkupComment(x) => x.fmt(f),
+            Node::Subscript(x) => x.fmt(f),
+            Node::Superscript(x) => x.fmt(f),
+            Node::Underline(x) => x.fmt(f),
+            Node::Citation(x) => x.fmt(f),
+            Node::GithubReference(x) => x.fmt(f),
+            Node::Mention(x) => x.fmt(f),
+            Node::Ruby(x) => x.fmt(f),
+            Node::WikiEmbed(x) => x.fmt(f),
+            Node::WikiLink(x) => x.fmt(f),
             Node::Text(x) => x.fmt(f),
             Node::Code(x) => x.fmt(f),
             Node::Math(x) => x.fmt(f),
@@ -214,6 +366,7 @@ impl fmt::Debug for Node {
             Node::TableRow(x) => x.fmt(f),
             Node::TableCell(x) => x.fmt(f),
             Node::ListItem(x) => x.fmt(f),
+            Node::AbbreviationDefinition(x) => x.fmt(f),
             Node::Definition(x) => x.fmt(f),
             Node::Paragraph(x) => x.fmt(f),
         }
@@ -224,12 +377,16 @@ fn children_to_string(children: &[Node]) -> String {
     children.iter().map(ToString::to_string).collect()
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Node {
     fn to_string(&self) -> String {
         match self {
             // Parents.
             Node::Root(x) => children_to_string(&x.children),
             Node::BlockQuote(x) => children_to_string(&x.children),
+            Node::ContainerDirective(x) => children_to_string(&x.children),
+            Node::Div(x) => children_to_string(&x.children),
+            Node::CustomBlock(x) => children_to_string(&x.children),
             Node::FootnoteDefinition(x) => children_to_string(&x.children),
             Node::MdxJsxFlowElement(x) => children_to_string(&x.children),
             Node::List(x) => children_to_string(&x.children),
@@ -238,7 +395,19 @@ impl ToString for Node {
             Node::MdxJsxTextElement(x) => children_to_string(&x.children),
             Node::Link(x) => children_to_string(&x.children),
             Node::LinkReference(x) => children_to_string(&x.children),
+            Node::Abbreviation(x) => children_to_string(&x.children),
+            Node::Spoiler(x) => children_to_string(&x.children),
+            Node::Span(x) => children_to_string(&x.children),
+            Node::CustomInline(x) => children_to_string(&x.children),
+            Node::TextDirective(x) => children_to_string(&x.children),
+            Node::LeafDirective(x) => children_to_string(&x.children),
             Node::Strong(x) => children_to_string(&x.children),
+            Node::Insert(x) => children_to_string(&x.children),
+            Node::Mark(x) => children_to_string(&x.children),
+            Node::CriticMarkupComment(x) => children_to_string(&x.children),
+            Node::Subscript(x) => children_to_string(&x.children),
+            Node::Superscript(x) => children_to_string(&x.children),
+            Node::Underline(x) => children_to_string(&x.children),
             Node::Heading(x) => children_to_string(&x.children),
             Node::Table(x) => children_to_string(&x.children),
             Node::TableRow(x) => children_to_string(&x.children),
@@ -255,16 +424,25 @@ impl ToString for Node {
             Node::MdxTextExpression(x) => x.value.clone(),
             Node::Html(x) => x.value.clone(),
             Node::Text(x) => x.value.clone(),
+            Node::Ruby(x) => x.base.clone(),
             Node::Code(x) => x.value.clone(),
             Node::Math(x) => x.value.clone(),
             Node::MdxFlowExpression(x) => x.value.clone(),
 
             // Voids.
-            Node::Break(_)
+            Node::AttributeList(_)
+            | Node::BlockId(_)
+            | Node::Break(_)
             | Node::FootnoteReference(_)
             | Node::Image(_)
             | Node::ImageReference(_)
             | Node::ThematicBreak(_)
+            | Node::Citation(_)
+            | Node::GithubReference(_)
+            | Node::Mention(_)
+            | Node::WikiEmbed(_)
+            | Node::WikiLink(_)
+            | Node::AbbreviationDefinition(_)
             | Node::Definition(_) => String::new(),
         }
     }
@@ -279,10 +457,25 @@ impl Node {
             Node::Paragraph(x) => Some(&x.children),
             Node::Heading(x) => Some(&x.children),
             Node::BlockQuote(x) => Some(&x.children),
+            Node::ContainerDirective(x) => Some(&x.children),
+            Node::Div(x) => Some(&x.children),
+            Node::CustomBlock(x) => Some(&x.children),
             Node::List(x) => Some(&x.children),
             Node::ListItem(x) => Some(&x.children),
             Node::Emphasis(x) => Some(&x.children),
+            Node::Abbreviation(x) => Some(&x.children),
+            Node::Spoiler(x) => Some(&x.children),
+            Node::Span(x) => Some(&x.children),
+            Node::CustomInline(x) => Some(&x.children),
+            Node::TextDirective(x) => Some(&x.children),
+            Node::LeafDirective(x) => Some(&x.children),
             Node::Strong(x) => Some(&x.children),
+            Node::Insert(x) => Some(&x.children),
+            Node::Mark(x) => Some(&x.children),
+            Node::CriticMarkupComment(x) => Some(&x.children),
+            Node::Subscript(x) => Some(&x.children),
+            Node::Superscript(x) => Some(&x.children),
+            Node::Underline(x) => Some(&x.children),
             Node::Link(x) => Some(&x.children),
             Node::LinkReference(x) => Some(&x.children),
             Node::FootnoteDefinition(x) => Some(&x.children),
@@ -304,10 +497,25 @@ impl Node {
             Node::Paragraph(x) => Some(&mut x.children),
             Node::Heading(x) => Some(&mut x.children),
             Node::BlockQuote(x) => Some(&mut x.children),
+            Node::ContainerDirective(x) => Some(&mut x.children),
+            Node::Div(x) => Some(&mut x.children),
+            Node::CustomBlock(x) => Some(&mut x.children),
             Node::List(x) => Some(&mut x.children),
             Node::ListItem(x) => Some(&mut x.children),
             Node::Emphasis(x) => Some(&mut x.children),
+            Node::Abbreviation(x) => Some(&mut x.children),
+            Node::Spoiler(x) => Some(&mut x.children),
+            Node::Span(x) => Some(&mut x.children),
+            Node::CustomInline(x) => Some(&mut x.children),
+            Node::TextDirective(x) => Some(&mut x.children),
+            Node::LeafDirective(x) => Some(&mut x.children),
             Node::Strong(x) => Some(&mut x.children),
+            Node::Insert(x) => Some(&mut x.children),
+            Node::Mark(x) => Some(&mut x.children),
+            Node::CriticMarkupComment(x) => Some(&mut x.children),
+            Node::Subscript(x) => Some(&mut x.children),
+            Node::Superscript(x) => Some(&mut x.children),
+            Node::Underline(x) => Some(&mut x.children),
             Node::Link(x) => Some(&mut x.children),
             Node::LinkReference(x) => Some(&mut x.children),
             Node::FootnoteDefinition(x) => Some(&mut x.children),
@@ -327,6 +535,9 @@ impl Node {
         match self {
             Node::Root(x) => x.position.as_ref(),
             Node::BlockQuote(x) => x.position.as_ref(),
+            Node::ContainerDirective(x) => x.position.as_ref(),
+            Node::Div(x) => x.position.as_ref(),
+            Node::CustomBlock(x) => x.position.as_ref(),
             Node::FootnoteDefinition(x) => x.position.as_ref(),
             Node::MdxJsxFlowElement(x) => x.position.as_ref(),
             Node::List(x) => x.position.as_ref(),
@@ -341,12 +552,32 @@ impl Node {
             Node::MdxTextExpression(x) => x.position.as_ref(),
             Node::FootnoteReference(x) => x.position.as_ref(),
             Node::Html(x) => x.position.as_ref(),
+            Node::AttributeList(x) => x.position.as_ref(),
+            Node::BlockId(x) => x.position.as_ref(),
             Node::Image(x) => x.position.as_ref(),
             Node::ImageReference(x) => x.position.as_ref(),
             Node::MdxJsxTextElement(x) => x.position.as_ref(),
             Node::Link(x) => x.position.as_ref(),
             Node::LinkReference(x) => x.position.as_ref(),
+            Node::Abbreviation(x) => x.position.as_ref(),
+            Node::Spoiler(x) => x.position.as_ref(),
+            Node::Span(x) => x.position.as_ref(),
+            Node::CustomInline(x) => x.position.as_ref(),
+            Node::TextDirective(x) => x.position.as_ref(),
+            Node::LeafDirective(x) => x.position.as_ref(),
             Node::Strong(x) => x.position.as_ref(),
+            Node::Insert(x) => x.position.as_ref(),
+            Node::Mark(x) => x.position.as_ref(),
+            Node::CriticMarkupComment(x) => x.position.as_ref(),
+            Node::Subscript(x) => x.position.as_ref(),
+            Node::Superscript(x) => x.position.as_ref(),
+            Node::Underline(x) => x.position.as_ref(),
+            Node::Citation(x) => x.position.as_ref(),
+            Node::GithubReference(x) => x.position.as_ref(),
+            Node::Mention(x) => x.position.as_ref(),
+            Node::Ruby(x) => x.position.as_ref(),
+            Node::WikiEmbed(x) => x.position.as_ref(),
+            Node::WikiLink(x) => x.position.as_ref(),
             Node::Text(x) => x.position.as_ref(),
             Node::Code(x) => x.position.as_ref(),
             Node::Math(x) => x.position.as_ref(),
@@ -357,6 +588,7 @@ impl Node {
             Node::TableRow(x) => x.position.as_ref(),
             Node::TableCell(x) => x.position.as_ref(),
             Node::ListItem(x) => x.position.as_ref(),
+            Node::AbbreviationDefinition(x) => x.position.as_ref(),
             Node::Definition(x) => x.position.as_ref(),
             Node::Paragraph(x) => x.position.as_ref(),
         }
@@ -366,6 +598,9 @@ impl Node {
         match self {
             Node::Root(x) => x.position.as_mut(),
             Node::BlockQuote(x) => x.position.as_mut(),
+            Node::ContainerDirective(x) => x.position.as_mut(),
+            Node::Div(x) => x.position.as_mut(),
+            Node::CustomBlock(x) => x.position.as_mut(),
             Node::FootnoteDefinition(x) => x.position.as_mut(),
             Node::MdxJsxFlowElement(x) => x.position.as_mut(),
             Node::List(x) => x.position.as_mut(),
@@ -380,12 +615,32 @@ impl Node {
             Node::MdxTextExpression(x) => x.position.as_mut(),
             Node::FootnoteReference(x) => x.position.as_mut(),
             Node::Html(x) => x.position.as_mut(),
+            Node::AttributeList(x) => x.position.as_mut(),
+            Node::BlockId(x) => x.position.as_mut(),
             Node::Image(x) => x.position.as_mut(),
             Node::ImageReference(x) => x.position.as_mut(),
             Node::MdxJsxTextElement(x) => x.position.as_mut(),
             Node::Link(x) => x.position.as_mut(),
             Node::LinkReference(x) => x.position.as_mut(),
+            Node::Abbreviation(x) => x.position.as_mut(),
+            Node::Spoiler(x) => x.position.as_mut(),
+            Node::Span(x) => x.position.as_mut(),
+            Node::CustomInline(x) => x.position.as_mut(),
+            Node::TextDirective(x) => x.position.as_mut(),
+            Node::LeafDirective(x) => x.position.as_mut(),
             Node::Strong(x) => x.position.as_mut(),
+            Node::Insert(x) => x.position.as_mut(),
+            Node::Mark(x) => x.position.as_mut(),
+            Node::CriticMarkupComment(x) => x.position.as_mut(),
+            Node::Subscript(x) => x.position.as_mut(),
+            Node::Superscript(x) => x.position.as_mut(),
+            Node::Underline(x) => x.position.as_mut(),
+            Node::Citation(x) => x.position.as_mut(),
+            Node::GithubReference(x) => x.position.as_mut(),
+            Node::Mention(x) => x.position.as_mut(),
+            Node::Ruby(x) => x.position.as_mut(),
+            Node::WikiEmbed(x) => x.position.as_mut(),
+            Node::WikiLink(x) => x.position.as_mut(),
             Node::Text(x) => x.position.as_mut(),
             Node::Code(x) => x.position.as_mut(),
             Node::Math(x) => x.position.as_mut(),
@@ -396,6 +651,7 @@ impl Node {
             Node::TableRow(x) => x.position.as_mut(),
             Node::TableCell(x) => x.position.as_mut(),
             Node::ListItem(x) => x.position.as_mut(),
+            Node::AbbreviationDefinition(x) => x.position.as_mut(),
             Node::Definition(x) => x.position.as_mut(),
             Node::Paragraph(x) => x.position.as_mut(),
         }
@@ -405,6 +661,9 @@ impl Node {
         match self {
             Node::Root(x) => x.position = position,
             Node::BlockQuote(x) => x.position = position,
+            Node::ContainerDirective(x) => x.position = position,
+            Node::Div(x) => x.position = position,
+            Node::CustomBlock(x) => x.position = position,
             Node::FootnoteDefinition(x) => x.position = position,
             Node::MdxJsxFlowElement(x) => x.position = position,
             Node::List(x) => x.position = position,
@@ -419,12 +678,32 @@ impl Node {
             Node::MdxTextExpression(x) => x.position = position,
             Node::FootnoteReference(x) => x.position = position,
             Node::Html(x) => x.position = position,
+            Node::AttributeList(x) => x.position = position,
+            Node::BlockId(x) => x.position = position,
             Node::Image(x) => x.position = position,
             Node::ImageReference(x) => x.position = position,
             Node::MdxJsxTextElement(x) => x.position = position,
             Node::Link(x) => x.position = position,
             Node::LinkReference(x) => x.position = position,
+            Node::Abbreviation(x) => x.position = position,
+            Node::Spoiler(x) => x.position = position,
+            Node::Span(x) => x.position = position,
+            Node::CustomInline(x) => x.position = position,
+            Node::TextDirective(x) => x.position = position,
+            Node::LeafDirective(x) => x.position = position,
             Node::Strong(x) => x.position = position,
+            Node::Insert(x) => x.position = position,
+            Node::Mark(x) => x.position = position,
+            Node::CriticMarkupComment(x) => x.position = position,
+            Node::Subscript(x) => x.position = position,
+            Node::Superscript(x) => x.position = position,
+            Node::Underline(x) => x.position = position,
+            Node::Citation(x) => x.position = position,
+            Node::GithubReference(x) => x.position = position,
+            Node::Mention(x) => x.position = position,
+            Node::Ruby(x) => x.position = position,
+            Node::WikiEmbed(x) => x.position = position,
+            Node::WikiLink(x) => x.position = position,
             Node::Text(x) => x.position = position,
             Node::Code(x) => x.position = position,
             Node::Math(x) => x.position = position,
@@ -435,6 +714,7 @@ impl Node {
             Node::TableRow(x) => x.position = position,
             Node::TableCell(x) => x.position = position,
             Node::ListItem(x) => x.position = position,
+            Node::AbbreviationDefinition(x) => x.position = position,
             Node::Definition(x) => x.position = position,
             Node::Paragraph(x) => x.position = position,
         }
@@ -541,6 +821,87 @@ pub struct Paragraph {
     pub position: Option<Position>,
 }
 
+/// Directive (leaf).
+///
+/// ```markdown
+/// > | ::a[b]
+///     ^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "leafDirective")
+)]
+pub struct LeafDirective {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Name.
+    pub name: String,
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Custom inline.
+///
+/// ```markdown
+/// > | %%a%%
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "customInline")
+)]
+pub struct CustomInline {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Name of the construct, as registered in
+    /// [`custom_inlines`][crate::ParseOptions::custom_inlines].
+    pub name: String,
+}
+
+/// Custom block.
+///
+/// ```markdown
+/// > | !!! a
+///     ^^^^^
+/// > | b
+///     ^
+/// > | !!!
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "customBlock")
+)]
+pub struct CustomBlock {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Name of the construct, as registered in
+    /// [`custom_blocks`][crate::ParseOptions::custom_blocks].
+    pub name: String,
+    /// Info string of the opening fence, if any.
+    pub info: Option<String>,
+}
+
 /// Heading.
 ///
 /// ```markdown
@@ -600,6 +961,69 @@ pub struct BlockQuote {
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// GFM: the type of the alert (such as `note`), if it is one.
+    pub alert: Option<String>,
+}
+
+/// Div.
+///
+/// ```markdown
+/// > | ::: a
+///     ^^^^^
+/// > | b
+///     ^
+/// > | :::
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "div")
+)]
+pub struct Div {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Directive (container).
+///
+/// ```markdown
+/// > | :::a
+///     ^^^^
+/// > | b
+///     ^
+/// > | :::
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "containerDirective")
+)]
+pub struct ContainerDirective {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Name.
+    pub name: String,
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
 }
 
 /// List.
@@ -656,6 +1080,13 @@ pub struct ListItem {
     /// GFM: whether the item is done (when `true`), not done (when `false`),
     /// or indeterminate or not applicable (`None`).
     pub checked: Option<bool>,
+    /// GFM: the raw state of the task list item (such as `' '` or `'x'`), if
+    /// it is one.
+    ///
+    /// Other states (`'-'`, `'~'`, or `'>'`) are only supported with
+    /// `gfm_task_list_item_states`, and have `checked: None`.
+    #[cfg_attr(feature = "serde", serde(rename = "taskState"))]
+    pub task_state: Option<char>,
 }
 
 /// Html (flow or phrasing).
@@ -678,6 +1109,56 @@ pub struct Html {
     pub position: Option<Position>,
 }
 
+/// Attribute list (flow or phrasing).
+///
+/// Applies to the node before it, or, in flow, when there is no node
+/// directly before it, the node after it.
+///
+/// ```markdown
+/// > | *a*{: .b}
+///        ^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "attributeList")
+)]
+pub struct AttributeList {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Block id (Obsidian).
+///
+/// Applies to the paragraph or heading it is in, or, when that paragraph
+/// is the first in a list item, to that list item.
+///
+/// ```markdown
+/// > | a ^b
+///       ^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "blockId")
+)]
+pub struct BlockId {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Value, without the caret (`^`).
+    pub value: String,
+}
+
 /// Code (flow).
 ///
 /// ```markdown
@@ -734,6 +1215,29 @@ pub struct Math {
     pub meta: Option<String>,
 }
 
+/// Abbreviation definition.
+///
+/// ```markdown
+/// > | *[a]: b
+///     ^^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "abbreviationDefinition")
+)]
+pub struct AbbreviationDefinition {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Label, the text that is abbreviated.
+    pub label: String,
+    /// Title, the expansion of the abbreviation.
+    pub title: String,
+}
+
 /// Definition.
 ///
 /// ```markdown
@@ -756,6 +1260,14 @@ pub struct Definition {
     /// Advisory info for the resource, such as something that would be
     /// appropriate for a tooltip.
     pub title: Option<String>,
+    /// Style of the destination (`url`).
+    #[cfg_attr(feature = "serde", serde(rename = "destinationKind"))]
+    pub destination_kind: DestinationKind,
+    /// Style of the title.
+    ///
+    /// This is set but `title` is `None` if the title is empty (`""`).
+    #[cfg_attr(feature = "serde", serde(rename = "titleKind"))]
+    pub title_kind: Option<TitleKind>,
     // Association.
     /// Value that can match another node.
     /// `identifier` is a source value: character escapes and character references
@@ -831,558 +1343,1389 @@ pub struct Strong {
     pub position: Option<Position>,
 }
 
-/// Code (phrasing).
+/// Directive (text).
 ///
 /// ```markdown
-/// > | `a`
-///     ^^^
+/// > | :a[b]
+///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "inlineCode")
+    serde(tag = "type", rename = "textDirective")
 )]
-pub struct InlineCode {
-    // Text.
+pub struct TextDirective {
+    // Parent.
     /// Content model.
-    pub value: String,
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Name.
+    pub name: String,
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
 }
 
-/// Math (phrasing).
+/// Abbreviation.
 ///
 /// ```markdown
-/// > | $a$
-///     ^^^
+///   | *[a]: b
+///   |
+/// > | a
+///     ^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "inlineMath")
+    serde(tag = "type", rename = "abbreviation")
 )]
-pub struct InlineMath {
-    // Text.
+pub struct Abbreviation {
+    // Parent.
     /// Content model.
-    pub value: String,
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Title, from the abbreviation definition.
+    pub title: String,
 }
 
-/// Break.
+/// Spoiler.
 ///
 /// ```markdown
-/// > | a\
-///      ^
-///   | b
+/// > | >!a!<
+///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "break")
+    serde(tag = "type", rename = "spoiler")
 )]
-pub struct Break {
-    // Void.
+pub struct Spoiler {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
 }
 
-/// Link.
+/// Span.
 ///
 /// ```markdown
-/// > | [a](b)
-///     ^^^^^^
+/// > | [a]{.b}
+///     ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "link")
+    serde(tag = "type", rename = "span")
 )]
-pub struct Link {
+pub struct Span {
     // Parent.
     /// Content model.
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Resource.
-    /// URL to the referenced resource.
-    pub url: String,
-    /// Advisory info for the resource, such as something that would be
-    /// appropriate for a tooltip.
-    pub title: Option<String>,
+    // Extra.
+    /// Attributes, as name and value pairs.
+    ///
+    /// Classes are joined into one `class` attribute.
+    pub attributes: Vec<(String, String)>,
 }
 
-/// Image.
+/// Insert.
 ///
 /// ```markdown
-/// > | ![a](b)
-///     ^^^^^^^
+/// > | a ++b++ c
+///       ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "image")
+    serde(tag = "type", rename = "insert")
 )]
-pub struct Image {
-    // Void.
+pub struct Insert {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Alternative.
-    /// Equivalent content for environments that cannot represent the node as
-    /// intended.
-    pub alt: String,
-    // Resource.
-    /// URL to the referenced resource.
-    pub url: String,
-    /// Advisory info for the resource, such as something that would be
-    /// appropriate for a tooltip.
-    pub title: Option<String>,
 }
 
-/// Link reference.
+/// Mark.
 ///
 /// ```markdown
-/// > | [a]
-///     ^^^
+/// > | a ==b== c
+///       ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "linkReference")
+    serde(tag = "type", rename = "mark")
 )]
-pub struct LinkReference {
+pub struct Mark {
     // Parent.
     /// Content model.
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Reference.
-    /// Explicitness of a reference.
-    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
-    pub reference_kind: ReferenceKind,
-    // Association.
-    /// Value that can match another node.
-    /// `identifier` is a source value: character escapes and character references
-    /// are *not* parsed.
-    /// Its value must be normalized.
-    pub identifier: String,
-    /// `label` is a string value: it works just like `title` on a link or a
-    /// `lang` on code: character escapes and character references are parsed.
-    ///
-    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
-    /// space, trim the optional initial and/or final space, and perform
-    /// case-folding.
-    pub label: Option<String>,
 }
 
-/// Image reference.
+/// Critic markup comment.
 ///
 /// ```markdown
-/// > | ![a]
-///     ^^^^
+/// > | a {>>b<<} c
+///       ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "imageReference")
+    serde(tag = "type", rename = "criticMarkupComment")
 )]
-pub struct ImageReference {
-    // Void.
+pub struct CriticMarkupComment {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Alternative.
-    /// Equivalent content for environments that cannot represent the node as
-    /// intended.
-    pub alt: String,
-    // Reference.
-    /// Explicitness of a reference.
-    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
-    pub reference_kind: ReferenceKind,
-    // Association.
-    /// Value that can match another node.
-    /// `identifier` is a source value: character escapes and character references
-    /// are *not* parsed.
-    /// Its value must be normalized.
-    pub identifier: String,
-    /// `label` is a string value: it works just like `title` on a link or a
-    /// `lang` on code: character escapes and character references are parsed.
-    ///
-    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
-    /// space, trim the optional initial and/or final space, and perform
-    /// case-folding.
-    pub label: Option<String>,
 }
 
-/// GFM: footnote definition.
+/// Subscript.
 ///
 /// ```markdown
-/// > | [^a]: b
-///     ^^^^^^^
+/// > | H~2~O
+///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "footnoteDefinition")
+    serde(tag = "type", rename = "subscript")
 )]
-pub struct FootnoteDefinition {
+pub struct Subscript {
     // Parent.
     /// Content model.
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Association.
-    /// Value that can match another node.
-    /// `identifier` is a source value: character escapes and character references
-    /// are *not* parsed.
-    /// Its value must be normalized.
-    pub identifier: String,
-    /// `label` is a string value: it works just like `title` on a link or a
-    /// `lang` on code: character escapes and character references are parsed.
-    ///
-    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
-    /// space, trim the optional initial and/or final space, and perform
-    /// case-folding.
-    pub label: Option<String>,
 }
 
-/// GFM: footnote reference.
+/// Superscript.
 ///
 /// ```markdown
-/// > | [^a]
+/// > | 2^10^
 ///     ^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "footnoteReference")
+    serde(tag = "type", rename = "superscript")
 )]
-pub struct FootnoteReference {
-    // Void.
+pub struct Superscript {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Association.
-    /// Value that can match another node.
-    /// `identifier` is a source value: character escapes and character references
-    /// are *not* parsed.
-    /// Its value must be normalized.
-    pub identifier: String,
-    /// `label` is a string value: it works just like `title` on a link or a
-    /// `lang` on code: character escapes and character references are parsed.
-    ///
-    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
-    /// space, trim the optional initial and/or final space, and perform
-    /// case-folding.
-    pub label: Option<String>,
 }
 
-/// GFM: table.
+/// Underline.
 ///
 /// ```markdown
-/// > | | a |
-///     ^^^^^
-/// > | | - |
+/// > | __a__
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "table")
+    serde(tag = "type", rename = "underline")
 )]
-pub struct Table {
+pub struct Underline {
     // Parent.
     /// Content model.
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // Extra.
-    /// Represents how cells in columns are aligned.
-    pub align: Vec<AlignKind>,
 }
 
-/// GFM: table row.
+/// Citation (Pandoc).
 ///
 /// ```markdown
-/// > | | a |
-///     ^^^^^
+/// > | [see @a, p. 1; @b]
+///     ^^^^^^^^^^^^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "tableRow")
+    serde(tag = "type", rename = "citation")
 )]
-pub struct TableRow {
-    // Parent.
-    /// Content model.
-    pub children: Vec<Node>,
+pub struct Citation {
+    // Void.
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Whether the citation is in the text (`@a`), instead of in brackets
+    /// (`[@a]`).
+    #[cfg_attr(feature = "serde", serde(rename = "inText"))]
+    pub in_text: bool,
+    /// Cited works.
+    pub items: Vec<CitationItem>,
 }
 
-/// GFM: table cell.
+/// Cited work in a citation.
 ///
 /// ```markdown
-/// > | | a |
+/// > | [see @a, p. 1; @b]
+///      ^^^^^^^^^^^^  ^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CitationItem {
+    /// Key of the work in the bibliography.
+    pub key: String,
+    /// Text before the key, such as `see`.
+    pub prefix: Option<String>,
+    /// Text after the key, such as `p. 1`.
+    pub locator: Option<String>,
+    /// Whether to not show the author (`-@a`).
+    #[cfg_attr(feature = "serde", serde(rename = "suppressAuthor"))]
+    pub suppress_author: bool,
+}
+
+/// GitHub reference.
+///
+/// ```markdown
+/// > | a/b#1
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "tableCell")
+    serde(tag = "type", rename = "githubReference")
 )]
-pub struct TableCell {
-    // Parent.
-    /// Content model.
-    pub children: Vec<Node>,
+pub struct GithubReference {
+    // Void.
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Kind of reference.
+    pub kind: GithubReferenceKind,
+    /// Repository (`owner/name`), if it is not the current one.
+    pub repository: Option<String>,
+    /// Number of the issue or pull request, or SHA of the commit.
+    pub value: String,
 }
 
-/// GFM: delete.
+/// Mention.
 ///
 /// ```markdown
-/// > | ~~a~~
-///     ^^^^^
+/// > | @a
+///     ^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "delete")
+    serde(tag = "type", rename = "mention")
 )]
-pub struct Delete {
-    // Parent.
-    /// Content model.
-    pub children: Vec<Node>,
+pub struct Mention {
+    // Void.
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Name, such as the username of who is mentioned.
+    pub name: String,
 }
 
-/// Frontmatter: yaml.
+/// Ruby.
 ///
 /// ```markdown
-/// > | ---
-///     ^^^
-/// > | a: b
-///     ^^^^
-/// > | ---
-///     ^^^
+/// > | {a|b}
+///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "yaml")
+    serde(tag = "type", rename = "ruby")
 )]
-pub struct Yaml {
+pub struct Ruby {
     // Void.
-    /// Content model.
-    pub value: String,
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Base, the text that is annotated.
+    pub base: String,
+    /// Ruby text, such as the pronunciation of the base.
+    pub text: String,
 }
 
-/// Frontmatter: toml.
+/// Wiki embed.
 ///
 /// ```markdown
-/// > | +++
-///     ^^^
-/// > | a: b
-///     ^^^^
-/// > | +++
-///     ^^^
+/// > | ![[a#b|c]]
+///     ^^^^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "toml")
+    serde(tag = "type", rename = "wikiEmbed")
 )]
-pub struct Toml {
+pub struct WikiEmbed {
     // Void.
-    /// Content model.
-    pub value: String,
     /// Positional info.
     pub position: Option<Position>,
+    // Extra.
+    /// Target, such as the name of a page or file.
+    pub target: String,
+    /// Fragment, such as a heading (`b`) or block id (`^b`) in the page.
+    pub fragment: Option<String>,
+    /// Alias, shown instead of the target.
+    pub alias: Option<String>,
 }
 
-/// MDX: ESM.
+/// Wiki link.
 ///
 /// ```markdown
-/// > | import a from 'b'
-///     ^^^^^^^^^^^^^^^^^
+/// > | [[a|b]]
+///     ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxjsEsm")
+    serde(tag = "type", rename = "wikiLink")
 )]
-pub struct MdxjsEsm {
-    // Literal.
-    /// Content model.
-    pub value: String,
+pub struct WikiLink {
+    // Void.
     /// Positional info.
     pub position: Option<Position>,
-
-    // Custom data on where each slice of `value` came from.
-    pub stops: Vec<Stop>,
+    // Extra.
+    /// Target, such as the name of a page, optionally with a heading
+    /// (`#b`) or block id (`#^b`).
+    pub target: String,
+    /// Alias, shown instead of the target.
+    pub alias: Option<String>,
 }
 
-/// MDX: expression (flow).
+/// Code (phrasing).
 ///
 /// ```markdown
-/// > | {a}
+/// > | `a`
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxFlowExpression")
+    serde(tag = "type", rename = "inlineCode")
 )]
-pub struct MdxFlowExpression {
-    // Literal.
+pub struct InlineCode {
+    // Text.
     /// Content model.
     pub value: String,
     /// Positional info.
     pub position: Option<Position>,
-
-    // Custom data on where each slice of `value` came from.
-    pub stops: Vec<Stop>,
 }
 
-/// MDX: expression (text).
+/// Math (phrasing).
 ///
 /// ```markdown
-/// > | a {b}
-///       ^^^
+/// > | $a$
+///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxTextExpression")
+    serde(tag = "type", rename = "inlineMath")
 )]
-pub struct MdxTextExpression {
-    // Literal.
+pub struct InlineMath {
+    // Text.
     /// Content model.
     pub value: String,
     /// Positional info.
     pub position: Option<Position>,
-
-    // Custom data on where each slice of `value` came from.
-    pub stops: Vec<Stop>,
 }
 
-/// MDX: JSX element (container).
+/// Break.
 ///
 /// ```markdown
-/// > | <a />
-///     ^^^^^
+/// > | a\
+///      ^
+///   | b
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxFlowElement")
+    serde(tag = "type", rename = "break")
 )]
-pub struct MdxJsxFlowElement {
-    // Parent.
-    /// Content model.
-    pub children: Vec<Node>,
+pub struct Break {
+    // Void.
     /// Positional info.
     pub position: Option<Position>,
-    // JSX element.
-    /// Name.
-    ///
-    /// Fragments have no name.
-    pub name: Option<String>,
-    /// Attributes.
-    pub attributes: Vec<AttributeContent>,
 }
 
-/// MDX: JSX element (text).
+/// Link.
 ///
 /// ```markdown
-/// > | <a />.
-///     ^^^^^
+/// > | [a](b)
+///     ^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxTextElement")
+    serde(tag = "type", rename = "link")
 )]
-pub struct MdxJsxTextElement {
+pub struct Link {
     // Parent.
     /// Content model.
     pub children: Vec<Node>,
     /// Positional info.
     pub position: Option<Position>,
-    // JSX element.
-    /// Name.
+    // Resource.
+    /// URL to the referenced resource.
+    pub url: String,
+    /// Advisory info for the resource, such as something that would be
+    /// appropriate for a tooltip.
+    pub title: Option<String>,
+    /// Style of the destination (`url`).
+    #[cfg_attr(feature = "serde", serde(rename = "destinationKind"))]
+    pub destination_kind: DestinationKind,
+    /// Style of the title.
     ///
-    /// Fragments have no name.
-    pub name: Option<String>,
-    /// Attributes.
-    pub attributes: Vec<AttributeContent>,
+    /// This is set but `title` is `None` if the title is empty (`""`).
+    #[cfg_attr(feature = "serde", serde(rename = "titleKind"))]
+    pub title_kind: Option<TitleKind>,
 }
 
-/// MDX: JSX attribute.
+/// Image.
 ///
 /// ```markdown
-/// > | <a b />
-///        ^
+/// > | ![a](b)
+///     ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxAttribute")
+    serde(tag = "type", rename = "image")
 )]
-pub struct MdxJsxAttribute {
+pub struct Image {
     // Void.
     /// Positional info.
-    // pub position: Option<Position>,
-    /// Key.
-    pub name: String,
-    /// Value.
-    pub value: Option<AttributeValue>,
+    pub position: Option<Position>,
+    // Alternative.
+    /// Equivalent content for environments that cannot represent the node as
+    /// intended.
+    pub alt: String,
+    // Resource.
+    /// URL to the referenced resource.
+    pub url: String,
+    /// Advisory info for the resource, such as something that would be
+    /// appropriate for a tooltip.
+    pub title: Option<String>,
+    /// Style of the destination (`url`).
+    #[cfg_attr(feature = "serde", serde(rename = "destinationKind"))]
+    pub destination_kind: DestinationKind,
+    /// Style of the title.
+    ///
+    /// This is set but `title` is `None` if the title is empty (`""`).
+    #[cfg_attr(feature = "serde", serde(rename = "titleKind"))]
+    pub title_kind: Option<TitleKind>,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::unist::Position;
-    use alloc::{format, string::ToString, vec};
+/// Link reference.
+///
+/// ```markdown
+/// > | [a]
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "linkReference")
+)]
+pub struct LinkReference {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Reference.
+    /// Explicitness of a reference.
+    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
+    pub reference_kind: ReferenceKind,
+    // Association.
+    /// Value that can match another node.
+    /// `identifier` is a source value: character escapes and character references
+    /// are *not* parsed.
+    /// Its value must be normalized.
+    pub identifier: String,
+    /// `label` is a string value: it works just like `title` on a link or a
+    /// `lang` on code: character escapes and character references are parsed.
+    ///
+    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
+    /// space, trim the optional initial and/or final space, and perform
+    /// case-folding.
+    pub label: Option<String>,
+}
 
-    // Literals.
+/// Image reference.
+///
+/// ```markdown
+/// > | ![a]
+///     ^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "imageReference")
+)]
+pub struct ImageReference {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Alternative.
+    /// Equivalent content for environments that cannot represent the node as
+    /// intended.
+    pub alt: String,
+    // Reference.
+    /// Explicitness of a reference.
+    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
+    pub reference_kind: ReferenceKind,
+    // Association.
+    /// Value that can match another node.
+    /// `identifier` is a source value: character escapes and character references
+    /// are *not* parsed.
+    /// Its value must be normalized.
+    pub identifier: String,
+    /// `label` is a string value: it works just like `title` on a link or a
+    /// `lang` on code: character escapes and character references are parsed.
+    ///
+    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
+    /// space, trim the optional initial and/or final space, and perform
+    /// case-folding.
+    pub label: Option<String>,
+}
+
+/// GFM: footnote definition.
+///
+/// ```markdown
+/// > | [^a]: b
+///     ^^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "footnoteDefinition")
+)]
+pub struct FootnoteDefinition {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Association.
+    /// Value that can match another node.
+    /// `identifier` is a source value: character escapes and character references
+    /// are *not* parsed.
+    /// Its value must be normalized.
+    pub identifier: String,
+    /// `label` is a string value: it works just like `title` on a link or a
+    /// `lang` on code: character escapes and character references are parsed.
+    ///
+    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
+    /// space, trim the optional initial and/or final space, and perform
+    /// case-folding.
+    pub label: Option<String>,
+}
+
+/// GFM: footnote reference.
+///
+/// ```markdown
+/// > | [^a]
+///     ^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "footnoteReference")
+)]
+pub struct FootnoteReference {
+    // Void.
+    /// Positional info.
+    pub position: Option<Position>,
+    // Association.
+    /// Value that can match another node.
+    /// `identifier` is a source value: character escapes and character references
+    /// are *not* parsed.
+    /// Its value must be normalized.
+    pub identifier: String,
+    /// `label` is a string value: it works just like `title` on a link or a
+    /// `lang` on code: character escapes and character references are parsed.
+    ///
+    /// To normalize a value, collapse markdown whitespace (`[\t\n\r ]+`) to a
+    /// space, trim the optional initial and/or final space, and perform
+    /// case-folding.
+    pub label: Option<String>,
+}
+
+/// GFM: table.
+///
+/// ```markdown
+/// > | | a |
+///     ^^^^^
+/// > | | - |
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "table")
+)]
+pub struct Table {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// Represents how cells in columns are aligned.
+    pub align: Vec<AlignKind>,
+}
+
+/// GFM: table row.
+///
+/// ```markdown
+/// > | | a |
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "tableRow")
+)]
+pub struct TableRow {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// GFM: table cell.
+///
+/// ```markdown
+/// > | | a |
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "tableCell")
+)]
+pub struct TableCell {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// GFM: delete.
+///
+/// ```markdown
+/// > | ~~a~~
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "delete")
+)]
+pub struct Delete {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Frontmatter: yaml.
+///
+/// ```markdown
+/// > | ---
+///     ^^^
+/// > | a: b
+///     ^^^^
+/// > | ---
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "yaml")
+)]
+pub struct Yaml {
+    // Void.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Frontmatter: toml.
+///
+/// ```markdown
+/// > | +++
+///     ^^^
+/// > | a: b
+///     ^^^^
+/// > | +++
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "toml")
+)]
+pub struct Toml {
+    // Void.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// MDX: ESM.
+///
+/// ```markdown
+/// > | import a from 'b'
+///     ^^^^^^^^^^^^^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxjsEsm")
+)]
+pub struct MdxjsEsm {
+    // Literal.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+
+    // Custom data on where each slice of `value` came from.
+    pub stops: Vec<Stop>,
+}
+
+/// MDX: expression (flow).
+///
+/// ```markdown
+/// > | {a}
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxFlowExpression")
+)]
+pub struct MdxFlowExpression {
+    // Literal.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+
+    // Custom data on where each slice of `value` came from.
+    pub stops: Vec<Stop>,
+}
+
+/// MDX: expression (text).
+///
+/// ```markdown
+/// > | a {b}
+///       ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxTextExpression")
+)]
+pub struct MdxTextExpression {
+    // Literal.
+    /// Content model.
+    pub value: String,
+    /// Positional info.
+    pub position: Option<Position>,
+
+    // Custom data on where each slice of `value` came from.
+    pub stops: Vec<Stop>,
+}
+
+/// MDX: JSX element (container).
+///
+/// ```markdown
+/// > | <a />
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxJsxFlowElement")
+)]
+pub struct MdxJsxFlowElement {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // JSX element.
+    /// Name.
+    ///
+    /// Fragments have no name.
+    pub name: Option<String>,
+    /// Attributes.
+    pub attributes: Vec<AttributeContent>,
+}
+
+/// MDX: JSX element (text).
+///
+/// ```markdown
+/// > | <a />.
+///     ^^^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxJsxTextElement")
+)]
+pub struct MdxJsxTextElement {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // JSX element.
+    /// Name.
+    ///
+    /// Fragments have no name.
+    pub name: Option<String>,
+    /// Attributes.
+    pub attributes: Vec<AttributeContent>,
+}
+
+/// MDX: JSX attribute.
+///
+/// ```markdown
+/// > | <a b />
+///        ^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename = "mdxJsxAttribute")
+)]
+pub struct MdxJsxAttribute {
+    // Void.
+    /// Positional info.
+    // pub position: Option<Position>,
+    /// Key.
+    pub name: String,
+    /// Value.
+    pub value: Option<AttributeValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unist::Position;
+    use alloc::{format, string::ToString, vec};
+
+    // Literals.
+
+    #[test]
+    fn text() {
+        let mut node = Node::Text(Text {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Text { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Text { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn inline_code() {
+        let mut node = Node::InlineCode(InlineCode {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "InlineCode { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "InlineCode { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn code() {
+        let mut node = Node::Code(Code {
+            value: "a".into(),
+            position: None,
+            lang: None,
+            meta: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Code { value: \"a\", position: None, lang: None, meta: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn inline_math() {
+        let mut node = Node::InlineMath(InlineMath {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "InlineMath { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "InlineMath { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn math() {
+        let mut node = Node::Math(Math {
+            value: "a".into(),
+            position: None,
+            meta: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Math { value: \"a\", position: None, meta: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Math { value: \"a\", position: Some(1:1-1:2 (0-1)), meta: None }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn html() {
+        let mut node = Node::Html(Html {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Html { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Html { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn mdx_text_expression() {
+        let mut node = Node::MdxTextExpression(MdxTextExpression {
+            value: "a".into(),
+            stops: vec![],
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxTextExpression { value: \"a\", position: None, stops: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxTextExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn mdx_flow_expression() {
+        let mut node = Node::MdxFlowExpression(MdxFlowExpression {
+            value: "a".into(),
+            stops: vec![],
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxFlowExpression { value: \"a\", position: None, stops: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxFlowExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn mdxjs_esm() {
+        let mut node = Node::MdxjsEsm(MdxjsEsm {
+            value: "a".into(),
+            stops: vec![],
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxjsEsm { value: \"a\", position: None, stops: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "MdxjsEsm { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn toml() {
+        let mut node = Node::Toml(Toml {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Toml { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Toml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn yaml() {
+        let mut node = Node::Yaml(Yaml {
+            value: "a".into(),
+            position: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Yaml { value: \"a\", position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Yaml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    // Voids.
+
+    #[test]
+    fn break_node() {
+        let mut node = Node::Break(Break { position: None });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Break { position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Break { position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn thematic_break() {
+        let mut node = Node::ThematicBreak(ThematicBreak { position: None });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "ThematicBreak { position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "ThematicBreak { position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn attribute_list() {
+        let mut node = Node::AttributeList(AttributeList {
+            position: None,
+            attributes: vec![("class".into(), "a".into())],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "AttributeList { position: None, attributes: [(\"class\", \"a\")] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "AttributeList { position: Some(1:1-1:2 (0-1)), attributes: [(\"class\", \"a\")] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn block_id() {
+        let mut node = Node::BlockId(BlockId {
+            position: None,
+            value: "a".into(),
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "BlockId { position: None, value: \"a\" }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "BlockId { position: Some(1:1-1:2 (0-1)), value: \"a\" }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn footnote_reference() {
+        let mut node = Node::FootnoteReference(FootnoteReference {
+            position: None,
+            identifier: "a".into(),
+            label: Some("b".into()),
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\") }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\") }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn image_reference() {
+        let mut node = Node::ImageReference(ImageReference {
+            position: None,
+            alt: "a".into(),
+            identifier: "b".into(),
+            label: Some("c".into()),
+            reference_kind: ReferenceKind::Full,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "ImageReference { position: None, alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "ImageReference { position: Some(1:1-1:2 (0-1)), alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "should support `position_set`"
+        );
+    }
 
     #[test]
-    fn text() {
-        let mut node = Node::Text(Text {
-            value: "a".into(),
+    fn image() {
+        let mut node = Node::Image(Image {
             position: None,
+            alt: "a".into(),
+            url: "b".into(),
+            title: None,
+            destination_kind: DestinationKind::Raw,
+            title_kind: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Text { value: \"a\", position: None }",
+            "Image { position: None, alt: \"a\", url: \"b\", title: None, destination_kind: Raw, title_kind: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
         assert_eq!(node.children_mut(), None, "should support `children_mut`");
         assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
@@ -1390,24 +2733,29 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Text { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Image { position: Some(1:1-1:2 (0-1)), alt: \"a\", url: \"b\", title: None, destination_kind: Raw, title_kind: None }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn inline_code() {
-        let mut node = Node::InlineCode(InlineCode {
-            value: "a".into(),
+    fn definition() {
+        let mut node = Node::Definition(Definition {
             position: None,
+            identifier: "a".into(),
+            label: None,
+            url: "b".into(),
+            title: None,
+            destination_kind: DestinationKind::Raw,
+            title_kind: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "InlineCode { value: \"a\", position: None }",
+            "Definition { position: None, url: \"b\", title: None, destination_kind: Raw, title_kind: None, identifier: \"a\", label: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
         assert_eq!(node.children_mut(), None, "should support `children_mut`");
         assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
@@ -1415,327 +2763,587 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "InlineCode { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Definition { position: Some(1:1-1:2 (0-1)), url: \"b\", title: None, destination_kind: Raw, title_kind: None, identifier: \"a\", label: None }",
             "should support `position_set`"
         );
     }
 
+    // Parents.
+
     #[test]
-    fn code() {
-        let mut node = Node::Code(Code {
-            value: "a".into(),
+    fn root() {
+        let mut node = Node::Root(Root {
             position: None,
-            lang: None,
-            meta: None,
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: None, lang: None, meta: None }",
+            "Root { children: [], position: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None }",
+            "Root { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn inline_math() {
-        let mut node = Node::InlineMath(InlineMath {
-            value: "a".into(),
+    fn block_quote() {
+        let mut node = Node::BlockQuote(BlockQuote {
+            position: None,
+            children: vec![],
+            alert: None,
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "BlockQuote { children: [], position: None, alert: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "BlockQuote { children: [], position: Some(1:1-1:2 (0-1)), alert: None }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn delete() {
+        let mut node = Node::Delete(Delete {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Delete { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Delete { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn emphasis() {
+        let mut node = Node::Emphasis(Emphasis {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Emphasis { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Emphasis { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn strong() {
+        let mut node = Node::Strong(Strong {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Strong { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Strong { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn spoiler() {
+        let mut node = Node::Spoiler(Spoiler {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Spoiler { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Spoiler { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn span() {
+        let mut node = Node::Span(Span {
+            position: None,
+            attributes: vec![],
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "Span { children: [], position: None, attributes: [] }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "Span { children: [], position: Some(1:1-1:2 (0-1)), attributes: [] }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn custom_block() {
+        let mut node = Node::CustomBlock(CustomBlock {
+            position: None,
+            name: "a".into(),
+            info: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "CustomBlock { children: [], position: None, name: \"a\", info: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "CustomBlock { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", info: None }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn custom_inline() {
+        let mut node = Node::CustomInline(CustomInline {
             position: None,
+            name: "a".into(),
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "InlineMath { value: \"a\", position: None }",
+            "CustomInline { children: [], position: None, name: \"a\" }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "InlineMath { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "CustomInline { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\" }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn math() {
-        let mut node = Node::Math(Math {
-            value: "a".into(),
+    fn container_directive() {
+        let mut node = Node::ContainerDirective(ContainerDirective {
             position: None,
-            meta: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Math { value: \"a\", position: None, meta: None }",
+            "ContainerDirective { children: [], position: None, name: \"a\", attributes: [] }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Math { value: \"a\", position: Some(1:1-1:2 (0-1)), meta: None }",
+            "ContainerDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn html() {
-        let mut node = Node::Html(Html {
-            value: "a".into(),
+    fn div() {
+        let mut node = Node::Div(Div {
             position: None,
+            attributes: vec![],
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Html { value: \"a\", position: None }",
+            "Div { children: [], position: None, attributes: [] }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Html { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Div { children: [], position: Some(1:1-1:2 (0-1)), attributes: [] }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn mdx_text_expression() {
-        let mut node = Node::MdxTextExpression(MdxTextExpression {
-            value: "a".into(),
-            stops: vec![],
+    fn leaf_directive() {
+        let mut node = Node::LeafDirective(LeafDirective {
             position: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxTextExpression { value: \"a\", position: None, stops: [] }",
+            "LeafDirective { children: [], position: None, name: \"a\", attributes: [] }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxTextExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "LeafDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn mdx_flow_expression() {
-        let mut node = Node::MdxFlowExpression(MdxFlowExpression {
-            value: "a".into(),
-            stops: vec![],
+    fn text_directive() {
+        let mut node = Node::TextDirective(TextDirective {
             position: None,
+            name: "a".into(),
+            attributes: vec![],
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxFlowExpression { value: \"a\", position: None, stops: [] }",
+            "TextDirective { children: [], position: None, name: \"a\", attributes: [] }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxFlowExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "TextDirective { children: [], position: Some(1:1-1:2 (0-1)), name: \"a\", attributes: [] }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn mdxjs_esm() {
-        let mut node = Node::MdxjsEsm(MdxjsEsm {
-            value: "a".into(),
-            stops: vec![],
+    fn insert() {
+        let mut node = Node::Insert(Insert {
             position: None,
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxjsEsm { value: \"a\", position: None, stops: [] }",
+            "Insert { children: [], position: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxjsEsm { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "Insert { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn toml() {
-        let mut node = Node::Toml(Toml {
-            value: "a".into(),
+    fn mark() {
+        let mut node = Node::Mark(Mark {
             position: None,
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Toml { value: \"a\", position: None }",
+            "Mark { children: [], position: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Toml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Mark { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn yaml() {
-        let mut node = Node::Yaml(Yaml {
-            value: "a".into(),
+    fn critic_markup_comment() {
+        let mut node = Node::CriticMarkupComment(CriticMarkupComment {
             position: None,
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Yaml { value: \"a\", position: None }",
+            "CriticMarkupComment { children: [], position: None }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "a", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Yaml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "CriticMarkupComment { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
-    // Voids.
-
     #[test]
-    fn break_node() {
-        let mut node = Node::Break(Break { position: None });
+    fn subscript() {
+        let mut node = Node::Subscript(Subscript {
+            position: None,
+            children: vec![],
+        });
 
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: None }",
+            "Subscript { children: [], position: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: Some(1:1-1:2 (0-1)) }",
+            "Subscript { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn thematic_break() {
-        let mut node = Node::ThematicBreak(ThematicBreak { position: None });
+    fn superscript() {
+        let mut node = Node::Superscript(Superscript {
+            position: None,
+            children: vec![],
+        });
 
         assert_eq!(
             format!("{:?}", node),
-            "ThematicBreak { position: None }",
+            "Superscript { children: [], position: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ThematicBreak { position: Some(1:1-1:2 (0-1)) }",
+            "Superscript { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn footnote_reference() {
-        let mut node = Node::FootnoteReference(FootnoteReference {
+    fn underline() {
+        let mut node = Node::Underline(Underline {
             position: None,
-            identifier: "a".into(),
-            label: Some("b".into()),
+            children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\") }",
+            "Underline { children: [], position: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(node.children_mut(), None, "should support `children_mut`");
-        assert_eq!(node.children(), None, "should support `children`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\") }",
+            "Underline { children: [], position: Some(1:1-1:2 (0-1)) }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn image_reference() {
-        let mut node = Node::ImageReference(ImageReference {
+    fn citation() {
+        let mut node = Node::Citation(Citation {
             position: None,
-            alt: "a".into(),
-            identifier: "b".into(),
-            label: Some("c".into()),
-            reference_kind: ReferenceKind::Full,
+            in_text: true,
+            items: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "ImageReference { position: None, alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "Citation { position: None, in_text: true, items: [] }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1746,23 +3354,23 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ImageReference { position: Some(1:1-1:2 (0-1)), alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "Citation { position: Some(1:1-1:2 (0-1)), in_text: true, items: [] }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn image() {
-        let mut node = Node::Image(Image {
+    fn github_reference() {
+        let mut node = Node::GithubReference(GithubReference {
             position: None,
-            alt: "a".into(),
-            url: "b".into(),
-            title: None,
+            kind: GithubReferenceKind::Issue,
+            repository: None,
+            value: "1".into(),
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Image { position: None, alt: \"a\", url: \"b\", title: None }",
+            "GithubReference { position: None, kind: Issue, repository: None, value: \"1\" }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1773,24 +3381,21 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Image { position: Some(1:1-1:2 (0-1)), alt: \"a\", url: \"b\", title: None }",
+            "GithubReference { position: Some(1:1-1:2 (0-1)), kind: Issue, repository: None, value: \"1\" }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn definition() {
-        let mut node = Node::Definition(Definition {
+    fn mention() {
+        let mut node = Node::Mention(Mention {
             position: None,
-            identifier: "a".into(),
-            label: None,
-            url: "b".into(),
-            title: None,
+            name: "a".into(),
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Definition { position: None, url: \"b\", title: None, identifier: \"a\", label: None }",
+            "Mention { position: None, name: \"a\" }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1801,110 +3406,101 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Definition { position: Some(1:1-1:2 (0-1)), url: \"b\", title: None, identifier: \"a\", label: None }",
+            "Mention { position: Some(1:1-1:2 (0-1)), name: \"a\" }",
             "should support `position_set`"
         );
     }
 
-    // Parents.
-
     #[test]
-    fn root() {
-        let mut node = Node::Root(Root {
+    fn ruby() {
+        let mut node = Node::Ruby(Ruby {
             position: None,
-            children: vec![],
+            base: "a".into(),
+            text: "b".into(),
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Root { children: [], position: None }",
+            "Ruby { position: None, base: \"a\", text: \"b\" }",
             "should support `Debug`"
         );
-        assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(
-            node.children_mut(),
-            Some(&mut vec![]),
-            "should support `children_mut`"
-        );
-        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.to_string(), "a", "should support `ToString`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Root { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Ruby { position: Some(1:1-1:2 (0-1)), base: \"a\", text: \"b\" }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn block_quote() {
-        let mut node = Node::BlockQuote(BlockQuote {
+    fn wiki_embed() {
+        let mut node = Node::WikiEmbed(WikiEmbed {
             position: None,
-            children: vec![],
+            target: "a".into(),
+            fragment: None,
+            alias: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "BlockQuote { children: [], position: None }",
+            "WikiEmbed { position: None, target: \"a\", fragment: None, alias: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(
-            node.children_mut(),
-            Some(&mut vec![]),
-            "should support `children_mut`"
-        );
-        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "BlockQuote { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "WikiEmbed { position: Some(1:1-1:2 (0-1)), target: \"a\", fragment: None, alias: None }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn delete() {
-        let mut node = Node::Delete(Delete {
+    fn wiki_link() {
+        let mut node = Node::WikiLink(WikiLink {
             position: None,
-            children: vec![],
+            target: "a".into(),
+            alias: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Delete { children: [], position: None }",
+            "WikiLink { position: None, target: \"a\", alias: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(
-            node.children_mut(),
-            Some(&mut vec![]),
-            "should support `children_mut`"
-        );
-        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Delete { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "WikiLink { position: Some(1:1-1:2 (0-1)), target: \"a\", alias: None }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn emphasis() {
-        let mut node = Node::Emphasis(Emphasis {
+    fn abbreviation() {
+        let mut node = Node::Abbreviation(Abbreviation {
             position: None,
             children: vec![],
+            title: "a".into(),
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Emphasis { children: [], position: None }",
+            "Abbreviation { children: [], position: None, title: \"a\" }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1919,36 +3515,33 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Emphasis { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Abbreviation { children: [], position: Some(1:1-1:2 (0-1)), title: \"a\" }",
             "should support `position_set`"
         );
     }
 
     #[test]
-    fn strong() {
-        let mut node = Node::Strong(Strong {
+    fn abbreviation_definition() {
+        let mut node = Node::AbbreviationDefinition(AbbreviationDefinition {
             position: None,
-            children: vec![],
+            label: "a".into(),
+            title: "b".into(),
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Strong { children: [], position: None }",
+            "AbbreviationDefinition { position: None, label: \"a\", title: \"b\" }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
-        assert_eq!(
-            node.children_mut(),
-            Some(&mut vec![]),
-            "should support `children_mut`"
-        );
-        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.children_mut(), None, "should support `children_mut`");
+        assert_eq!(node.children(), None, "should support `children`");
         assert_eq!(node.position(), None, "should support `position`");
         assert_eq!(node.position_mut(), None, "should support `position`");
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Strong { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "AbbreviationDefinition { position: Some(1:1-1:2 (0-1)), label: \"a\", title: \"b\" }",
             "should support `position_set`"
         );
     }
@@ -2106,12 +3699,13 @@ mod tests {
             position: None,
             spread: false,
             checked: None,
+            task_state: None,
             children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "ListItem { children: [], position: None, spread: false, checked: None }",
+            "ListItem { children: [], position: None, spread: false, checked: None, task_state: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2126,7 +3720,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ListItem { children: [], position: Some(1:1-1:2 (0-1)), spread: false, checked: None }",
+            "ListItem { children: [], position: Some(1:1-1:2 (0-1)), spread: false, checked: None, task_state: None }",
             "should support `position_set`"
         );
     }
@@ -2201,12 +3795,14 @@ mod tests {
             position: None,
             url: "a".into(),
             title: None,
+            destination_kind: DestinationKind::Raw,
+            title_kind: None,
             children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: None, url: \"a\", title: None }",
+            "Link { children: [], position: None, url: \"a\", title: None, destination_kind: Raw, title_kind: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2221,7 +3817,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None }",
+            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None, destination_kind: Raw, title_kind: None }",
             "should support `position_set`"
         );
     }
@@ -1,5 +1,5 @@
 use crate::unist::{Point, Position};
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{boxed::Box, fmt, string::String, vec::Vec};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Message {
@@ -11,13 +11,15 @@ pub struct Message {
     pub rule_id: Box<String>,
     /// Namespace of message.
     pub source: Box<String>,
+    /// Suggested change that fixes the problem, if there is one.
+    pub fix: Option<Box<Fix>>,
 }
 
 impl fmt::Display for Message {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(ref place) = self.place {
             write!(f, "{}: ", place)?;
-        };
+        }
 
         write!(f, "{} ({}:{})", self.reason, self.source, self.rule_id)
     }
@@ -44,3 +46,37 @@ impl fmt::Display for Place {
         }
     }
 }
+
+/// Suggested change that fixes a problem.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fix {
+    /// Edits to make, in order and not overlapping.
+    pub edits: Vec<Edit>,
+}
+
+impl Fix {
+    /// Apply the fix to `value`, the document the message is about.
+    #[must_use]
+    pub fn apply(&self, value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        let mut start = 0;
+
+        for edit in &self.edits {
+            result.push_str(&value[start..edit.position.start.offset]);
+            result.push_str(&edit.value);
+            start = edit.position.end.offset;
+        }
+
+        result.push_str(&value[start..]);
+        result
+    }
+}
+
+/// Replacement of some text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edit {
+    /// Place of the text to replace.
+    pub position: Position,
+    /// Text to replace it with.
+    pub value: String,
+}
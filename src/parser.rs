@@ -1,13 +1,31 @@
 //! Turn bytes of markdown into events.
 
-use crate::event::{Event, Point};
+use crate::construct::text::MARKERS as TEXT_MARKERS;
+use crate::event::{Event, Name, Point};
 use crate::message;
 use crate::state::{Name as StateName, State};
-use crate::subtokenize::subtokenize;
-use crate::tokenizer::Tokenizer;
-use crate::util::location::Location;
+use crate::subtokenize::{subtokenize, Subresult};
+use crate::tokenizer::{Spare, Tokenizer};
+use crate::util::{
+    custom_inline::markers as custom_inline_markers, location::Location, validate::validate_events,
+};
 use crate::ParseOptions;
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{collections::BTreeSet, string::String, vec, vec::Vec};
+use core::cell::RefCell;
+
+#[cfg(feature = "trace")]
+use core::fmt;
+
+/// Where a trace is written to: called with each line, as it is made.
+#[cfg(feature = "trace")]
+pub struct Trace<'a>(pub &'a dyn Fn(fmt::Arguments));
+
+#[cfg(feature = "trace")]
+impl fmt::Debug for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Trace(..)")
+    }
+}
 
 /// Info needed, in all content types, when parsing markdown.
 ///
@@ -22,9 +40,69 @@ pub struct ParseState<'a> {
     /// List of chars.
     pub bytes: &'a [u8],
     /// Set of defined definition identifiers.
-    pub definitions: Vec<String>,
+    ///
+    /// This is a set, as every reference looks itself up in it.
+    pub definitions: BTreeSet<String>,
     /// Set of defined GFM footnote definition identifiers.
-    pub gfm_footnote_definitions: Vec<String>,
+    pub gfm_footnote_definitions: BTreeSet<String>,
+    /// Set of defined abbreviation labels.
+    pub abbreviations: Vec<String>,
+    /// Bytes that can start something in text.
+    ///
+    /// These are the markers of the constructs that are built in, and the
+    /// first bytes of custom inline delimiters.
+    pub text_markers: Vec<u8>,
+    /// Memory of tokenizers that are done, to reuse.
+    pub spare: RefCell<Vec<Spare>>,
+    /// Where to write a trace of the tokenizer to, if anywhere.
+    #[cfg(feature = "trace")]
+    pub trace: Option<Trace<'a>>,
+}
+
+impl<'a> ParseState<'a> {
+    /// Create a new parse state.
+    fn new(bytes: &'a [u8], options: &'a ParseOptions) -> ParseState<'a> {
+        // Letters are common, so leave out those of GFM autolink literals
+        // when they are off, so that data does not stop at them.
+        let mut text_markers = TEXT_MARKERS
+            .iter()
+            .copied()
+            .filter(|d| {
+                options.constructs.gfm_autolink_literal || !matches!(d, b'H' | b'W' | b'h' | b'w')
+            })
+            .collect::<Vec<_>>();
+
+        for marker in custom_inline_markers(&options.custom_inlines) {
+            if !text_markers.contains(&marker) {
+                text_markers.push(marker);
+            }
+        }
+
+        ParseState {
+            options,
+            bytes,
+            location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
+                Some(Location::new(bytes))
+            } else {
+                None
+            },
+            definitions: BTreeSet::new(),
+            gfm_footnote_definitions: BTreeSet::new(),
+            abbreviations: vec![],
+            text_markers,
+            spare: RefCell::new(vec![]),
+            #[cfg(feature = "trace")]
+            trace: None,
+        }
+    }
+
+    /// Write a line to the trace, if tracing.
+    #[cfg(feature = "trace")]
+    pub fn trace(&self, args: fmt::Arguments) {
+        if let Some(trace) = &self.trace {
+            (trace.0)(args);
+        }
+    }
 }
 
 /// Turn a string of markdown into events.
@@ -34,46 +112,232 @@ pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
 ) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
-    let bytes = value.as_bytes();
-
-    let mut parse_state = ParseState {
-        options,
-        bytes,
-        location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
-            Some(Location::new(bytes))
-        } else {
-            None
-        },
-        definitions: vec![],
+    run(
+        ParseState::new(value.as_bytes(), options),
+        StateName::DocumentStart,
+        origin(),
+        value.len(),
+    )
+}
+
+/// Turn a string of markdown into events, reusing the memory of `spare`
+/// tokenizers.
+///
+/// Passes the bytes back so the compiler can access the source, and the
+/// memory that can be reused after it.
+pub fn parse_with_spare<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    spare: Vec<Spare>,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    let parse_state = ParseState::new(value.as_bytes(), options);
+    parse_state.spare.replace(spare);
+    run(parse_state, StateName::DocumentStart, origin(), value.len())
+}
+
+/// Turn a part of a string of markdown into events, from `from` to `to`.
+///
+/// `from` must be at the start of the document (on line 1), or at the start
+/// of a line where no containers are open.
+/// Its line is used for events, so it can be the line in a larger document
+/// that `value` is a part of.
+/// The definitions, GFM footnote definitions, and abbreviations in the rest
+/// of the document must be given, as they are needed to parse references.
+///
+/// Passes the bytes back so the compiler can access the source.
+pub fn parse_range<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    from: Point,
+    to: usize,
+    known: (&[String], &[String], &[String]),
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    let mut parse_state = ParseState::new(value.as_bytes(), options);
+    parse_state.definitions = known.0.iter().cloned().collect();
+    parse_state.gfm_footnote_definitions = known.1.iter().cloned().collect();
+    parse_state.abbreviations = known.2.to_vec();
+    let start = if from.line == 1 {
+        StateName::DocumentStart
+    } else {
+        StateName::DocumentResume
+    };
+    run(parse_state, start, from, to)
+}
+
+/// Turn a string of markdown into events, as if it was the content of a
+/// paragraph.
+///
+/// Only the text content type is used: there are no block constructs.
+/// Like in paragraphs, blank lines and a final line ending end the text
+/// before them.
+///
+/// Passes the bytes back so the compiler can access the source.
+pub fn parse_inline<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    let parse_state = ParseState::new(value.as_bytes(), options);
+    let mut events = vec![];
+    let mut point = origin();
+
+    for (start, end) in paragraphs(parse_state.bytes) {
+        if point.index < start {
+            point = run_inline(&parse_state, &mut events, point, start, false)?;
+        }
+
+        point = run_inline(&parse_state, &mut events, point, end, true)?;
+    }
+
+    if point.index < value.len() {
+        run_inline(&parse_state, &mut events, point, value.len(), false)?;
+    }
+
+    let result = Subresult {
+        done: false,
         gfm_footnote_definitions: vec![],
+        definitions: vec![],
+        abbreviations: vec![],
     };
 
-    let start = Point {
+    finish(parse_state, events, result)
+}
+
+/// Turn a string of markdown into events, writing a trace of the state
+/// machine to `trace`.
+///
+/// Passes the bytes back so the compiler can access the source.
+#[cfg(feature = "trace")]
+pub fn parse_with_trace<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    trace: &'a dyn Fn(fmt::Arguments),
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    let mut parse_state = ParseState::new(value.as_bytes(), options);
+    parse_state.trace = Some(Trace(trace));
+    run(parse_state, StateName::DocumentStart, origin(), value.len())
+}
+
+/// Get the point at the start of the input.
+fn origin() -> Point {
+    Point {
         line: 1,
         column: 1,
         index: 0,
         vs: 0,
+    }
+}
+
+/// Tokenize the document (or other content, starting at `start`) from `from`
+/// to `to`, and then subtokenize its content, until done.
+fn run(
+    parse_state: ParseState,
+    start: StateName,
+    from: Point,
+    to: usize,
+) -> Result<(Vec<Event>, ParseState), message::Message> {
+    let index = from.index;
+    let mut tokenizer = Tokenizer::new(from, &parse_state);
+    let state = tokenizer.push((index, 0), (to, 0), State::Next(start));
+    let result = tokenizer.flush(state, true)?;
+    let events = core::mem::take(&mut tokenizer.events);
+    drop(tokenizer);
+    finish(parse_state, events, result)
+}
+
+/// Tokenize a paragraph (when `text`) or the blank lines and line endings
+/// between paragraphs, from `from` to `to`, adding their events to `events`.
+///
+/// Returns the point at `to`.
+fn run_inline(
+    parse_state: &ParseState,
+    events: &mut Vec<Event>,
+    from: Point,
+    to: usize,
+    text: bool,
+) -> Result<Point, message::Message> {
+    let index = from.index;
+    let mut tokenizer = Tokenizer::new(from.clone(), parse_state);
+    // Text is otherwise fed by a parent, which defines where each of its lines
+    // start; without that, a line ending in a reverted attempt can skip over
+    // the next line.
+    tokenizer.define_skip(from);
+    let start = if text {
+        StateName::TextStart
+    } else {
+        StateName::DataStart
     };
-    let mut tokenizer = Tokenizer::new(start, &parse_state);
+    let state = tokenizer.push((index, 0), (to, 0), State::Next(start));
+    tokenizer.flush(state, true)?;
+
+    // Between paragraphs, there is only whitespace and line endings.
+    if !text {
+        for event in &mut tokenizer.events {
+            if event.name == Name::Data {
+                event.name = Name::SpaceOrTab;
+            }
+        }
+    }
+
+    events.append(&mut tokenizer.events);
+    Ok(tokenizer.point.clone())
+}
+
+/// Get where paragraphs are in `bytes`: runs of lines that are not blank,
+/// without their final line ending.
+fn paragraphs(bytes: &[u8]) -> Vec<(usize, usize)> {
+    let mut result: Vec<(usize, usize)> = vec![];
+    let mut start = 0;
 
-    let state = tokenizer.push(
-        (0, 0),
-        (parse_state.bytes.len(), 0),
-        State::Next(StateName::DocumentStart),
-    );
-    let mut result = tokenizer.flush(state, true)?;
-    let mut events = tokenizer.events;
+    while start < bytes.len() {
+        let end = bytes[start..]
+            .iter()
+            .position(|d| matches!(d, b'\n' | b'\r'))
+            .map_or(bytes.len(), |d| start + d);
+
+        if bytes[start..end].iter().any(|d| !matches!(d, b'\t' | b' ')) {
+            match result.last_mut() {
+                // Continue the paragraph on the previous line.
+                Some(last) if last.1 + line_ending_size(bytes, last.1) == start => {
+                    last.1 = end;
+                }
+                _ => result.push((start, end)),
+            }
+        }
+
+        start = end + line_ending_size(bytes, end);
+    }
+
+    result
+}
+
+/// Get the size of the line ending at `index` in `bytes`.
+fn line_ending_size(bytes: &[u8], index: usize) -> usize {
+    match bytes.get(index) {
+        Some(b'\r') if bytes.get(index + 1) == Some(&b'\n') => 2,
+        Some(b'\n' | b'\r') => 1,
+        _ => 0,
+    }
+}
 
+/// Subtokenize `events`, until done.
+fn finish(
+    mut parse_state: ParseState,
+    mut events: Vec<Event>,
+    mut result: Subresult,
+) -> Result<(Vec<Event>, ParseState), message::Message> {
     loop {
         let fn_defs = &mut parse_state.gfm_footnote_definitions;
         let defs = &mut parse_state.definitions;
-        fn_defs.append(&mut result.gfm_footnote_definitions);
-        defs.append(&mut result.definitions);
+        let abbrs = &mut parse_state.abbreviations;
+        fn_defs.extend(result.gfm_footnote_definitions.drain(..));
+        defs.extend(result.definitions.drain(..));
+        abbrs.append(&mut result.abbreviations);
 
         if result.done {
+            debug_assert_eq!(validate_events(&events), Ok(()), "expected valid events");
             return Ok((events, parse_state));
         }
 
-        result = subtokenize(&mut events, &parse_state, &None)?;
+        result = subtokenize(&mut events, &parse_state, None)?;
     }
 }
@@ -0,0 +1,187 @@
+//! Pull parser: walk through the events of a document as an iterator.
+//!
+//! Events are not compiled to HTML or a syntax tree, but handed out one at a
+//! time, with the markdown they span and where that markdown is.
+//! Custom renderers and filters can be built on top of that.
+//!
+//! Markdown cannot be tokenized lazily: whether `*` starts emphasis, or
+//! whether `[a]` is a link, depends on things that come later in the
+//! document.
+//! So the document is parsed when the parser is made, and only the items are
+//! made lazily, when they are pulled.
+
+use crate::event::{Event, Kind, Name};
+use crate::message;
+use crate::parser::parse;
+use crate::unist::Position;
+use crate::ParseOptions;
+use alloc::{vec, vec::Vec};
+
+/// Something that happens in a document.
+#[derive(Clone, Debug)]
+pub struct PullEvent<'a> {
+    /// Whether a span starts ([`Kind::Enter`][]) or ends ([`Kind::Exit`][]).
+    pub kind: Kind,
+    /// What the span is.
+    pub name: Name,
+    /// Markdown spanned by this span.
+    ///
+    /// This is the same for the enter and the exit of a span.
+    pub value: &'a str,
+    /// Place of this span in the document.
+    ///
+    /// This is the same for the enter and the exit of a span.
+    pub position: Position,
+}
+
+/// Parsed document, whose events can be pulled.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::{Kind, Name}, ParseOptions, Parser};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let parser = Parser::new("*a* b", &ParseOptions::default())?;
+/// let emphasis = parser
+///     .iter()
+///     .filter(|d| d.kind == Kind::Enter && d.name == Name::Emphasis)
+///     .map(|d| d.value)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(emphasis, vec!["*a*"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Parser<'a> {
+    /// Markdown.
+    value: &'a str,
+    /// Events.
+    events: Vec<Event>,
+    /// Index of the other event of the span of each event.
+    partners: Vec<usize>,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse `value`.
+    ///
+    /// ## Errors
+    ///
+    /// `Parser::new()` never errors with normal markdown because markdown
+    /// does not have syntax errors, so feel free to `unwrap()`.
+    /// However, MDX does have syntax errors.
+    pub fn new(value: &'a str, options: &ParseOptions) -> Result<Self, message::Message> {
+        let (events, _) = parse(value, options)?;
+        let mut partners = vec![0; events.len()];
+        let mut stack = vec![];
+        let mut index = 0;
+
+        while index < events.len() {
+            if events[index].kind == Kind::Enter {
+                stack.push(index);
+            } else {
+                let enter = stack.pop().expect("expected enter on stack");
+                partners[enter] = index;
+                partners[index] = enter;
+            }
+
+            index += 1;
+        }
+
+        Ok(Parser {
+            value,
+            events,
+            partners,
+        })
+    }
+
+    /// Get an iterator over the events.
+    pub fn iter(&self) -> EventIter<'_> {
+        EventIter {
+            value: self.value,
+            events: &self.events,
+            partners: &self.partners,
+            index: 0,
+            back: self.events.len(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Parser<'a> {
+    type Item = PullEvent<'a>;
+    type IntoIter = EventIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the events of a [`Parser`][].
+#[derive(Clone, Debug)]
+pub struct EventIter<'a> {
+    /// Markdown.
+    value: &'a str,
+    /// Events.
+    events: &'a [Event],
+    /// Index of the other event of the span of each event.
+    partners: &'a [usize],
+    /// Index of the next event from the front.
+    index: usize,
+    /// Index after the next event from the back.
+    back: usize,
+}
+
+impl<'a> EventIter<'a> {
+    /// Make the item for the event at `index`.
+    fn item(&self, index: usize) -> PullEvent<'a> {
+        let event = &self.events[index];
+        let (enter, exit) = if event.kind == Kind::Enter {
+            (event, &self.events[self.partners[index]])
+        } else {
+            (&self.events[self.partners[index]], event)
+        };
+
+        PullEvent {
+            kind: event.kind.clone(),
+            name: event.name.clone(),
+            value: &self.value[enter.point.index..exit.point.index],
+            position: Position {
+                start: enter.point.to_unist(),
+                end: exit.point.to_unist(),
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for EventIter<'a> {
+    type Item = PullEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.back {
+            let item = self.item(self.index);
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.back - self.index;
+        (size, Some(size))
+    }
+}
+
+impl DoubleEndedIterator for EventIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.back {
+            self.back -= 1;
+            Some(self.item(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for EventIter<'_> {}
@@ -21,6 +21,30 @@ pub enum Name {
     /// and what occurs before and after each sequence.
     /// Otherwise they are turned into data.
     Attention,
+    /// Resolve spoilers.
+    ///
+    /// Spoiler sequences are parsed and finally matched together to form
+    /// spoilers.
+    /// Otherwise they are turned into data.
+    Spoiler,
+    /// Resolve critic markup.
+    ///
+    /// Critic markup sequences are parsed and finally matched together to form
+    /// additions, deletions, substitutions, highlights, and comments.
+    /// Otherwise they are turned into data.
+    CriticMarkup,
+    /// Resolve custom inline constructs.
+    ///
+    /// Custom inline sequences are parsed and finally matched together to
+    /// form the registered constructs.
+    /// Otherwise they are turned into data.
+    CustomInline,
+    /// Resolve attribute lists (text) without colon.
+    ///
+    /// Whether they apply to a span is only known after labels and attention
+    /// are resolved.
+    /// Otherwise they are turned into data.
+    AttributeList,
     /// Resolve GFM tables.
     ///
     /// The table head, and later each row, are all parsed separately.
@@ -67,6 +91,10 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> Result<Option<Subresult>,
     let result = match name {
         Name::Label => construct::label_end::resolve(tokenizer),
         Name::Attention => construct::attention::resolve(tokenizer),
+        Name::Spoiler => construct::spoiler::resolve(tokenizer),
+        Name::CriticMarkup => construct::critic_markup::resolve(tokenizer),
+        Name::CustomInline => construct::custom_inline::resolve(tokenizer),
+        Name::AttributeList => construct::attribute_list::resolve(tokenizer),
         Name::GfmTable => construct::gfm_table::resolve(tokenizer),
         Name::HeadingAtx => construct::heading_atx::resolve(tokenizer),
         Name::HeadingSetext => construct::heading_setext::resolve(tokenizer),
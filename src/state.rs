@@ -43,14 +43,47 @@ impl State {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum Name {
+    AbbreviationDefinitionStart,
+    AbbreviationDefinitionBefore,
+    AbbreviationDefinitionLabelOpen,
+    AbbreviationDefinitionLabelBefore,
+    AbbreviationDefinitionLabelInside,
+    AbbreviationDefinitionLabelAfter,
+    AbbreviationDefinitionMarkerAfter,
+    AbbreviationDefinitionTitleBefore,
+    AbbreviationDefinitionTitleInside,
+    AbbreviationDefinitionTitleWhitespace,
+    AbbreviationDefinitionTitleEnd,
+    AbbreviationDefinitionAfter,
     AttentionStart,
     AttentionInside,
 
+    AttributeListFlowStart,
+    AttributeListTextStart,
+    AttributeListBracketedSpanStart,
+    AttributeListDirectiveStart,
+    AttributeListFencedDivStart,
+    AttributeListStart,
+    AttributeListOpen,
+    AttributeListBetween,
+    AttributeListAfter,
+    AttributeListShorthandBefore,
+    AttributeListShorthandInside,
+    AttributeListPropertyName,
+    AttributeListPropertyValueBefore,
+    AttributeListPropertyValueQuotedStart,
+    AttributeListPropertyValueQuoted,
+    AttributeListPropertyValueUnquoted,
+    AttributeListFlowAfter,
+    AttributeListFlowEnd,
+
     AutolinkStart,
     AutolinkOpen,
     AutolinkSchemeOrEmailAtext,
     AutolinkSchemeInsideOrEmailAtext,
     AutolinkUrlInside,
+    AutolinkUrlLabelBefore,
+    AutolinkUrlLabelInside,
     AutolinkEmailAtSignOrDot,
     AutolinkEmailAtext,
     AutolinkEmailValue,
@@ -59,11 +92,26 @@ pub enum Name {
     BlankLineStart,
     BlankLineAfter,
 
+    BlockIdStart,
+    BlockIdValueBefore,
+    BlockIdValueInside,
+    BlockIdAfter,
+
     BlockQuoteStart,
     BlockQuoteContStart,
     BlockQuoteContBefore,
     BlockQuoteContAfter,
 
+    BlockQuoteFencedStart,
+    BlockQuoteFencedStartAfter,
+    BlockQuoteFencedContStart,
+    BlockQuoteFencedContClose,
+    BlockQuoteFencedContAfter,
+    BlockQuoteFencedFenceStart,
+    BlockQuoteFencedFenceBefore,
+    BlockQuoteFencedFenceSequence,
+    BlockQuoteFencedFenceAfter,
+
     BomStart,
     BomInside,
 
@@ -75,6 +123,31 @@ pub enum Name {
     CharacterReferenceNumeric,
     CharacterReferenceValue,
 
+    CitationStart,
+    CitationItemBefore,
+    CitationItemStart,
+    CitationSuppressAuthor,
+    CitationSuppressAuthorAfter,
+    CitationPrefixStart,
+    CitationPrefixInside,
+    CitationKeyMarker,
+    CitationKeyStart,
+    CitationKeyInside,
+    CitationKeyPunctuation,
+    CitationKeyEnd,
+    CitationKeyAfter,
+    CitationLocatorBefore,
+    CitationLocatorStart,
+    CitationLocatorInside,
+    CitationItemEnd,
+    CitationAfter,
+    CitationBareLocatorBefore,
+    CitationBareLocatorOpen,
+    CitationBareLocatorStart,
+    CitationBareLocatorInside,
+    CitationBareLocatorAfter,
+    CitationBareEnd,
+
     CodeIndentedStart,
     CodeIndentedAtBreak,
     CodeIndentedAfter,
@@ -83,6 +156,27 @@ pub enum Name {
     CodeIndentedFurtherBegin,
     CodeIndentedFurtherAfter,
 
+    CriticMarkupStart,
+    CriticMarkupOpen,
+    CriticMarkupOpenInside,
+    CriticMarkupClose,
+    CriticMarkupCloseInside,
+
+    CustomBlockStart,
+    CustomBlockOpenBefore,
+    CustomBlockStartAfter,
+    CustomBlockContStart,
+    CustomBlockContBefore,
+    CustomBlockContClose,
+    CustomBlockContAfter,
+    CustomBlockMarkerStart,
+    CustomBlockMarkerInside,
+    CustomBlockFenceAfter,
+    CustomBlockFenceInfo,
+
+    CustomInlineStart,
+    CustomInlineInside,
+
     ContentChunkStart,
     ContentChunkInside,
     ContentDefinitionBefore,
@@ -114,14 +208,61 @@ pub enum Name {
     DestinationRaw,
     DestinationRawEscape,
 
+    DirectiveContainerStart,
+    DirectiveContainerStartAfter,
+    DirectiveContainerAfterName,
+    DirectiveContainerAfterLabel,
+    DirectiveContainerAfterAttributes,
+    DirectiveContainerOpenEnd,
+    DirectiveContainerNok,
+    DirectiveContainerContStart,
+    DirectiveContainerContClose,
+    DirectiveContainerContAfter,
+    DirectiveContainerCloseStart,
+    DirectiveContainerCloseSequenceAfter,
+    DirectiveContainerCloseEnd,
+    DirectiveContainerSequenceStart,
+    DirectiveContainerSequenceBefore,
+    DirectiveContainerSequenceInside,
+
+    DirectiveLeafStart,
+    DirectiveLeafBefore,
+    DirectiveLeafMarker,
+    DirectiveLeafAfterName,
+    DirectiveLeafAfterLabel,
+    DirectiveLeafAfterAttributes,
+    DirectiveLeafEnd,
+    DirectiveLeafNok,
+
+    DirectiveTextStart,
+    DirectiveTextAfterName,
+    DirectiveTextAfterLabel,
+    DirectiveTextAfter,
+    DirectiveTextNok,
+
+    DirectiveNameStart,
+    DirectiveNameInside,
+    DirectiveLabelStart,
+    DirectiveLabelBefore,
+    DirectiveLabelAtBreak,
+    DirectiveLabelEolAfter,
+    DirectiveLabelNok,
+    DirectiveLabelInside,
+    DirectiveLabelEscape,
+
     DocumentStart,
+    DocumentResume,
     DocumentBeforeFrontmatter,
     DocumentContainerExistingBefore,
     DocumentContainerExistingAfter,
     DocumentContainerNewBefore,
+    DocumentContainerNewBeforeNotCustomBlock,
+    DocumentContainerNewBeforeNotBlockQuoteFenced,
     DocumentContainerNewBeforeNotBlockQuote,
     DocumentContainerNewBeforeNotList,
     DocumentContainerNewBeforeNotGfmFootnoteDefinition,
+    DocumentContainerNewBeforeNotDirectiveContainer,
+    DocumentContainerNewBeforeNotFencedDiv,
     DocumentContainerNewAfter,
     DocumentContainersAfter,
     DocumentFlowInside,
@@ -132,16 +273,38 @@ pub enum Name {
     FlowBeforeCodeIndented,
     FlowBeforeRaw,
     FlowBeforeHtml,
+    FlowBeforeAttributeList,
     FlowBeforeMdxExpression,
     FlowBeforeMdxJsx,
     FlowBeforeHeadingAtx,
     FlowBeforeHeadingSetext,
     FlowBeforeThematicBreak,
+    FlowBeforeAbbreviationDefinition,
+    FlowBeforeDirectiveLeaf,
     FlowAfter,
     FlowBlankLineBefore,
     FlowBlankLineAfter,
     FlowBeforeContent,
 
+    FencedDivStart,
+    FencedDivStartAfter,
+    FencedDivAttributesBefore,
+    FencedDivClassInside,
+    FencedDivAttributesAfter,
+    FencedDivTrailerBefore,
+    FencedDivTrailerInside,
+    FencedDivOpenEnd,
+    FencedDivNok,
+    FencedDivContStart,
+    FencedDivContClose,
+    FencedDivContAfter,
+    FencedDivCloseStart,
+    FencedDivCloseSequenceAfter,
+    FencedDivCloseEnd,
+    FencedDivSequenceStart,
+    FencedDivSequenceBefore,
+    FencedDivSequenceInside,
+
     FrontmatterStart,
     FrontmatterOpenSequence,
     FrontmatterOpenAfter,
@@ -190,6 +353,11 @@ pub enum Name {
     GfmLabelStartFootnoteStart,
     GfmLabelStartFootnoteOpen,
 
+    GfmAlertStart,
+    GfmAlertOpen,
+    GfmAlertInside,
+    GfmAlertAfter,
+
     GfmTaskListItemCheckStart,
     GfmTaskListItemCheckInside,
     GfmTaskListItemCheckClose,
@@ -202,6 +370,7 @@ pub enum Name {
     GfmTableHeadRowBreak,
     GfmTableHeadRowData,
     GfmTableHeadRowEscape,
+    GfmTableHeadlessBefore,
     GfmTableHeadDelimiterStart,
     GfmTableHeadDelimiterBefore,
     GfmTableHeadDelimiterCellBefore,
@@ -216,6 +385,10 @@ pub enum Name {
     GfmTableBodyRowBreak,
     GfmTableBodyRowData,
     GfmTableBodyRowEscape,
+    GfmTableBodyRowBackslash,
+    GfmTableBodyRowContinuationBefore,
+    GfmTableBodyRowContinuationAfter,
+    GfmTableBodyRowContinuation,
 
     HardBreakEscapeStart,
     HardBreakEscapeAfter,
@@ -255,6 +428,7 @@ pub enum Name {
     HtmlFlowContinuation,
     HtmlFlowContinuationDeclarationInside,
     HtmlFlowContinuationAfter,
+    HtmlFlowMarkdownAttribute,
     HtmlFlowContinuationStart,
     HtmlFlowContinuationBefore,
     HtmlFlowContinuationCommentInside,
@@ -303,6 +477,8 @@ pub enum Name {
 
     LabelEndStart,
     LabelEndAfter,
+    LabelEndNotBracketedSpan,
+    LabelEndBracketedSpanAfter,
     LabelEndResourceStart,
     LabelEndResourceBefore,
     LabelEndResourceOpen,
@@ -401,6 +577,12 @@ pub enum Name {
     MdxJsxAttributeValueQuoted,
     MdxJsxAttributeValueExpressionAfter,
 
+    MentionStart,
+    MentionNameStart,
+    MentionNameInside,
+    MentionNamePunctuation,
+    MentionNameEnd,
+
     NonLazyContinuationStart,
     NonLazyContinuationAfter,
 
@@ -431,6 +613,13 @@ pub enum Name {
     RawTextBetween,
     RawTextData,
     RawTextSequenceClose,
+    RawTextSequenceCloseAfter,
+
+    RubyStart,
+    RubyBaseBefore,
+    RubyBaseInside,
+    RubyTextBefore,
+    RubyTextInside,
 
     SpaceOrTabStart,
     SpaceOrTabInside,
@@ -442,16 +631,31 @@ pub enum Name {
     SpaceOrTabEolAtEol,
     SpaceOrTabEolAfterMore,
 
+    SpoilerStart,
+    SpoilerInside,
+
     StringStart,
     StringBefore,
     StringBeforeData,
 
     TextStart,
     TextBefore,
+    TextBeforeBuiltin,
+    TextBeforeGfmTaskListItemCheck,
+    TextBeforeAutolink,
     TextBeforeHtml,
     TextBeforeMdxJsx,
     TextBeforeHardBreakEscape,
+    TextBeforeMention,
+    TextBeforeWikiLink,
+    TextBeforeGfmLabelStartFootnote,
     TextBeforeLabelStartLink,
+    TextBeforeRuby,
+    TextBeforeAttributeList,
+    TextBeforeMdxExpression,
+    TextBeforeLabelStartImage,
+    TextBeforeSpoiler,
+    TextBeforeAttention,
     TextBeforeData,
 
     ThematicBreakStart,
@@ -466,20 +670,70 @@ pub enum Name {
     TitleEscape,
     TitleInside,
     TitleNok,
+
+    WikiLinkStart,
+    WikiLinkEmbedOpen,
+    WikiLinkOpen,
+    WikiLinkTargetBefore,
+    WikiLinkTargetInside,
+    WikiLinkFragmentStart,
+    WikiLinkFragmentInside,
+    WikiLinkTargetAfter,
+    WikiLinkAliasBefore,
+    WikiLinkAliasInside,
+    WikiLinkCloseStart,
+    WikiLinkCloseInside,
 }
 
 #[allow(clippy::too_many_lines)]
 /// Call the corresponding state for a state name.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
     let func = match name {
+        Name::AbbreviationDefinitionStart => construct::abbreviation::start,
+        Name::AbbreviationDefinitionBefore => construct::abbreviation::before,
+        Name::AbbreviationDefinitionLabelOpen => construct::abbreviation::label_open,
+        Name::AbbreviationDefinitionLabelBefore => construct::abbreviation::label_before,
+        Name::AbbreviationDefinitionLabelInside => construct::abbreviation::label_inside,
+        Name::AbbreviationDefinitionLabelAfter => construct::abbreviation::label_after,
+        Name::AbbreviationDefinitionMarkerAfter => construct::abbreviation::marker_after,
+        Name::AbbreviationDefinitionTitleBefore => construct::abbreviation::title_before,
+        Name::AbbreviationDefinitionTitleInside => construct::abbreviation::title_inside,
+        Name::AbbreviationDefinitionTitleWhitespace => construct::abbreviation::title_whitespace,
+        Name::AbbreviationDefinitionTitleEnd => construct::abbreviation::title_end,
+        Name::AbbreviationDefinitionAfter => construct::abbreviation::after,
         Name::AttentionStart => construct::attention::start,
         Name::AttentionInside => construct::attention::inside,
 
+        Name::AttributeListFlowStart => construct::attribute_list::flow_start,
+        Name::AttributeListTextStart => construct::attribute_list::text_start,
+        Name::AttributeListBracketedSpanStart => construct::attribute_list::bracketed_span_start,
+        Name::AttributeListDirectiveStart => construct::attribute_list::directive_start,
+        Name::AttributeListFencedDivStart => construct::attribute_list::fenced_div_start,
+        Name::AttributeListStart => construct::attribute_list::start,
+        Name::AttributeListOpen => construct::attribute_list::open,
+        Name::AttributeListBetween => construct::attribute_list::between,
+        Name::AttributeListAfter => construct::attribute_list::after,
+        Name::AttributeListShorthandBefore => construct::attribute_list::shorthand_before,
+        Name::AttributeListShorthandInside => construct::attribute_list::shorthand_inside,
+        Name::AttributeListPropertyName => construct::attribute_list::property_name,
+        Name::AttributeListPropertyValueBefore => construct::attribute_list::property_value_before,
+        Name::AttributeListPropertyValueQuotedStart => {
+            construct::attribute_list::property_value_quoted_start
+        }
+        Name::AttributeListPropertyValueQuoted => construct::attribute_list::property_value_quoted,
+        Name::AttributeListPropertyValueUnquoted => {
+            construct::attribute_list::property_value_unquoted
+        }
+        Name::AttributeListFlowAfter => construct::attribute_list::flow_after,
+        Name::AttributeListFlowEnd => construct::attribute_list::flow_end,
+
         Name::AutolinkStart => construct::autolink::start,
         Name::AutolinkOpen => construct::autolink::open,
         Name::AutolinkSchemeOrEmailAtext => construct::autolink::scheme_or_email_atext,
         Name::AutolinkSchemeInsideOrEmailAtext => construct::autolink::scheme_inside_or_email_atext,
         Name::AutolinkUrlInside => construct::autolink::url_inside,
+        Name::AutolinkUrlLabelBefore => construct::autolink::url_label_before,
+        Name::AutolinkUrlLabelInside => construct::autolink::url_label_inside,
         Name::AutolinkEmailAtSignOrDot => construct::autolink::email_at_sign_or_dot,
         Name::AutolinkEmailAtext => construct::autolink::email_atext,
         Name::AutolinkEmailValue => construct::autolink::email_value,
@@ -488,11 +742,26 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::BlankLineStart => construct::blank_line::start,
         Name::BlankLineAfter => construct::blank_line::after,
 
+        Name::BlockIdStart => construct::block_id::start,
+        Name::BlockIdValueBefore => construct::block_id::value_before,
+        Name::BlockIdValueInside => construct::block_id::value_inside,
+        Name::BlockIdAfter => construct::block_id::after,
+
         Name::BlockQuoteStart => construct::block_quote::start,
         Name::BlockQuoteContStart => construct::block_quote::cont_start,
         Name::BlockQuoteContBefore => construct::block_quote::cont_before,
         Name::BlockQuoteContAfter => construct::block_quote::cont_after,
 
+        Name::BlockQuoteFencedStart => construct::block_quote_fenced::start,
+        Name::BlockQuoteFencedStartAfter => construct::block_quote_fenced::start_after,
+        Name::BlockQuoteFencedContStart => construct::block_quote_fenced::cont_start,
+        Name::BlockQuoteFencedContClose => construct::block_quote_fenced::cont_close,
+        Name::BlockQuoteFencedContAfter => construct::block_quote_fenced::cont_after,
+        Name::BlockQuoteFencedFenceStart => construct::block_quote_fenced::fence_start,
+        Name::BlockQuoteFencedFenceBefore => construct::block_quote_fenced::fence_before,
+        Name::BlockQuoteFencedFenceSequence => construct::block_quote_fenced::fence_sequence,
+        Name::BlockQuoteFencedFenceAfter => construct::block_quote_fenced::fence_after,
+
         Name::BomStart => construct::partial_bom::start,
         Name::BomInside => construct::partial_bom::inside,
 
@@ -504,6 +773,31 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::CharacterReferenceNumeric => construct::character_reference::numeric,
         Name::CharacterReferenceValue => construct::character_reference::value,
 
+        Name::CitationStart => construct::citation::start,
+        Name::CitationItemBefore => construct::citation::item_before,
+        Name::CitationItemStart => construct::citation::item_start,
+        Name::CitationSuppressAuthor => construct::citation::suppress_author,
+        Name::CitationSuppressAuthorAfter => construct::citation::suppress_author_after,
+        Name::CitationPrefixStart => construct::citation::prefix_start,
+        Name::CitationPrefixInside => construct::citation::prefix_inside,
+        Name::CitationKeyMarker => construct::citation::key_marker,
+        Name::CitationKeyStart => construct::citation::key_start,
+        Name::CitationKeyInside => construct::citation::key_inside,
+        Name::CitationKeyPunctuation => construct::citation::key_punctuation,
+        Name::CitationKeyEnd => construct::citation::key_end,
+        Name::CitationKeyAfter => construct::citation::key_after,
+        Name::CitationLocatorBefore => construct::citation::locator_before,
+        Name::CitationLocatorStart => construct::citation::locator_start,
+        Name::CitationLocatorInside => construct::citation::locator_inside,
+        Name::CitationItemEnd => construct::citation::item_end,
+        Name::CitationAfter => construct::citation::after,
+        Name::CitationBareLocatorBefore => construct::citation::bare_locator_before,
+        Name::CitationBareLocatorOpen => construct::citation::bare_locator_open,
+        Name::CitationBareLocatorStart => construct::citation::bare_locator_start,
+        Name::CitationBareLocatorInside => construct::citation::bare_locator_inside,
+        Name::CitationBareLocatorAfter => construct::citation::bare_locator_after,
+        Name::CitationBareEnd => construct::citation::bare_end,
+
         Name::CodeIndentedStart => construct::code_indented::start,
         Name::CodeIndentedAtBreak => construct::code_indented::at_break,
         Name::CodeIndentedAfter => construct::code_indented::after,
@@ -512,6 +806,27 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::CodeIndentedFurtherBegin => construct::code_indented::further_begin,
         Name::CodeIndentedFurtherAfter => construct::code_indented::further_after,
 
+        Name::CriticMarkupStart => construct::critic_markup::start,
+        Name::CriticMarkupOpen => construct::critic_markup::open,
+        Name::CriticMarkupOpenInside => construct::critic_markup::open_inside,
+        Name::CriticMarkupClose => construct::critic_markup::close,
+        Name::CriticMarkupCloseInside => construct::critic_markup::close_inside,
+
+        Name::CustomBlockStart => construct::custom_block::start,
+        Name::CustomBlockOpenBefore => construct::custom_block::open_before,
+        Name::CustomBlockStartAfter => construct::custom_block::start_after,
+        Name::CustomBlockContStart => construct::custom_block::cont_start,
+        Name::CustomBlockContBefore => construct::custom_block::cont_before,
+        Name::CustomBlockContClose => construct::custom_block::cont_close,
+        Name::CustomBlockContAfter => construct::custom_block::cont_after,
+        Name::CustomBlockMarkerStart => construct::custom_block::marker_start,
+        Name::CustomBlockMarkerInside => construct::custom_block::marker_inside,
+        Name::CustomBlockFenceAfter => construct::custom_block::fence_after,
+        Name::CustomBlockFenceInfo => construct::custom_block::fence_info,
+
+        Name::CustomInlineStart => construct::custom_inline::start,
+        Name::CustomInlineInside => construct::custom_inline::inside,
+
         Name::ContentChunkStart => construct::content::chunk_start,
         Name::ContentChunkInside => construct::content::chunk_inside,
         Name::ContentDefinitionBefore => construct::content::definition_before,
@@ -545,11 +860,62 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DestinationRaw => construct::partial_destination::raw,
         Name::DestinationRawEscape => construct::partial_destination::raw_escape,
 
+        Name::DirectiveContainerStart => construct::directive_container::start,
+        Name::DirectiveContainerStartAfter => construct::directive_container::start_after,
+        Name::DirectiveContainerAfterName => construct::directive_container::after_name,
+        Name::DirectiveContainerAfterLabel => construct::directive_container::after_label,
+        Name::DirectiveContainerAfterAttributes => construct::directive_container::after_attributes,
+        Name::DirectiveContainerOpenEnd => construct::directive_container::open_end,
+        Name::DirectiveContainerNok => construct::directive_container::nok,
+        Name::DirectiveContainerContStart => construct::directive_container::cont_start,
+        Name::DirectiveContainerContClose => construct::directive_container::cont_close,
+        Name::DirectiveContainerContAfter => construct::directive_container::cont_after,
+        Name::DirectiveContainerCloseStart => construct::directive_container::close_start,
+        Name::DirectiveContainerCloseSequenceAfter => {
+            construct::directive_container::close_sequence_after
+        }
+        Name::DirectiveContainerCloseEnd => construct::directive_container::close_end,
+        Name::DirectiveContainerSequenceStart => construct::directive_container::sequence_start,
+        Name::DirectiveContainerSequenceBefore => construct::directive_container::sequence_before,
+        Name::DirectiveContainerSequenceInside => construct::directive_container::sequence_inside,
+
+        Name::DirectiveLeafStart => construct::directive_leaf::start,
+        Name::DirectiveLeafBefore => construct::directive_leaf::before,
+        Name::DirectiveLeafMarker => construct::directive_leaf::marker,
+        Name::DirectiveLeafAfterName => construct::directive_leaf::after_name,
+        Name::DirectiveLeafAfterLabel => construct::directive_leaf::after_label,
+        Name::DirectiveLeafAfterAttributes => construct::directive_leaf::after_attributes,
+        Name::DirectiveLeafEnd => construct::directive_leaf::end,
+        Name::DirectiveLeafNok => construct::directive_leaf::nok,
+
+        Name::DirectiveTextStart => construct::directive_text::start,
+        Name::DirectiveTextAfterName => construct::directive_text::after_name,
+        Name::DirectiveTextAfterLabel => construct::directive_text::after_label,
+        Name::DirectiveTextAfter => construct::directive_text::after,
+        Name::DirectiveTextNok => construct::directive_text::nok,
+
+        Name::DirectiveNameStart => construct::partial_directive::name_start,
+        Name::DirectiveNameInside => construct::partial_directive::name_inside,
+        Name::DirectiveLabelStart => construct::partial_directive::label_start,
+        Name::DirectiveLabelBefore => construct::partial_directive::label_before,
+        Name::DirectiveLabelAtBreak => construct::partial_directive::label_at_break,
+        Name::DirectiveLabelEolAfter => construct::partial_directive::label_eol_after,
+        Name::DirectiveLabelNok => construct::partial_directive::label_nok,
+        Name::DirectiveLabelInside => construct::partial_directive::label_inside,
+        Name::DirectiveLabelEscape => construct::partial_directive::label_escape,
+
         Name::DocumentStart => construct::document::start,
+        Name::DocumentResume => construct::document::resume,
         Name::DocumentBeforeFrontmatter => construct::document::before_frontmatter,
         Name::DocumentContainerExistingBefore => construct::document::container_existing_before,
         Name::DocumentContainerExistingAfter => construct::document::container_existing_after,
         Name::DocumentContainerNewBefore => construct::document::container_new_before,
+        Name::DocumentContainerNewBeforeNotCustomBlock => {
+            construct::document::container_new_before_not_custom_block
+        }
+        Name::DocumentContainerNewBeforeNotBlockQuoteFenced => {
+            construct::document::container_new_before_not_block_quote_fenced
+        }
         Name::DocumentContainerNewBeforeNotBlockQuote => {
             construct::document::container_new_before_not_block_quote
         }
@@ -559,6 +925,12 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentContainerNewBeforeNotGfmFootnoteDefinition => {
             construct::document::container_new_before_not_footnote_definition
         }
+        Name::DocumentContainerNewBeforeNotDirectiveContainer => {
+            construct::document::container_new_before_not_directive_container
+        }
+        Name::DocumentContainerNewBeforeNotFencedDiv => {
+            construct::document::container_new_before_not_fenced_div
+        }
         Name::DocumentContainerNewAfter => construct::document::container_new_after,
         Name::DocumentContainersAfter => construct::document::containers_after,
         Name::DocumentFlowEnd => construct::document::flow_end,
@@ -569,16 +941,37 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::FlowBeforeCodeIndented => construct::flow::before_code_indented,
         Name::FlowBeforeRaw => construct::flow::before_raw,
         Name::FlowBeforeHtml => construct::flow::before_html,
+        Name::FlowBeforeAttributeList => construct::flow::before_attribute_list,
         Name::FlowBeforeMdxExpression => construct::flow::before_mdx_expression,
         Name::FlowBeforeMdxJsx => construct::flow::before_mdx_jsx,
         Name::FlowBeforeHeadingAtx => construct::flow::before_heading_atx,
         Name::FlowBeforeHeadingSetext => construct::flow::before_heading_setext,
         Name::FlowBeforeThematicBreak => construct::flow::before_thematic_break,
+        Name::FlowBeforeAbbreviationDefinition => construct::flow::before_abbreviation_definition,
+        Name::FlowBeforeDirectiveLeaf => construct::flow::before_directive_leaf,
         Name::FlowAfter => construct::flow::after,
         Name::FlowBlankLineBefore => construct::flow::blank_line_before,
         Name::FlowBlankLineAfter => construct::flow::blank_line_after,
         Name::FlowBeforeContent => construct::flow::before_content,
 
+        Name::FencedDivStart => construct::fenced_div::start,
+        Name::FencedDivStartAfter => construct::fenced_div::start_after,
+        Name::FencedDivAttributesBefore => construct::fenced_div::attributes_before,
+        Name::FencedDivClassInside => construct::fenced_div::class_inside,
+        Name::FencedDivAttributesAfter => construct::fenced_div::attributes_after,
+        Name::FencedDivTrailerBefore => construct::fenced_div::trailer_before,
+        Name::FencedDivTrailerInside => construct::fenced_div::trailer_inside,
+        Name::FencedDivOpenEnd => construct::fenced_div::open_end,
+        Name::FencedDivNok => construct::fenced_div::nok,
+        Name::FencedDivContStart => construct::fenced_div::cont_start,
+        Name::FencedDivContClose => construct::fenced_div::cont_close,
+        Name::FencedDivContAfter => construct::fenced_div::cont_after,
+        Name::FencedDivCloseStart => construct::fenced_div::close_start,
+        Name::FencedDivCloseSequenceAfter => construct::fenced_div::close_sequence_after,
+        Name::FencedDivCloseEnd => construct::fenced_div::close_end,
+        Name::FencedDivSequenceStart => construct::fenced_div::sequence_start,
+        Name::FencedDivSequenceBefore => construct::fenced_div::sequence_before,
+        Name::FencedDivSequenceInside => construct::fenced_div::sequence_inside,
         Name::FrontmatterStart => construct::frontmatter::start,
         Name::FrontmatterOpenSequence => construct::frontmatter::open_sequence,
         Name::FrontmatterOpenAfter => construct::frontmatter::open_after,
@@ -649,6 +1042,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::GfmTableHeadRowBreak => construct::gfm_table::head_row_break,
         Name::GfmTableHeadRowData => construct::gfm_table::head_row_data,
         Name::GfmTableHeadRowEscape => construct::gfm_table::head_row_escape,
+        Name::GfmTableHeadlessBefore => construct::gfm_table::headless_before,
         Name::GfmTableHeadDelimiterStart => construct::gfm_table::head_delimiter_start,
         Name::GfmTableHeadDelimiterBefore => construct::gfm_table::head_delimiter_before,
         Name::GfmTableHeadDelimiterCellBefore => construct::gfm_table::head_delimiter_cell_before,
@@ -666,6 +1060,17 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::GfmTableBodyRowBreak => construct::gfm_table::body_row_break,
         Name::GfmTableBodyRowData => construct::gfm_table::body_row_data,
         Name::GfmTableBodyRowEscape => construct::gfm_table::body_row_escape,
+        Name::GfmTableBodyRowBackslash => construct::gfm_table::body_row_backslash,
+        Name::GfmTableBodyRowContinuationBefore => {
+            construct::gfm_table::body_row_continuation_before
+        }
+        Name::GfmTableBodyRowContinuationAfter => construct::gfm_table::body_row_continuation_after,
+        Name::GfmTableBodyRowContinuation => construct::gfm_table::body_row_continuation,
+
+        Name::GfmAlertStart => construct::gfm_alert::start,
+        Name::GfmAlertOpen => construct::gfm_alert::open,
+        Name::GfmAlertInside => construct::gfm_alert::inside,
+        Name::GfmAlertAfter => construct::gfm_alert::after,
 
         Name::GfmTaskListItemCheckStart => construct::gfm_task_list_item_check::start,
         Name::GfmTaskListItemCheckInside => construct::gfm_task_list_item_check::inside,
@@ -727,6 +1132,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
             construct::html_flow::continuation_declaration_inside
         }
         Name::HtmlFlowContinuationAfter => construct::html_flow::continuation_after,
+        Name::HtmlFlowMarkdownAttribute => construct::html_flow::markdown_attribute,
         Name::HtmlFlowContinuationStart => construct::html_flow::continuation_start,
         Name::HtmlFlowContinuationBefore => construct::html_flow::continuation_before,
         Name::HtmlFlowContinuationCommentInside => {
@@ -787,6 +1193,8 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
 
         Name::LabelEndStart => construct::label_end::start,
         Name::LabelEndAfter => construct::label_end::after,
+        Name::LabelEndNotBracketedSpan => construct::label_end::not_bracketed_span,
+        Name::LabelEndBracketedSpanAfter => construct::label_end::bracketed_span_after,
         Name::LabelEndResourceStart => construct::label_end::resource_start,
         Name::LabelEndResourceBefore => construct::label_end::resource_before,
         Name::LabelEndResourceOpen => construct::label_end::resource_open,
@@ -900,6 +1308,12 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::MdxJsxEsWhitespaceInside => construct::partial_mdx_jsx::es_whitespace_inside,
         Name::MdxJsxEsWhitespaceEolAfter => construct::partial_mdx_jsx::es_whitespace_eol_after,
 
+        Name::MentionStart => construct::mention::start,
+        Name::MentionNameStart => construct::mention::name_start,
+        Name::MentionNameInside => construct::mention::name_inside,
+        Name::MentionNamePunctuation => construct::mention::name_punctuation,
+        Name::MentionNameEnd => construct::mention::name_end,
+
         Name::NonLazyContinuationStart => construct::partial_non_lazy_continuation::start,
         Name::NonLazyContinuationAfter => construct::partial_non_lazy_continuation::after,
 
@@ -930,6 +1344,13 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::RawTextBetween => construct::raw_text::between,
         Name::RawTextData => construct::raw_text::data,
         Name::RawTextSequenceClose => construct::raw_text::sequence_close,
+        Name::RawTextSequenceCloseAfter => construct::raw_text::sequence_close_after,
+
+        Name::RubyStart => construct::ruby::start,
+        Name::RubyBaseBefore => construct::ruby::base_before,
+        Name::RubyBaseInside => construct::ruby::base_inside,
+        Name::RubyTextBefore => construct::ruby::text_before,
+        Name::RubyTextInside => construct::ruby::text_inside,
 
         Name::SpaceOrTabStart => construct::partial_space_or_tab::start,
         Name::SpaceOrTabInside => construct::partial_space_or_tab::inside,
@@ -941,16 +1362,31 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::SpaceOrTabEolAtEol => construct::partial_space_or_tab_eol::at_eol,
         Name::SpaceOrTabEolAfterMore => construct::partial_space_or_tab_eol::after_more,
 
+        Name::SpoilerStart => construct::spoiler::start,
+        Name::SpoilerInside => construct::spoiler::inside,
+
         Name::StringStart => construct::string::start,
         Name::StringBefore => construct::string::before,
         Name::StringBeforeData => construct::string::before_data,
 
         Name::TextStart => construct::text::start,
         Name::TextBefore => construct::text::before,
+        Name::TextBeforeBuiltin => construct::text::before_builtin,
+        Name::TextBeforeGfmTaskListItemCheck => construct::text::before_gfm_task_list_item_check,
+        Name::TextBeforeAutolink => construct::text::before_autolink,
         Name::TextBeforeHtml => construct::text::before_html,
         Name::TextBeforeMdxJsx => construct::text::before_mdx_jsx,
         Name::TextBeforeHardBreakEscape => construct::text::before_hard_break_escape,
+        Name::TextBeforeMention => construct::text::before_mention,
+        Name::TextBeforeWikiLink => construct::text::before_wiki_link,
+        Name::TextBeforeGfmLabelStartFootnote => construct::text::before_gfm_label_start_footnote,
         Name::TextBeforeLabelStartLink => construct::text::before_label_start_link,
+        Name::TextBeforeRuby => construct::text::before_ruby,
+        Name::TextBeforeAttributeList => construct::text::before_attribute_list,
+        Name::TextBeforeMdxExpression => construct::text::before_mdx_expression,
+        Name::TextBeforeLabelStartImage => construct::text::before_label_start_image,
+        Name::TextBeforeSpoiler => construct::text::before_spoiler,
+        Name::TextBeforeAttention => construct::text::before_attention,
         Name::TextBeforeData => construct::text::before_data,
 
         Name::ThematicBreakStart => construct::thematic_break::start,
@@ -965,6 +1401,19 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::TitleEscape => construct::partial_title::escape,
         Name::TitleInside => construct::partial_title::inside,
         Name::TitleNok => construct::partial_title::nok,
+
+        Name::WikiLinkStart => construct::wiki_link::start,
+        Name::WikiLinkEmbedOpen => construct::wiki_link::embed_open,
+        Name::WikiLinkOpen => construct::wiki_link::open,
+        Name::WikiLinkTargetBefore => construct::wiki_link::target_before,
+        Name::WikiLinkTargetInside => construct::wiki_link::target_inside,
+        Name::WikiLinkFragmentStart => construct::wiki_link::fragment_start,
+        Name::WikiLinkFragmentInside => construct::wiki_link::fragment_inside,
+        Name::WikiLinkTargetAfter => construct::wiki_link::target_after,
+        Name::WikiLinkAliasBefore => construct::wiki_link::alias_before,
+        Name::WikiLinkAliasInside => construct::wiki_link::alias_inside,
+        Name::WikiLinkCloseStart => construct::wiki_link::close_start,
+        Name::WikiLinkCloseInside => construct::wiki_link::close_inside,
     };
 
     func(tokenizer)
@@ -0,0 +1,301 @@
+//! Streaming: parse markdown from a reader, a few blocks at a time.
+//!
+//! Blocks that are separated by blank lines, outside of containers, are
+//! done when the next one starts: nothing after them can change them.
+//! So markdown is read until such a block starts, the blocks before it are
+//! handed out, and only the rest is kept, to be parsed again with more
+//! markdown.
+
+use crate::event::{Event, Kind, Name, Point};
+use crate::incremental::boundaries;
+use crate::message;
+use crate::parser::parse_range;
+use crate::util::{
+    normalize_identifier::normalize_identifier,
+    slice::{Position, Slice},
+};
+use crate::ParseOptions;
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// Size of the reads from the reader.
+const READ_SIZE: usize = 8 * 1024;
+
+/// Blocks parsed by a [`Stream`][].
+#[derive(Clone, Debug)]
+pub struct Chunk {
+    /// Markdown of the blocks, and the blank lines after them.
+    pub value: String,
+    /// Byte offset of `value` in the input.
+    pub offset: usize,
+    /// Events of the blocks.
+    ///
+    /// Their places are in the input, so subtract `offset` from their
+    /// indices to get the markdown they span in `value`.
+    pub events: Vec<Event>,
+}
+
+/// Parser of markdown from a reader, which hands out blocks as they are
+/// read.
+///
+/// This is useful for very large documents, such as logs, that should not
+/// be read into memory at once.
+/// Only the markdown of the block that is being read is kept, and parsed
+/// again when more markdown is read.
+/// Once that is larger than a read, it is only parsed again when it has
+/// doubled, so large blocks (such as a list with everything in it, or a log
+/// without blank lines) take about twice as long as when parsed at once.
+///
+/// References are only found when their definitions come before them, or in
+/// the same chunk.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::{Kind, Name}, ParseOptions, Stream};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = ParseOptions::default();
+/// let stream = Stream::new("# a\n\n## b\n".as_bytes(), &options);
+/// let mut headings = 0;
+///
+/// for chunk in stream {
+///     let chunk = chunk?;
+///     headings += chunk.events.iter().filter(|d| d.kind == Kind::Enter && d.name == Name::HeadingAtx).count();
+/// }
+///
+/// assert_eq!(headings, 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Stream<'a, R: std::io::Read> {
+    /// Reader.
+    reader: R,
+    /// Configuration.
+    options: &'a ParseOptions,
+    /// Markdown that is read but not handed out.
+    buffer: Vec<u8>,
+    /// Byte offset of `buffer` in the input.
+    offset: usize,
+    /// Size of `buffer` when it was parsed without blocks that are done.
+    parsed: usize,
+    /// Line of `buffer` in the input.
+    line: usize,
+    /// Whether the reader is done.
+    eof: bool,
+    /// Whether everything is handed out.
+    done: bool,
+    /// Defined definition identifiers.
+    definitions: Vec<String>,
+    /// Defined GFM footnote definition identifiers.
+    gfm_footnote_definitions: Vec<String>,
+    /// Defined abbreviation labels.
+    abbreviations: Vec<String>,
+}
+
+impl<'a, R: std::io::Read> Stream<'a, R> {
+    /// Create a parser of markdown from `reader`.
+    pub fn new(reader: R, options: &'a ParseOptions) -> Self {
+        Stream {
+            reader,
+            options,
+            buffer: vec![],
+            offset: 0,
+            parsed: 0,
+            line: 1,
+            eof: false,
+            done: false,
+            definitions: vec![],
+            gfm_footnote_definitions: vec![],
+            abbreviations: vec![],
+        }
+    }
+
+    /// Read more markdown into the buffer.
+    fn read(&mut self) -> Result<(), message::Message> {
+        let mut bytes = [0; READ_SIZE];
+
+        loop {
+            match self.reader.read(&mut bytes) {
+                Ok(size) => {
+                    self.buffer.extend_from_slice(&bytes[..size]);
+                    self.eof = size == 0;
+                    return Ok(());
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    return Err(message::Message {
+                        place: None,
+                        reason: format!("Cannot read markdown: {}", error),
+                        rule_id: Box::new("read".into()),
+                        source: Box::new("markdown-rs".into()),
+                        fix: None,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Parse the buffer, and take the blocks that are done.
+    ///
+    /// Returns `None` if no blocks are done yet.
+    fn take(&mut self) -> Result<Option<Chunk>, message::Message> {
+        // When the buffer is larger than a read, only parse it again when it
+        // has doubled, so that a block that does not end (such as a log
+        // without blank lines) is not parsed again for each read.
+        if self.buffer.is_empty()
+            || (!self.eof && self.parsed > READ_SIZE && self.buffer.len() < self.parsed * 2)
+        {
+            return Ok(None);
+        }
+
+        self.parsed = self.buffer.len();
+
+        // Parse up to the last line ending, as the line after it could
+        // continue blocks.
+        // Only line feeds are used, as a carriage return at the end could be
+        // followed by a line feed.
+        let end = if self.eof {
+            self.buffer.len()
+        } else {
+            match self.buffer.iter().rposition(|d| *d == b'\n') {
+                Some(index) => index + 1,
+                None => return Ok(None),
+            }
+        };
+
+        let value =
+            core::str::from_utf8(&self.buffer[..end]).map_err(|error| message::Message {
+                place: None,
+                reason: format!(
+                    "Cannot read markdown: invalid UTF-8 at offset {}",
+                    self.offset + error.valid_up_to()
+                ),
+                rule_id: Box::new("read".into()),
+                source: Box::new("markdown-rs".into()),
+                fix: None,
+            })?;
+        let (mut events, _) = parse_range(
+            value,
+            self.options,
+            Point {
+                line: self.line,
+                column: 1,
+                index: 0,
+                vs: 0,
+            },
+            end,
+            (
+                &self.definitions,
+                &self.gfm_footnote_definitions,
+                &self.abbreviations,
+            ),
+        )?;
+
+        let (size, count) = if self.eof {
+            (end, events.len())
+        } else {
+            // The last block could continue, the ones before it are done.
+            match boundaries(&events).last() {
+                Some(last) if last.0 > 0 => *last,
+                _ => return Ok(None),
+            }
+        };
+
+        if count < events.len() {
+            self.line = events[count].point.line;
+            events.truncate(count);
+        }
+
+        define(
+            &events,
+            value.as_bytes(),
+            &mut self.definitions,
+            &mut self.gfm_footnote_definitions,
+            &mut self.abbreviations,
+        );
+
+        for event in &mut events {
+            event.point.index += self.offset;
+        }
+
+        let value = String::from_utf8(self.buffer.drain(..size).collect())
+            .expect("expected valid UTF-8 before a line ending");
+        let offset = self.offset;
+        self.offset += size;
+        self.parsed = 0;
+
+        Ok(Some(Chunk {
+            value,
+            offset,
+            events,
+        }))
+    }
+}
+
+impl<R: std::io::Read> Iterator for Stream<'_, R> {
+    type Item = Result<Chunk, message::Message>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if !self.eof {
+                if let Err(message) = self.read() {
+                    self.done = true;
+                    return Some(Err(message));
+                }
+            }
+
+            match self.take() {
+                Ok(Some(chunk)) => {
+                    self.done = self.eof && self.buffer.is_empty();
+                    return Some(Ok(chunk));
+                }
+                Ok(None) => {
+                    // Nothing at all, or nothing done yet.
+                    self.done = self.eof && self.buffer.is_empty();
+                }
+                Err(message) => {
+                    self.done = true;
+                    return Some(Err(message));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Add the definitions, GFM footnote definitions, and abbreviations in
+/// `events` to those that are known, like the tokenizer does.
+fn define(
+    events: &[Event],
+    bytes: &[u8],
+    definitions: &mut Vec<String>,
+    gfm_footnote_definitions: &mut Vec<String>,
+    abbreviations: &mut Vec<String>,
+) {
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            let label = || -> String {
+                Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                    .as_str()
+                    .into()
+            };
+
+            match event.name {
+                Name::DefinitionLabelString => definitions.push(normalize_identifier(&label())),
+                Name::GfmFootnoteDefinitionLabelString => {
+                    gfm_footnote_definitions.push(normalize_identifier(&label()));
+                }
+                Name::AbbreviationDefinitionLabelString => abbreviations.push(label()),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+}
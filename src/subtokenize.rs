@@ -30,6 +30,7 @@ pub struct Subresult {
     pub done: bool,
     pub gfm_footnote_definitions: Vec<String>,
     pub definitions: Vec<String>,
+    pub abbreviations: Vec<String>,
 }
 
 /// Link two [`Event`][]s.
@@ -74,11 +75,12 @@ pub fn link_to(events: &mut [Event], previous: usize, next: usize) {
 
 /// Parse linked events.
 ///
-/// Supposed to be called repeatedly, returns `true` when done.
+/// Supposed to be called repeatedly, returns `true` when done: when none of
+/// the linked events it parsed resulted in more linked events.
 pub fn subtokenize(
     events: &mut Vec<Event>,
     parse_state: &ParseState,
-    filter: &Option<Content>,
+    filter: Option<&Content>,
 ) -> Result<Subresult, message::Message> {
     let mut map = EditMap::new();
     let mut index = 0;
@@ -86,6 +88,7 @@ pub fn subtokenize(
         done: true,
         gfm_footnote_definitions: vec![],
         definitions: vec![],
+        abbreviations: vec![],
     };
     let mut acc = (0, 0);
 
@@ -97,11 +100,15 @@ pub fn subtokenize(
             debug_assert_eq!(event.kind, Kind::Enter);
 
             // No need to enter linked events again.
-            if link.previous.is_none()
-                && (filter.is_none() || &link.content == filter.as_ref().unwrap())
-            {
+            if link.previous.is_none() && (filter.is_none() || Some(&link.content) == filter) {
                 // Index into `events` pointing to a chunk.
                 let mut link_index = Some(index);
+                #[cfg(feature = "trace")]
+                parse_state.trace(format_args!(
+                    "subtokenize: `{:?}` at {}:{}",
+                    link.content, event.point.index, event.point.vs
+                ));
+
                 // Subtokenizer.
                 let mut tokenizer = Tokenizer::new(event.point.clone(), parse_state);
                 debug_assert!(
@@ -143,6 +150,23 @@ pub fn subtokenize(
                     }
                 }
 
+                // Check if this is the first paragraph in a block quote.
+                // Used for GFM alerts.
+                if tokenizer.parse_state.options.constructs.gfm_alert
+                    && index > 2
+                    && events[index - 1].kind == Kind::Enter
+                    && events[index - 1].name == Name::Paragraph
+                {
+                    let before = skip::opt_back(events, index - 2, &[Name::BlockQuotePrefix]);
+
+                    if events[before].kind == Kind::Enter && events[before].name == Name::BlockQuote
+                    {
+                        tokenizer
+                            .tokenize_state
+                            .document_at_first_paragraph_of_block_quote = true;
+                    }
+                }
+
                 // Loop through links to pass them in order to the subtokenizer.
                 while let Some(index) = link_index {
                     let enter = &events[index];
@@ -165,11 +189,30 @@ pub fn subtokenize(
                 }
 
                 let mut result = tokenizer.flush(state, true)?;
+
+                // Once definitions are known (when not filtering for
+                // content), content in this content does not have to wait
+                // for another pass over the whole document: subtokenize it
+                // now, in the events of this content.
+                if filter.is_none() {
+                    let mut subresult = subtokenize(&mut tokenizer.events, parse_state, None)?;
+                    result
+                        .gfm_footnote_definitions
+                        .append(&mut subresult.gfm_footnote_definitions);
+                    result.definitions.append(&mut subresult.definitions);
+                    result.abbreviations.append(&mut subresult.abbreviations);
+                }
                 value
                     .gfm_footnote_definitions
                     .append(&mut result.gfm_footnote_definitions);
                 value.definitions.append(&mut result.definitions);
-                value.done = false;
+                value.abbreviations.append(&mut result.abbreviations);
+
+                // Another pass is only needed if there is content in this
+                // content.
+                if tokenizer.events.iter().any(|d| d.link.is_some()) {
+                    value.done = false;
+                }
 
                 acc = divide_events(&mut map, events, index, &mut tokenizer.events, acc);
             }
@@ -0,0 +1,66 @@
+//! Turn events into a concrete syntax tree.
+
+use crate::cst::Node;
+use crate::event::{Event, Kind};
+use alloc::vec;
+
+/// Turn events and bytes into a concrete syntax tree.
+pub fn compile(events: &[Event], bytes: &[u8]) -> Node {
+    let mut stack = vec![Node {
+        name: None,
+        start: 0,
+        end: bytes.len(),
+        children: vec![],
+    }];
+
+    for event in events {
+        if event.kind == Kind::Enter {
+            let parent = stack.last_mut().expect("expected parent");
+            let cursor = parent.children.last().map_or(parent.start, |d| d.end);
+            // Events do not overlap, but be safe.
+            let start = event.point.index.max(cursor);
+            push_gap(parent, cursor, start);
+            stack.push(Node {
+                name: Some(event.name.clone()),
+                start,
+                end: start,
+                children: vec![],
+            });
+        } else {
+            let mut node = stack.pop().expect("expected node");
+            let cursor = node.children.last().map_or(node.start, |d| d.end);
+            node.end = event.point.index.max(cursor);
+
+            if !node.children.is_empty() {
+                let end = node.end;
+                push_gap(&mut node, cursor, end);
+            }
+
+            stack
+                .last_mut()
+                .expect("expected parent")
+                .children
+                .push(node);
+        }
+    }
+
+    debug_assert_eq!(stack.len(), 1, "expected only the root");
+    let mut root = stack.pop().expect("expected root");
+    let cursor = root.children.last().map_or(root.start, |d| d.end);
+    let end = root.end;
+    push_gap(&mut root, cursor, end);
+    root
+}
+
+/// Add a node without name to `parent` for the bytes from `start` to `end`,
+/// which are not in an event, if there are any.
+fn push_gap(parent: &mut Node, start: usize, end: usize) {
+    if start < end {
+        parent.children.push(Node {
+            name: None,
+            start,
+            end,
+            children: vec![],
+        });
+    }
+}
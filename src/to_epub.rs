@@ -0,0 +1,68 @@
+//! Turn events into XHTML documents for an EPUB.
+
+use crate::event::Event;
+use crate::mdast::Node;
+use crate::message;
+use crate::to_html::compile_chapters;
+use crate::to_mdast::compile as to_mdast;
+use crate::util::encode::encode;
+use crate::{CompileOptions, ParseOptions};
+use alloc::{format, string::String, string::ToString, vec::Vec};
+
+/// Turn events and bytes into XHTML documents, one per chapter.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> Result<Vec<String>, message::Message> {
+    let options = CompileOptions {
+        epub: true,
+        ..options.clone()
+    };
+    let chapters = compile_chapters(events, bytes, parse_options, &options);
+    let tree = to_mdast(events, bytes, parse_options)?;
+
+    // Headings that start chapters, to use as titles.
+    let mut titles = Vec::new();
+    // Other heading before those, to use as a title for the first chapter.
+    let mut first_title = None;
+
+    if let Node::Root(root) = &tree {
+        for child in &root.children {
+            if let Node::Heading(heading) = child {
+                if heading.depth <= options.epub_chapter_rank {
+                    titles.push(child.to_string());
+                } else if titles.is_empty() && first_title.is_none() {
+                    first_title = Some(child.to_string());
+                }
+            }
+        }
+    }
+
+    // When the first chapter does not start with a heading of that rank.
+    if titles.len() < chapters.len() {
+        titles.insert(0, first_title.unwrap_or_default());
+    }
+
+    Ok(chapters
+        .iter()
+        .zip(titles)
+        .map(|(chapter, title)| document(chapter.trim_matches(|d| d == '\n' || d == '\r'), &title))
+        .collect())
+}
+
+/// Wrap a chapter in an XHTML document.
+fn document(value: &str, title: &str) -> String {
+    let body = if value.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", value)
+    };
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\">\n<head>\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        encode(title, true),
+        body
+    )
+}
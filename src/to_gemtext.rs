@@ -0,0 +1,344 @@
+//! Turn a syntax tree into a string of gemtext.
+//!
+//! Gemtext is the markup of the Gemini protocol.
+//! It has headings (`#` to `###`), list items (`* a`), quote lines (`> a`),
+//! preformatted blocks, and link lines (`=> https://example.com a`), but no
+//! phrasing formatting or inline links.
+//! Line endings in paragraphs are turned into spaces, as each line of text is
+//! a paragraph, but hard breaks are kept.
+//! Links and images are turned into their text, and each block is followed by
+//! link lines for the links and images in it.
+//! Paragraphs with only links or images are turned into just link lines.
+
+use crate::mdast::{Definition, Node};
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Context used to compile gemtext.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Definitions, to resolve link and image references with.
+    definitions: Vec<&'a Definition>,
+}
+
+/// Turn a syntax tree into a string of gemtext.
+pub fn compile(tree: &Node) -> String {
+    let mut context = CompileContext {
+        definitions: Vec::new(),
+    };
+    collect_definitions(&mut context, tree);
+
+    block(&context, tree).unwrap_or_default()
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Serialize flow (block) children, separated by blank lines.
+fn flow(context: &CompileContext, children: &[Node]) -> String {
+    let mut results: Vec<String> = Vec::new();
+
+    for child in children {
+        if let Some(value) = block(context, child) {
+            results.push(value);
+        }
+    }
+
+    results.join("\n\n")
+}
+
+/// Serialize a flow (block) node.
+///
+/// Returns `None` for nodes that are not shown (such as definitions).
+fn block(context: &CompileContext, node: &Node) -> Option<String> {
+    match node {
+        Node::BlockQuote(x) => Some(quote(&flow(context, &x.children))),
+        Node::Code(x) => Some(preformatted(&x.value, x.lang.as_deref())),
+        Node::Math(x) => Some(preformatted(&x.value, Some("math"))),
+        Node::FootnoteDefinition(x) => Some(format!(
+            "[{}]: {}",
+            x.label.as_ref().unwrap_or(&x.identifier),
+            flow(context, &x.children)
+        )),
+        Node::Heading(x) => {
+            let mut links = Vec::new();
+            let value = format!(
+                "{} {}",
+                "#".repeat(x.depth.min(3).into()),
+                phrasing(context, &x.children, &mut links)
+            );
+            Some(with_links(value, &links))
+        }
+        Node::List(x) => {
+            let mut links = Vec::new();
+            let value = list(context, x.ordered, x.start, &x.children, &mut links);
+            Some(with_links(value, &links))
+        }
+        Node::ContainerDirective(x) => Some(flow(context, &x.children)),
+        Node::Div(x) => Some(flow(context, &x.children)),
+        Node::CustomBlock(x) => Some(flow(context, &x.children)),
+        Node::ListItem(x) => Some(flow(context, &x.children)),
+        Node::MdxJsxFlowElement(x) => Some(flow(context, &x.children)),
+        Node::Root(x) => Some(flow(context, &x.children)),
+        Node::Paragraph(x) => {
+            let mut links = Vec::new();
+            let value = phrasing(context, &x.children, &mut links);
+
+            // Only links: use just the link lines.
+            if !links.is_empty() && x.children.iter().all(is_link_or_whitespace) {
+                Some(with_links(String::new(), &links))
+            } else {
+                Some(with_links(value, &links))
+            }
+        }
+        // Gemtext has no tables: use a preformatted line per row.
+        Node::Table(x) => {
+            let mut links = Vec::new();
+            let rows = x
+                .children
+                .iter()
+                .map(|row| {
+                    row.children()
+                        .into_iter()
+                        .flatten()
+                        .map(|cell| {
+                            phrasing(
+                                context,
+                                cell.children().map_or(&[], |d| d.as_slice()),
+                                &mut links,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some(with_links(preformatted(&rows, None), &links))
+        }
+        Node::ThematicBreak(_) => Some("---".into()),
+        Node::AbbreviationDefinition(_)
+        | Node::AttributeList(_)
+        | Node::Definition(_)
+        | Node::Html(_)
+        | Node::MdxFlowExpression(_)
+        | Node::MdxjsEsm(_)
+        | Node::TableCell(_)
+        | Node::TableRow(_)
+        | Node::Toml(_)
+        | Node::Yaml(_) => None,
+        // Phrasing.
+        _ => {
+            let mut links = Vec::new();
+            let value = phrasing(context, core::slice::from_ref(node), &mut links);
+            Some(with_links(value, &links))
+        }
+    }
+}
+
+/// Serialize the items of a list.
+///
+/// Gemtext has no nested or ordered lists: nested lists are flattened, and
+/// ordered items are turned into text lines that start with their number.
+fn list(
+    context: &CompileContext,
+    ordered: bool,
+    start: Option<u32>,
+    children: &[Node],
+    links: &mut Vec<(String, String)>,
+) -> String {
+    let mut number = start.unwrap_or(1);
+    let mut results: Vec<String> = Vec::new();
+
+    for child in children {
+        let marker = if ordered {
+            number += 1;
+            format!("{}. ", number - 1)
+        } else {
+            "* ".into()
+        };
+        let checked = match child {
+            Node::ListItem(item) => match item.checked {
+                Some(true) => "[x] ",
+                Some(false) => "[ ] ",
+                None => "",
+            },
+            _ => "",
+        };
+        let nodes = child.children().map_or(&[][..], |d| d.as_slice());
+        let rest = if let Some((Node::Paragraph(x), rest)) = nodes.split_first() {
+            let text = phrasing(context, &x.children, links);
+            results.push(format!("{}{}{}", marker, checked, text));
+            rest
+        } else {
+            results.push(format!("{}{}", marker, checked).trim_end().into());
+            nodes
+        };
+
+        for grandchild in rest {
+            if let Node::List(x) = grandchild {
+                results.push(list(context, x.ordered, x.start, &x.children, links));
+            } else if let Some(value) = block(context, grandchild) {
+                results.push(value);
+            }
+        }
+    }
+
+    results.join("\n")
+}
+
+/// Serialize phrasing (inline) children.
+///
+/// Links and images are added to `links`.
+fn phrasing(
+    context: &CompileContext,
+    children: &[Node],
+    links: &mut Vec<(String, String)>,
+) -> String {
+    let mut result = String::new();
+
+    for child in children {
+        match child {
+            Node::Break(_) => result.push('\n'),
+            Node::FootnoteReference(x) => {
+                result.push('[');
+                result.push_str(x.label.as_ref().unwrap_or(&x.identifier));
+                result.push(']');
+            }
+            Node::Image(x) => {
+                result.push_str(&x.alt);
+                links.push((x.url.clone(), x.alt.clone()));
+            }
+            Node::ImageReference(x) => {
+                result.push_str(&x.alt);
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    links.push((definition.url.clone(), x.alt.clone()));
+                }
+            }
+            Node::InlineCode(x) => result.push_str(&x.value),
+            Node::InlineMath(x) => result.push_str(&x.value),
+            Node::Link(x) => {
+                let mut inner = Vec::new();
+                let text = phrasing(context, &x.children, &mut inner);
+                result.push_str(&text);
+                links.push((x.url.clone(), text));
+                links.append(&mut inner);
+            }
+            Node::LinkReference(x) => {
+                let mut inner = Vec::new();
+                let text = phrasing(context, &x.children, &mut inner);
+                result.push_str(&text);
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    links.push((definition.url.clone(), text));
+                }
+                links.append(&mut inner);
+            }
+            Node::Text(x) => result.push_str(&x.value.replace('\n', " ")),
+            Node::GithubReference(x) => result.push_str(&github_reference_serialize(x)),
+            Node::Mention(x) => {
+                result.push('@');
+                result.push_str(&x.name);
+            }
+            Node::Ruby(x) => {
+                result.push_str(&x.base);
+                result.push('(');
+                result.push_str(&x.text);
+                result.push(')');
+            }
+            Node::WikiLink(x) => result.push_str(x.alias.as_ref().unwrap_or(&x.target)),
+            Node::AttributeList(_)
+            | Node::BlockId(_)
+            | Node::Html(_)
+            | Node::MdxTextExpression(_) => {}
+            _ => {
+                if let Some(children) = child.children() {
+                    result.push_str(&phrasing(context, children, links));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Whether `node` is a link, an image, or whitespace.
+fn is_link_or_whitespace(node: &Node) -> bool {
+    match node {
+        Node::Break(_)
+        | Node::Image(_)
+        | Node::ImageReference(_)
+        | Node::Link(_)
+        | Node::LinkReference(_) => true,
+        Node::Text(x) => x.value.trim().is_empty(),
+        _ => false,
+    }
+}
+
+/// Find the definition for `identifier`.
+fn find_definition<'a>(context: &CompileContext<'a>, identifier: &str) -> Option<&'a Definition> {
+    context
+        .definitions
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+        .copied()
+}
+
+/// Add link lines for `links` after `value`.
+fn with_links(value: String, links: &[(String, String)]) -> String {
+    let mut results = Vec::new();
+
+    if !value.is_empty() {
+        results.push(value);
+    }
+
+    for (url, text) in links {
+        if text.is_empty() || text == url {
+            results.push(format!("=> {}", url));
+        } else {
+            results.push(format!("=> {} {}", url, text));
+        }
+    }
+
+    results.join("\n")
+}
+
+/// Serialize a preformatted block, with optional alt text.
+fn preformatted(value: &str, alt: Option<&str>) -> String {
+    format!("```{}\n{}\n```", alt.unwrap_or_default(), value)
+}
+
+/// Prefix text lines in `value` with `> `.
+///
+/// Link lines and preformatted blocks cannot be quoted, so they are kept
+/// as they are.
+fn quote(value: &str) -> String {
+    let mut preformatted = false;
+
+    value
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("```") {
+                preformatted = !preformatted;
+                line.to_string()
+            } else if preformatted || line.starts_with("=>") {
+                line.to_string()
+            } else if line.is_empty() {
+                ">".into()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
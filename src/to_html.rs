@@ -1,25 +1,50 @@
 //! Turn events into a string of HTML.
 use crate::event::{Event, Kind, Name};
-use crate::mdast::AlignKind;
+use crate::mdast::{AlignKind, Citation};
 use crate::util::{
+    abbreviation::definitions as abbreviation_definitions,
+    attribute_list::{
+        attributes as attribute_list_attributes, enter as attribute_list_enter,
+        targets as attribute_list_targets,
+    },
+    bibliography::Reference,
+    block_id::target as block_id_target,
+    bracketed_span::attributes as bracketed_span_attributes,
     character_reference::decode as decode_character_reference,
+    citation::citation as citation_from_events,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
-    encode::encode,
+    custom_block::custom_block,
+    custom_inline::custom_inline,
+    directive::{container_label, name_and_attributes as directive_name_and_attributes},
+    encode::{encode, encode_to},
+    external_link::is_external,
+    fenced_div::attributes as fenced_div_attributes,
+    gfm_alert::{alert_type as gfm_alert_type, title as gfm_alert_title},
     gfm_tagfilter::gfm_tagfilter,
+    github_reference::reference as github_reference,
+    image_proxy::proxy as image_proxy,
     infer::{gfm_table_align, list_loose},
+    mathml::to_mathml,
+    mention::name as mention_name,
     normalize_identifier::normalize_identifier,
-    sanitize_uri::{sanitize, sanitize_with_protocols},
+    ruby::base_and_text as ruby_base_and_text,
+    sanitize_html::sanitize_html,
+    sanitize_uri::{sanitize, sanitize_with_protocols, UrlKind},
     skip,
     slice::{Position, Slice},
+    slug::{heading_text, slug, unique},
+    url::resolve as resolve_url,
+    wiki_link::{parts as wiki_link_parts, target_and_alias as wiki_link_target_and_alias},
 };
-use crate::{CompileOptions, LineEnding};
+use crate::{CompileOptions, CriticMarkupMode, Element, LineEnding, LinkKind, ParseOptions};
 use alloc::{
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
-use core::str;
+use core::{convert::TryFrom, fmt, str};
 
 /// Link, image, or footnote call.
 /// Resource or reference.
@@ -80,6 +105,8 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Configuration of the parser.
+    parse_options: &'a ParseOptions,
     /// Configuration.
     options: &'a CompileOptions,
     // Fields used by handlers to track the things they need to track to
@@ -88,14 +115,30 @@ struct CompileContext<'a> {
     heading_atx_rank: Option<usize>,
     /// Buffer of heading (setext) text.
     heading_setext_buffer: Option<String>,
+    /// Id of heading (atx), when rendering elements.
+    heading_atx_id: Option<String>,
+    /// Buffer of heading (atx) text, when rendering elements.
+    heading_atx_buffer: Option<String>,
     /// Whether raw (flow) (code (fenced), math (flow)) or code (indented) contains data.
     raw_flow_seen_data: Option<bool>,
     /// Number of raw (flow) fences.
     raw_flow_fences_count: Option<usize>,
+    /// Class of the code in raw (flow), from the info or for math.
+    raw_flow_class: Option<String>,
+    /// Whether code (fenced, indented) is rendered with `element_render` or
+    /// `code_block_handler`.
+    raw_flow_render: bool,
+    /// Info of code (fenced), when rendering it.
+    raw_flow_info: Option<String>,
+    /// Meta of code (fenced), when rendering it.
+    raw_flow_meta: Option<String>,
     /// Whether we are in code (text).
     raw_text_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in phrasing that can contain line endings (paragraph,
+    /// heading (setext) text).
+    phrasing_inside: bool,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
@@ -104,8 +147,12 @@ struct CompileContext<'a> {
     media_stack: Vec<Media>,
     /// Stack of containers.
     tight_stack: Vec<bool>,
+    /// Stack of block quotes, whether they are GFM alerts.
+    gfm_alert_stack: Vec<bool>,
     /// List of definitions.
     definitions: Vec<Definition>,
+    /// Labels and titles of abbreviation definitions.
+    abbreviations: Vec<(String, String)>,
     /// List of definitions.
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
@@ -116,6 +163,18 @@ struct CompileContext<'a> {
     gfm_table_align: Option<Vec<AlignKind>>,
     /// Current GFM table column.
     gfm_table_column: usize,
+    /// Cells of GFM table rows that are continued by the next row.
+    gfm_table_cells: Option<Vec<String>>,
+    /// Attributes from attribute lists, by the index of the enter of the
+    /// element they apply to.
+    attributes: Vec<(usize, Vec<(String, String)>)>,
+    /// Ids of headings, with how often they occurred.
+    heading_ids: BTreeMap<String, usize>,
+    /// Indices of the references in the bibliography that are cited, in the
+    /// order of the reference list.
+    bibliography_cited: Vec<usize>,
+    /// Whether the reference list was added at a placeholder.
+    bibliography_placed: bool,
     // Fields used to influance the current compilation.
     /// Ignore the next line ending.
     slurp_one_line_ending: bool,
@@ -136,6 +195,7 @@ impl<'a> CompileContext<'a> {
     fn new(
         events: &'a [Event],
         bytes: &'a [u8],
+        parse_options: &'a ParseOptions,
         options: &'a CompileOptions,
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
@@ -144,30 +204,317 @@ impl<'a> CompileContext<'a> {
             bytes,
             heading_atx_rank: None,
             heading_setext_buffer: None,
+            heading_atx_id: None,
+            heading_atx_buffer: None,
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
+            raw_flow_class: None,
+            raw_flow_render: false,
+            raw_flow_info: None,
+            raw_flow_meta: None,
             raw_text_inside: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
             media_stack: vec![],
             definitions: vec![],
+            abbreviations: abbreviation_definitions(events, bytes),
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
             gfm_table_in_head: false,
             gfm_table_align: None,
             gfm_table_column: 0,
+            gfm_table_cells: None,
+            attributes: vec![],
+            heading_ids: BTreeMap::new(),
+            bibliography_cited: bibliography_cited(events, bytes, options),
+            bibliography_placed: false,
             tight_stack: vec![],
+            gfm_alert_stack: vec![],
             slurp_one_line_ending: false,
             image_alt_inside: false,
+            phrasing_inside: false,
             encode_html: true,
             line_ending_default: line_ending,
             buffers: vec![String::new()],
             index: 0,
+            parse_options,
             options,
         }
     }
 
+    /// Serialize the attributes of the element `name`.
+    ///
+    /// Includes the (encoded) classes that the compiler generates (`class`),
+    /// the classes from `element_classes`, and the attributes of the
+    /// attribute lists of the element whose enter is at `index`, if any.
+    /// Classes are joined together into one `class` attribute.
+    fn attributes(&self, name: &str, class: Option<&str>, index: Option<usize>) -> String {
+        let mut result = String::new();
+        let attributes = index
+            .and_then(|index| self.attributes.binary_search_by_key(&index, |d| d.0).ok())
+            .map_or(&[][..], |position| &self.attributes[position].1[..]);
+        let mut element_classes = self
+            .options
+            .element_classes
+            .iter()
+            .filter(|d| d.0 == name)
+            .peekable();
+
+        // Most elements have no attributes.
+        if class.is_none() && element_classes.peek().is_none() && attributes.is_empty() {
+            return result;
+        }
+
+        // The `class` of an attribute list is joined with the other classes,
+        // if there are any.
+        let mut class_attribute = None;
+
+        if class.is_some() || element_classes.peek().is_some() {
+            result.push_str(" class=\"");
+            let mut first = true;
+
+            if let Some(class) = class {
+                for class in class.split(' ') {
+                    if !first {
+                        result.push(' ');
+                    }
+                    first = false;
+                    if let Some(ref prefix) = self.options.class_prefix {
+                        encode_to(&mut result, prefix, true);
+                    }
+                    result.push_str(class);
+                }
+            }
+
+            for (_, value) in element_classes {
+                if !first {
+                    result.push(' ');
+                }
+                first = false;
+                encode_to(&mut result, value, true);
+            }
+
+            class_attribute = attributes.iter().position(|d| d.0 == "class");
+
+            if let Some(position) = class_attribute {
+                result.push(' ');
+                encode_to(&mut result, &attributes[position].1, true);
+            }
+
+            result.push('"');
+        }
+
+        for (position, (name, value)) in attributes.iter().enumerate() {
+            if class_attribute != Some(position) {
+                result.push(' ');
+                encode_to(&mut result, name, true);
+                result.push_str("=\"");
+                encode_to(&mut result, value, true);
+                result.push('"');
+            }
+        }
+
+        result
+    }
+
+    /// Prefix a class that the compiler generates with `class_prefix`, if
+    /// given.
+    fn class(&self, class: &str) -> String {
+        if let Some(ref prefix) = self.options.class_prefix {
+            let mut value = encode(prefix, true);
+            value.push_str(class);
+            value
+        } else {
+            class.into()
+        }
+    }
+
+    /// Push the attributes of the element `name`, see
+    /// [`CompileContext::attributes`][].
+    fn push_attributes(&mut self, name: &str, class: Option<&str>, index: Option<usize>) {
+        let value = self.attributes(name, class, index);
+        self.push(&value);
+    }
+
+    /// Push an `id` attribute for the heading whose enter is at `index`, if
+    /// heading ids are turned on and it does not have an `id` from an
+    /// attribute list.
+    ///
+    /// Returns the value of the attribute, if any.
+    fn push_heading_id(&mut self, index: usize) -> Option<String> {
+        if !self.options.heading_ids {
+            return None;
+        }
+
+        if let Ok(position) = self.attributes.binary_search_by_key(&index, |d| d.0) {
+            if self.attributes[position].1.iter().any(|d| d.0 == "id") {
+                return None;
+            }
+        }
+
+        let text = heading_text(self.events, self.bytes, index);
+        let id = unique(&slug(&self.options.slug, &text), &mut self.heading_ids);
+        let mut value = String::new();
+
+        if let Some(ref prefix) = self.options.id_prefix {
+            value.push_str(&encode(prefix, self.encode_html));
+        }
+
+        value.push_str(&encode(&id, true));
+        self.push(" id=\"");
+        self.push(&value);
+        self.push("\"");
+        Some(value)
+    }
+
+    /// Push what `element_render` gives for `element`, or `html` if it
+    /// gives nothing.
+    fn push_element(&mut self, element: &Element, html: &str) {
+        let value = self
+            .options
+            .element_render
+            .as_ref()
+            .and_then(|render| (render.0)(element));
+        self.push(value.as_deref().unwrap_or(html));
+    }
+
+    /// Push the attributes of the element `name` whose exit is at `index`.
+    fn push_attributes_of_exit(&mut self, name: &str, index: usize) {
+        let enter = if self.attributes.is_empty() {
+            None
+        } else {
+            Some(attribute_list_enter(self.events, index))
+        };
+        self.push_attributes(name, None, enter);
+    }
+
+    /// Push the attributes of a link to `url` that depend on whether it is
+    /// external.
+    fn push_link_attributes(&mut self, url: &str) {
+        if (self.options.external_link_rel.is_empty() && !self.options.external_link_target_blank)
+            || !self.is_external_link(url)
+        {
+            return;
+        }
+
+        if !self.options.external_link_rel.is_empty() {
+            self.push(" rel=\"");
+            self.push(&encode(&self.options.external_link_rel.join(" "), true));
+            self.push("\"");
+        }
+
+        if self.options.external_link_target_blank {
+            self.push(" target=\"_blank\"");
+        }
+    }
+
+    /// Whether a link to `url` is external.
+    fn is_external_link(&self, url: &str) -> bool {
+        if let Some(check) = &self.options.external_link_check {
+            (check.0)(url)
+        } else {
+            is_external(url, self.options.origin.as_deref())
+        }
+    }
+
+    /// Make `url` safe to use as the source of an image or the destination
+    /// of a link.
+    fn sanitize_url(&self, url: &str, kind: UrlKind) -> String {
+        if let Some(sanitize_url) = &self.options.url_sanitize {
+            if let Some(value) = (sanitize_url.0)(url, kind) {
+                return sanitize(&value);
+            }
+        }
+
+        if self.options.allow_dangerous_protocol {
+            return sanitize(url);
+        }
+
+        let image = kind == UrlKind::Image;
+        let protocols = if image {
+            &self.options.image_protocols
+        } else {
+            &self.options.link_protocols
+        };
+
+        if let Some(protocols) = protocols {
+            let protocols = protocols.iter().map(String::as_str).collect::<Vec<_>>();
+            sanitize_with_protocols(url, &protocols)
+        } else if image {
+            sanitize_with_protocols(url, &SAFE_PROTOCOL_SRC)
+        } else {
+            sanitize_with_protocols(url, &SAFE_PROTOCOL_HREF)
+        }
+    }
+
+    /// Push a `data-sourcepos` attribute for the element whose enter or
+    /// exit is at `index`, if `sourcepos` is on (and `sourcepos_inline`, if
+    /// the element is `inline`).
+    fn push_sourcepos(&mut self, index: usize, inline: bool) {
+        if !self.options.sourcepos || (inline && !self.options.sourcepos_inline) {
+            return;
+        }
+
+        let name = &self.events[index].name;
+        let forward = self.events[index].kind == Kind::Enter;
+        let mut other = index;
+        let mut depth = 0;
+
+        // Find the other event, skipping nested elements of the same kind.
+        loop {
+            if self.events[other].name == *name {
+                if (self.events[other].kind == Kind::Enter) == forward {
+                    depth += 1;
+                } else {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+
+            other = if forward { other + 1 } else { other - 1 };
+        }
+
+        let (enter, mut exit) = if forward {
+            (index, other)
+        } else {
+            (other, index)
+        };
+
+        // Ignore trailing line endings and whitespace, also when they are in
+        // children.
+        while exit > enter + 1
+            && (matches!(
+                self.events[exit - 1].name,
+                Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab
+            ) || self.events[exit - 1].point.index == self.events[exit].point.index)
+        {
+            exit -= 1;
+        }
+
+        let start = &self.events[enter].point;
+        let end = &self.events[exit].point;
+        // The end column is inclusive.
+        let value = format!(
+            " data-sourcepos=\"{}:{}-{}:{}\"",
+            start.line,
+            start.column,
+            end.line,
+            end.column.saturating_sub(1)
+        );
+        self.push(&value);
+    }
+
+    /// Push the namespace of generated ids, if there is one.
+    fn push_id_prefix(&mut self) {
+        if let Some(ref value) = self.options.id_prefix {
+            self.push_encoded(value);
+        }
+    }
+
     /// Push a buffer.
     fn buffer(&mut self) {
         self.buffers.push(String::new());
@@ -185,6 +532,16 @@ impl<'a> CompileContext<'a> {
         last_buf.push_str(value);
     }
 
+    /// Push an encoded str to the last buffer.
+    fn push_encoded(&mut self, value: &str) {
+        let encode_html = self.encode_html;
+        let last_buf = self
+            .buffers
+            .last_mut()
+            .expect("at least one buffer should exist");
+        encode_to(last_buf, value, encode_html);
+    }
+
     /// Add a line ending.
     fn line_ending(&mut self) {
         let eol = self.line_ending_default.as_str().to_string();
@@ -203,8 +560,175 @@ impl<'a> CompileContext<'a> {
     }
 }
 
+/// A top-level block in the HTML, with the place it came from in markdown.
+///
+/// Top-level blocks are the blocks that are not in containers (such as
+/// paragraphs, headings, or whole block quotes), which produce HTML.
+/// The GFM footnote section at the end is not included.
+/// This can be used to synchronize the scroll position of an editor and a
+/// live preview.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_blocks, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let (html, blocks) = to_html_with_blocks("# a\n\nb\nc", &Options::default())?;
+///
+/// assert_eq!(&html[blocks[1].start..blocks[1].end], "<p>b\nc</p>");
+/// assert_eq!(blocks[1].position.start.line, 3);
+/// assert_eq!(blocks[1].position.end.line, 4);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HtmlBlock {
+    /// Byte offset in the HTML where the block starts.
+    pub start: usize,
+    /// Byte offset in the HTML where the block ends.
+    pub end: usize,
+    /// Place of the block in markdown.
+    pub position: crate::unist::Position,
+}
+
+/// A piece of the HTML, with the event it came from in markdown.
+///
+/// This is more precise than [`HtmlBlock`][], as there is one for each
+/// event (such as a paragraph, emphasis, or data), not only for top-level
+/// blocks.
+/// This can be used to map between places in an editor and a live preview,
+/// such as to show errors over the preview.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::Name, to_html_with_source_map, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let (html, map) = to_html_with_source_map("a *b*", &Options::default())?;
+/// let emphasis = map.iter().find(|d| d.name == Name::Emphasis).unwrap();
+///
+/// assert_eq!(&html[emphasis.start..emphasis.end], "<em>b</em>");
+/// assert_eq!(emphasis.source_start, 2);
+/// assert_eq!(emphasis.source_end, 5);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceMapping {
+    /// Name of the event.
+    pub name: Name,
+    /// Byte offset in markdown where the event starts.
+    pub source_start: usize,
+    /// Byte offset in markdown where the event ends.
+    pub source_end: usize,
+    /// Byte offset in the HTML where what the event produced starts.
+    pub start: usize,
+    /// Byte offset in the HTML where what the event produced ends.
+    pub end: usize,
+}
+
+/// Result of compiling.
+#[derive(Debug)]
+struct Compiled {
+    /// HTML.
+    value: String,
+    /// Byte offsets in `value` where chapters start.
+    chapter_starts: Vec<usize>,
+    /// Top-level blocks in `value`.
+    blocks: Vec<HtmlBlock>,
+    /// Events in `value`, if asked for.
+    source_map: Vec<SourceMapping>,
+}
+
 /// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> String {
+    compile_all(events, bytes, parse_options, options, None, false)
+        .expect("expected no error w/o writer")
+        .value
+}
+
+/// Turn events and bytes into HTML, written to `writer`.
+///
+/// The HTML is written after each top-level block, so that only one block is
+/// kept in memory.
+pub fn compile_to_writer(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+    writer: &mut dyn fmt::Write,
+) -> fmt::Result {
+    compile_all(events, bytes, parse_options, options, Some(writer), false)?;
+    Ok(())
+}
+
+/// Turn events and bytes into strings of HTML, one per chapter.
+///
+/// A chapter starts at each heading, not in a container, of
+/// `options.epub_chapter_rank` or lower.
+pub fn compile_chapters(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> Vec<String> {
+    let compiled = compile_all(events, bytes, parse_options, options, None, false)
+        .expect("expected no error w/o writer");
+    let mut chapters = vec![];
+    let mut start = 0;
+
+    for end in compiled.chapter_starts {
+        chapters.push(compiled.value[start..end].into());
+        start = end;
+    }
+
+    chapters.push(compiled.value[start..].into());
+    chapters
+}
+
+/// Turn events and bytes into a string of HTML, and its top-level blocks.
+pub fn compile_with_blocks(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> (String, Vec<HtmlBlock>) {
+    let compiled = compile_all(events, bytes, parse_options, options, None, false)
+        .expect("expected no error w/o writer");
+    (compiled.value, compiled.blocks)
+}
+
+/// Turn events and bytes into a string of HTML, and a source map.
+pub fn compile_with_source_map(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+) -> (String, Vec<SourceMapping>) {
+    let compiled = compile_all(events, bytes, parse_options, options, None, true)
+        .expect("expected no error w/o writer");
+    (compiled.value, compiled.source_map)
+}
+
+/// Turn events and bytes into HTML.
+///
+/// When there is a `writer`, HTML is written to it instead of returned.
+/// When `source_map` is on, the events that produced HTML are collected.
+fn compile_all(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+    options: &CompileOptions,
+    mut writer: Option<&mut dyn fmt::Write>,
+    source_map: bool,
+) -> Result<Compiled, fmt::Error> {
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -228,7 +752,87 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     let line_ending_default =
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let mut context =
+        CompileContext::new(events, bytes, parse_options, options, line_ending_default);
+
+    // Collect attribute lists.
+    for (enter, _, index) in attribute_list_targets(events) {
+        let mut attributes = attribute_list_attributes(events, bytes, index);
+
+        // Multiple attribute lists can apply to one element.
+        if let Some(last) = context.attributes.last_mut() {
+            if last.0 == enter {
+                last.1.append(&mut attributes);
+                continue;
+            }
+        }
+
+        context.attributes.push((enter, attributes));
+    }
+
+    // Collect block ids.
+    let mut index = 0;
+    while index < events.len() {
+        if events[index].kind == Kind::Enter && events[index].name == Name::BlockId {
+            if let Some(enter) = block_id_target(events, index) {
+                // Skip the marker to the exit of the value.
+                let value =
+                    Slice::from_position(bytes, &Position::from_exit_event(events, index + 4));
+                let id = (
+                    String::from("id"),
+                    format!(
+                        "{}^{}",
+                        context.options.id_prefix.as_deref().unwrap_or_default(),
+                        value.as_str()
+                    ),
+                );
+
+                match context.attributes.binary_search_by_key(&enter, |d| d.0) {
+                    // Ids from attribute lists win.
+                    Ok(position) => {
+                        let attributes = &mut context.attributes[position].1;
+                        if !attributes.iter().any(|d| d.0 == "id") {
+                            attributes.push(id);
+                        }
+                    }
+                    Err(position) => context.attributes.insert(position, (enter, vec![id])),
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    // Collect attributes of directives, fenced divs, and bracketed spans.
+    let mut index = 0;
+    while index < events.len() {
+        if events[index].kind == Kind::Enter
+            && matches!(
+                events[index].name,
+                Name::BracketedSpan
+                    | Name::DirectiveContainer
+                    | Name::DirectiveLeaf
+                    | Name::DirectiveText
+                    | Name::FencedDiv
+            )
+        {
+            let mut attributes = match events[index].name {
+                Name::BracketedSpan => bracketed_span_attributes(events, bytes, index),
+                Name::FencedDiv => fenced_div_attributes(events, bytes, index),
+                _ => directive_name_and_attributes(events, bytes, index).1,
+            };
+
+            if !attributes.is_empty() {
+                match context.attributes.binary_search_by_key(&index, |d| d.0) {
+                    Ok(position) => context.attributes[position].1.append(&mut attributes),
+                    Err(position) => context.attributes.insert(position, (index, attributes)),
+                }
+            }
+        }
+
+        index += 1;
+    }
+
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
@@ -266,6 +870,16 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     }
 
     let mut index = 0;
+    let mut depth = 0;
+    let mut chapter_starts = vec![];
+    let mut blocks = vec![];
+    let mut block_start = (0, 0);
+    let mut mappings = vec![];
+    // Enters of open events: their index, where their HTML starts, and
+    // whether that is in the top-level buffer.
+    let mut mapping_stack = vec![];
+    // Bytes of HTML that are written to `writer` already.
+    let mut flushed = 0;
     let jump_default = (events.len(), events.len());
     let mut definition_index = 0;
     let mut jump = definition_indices
@@ -280,22 +894,210 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
                 .get(definition_index)
                 .unwrap_or(&jump_default);
         } else {
-            handle(&mut context, index);
+            if events[index].kind == Kind::Enter {
+                if depth == 0
+                    && !context.buffers[0].is_empty()
+                    && heading_rank(events, bytes, index)
+                        .map_or(false, |rank| rank <= options.epub_chapter_rank)
+                {
+                    chapter_starts.push(flushed + context.buffers[0].len());
+                }
+
+                if depth == 0 {
+                    block_start = (index, flushed + context.buffers[0].len());
+                }
+
+                if source_map {
+                    mapping_stack.push((
+                        index,
+                        flushed + context.buffers[0].len(),
+                        context.buffers.len() == 1,
+                    ));
+                }
+
+                depth += 1;
+                handle(&mut context, index);
+            } else {
+                depth -= 1;
+                handle(&mut context, index);
+
+                if source_map {
+                    let (enter, start, top) = mapping_stack.pop().expect("expected enter for exit");
+
+                    if top && context.buffers.len() == 1 {
+                        let value = &context.buffers[0];
+                        let end = flushed + value.len();
+                        // Skip the line ending that was added before the
+                        // event.
+                        let start = value[start - flushed..]
+                            .find(|d| d != '\n' && d != '\r')
+                            .map_or(end, |offset| start + offset);
+
+                        if start < end {
+                            mappings.push(SourceMapping {
+                                name: events[enter].name.clone(),
+                                source_start: events[enter].point.index,
+                                source_end: events[index].point.index,
+                                start,
+                                end,
+                            });
+                        }
+                    }
+                }
+
+                if depth == 0 && context.buffers.len() == 1 {
+                    let value = &context.buffers[0];
+                    let end = flushed + value.len();
+                    // Skip the line ending that was added before the block.
+                    let start = value[block_start.1 - flushed..]
+                        .find(|d| d != '\n' && d != '\r')
+                        .map_or(end, |offset| block_start.1 + offset);
+
+                    if start < end {
+                        let enter = &events[block_start.0];
+                        let exit = &events[index];
+                        blocks.push(HtmlBlock {
+                            start,
+                            end,
+                            position: crate::unist::Position::new(
+                                enter.point.line,
+                                enter.point.column,
+                                enter.point.index,
+                                exit.point.line,
+                                exit.point.column,
+                                exit.point.index,
+                            ),
+                        });
+                    }
+
+                    if let Some(writer) = writer.as_mut() {
+                        // Keep the last character, to know whether a line
+                        // ending is needed before the next block.
+                        let value = &mut context.buffers[0];
+                        let keep = value.char_indices().last().map_or(0, |d| d.0);
+                        writer.write_str(&value[..keep])?;
+                        value.drain(..keep);
+                        flushed += keep;
+                    }
+                }
+            }
+
             index += 1;
         }
     }
 
+    // Add the reference list if there was no placeholder for it.
+    if !context.bibliography_placed && !context.bibliography_cited.is_empty() {
+        generate_bibliography(&mut context);
+    }
+
     // No section to generate.
     if !context.gfm_footnote_definition_calls.is_empty() {
         generate_footnote_section(&mut context);
     }
 
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .first()
-        .expect("expected 1 final buffer")
-        .into()
+
+    let mut value = context.buffers.pop().expect("expected 1 final buffer");
+
+    if let Some(writer) = writer {
+        writer.write_str(&value)?;
+        value = String::new();
+    }
+
+    Ok(Compiled {
+        value,
+        chapter_starts,
+        blocks,
+        source_map: mappings,
+    })
+}
+
+/// Whether the GFM table head whose enter is at `index` has no head row.
+fn gfm_table_headless(events: &[Event], index: usize) -> bool {
+    events[index + 1].name == Name::GfmTableDelimiterRow
+}
+
+/// Whether the GFM table row whose enter or exit is at `index` is continued
+/// by the next row.
+fn gfm_table_row_continued(events: &[Event], index: usize) -> bool {
+    let (start, end) = if events[index].kind == Kind::Enter {
+        (index, skip::to(events, index + 1, &[Name::GfmTableRow]))
+    } else {
+        (
+            skip::to_back(events, index - 1, &[Name::GfmTableRow]),
+            index,
+        )
+    };
+
+    events[start..end]
+        .iter()
+        .any(|event| event.name == Name::GfmTableRowContinuation)
+}
+
+/// Get the class for the state of the GFM task list item whose marker is at
+/// `index`, if it is in another state than checked or unchecked.
+fn gfm_task_list_item_state_class(
+    events: &[Event],
+    bytes: &[u8],
+    mut index: usize,
+) -> Option<&'static str> {
+    while index < events.len() {
+        let event = &events[index];
+
+        match (&event.kind, &event.name) {
+            (Kind::Enter, Name::GfmTaskListItemValueOther) => {
+                return match bytes[event.point.index] {
+                    b'-' => Some("task-list-item-cancelled"),
+                    b'~' => Some("task-list-item-in-progress"),
+                    _ => Some("task-list-item-deferred"),
+                };
+            }
+            // The check can only be in the first paragraph of this item.
+            (Kind::Enter, Name::ListItem) | (Kind::Exit, Name::ListItem | Name::Paragraph) => {
+                break;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Get the rank of the heading whose enter is at `index`, if it is one.
+fn heading_rank(events: &[Event], bytes: &[u8], mut index: usize) -> Option<u8> {
+    let name = events[index].name.clone();
+
+    if !matches!(name, Name::HeadingAtx | Name::HeadingSetext) {
+        return None;
+    }
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            if event.name == Name::HeadingAtxSequence {
+                let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                #[allow(clippy::cast_possible_truncation)]
+                return Some(slice.len() as u8);
+            } else if event.name == Name::HeadingSetextUnderlineSequence {
+                let position = Position::from_exit_event(events, index);
+                return Some(if bytes[position.start.index] == b'-' {
+                    2
+                } else {
+                    1
+                });
+            } else if event.name == name {
+                break;
+            }
+        }
+
+        index += 1;
+    }
+
+    None
 }
 
 /// Handle the event at `index`.
@@ -312,6 +1114,9 @@ fn handle(context: &mut CompileContext, index: usize) {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) {
     match context.events[context.index].name {
+        Name::CodeFencedFenceInfo | Name::CodeFencedFenceMeta if context.raw_flow_render => {
+            on_enter_raw_flow_fence_info_render(context);
+        }
         Name::CodeFencedFenceInfo
         | Name::CodeFencedFenceMeta
         | Name::MathFlowFenceMeta
@@ -319,7 +1124,6 @@ fn enter(context: &mut CompileContext) {
         | Name::DefinitionTitleString
         | Name::GfmFootnoteDefinitionPrefix
         | Name::HeadingAtxText
-        | Name::HeadingSetextText
         | Name::Label
         | Name::MdxEsm
         | Name::MdxFlowExpression
@@ -329,14 +1133,27 @@ fn enter(context: &mut CompileContext) {
         | Name::ReferenceString
         | Name::ResourceTitleString => on_enter_buffer(context),
 
+        Name::Abbreviation => on_enter_abbreviation(context),
         Name::BlockQuote => on_enter_block_quote(context),
+        Name::BracketedSpan => on_enter_bracketed_span(context),
         Name::CodeIndented => on_enter_code_indented(context),
+        Name::MathFlow if context.options.math_mathml => on_enter_math_flow_mathml(context),
         Name::CodeFenced | Name::MathFlow => on_enter_raw_flow(context),
+        Name::MathText if context.options.math_mathml => on_enter_math_text_mathml(context),
         Name::CodeText | Name::MathText => on_enter_raw_text(context),
+        Name::CriticMarkupText => on_enter_critic_markup_text(context),
+        Name::CustomBlock => on_enter_custom_block(context),
+        Name::CustomInline => on_enter_custom_inline(context),
         Name::Definition => on_enter_definition(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
+        Name::DirectiveContainer => on_enter_directive_container(context),
+        Name::DirectiveLabelString => on_enter_directive_label_string(context),
+        Name::DirectiveLeaf => on_enter_directive_leaf(context),
+        Name::DirectiveText => on_enter_directive_text(context),
         Name::Emphasis => on_enter_emphasis(context),
+        Name::FencedDiv => on_enter_fenced_div(context),
         Name::Frontmatter => on_enter_frontmatter(context),
+        Name::HeadingSetextText => on_enter_heading_setext_text(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
         Name::GfmFootnoteCall => on_enter_gfm_footnote_call(context),
         Name::GfmStrikethrough => on_enter_gfm_strikethrough(context),
@@ -345,7 +1162,9 @@ fn enter(context: &mut CompileContext) {
         Name::GfmTableCell => on_enter_gfm_table_cell(context),
         Name::GfmTableHead => on_enter_gfm_table_head(context),
         Name::GfmTableRow => on_enter_gfm_table_row(context),
+        Name::GfmAlert => on_enter_gfm_alert(context),
         Name::GfmTaskListItemCheck => on_enter_gfm_task_list_item_check(context),
+        Name::GithubReference => on_enter_github_reference(context),
         Name::HtmlFlow => on_enter_html_flow(context),
         Name::HtmlText => on_enter_html_text(context),
         Name::Image => on_enter_image(context),
@@ -355,7 +1174,18 @@ fn enter(context: &mut CompileContext) {
         Name::Paragraph => on_enter_paragraph(context),
         Name::Resource => on_enter_resource(context),
         Name::ResourceDestinationString => on_enter_resource_destination_string(context),
+        Name::Spoiler => on_enter_spoiler(context),
         Name::Strong => on_enter_strong(context),
+        Name::Insert => on_enter_insert(context),
+        Name::Mark => on_enter_mark(context),
+        Name::Subscript => on_enter_subscript(context),
+        Name::Superscript => on_enter_superscript(context),
+        Name::Underline => on_enter_underline(context),
+        Name::Citation => on_enter_citation(context),
+        Name::Mention => on_enter_mention(context),
+        Name::Ruby => on_enter_ruby(context),
+        Name::WikiEmbed => on_enter_wiki_embed(context),
+        Name::WikiLink => on_enter_wiki_link(context),
         _ => {}
     }
 }
@@ -363,6 +1193,9 @@ fn enter(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) {
     match context.events[context.index].name {
+        Name::CodeFencedFenceMeta if context.raw_flow_render => {
+            on_exit_raw_flow_fence_meta_render(context);
+        }
         Name::CodeFencedFenceMeta
         | Name::MathFlowFenceMeta
         | Name::MdxJsxTextTag
@@ -374,10 +1207,13 @@ fn exit(context: &mut CompileContext) {
         Name::CharacterEscapeValue | Name::CodeTextData | Name::Data | Name::MathTextData => {
             on_exit_data(context);
         }
+        Name::Abbreviation => on_exit_abbreviation(context),
         Name::AutolinkEmail => on_exit_autolink_email(context),
         Name::AutolinkProtocol => on_exit_autolink_protocol(context),
         Name::BlankLineEnding => on_exit_blank_line_ending(context),
+        Name::BlockId => on_exit_block_id(context),
         Name::BlockQuote => on_exit_block_quote(context),
+        Name::BracketedSpan => on_exit_bracketed_span(context),
         Name::CharacterReferenceMarker => on_exit_character_reference_marker(context),
         Name::CharacterReferenceMarkerNumeric => {
             on_exit_character_reference_marker_numeric(context);
@@ -386,16 +1222,26 @@ fn exit(context: &mut CompileContext) {
             on_exit_character_reference_marker_hexadecimal(context);
         }
         Name::CharacterReferenceValue => on_exit_character_reference_value(context),
+        Name::MathFlow if context.options.math_mathml => on_exit_math_flow_mathml(context),
         Name::CodeFenced | Name::CodeIndented | Name::MathFlow => on_exit_raw_flow(context),
         Name::CodeFencedFence | Name::MathFlowFence => on_exit_raw_flow_fence(context),
         Name::CodeFencedFenceInfo => on_exit_raw_flow_fence_info(context),
         Name::CodeFlowChunk | Name::MathFlowChunk => on_exit_raw_flow_chunk(context),
+        Name::MathText if context.options.math_mathml => on_exit_math_text_mathml(context),
         Name::CodeText | Name::MathText => on_exit_raw_text(context),
+        Name::CriticMarkupText => on_exit_critic_markup_text(context),
+        Name::CustomBlock => on_exit_custom_block(context),
+        Name::CustomInline => on_exit_custom_inline(context),
         Name::Definition => on_exit_definition(context),
         Name::DefinitionDestinationString => on_exit_definition_destination_string(context),
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::DirectiveContainer => on_exit_directive_container(context),
+        Name::DirectiveLabelString => on_exit_directive_label_string(context),
+        Name::DirectiveLeaf => on_exit_directive_leaf(context),
+        Name::DirectiveText => on_exit_directive_text(context),
         Name::Emphasis => on_exit_emphasis(context),
+        Name::FencedDiv => on_exit_fenced_div(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
         Name::GfmAutolinkLiteralMailto => on_exit_gfm_autolink_literal_mailto(context),
@@ -414,6 +1260,7 @@ fn exit(context: &mut CompileContext) {
         Name::GfmTableCell => on_exit_gfm_table_cell(context),
         Name::GfmTableHead => on_exit_gfm_table_head(context),
         Name::GfmTableRow => on_exit_gfm_table_row(context),
+        Name::GfmAlert => on_exit_gfm_alert(context),
         Name::GfmTaskListItemCheck => on_exit_gfm_task_list_item_check(context),
         Name::GfmTaskListItemValueChecked => on_exit_gfm_task_list_item_value_checked(context),
         Name::HardBreakEscape | Name::HardBreakTrailing => on_exit_break(context),
@@ -435,7 +1282,13 @@ fn exit(context: &mut CompileContext) {
         Name::ReferenceString => on_exit_reference_string(context),
         Name::ResourceDestinationString => on_exit_resource_destination_string(context),
         Name::ResourceTitleString => on_exit_resource_title_string(context),
+        Name::Spoiler => on_exit_spoiler(context),
         Name::Strong => on_exit_strong(context),
+        Name::Insert => on_exit_insert(context),
+        Name::Mark => on_exit_mark(context),
+        Name::Subscript => on_exit_subscript(context),
+        Name::Superscript => on_exit_superscript(context),
+        Name::Underline => on_exit_underline(context),
         Name::ThematicBreak => on_exit_thematic_break(context),
         _ => {}
     }
@@ -448,56 +1301,281 @@ fn on_enter_buffer(context: &mut CompileContext) {
     context.buffer();
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Abbreviation`][Name::Abbreviation].
+fn on_enter_abbreviation(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        let label = Slice::from_position(
+            context.bytes,
+            &Position::from_exit_event(context.events, context.index + 2),
+        );
+        let title = context
+            .abbreviations
+            .iter()
+            .find(|d| d.0 == label.as_str())
+            .map(|d| encode(&d.1, context.encode_html))
+            .unwrap_or_default();
+
+        context.push("<abbr title=\"");
+        context.push(&title);
+        context.push("\"");
+        context.push_attributes("abbr", None, None);
+        context.push(">");
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`BlockQuote`][Name::BlockQuote].
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tight_stack.push(false);
     context.line_ending_if_needed();
-    context.push("<blockquote>");
+
+    if let Some(kind) = gfm_alert_type(context.events, context.bytes, context.index) {
+        let class = format!("markdown-alert markdown-alert-{}", kind);
+        context.gfm_alert_stack.push(true);
+        context.push("<div");
+        context.push_attributes("div", Some(&class), Some(context.index));
+        context.push_sourcepos(context.index, false);
+    } else {
+        context.gfm_alert_stack.push(false);
+        context.push("<blockquote");
+        context.push_attributes("blockquote", None, Some(context.index));
+        context.push_sourcepos(context.index, false);
+    }
+
+    context.push(">");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`BracketedSpan`][Name::BracketedSpan].
+fn on_enter_bracketed_span(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<span");
+        context.push_attributes("span", None, Some(context.index));
+        context.push(">");
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`CodeIndented`][Name::CodeIndented].
 fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
+    context.raw_flow_render =
+        context.options.element_render.is_some() || context.options.code_block_handler.is_some();
     context.line_ending_if_needed();
-    context.push("<pre><code>");
+
+    if context.raw_flow_render {
+        context.buffer();
+    }
+
+    context.push("<pre");
+    context.push_attributes("pre", None, Some(context.index));
+    context.push_sourcepos(context.index, false);
+    context.push("><code");
+    context.push_attributes("code", None, None);
+    context.push(">");
+
+    if context.raw_flow_render {
+        context.buffer();
+        context.encode_html = false;
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
 fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
+    context.raw_flow_render = context.events[context.index].name == Name::CodeFenced
+        && (context.options.element_render.is_some()
+            || context.options.code_block_handler.is_some()
+            || syntax_highlight(context.options));
     context.line_ending_if_needed();
+
+    if context.raw_flow_render {
+        context.buffer();
+    }
+
+    context.push("<pre");
+    context.push_attributes("pre", None, Some(context.index));
+    context.push_sourcepos(context.index, false);
     // Note that no `>` is used, which is added later (due to info)
-    context.push("<pre><code");
+    context.push("><code");
     context.raw_flow_fences_count = Some(0);
 
     if context.events[context.index].name == Name::MathFlow {
-        context.push(" class=\"language-math math-display\"");
+        context.raw_flow_class = Some("language-math math-display".into());
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`MathFlow`][Name::MathFlow], when compiling
+/// to `MathML`.
+fn on_enter_math_flow_mathml(context: &mut CompileContext) {
+    let mut value = String::new();
+    let mut index = context.index + 1;
+
+    while context.events[index].name != Name::MathFlow {
+        if context.events[index].kind == Kind::Exit
+            && context.events[index].name == Name::MathFlowChunk
+        {
+            if !value.is_empty() {
+                value.push('\n');
+            }
+            value.push_str(
+                &Slice::from_position(
+                    context.bytes,
+                    &Position::from_exit_event(context.events, index),
+                )
+                .serialize(),
+            );
+        }
+
+        index += 1;
     }
+
+    // Some state is still needed for the fences and chunks, which are
+    // compiled into a buffer that is dropped.
+    context.raw_flow_seen_data = Some(false);
+    context.raw_flow_fences_count = Some(0);
+    context.line_ending_if_needed();
+    context.push("<math display=\"block\"");
+    context.push_attributes("math", None, Some(context.index));
+    context.push(">");
+    context.push(&to_mathml(&value));
+    context.push("</math>");
+    context.buffer();
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_enter_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = true;
+
     if !context.image_alt_inside {
         context.push("<code");
-        if context.events[context.index].name == Name::MathText {
-            context.push(" class=\"language-math math-inline\"");
-        }
+        let class = if context.events[context.index].name == Name::MathText {
+            Some("language-math math-inline")
+        } else {
+            None
+        };
+        context.push_attributes("code", class, Some(context.index));
+        context.push_sourcepos(context.index, true);
         context.push(">");
     }
     context.buffer();
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`Definition`][Name::Definition].
-fn on_enter_definition(context: &mut CompileContext) {
-    context.buffer();
-    context.media_stack.push(Media {
-        image: false,
-        label: None,
-        label_id: None,
-        reference_id: None,
-        destination: None,
-        title: None,
+/// Handle [`Enter`][Kind::Enter]:[`MathText`][Name::MathText], when compiling
+/// to `MathML`.
+fn on_enter_math_text_mathml(context: &mut CompileContext) {
+    context.raw_text_inside = true;
+
+    let mut value = String::new();
+    let mut index = context.index + 1;
+
+    while context.events[index].name != Name::MathText {
+        if context.events[index].kind == Kind::Exit {
+            match context.events[index].name {
+                Name::LineEnding => value.push(' '),
+                Name::MathTextData => value.push_str(
+                    Slice::from_position(
+                        context.bytes,
+                        &Position::from_exit_event(context.events, index),
+                    )
+                    .as_str(),
+                ),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    // Padding does not matter in TeX.
+    let value = value.trim();
+
+    if context.image_alt_inside {
+        context.push_encoded(value);
+    } else {
+        context.push("<math");
+        context.push_attributes("math", None, Some(context.index));
+        context.push(">");
+        context.push(&to_mathml(value));
+        context.push("</math>");
+    }
+
+    // Data is compiled into a buffer that is dropped.
+    context.buffer();
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CriticMarkupText`][Name::CriticMarkupText].
+fn on_enter_critic_markup_text(context: &mut CompileContext) {
+    match critic_markup_text_element(context) {
+        // Dropped text is compiled into a buffer that is dropped.
+        None => context.buffer(),
+        Some("") => {}
+        Some(name) => {
+            if !context.image_alt_inside {
+                context.push("<");
+                context.push(name);
+                context.push_attributes(
+                    name,
+                    if name == "span" {
+                        Some("critic-comment")
+                    } else {
+                        None
+                    },
+                    None,
+                );
+                context.push(">");
+            }
+        }
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CustomBlock`][Name::CustomBlock].
+fn on_enter_custom_block(context: &mut CompileContext) {
+    let (custom_block, info) = custom_block(
+        &context.parse_options.custom_blocks,
+        context.events,
+        context.bytes,
+        context.index,
+    );
+    let name = custom_block.name.clone();
+    context.tight_stack.push(false);
+    context.line_ending_if_needed();
+    context.push("<div");
+    context.push_attributes("div", Some(&name), Some(context.index));
+
+    if let Some(info) = info {
+        context.push(" data-info=\"");
+        context.push(&encode(&info, true));
+        context.push("\"");
+    }
+
+    context.push(">");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CustomInline`][Name::CustomInline].
+fn on_enter_custom_inline(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        let name = custom_inline(
+            &context.parse_options.custom_inlines,
+            context.events,
+            context.bytes,
+            context.index,
+        )
+        .name
+        .clone();
+        context.push("<span");
+        context.push_attributes("span", Some(&name), Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Definition`][Name::Definition].
+fn on_enter_definition(context: &mut CompileContext) {
+    context.buffer();
+    context.media_stack.push(Media {
+        image: false,
+        label: None,
+        label_id: None,
+        reference_id: None,
+        destination: None,
+        title: None,
     });
 }
 
@@ -507,10 +1585,82 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
     context.encode_html = false;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_enter_directive_container(context: &mut CompileContext) {
+    context.tight_stack.push(false);
+    context.line_ending_if_needed();
+    push_directive_open(context, "div");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_enter_directive_label_string(context: &mut CompileContext) {
+    // The label of a directive (container) is a paragraph.
+    if container_label(context.events, context.index) {
+        context.line_ending_if_needed();
+        context.push("<p>");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLeaf`][Name::DirectiveLeaf].
+fn on_enter_directive_leaf(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    push_directive_open(context, "div");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveText`][Name::DirectiveText].
+fn on_enter_directive_text(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        push_directive_open(context, "span");
+    }
+}
+
+/// Push the opening tag of the directive whose enter is at the current
+/// index.
+fn push_directive_open(context: &mut CompileContext, name: &str) {
+    let (directive, _) =
+        directive_name_and_attributes(context.events, context.bytes, context.index);
+    context.push("<");
+    context.push(name);
+    context.push(" data-directive=\"");
+    context.push(&encode(&directive, true));
+    context.push("\"");
+    context.push_attributes(name, None, Some(context.index));
+    context.push(">");
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<em>");
+        context.push("<em");
+        context.push_attributes("em", None, Some(context.index));
+        context.push_sourcepos(context.index, true);
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`FencedDiv`][Name::FencedDiv].
+fn on_enter_fenced_div(context: &mut CompileContext) {
+    context.tight_stack.push(false);
+    context.line_ending_if_needed();
+    context.push("<div");
+    context.push_attributes("div", None, Some(context.index));
+    context.push(">");
+
+    // A fenced div with the id `refs` is where the reference list goes.
+    if context.options.bibliography.is_some() && !context.bibliography_placed {
+        if let Ok(position) = context
+            .attributes
+            .binary_search_by_key(&context.index, |d| d.0)
+        {
+            if context.attributes[position]
+                .1
+                .iter()
+                .any(|d| d.0 == "id" && d.1 == "refs")
+            {
+                context.bibliography_placed = true;
+                generate_bibliography_entries(context);
+            }
+        }
     }
 }
 
@@ -539,7 +1689,10 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<del>");
+        context.push("<del");
+        context.push_attributes("del", None, Some(context.index));
+        context.push_sourcepos(context.index, true);
+        context.push(">");
     }
 }
 
@@ -548,16 +1701,27 @@ fn on_enter_gfm_table(context: &mut CompileContext) {
     let align = gfm_table_align(context.events, context.index);
     context.gfm_table_align = Some(align);
     context.line_ending_if_needed();
-    context.push("<table>");
+    context.push("<table");
+    context.push_attributes("table", None, Some(context.index));
+    context.push_sourcepos(context.index, false);
+    context.push(">");
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_enter_gfm_table_body(context: &mut CompileContext) {
-    context.push("<tbody>");
+    context.push("<tbody");
+    context.push_attributes("tbody", None, None);
+    context.push(">");
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableCell`][Name::GfmTableCell].
 fn on_enter_gfm_table_cell(context: &mut CompileContext) {
+    // Capture cells of continued rows, to join them.
+    if context.gfm_table_cells.is_some() {
+        context.buffer();
+        return;
+    }
+
     let column = context.gfm_table_column;
     let align = context.gfm_table_align.as_ref().unwrap();
 
@@ -568,141 +1732,473 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
         let value = align[column];
         context.line_ending_if_needed();
 
-        if context.gfm_table_in_head {
-            context.push("<th");
+        let name = if context.gfm_table_in_head {
+            "th"
+        } else {
+            "td"
+        };
+        context.push("<");
+        context.push(name);
+        context.push_attributes(name, None, None);
+        context.push_sourcepos(context.index, false);
+
+        match value {
+            AlignKind::Left => context.push(" align=\"left\""),
+            AlignKind::Right => context.push(" align=\"right\""),
+            AlignKind::Center => context.push(" align=\"center\""),
+            AlignKind::None => {}
+        }
+
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GfmTableHead`][Name::GfmTableHead].
+fn on_enter_gfm_table_head(context: &mut CompileContext) {
+    // Tables without head row only have a delimiter row in their head.
+    if !gfm_table_headless(context.events, context.index) {
+        context.line_ending_if_needed();
+        context.push("<thead");
+        context.push_attributes("thead", None, None);
+        context.push(">");
+    }
+    context.gfm_table_in_head = true;
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GfmTableRow`][Name::GfmTableRow].
+fn on_enter_gfm_table_row(context: &mut CompileContext) {
+    if context.gfm_table_cells.is_some() {
+        // Continuing a previous row.
+    } else if gfm_table_row_continued(context.events, context.index) {
+        context.gfm_table_cells = Some(vec![]);
+    } else {
+        context.line_ending_if_needed();
+        context.push("<tr");
+        context.push_attributes("tr", None, None);
+        context.push_sourcepos(context.index, false);
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GfmAlert`][Name::GfmAlert].
+fn on_enter_gfm_alert(context: &mut CompileContext) {
+    // Drop the alert: its block quote has the type.
+    context.buffer();
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
+fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<input type=\"checkbox\"");
+        context.push_attributes("input", None, None);
+        context.push(" ");
+        if !context.options.gfm_task_list_item_checkable {
+            context.push("disabled=\"\" ");
+        }
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`HeadingSetextText`][Name::HeadingSetextText].
+fn on_enter_heading_setext_text(context: &mut CompileContext) {
+    context.buffer();
+    context.phrasing_inside = true;
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
+fn on_enter_html_flow(context: &mut CompileContext) {
+    if !strip_html(context.options) {
+        context.line_ending_if_needed();
+    }
+
+    on_enter_html_text(context);
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
+fn on_enter_html_text(context: &mut CompileContext) {
+    if strip_html(context.options) {
+        context.buffer();
+    } else if context.options.allow_dangerous_html {
+        context.encode_html = false;
+
+        if context.options.sanitize_html {
+            context.buffer();
+        }
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Image`][Name::Image].
+fn on_enter_image(context: &mut CompileContext) {
+    context.media_stack.push(Media {
+        image: true,
+        label_id: None,
+        label: None,
+        reference_id: None,
+        destination: None,
+        title: None,
+    });
+    context.image_alt_inside = true; // Disallow tags.
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Link`][Name::Link].
+fn on_enter_link(context: &mut CompileContext) {
+    context.media_stack.push(Media {
+        image: false,
+        label_id: None,
+        label: None,
+        reference_id: None,
+        destination: None,
+        title: None,
+    });
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
+fn on_enter_list(context: &mut CompileContext) {
+    let loose = list_loose(context.events, context.index, true);
+    context.tight_stack.push(!loose);
+    context.line_ending_if_needed();
+
+    // Note: no `>`.
+    context.push(if context.events[context.index].name == Name::ListOrdered {
+        "<ol"
+    } else {
+        "<ul"
+    });
+    context.push_attributes(
+        if context.events[context.index].name == Name::ListOrdered {
+            "ol"
+        } else {
+            "ul"
+        },
+        None,
+        Some(context.index),
+    );
+    context.push_sourcepos(context.index, false);
+    context.list_expect_first_marker = Some(true);
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`ListItemMarker`][Name::ListItemMarker].
+fn on_enter_list_item_marker(context: &mut CompileContext) {
+    if context.list_expect_first_marker.take().unwrap() {
+        context.push(">");
+    }
+
+    context.line_ending_if_needed();
+
+    let enter = skip::to_back(context.events, context.index, &[Name::ListItem]);
+    context.push("<li");
+    context.push_attributes(
+        "li",
+        gfm_task_list_item_state_class(context.events, context.bytes, context.index),
+        Some(enter),
+    );
+    context.push_sourcepos(enter, false);
+    context.push(">");
+    context.list_expect_first_marker = Some(false);
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Paragraph`][Name::Paragraph].
+fn on_enter_paragraph(context: &mut CompileContext) {
+    let tight = context.tight_stack.last().unwrap_or(&false);
+    context.phrasing_inside = true;
+
+    if !tight {
+        context.line_ending_if_needed();
+
+        // The alert of a GFM alert is compiled to its own paragraph.
+        if context.events[context.index + 1].name != Name::GfmAlert {
+            context.push("<p");
+            context.push_attributes("p", None, Some(context.index));
+            context.push_sourcepos(context.index, false);
+            context.push(">");
+        }
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Resource`][Name::Resource].
+fn on_enter_resource(context: &mut CompileContext) {
+    context.buffer(); // We can have line endings in the resource, ignore them.
+    context.media_stack.last_mut().unwrap().destination = Some(String::new());
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`ResourceDestinationString`][Name::ResourceDestinationString].
+fn on_enter_resource_destination_string(context: &mut CompileContext) {
+    context.buffer();
+    // Ignore encoding the result, as we’ll first percent encode the url and
+    // encode manually after.
+    context.encode_html = false;
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Spoiler`][Name::Spoiler].
+fn on_enter_spoiler(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<span");
+        context.push_attributes("span", Some("spoiler"), Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
+fn on_enter_strong(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<strong");
+        context.push_attributes("strong", None, Some(context.index));
+        context.push_sourcepos(context.index, true);
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Insert`][Name::Insert].
+fn on_enter_insert(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<ins");
+        context.push_attributes("ins", None, Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Mark`][Name::Mark].
+fn on_enter_mark(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<mark");
+        context.push_attributes("mark", None, Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Subscript`][Name::Subscript].
+fn on_enter_subscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<sub");
+        context.push_attributes("sub", None, Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Superscript`][Name::Superscript].
+fn on_enter_superscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<sup");
+        context.push_attributes("sup", None, Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Underline`][Name::Underline].
+fn on_enter_underline(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("<u");
+        context.push_attributes("u", None, Some(context.index));
+        context.push(">");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Citation`][Name::Citation].
+fn on_enter_citation(context: &mut CompileContext) {
+    let citation = citation_from_events(context.events, context.bytes, context.index);
+
+    if !context.image_alt_inside {
+        if let Some(resolve) = &context.options.citation_resolve {
+            if let Some(html) = (resolve.0)(&citation) {
+                context.push(&html);
+                return;
+            }
+        }
+    }
+
+    let position = citation.position.as_ref().unwrap();
+    let value = encode(
+        Slice::from_indices(context.bytes, position.start.offset, position.end.offset).as_str(),
+        context.encode_html,
+    );
+
+    if context.image_alt_inside {
+        context.push(&value);
+        return;
+    }
+
+    let value = if context.options.bibliography.is_some() {
+        citation_with_bibliography(context, &citation)
+    } else {
+        value
+    };
+
+    let keys = citation
+        .items
+        .iter()
+        .map(|d| d.key.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    context.push("<span");
+    context.push_attributes("span", Some("citation"), Some(context.index));
+    context.push(" data-cites=\"");
+    context.push(&encode(&keys, true));
+    context.push("\">");
+    context.push(&value);
+    context.push("</span>");
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GithubReference`][Name::GithubReference].
+fn on_enter_github_reference(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index + 1),
+    );
+    let value = encode(slice.as_str(), context.encode_html);
+
+    if let Some(resolve) = &context.options.github_reference_resolve {
+        if let Some(url) = (resolve.0)(&github_reference(slice.as_str())) {
+            let url = context.sanitize_url(&url, UrlKind::Link);
+            context.push("<a href=\"");
+            context.push(&url);
+            context.push("\"");
+            context.push_attributes("a", Some("github-reference"), Some(context.index));
+            context.push(">");
+            context.push(&value);
+            context.push("</a>");
+            return;
+        }
+    }
+
+    context.push(&value);
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Mention`][Name::Mention].
+fn on_enter_mention(context: &mut CompileContext) {
+    let name = mention_name(context.events, context.bytes, context.index);
+    let value = encode(&format!("@{}", name), context.encode_html);
+
+    if context.image_alt_inside {
+        context.push(&value);
+        return;
+    }
+
+    if let Some(resolve) = &context.options.mention_resolve {
+        if let Some((url, label)) = (resolve.0)(&name) {
+            let url = context.sanitize_url(&url, UrlKind::Link);
+            context.push("<a href=\"");
+            context.push(&url);
+            context.push("\"");
+            context.push_attributes("a", Some("mention"), Some(context.index));
+            context.push(">");
+            context.push_encoded(&label);
+            context.push("</a>");
         } else {
-            context.push("<td");
-        }
-
-        match value {
-            AlignKind::Left => context.push(" align=\"left\""),
-            AlignKind::Right => context.push(" align=\"right\""),
-            AlignKind::Center => context.push(" align=\"center\""),
-            AlignKind::None => {}
+            context.push(&value);
         }
 
-        context.push(">");
+        return;
     }
-}
 
-/// Handle [`Enter`][Kind::Enter]:[`GfmTableHead`][Name::GfmTableHead].
-fn on_enter_gfm_table_head(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<thead>");
-    context.gfm_table_in_head = true;
+    context.push("<span");
+    context.push_attributes("span", Some("mention"), Some(context.index));
+    context.push(" data-mention=\"");
+    context.push(&encode(&name, true));
+    context.push("\">");
+    context.push(&value);
+    context.push("</span>");
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`GfmTableRow`][Name::GfmTableRow].
-fn on_enter_gfm_table_row(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<tr>");
-}
+/// Handle [`Enter`][Kind::Enter]:[`Ruby`][Name::Ruby].
+fn on_enter_ruby(context: &mut CompileContext) {
+    let (base, text) = ruby_base_and_text(context.events, context.bytes, context.index);
+    let base = encode(&base, context.encode_html);
 
-/// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
-fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("<input type=\"checkbox\" ");
-        if !context.options.gfm_task_list_item_checkable {
-            context.push("disabled=\"\" ");
-        }
+    if context.image_alt_inside {
+        context.push(&base);
+        return;
     }
-}
 
-/// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
-fn on_enter_html_flow(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    if context.options.allow_dangerous_html {
-        context.encode_html = false;
-    }
+    context.push("<ruby");
+    context.push_attributes("ruby", None, Some(context.index));
+    context.push("><rb>");
+    context.push(&base);
+    context.push("</rb><rt>");
+    context.push_encoded(&text);
+    context.push("</rt></ruby>");
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
-fn on_enter_html_text(context: &mut CompileContext) {
-    if context.options.allow_dangerous_html {
-        context.encode_html = false;
+/// Handle [`Enter`][Kind::Enter]:[`WikiEmbed`][Name::WikiEmbed].
+fn on_enter_wiki_embed(context: &mut CompileContext) {
+    let (target, fragment, alias) = wiki_link_parts(context.events, context.bytes, context.index);
+    let label = match (&alias, &fragment) {
+        (Some(alias), _) => encode(alias, context.encode_html),
+        (None, Some(fragment)) => encode(&format!("{}#{}", target, fragment), context.encode_html),
+        (None, None) => encode(&target, context.encode_html),
+    };
+
+    if context.image_alt_inside {
+        context.push(&label);
+        return;
     }
-}
 
-/// Handle [`Enter`][Kind::Enter]:[`Image`][Name::Image].
-fn on_enter_image(context: &mut CompileContext) {
-    context.media_stack.push(Media {
-        image: true,
-        label_id: None,
-        label: None,
-        reference_id: None,
-        destination: None,
-        title: None,
-    });
-    context.image_alt_inside = true; // Disallow tags.
-}
+    if let Some(resolve) = &context.options.wiki_embed_resolve {
+        if let Some(html) = (resolve.0)(&target, fragment.as_deref(), alias.as_deref()) {
+            context.push(&html);
+            return;
+        }
+    }
 
-/// Handle [`Enter`][Kind::Enter]:[`Link`][Name::Link].
-fn on_enter_link(context: &mut CompileContext) {
-    context.media_stack.push(Media {
-        image: false,
-        label_id: None,
-        label: None,
-        reference_id: None,
-        destination: None,
-        title: None,
-    });
-}
+    context.push("<span");
+    context.push_attributes("span", Some("wikiembed"), Some(context.index));
+    context.push(" data-target=\"");
+    context.push(&encode(&target, true));
+    context.push("\"");
 
-/// Handle [`Enter`][Kind::Enter]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
-fn on_enter_list(context: &mut CompileContext) {
-    let loose = list_loose(context.events, context.index, true);
-    context.tight_stack.push(!loose);
-    context.line_ending_if_needed();
+    if let Some(fragment) = fragment {
+        context.push(" data-fragment=\"");
+        context.push(&encode(&fragment, true));
+        context.push("\"");
+    }
 
-    // Note: no `>`.
-    context.push(if context.events[context.index].name == Name::ListOrdered {
-        "<ol"
-    } else {
-        "<ul"
-    });
-    context.list_expect_first_marker = Some(true);
+    context.push(">");
+    context.push(&label);
+    context.push("</span>");
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`ListItemMarker`][Name::ListItemMarker].
-fn on_enter_list_item_marker(context: &mut CompileContext) {
-    if context.list_expect_first_marker.take().unwrap() {
-        context.push(">");
-    }
+/// Handle [`Enter`][Kind::Enter]:[`WikiLink`][Name::WikiLink].
+fn on_enter_wiki_link(context: &mut CompileContext) {
+    let (target, alias) = wiki_link_target_and_alias(context.events, context.bytes, context.index);
+    let label = encode(alias.as_ref().unwrap_or(&target), context.encode_html);
 
-    context.line_ending_if_needed();
+    if context.image_alt_inside {
+        context.push(&label);
+        return;
+    }
 
-    context.push("<li>");
-    context.list_expect_first_marker = Some(false);
-}
+    let url = if let Some(resolve) = &context.options.wiki_link_resolve {
+        (resolve.0)(&target)
+    } else {
+        Some(target)
+    };
 
-/// Handle [`Enter`][Kind::Enter]:[`Paragraph`][Name::Paragraph].
-fn on_enter_paragraph(context: &mut CompileContext) {
-    let tight = context.tight_stack.last().unwrap_or(&false);
+    context.push("<a");
 
-    if !tight {
-        context.line_ending_if_needed();
-        context.push("<p>");
+    if let Some(ref url) = url {
+        let url = context.sanitize_url(url, UrlKind::Link);
+        context.push(" href=\"");
+        context.push(&url);
+        context.push("\"");
     }
-}
-
-/// Handle [`Enter`][Kind::Enter]:[`Resource`][Name::Resource].
-fn on_enter_resource(context: &mut CompileContext) {
-    context.buffer(); // We can have line endings in the resource, ignore them.
-    context.media_stack.last_mut().unwrap().destination = Some(String::new());
-}
 
-/// Handle [`Enter`][Kind::Enter]:[`ResourceDestinationString`][Name::ResourceDestinationString].
-fn on_enter_resource_destination_string(context: &mut CompileContext) {
-    context.buffer();
-    // Ignore encoding the result, as we’ll first percent encode the url and
-    // encode manually after.
-    context.encode_html = false;
+    context.push_attributes(
+        "a",
+        Some(if url.is_some() {
+            "wikilink"
+        } else {
+            "wikilink missing"
+        }),
+        Some(context.index),
+    );
+    context.push(">");
+    context.push(&label);
+    context.push("</a>");
 }
 
-/// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
-fn on_enter_strong(context: &mut CompileContext) {
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<strong>");
+        context.push("</abbr>");
     }
 }
 
@@ -716,12 +2212,23 @@ fn on_exit_autolink_email(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         false,
     );
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkProtocol`][Name::AutolinkProtocol].
 fn on_exit_autolink_protocol(context: &mut CompileContext) {
+    // Label (`<https://example.com|a>`).
+    let label = if context.events[context.index + 1].name == Name::AutolinkLabelMarker {
+        Some(Slice::from_position(
+            context.bytes,
+            &Position::from_exit_event(context.events, context.index + 4),
+        ))
+    } else {
+        None
+    };
+
     generate_autolink(
         context,
         None,
@@ -730,14 +2237,36 @@ fn on_exit_autolink_protocol(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        label.as_ref().map(Slice::as_str),
         false,
     );
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`BlockId`][Name::BlockId].
+fn on_exit_block_id(context: &mut CompileContext) {
+    // Skip the value and the marker to the enter.
+    let mut enter = context.index - 5;
+
+    // Block ids that do not apply to anything (such as in table cells) are
+    // shown as they are, including the whitespace before them.
+    if block_id_target(context.events, enter).is_none() {
+        if context.events[enter - 1].name == Name::SpaceOrTab {
+            enter -= 2;
+        }
+
+        let start = context.events[enter].point.index;
+        let end = context.events[context.index].point.index;
+        let value = String::from_utf8_lossy(&context.bytes[start..end]).into_owned();
+        context.push_encoded(&value);
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`HardBreakEscape`][Name::HardBreakEscape],[`HardBreakTrailing`][Name::HardBreakTrailing]}.
 fn on_exit_break(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<br />");
+        context.push("<br");
+        context.push_attributes("br", None, None);
+        context.push(" />");
     }
 }
 
@@ -753,7 +2282,19 @@ fn on_exit_block_quote(context: &mut CompileContext) {
     context.tight_stack.pop();
     context.line_ending_if_needed();
     context.slurp_one_line_ending = false;
-    context.push("</blockquote>");
+
+    if context.gfm_alert_stack.pop().unwrap() {
+        context.push("</div>");
+    } else {
+        context.push("</blockquote>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`BracketedSpan`][Name::BracketedSpan].
+fn on_exit_bracketed_span(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</span>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceMarker`][Name::CharacterReferenceMarker].
@@ -784,21 +2325,20 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
     let value = decode_character_reference(slice.as_str(), marker, true)
         .expect("expected to parse only valid named references");
 
-    context.push(&encode(&value, context.encode_html));
+    context.push_encoded(&value);
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFlowChunk`][Name::CodeFlowChunk],[`MathFlowChunk`][Name::MathFlowChunk]}.
 fn on_exit_raw_flow_chunk(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(true);
-    context.push(&encode(
+    context.push_encoded(
         &Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
         )
         // Must serialize to get virtual spaces.
         .serialize(),
-        context.encode_html,
-    ));
+    );
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFence`][Name::CodeFencedFence],[`MathFlowFence`][Name::MathFlowFence]}.
@@ -808,8 +2348,15 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
         .expect("expected `raw_flow_fences_count`");
 
     if count == 0 {
+        let class = context.raw_flow_class.take();
+        context.push_attributes("code", class.as_deref(), None);
         context.push(">");
         context.slurp_one_line_ending = true;
+
+        if context.raw_flow_render {
+            context.buffer();
+            context.encode_html = false;
+        }
     }
 
     context.raw_flow_fences_count = Some(count + 1);
@@ -820,9 +2367,29 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
 /// Note: math (flow) does not support `info`.
 fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
     let value = context.resume();
-    context.push(" class=\"language-");
-    context.push(&value);
-    context.push("\"");
+
+    if context.raw_flow_render {
+        context.encode_html = true;
+        context.raw_flow_class = Some(format!("language-{}", encode(&value, true)));
+        context.raw_flow_info = Some(value);
+    } else {
+        context.raw_flow_class = Some(format!("language-{}", value));
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo],[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta]},
+/// when rendering code.
+fn on_enter_raw_flow_fence_info_render(context: &mut CompileContext) {
+    context.buffer();
+    // Ignore encoding the result, as the renderer gets the text.
+    context.encode_html = false;
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],
+/// when rendering code.
+fn on_exit_raw_flow_fence_meta_render(context: &mut CompileContext) {
+    context.raw_flow_meta = Some(context.resume());
+    context.encode_html = true;
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFenced`][Name::CodeFenced],[`CodeIndented`][Name::CodeIndented],[`MathFlow`][Name::MathFlow]}.
@@ -854,8 +2421,52 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
         context.line_ending_if_needed();
     }
 
+    let mut value = None;
+
+    if context.raw_flow_render {
+        let code = context.resume();
+        context.encode_html = true;
+
+        #[cfg(feature = "syntect")]
+        if let Some(config) = &context.options.syntax_highlight {
+            let html = crate::util::syntax_highlight::highlight_or_encode(
+                config,
+                context.raw_flow_info.as_deref(),
+                &code,
+            );
+            context.push(&html);
+        } else {
+            context.push_encoded(&code);
+        }
+
+        #[cfg(not(feature = "syntect"))]
+        context.push_encoded(&code);
+
+        value = Some(code);
+    }
+
     context.push("</code></pre>");
 
+    if let Some(value) = value {
+        let mut html = context.resume();
+        let lang = context.raw_flow_info.take();
+        let meta = context.raw_flow_meta.take();
+        context.raw_flow_render = false;
+
+        if let Some(handler) = &context.options.code_block_handler {
+            html = (handler.0)(lang.as_deref(), meta.as_deref(), &value);
+        }
+
+        context.push_element(
+            &Element::Code {
+                lang: lang.as_deref(),
+                meta: meta.as_deref(),
+                value: &value,
+            },
+            &html,
+        );
+    }
+
     if let Some(count) = context.raw_flow_fences_count.take() {
         if count < 2 {
             context.line_ending_if_needed();
@@ -865,9 +2476,27 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
     context.slurp_one_line_ending = false;
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`MathFlow`][Name::MathFlow], when compiling to
+/// `MathML`.
+fn on_exit_math_flow_mathml(context: &mut CompileContext) {
+    context.resume();
+    context.raw_flow_seen_data = None;
+    context.raw_flow_fences_count = None;
+    context.raw_flow_class = None;
+    context.slurp_one_line_ending = false;
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`MathText`][Name::MathText], when compiling to
+/// `MathML`.
+fn on_exit_math_text_mathml(context: &mut CompileContext) {
+    context.resume();
+    context.raw_text_inside = false;
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_exit_raw_text(context: &mut CompileContext) {
     let result = context.resume();
+
     // To do: share with `to_mdast`.
     let mut bytes = result.as_bytes().to_vec();
 
@@ -933,14 +2562,90 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
+    context.push_encoded(
         Slice::from_position(
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
-        context.encode_html,
-    ));
+    );
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CriticMarkupText`][Name::CriticMarkupText].
+fn on_exit_critic_markup_text(context: &mut CompileContext) {
+    match critic_markup_text_element(context) {
+        None => {
+            context.resume();
+        }
+        Some("") => {}
+        Some(name) => {
+            if !context.image_alt_inside {
+                context.push("</");
+                context.push(name);
+                context.push(">");
+            }
+        }
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CustomBlock`][Name::CustomBlock].
+fn on_exit_custom_block(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.slurp_one_line_ending = false;
+    context.push("</div>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CustomInline`][Name::CustomInline].
+fn on_exit_custom_inline(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</span>");
+    }
+}
+
+/// Get the name of the element to compile the critic markup text, whose enter
+/// or exit is the current event, to.
+///
+/// Returns an empty string if the text is compiled without an element, and
+/// `None` if it is dropped, based on `critic_markup_mode`.
+fn critic_markup_text_element(context: &CompileContext) -> Option<&'static str> {
+    let events = context.events;
+    let index = context.index;
+    // Substitutions have two texts: the old one before the separator, and the
+    // new one after it.
+    let (name, new) = if events[index].kind == Kind::Enter {
+        let before = &events[index - 3];
+        (before.name.clone(), before.kind == Kind::Exit)
+    } else {
+        let after = &events[index + 3];
+        (after.name.clone(), after.kind == Kind::Exit)
+    };
+    let addition = match name {
+        Name::CriticMarkupAddition => true,
+        Name::CriticMarkupComment => {
+            return if context.options.critic_markup_mode == CriticMarkupMode::Visualize {
+                Some("span")
+            } else {
+                None
+            };
+        }
+        Name::CriticMarkupHighlight => {
+            return if context.options.critic_markup_mode == CriticMarkupMode::Visualize {
+                Some("mark")
+            } else {
+                Some("")
+            };
+        }
+        Name::CriticMarkupDeletion => false,
+        _ => new,
+    };
+
+    match (&context.options.critic_markup_mode, addition) {
+        (CriticMarkupMode::Visualize, true) => Some("ins"),
+        (CriticMarkupMode::Visualize, false) => Some("del"),
+        (CriticMarkupMode::Accept, true) | (CriticMarkupMode::Reject, false) => Some(""),
+        _ => None,
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -979,6 +2684,36 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
     context.media_stack.last_mut().unwrap().title = Some(buf);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_exit_directive_container(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.slurp_one_line_ending = false;
+    context.push("</div>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_exit_directive_label_string(context: &mut CompileContext) {
+    if container_label(
+        context.events,
+        attribute_list_enter(context.events, context.index),
+    ) {
+        context.push("</p>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLeaf`][Name::DirectiveLeaf].
+fn on_exit_directive_leaf(context: &mut CompileContext) {
+    context.push("</div>");
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveText`][Name::DirectiveText].
+fn on_exit_directive_text(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</span>");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
 fn on_exit_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -986,6 +2721,14 @@ fn on_exit_emphasis(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`FencedDiv`][Name::FencedDiv].
+fn on_exit_fenced_div(context: &mut CompileContext) {
+    context.tight_stack.pop();
+    context.line_ending_if_needed();
+    context.slurp_one_line_ending = false;
+    context.push("</div>");
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Frontmatter`][Name::Frontmatter].
 fn on_exit_frontmatter(context: &mut CompileContext) {
     context.resume();
@@ -1002,6 +2745,7 @@ fn on_exit_gfm_autolink_literal_email(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         true,
     );
 }
@@ -1016,6 +2760,7 @@ fn on_exit_gfm_autolink_literal_mailto(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         true,
     );
 }
@@ -1030,6 +2775,7 @@ fn on_exit_gfm_autolink_literal_protocol(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         true,
     );
 }
@@ -1044,6 +2790,7 @@ fn on_exit_gfm_autolink_literal_www(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         true,
     );
 }
@@ -1058,6 +2805,7 @@ fn on_exit_gfm_autolink_literal_xmpp(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .as_str(),
+        None,
         true,
     );
 }
@@ -1092,17 +2840,21 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
         return;
     }
 
-    context.push("<sup><a href=\"#");
+    context.push("<sup");
+    context.push_attributes("sup", None, None);
+    context.push("><a href=\"#");
+    context.push_id_prefix();
     if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("user-content-");
     }
     context.push("fn-");
     context.push(&safe_id);
     context.push("\" id=\"");
+    context.push_id_prefix();
     if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("user-content-");
     }
@@ -1116,7 +2868,14 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
                 .to_string(),
         );
     }
-    context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">");
+    context.push("\" data-footnote-ref=\"\" aria-describedby=\"");
+    context.push_id_prefix();
+    context.push("footnote-label\"");
+    context.push_attributes("a", None, None);
+    if context.options.epub {
+        context.push(" epub:type=\"noteref\"");
+    }
+    context.push(">");
 
     context.push(&(call_index + 1).to_string());
     context.push("</a></sup>");
@@ -1164,12 +2923,37 @@ fn on_exit_gfm_table(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_exit_gfm_table_body(context: &mut CompileContext) {
+    // The last row was continued, but there is no next row.
+    if context.gfm_table_cells.is_some() {
+        context.slurp_one_line_ending = false;
+        gfm_table_row_end(context);
+    }
+
     context.line_ending_if_needed();
     context.push("</tbody>");
 }
 
-/// Handle [`Exit`][Kind::Exit]:[`GfmTableCell`][Name::GfmTableCell].
-fn on_exit_gfm_table_cell(context: &mut CompileContext) {
+/// Handle [`Exit`][Kind::Exit]:[`GfmTableCell`][Name::GfmTableCell].
+fn on_exit_gfm_table_cell(context: &mut CompileContext) {
+    if context.gfm_table_cells.is_some() {
+        let value = context.resume();
+        let column = context.gfm_table_column;
+        let line_ending = context.line_ending_default.as_str();
+        let cells = context.gfm_table_cells.as_mut().unwrap();
+
+        if let Some(cell) = cells.get_mut(column) {
+            if !cell.is_empty() && !value.is_empty() {
+                cell.push_str(line_ending);
+            }
+            cell.push_str(&value);
+        } else {
+            cells.push(value);
+        }
+
+        context.gfm_table_column += 1;
+        return;
+    }
+
     let align = context.gfm_table_align.as_ref().unwrap();
 
     if context.gfm_table_column < align.len() {
@@ -1189,12 +2973,43 @@ fn on_exit_gfm_table_cell(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableHead`][Name::GfmTableHead].
 fn on_exit_gfm_table_head(context: &mut CompileContext) {
     context.gfm_table_in_head = false;
-    context.line_ending_if_needed();
-    context.push("</thead>");
+
+    if !gfm_table_headless(
+        context.events,
+        skip::to_back(context.events, context.index - 1, &[Name::GfmTableHead]),
+    ) {
+        context.line_ending_if_needed();
+        context.push("</thead>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableRow`][Name::GfmTableRow].
 fn on_exit_gfm_table_row(context: &mut CompileContext) {
+    if context.gfm_table_cells.is_some() && gfm_table_row_continued(context.events, context.index) {
+        // Join the cells of the next row into these, without line ending.
+        context.gfm_table_column = 0;
+        context.slurp_one_line_ending = true;
+    } else {
+        gfm_table_row_end(context);
+    }
+}
+
+/// End a GFM table row, and its joined cells of continued rows.
+fn gfm_table_row_end(context: &mut CompileContext) {
+    if let Some(cells) = context.gfm_table_cells.take() {
+        context.line_ending_if_needed();
+        context.push("<tr");
+        context.push_attributes("tr", None, None);
+        context.push(">");
+
+        for (column, value) in cells.iter().enumerate() {
+            context.gfm_table_column = column;
+            on_enter_gfm_table_cell(context);
+            context.push(value);
+            on_exit_gfm_table_cell(context);
+        }
+    }
+
     let mut column = context.gfm_table_column;
     let len = context.gfm_table_align.as_ref().unwrap().len();
 
@@ -1211,6 +3026,28 @@ fn on_exit_gfm_table_row(context: &mut CompileContext) {
     context.push("</tr>");
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`GfmAlert`][Name::GfmAlert].
+fn on_exit_gfm_alert(context: &mut CompileContext) {
+    context.resume();
+    let enter = skip::to_back(context.events, context.index - 1, &[Name::GfmAlert]);
+    let block_quote = skip::to_back(context.events, enter, &[Name::BlockQuote]);
+    let kind = gfm_alert_type(context.events, context.bytes, block_quote)
+        .expect("expected alert in block quote");
+
+    context.push("<p class=\"markdown-alert-title\">");
+    context.push(&gfm_alert_title(&kind));
+    context.push("</p>");
+
+    // The rest of the paragraph, if any, is a paragraph after the title.
+    if context.events[context.index + 1].name == Name::LineEnding {
+        context.slurp_one_line_ending = true;
+        context.line_ending();
+        context.push("<p");
+        context.push_attributes("p", None, Some(enter - 1));
+        context.push(">");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_exit_gfm_task_list_item_check(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -1235,6 +3072,20 @@ fn on_exit_heading_atx(context: &mut CompileContext) {
     context.push("</h");
     context.push(&rank.to_string());
     context.push(">");
+
+    if context.options.element_render.is_some() {
+        let html = context.resume();
+        let id = context.heading_atx_id.take();
+        let children = context.heading_atx_buffer.take().unwrap_or_default();
+        context.push_element(
+            &Element::Heading {
+                rank: u8::try_from(rank).unwrap_or(6),
+                id: id.as_deref(),
+                children: &children,
+            },
+            &html,
+        );
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtxSequence`][Name::HeadingAtxSequence].
@@ -1248,8 +3099,17 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         .len();
         context.line_ending_if_needed();
         context.heading_atx_rank = Some(rank);
+        let enter = skip::to_back(context.events, context.index, &[Name::HeadingAtx]);
+
+        if context.options.element_render.is_some() {
+            context.buffer();
+        }
+
         context.push("<h");
         context.push(&rank.to_string());
+        context.heading_atx_id = context.push_heading_id(enter);
+        context.push_attributes(&format!("h{}", rank), None, Some(enter));
+        context.push_sourcepos(enter, false);
         context.push(">");
     }
 }
@@ -1258,10 +3118,15 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
 fn on_exit_heading_atx_text(context: &mut CompileContext) {
     let value = context.resume();
     context.push(&value);
+
+    if context.options.element_render.is_some() {
+        context.heading_atx_buffer = Some(value);
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingSetextText`][Name::HeadingSetextText].
 fn on_exit_heading_setext_text(context: &mut CompileContext) {
+    context.phrasing_inside = false;
     let buf = context.resume();
     context.heading_setext_buffer = Some(buf);
     context.slurp_one_line_ending = true;
@@ -1277,18 +3142,47 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
     let head = context.bytes[position.start.index];
     let rank = if head == b'-' { "2" } else { "1" };
 
+    let enter = skip::to_back(context.events, context.index, &[Name::HeadingSetext]);
+    let render = context.options.element_render.is_some();
     context.line_ending_if_needed();
+
+    if render {
+        context.buffer();
+    }
+
     context.push("<h");
     context.push(rank);
+    let id = context.push_heading_id(enter);
+    context.push_attributes(&format!("h{}", rank), None, Some(enter));
+    context.push_sourcepos(enter, false);
     context.push(">");
     context.push(&text);
     context.push("</h");
     context.push(rank);
     context.push(">");
+
+    if render {
+        let html = context.resume();
+        context.push_element(
+            &Element::Heading {
+                rank: if head == b'-' { 2 } else { 1 },
+                id: id.as_deref(),
+                children: &text,
+            },
+            &html,
+        );
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
+    if strip_html(context.options) {
+        context.resume();
+    } else if context.options.allow_dangerous_html && context.options.sanitize_html {
+        let value = context.resume();
+        context.push(&sanitize_html(&value));
+    }
+
     context.encode_html = true;
 }
 
@@ -1300,13 +3194,11 @@ fn on_exit_html_data(context: &mut CompileContext) {
     );
     let value = slice.as_str();
 
-    let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
-        encode(&gfm_tagfilter(value), context.encode_html)
+    if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
+        context.push_encoded(&gfm_tagfilter(value));
     } else {
-        encode(value, context.encode_html)
-    };
-
-    context.push(&encoded);
+        context.push_encoded(value);
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Label`][Name::Label].
@@ -1326,21 +3218,40 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     if context.raw_text_inside {
         context.push(" ");
     } else if context.slurp_one_line_ending
-        // Ignore line endings after definitions.
+        // Ignore line endings after definitions (also abbreviation definitions),
+        // attribute lists (flow), and dropped HTML (flow).
         || (context.index > 1
             && (context.events[context.index - 2].name == Name::Definition
-                || context.events[context.index - 2].name == Name::GfmFootnoteDefinition))
+                || context.events[context.index - 2].name == Name::GfmFootnoteDefinition
+                || context.events[context.index - 2].name == Name::AbbreviationDefinition
+                || (context.events[context.index - 2].name == Name::AttributeList
+                    && !context.phrasing_inside)
+                || (context.events[context.index - 2].name == Name::HtmlFlow
+                    && strip_html(context.options))))
     {
         context.slurp_one_line_ending = false;
     } else {
-        context.push(&encode(
+        // Soft breaks, when they are to be turned into hard breaks.
+        if context.options.hard_breaks
+            && context.phrasing_inside
+            && !context.image_alt_inside
+            && !matches!(
+                context.events[context.index - 2].name,
+                Name::HardBreakEscape | Name::HardBreakTrailing | Name::HtmlTextData
+            )
+        {
+            context.push("<br");
+            context.push_attributes("br", None, None);
+            context.push(" />");
+        }
+
+        context.push_encoded(
             Slice::from_position(
                 context.bytes,
                 &Position::from_exit_event(context.events, context.index),
             )
             .as_str(),
-            context.encode_html,
-        ));
+        );
     }
 }
 
@@ -1363,10 +3274,16 @@ fn on_exit_list_item(context: &mut CompileContext) {
         context.index - 1,
         &[
             Name::BlankLineEnding,
+            Name::BlockQuoteFencedFence,
             Name::BlockQuotePrefix,
+            Name::CustomBlockFence,
+            Name::CustomBlockPrefix,
+            Name::DirectiveContainerFence,
+            Name::FencedDivFence,
             Name::LineEnding,
             Name::SpaceOrTab,
             // Also ignore things that don’t contribute to the document.
+            Name::AbbreviationDefinition,
             Name::Definition,
             Name::GfmFootnoteDefinition,
         ],
@@ -1442,58 +3359,125 @@ fn on_exit_media(context: &mut CompileContext) {
         None
     };
 
-    if !is_in_image {
+    let mut destination = if let Some(index) = definition_index {
+        context.definitions[index].destination.clone()
+    } else {
+        media.destination
+    };
+    let mut open = !is_in_image;
+
+    if open && !media.image {
+        if let Some(resolve) = &context.options.link_resolve {
+            let kind = if definition_index.is_some() {
+                LinkKind::Reference
+            } else {
+                LinkKind::Resource
+            };
+
+            destination = (resolve.0)(destination.as_deref().unwrap_or(""), kind);
+            open = destination.is_some();
+        }
+    }
+
+    let title = if !open {
+        None
+    } else if let Some(index) = definition_index {
+        context.definitions[index].title.clone()
+    } else {
+        media.title
+    };
+    let mut attributes = vec![];
+
+    if open && media.image {
+        if let Some(resolve) = &context.options.image_resolve {
+            if let Some(image) = (resolve.0)(
+                destination.as_deref().unwrap_or(""),
+                &label,
+                title.as_deref(),
+            ) {
+                destination = Some(image.src);
+                attributes = image.attributes;
+            }
+        }
+    }
+
+    if open {
+        if let Some(base) = &context.options.base_url {
+            destination = destination.map(|destination| resolve_url(base, &destination));
+        }
+    }
+
+    if open && media.image {
+        if let Some(config) = &context.options.image_proxy {
+            destination = destination.map(|destination| {
+                if is_external(&destination, context.options.origin.as_deref()) {
+                    image_proxy(config, &destination)
+                } else {
+                    destination
+                }
+            });
+        }
+    }
+
+    let render = open && context.options.element_render.is_some();
+    let mut url = None;
+
+    if render {
+        context.buffer();
+    }
+
+    if open {
         if media.image {
             context.push("<img src=\"");
         } else {
             context.push("<a href=\"");
-        };
-
-        let destination = if let Some(index) = definition_index {
-            context.definitions[index].destination.as_ref()
-        } else {
-            media.destination.as_ref()
-        };
+        }
 
         if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
-                sanitize(destination)
-            } else {
-                sanitize_with_protocols(
-                    destination,
-                    if media.image {
-                        &SAFE_PROTOCOL_SRC
-                    } else {
-                        &SAFE_PROTOCOL_HREF
-                    },
-                )
-            };
-            context.push(&url);
+            let value = context.sanitize_url(
+                &destination,
+                if media.image {
+                    UrlKind::Image
+                } else {
+                    UrlKind::Link
+                },
+            );
+            context.push(&value);
+            url = Some(value);
         }
 
         if media.image {
             context.push("\" alt=\"");
-        };
+        }
     }
 
     if media.image {
         context.push(&label);
     }
 
-    if !is_in_image {
+    if open {
         context.push("\"");
 
-        let title = if let Some(index) = definition_index {
-            context.definitions[index].title.clone()
-        } else {
-            media.title
-        };
-
-        if let Some(title) = title {
+        if let Some(ref title) = title {
             context.push(" title=\"");
-            context.push(&title);
+            context.push(title);
             context.push("\"");
-        };
+        }
+
+        for (name, value) in &attributes {
+            context.push(" ");
+            context.push(&encode(name, true));
+            context.push("=\"");
+            context.push(&encode(value, true));
+            context.push("\"");
+        }
+
+        if !media.image {
+            context.push_link_attributes(url.as_deref().unwrap_or(""));
+        }
+
+        context.push_attributes_of_exit(if media.image { "img" } else { "a" }, context.index);
+        context.push_sourcepos(context.index, true);
 
         if media.image {
             context.push(" /");
@@ -1505,19 +3489,38 @@ fn on_exit_media(context: &mut CompileContext) {
     if !media.image {
         context.push(&label);
 
-        if !is_in_image {
+        if open {
             context.push("</a>");
         }
     }
+
+    if render {
+        let html = context.resume();
+        let element = if media.image {
+            Element::Image {
+                destination: url.as_deref(),
+                title: title.as_deref(),
+                alt: &label,
+            }
+        } else {
+            Element::Link {
+                destination: url.as_deref(),
+                title: title.as_deref(),
+                children: &label,
+            }
+        };
+        context.push_element(&element, &html);
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Paragraph`][Name::Paragraph].
 fn on_exit_paragraph(context: &mut CompileContext) {
     let tight = context.tight_stack.last().unwrap_or(&false);
+    context.phrasing_inside = false;
 
     if *tight {
         context.slurp_one_line_ending = true;
-    } else {
+    } else if context.events[context.index - 1].name != Name::GfmAlert {
         context.push("</p>");
     }
 }
@@ -1544,6 +3547,13 @@ fn on_exit_resource_title_string(context: &mut CompileContext) {
     context.media_stack.last_mut().unwrap().title = Some(buf);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Spoiler`][Name::Spoiler].
+fn on_exit_spoiler(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</span>");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Strong`][Name::Strong].
 fn on_exit_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
@@ -1551,42 +3561,92 @@ fn on_exit_strong(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Insert`][Name::Insert].
+fn on_exit_insert(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</ins>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Mark`][Name::Mark].
+fn on_exit_mark(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</mark>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Subscript`][Name::Subscript].
+fn on_exit_subscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</sub>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Superscript`][Name::Superscript].
+fn on_exit_superscript(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</sup>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Underline`][Name::Underline].
+fn on_exit_underline(context: &mut CompileContext) {
+    if !context.image_alt_inside {
+        context.push("</u>");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    context.push("<hr />");
+    context.push("<hr");
+    context.push_attributes_of_exit("hr", context.index);
+    context.push_sourcepos(context.index, false);
+    context.push(" />");
 }
 
 /// Generate a footnote section.
 fn generate_footnote_section(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    context.push("<section data-footnotes=\"\" class=\"footnotes\"><");
+    context.push("<section data-footnotes=\"\"");
+    context.push_attributes("section", Some("footnotes"), None);
+    if context.options.epub {
+        context.push(" epub:type=\"footnotes\"");
+    }
+    context.push("><");
     if let Some(ref value) = context.options.gfm_footnote_label_tag_name {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("h2");
     }
-    context.push(" id=\"footnote-label\" ");
+    context.push(" id=\"");
+    context.push_id_prefix();
+    context.push("footnote-label\" ");
     if let Some(ref value) = context.options.gfm_footnote_label_attributes {
         context.push(value);
     } else {
-        context.push("class=\"sr-only\"");
+        let class = context.class("sr-only");
+        context.push("class=\"");
+        context.push(&class);
+        context.push("\"");
     }
     context.push(">");
     if let Some(ref value) = context.options.gfm_footnote_label {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("Footnotes");
     }
     context.push("</");
     if let Some(ref value) = context.options.gfm_footnote_label_tag_name {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("h2");
     }
     context.push(">");
     context.line_ending();
-    context.push("<ol>");
+    context.push("<ol");
+    context.push_attributes("ol", None, None);
+    context.push(">");
 
     let mut index = 0;
     while index < context.gfm_footnote_definition_calls.len() {
@@ -1601,6 +3661,235 @@ fn generate_footnote_section(context: &mut CompileContext) {
     context.line_ending();
 }
 
+/// Get the indices of the references in the bibliography that are cited,
+/// sorted by author and year, as they are in the reference list.
+fn bibliography_cited(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Vec<usize> {
+    let mut cited: Vec<usize> = vec![];
+
+    if let Some(ref bibliography) = options.bibliography {
+        for (index, event) in events.iter().enumerate() {
+            if event.kind == Kind::Enter && event.name == Name::CitationKey {
+                let key =
+                    Slice::from_position(bytes, &Position::from_exit_event(events, index + 1));
+                let key = key.as_str();
+
+                if let Some(position) = bibliography.references.iter().position(|d| d.id == key) {
+                    if !cited.contains(&position) {
+                        cited.push(position);
+                    }
+                }
+            }
+        }
+
+        cited.sort_by_cached_key(|index| {
+            let reference = &bibliography.references[*index];
+            (
+                reference
+                    .author_long()
+                    .unwrap_or_else(|| reference.author_short())
+                    .to_lowercase(),
+                reference.year().to_string(),
+            )
+        });
+    }
+
+    cited
+}
+
+/// Format a citation with the bibliography, in author-date style.
+///
+/// Keys that are not in the bibliography are shown in bold with a question
+/// mark (`doe99?`), like Pandoc does.
+fn citation_with_bibliography(context: &CompileContext, citation: &Citation) -> String {
+    let bibliography = context.options.bibliography.as_ref().unwrap();
+    let mut items = vec![];
+
+    for item in &citation.items {
+        let mut value = String::new();
+
+        if let Some(ref prefix) = item.prefix {
+            value.push_str(&encode(prefix, context.encode_html));
+            value.push(' ');
+        }
+
+        if let Some(reference) = bibliography.get(&item.key) {
+            let mut text = String::new();
+
+            if citation.in_text {
+                text.push_str(&reference.author_short());
+                text.push_str(" (");
+                text.push_str(reference.year());
+
+                if let Some(ref locator) = item.locator {
+                    text.push_str(", ");
+                    text.push_str(locator);
+                }
+
+                text.push(')');
+            } else {
+                if !item.suppress_author {
+                    text.push_str(&reference.author_short());
+                    text.push(' ');
+                }
+
+                text.push_str(reference.year());
+            }
+
+            value.push_str("<a href=\"#");
+            if let Some(ref prefix) = context.options.id_prefix {
+                value.push_str(&encode(prefix, true));
+            }
+            value.push_str("ref-");
+            value.push_str(&sanitize(&item.key));
+            value.push_str("\" role=\"doc-biblioref\">");
+            value.push_str(&encode(&text, context.encode_html));
+            value.push_str("</a>");
+        } else {
+            value.push_str("<strong>");
+            value.push_str(&encode(&item.key, context.encode_html));
+            value.push_str("?</strong>");
+        }
+
+        if !citation.in_text || bibliography.get(&item.key).is_none() {
+            if let Some(ref locator) = item.locator {
+                value.push_str(", ");
+                value.push_str(&encode(locator, context.encode_html));
+            }
+        }
+
+        items.push(value);
+    }
+
+    let value = items.join("; ");
+
+    if citation.in_text {
+        value
+    } else {
+        format!("({})", value)
+    }
+}
+
+/// Generate the reference list, at the end of the document.
+fn generate_bibliography(context: &mut CompileContext) {
+    context.line_ending_if_needed();
+    context.push("<div id=\"");
+    context.push_id_prefix();
+    context.push("refs\"");
+    context.push_attributes("div", Some("references csl-bib-body"), None);
+    context.push(" role=\"list\">");
+    generate_bibliography_entries(context);
+    context.line_ending();
+    context.push("</div>");
+    context.line_ending();
+}
+
+/// Generate the entries of the reference list.
+fn generate_bibliography_entries(context: &mut CompileContext) {
+    let bibliography = context.options.bibliography.as_ref().unwrap();
+    let mut index = 0;
+
+    while index < context.bibliography_cited.len() {
+        let reference = &bibliography.references[context.bibliography_cited[index]];
+        let value = reference_to_html(reference, context.encode_html);
+        let id = sanitize(&reference.id);
+        context.line_ending();
+        context.push("<div id=\"");
+        context.push_id_prefix();
+        context.push("ref-");
+        context.push(&id);
+        context.push("\"");
+        context.push_attributes("div", Some("csl-entry"), None);
+        context.push(" role=\"listitem\">");
+        context.push(&value);
+        context.push("</div>");
+        index += 1;
+    }
+}
+
+/// Format a reference, as an entry in the reference list.
+///
+/// The style is like the Chicago author-date style:
+/// `Doe, Jane. 1999. “Title.” <em>Journal</em> 1 (2): 3–4.`
+fn reference_to_html(reference: &Reference, encode_html: bool) -> String {
+    let mut parts = vec![];
+
+    if let Some(author) = reference.author_long() {
+        parts.push(encode(&author, encode_html));
+    }
+
+    parts.push(encode(reference.year(), encode_html));
+
+    if let Some(ref container) = reference.container_title {
+        if let Some(ref title) = reference.title {
+            parts.push(format!("“{}.”", encode(title, encode_html)));
+        }
+
+        let mut value = format!("<em>{}</em>", encode(container, encode_html));
+
+        if let Some(ref volume) = reference.volume {
+            value.push(' ');
+            value.push_str(&encode(volume, encode_html));
+        }
+
+        if let Some(ref issue) = reference.issue {
+            value.push_str(" (");
+            value.push_str(&encode(issue, encode_html));
+            value.push(')');
+        }
+
+        if let Some(ref page) = reference.page {
+            value.push_str(": ");
+            value.push_str(&encode(page, encode_html));
+        }
+
+        parts.push(value);
+    } else if let Some(ref title) = reference.title {
+        parts.push(format!("<em>{}</em>", encode(title, encode_html)));
+    }
+
+    if let Some(ref publisher) = reference.publisher {
+        let mut value = String::new();
+
+        if let Some(ref place) = reference.publisher_place {
+            value.push_str(&encode(place, encode_html));
+            value.push_str(": ");
+        }
+
+        value.push_str(&encode(publisher, encode_html));
+        parts.push(value);
+    }
+
+    let url = reference
+        .doi
+        .as_ref()
+        .map(|doi| format!("https://doi.org/{}", doi))
+        .or_else(|| reference.url.clone());
+
+    if let Some(url) = url {
+        parts.push(format!(
+            "<a href=\"{}\">{}</a>",
+            sanitize(&url),
+            encode(&url, encode_html)
+        ));
+    }
+
+    let mut value = String::new();
+
+    for part in parts {
+        if !value.is_empty() {
+            value.push(' ');
+        }
+
+        value.push_str(&part);
+
+        if !part.ends_with('.') && !part.ends_with('”') {
+            value.push('.');
+        }
+    }
+
+    value
+}
+
 /// Generate a footnote item from a call.
 fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     let id = &context.gfm_footnote_definition_calls[index].0;
@@ -1623,14 +3912,20 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
 
     context.line_ending();
     context.push("<li id=\"");
+    context.push_id_prefix();
     if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
+        context.push_encoded(value);
     } else {
         context.push("user-content-");
     }
     context.push("fn-");
     context.push(&safe_id);
-    context.push("\">");
+    context.push("\"");
+    context.push_attributes("li", None, None);
+    if context.options.epub {
+        context.push(" epub:type=\"footnote\"");
+    }
+    context.push(">");
     context.line_ending();
 
     // Create one or more backreferences.
@@ -1641,6 +3936,9 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
             backreferences.push(' ');
         }
         backreferences.push_str("<a href=\"#");
+        if let Some(ref value) = context.options.id_prefix {
+            backreferences.push_str(&encode(value, context.encode_html));
+        }
         if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
             backreferences.push_str(&encode(value, context.encode_html));
         } else {
@@ -1658,7 +3956,12 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         } else {
             backreferences.push_str("Back to content");
         }
-        backreferences.push_str("\" class=\"data-footnote-backref\">↩");
+        backreferences.push('"');
+        backreferences.push_str(&context.attributes("a", Some("data-footnote-backref"), None));
+        if context.options.epub {
+            backreferences.push_str(" epub:type=\"backlink\"");
+        }
+        backreferences.push_str(">↩");
         if reference_index != 0 {
             backreferences.push_str("<sup>");
             backreferences.push_str(&(reference_index + 1).to_string());
@@ -1703,11 +4006,31 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     context.push("</li>");
 }
 
+/// Whether code (fenced) is highlighted with syntect.
+/// Whether HTML is dropped.
+fn strip_html(options: &CompileOptions) -> bool {
+    options.strip_html && !options.allow_dangerous_html
+}
+
+#[cfg(feature = "syntect")]
+fn syntax_highlight(options: &CompileOptions) -> bool {
+    options.syntax_highlight.is_some()
+}
+
+/// Whether code (fenced) is highlighted with syntect.
+#[cfg(not(feature = "syntect"))]
+fn syntax_highlight(_options: &CompileOptions) -> bool {
+    false
+}
+
 /// Generate an autolink (used by unicode autolinks and GFM autolink literals).
+///
+/// The text of the link is `label` if given, and `value` otherwise.
 fn generate_autolink(
     context: &mut CompileContext,
     protocol: Option<&str>,
     value: &str,
+    label: Option<&str>,
     is_gfm_literal: bool,
 ) {
     let mut is_in_link = false;
@@ -1721,27 +4044,66 @@ fn generate_autolink(
         index += 1;
     }
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    let mut is_link = !context.image_alt_inside && (!is_in_link || !is_gfm_literal);
+    let mut url = if let Some(protocol) = protocol {
+        format!("{}{}", protocol, value)
+    } else {
+        value.into()
+    };
+
+    if is_link {
+        if let Some(resolve) = &context.options.link_resolve {
+            if let Some(value) = (resolve.0)(&url, LinkKind::Autolink) {
+                url = value;
+            } else {
+                is_link = false;
+            }
+        }
+    }
+
+    let render = is_link && context.options.element_render.is_some();
+    let mut destination = String::new();
+
+    if render {
+        context.buffer();
+    }
+
+    if is_link {
         context.push("<a href=\"");
-        let url = if let Some(protocol) = protocol {
-            format!("{}{}", protocol, value)
-        } else {
-            value.into()
-        };
 
-        let url = if context.options.allow_dangerous_protocol {
-            sanitize(&url)
-        } else {
-            sanitize_with_protocols(&url, &SAFE_PROTOCOL_HREF)
-        };
+        destination = context.sanitize_url(&url, UrlKind::Link);
 
-        context.push(&url);
-        context.push("\">");
+        context.push(&destination);
+        context.push("\"");
+        context.push_link_attributes(&destination);
+        context.push_attributes("a", None, None);
+        context.push_sourcepos(
+            if is_gfm_literal {
+                context.index
+            } else {
+                skip::to(context.events, context.index, &[Name::Autolink])
+            },
+            true,
+        );
+        context.push(">");
     }
 
-    context.push(&encode(value, context.encode_html));
+    let children = encode(label.unwrap_or(value), context.encode_html);
+    context.push(&children);
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    if is_link {
         context.push("</a>");
     }
+
+    if render {
+        let html = context.resume();
+        context.push_element(
+            &Element::Link {
+                destination: Some(&destination),
+                title: None,
+                children: &children,
+            },
+            &html,
+        );
+    }
 }
@@ -0,0 +1,495 @@
+//! Turn a syntax tree into a string of roff, for man pages.
+//!
+//! This uses the `man` macros of groff (`.TH`, `.SH`, `.PP`, and so on).
+//! The first heading of rank 1 becomes the title (`.TH`), headings of rank 2
+//! become sections (`.SH`), and other headings become subsections (`.SS`).
+//! Like [ronn][], when that first heading looks like `name(1) -- description`,
+//! the name and section are used for the title, and a `NAME` section is added.
+//!
+//! Strong, emphasis, and code are turned into bold, italic, and constant width
+//! fonts.
+//! Links are turned into their text followed by their URL.
+//! HTML and other things that roff does not support are not included, except
+//! for their content, where they have phrasing content.
+//!
+//! [ronn]: https://github.com/apjanke/ronn-ng
+
+use crate::mdast::{AlignKind, Definition, Heading, Node};
+use crate::util::citation::serialize as citation_serialize;
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Context used to compile roff.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Definitions, to resolve link and image references with.
+    definitions: Vec<&'a Definition>,
+}
+
+/// Current font.
+#[derive(Clone, Copy, Debug, Default)]
+struct Font {
+    /// Whether in strong.
+    bold: bool,
+    /// Whether in emphasis.
+    italic: bool,
+    /// Whether in code.
+    code: bool,
+}
+
+impl Font {
+    /// Get the groff name of the font.
+    fn name(self) -> &'static str {
+        match (self.code, self.bold, self.italic) {
+            (true, true, _) => "CB",
+            (true, false, true) => "CI",
+            (true, false, false) => "CR",
+            (false, true, true) => "BI",
+            (false, true, false) => "B",
+            (false, false, true) => "I",
+            (false, false, false) => "R",
+        }
+    }
+}
+
+/// Turn a syntax tree into a string of roff.
+pub fn compile(tree: &Node) -> String {
+    let mut context = CompileContext {
+        definitions: Vec::new(),
+    };
+    collect_definitions(&mut context, tree);
+
+    let children = tree.children().map_or(&[][..], |d| d.as_slice());
+    let mut results: Vec<String> = Vec::new();
+    let mut rest = children;
+
+    if let Some((Node::Heading(heading), tail)) = children.split_first() {
+        if heading.depth == 1 {
+            results.push(title(&context, heading));
+            rest = tail;
+        }
+    }
+
+    let value = flow(&context, rest);
+
+    if !value.is_empty() {
+        results.push(value);
+    }
+
+    let mut value = results.join("\n");
+
+    if !value.is_empty() {
+        value.push('\n');
+    }
+
+    value
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Serialize the title (`.TH`), from the first heading.
+fn title(context: &CompileContext, heading: &Heading) -> String {
+    let text = to_text(&heading.children);
+    let (name, description) = match text.split_once(" -- ") {
+        Some((name, description)) => (name.trim(), Some(description.trim())),
+        None => (text.trim(), None),
+    };
+    let page = name.strip_suffix(')').and_then(|d| d.split_once('('));
+
+    if let Some((name, section)) = page {
+        let value = format!(".TH {} {}", quote(&name.to_uppercase()), quote(section));
+
+        if let Some(description) = description {
+            format!(
+                "{}\n.SH NAME\n{} \\- {}",
+                value,
+                escape(name),
+                escape(description)
+            )
+        } else {
+            value
+        }
+    } else {
+        format!(
+            ".TH {}",
+            quote_phrasing(&phrasing(context, &heading.children, Font::default()))
+        )
+    }
+}
+
+/// Serialize flow (block) children.
+fn flow(context: &CompileContext, children: &[Node]) -> String {
+    let mut results: Vec<String> = Vec::new();
+
+    for child in children {
+        if let Some(value) = block(context, child) {
+            results.push(value);
+        }
+    }
+
+    results.join("\n")
+}
+
+/// Serialize a flow (block) node.
+///
+/// Returns `None` for nodes that are not shown (such as definitions).
+fn block(context: &CompileContext, node: &Node) -> Option<String> {
+    match node {
+        Node::BlockQuote(x) => Some(format!(".RS\n{}\n.RE", flow(context, &x.children))),
+        Node::Code(x) => Some(preformatted(&x.value)),
+        Node::Math(x) => Some(preformatted(&x.value)),
+        Node::FootnoteDefinition(x) => Some(format!(
+            ".TP\n[{}]\n{}",
+            escape(x.label.as_ref().unwrap_or(&x.identifier)),
+            item(context, &x.children)
+        )),
+        Node::Heading(x) => {
+            let macro_name = if x.depth <= 2 { ".SH" } else { ".SS" };
+            Some(format!(
+                "{} {}",
+                macro_name,
+                quote_phrasing(&phrasing(context, &x.children, Font::default()))
+            ))
+        }
+        Node::List(x) => {
+            let mut number = x.start.unwrap_or(1);
+            let mut items: Vec<String> = Vec::new();
+
+            for child in &x.children {
+                let marker = if x.ordered {
+                    number += 1;
+                    format!(".IP \"{}.\" 4", number - 1)
+                } else {
+                    ".IP \\(bu 2".into()
+                };
+                let checked = match child {
+                    Node::ListItem(item) => match item.checked {
+                        Some(true) => "[x] ",
+                        Some(false) => "[ ] ",
+                        None => "",
+                    },
+                    _ => "",
+                };
+                let value = item(context, child.children().map_or(&[], |d| d.as_slice()));
+                items.push(format!("{}\n{}{}", marker, checked, value));
+            }
+
+            Some(items.join("\n"))
+        }
+        Node::ContainerDirective(x) => Some(flow(context, &x.children)),
+        Node::Div(x) => Some(flow(context, &x.children)),
+        Node::CustomBlock(x) => Some(flow(context, &x.children)),
+        Node::LeafDirective(x) => Some(format!(
+            ".PP\n{}",
+            phrasing(context, &x.children, Font::default())
+        )),
+        Node::ListItem(x) => Some(flow(context, &x.children)),
+        Node::MdxJsxFlowElement(x) => Some(flow(context, &x.children)),
+        Node::Root(x) => Some(flow(context, &x.children)),
+        Node::Paragraph(x) => Some(format!(
+            ".PP\n{}",
+            phrasing(context, &x.children, Font::default())
+        )),
+        Node::Table(x) => {
+            let mut lines = vec![".PP".to_string(), ".TS".into(), "tab(@);".into()];
+            let format = x
+                .align
+                .iter()
+                .map(|align| match align {
+                    AlignKind::Right => "r",
+                    AlignKind::Center => "c",
+                    AlignKind::Left | AlignKind::None => "l",
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(format!("{}.", format));
+
+            for (index, row) in x.children.iter().enumerate() {
+                let cells = row
+                    .children()
+                    .into_iter()
+                    .flatten()
+                    .map(|cell| {
+                        let font = Font {
+                            bold: index == 0,
+                            ..Font::default()
+                        };
+                        let value =
+                            phrasing(context, cell.children().map_or(&[], |d| d.as_slice()), font);
+                        if index == 0 {
+                            format!("T{{\n\\f[B]{}\\f[R]\nT}}", value)
+                        } else {
+                            format!("T{{\n{}\nT}}", value)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                lines.push(cells.join("@"));
+
+                if index == 0 {
+                    lines.push("_".into());
+                }
+            }
+
+            lines.push(".TE".into());
+            Some(lines.join("\n"))
+        }
+        Node::ThematicBreak(_) => Some(".PP\n.ce\n* * *".into()),
+        Node::AbbreviationDefinition(_)
+        | Node::AttributeList(_)
+        | Node::Definition(_)
+        | Node::Html(_)
+        | Node::MdxFlowExpression(_)
+        | Node::MdxjsEsm(_)
+        | Node::TableCell(_)
+        | Node::TableRow(_)
+        | Node::Toml(_)
+        | Node::Yaml(_) => None,
+        // Phrasing.
+        _ => Some(format!(
+            ".PP\n{}",
+            phrasing(context, core::slice::from_ref(node), Font::default())
+        )),
+    }
+}
+
+/// Serialize the children of a list item or footnote definition, after a
+/// `.IP` or `.TP` macro.
+///
+/// The first paragraph is the text of the item, further blocks are indented.
+fn item(context: &CompileContext, children: &[Node]) -> String {
+    let mut rest = children;
+    let mut results: Vec<String> = Vec::new();
+
+    if let Some((Node::Paragraph(paragraph), tail)) = children.split_first() {
+        results.push(phrasing(context, &paragraph.children, Font::default()));
+        rest = tail;
+    }
+
+    if !rest.is_empty() {
+        results.push(format!(".RS\n{}\n.RE", flow(context, rest)));
+    }
+
+    results.join("\n")
+}
+
+/// Serialize phrasing (inline) children, in `font`.
+fn phrasing(context: &CompileContext, children: &[Node], font: Font) -> String {
+    let mut result = String::new();
+
+    for child in children {
+        match child {
+            Node::Break(_) => result.push_str("\n.br\n"),
+            Node::Delete(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Emphasis(x) => {
+                let inner = Font {
+                    italic: true,
+                    ..font
+                };
+                result.push_str(&in_font(
+                    &phrasing(context, &x.children, inner),
+                    inner,
+                    font,
+                ));
+            }
+            Node::FootnoteReference(x) => {
+                result.push('[');
+                result.push_str(&escape(x.label.as_ref().unwrap_or(&x.identifier)));
+                result.push(']');
+            }
+            Node::Image(x) => result.push_str(&escape(&x.alt)),
+            Node::ImageReference(x) => result.push_str(&escape(&x.alt)),
+            Node::InlineCode(x) => {
+                let inner = Font { code: true, ..font };
+                result.push_str(&in_font(&escape(&x.value), inner, font));
+            }
+            Node::InlineMath(x) => {
+                let inner = Font { code: true, ..font };
+                result.push_str(&in_font(&escape(&x.value), inner, font));
+            }
+            Node::Link(x) => result.push_str(&link(
+                &x.url,
+                &to_text(&x.children),
+                &phrasing(context, &x.children, font),
+            )),
+            Node::LinkReference(x) => {
+                let text = phrasing(context, &x.children, font);
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    result.push_str(&link(&definition.url, &to_text(&x.children), &text));
+                } else {
+                    result.push_str(&text);
+                }
+            }
+            Node::MdxJsxTextElement(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Abbreviation(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Spoiler(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Span(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::CustomInline(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::TextDirective(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Insert(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Mark(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Subscript(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Superscript(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Underline(x) => result.push_str(&phrasing(context, &x.children, font)),
+            Node::Strong(x) => {
+                let inner = Font { bold: true, ..font };
+                result.push_str(&in_font(
+                    &phrasing(context, &x.children, inner),
+                    inner,
+                    font,
+                ));
+            }
+            Node::Text(x) => result.push_str(&escape(&x.value)),
+            Node::Citation(x) => result.push_str(&escape(&citation_serialize(x))),
+            Node::GithubReference(x) => result.push_str(&escape(&github_reference_serialize(x))),
+            Node::Mention(x) => result.push_str(&escape(&format!("@{}", x.name))),
+            Node::Ruby(x) => result.push_str(&escape(&format!("{}({})", x.base, x.text))),
+            Node::WikiEmbed(x) => result.push_str(&escape(x.alias.as_ref().unwrap_or(&x.target))),
+            Node::WikiLink(x) => result.push_str(&escape(x.alias.as_ref().unwrap_or(&x.target))),
+            Node::AttributeList(_)
+            | Node::BlockId(_)
+            | Node::CriticMarkupComment(_)
+            | Node::Html(_)
+            | Node::MdxTextExpression(_) => {}
+            Node::AbbreviationDefinition(_)
+            | Node::BlockQuote(_)
+            | Node::Code(_)
+            | Node::ContainerDirective(_)
+            | Node::Definition(_)
+            | Node::Div(_)
+            | Node::CustomBlock(_)
+            | Node::FootnoteDefinition(_)
+            | Node::Heading(_)
+            | Node::LeafDirective(_)
+            | Node::List(_)
+            | Node::ListItem(_)
+            | Node::Math(_)
+            | Node::MdxFlowExpression(_)
+            | Node::MdxJsxFlowElement(_)
+            | Node::MdxjsEsm(_)
+            | Node::Paragraph(_)
+            | Node::Root(_)
+            | Node::Table(_)
+            | Node::TableCell(_)
+            | Node::TableRow(_)
+            | Node::ThematicBreak(_)
+            | Node::Toml(_)
+            | Node::Yaml(_) => {
+                if let Some(value) = block(context, child) {
+                    result.push_str(&value);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Find the definition for `identifier`.
+fn find_definition<'a>(context: &CompileContext<'a>, identifier: &str) -> Option<&'a Definition> {
+    context
+        .definitions
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+        .copied()
+}
+
+/// Serialize `value` in `font`, switching back to `outer` after it.
+fn in_font(value: &str, font: Font, outer: Font) -> String {
+    format!("\\f[{}]{}\\f[{}]", font.name(), value, outer.name())
+}
+
+/// Serialize a link to `url` with `text` (as plain text) and `value` (as
+/// roff).
+///
+/// When the text is the same as the URL, or empty, it is not included.
+fn link(url: &str, text: &str, value: &str) -> String {
+    if text.is_empty() || text == url || format!("mailto:{}", text) == url {
+        escape(url)
+    } else {
+        format!("{} <{}>", value, escape(url))
+    }
+}
+
+/// Serialize a literal block.
+fn preformatted(value: &str) -> String {
+    format!(".IP\n.nf\n\\f[CR]\n{}\n\\f[R]\n.fi", escape(value))
+}
+
+/// Get the plain text of phrasing children.
+fn to_text(children: &[Node]) -> String {
+    let mut result = String::new();
+
+    for child in children {
+        match child {
+            Node::Image(x) => result.push_str(&x.alt),
+            Node::ImageReference(x) => result.push_str(&x.alt),
+            Node::InlineCode(x) => result.push_str(&x.value),
+            Node::InlineMath(x) => result.push_str(&x.value),
+            Node::Text(x) => result.push_str(&x.value),
+            _ => {
+                if let Some(children) = child.children() {
+                    result.push_str(&to_text(children));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Quote an argument to a macro.
+fn quote(value: &str) -> String {
+    quote_phrasing(&escape(value))
+}
+
+/// Quote serialized phrasing as an argument to a macro.
+///
+/// Macro arguments cannot span lines, so line endings are turned into spaces.
+fn quote_phrasing(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace("\n.br\n", " ")
+            .replace('\n', " ")
+            .replace('"', "\\(dq")
+    )
+}
+
+/// Escape the control characters of roff.
+///
+/// Backslashes and hyphens are escaped, and so are periods and apostrophes
+/// at the start of lines, which would otherwise start a macro.
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut at_line_start = true;
+
+    for char in value.chars() {
+        match char {
+            '\\' => result.push_str("\\e"),
+            '-' => result.push_str("\\-"),
+            '.' | '\'' if at_line_start => {
+                result.push_str("\\&");
+                result.push(char);
+            }
+            _ => result.push(char),
+        }
+
+        at_line_start = char == '\n';
+    }
+
+    result
+}
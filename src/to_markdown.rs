@@ -0,0 +1,1187 @@
+//! Turn a syntax tree into a string of markdown.
+//!
+//! The markdown is canonical: whatever way something was written, it is
+//! written in one way, such as `*` for emphasis, `**` for strong, `*` for
+//! bullets, ATX headings, and fenced code.
+//! Which way can be configured with [`FormatOptions`][].
+//! Text is escaped where it would otherwise be markdown, per `CommonMark`.
+//! Turning the result into a syntax tree again gives the same tree.
+
+use crate::configuration::FormatOptions;
+use crate::mdast::{
+    AlignKind, AttributeContent, AttributeValue, Definition, DestinationKind, Heading, List, Node,
+    ReferenceKind, Table, TitleKind,
+};
+use crate::util::citation::serialize as citation_serialize;
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Where text can be wrapped, when wrapping.
+///
+/// This is a noncharacter, which is for internal use: it is turned into a
+/// space or a line ending before the markdown is returned.
+const SOFT_BREAK: char = '\u{FFFF}';
+
+/// How to number the items of ordered lists.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{FormatOptions, ListNumbering};
+/// # fn main() {
+///
+/// // Use `1.` for all items (when the list starts at 1):
+/// let same = FormatOptions {
+///     list_numbering: ListNumbering::Same,
+///     ..FormatOptions::default()
+/// };
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ListNumbering {
+    /// Count up from the start of the list: `1.`, `2.`, `3.`.
+    #[default]
+    Increment,
+    /// Use the start of the list for all items: `1.`, `1.`, `1.`.
+    ///
+    /// This keeps diffs small when items are added or removed.
+    Same,
+}
+
+/// Context used to compile markdown.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Configuration.
+    options: &'a FormatOptions,
+    /// Definitions, to write the labels of references with.
+    definitions: Vec<&'a Definition>,
+}
+
+/// Turn a syntax tree into a string of markdown.
+pub fn compile(tree: &Node, options: &FormatOptions) -> String {
+    let mut context = CompileContext {
+        options,
+        definitions: Vec::new(),
+    };
+    collect_definitions(&mut context, tree);
+    let context = &context;
+    let mut result = block(context, tree)
+        .unwrap_or_default()
+        .replace(SOFT_BREAK, " ");
+
+    if !result.is_empty() {
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Serialize flow (block) children, separated by blank lines.
+fn flow(context: &CompileContext, children: &[Node], join: &str) -> String {
+    let mut results: Vec<String> = Vec::new();
+    // Whether the previous list used the other bullet.
+    let mut other = false;
+    let mut previous: Option<&List> = None;
+
+    for child in children {
+        let value = if let Node::List(list) = child {
+            // Adjacent lists of the same kind would be one list if they used
+            // the same bullet.
+            other = match previous {
+                Some(previous) if previous.ordered == list.ordered => !other,
+                _ => false,
+            };
+            previous = Some(list);
+            Some(self::list(context, list, other))
+        } else {
+            previous = None;
+            block(context, child)
+        };
+
+        if let Some(value) = value {
+            results.push(value);
+        }
+    }
+
+    results.join(join)
+}
+
+/// Serialize a flow (block) node.
+///
+/// Returns `None` for nodes that cannot be written.
+fn block(context: &CompileContext, node: &Node) -> Option<String> {
+    match node {
+        Node::BlockQuote(x) => {
+            let mut value = String::new();
+
+            if let Some(ref alert) = x.alert {
+                value.push_str("[!");
+                value.push_str(&alert.to_ascii_uppercase());
+                value.push(']');
+            }
+
+            let children = flow(context, &x.children, "\n\n");
+
+            if !value.is_empty() && !children.is_empty() {
+                value.push('\n');
+            }
+
+            value.push_str(&children);
+            Some(prefix_lines(&value, "> ", ">"))
+        }
+        Node::Code(x) => {
+            let mut info = x.lang.clone().unwrap_or_default();
+
+            if let Some(ref meta) = x.meta {
+                info.push(' ');
+                info.push_str(meta);
+            }
+
+            // Info cannot contain grave accents if the fence is made of them.
+            let marker = if context.options.fence == '`' && info.contains('`') {
+                '~'
+            } else {
+                context.options.fence
+            };
+            Some(fenced(
+                &x.value,
+                marker,
+                context.options.fence_length,
+                &info,
+            ))
+        }
+        Node::Math(x) => Some(fenced(
+            &x.value,
+            '$',
+            2,
+            x.meta.as_ref().map_or("", |d| d.as_str()),
+        )),
+        Node::FootnoteDefinition(x) => {
+            let value = flow(context, &x.children, "\n\n");
+            let value = prefix_lines(&value, "    ", "");
+            let value = value.strip_prefix("    ").unwrap_or(&value);
+            Some(
+                format!(
+                    "[^{}]: {}",
+                    label(x.label.as_ref().unwrap_or(&x.identifier)),
+                    value
+                )
+                .trim_end()
+                .into(),
+            )
+        }
+        Node::Heading(x) => Some(heading(context, x)),
+        // Blank lines at the end of HTML (flow) are part of it.
+        Node::Html(x) => Some(x.value.trim_end_matches(['\n', '\r']).into()),
+        Node::List(x) => Some(list(context, x, false)),
+        Node::ListItem(x) => Some(flow(
+            context,
+            &x.children,
+            if x.spread { "\n\n" } else { "\n" },
+        )),
+        Node::Root(x) => Some(flow(context, &x.children, "\n\n")),
+        Node::Paragraph(x) => {
+            let value = phrasing(context, &x.children, true);
+            Some(match context.options.line_width {
+                Some(width) => wrap_lines(&value, width),
+                None => value,
+            })
+        }
+        Node::Table(x) => Some(table(context, x)),
+        Node::ThematicBreak(_) => Some("***".into()),
+        Node::Definition(x) => Some(definition(x)),
+        Node::Yaml(x) => Some(format!("---\n{}\n---", x.value)),
+        Node::Toml(x) => Some(format!("+++\n{}\n+++", x.value)),
+        Node::MdxjsEsm(x) => Some(x.value.clone()),
+        Node::MdxFlowExpression(x) => Some(format!("{{{}}}", x.value)),
+        Node::MdxJsxFlowElement(x) => {
+            let open = jsx_open(x.name.as_ref(), &x.attributes, x.children.is_empty());
+
+            if x.children.is_empty() {
+                Some(open)
+            } else {
+                Some(format!(
+                    "{}\n{}\n</{}>",
+                    open,
+                    prefix_lines(&flow(context, &x.children, "\n\n"), "  ", ""),
+                    x.name.as_ref().map_or("", |d| d.as_str())
+                ))
+            }
+        }
+        Node::ContainerDirective(x) => {
+            let children = flow(context, &x.children, "\n\n");
+            let fence = ":".repeat(3.max(longest_sequence(&children, ':') + 1));
+            Some(format!(
+                "{}{}{}\n{}{}{}",
+                fence,
+                x.name,
+                attributes(&x.attributes, false),
+                children,
+                if children.is_empty() { "" } else { "\n" },
+                fence
+            ))
+        }
+        Node::LeafDirective(x) => Some(format!(
+            "::{}{}{}",
+            x.name,
+            directive_label(context, &x.children),
+            attributes(&x.attributes, false)
+        )),
+        Node::Div(x) => {
+            let children = flow(context, &x.children, "\n\n");
+            let fence = ":".repeat(3.max(longest_sequence(&children, ':') + 1));
+            Some(format!(
+                "{} {}\n{}{}{}",
+                fence,
+                attributes(&x.attributes, true),
+                children,
+                if children.is_empty() { "" } else { "\n" },
+                fence
+            ))
+        }
+        // Custom blocks are written with registered markers, which are not
+        // known here: use the content.
+        Node::CustomBlock(x) => Some(flow(context, &x.children, "\n\n")),
+        Node::AbbreviationDefinition(x) => Some(format!("*[{}]: {}", x.label, x.title)),
+        Node::AttributeList(x) => Some(attributes(&x.attributes, true)),
+        Node::TableRow(_) | Node::TableCell(_) => None,
+        // Phrasing.
+        _ => Some(phrasing(context, core::slice::from_ref(node), true)),
+    }
+}
+
+/// Serialize a heading.
+///
+/// Headings are ATX, unless they contain line endings or setext headings are
+/// preferred, in which case they are setext if they can be.
+fn heading(context: &CompileContext, heading: &Heading) -> String {
+    let value = phrasing(context, &heading.children, true);
+
+    if heading.depth < 3 && !value.is_empty() && (context.options.setext || value.contains('\n')) {
+        let size = value
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count();
+        let marker = if heading.depth == 1 { "=" } else { "-" };
+        return format!("{}\n{}", value, marker.repeat(size.max(3)));
+    }
+
+    let mut result = "#".repeat(usize::from(heading.depth));
+    let mut value = value.replace("\\\n", " ").replace('\n', " ");
+
+    // A closing sequence is not content.
+    if value.ends_with('#') {
+        value.pop();
+        value.push_str("\\#");
+    }
+
+    if !value.is_empty() {
+        result.push(' ');
+        result.push_str(&value);
+    }
+
+    result
+}
+
+/// Serialize a list.
+///
+/// Uses the other bullet (or the other delimiter, when ordered) if `other`.
+fn list(context: &CompileContext, list: &List, other: bool) -> String {
+    let spread = list.spread
+        || list
+            .children
+            .iter()
+            .any(|d| matches!(d, Node::ListItem(item) if item.spread));
+    let mut bullet = context.options.bullet;
+
+    if other {
+        bullet = if bullet == '*' { '-' } else { '*' };
+    }
+
+    // A thematic break right after an asterisk would be one thematic break.
+    if bullet == '*'
+        && list.children.iter().any(|d| match d {
+            Node::ListItem(item) => matches!(item.children.first(), Some(Node::ThematicBreak(_))),
+            _ => false,
+        })
+    {
+        bullet = '-';
+    }
+
+    let mut number = list.start.unwrap_or(1);
+    let mut items: Vec<String> = Vec::new();
+
+    for child in &list.children {
+        let marker = if list.ordered {
+            let marker = format!("{}{}", number, if other { ')' } else { '.' });
+
+            if context.options.list_numbering == ListNumbering::Increment {
+                number += 1;
+            }
+
+            marker
+        } else {
+            bullet.to_string()
+        };
+        let mut value = String::new();
+
+        if let Node::ListItem(item) = child {
+            match item.checked {
+                Some(true) => value.push_str("[x] "),
+                Some(false) => value.push_str("[ ] "),
+                None => {}
+            }
+        }
+
+        value.push_str(&block(context, child).unwrap_or_default());
+        let value = value.trim_end();
+
+        if value.is_empty() {
+            items.push(marker);
+        } else {
+            let indent = " ".repeat(marker.len() + 1);
+            let value = prefix_lines(value, &indent, "");
+            let value = value.strip_prefix(&indent).unwrap_or(&value);
+            items.push(format!("{} {}", marker, value));
+        }
+    }
+
+    items.join(if spread { "\n\n" } else { "\n" })
+}
+
+/// Serialize a GFM table.
+fn table(context: &CompileContext, table: &Table) -> String {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    for row in &table.children {
+        if let Node::TableRow(row) = row {
+            rows.push(
+                row.children
+                    .iter()
+                    .map(|cell| match cell {
+                        Node::TableCell(cell) => phrasing(context, &cell.children, false)
+                            .replace("\\\n", " ")
+                            .replace('\n', " ")
+                            .replace('|', "\\|"),
+                        _ => String::new(),
+                    })
+                    .collect(),
+            );
+        }
+    }
+
+    let columns = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(table.align.len());
+    let mut widths = vec![3; columns];
+
+    for row in &rows {
+        for (index, cell) in row.iter().enumerate() {
+            widths[index] = widths[index].max(cell.chars().count());
+        }
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let mut line = String::from("|");
+
+        for (column, width) in widths.iter().enumerate() {
+            let align = table.align.get(column).copied().unwrap_or(AlignKind::None);
+            let cell = row.get(column).map_or("", |d| d.as_str());
+            let size = width - cell.chars().count();
+            let before = match align {
+                AlignKind::Right => size,
+                AlignKind::Center => size / 2,
+                AlignKind::Left | AlignKind::None => 0,
+            };
+            line.push(' ');
+            line.push_str(&" ".repeat(before));
+            line.push_str(cell);
+            line.push_str(&" ".repeat(size - before));
+            line.push_str(" |");
+        }
+
+        lines.push(line);
+
+        // Delimiter row.
+        if index == 0 {
+            let mut line = String::from("|");
+
+            for (column, width) in widths.iter().enumerate() {
+                let align = table.align.get(column).copied().unwrap_or(AlignKind::None);
+                let (before, after) = match align {
+                    AlignKind::Left => (":", ""),
+                    AlignKind::Right => ("", ":"),
+                    AlignKind::Center => (":", ":"),
+                    AlignKind::None => ("", ""),
+                };
+                line.push(' ');
+                line.push_str(before);
+                line.push_str(&"-".repeat(width - before.len() - after.len()));
+                line.push_str(after);
+                line.push_str(" |");
+            }
+
+            lines.push(line);
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Serialize a definition.
+fn definition(definition: &Definition) -> String {
+    let mut result = format!(
+        "[{}]: {}",
+        label(definition.label.as_ref().unwrap_or(&definition.identifier)),
+        destination(&definition.url, definition.destination_kind, true)
+    );
+
+    if let Some(ref value) = definition.title {
+        result.push(' ');
+        result.push_str(&title(value, definition.title_kind));
+    }
+
+    result
+}
+
+/// Serialize phrasing (inline) children.
+///
+/// `start` is whether the children are at the start of a line.
+fn phrasing(context: &CompileContext, children: &[Node], start: bool) -> String {
+    let mut result = String::new();
+
+    for (index, child) in children.iter().enumerate() {
+        let at_start = (start && result.is_empty()) || result.ends_with('\n');
+
+        match child {
+            Node::Break(_) => result.push_str("\\\n"),
+            Node::Delete(x) => wrap(context, &mut result, "~~", &x.children),
+            Node::Emphasis(x) => {
+                let mut marker = context.options.emphasis;
+
+                // Emphasis in emphasis would be strong if they used the same
+                // marker.
+                if matches!(x.children.first(), Some(Node::Emphasis(_)))
+                    || matches!(x.children.last(), Some(Node::Emphasis(_)))
+                {
+                    marker = if marker == '*' { '_' } else { '*' };
+                }
+
+                if marker == '_' && intraword(&result, children.get(index + 1)) {
+                    marker = '*';
+                }
+
+                wrap(context, &mut result, &marker.to_string(), &x.children);
+            }
+            Node::Strong(x) => {
+                let mut marker = context.options.strong;
+
+                if marker == '_' && intraword(&result, children.get(index + 1)) {
+                    marker = '*';
+                }
+
+                wrap(
+                    context,
+                    &mut result,
+                    &marker.to_string().repeat(2),
+                    &x.children,
+                );
+            }
+            Node::Insert(x) => wrap(context, &mut result, "++", &x.children),
+            Node::Mark(x) => wrap(context, &mut result, "==", &x.children),
+            Node::Subscript(x) => wrap(context, &mut result, "~", &x.children),
+            Node::Superscript(x) => wrap(context, &mut result, "^", &x.children),
+            Node::Underline(x) => wrap(context, &mut result, "__", &x.children),
+            Node::FootnoteReference(x) => {
+                result.push_str("[^");
+                result.push_str(&label(x.label.as_ref().unwrap_or(&x.identifier)));
+                result.push(']');
+            }
+            Node::Html(x) => result.push_str(&x.value),
+            Node::Image(x) => {
+                result.push_str("![");
+                result.push_str(&escape(&x.alt, false));
+                result.push_str("](");
+                result.push_str(&destination(&x.url, x.destination_kind, false));
+
+                if let Some(ref value) = x.title {
+                    result.push(' ');
+                    result.push_str(&title(value, x.title_kind));
+                }
+
+                result.push(')');
+            }
+            Node::ImageReference(x) => {
+                let text = escape(&x.alt, false);
+                result.push_str("![");
+                result.push_str(&text);
+                result.push(']');
+                result.push_str(&reference(
+                    context,
+                    x.reference_kind,
+                    &x.identifier,
+                    x.label.as_ref(),
+                    &text,
+                ));
+            }
+            Node::InlineCode(x) => {
+                let sequence = "`".repeat(unused_sequence(&x.value, '`'));
+                // Code cannot start or end with a grave accent.
+                let pad = if x.value.starts_with('`') || x.value.ends_with('`') {
+                    " "
+                } else {
+                    ""
+                };
+                result.push_str(&sequence);
+                result.push_str(pad);
+                result.push_str(&x.value);
+                result.push_str(pad);
+                result.push_str(&sequence);
+            }
+            Node::InlineMath(x) => {
+                let sequence = "$".repeat(unused_sequence(&x.value, '$'));
+                result.push_str(&sequence);
+                result.push_str(&x.value);
+                result.push_str(&sequence);
+            }
+            Node::Link(x) => {
+                let text = phrasing(context, &x.children, false);
+
+                if autolink(&x.url, &x.children, x.title.as_ref()) {
+                    result.push('<');
+                    // Email autolinks do not have the protocol.
+                    result.push_str(match x.children.as_slice() {
+                        [Node::Text(text)] => &text.value,
+                        _ => &x.url,
+                    });
+                    result.push('>');
+                } else {
+                    bang(&mut result);
+                    result.push('[');
+                    result.push_str(&text);
+                    result.push_str("](");
+                    result.push_str(&destination(&x.url, x.destination_kind, false));
+
+                    if let Some(ref value) = x.title {
+                        result.push(' ');
+                        result.push_str(&title(value, x.title_kind));
+                    }
+
+                    result.push(')');
+                }
+            }
+            Node::LinkReference(x) => {
+                let text = phrasing(context, &x.children, false);
+                bang(&mut result);
+                result.push('[');
+                result.push_str(&text);
+                result.push(']');
+                result.push_str(&reference(
+                    context,
+                    x.reference_kind,
+                    &x.identifier,
+                    x.label.as_ref(),
+                    &text,
+                ));
+            }
+            Node::MdxJsxTextElement(x) => {
+                result.push_str(&jsx_open(
+                    x.name.as_ref(),
+                    &x.attributes,
+                    x.children.is_empty(),
+                ));
+
+                if !x.children.is_empty() {
+                    result.push_str(&phrasing(context, &x.children, false));
+                    result.push_str("</");
+                    result.push_str(x.name.as_ref().map_or("", |d| d.as_str()));
+                    result.push('>');
+                }
+            }
+            Node::MdxTextExpression(x) => {
+                result.push('{');
+                result.push_str(&x.value);
+                result.push('}');
+            }
+            Node::TextDirective(x) => {
+                result.push(':');
+                result.push_str(&x.name);
+                result.push_str(&directive_label(context, &x.children));
+                result.push_str(&attributes(&x.attributes, false));
+            }
+            Node::Span(x) => {
+                bang(&mut result);
+                result.push('[');
+                result.push_str(&phrasing(context, &x.children, false));
+                result.push(']');
+                result.push_str(&attributes(&x.attributes, false));
+            }
+            Node::Spoiler(x) => {
+                result.push_str(">!");
+                result.push_str(&phrasing(context, &x.children, false));
+                result.push_str("!<");
+            }
+            Node::CriticMarkupComment(x) => {
+                result.push_str("{>>");
+                result.push_str(&phrasing(context, &x.children, false));
+                result.push_str("<<}");
+            }
+            // Abbreviations are inferred from their definitions, and custom
+            // inlines are written with registered delimiters, which are not
+            // known here: use the content.
+            Node::Abbreviation(x) => result.push_str(&phrasing(context, &x.children, at_start)),
+            Node::CustomInline(x) => result.push_str(&phrasing(context, &x.children, at_start)),
+            Node::AttributeList(x) => result.push_str(&attributes(&x.attributes, true)),
+            Node::BlockId(x) => {
+                if !result.is_empty() && !result.ends_with([' ', '\t', '\n']) {
+                    result.push(' ');
+                }
+
+                result.push('^');
+                result.push_str(&x.value);
+            }
+            Node::Text(x) => {
+                let value = escape(&x.value, at_start);
+
+                // Line endings are reflowed when wrapping.
+                if context.options.line_width.is_some() {
+                    result.push_str(&value.replace([' ', '\n'], &SOFT_BREAK.to_string()));
+                } else {
+                    result.push_str(&value);
+                }
+            }
+            Node::Citation(x) => result.push_str(&citation_serialize(x)),
+            Node::GithubReference(x) => result.push_str(&github_reference_serialize(x)),
+            Node::Mention(x) => {
+                result.push('@');
+                result.push_str(&x.name);
+            }
+            Node::Ruby(x) => {
+                result.push('{');
+                result.push_str(&x.base);
+                result.push('|');
+                result.push_str(&x.text);
+                result.push('}');
+            }
+            Node::WikiEmbed(x) => {
+                result.push_str("![[");
+                result.push_str(&x.target);
+
+                if let Some(ref fragment) = x.fragment {
+                    result.push('#');
+                    result.push_str(fragment);
+                }
+
+                if let Some(ref alias) = x.alias {
+                    result.push('|');
+                    result.push_str(alias);
+                }
+
+                result.push_str("]]");
+            }
+            Node::WikiLink(x) => {
+                result.push_str("[[");
+                result.push_str(&x.target);
+
+                if let Some(ref alias) = x.alias {
+                    result.push('|');
+                    result.push_str(alias);
+                }
+
+                result.push_str("]]");
+            }
+            Node::AbbreviationDefinition(_)
+            | Node::BlockQuote(_)
+            | Node::Code(_)
+            | Node::ContainerDirective(_)
+            | Node::Definition(_)
+            | Node::Div(_)
+            | Node::CustomBlock(_)
+            | Node::FootnoteDefinition(_)
+            | Node::Heading(_)
+            | Node::LeafDirective(_)
+            | Node::List(_)
+            | Node::ListItem(_)
+            | Node::Math(_)
+            | Node::MdxFlowExpression(_)
+            | Node::MdxJsxFlowElement(_)
+            | Node::MdxjsEsm(_)
+            | Node::Paragraph(_)
+            | Node::Root(_)
+            | Node::Table(_)
+            | Node::TableCell(_)
+            | Node::TableRow(_)
+            | Node::ThematicBreak(_)
+            | Node::Toml(_)
+            | Node::Yaml(_) => {
+                if let Some(value) = block(context, child) {
+                    result.push_str(&value);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Push `children` to `result`, wrapped in `marker`.
+fn wrap(context: &CompileContext, result: &mut String, marker: &str, children: &[Node]) {
+    result.push_str(marker);
+    result.push_str(&phrasing(context, children, false));
+    result.push_str(marker);
+}
+
+/// Check whether something between the end of `result` and `next` is in a
+/// word, where underscores do not work.
+fn intraword(result: &str, next: Option<&Node>) -> bool {
+    result.chars().last().map_or(false, char::is_alphanumeric)
+        || matches!(next, Some(Node::Text(text)) if text.value.starts_with(char::is_alphanumeric))
+}
+
+/// Wrap a paragraph at `width`, where text had spaces or line endings.
+///
+/// Lines are not broken where the next word would be markdown at the start
+/// of a line.
+fn wrap_lines(value: &str, width: usize) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut column = 0;
+
+    for (index, word) in value.split(SOFT_BREAK).enumerate() {
+        if index > 0 {
+            let size = word.split('\n').next().unwrap_or_default().chars().count();
+
+            if column > 0 && column + 1 + size > width && !word.is_empty() && !markdown_start(word)
+            {
+                result.push('\n');
+                column = 0;
+            } else {
+                result.push(' ');
+                column += 1;
+            }
+        }
+
+        result.push_str(word);
+
+        if let Some(position) = word.rfind('\n') {
+            column = word[(position + 1)..].chars().count();
+        } else {
+            column += word.chars().count();
+        }
+    }
+
+    result
+}
+
+/// Check whether `value` would be (or could be) markdown at the start of a
+/// line, such as a heading, block quote, list item, or fence.
+fn markdown_start(value: &str) -> bool {
+    let digits = value.bytes().take_while(u8::is_ascii_digit).count();
+
+    value.starts_with([
+        '#', '>', '+', '-', '=', '*', '_', '|', '~', '`', '<', ':', '$',
+    ]) || (digits > 0 && matches!(value.as_bytes().get(digits), Some(b'.' | b')')))
+}
+
+/// Serialize fenced code (or math), with `marker` repeated at least `min`
+/// times, and more than it occurs in `value`.
+fn fenced(value: &str, marker: char, min: usize, info: &str) -> String {
+    let fence = marker
+        .to_string()
+        .repeat(min.max(longest_sequence(value, marker) + 1));
+    let mut result = fence.clone();
+    result.push_str(info);
+    result.push('\n');
+
+    if !value.is_empty() {
+        result.push_str(value);
+        result.push('\n');
+    }
+
+    result.push_str(&fence);
+    result
+}
+
+/// Serialize the reference part of a link or image reference.
+///
+/// References are matched by how their label is written, so the label of
+/// the definition is used, and collapsed and shortcut references become
+/// full if `text` is written differently.
+fn reference(
+    context: &CompileContext,
+    kind: ReferenceKind,
+    identifier: &str,
+    value: Option<&String>,
+    text: &str,
+) -> String {
+    let value = label(
+        context
+            .definitions
+            .iter()
+            .find(|definition| definition.identifier == identifier)
+            .and_then(|definition| definition.label.as_ref())
+            .or(value)
+            .map_or(identifier, |d| d.as_str()),
+    );
+
+    let same = fold(text) == fold(&value);
+
+    match kind {
+        ReferenceKind::Collapsed if same => "[]".into(),
+        ReferenceKind::Shortcut if same => String::new(),
+        _ => format!("[{}]", value),
+    }
+}
+
+/// Fold a label, like how they are matched: whitespace collapsed and case
+/// ignored.
+fn fold(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Serialize a label.
+fn label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// Escape a `!` at the end of `result`, as it would turn a link after it
+/// into an image.
+fn bang(result: &mut String) {
+    if result.ends_with('!') && !result.ends_with("\\!") {
+        result.pop();
+        result.push_str("\\!");
+    }
+}
+
+/// Serialize a destination.
+///
+/// Destinations that were enclosed in angle brackets (`kind`), that are
+/// empty (in definitions, if `definition`), or that contain whitespace,
+/// controls, angle brackets, or unbalanced parens, are enclosed in angle
+/// brackets.
+fn destination(url: &str, kind: DestinationKind, definition: bool) -> String {
+    let mut balance = 0_usize;
+    let mut balanced = true;
+
+    for byte in url.bytes() {
+        if byte == b'(' {
+            balance += 1;
+        } else if byte == b')' {
+            if balance == 0 {
+                balanced = false;
+            }
+
+            balance = balance.saturating_sub(1);
+        }
+    }
+
+    if kind == DestinationKind::Literal
+        || (url.is_empty() && definition)
+        || balance != 0
+        || !balanced
+        || url
+            .bytes()
+            .any(|d| d.is_ascii_whitespace() || d.is_ascii_control() || d == b'<' || d == b'>')
+    {
+        format!(
+            "<{}>",
+            url.replace('\\', "\\\\")
+                .replace('<', "\\<")
+                .replace('>', "\\>")
+        )
+    } else {
+        escape_references(&url.replace('\\', "\\\\"))
+    }
+}
+
+/// Serialize a title.
+fn title(value: &str, kind: Option<TitleKind>) -> String {
+    let value = escape_references(&value.replace('\\', "\\\\"));
+
+    match kind {
+        Some(TitleKind::Single) => format!("'{}'", value.replace('\'', "\\'")),
+        Some(TitleKind::Paren) => format!("({})", value.replace('(', "\\(").replace(')', "\\)")),
+        _ => format!("\"{}\"", value.replace('"', "\\\"")),
+    }
+}
+
+/// Check whether a link can be written as an autolink.
+fn autolink(url: &str, children: &[Node], title: Option<&String>) -> bool {
+    let text = match children {
+        [Node::Text(text)] => &text.value,
+        _ => return false,
+    };
+    let scheme = url.split(':').next().unwrap_or_default();
+
+    title.is_none()
+        && (text == url || url.strip_prefix("mailto:") == Some(text))
+        && url.len() > scheme.len()
+        && (2..=32).contains(&scheme.len())
+        && scheme.starts_with(|d: char| d.is_ascii_alphabetic())
+        && scheme
+            .bytes()
+            .all(|d| d.is_ascii_alphanumeric() || matches!(d, b'+' | b'-' | b'.'))
+        && !url
+            .bytes()
+            .any(|d| d.is_ascii_whitespace() || d.is_ascii_control() || d == b'<' || d == b'>')
+}
+
+/// Serialize the label of a directive, if there is one.
+fn directive_label(context: &CompileContext, children: &[Node]) -> String {
+    if children.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", phrasing(context, children, false))
+    }
+}
+
+/// Serialize attributes, if there are any (or always, if `always`), as an
+/// attribute list.
+fn attributes(attributes: &[(String, String)], always: bool) -> String {
+    if attributes.is_empty() && !always {
+        return String::new();
+    }
+
+    let mut result = String::from("{");
+
+    for (index, (name, value)) in attributes.iter().enumerate() {
+        if index > 0 {
+            result.push(' ');
+        }
+
+        if name == "id" && !value.is_empty() && !value.contains([' ', '"']) {
+            result.push('#');
+            result.push_str(value);
+        } else if name == "class" && !value.is_empty() && !value.contains('"') {
+            result.push('.');
+            result.push_str(&value.split_whitespace().collect::<Vec<_>>().join(" ."));
+        } else {
+            result.push_str(name);
+            result.push_str("=\"");
+            result.push_str(value);
+            result.push('"');
+        }
+    }
+
+    result.push('}');
+    result
+}
+
+/// Serialize the opening tag of a JSX element.
+fn jsx_open(name: Option<&String>, attributes: &[AttributeContent], close: bool) -> String {
+    let mut result = String::from("<");
+    result.push_str(name.map_or("", |d| d.as_str()));
+
+    for attribute in attributes {
+        result.push(' ');
+
+        match attribute {
+            AttributeContent::Expression { value, .. } => {
+                result.push('{');
+                result.push_str(value);
+                result.push('}');
+            }
+            AttributeContent::Property(property) => {
+                result.push_str(&property.name);
+
+                match property.value {
+                    Some(AttributeValue::Literal(ref value)) => {
+                        result.push_str("=\"");
+                        result.push_str(&value.replace('"', "&quot;"));
+                        result.push('"');
+                    }
+                    Some(AttributeValue::Expression(ref value)) => {
+                        result.push_str("={");
+                        result.push_str(&value.value);
+                        result.push('}');
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+
+    if close && name.is_some() {
+        result.push_str(" />");
+    } else {
+        result.push('>');
+    }
+
+    result
+}
+
+/// Get the size of the longest run of `marker` in `value`.
+fn longest_sequence(value: &str, marker: char) -> usize {
+    let mut longest = 0;
+    let mut size = 0;
+
+    for char in value.chars() {
+        if char == marker {
+            size += 1;
+            longest = longest.max(size);
+        } else {
+            size = 0;
+        }
+    }
+
+    longest
+}
+
+/// Get the smallest size of a run of `marker` that is not in `value`.
+fn unused_sequence(value: &str, marker: char) -> usize {
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut size = 0;
+
+    for char in value.chars().chain(core::iter::once('\0')) {
+        if char == marker {
+            size += 1;
+        } else if size > 0 {
+            sizes.push(size);
+            size = 0;
+        }
+    }
+
+    let mut size = 1;
+
+    while sizes.contains(&size) {
+        size += 1;
+    }
+
+    size
+}
+
+/// Prefix each line in `value` with `prefix`, or `empty` for empty lines.
+fn prefix_lines(value: &str, prefix: &str, empty: &str) -> String {
+    value
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                empty.to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escape `&` where it would start a character reference.
+fn escape_references(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for (index, char) in value.char_indices() {
+        if char == '&' && character_reference(&value[(index + 1)..]) {
+            result.push('\\');
+        }
+
+        result.push(char);
+    }
+
+    result
+}
+
+/// Check whether `value`, after a `&`, looks like the rest of a character
+/// reference.
+fn character_reference(value: &str) -> bool {
+    let value = value.strip_prefix('#').unwrap_or(value);
+    let size = value.bytes().take_while(u8::is_ascii_alphanumeric).count();
+    size > 0 && value.as_bytes().get(size) == Some(&b';')
+}
+
+/// Escape text, so that it is not markdown.
+///
+/// `start` is whether the text is at the start of a line.
+fn escape(value: &str, start: bool) -> String {
+    let bytes = value.as_bytes();
+    let mut result = String::with_capacity(value.len());
+    let mut line_start = start;
+
+    for (index, char) in value.char_indices() {
+        let escape = match char {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~' => true,
+            '&' => character_reference(&value[(index + 1)..]),
+            SOFT_BREAK => {
+                result.push_str("&#xFFFF;");
+                line_start = false;
+                continue;
+            }
+            // Initial whitespace is not content, and blank lines end
+            // paragraphs: encode them.
+            ' ' | '\t' | '\n' if line_start => {
+                result.push_str(match char {
+                    ' ' => "&#x20;",
+                    '\t' => "&#x9;",
+                    _ => "&#xA;",
+                });
+                line_start = false;
+                continue;
+            }
+            // Block quotes.
+            '>' => line_start,
+            // Headings, setext underlines, lists, and thematic breaks.
+            '#' | '+' | '-' | '=' if line_start => {
+                let after = bytes[index..]
+                    .iter()
+                    .position(|d| *d != bytes[index])
+                    .map(|d| bytes[index + d]);
+                matches!(after, None | Some(b'\t' | b'\n' | b' '))
+            }
+            // Ordered lists.
+            '.' | ')' => {
+                let before = &bytes[..index];
+                let digits = before
+                    .iter()
+                    .rev()
+                    .take_while(|d| d.is_ascii_digit())
+                    .count();
+                digits > 0
+                    && digits < 10
+                    && if digits == before.len() {
+                        start
+                    } else {
+                        before[before.len() - digits - 1] == b'\n'
+                    }
+                    && matches!(bytes.get(index + 1), None | Some(b'\t' | b'\n' | b' '))
+            }
+            _ => false,
+        };
+
+        if escape {
+            result.push('\\');
+        }
+
+        result.push(char);
+        line_start = char == '\n';
+    }
+
+    result
+}
@@ -0,0 +1,719 @@
+//! Turn a syntax tree into markdown-it tokens, as JSON.
+//!
+//! [markdown-it][] is a markdown parser in JavaScript.
+//! Its renderers and plugins work on a flat list of block tokens, where
+//! each inline token holds a list of child tokens.
+//! This generates that list, with the same fields (`type`, `tag`, `attrs`,
+//! `map`, `nesting`, `level`, `children`, `content`, `markup`, `info`,
+//! `meta`, `block`, and `hidden`), so that tokens can be compared to or
+//! rendered by markdown-it.
+//!
+//! Things that markdown-it does not support itself are turned into the tokens
+//! of well-known plugins (such as `footnote_ref`, `math_inline`, and
+//! `front_matter`), or left out.
+//!
+//! [markdown-it]: https://github.com/markdown-it/markdown-it
+
+use crate::mdast::{AlignKind, Definition, Node};
+use crate::unist::Position;
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// A markdown-it token.
+#[derive(Debug)]
+struct Token {
+    /// Type of token (such as `paragraph_open`).
+    kind: &'static str,
+    /// HTML tag name (such as `p`).
+    tag: String,
+    /// HTML attributes.
+    attrs: Vec<(String, String)>,
+    /// Source lines (0-indexed start, exclusive end), for block tokens.
+    map: Option<(usize, usize)>,
+    /// Whether this opens (`1`), closes (`-1`), or is self-closing (`0`).
+    nesting: i8,
+    /// Nesting level.
+    level: usize,
+    /// Children, for inline tokens.
+    children: Option<Vec<Token>>,
+    /// Content (such as the value of code).
+    content: String,
+    /// Markers (such as `*` or `` ``` ``).
+    markup: String,
+    /// Extra info (such as the info string of code (fenced)).
+    info: String,
+    /// Plugin data, as serialized JSON.
+    meta: Option<String>,
+    /// Whether this is a block token.
+    block: bool,
+    /// Whether to not render this token (paragraphs in tight lists).
+    hidden: bool,
+}
+
+impl Token {
+    /// Create a token.
+    fn new(kind: &'static str, tag: &str, nesting: i8) -> Token {
+        Token {
+            kind,
+            tag: tag.into(),
+            attrs: vec![],
+            map: None,
+            nesting,
+            level: 0,
+            children: None,
+            content: String::new(),
+            markup: String::new(),
+            info: String::new(),
+            meta: None,
+            block: false,
+            hidden: false,
+        }
+    }
+}
+
+/// Context used to compile tokens.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Source document.
+    value: &'a str,
+    /// Definitions, to resolve link and image references with.
+    definitions: Vec<&'a Definition>,
+    /// Block tokens.
+    tokens: Vec<Token>,
+    /// Current nesting level of block tokens.
+    level: usize,
+}
+
+impl CompileContext<'_> {
+    /// Add a block token.
+    fn push(&mut self, mut token: Token, position: Option<&Position>) {
+        token.block = true;
+        token.map = position.map(|d| (d.start.line - 1, d.end.line));
+
+        if token.nesting < 0 {
+            self.level -= 1;
+        }
+
+        token.level = self.level;
+
+        if token.nesting > 0 {
+            self.level += 1;
+        }
+
+        self.tokens.push(token);
+    }
+}
+
+/// Turn a syntax tree into a JSON string of markdown-it tokens.
+pub fn compile(tree: &Node, value: &str) -> String {
+    let mut context = CompileContext {
+        value,
+        definitions: vec![],
+        tokens: vec![],
+        level: 0,
+    };
+    collect_definitions(&mut context, tree);
+
+    if let Some(children) = tree.children() {
+        flow(&mut context, children, false);
+    }
+
+    serialize_tokens(&context.tokens)
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Turn flow (block) children into tokens.
+///
+/// Paragraphs are hidden when `tight`.
+fn flow(context: &mut CompileContext, children: &[Node], tight: bool) {
+    for child in children {
+        block(context, child, tight);
+    }
+}
+
+/// Turn a flow (block) node into tokens.
+fn block(context: &mut CompileContext, node: &Node, tight: bool) {
+    let position = node.position();
+
+    match node {
+        Node::BlockQuote(x) => {
+            let mut open = Token::new("blockquote_open", "blockquote", 1);
+            open.markup = ">".into();
+            context.push(open, position);
+            flow(context, &x.children, false);
+            let mut close = Token::new("blockquote_close", "blockquote", -1);
+            close.markup = ">".into();
+            context.push(close, None);
+        }
+        Node::Code(x) => {
+            let source = slice(context.value, position).trim_start();
+            let marker = source.chars().next().filter(|d| *d == '`' || *d == '~');
+            let mut token = if let Some(marker) = marker {
+                let mut token = Token::new("fence", "code", 0);
+                token.markup = source.chars().take_while(|d| *d == marker).collect();
+                token.info = info(x.lang.as_ref(), x.meta.as_ref());
+                token
+            } else {
+                Token::new("code_block", "code", 0)
+            };
+            token.content = lines(&x.value);
+            context.push(token, position);
+        }
+        Node::FootnoteDefinition(x) => {
+            let mut open = Token::new("footnote_open", "", 1);
+            open.meta = Some(format!(
+                "{{\"label\":{}}}",
+                string(x.label.as_ref().unwrap_or(&x.identifier))
+            ));
+            context.push(open, position);
+            flow(context, &x.children, false);
+            context.push(Token::new("footnote_close", "", -1), None);
+        }
+        Node::Heading(x) => {
+            let tag = format!("h{}", x.depth);
+            let source = slice(context.value, position);
+            let markup = if source.trim_start().starts_with('#') {
+                "#".repeat(x.depth.into())
+            } else if x.depth == 1 {
+                "=".into()
+            } else {
+                "-".into()
+            };
+            let mut open = Token::new("heading_open", &tag, 1);
+            open.markup.clone_from(&markup);
+            context.push(open, position);
+            inline(context, &x.children, position);
+            let mut close = Token::new("heading_close", &tag, -1);
+            close.markup = markup;
+            context.push(close, None);
+        }
+        Node::Html(x) => {
+            let mut token = Token::new("html_block", "", 0);
+            token.content = lines(&x.value);
+            context.push(token, position);
+        }
+        Node::List(x) => {
+            let (open_kind, close_kind, tag) = if x.ordered {
+                ("ordered_list_open", "ordered_list_close", "ol")
+            } else {
+                ("bullet_list_open", "bullet_list_close", "ul")
+            };
+            let first = x
+                .children
+                .first()
+                .map_or("", |d| slice(context.value, d.position()).trim_start());
+            let number_size = first.bytes().take_while(u8::is_ascii_digit).count();
+            let markup: String = first[number_size..].chars().take(1).collect();
+
+            let mut open = Token::new(open_kind, tag, 1);
+            open.markup.clone_from(&markup);
+            if let Some(start) = x.start.filter(|d| *d != 1) {
+                open.attrs.push(("start".into(), start.to_string()));
+            }
+            context.push(open, position);
+
+            let mut number = x.start.unwrap_or(1);
+
+            for child in &x.children {
+                let mut open = Token::new("list_item_open", "li", 1);
+                open.markup.clone_from(&markup);
+                if x.ordered {
+                    open.info = number.to_string();
+                    number += 1;
+                }
+                context.push(open, child.position());
+                if let Some(children) = child.children() {
+                    flow(context, children, !x.spread);
+                }
+                let mut close = Token::new("list_item_close", "li", -1);
+                close.markup.clone_from(&markup);
+                context.push(close, None);
+            }
+
+            let mut close = Token::new(close_kind, tag, -1);
+            close.markup = markup;
+            context.push(close, None);
+        }
+        Node::Math(x) => {
+            let mut token = Token::new("math_block", "math", 0);
+            token.markup = "$$".into();
+            token.content = lines(&x.value);
+            context.push(token, position);
+        }
+        Node::Paragraph(x) => {
+            let mut open = Token::new("paragraph_open", "p", 1);
+            open.hidden = tight;
+            context.push(open, position);
+            inline(context, &x.children, position);
+            let mut close = Token::new("paragraph_close", "p", -1);
+            close.hidden = tight;
+            context.push(close, None);
+        }
+        Node::Table(x) => {
+            context.push(Token::new("table_open", "table", 1), position);
+
+            for (index, row) in x.children.iter().enumerate() {
+                if index == 0 {
+                    context.push(Token::new("thead_open", "thead", 1), row.position());
+                } else if index == 1 {
+                    let map = position.zip(row.position()).map(|(table, row)| Position {
+                        start: row.start.clone(),
+                        end: table.end.clone(),
+                    });
+                    context.push(Token::new("tbody_open", "tbody", 1), map.as_ref());
+                }
+
+                let (open_kind, close_kind, tag) = if index == 0 {
+                    ("th_open", "th_close", "th")
+                } else {
+                    ("td_open", "td_close", "td")
+                };
+                context.push(Token::new("tr_open", "tr", 1), row.position());
+
+                for (column, cell) in row.children().into_iter().flatten().enumerate() {
+                    let mut open = Token::new(open_kind, tag, 1);
+                    let align = match x.align.get(column) {
+                        Some(AlignKind::Left) => "left",
+                        Some(AlignKind::Right) => "right",
+                        Some(AlignKind::Center) => "center",
+                        Some(AlignKind::None) | None => "",
+                    };
+                    if !align.is_empty() {
+                        open.attrs
+                            .push(("style".into(), format!("text-align:{}", align)));
+                    }
+                    context.push(open, cell.position());
+                    inline(
+                        context,
+                        cell.children().map_or(&[], |d| d.as_slice()),
+                        cell.position(),
+                    );
+                    context.push(Token::new(close_kind, tag, -1), None);
+                }
+
+                context.push(Token::new("tr_close", "tr", -1), None);
+
+                if index == 0 {
+                    context.push(Token::new("thead_close", "thead", -1), None);
+                }
+            }
+
+            if x.children.len() > 1 {
+                context.push(Token::new("tbody_close", "tbody", -1), None);
+            }
+
+            context.push(Token::new("table_close", "table", -1), None);
+        }
+        Node::ThematicBreak(_) => {
+            let mut token = Token::new("hr", "hr", 0);
+            token.markup = slice(context.value, position)
+                .chars()
+                .filter(|d| !d.is_whitespace())
+                .collect();
+            context.push(token, position);
+        }
+        Node::Toml(x) => front_matter(context, "+++", &x.value, position),
+        Node::Yaml(x) => front_matter(context, "---", &x.value, position),
+        // Not in markdown-it: use the content.
+        Node::ContainerDirective(x) => flow(context, &x.children, false),
+        Node::Div(x) => flow(context, &x.children, false),
+        Node::CustomBlock(x) => flow(context, &x.children, false),
+        // Other nodes have no tokens.
+        _ => {}
+    }
+}
+
+/// Add a frontmatter token.
+fn front_matter(
+    context: &mut CompileContext,
+    markup: &str,
+    value: &str,
+    position: Option<&Position>,
+) {
+    let mut token = Token::new("front_matter", "", 0);
+    token.markup = markup.into();
+    token.meta = Some(string(value));
+    context.push(token, position);
+}
+
+/// Turn phrasing (inline) children into an inline token.
+fn inline(context: &mut CompileContext, children: &[Node], position: Option<&Position>) {
+    let start = children.first().and_then(Node::position);
+    let end = children.last().and_then(Node::position);
+    let value = match (start, end) {
+        (Some(start), Some(end)) => strip_prefixes(
+            &context.value[start.start.offset..end.end.offset],
+            start.start.column,
+        ),
+        _ => String::new(),
+    };
+    let mut tokens = vec![];
+    let mut level = 0;
+    phrasing(context, children, &mut tokens, &mut level);
+    let mut token = Token::new("inline", "", 0);
+    token.content = value;
+    token.children = Some(tokens);
+    context.push(token, position);
+}
+
+/// Turn phrasing (inline) children into tokens.
+fn phrasing(
+    context: &CompileContext,
+    children: &[Node],
+    tokens: &mut Vec<Token>,
+    level: &mut usize,
+) {
+    for child in children {
+        let source = slice(context.value, child.position());
+
+        match child {
+            Node::Break(_) => push(tokens, level, Token::new("hardbreak", "br", 0)),
+            Node::Delete(x) => {
+                let markup: String = source.chars().take_while(|d| *d == '~').collect();
+                wrap(
+                    context,
+                    tokens,
+                    level,
+                    ("s_open", "s_close", "s"),
+                    &markup,
+                    &x.children,
+                );
+            }
+            Node::Emphasis(x) => {
+                let markup: String = source.chars().take(1).collect();
+                wrap(
+                    context,
+                    tokens,
+                    level,
+                    ("em_open", "em_close", "em"),
+                    &markup,
+                    &x.children,
+                );
+            }
+            Node::FootnoteReference(x) => {
+                let mut token = Token::new("footnote_ref", "", 0);
+                token.meta = Some(format!(
+                    "{{\"label\":{}}}",
+                    string(x.label.as_ref().unwrap_or(&x.identifier))
+                ));
+                push(tokens, level, token);
+            }
+            Node::Html(x) => {
+                let mut token = Token::new("html_inline", "", 0);
+                token.content.clone_from(&x.value);
+                push(tokens, level, token);
+            }
+            Node::Image(x) => image(tokens, level, &x.url, x.title.as_ref(), &x.alt),
+            Node::ImageReference(x) => {
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    image(
+                        tokens,
+                        level,
+                        &definition.url,
+                        definition.title.as_ref(),
+                        &x.alt,
+                    );
+                } else {
+                    text(tokens, level, &x.alt);
+                }
+            }
+            Node::InlineCode(x) => {
+                let mut token = Token::new("code_inline", "code", 0);
+                token.markup = source.chars().take_while(|d| *d == '`').collect();
+                token.content.clone_from(&x.value);
+                push(tokens, level, token);
+            }
+            Node::InlineMath(x) => {
+                let mut token = Token::new("math_inline", "math", 0);
+                token.markup = source.chars().take_while(|d| *d == '$').collect();
+                token.content.clone_from(&x.value);
+                push(tokens, level, token);
+            }
+            Node::Link(x) => {
+                let mut open = Token::new("link_open", "a", 1);
+                link_attrs(&mut open, &x.url, x.title.as_ref());
+                if source.starts_with('<') {
+                    open.markup = "autolink".into();
+                    open.info = "auto".into();
+                } else if !source.starts_with('[') {
+                    open.markup = "linkify".into();
+                    open.info = "auto".into();
+                }
+                let markup = open.markup.clone();
+                let info = open.info.clone();
+                push(tokens, level, open);
+                phrasing(context, &x.children, tokens, level);
+                let mut close = Token::new("link_close", "a", -1);
+                close.markup = markup;
+                close.info = info;
+                push(tokens, level, close);
+            }
+            Node::LinkReference(x) => {
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    let mut open = Token::new("link_open", "a", 1);
+                    link_attrs(&mut open, &definition.url, definition.title.as_ref());
+                    push(tokens, level, open);
+                    phrasing(context, &x.children, tokens, level);
+                    push(tokens, level, Token::new("link_close", "a", -1));
+                } else {
+                    phrasing(context, &x.children, tokens, level);
+                }
+            }
+            Node::Strong(x) => {
+                let markup: String = source.chars().take(2).collect();
+                wrap(
+                    context,
+                    tokens,
+                    level,
+                    ("strong_open", "strong_close", "strong"),
+                    &markup,
+                    &x.children,
+                );
+            }
+            Node::Text(x) => {
+                for (index, line) in x.value.split('\n').enumerate() {
+                    if index > 0 {
+                        push(tokens, level, Token::new("softbreak", "br", 0));
+                    }
+                    if !line.is_empty() {
+                        text(tokens, level, line);
+                    }
+                }
+            }
+            // Not in markdown-it: use the text.
+            Node::GithubReference(x) => text(tokens, level, &github_reference_serialize(x)),
+            Node::Mention(x) => text(tokens, level, &format!("@{}", x.name)),
+            Node::Ruby(x) => text(tokens, level, &format!("{}({})", x.base, x.text)),
+            Node::WikiLink(x) => text(tokens, level, x.alias.as_ref().unwrap_or(&x.target)),
+            // Not in markdown-it: use the content.
+            Node::Abbreviation(_)
+            | Node::MdxJsxTextElement(_)
+            | Node::Spoiler(_)
+            | Node::Span(_)
+            | Node::CustomInline(_)
+            | Node::Insert(_)
+            | Node::Mark(_)
+            | Node::Subscript(_)
+            | Node::Superscript(_)
+            | Node::TextDirective(_)
+            | Node::Underline(_) => {
+                if let Some(children) = child.children() {
+                    phrasing(context, children, tokens, level);
+                }
+            }
+            // Other nodes have no tokens.
+            _ => {}
+        }
+    }
+}
+
+/// Add an inline token.
+fn push(tokens: &mut Vec<Token>, level: &mut usize, mut token: Token) {
+    if token.nesting < 0 {
+        *level -= 1;
+    }
+
+    token.level = *level;
+
+    if token.nesting > 0 {
+        *level += 1;
+    }
+
+    tokens.push(token);
+}
+
+/// Add a text token.
+fn text(tokens: &mut Vec<Token>, level: &mut usize, value: &str) {
+    let mut token = Token::new("text", "", 0);
+    token.content = value.into();
+    push(tokens, level, token);
+}
+
+/// Add an image token.
+fn image(tokens: &mut Vec<Token>, level: &mut usize, url: &str, title: Option<&String>, alt: &str) {
+    let mut token = Token::new("image", "img", 0);
+    token.attrs.push(("src".into(), url.into()));
+    token.attrs.push(("alt".into(), String::new()));
+    if let Some(title) = title {
+        token.attrs.push(("title".into(), title.clone()));
+    }
+    token.content = alt.into();
+    let mut children = vec![];
+    let mut child_level = 0;
+    if !alt.is_empty() {
+        text(&mut children, &mut child_level, alt);
+    }
+    token.children = Some(children);
+    push(tokens, level, token);
+}
+
+/// Add open and close tokens around phrasing children.
+fn wrap(
+    context: &CompileContext,
+    tokens: &mut Vec<Token>,
+    level: &mut usize,
+    (open, close, tag): (&'static str, &'static str, &str),
+    markup: &str,
+    children: &[Node],
+) {
+    let mut token = Token::new(open, tag, 1);
+    token.markup = markup.into();
+    push(tokens, level, token);
+    phrasing(context, children, tokens, level);
+    let mut token = Token::new(close, tag, -1);
+    token.markup = markup.into();
+    push(tokens, level, token);
+}
+
+/// Add `href` and `title` attributes to a link token.
+fn link_attrs(token: &mut Token, url: &str, title: Option<&String>) {
+    token.attrs.push(("href".into(), url.into()));
+    if let Some(title) = title {
+        token.attrs.push(("title".into(), title.clone()));
+    }
+}
+
+/// Find the definition for `identifier`.
+fn find_definition<'a>(context: &CompileContext<'a>, identifier: &str) -> Option<&'a Definition> {
+    context
+        .definitions
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+        .copied()
+}
+
+/// Get the source of `position`.
+fn slice<'a>(value: &'a str, position: Option<&Position>) -> &'a str {
+    position.map_or("", |d| &value[d.start.offset..d.end.offset])
+}
+
+/// Remove container prefixes (such as `> `) from lines after the first.
+///
+/// Up to `column - 1` characters are removed, but only whitespace and
+/// greater than signs, as lazy lines have no prefix.
+fn strip_prefixes(value: &str, column: usize) -> String {
+    value
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 {
+                line
+            } else {
+                let size = line
+                    .bytes()
+                    .take(column - 1)
+                    .take_while(|d| matches!(d, b'\t' | b' ' | b'>'))
+                    .count();
+                &line[size..]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Turn the value of code into markdown-it content, which ends in a line
+/// ending.
+fn lines(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", value)
+    }
+}
+
+/// Turn the language and meta of code into an info string.
+fn info(lang: Option<&String>, meta: Option<&String>) -> String {
+    match (lang, meta) {
+        (Some(lang), Some(meta)) => format!("{} {}", lang, meta),
+        (Some(lang), None) => lang.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Serialize tokens as a JSON array.
+fn serialize_tokens(tokens: &[Token]) -> String {
+    let values = tokens.iter().map(serialize_token).collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+/// Serialize a token as a JSON object.
+fn serialize_token(token: &Token) -> String {
+    let attrs = if token.attrs.is_empty() {
+        "null".into()
+    } else {
+        let attrs = token
+            .attrs
+            .iter()
+            .map(|(key, value)| format!("[{},{}]", string(key), string(value)))
+            .collect::<Vec<_>>();
+        format!("[{}]", attrs.join(","))
+    };
+    let map = token
+        .map
+        .map_or("null".into(), |(start, end)| format!("[{},{}]", start, end));
+    let children = token
+        .children
+        .as_ref()
+        .map_or("null".into(), |d| serialize_tokens(d));
+
+    format!(
+        "{{\"type\":{},\"tag\":{},\"attrs\":{},\"map\":{},\"nesting\":{},\"level\":{},\"children\":{},\"content\":{},\"markup\":{},\"info\":{},\"meta\":{},\"block\":{},\"hidden\":{}}}",
+        string(token.kind),
+        string(&token.tag),
+        attrs,
+        map,
+        token.nesting,
+        token.level,
+        children,
+        string(&token.content),
+        string(&token.markup),
+        string(&token.info),
+        token.meta.as_deref().unwrap_or("null"),
+        token.block,
+        token.hidden
+    )
+}
+
+/// Hexadecimal digits.
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Serialize a string as JSON.
+fn string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            char if (char as u32) < 0x20 => {
+                let code = char as u8;
+                result.push_str("\\u00");
+                result.push(char::from(HEX[usize::from(code >> 4)]));
+                result.push(char::from(HEX[usize::from(code & 0x0F)]));
+            }
+            char => result.push(char),
+        }
+    }
+
+    result.push('"');
+    result
+}
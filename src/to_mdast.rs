@@ -2,24 +2,42 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::mdast::{
-    AttributeContent, AttributeValue, AttributeValueExpression, BlockQuote, Break, Code,
-    Definition, Delete, Emphasis, FootnoteDefinition, FootnoteReference, Heading, Html, Image,
-    ImageReference, InlineCode, InlineMath, Link, LinkReference, List, ListItem, Math,
+    Abbreviation, AbbreviationDefinition, AttributeContent, AttributeList, AttributeValue,
+    AttributeValueExpression, BlockId, BlockQuote, Break, Code, ContainerDirective,
+    CriticMarkupComment, CustomBlock, CustomInline, Definition, Delete, DestinationKind, Div,
+    Emphasis, FootnoteDefinition, FootnoteReference, Heading, Html, Image, ImageReference,
+    InlineCode, InlineMath, Insert, LeafDirective, Link, LinkReference, List, ListItem, Mark, Math,
     MdxFlowExpression, MdxJsxAttribute, MdxJsxFlowElement, MdxJsxTextElement, MdxTextExpression,
-    MdxjsEsm, Node, Paragraph, ReferenceKind, Root, Strong, Table, TableCell, TableRow, Text,
-    ThematicBreak, Toml, Yaml,
+    MdxjsEsm, Mention, Node, Paragraph, ReferenceKind, Root, Ruby, Span, Spoiler, Strong,
+    Subscript, Superscript, Table, TableCell, TableRow, Text, TextDirective, ThematicBreak,
+    TitleKind, Toml, Underline, WikiEmbed, WikiLink, Yaml,
 };
 use crate::message;
 use crate::unist::{Point, Position};
 use crate::util::{
+    abbreviation::{definitions as abbreviation_definitions, label_and_title},
+    attribute_list::attributes as attribute_list_attributes,
+    bracketed_span::attributes as bracketed_span_attributes,
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
+    citation::citation as citation_from_events,
+    custom_block::custom_block,
+    custom_inline::custom_inline,
+    directive::{container_label, name_and_attributes as directive_name_and_attributes},
+    fenced_div::attributes as fenced_div_attributes,
+    gfm_alert::alert_type as gfm_alert_type,
+    github_reference::reference as github_reference,
     infer::{gfm_table_align, list_item_loose, list_loose},
     mdx_collect::{collect, Result as CollectResult},
+    mention::name as mention_name,
     normalize_identifier::normalize_identifier,
+    ruby::base_and_text as ruby_base_and_text,
+    skip,
     slice::{Position as SlicePosition, Slice},
+    wiki_link::{parts as wiki_link_parts, target_and_alias as wiki_link_target_and_alias},
 };
+use crate::ParseOptions;
 use alloc::{
     boxed::Box,
     format,
@@ -90,6 +108,10 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Configuration of the parser.
+    parse_options: &'a ParseOptions,
+    /// Labels and titles of abbreviation definitions.
+    abbreviations: Vec<(String, String)>,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     character_reference_marker: u8,
@@ -109,7 +131,11 @@ struct CompileContext<'a> {
 
 impl<'a> CompileContext<'a> {
     /// Create a new compile context.
-    fn new(events: &'a [Event], bytes: &'a [u8]) -> CompileContext<'a> {
+    fn new(
+        events: &'a [Event],
+        bytes: &'a [u8],
+        parse_options: &'a ParseOptions,
+    ) -> CompileContext<'a> {
         let tree = Node::Root(Root {
             children: vec![],
             position: Some(Position {
@@ -129,6 +155,8 @@ impl<'a> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            parse_options,
+            abbreviations: abbreviation_definitions(events, bytes),
             character_reference_marker: 0,
             gfm_table_inside: false,
             hard_break_after: false,
@@ -225,8 +253,12 @@ impl<'a> CompileContext<'a> {
 }
 
 /// Turn events and bytes into a syntax tree.
-pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, message::Message> {
-    let mut context = CompileContext::new(events, bytes);
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    parse_options: &ParseOptions,
+) -> Result<Node, message::Message> {
+    let mut context = CompileContext::new(events, bytes, parse_options);
 
     let mut index = 0;
     while index < events.len() {
@@ -262,7 +294,7 @@ fn handle(context: &mut CompileContext, index: usize) -> Result<(), message::Mes
 fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
     match context.events[context.index].name {
         Name::AutolinkEmail
-        | Name::AutolinkProtocol
+        | Name::AutolinkLabel
         | Name::CharacterEscapeValue
         | Name::CharacterReference
         | Name::CodeFlowChunk
@@ -286,13 +318,28 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
         | Name::ReferenceString
         | Name::ResourceDestinationString
         | Name::ResourceTitleString => on_enter_buffer(context),
+        Name::Abbreviation => on_enter_abbreviation(context),
+        Name::AbbreviationDefinition => on_enter_abbreviation_definition(context),
+        Name::AttributeList => on_enter_attribute_list(context),
+        Name::BlockId => on_enter_block_id(context),
         Name::Autolink => on_enter_autolink(context),
+        Name::AutolinkProtocol => on_enter_autolink_protocol(context),
         Name::BlockQuote => on_enter_block_quote(context),
+        Name::BracketedSpan => on_enter_bracketed_span(context),
         Name::CodeFenced => on_enter_code_fenced(context),
         Name::CodeIndented => on_enter_code_indented(context),
         Name::CodeText => on_enter_code_text(context),
         Name::Definition => on_enter_definition(context),
+        Name::DefinitionDestinationLiteral | Name::ResourceDestinationLiteral => {
+            on_enter_destination_literal(context);
+        }
+        Name::DefinitionTitle | Name::ResourceTitle => on_enter_title(context),
+        Name::DirectiveContainer => on_enter_directive_container(context),
+        Name::DirectiveLabelString => on_enter_directive_label_string(context),
+        Name::DirectiveLeaf => on_enter_directive_leaf(context),
+        Name::DirectiveText => on_enter_directive_text(context),
         Name::Emphasis => on_enter_emphasis(context),
+        Name::FencedDiv => on_enter_fenced_div(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -301,7 +348,7 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
         | Name::GfmAutolinkLiteralXmpp => on_enter_gfm_autolink_literal(context),
         Name::GfmFootnoteCall => on_enter_gfm_footnote_call(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
-        Name::GfmStrikethrough => on_enter_gfm_strikethrough(context),
+        Name::GfmStrikethrough | Name::CriticMarkupDeletion => on_enter_gfm_strikethrough(context),
         Name::GfmTable => on_enter_gfm_table(context),
         Name::GfmTableRow => on_enter_gfm_table_row(context),
         Name::GfmTableCell => on_enter_gfm_table_cell(context),
@@ -328,8 +375,24 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
         Name::Paragraph => on_enter_paragraph(context),
         Name::Reference => on_enter_reference(context),
         Name::Resource => on_enter_resource(context),
+        Name::Spoiler => on_enter_spoiler(context),
         Name::Strong => on_enter_strong(context),
+        Name::Insert | Name::CriticMarkupAddition => on_enter_insert(context),
+        Name::Mark | Name::CriticMarkupHighlight => on_enter_mark(context),
+        Name::CriticMarkupComment => on_enter_critic_markup_comment(context),
+        Name::CriticMarkupText => on_enter_critic_markup_text(context),
+        Name::CustomBlock => on_enter_custom_block(context),
+        Name::CustomInline => on_enter_custom_inline(context),
+        Name::Subscript => on_enter_subscript(context),
+        Name::Superscript => on_enter_superscript(context),
+        Name::Underline => on_enter_underline(context),
         Name::ThematicBreak => on_enter_thematic_break(context),
+        Name::Citation => on_enter_citation(context),
+        Name::GithubReference => on_enter_github_reference(context),
+        Name::Mention => on_enter_mention(context),
+        Name::Ruby => on_enter_ruby(context),
+        Name::WikiEmbed => on_enter_wiki_embed(context),
+        Name::WikiLink => on_enter_wiki_link(context),
         _ => {}
     }
 
@@ -339,11 +402,27 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
     match context.events[context.index].name {
-        Name::Autolink
+        Name::Abbreviation
+        | Name::AbbreviationDefinition
+        | Name::AttributeList
+        | Name::Autolink
+        | Name::BlockId
         | Name::BlockQuote
+        | Name::BracketedSpan
         | Name::CharacterReference
+        | Name::Citation
+        | Name::CriticMarkupAddition
+        | Name::CriticMarkupComment
+        | Name::CriticMarkupDeletion
+        | Name::CriticMarkupHighlight
+        | Name::CustomBlock
+        | Name::CustomInline
         | Name::Definition
+        | Name::DirectiveContainer
+        | Name::DirectiveLeaf
+        | Name::DirectiveText
         | Name::Emphasis
+        | Name::FencedDiv
         | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
         | Name::GfmTableRow
@@ -351,12 +430,23 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         | Name::HeadingAtx
         | Name::ListOrdered
         | Name::ListUnordered
-        | Name::Paragraph
+        | Name::GithubReference
+        | Name::Mention
+        | Name::Ruby
+        | Name::Spoiler
         | Name::Strong
-        | Name::ThematicBreak => {
+        | Name::Insert
+        | Name::Mark
+        | Name::Subscript
+        | Name::Superscript
+        | Name::Underline
+        | Name::ThematicBreak
+        | Name::WikiEmbed
+        | Name::WikiLink => {
             on_exit(context)?;
         }
-        Name::CharacterEscapeValue
+        Name::AutolinkLabel
+        | Name::CharacterEscapeValue
         | Name::CodeFlowChunk
         | Name::CodeTextData
         | Name::Data
@@ -372,6 +462,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
             on_exit_drop(context);
         }
         Name::AutolinkProtocol => on_exit_autolink_protocol(context)?,
+        Name::CriticMarkupText => on_exit_critic_markup_text(context)?,
         Name::AutolinkEmail => on_exit_autolink_email(context)?,
         Name::CharacterReferenceMarker => on_exit_character_reference_marker(context),
         Name::CharacterReferenceMarkerNumeric => {
@@ -392,6 +483,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
             on_exit_definition_id(context);
         }
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::DirectiveLabelString => on_exit_directive_label_string(context)?,
         Name::Frontmatter => on_exit_frontmatter(context)?,
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -400,7 +492,9 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         | Name::GfmAutolinkLiteralXmpp => on_exit_gfm_autolink_literal(context)?,
         Name::GfmFootnoteCall | Name::Image | Name::Link => on_exit_media(context)?,
         Name::GfmTable => on_exit_gfm_table(context)?,
-        Name::GfmTaskListItemValueUnchecked | Name::GfmTaskListItemValueChecked => {
+        Name::GfmTaskListItemValueChecked
+        | Name::GfmTaskListItemValueOther
+        | Name::GfmTaskListItemValueUnchecked => {
             on_exit_gfm_task_list_item_value(context);
         }
         Name::HardBreakEscape | Name::HardBreakTrailing => on_exit_hard_break(context)?,
@@ -411,6 +505,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         Name::HtmlFlow | Name::HtmlText => on_exit_html(context)?,
         Name::LabelText => on_exit_label_text(context),
         Name::LineEnding => on_exit_line_ending(context)?,
+        Name::Paragraph => on_exit_paragraph(context)?,
         Name::ListItem => on_exit_list_item(context)?,
         Name::ListItemValue => on_exit_list_item_value(context),
         Name::MdxEsm | Name::MdxFlowExpression | Name::MdxTextExpression => {
@@ -458,21 +553,92 @@ fn on_enter_data(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Abbreviation`][Name::Abbreviation].
+fn on_enter_abbreviation(context: &mut CompileContext) {
+    let label = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index + 2),
+    );
+    let title = context
+        .abbreviations
+        .iter()
+        .find(|d| d.0 == label.as_str())
+        .map(|d| d.1.clone())
+        .unwrap_or_default();
+    context.tail_push(Node::Abbreviation(Abbreviation {
+        children: vec![],
+        position: None,
+        title,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`AbbreviationDefinition`][Name::AbbreviationDefinition].
+fn on_enter_abbreviation_definition(context: &mut CompileContext) {
+    let (label, title) = label_and_title(context.events, context.bytes, context.index);
+    context.tail_push(Node::AbbreviationDefinition(AbbreviationDefinition {
+        position: None,
+        label,
+        title,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`AttributeList`][Name::AttributeList].
+fn on_enter_attribute_list(context: &mut CompileContext) {
+    context.tail_push(Node::AttributeList(AttributeList {
+        position: None,
+        attributes: attribute_list_attributes(context.events, context.bytes, context.index),
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`BlockId`][Name::BlockId].
+fn on_enter_block_id(context: &mut CompileContext) {
+    // Skip the marker to the exit of the value.
+    let value = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index + 4),
+    );
+    context.tail_push(Node::BlockId(BlockId {
+        position: None,
+        value: value.as_str().into(),
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Autolink`][Name::Autolink].
 fn on_enter_autolink(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
         url: String::new(),
         title: None,
+        destination_kind: DestinationKind::Raw,
+        title_kind: None,
         children: vec![],
         position: None,
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`AutolinkProtocol`][Name::AutolinkProtocol].
+fn on_enter_autolink_protocol(context: &mut CompileContext) {
+    // With a label (`<https://example.com|a>`), the label is the text.
+    if context.events[context.index + 2].name != Name::AutolinkLabelMarker {
+        on_enter_data(context);
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`BlockQuote`][Name::BlockQuote].
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tail_push(Node::BlockQuote(BlockQuote {
         children: vec![],
         position: None,
+        alert: gfm_alert_type(context.events, context.bytes, context.index),
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`BracketedSpan`][Name::BracketedSpan].
+fn on_enter_bracketed_span(context: &mut CompileContext) {
+    let attributes = bracketed_span_attributes(context.events, context.bytes, context.index);
+    context.tail_push(Node::Span(Span {
+        children: vec![],
+        position: None,
+        attributes,
     }));
 }
 
@@ -568,7 +734,63 @@ fn on_enter_definition(context: &mut CompileContext) {
         identifier: String::new(),
         label: None,
         title: None,
+        destination_kind: DestinationKind::Raw,
+        title_kind: None,
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`DefinitionDestinationLiteral`][Name::DefinitionDestinationLiteral],[`ResourceDestinationLiteral`][Name::ResourceDestinationLiteral]}.
+fn on_enter_destination_literal(context: &mut CompileContext) {
+    match context.tail_mut() {
+        Node::Definition(node) => node.destination_kind = DestinationKind::Literal,
+        Node::Link(node) => node.destination_kind = DestinationKind::Literal,
+        Node::Image(node) => node.destination_kind = DestinationKind::Literal,
+        _ => unreachable!("expected definition, link, image on stack"),
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveContainer`][Name::DirectiveContainer].
+fn on_enter_directive_container(context: &mut CompileContext) {
+    let (name, attributes) =
+        directive_name_and_attributes(context.events, context.bytes, context.index);
+    context.tail_push(Node::ContainerDirective(ContainerDirective {
+        children: vec![],
+        position: None,
+        name,
+        attributes,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_enter_directive_label_string(context: &mut CompileContext) {
+    // The label of a directive (container) is its first paragraph.
+    if container_label(context.events, context.index) {
+        on_enter_paragraph(context);
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveLeaf`][Name::DirectiveLeaf].
+fn on_enter_directive_leaf(context: &mut CompileContext) {
+    let (name, attributes) =
+        directive_name_and_attributes(context.events, context.bytes, context.index);
+    context.tail_push(Node::LeafDirective(LeafDirective {
+        children: vec![],
+        position: None,
+        name,
+        attributes,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DirectiveText`][Name::DirectiveText].
+fn on_enter_directive_text(context: &mut CompileContext) {
+    let (name, attributes) =
+        directive_name_and_attributes(context.events, context.bytes, context.index);
+    context.tail_push(Node::TextDirective(TextDirective {
+        children: vec![],
         position: None,
+        name,
+        attributes,
     }));
 }
 
@@ -646,6 +868,16 @@ fn on_enter_hard_break(context: &mut CompileContext) {
     context.tail_push(Node::Break(Break { position: None }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`FencedDiv`][Name::FencedDiv].
+fn on_enter_fenced_div(context: &mut CompileContext) {
+    let attributes = fenced_div_attributes(context.events, context.bytes, context.index);
+    context.tail_push(Node::Div(Div {
+        children: vec![],
+        position: None,
+        attributes,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Frontmatter`][Name::Frontmatter].
 fn on_enter_frontmatter(context: &mut CompileContext) {
     let index = context.events[context.index].point.index;
@@ -687,6 +919,14 @@ fn on_enter_resource(context: &mut CompileContext) {
     reference.reference_kind = None;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Spoiler`][Name::Spoiler].
+fn on_enter_spoiler(context: &mut CompileContext) {
+    context.tail_push(Node::Spoiler(Spoiler {
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
     context.tail_push(Node::Strong(Strong {
@@ -695,11 +935,176 @@ fn on_enter_strong(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Insert`][Name::Insert].
+fn on_enter_insert(context: &mut CompileContext) {
+    context.tail_push(Node::Insert(Insert {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Mark`][Name::Mark].
+fn on_enter_mark(context: &mut CompileContext) {
+    context.tail_push(Node::Mark(Mark {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CriticMarkupComment`][Name::CriticMarkupComment].
+fn on_enter_critic_markup_comment(context: &mut CompileContext) {
+    context.tail_push(Node::CriticMarkupComment(CriticMarkupComment {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CriticMarkupText`][Name::CriticMarkupText].
+fn on_enter_critic_markup_text(context: &mut CompileContext) {
+    let before = &context.events[context.index - 3];
+
+    // The old text of a substitution is a delete, the new text an insert.
+    if before.kind == Kind::Exit {
+        on_enter_insert(context);
+    } else if before.name == Name::CriticMarkupSubstitution {
+        on_enter_gfm_strikethrough(context);
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CustomBlock`][Name::CustomBlock].
+fn on_enter_custom_block(context: &mut CompileContext) {
+    let (custom_block, info) = custom_block(
+        &context.parse_options.custom_blocks,
+        context.events,
+        context.bytes,
+        context.index,
+    );
+    let name = custom_block.name.clone();
+    context.tail_push(Node::CustomBlock(CustomBlock {
+        children: vec![],
+        position: None,
+        name,
+        info,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`CustomInline`][Name::CustomInline].
+fn on_enter_custom_inline(context: &mut CompileContext) {
+    let name = custom_inline(
+        &context.parse_options.custom_inlines,
+        context.events,
+        context.bytes,
+        context.index,
+    )
+    .name
+    .clone();
+    context.tail_push(Node::CustomInline(CustomInline {
+        children: vec![],
+        position: None,
+        name,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Subscript`][Name::Subscript].
+fn on_enter_subscript(context: &mut CompileContext) {
+    context.tail_push(Node::Subscript(Subscript {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Superscript`][Name::Superscript].
+fn on_enter_superscript(context: &mut CompileContext) {
+    context.tail_push(Node::Superscript(Superscript {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Underline`][Name::Underline].
+fn on_enter_underline(context: &mut CompileContext) {
+    context.tail_push(Node::Underline(Underline {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`DefinitionTitle`][Name::DefinitionTitle],[`ResourceTitle`][Name::ResourceTitle]}.
+fn on_enter_title(context: &mut CompileContext) {
+    let kind = match context.bytes[context.events[context.index].point.index] {
+        b'"' => TitleKind::Double,
+        b'\'' => TitleKind::Single,
+        _ => TitleKind::Paren,
+    };
+
+    match context.tail_mut() {
+        Node::Definition(node) => node.title_kind = Some(kind),
+        Node::Link(node) => node.title_kind = Some(kind),
+        Node::Image(node) => node.title_kind = Some(kind),
+        _ => unreachable!("expected definition, link, image on stack"),
+    }
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_enter_thematic_break(context: &mut CompileContext) {
     context.tail_push(Node::ThematicBreak(ThematicBreak { position: None }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Citation`][Name::Citation].
+fn on_enter_citation(context: &mut CompileContext) {
+    let mut citation = citation_from_events(context.events, context.bytes, context.index);
+    citation.position = None;
+    context.tail_push(Node::Citation(citation));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`GithubReference`][Name::GithubReference].
+fn on_enter_github_reference(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index + 1),
+    );
+    context.tail_push(Node::GithubReference(github_reference(slice.as_str())));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Mention`][Name::Mention].
+fn on_enter_mention(context: &mut CompileContext) {
+    context.tail_push(Node::Mention(Mention {
+        position: None,
+        name: mention_name(context.events, context.bytes, context.index),
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`Ruby`][Name::Ruby].
+fn on_enter_ruby(context: &mut CompileContext) {
+    let (base, text) = ruby_base_and_text(context.events, context.bytes, context.index);
+    context.tail_push(Node::Ruby(Ruby {
+        position: None,
+        base,
+        text,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`WikiEmbed`][Name::WikiEmbed].
+fn on_enter_wiki_embed(context: &mut CompileContext) {
+    let (target, fragment, alias) = wiki_link_parts(context.events, context.bytes, context.index);
+    context.tail_push(Node::WikiEmbed(WikiEmbed {
+        position: None,
+        target,
+        fragment,
+        alias,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`WikiLink`][Name::WikiLink].
+fn on_enter_wiki_link(context: &mut CompileContext) {
+    let (target, alias) = wiki_link_target_and_alias(context.events, context.bytes, context.index);
+    context.tail_push(Node::WikiLink(WikiLink {
+        position: None,
+        target,
+        alias,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`HeadingAtx`][Name::HeadingAtx].
 fn on_enter_heading(context: &mut CompileContext) {
     context.tail_push(Node::Heading(Heading {
@@ -723,6 +1128,8 @@ fn on_enter_image(context: &mut CompileContext) {
     context.tail_push(Node::Image(Image {
         url: String::new(),
         title: None,
+        destination_kind: DestinationKind::Raw,
+        title_kind: None,
         alt: String::new(),
         position: None,
     }));
@@ -734,6 +1141,8 @@ fn on_enter_link(context: &mut CompileContext) {
     context.tail_push(Node::Link(Link {
         url: String::new(),
         title: None,
+        destination_kind: DestinationKind::Raw,
+        title_kind: None,
         children: vec![],
         position: None,
     }));
@@ -761,6 +1170,7 @@ fn on_enter_list_item(context: &mut CompileContext) {
     context.tail_push(Node::ListItem(ListItem {
         spread,
         checked: None,
+        task_state: None,
         children: vec![],
         position: None,
     }));
@@ -800,6 +1210,7 @@ fn on_enter_mdx_jsx_tag_closing_marker(
             reason: "Unexpected closing slash `/` in tag, expected an open tag first".into(),
             rule_id: Box::new("unexpected-closing-slash".into()),
             source: Box::new("markdown-rs".into()),
+            fix: None,
         })
     } else {
         Ok(())
@@ -817,6 +1228,7 @@ fn on_enter_mdx_jsx_tag_any_attribute(
             reason: "Unexpected attribute in closing tag, expected the end of the tag".into(),
             rule_id: Box::new("unexpected-attribute".into()),
             source: Box::new("markdown-rs".into()),
+            fix: None,
         })
     } else {
         Ok(())
@@ -906,6 +1318,7 @@ fn on_enter_mdx_jsx_tag_self_closing_marker(
                 .into(),
             rule_id: Box::new("unexpected-self-closing-slash".into()),
             source: Box::new("markdown-rs".into()),
+            fix: None,
         })
     } else {
         Ok(())
@@ -928,7 +1341,10 @@ fn on_exit(context: &mut CompileContext) -> Result<(), message::Message> {
 
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkProtocol`][Name::AutolinkProtocol].
 fn on_exit_autolink_protocol(context: &mut CompileContext) -> Result<(), message::Message> {
-    on_exit_data(context)?;
+    if context.events[context.index + 1].name != Name::AutolinkLabelMarker {
+        on_exit_data(context)?;
+    }
+
     let value = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
@@ -957,6 +1373,17 @@ fn on_exit_autolink_email(context: &mut CompileContext) -> Result<(), message::M
     Ok(())
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`CriticMarkupText`][Name::CriticMarkupText].
+fn on_exit_critic_markup_text(context: &mut CompileContext) -> Result<(), message::Message> {
+    let after = &context.events[context.index + 3];
+
+    if after.kind == Kind::Enter || after.name == Name::CriticMarkupSubstitution {
+        on_exit(context)?;
+    }
+
+    Ok(())
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceMarker`][Name::CharacterReferenceMarker].
 fn on_exit_character_reference_marker(context: &mut CompileContext) {
     context.character_reference_marker = b'&';
@@ -1147,6 +1574,15 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DirectiveLabelString`][Name::DirectiveLabelString].
+fn on_exit_directive_label_string(context: &mut CompileContext) -> Result<(), message::Message> {
+    if let Node::Paragraph(_) = context.tail_mut() {
+        on_exit(context)?;
+    }
+
+    Ok(())
+}
+
 /// Handle [`Exit`][Kind::Exit]:*, by dropping the current buffer.
 fn on_exit_drop(context: &mut CompileContext) {
     context.resume();
@@ -1198,18 +1634,136 @@ fn on_exit_gfm_autolink_literal(context: &mut CompileContext) -> Result<(), mess
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTable`][Name::GfmTable].
 fn on_exit_gfm_table(context: &mut CompileContext) -> Result<(), message::Message> {
+    let continued = gfm_table_rows_continued(context.events, context.index);
+
+    if continued.contains(&true) {
+        let bytes = context.bytes;
+
+        if let Node::Table(table) = context.tail_mut() {
+            // Walk backwards, so that a row takes over rows joined into the
+            // next row already.
+            let mut index = continued.len();
+
+            while index > 1 {
+                index -= 1;
+
+                if continued[index - 1] {
+                    let next = table.children.remove(index);
+
+                    if let (Node::TableRow(row), Node::TableRow(next)) =
+                        (&mut table.children[index - 1], next)
+                    {
+                        gfm_table_row_join(row, next, bytes);
+                    }
+                }
+            }
+        } else {
+            unreachable!("expected table on stack");
+        }
+    }
+
     on_exit(context)?;
     context.gfm_table_inside = false;
     Ok(())
 }
 
-/// Handle [`Exit`][Kind::Exit]:{[`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked],[`GfmTaskListItemValueUnchecked`][Name::GfmTaskListItemValueUnchecked]}.
+/// Whether each row in the GFM table whose exit is at `index` is continued by
+/// the next row.
+fn gfm_table_rows_continued(events: &[Event], mut index: usize) -> Vec<bool> {
+    let mut continued = vec![];
+
+    while !(events[index].kind == Kind::Enter && events[index].name == Name::GfmTable) {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit && event.name == Name::GfmTableRow {
+            continued.push(false);
+        } else if event.kind == Kind::Enter && event.name == Name::GfmTableRowContinuation {
+            *continued.last_mut().unwrap() = true;
+        }
+
+        index -= 1;
+    }
+
+    continued.reverse();
+    continued
+}
+
+/// Join the cells of the GFM table row `next` into the cells of `row`.
+fn gfm_table_row_join(row: &mut TableRow, next: TableRow, bytes: &[u8]) {
+    let row_end = row.position.as_ref().unwrap().end.offset;
+    let line_ending = match bytes.get(row_end) {
+        Some(b'\r') if bytes.get(row_end + 1) == Some(&b'\n') => "\r\n",
+        Some(b'\r') => "\r",
+        _ => "\n",
+    };
+
+    for (column, cell) in next.children.into_iter().enumerate() {
+        match (row.children.get_mut(column), cell) {
+            (Some(Node::TableCell(previous)), Node::TableCell(cell)) => {
+                gfm_table_cell_join(previous, cell, line_ending);
+            }
+            (_, cell) => row.children.push(cell),
+        }
+    }
+
+    if let (Some(position), Some(next)) = (row.position.as_mut(), next.position) {
+        position.end = next.end;
+    }
+}
+
+/// Join the children of the GFM table cell `next` into the children of `cell`,
+/// with a line ending between them.
+fn gfm_table_cell_join(cell: &mut TableCell, next: TableCell, line_ending: &str) {
+    let mut children = next.children;
+
+    if let (Some(last), Some(first)) = (cell.children.last_mut(), children.first()) {
+        if let (Node::Text(last), Node::Text(first)) = (&mut *last, first) {
+            last.value.push_str(line_ending);
+            last.value.push_str(&first.value);
+
+            if let (Some(position), Some(first)) = (last.position.as_mut(), &first.position) {
+                position.end = first.end.clone();
+            }
+
+            children.remove(0);
+        } else {
+            let position = match (last.position(), first.position()) {
+                (Some(start), Some(end)) => Some(Position {
+                    start: start.end.clone(),
+                    end: end.start.clone(),
+                }),
+                _ => None,
+            };
+            children.insert(
+                0,
+                Node::Text(Text {
+                    value: line_ending.into(),
+                    position,
+                }),
+            );
+        }
+    }
+
+    cell.children.append(&mut children);
+
+    if let (Some(position), Some(next)) = (cell.position.as_mut(), next.position) {
+        position.end = next.end;
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:{[`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked],[`GfmTaskListItemValueOther`][Name::GfmTaskListItemValueOther],[`GfmTaskListItemValueUnchecked`][Name::GfmTaskListItemValueUnchecked]}.
 fn on_exit_gfm_task_list_item_value(context: &mut CompileContext) {
-    let checked = context.events[context.index].name == Name::GfmTaskListItemValueChecked;
+    let checked = match context.events[context.index].name {
+        Name::GfmTaskListItemValueChecked => Some(true),
+        Name::GfmTaskListItemValueUnchecked => Some(false),
+        _ => None,
+    };
+    let state = context.bytes[context.events[context.index - 1].point.index] as char;
     let ancestor = context.tail_penultimate_mut();
 
     if let Node::ListItem(node) = ancestor {
-        node.checked = Some(checked);
+        node.checked = checked;
+        node.task_state = Some(state);
     } else {
         unreachable!("expected list item on stack");
     }
@@ -1280,7 +1834,7 @@ fn on_exit_label_text(context: &mut CompileContext) {
         .media_reference_stack
         .last_mut()
         .expect("expected reference on media stack");
-    reference.label = label.clone();
+    reference.label.clone_from(&label);
     reference.identifier = identifier;
 
     match context.tail_mut() {
@@ -1291,11 +1845,38 @@ fn on_exit_label_text(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`Paragraph`][Name::Paragraph].
+fn on_exit_paragraph(context: &mut CompileContext) -> Result<(), message::Message> {
+    on_exit(context)?;
+
+    // Drop paragraphs that only contain a GFM alert.
+    if context.events[context.index - 1].name == Name::GfmAlert {
+        context
+            .tail_mut()
+            .children_mut()
+            .expect("expected parent")
+            .pop();
+    }
+
+    Ok(())
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`LineEnding`][Name::LineEnding].
 fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), message::Message> {
     if context.heading_setext_text_after {
         // Ignore.
     }
+    // Ignore the line ending after a GFM alert: the paragraph starts after
+    // it.
+    else if context.events[context.index - 2].name == Name::GfmAlert {
+        let next = skip::opt(
+            context.events,
+            context.index + 1,
+            &[Name::BlockQuotePrefix, Name::SpaceOrTab],
+        );
+        let start = context.events[next].point.to_unist();
+        context.tail_mut().position_mut().unwrap().start = start;
+    }
     // Line ending position after hard break is part of it.
     else if context.hard_break_after {
         let end = context.events[context.index].point.to_unist();
@@ -1314,7 +1895,16 @@ fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), message::Mess
         Node::Emphasis(_)
             | Node::Heading(_)
             | Node::Paragraph(_)
+            | Node::Spoiler(_)
+            | Node::Span(_)
             | Node::Strong(_)
+            | Node::Insert(_)
+            | Node::Mark(_)
+            | Node::CriticMarkupComment(_)
+            | Node::Subscript(_)
+            | Node::Superscript(_)
+            | Node::TextDirective(_)
+            | Node::Underline(_)
             | Node::Delete(_)
     ) {
         context.index -= 1;
@@ -1395,7 +1985,7 @@ fn on_exit_media(context: &mut CompileContext) -> Result<(), message::Message> {
 /// Handle [`Exit`][Kind::Exit]:[`ListItem`][Name::ListItem].
 fn on_exit_list_item(context: &mut CompileContext) -> Result<(), message::Message> {
     if let Node::ListItem(item) = context.tail_mut() {
-        if item.checked.is_some() {
+        if item.task_state.is_some() {
             if let Some(Node::Paragraph(paragraph)) = item.children.first_mut() {
                 if let Some(Node::Text(text)) = paragraph.children.first_mut() {
                     let mut point = text.position.as_ref().unwrap().start.clone();
@@ -1490,6 +2080,7 @@ fn on_exit_mdx_jsx_tag(context: &mut CompileContext) -> Result<(), message::Mess
                     ),
                     rule_id: Box::new("end-tag-mismatch".into()),
                     source: Box::new("markdown-rs".into()),
+                    fix: None,
                 },
             );
         }
@@ -1779,6 +2370,7 @@ fn on_mismatch_error(
             ),
             rule_id: Box::new("end-tag-mismatch".into()),
             source: Box::new("markdown-rs".into()),
+            fix: None,
         });
     }
 
@@ -1798,6 +2390,7 @@ fn on_mismatch_error(
                     ),
                     rule_id: Box::new("end-tag-mismatch".into()),
                     source: Box::new("markdown-rs".into()),
+                    fix: None,
                 }
             );
         }
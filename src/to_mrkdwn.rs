@@ -0,0 +1,300 @@
+//! Turn a syntax tree into a string of Slack markdown (mrkdwn).
+//!
+//! mrkdwn is the markup that Slack uses in messages.
+//! It has strong (`*a*`), emphasis (`_a_`), strikethrough (`~a~`), code,
+//! block quotes, and links (`<https://example.com|a>`), but no headings,
+//! images, tables, or HTML.
+//! Those are turned into the closest thing that mrkdwn supports.
+
+use crate::mdast::{Definition, Node};
+use crate::util::citation::serialize as citation_serialize;
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Context used to compile mrkdwn.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Definitions, to resolve link and image references with.
+    definitions: Vec<&'a Definition>,
+}
+
+/// Turn a syntax tree into a string of mrkdwn.
+pub fn compile(tree: &Node) -> String {
+    let mut context = CompileContext {
+        definitions: Vec::new(),
+    };
+    collect_definitions(&mut context, tree);
+
+    block(&context, tree).unwrap_or_default()
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Serialize flow (block) children, separated by blank lines.
+fn flow(context: &CompileContext, children: &[Node]) -> String {
+    let mut results: Vec<String> = Vec::new();
+
+    for child in children {
+        if let Some(value) = block(context, child) {
+            results.push(value);
+        }
+    }
+
+    results.join("\n\n")
+}
+
+/// Serialize a flow (block) node.
+///
+/// Returns `None` for nodes that are not shown (such as definitions).
+fn block(context: &CompileContext, node: &Node) -> Option<String> {
+    match node {
+        Node::BlockQuote(x) => Some(prefix_lines(&flow(context, &x.children), "> ", ">")),
+        Node::Code(x) => Some(format!("```\n{}\n```", escape(&x.value))),
+        Node::Math(x) => Some(format!("```\n{}\n```", escape(&x.value))),
+        Node::FootnoteDefinition(x) => Some(format!(
+            "[^{}]: {}",
+            escape(x.label.as_ref().unwrap_or(&x.identifier)),
+            flow(context, &x.children)
+        )),
+        // Slack has no headings: use strong.
+        Node::Heading(x) => Some(format!("*{}*", phrasing(context, &x.children))),
+        Node::Html(x) => Some(escape(&x.value)),
+        Node::List(x) => {
+            let mut number = x.start.unwrap_or(1);
+            let mut items: Vec<String> = Vec::new();
+
+            for child in &x.children {
+                let marker = if x.ordered {
+                    number += 1;
+                    format!("{}. ", number - 1)
+                } else {
+                    "• ".into()
+                };
+                let checked = match child {
+                    Node::ListItem(item) => match item.checked {
+                        Some(true) => "[x] ",
+                        Some(false) => "[ ] ",
+                        None => "",
+                    },
+                    _ => "",
+                };
+                let value = block(context, child).unwrap_or_default();
+                let indent = " ".repeat(marker.chars().count());
+                let value = prefix_lines(&value, &indent, "");
+                let value = value.strip_prefix(&indent).unwrap_or(&value);
+                items.push(format!("{}{}{}", marker, checked, value));
+            }
+
+            Some(items.join("\n"))
+        }
+        Node::ContainerDirective(x) => Some(flow(context, &x.children)),
+        Node::Div(x) => Some(flow(context, &x.children)),
+        Node::CustomBlock(x) => Some(flow(context, &x.children)),
+        Node::LeafDirective(x) => Some(phrasing(context, &x.children)),
+        Node::ListItem(x) => Some(flow(context, &x.children)),
+        Node::MdxJsxFlowElement(x) => Some(flow(context, &x.children)),
+        Node::Root(x) => Some(flow(context, &x.children)),
+        Node::Paragraph(x) => Some(phrasing(context, &x.children)),
+        // Slack has no tables: use a line per row.
+        Node::Table(x) => Some(
+            x.children
+                .iter()
+                .filter_map(|row| block(context, row))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        Node::TableCell(x) => Some(phrasing(context, &x.children)),
+        Node::TableRow(x) => Some(
+            x.children
+                .iter()
+                .filter_map(|cell| block(context, cell))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        ),
+        Node::ThematicBreak(_) => Some("---".into()),
+        Node::AbbreviationDefinition(_)
+        | Node::AttributeList(_)
+        | Node::Definition(_)
+        | Node::MdxFlowExpression(_)
+        | Node::MdxjsEsm(_)
+        | Node::Toml(_)
+        | Node::Yaml(_) => None,
+        // Phrasing.
+        _ => Some(phrasing(context, core::slice::from_ref(node))),
+    }
+}
+
+/// Serialize phrasing (inline) children.
+fn phrasing(context: &CompileContext, children: &[Node]) -> String {
+    let mut result = String::new();
+
+    for child in children {
+        match child {
+            Node::Break(_) => result.push('\n'),
+            Node::Delete(x) => {
+                result.push('~');
+                result.push_str(&phrasing(context, &x.children));
+                result.push('~');
+            }
+            Node::Emphasis(x) => {
+                result.push('_');
+                result.push_str(&phrasing(context, &x.children));
+                result.push('_');
+            }
+            Node::FootnoteReference(x) => {
+                result.push_str("[^");
+                result.push_str(&escape(x.label.as_ref().unwrap_or(&x.identifier)));
+                result.push(']');
+            }
+            Node::Html(x) => result.push_str(&escape(&x.value)),
+            Node::Image(x) => result.push_str(&link(&x.url, &escape(&x.alt))),
+            Node::ImageReference(x) => {
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    result.push_str(&link(&definition.url, &escape(&x.alt)));
+                } else {
+                    result.push_str(&escape(&x.alt));
+                }
+            }
+            Node::InlineCode(x) => {
+                result.push('`');
+                result.push_str(&escape(&x.value));
+                result.push('`');
+            }
+            Node::InlineMath(x) => {
+                result.push('`');
+                result.push_str(&escape(&x.value));
+                result.push('`');
+            }
+            Node::Link(x) => result.push_str(&link(&x.url, &phrasing(context, &x.children))),
+            Node::LinkReference(x) => {
+                let text = phrasing(context, &x.children);
+                if let Some(definition) = find_definition(context, &x.identifier) {
+                    result.push_str(&link(&definition.url, &text));
+                } else {
+                    result.push_str(&text);
+                }
+            }
+            Node::MdxJsxTextElement(x) => result.push_str(&phrasing(context, &x.children)),
+            // Slack has no abbreviations, inserts, marks, spoilers, subscript,
+            // superscript, underline, or directives: use the content.
+            Node::Abbreviation(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Spoiler(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Span(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::CustomInline(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::TextDirective(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Insert(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Mark(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Subscript(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Superscript(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Underline(x) => result.push_str(&phrasing(context, &x.children)),
+            Node::Strong(x) => {
+                result.push('*');
+                result.push_str(&phrasing(context, &x.children));
+                result.push('*');
+            }
+            Node::Text(x) => result.push_str(&escape(&x.value)),
+            Node::Citation(x) => result.push_str(&escape(&citation_serialize(x))),
+            Node::GithubReference(x) => result.push_str(&escape(&github_reference_serialize(x))),
+            Node::Mention(x) => result.push_str(&escape(&format!("@{}", x.name))),
+            Node::Ruby(x) => result.push_str(&escape(&format!("{}({})", x.base, x.text))),
+            Node::WikiEmbed(x) => result.push_str(&escape(x.alias.as_ref().unwrap_or(&x.target))),
+            Node::WikiLink(x) => result.push_str(&escape(x.alias.as_ref().unwrap_or(&x.target))),
+            Node::AttributeList(_)
+            | Node::BlockId(_)
+            | Node::CriticMarkupComment(_)
+            | Node::MdxTextExpression(_) => {}
+            Node::AbbreviationDefinition(_)
+            | Node::BlockQuote(_)
+            | Node::Code(_)
+            | Node::ContainerDirective(_)
+            | Node::Definition(_)
+            | Node::Div(_)
+            | Node::CustomBlock(_)
+            | Node::FootnoteDefinition(_)
+            | Node::Heading(_)
+            | Node::LeafDirective(_)
+            | Node::List(_)
+            | Node::ListItem(_)
+            | Node::Math(_)
+            | Node::MdxFlowExpression(_)
+            | Node::MdxJsxFlowElement(_)
+            | Node::MdxjsEsm(_)
+            | Node::Paragraph(_)
+            | Node::Root(_)
+            | Node::Table(_)
+            | Node::TableCell(_)
+            | Node::TableRow(_)
+            | Node::ThematicBreak(_)
+            | Node::Toml(_)
+            | Node::Yaml(_) => {
+                if let Some(value) = block(context, child) {
+                    result.push_str(&value);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Find the definition for `identifier`.
+fn find_definition<'a>(context: &CompileContext<'a>, identifier: &str) -> Option<&'a Definition> {
+    context
+        .definitions
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+        .copied()
+}
+
+/// Serialize a link to `url` with `text`.
+///
+/// When the text is the same as the URL, or empty, it is not included.
+///
+/// `|` separates the URL from the text, and cannot be escaped, so it is
+/// percent-encoded in the URL, and turned into `¦` in the text.
+fn link(url: &str, text: &str) -> String {
+    let url = escape(url).replace('|', "%7C");
+    let text = text.replace('|', "¦");
+
+    if text.is_empty() || text == url {
+        format!("<{}>", url)
+    } else {
+        format!("<{}|{}>", url, text)
+    }
+}
+
+/// Prefix each line in `value` with `prefix`, or `empty` for empty lines.
+fn prefix_lines(value: &str, prefix: &str, empty: &str) -> String {
+    value
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                empty.to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escape the control characters of mrkdwn (`&`, `<`, and `>`).
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,418 @@
+//! Turn a syntax tree into a string of `CommonMark` XML.
+//!
+//! This is the XML that `cmark -t xml` generates, which follows the
+//! [`CommonMark` DTD][dtd].
+//! GFM tables, strikethrough, and task list items are serialized like
+//! `cmark-gfm` does.
+//! Other things that are not in `CommonMark` are not included, except for their
+//! content, where they have phrasing content.
+//!
+//! [dtd]: https://github.com/commonmark/commonmark-spec/blob/master/CommonMark.dtd
+
+use crate::mdast::{AlignKind, Definition, Node};
+use crate::util::citation::serialize as citation_serialize;
+use crate::util::github_reference::serialize as github_reference_serialize;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Context used to compile XML.
+#[derive(Debug)]
+struct CompileContext<'a> {
+    /// Source document.
+    value: &'a str,
+    /// Definitions, to resolve link and image references with.
+    definitions: Vec<&'a Definition>,
+    /// Whether in phrasing content.
+    phrasing: bool,
+    /// Output.
+    buffer: String,
+}
+
+/// Turn a syntax tree into a string of `CommonMark` XML.
+pub fn compile(tree: &Node, value: &str) -> String {
+    let mut context = CompileContext {
+        value,
+        definitions: Vec::new(),
+        phrasing: false,
+        buffer: String::new(),
+    };
+    collect_definitions(&mut context, tree);
+
+    context
+        .buffer
+        .push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE document SYSTEM \"CommonMark.dtd\">\n");
+    element(
+        &mut context,
+        0,
+        "document",
+        &[("xmlns", "http://commonmark.org/xml/1.0".into())],
+        tree.children().map_or(&[], |d| d.as_slice()),
+    );
+
+    context.buffer
+}
+
+/// Collect all definitions in `node`.
+fn collect_definitions<'a>(context: &mut CompileContext<'a>, node: &'a Node) {
+    if let Node::Definition(definition) = node {
+        context.definitions.push(definition);
+    } else if let Some(children) = node.children() {
+        for child in children {
+            collect_definitions(context, child);
+        }
+    }
+}
+
+/// Serialize a node.
+fn node(context: &mut CompileContext, depth: usize, node: &Node) {
+    match node {
+        Node::BlockQuote(x) => element(context, depth, "block_quote", &[], &x.children),
+        Node::Break(_) => element(context, depth, "linebreak", &[], &[]),
+        Node::Code(x) => {
+            let mut attributes = Vec::new();
+            if let Some(lang) = &x.lang {
+                let info = if let Some(meta) = &x.meta {
+                    format!("{} {}", lang, meta)
+                } else {
+                    lang.clone()
+                };
+                attributes.push(("info", info));
+            }
+            let value = if x.value.is_empty() {
+                String::new()
+            } else {
+                format!("{}\n", x.value)
+            };
+            literal(context, depth, "code_block", &attributes, &value);
+        }
+        Node::Delete(x) => element(context, depth, "strikethrough", &[], &x.children),
+        Node::Emphasis(x) => element(context, depth, "emph", &[], &x.children),
+        Node::Heading(x) => element(
+            context,
+            depth,
+            "heading",
+            &[("level", x.depth.to_string())],
+            &x.children,
+        ),
+        Node::Html(x) => {
+            if context.phrasing {
+                literal(context, depth, "html_inline", &[], &x.value);
+            } else {
+                // Flow HTML ends in a line ending in CommonMark.
+                literal(context, depth, "html_block", &[], &format!("{}\n", x.value));
+            }
+        }
+        Node::Image(x) => image(context, depth, &x.url, x.title.as_ref(), &x.alt),
+        Node::ImageReference(x) => {
+            if let Some(definition) = find_definition(context, &x.identifier) {
+                image(
+                    context,
+                    depth,
+                    &definition.url,
+                    definition.title.as_ref(),
+                    &x.alt,
+                );
+            } else {
+                literal(context, depth, "text", &[], &x.alt);
+            }
+        }
+        Node::InlineCode(x) => literal(context, depth, "code", &[], &x.value),
+        Node::Link(x) => element(
+            context,
+            depth,
+            "link",
+            &link_attributes(&x.url, x.title.as_ref()),
+            &x.children,
+        ),
+        Node::LinkReference(x) => {
+            if let Some(definition) = find_definition(context, &x.identifier) {
+                element(
+                    context,
+                    depth,
+                    "link",
+                    &link_attributes(&definition.url, definition.title.as_ref()),
+                    &x.children,
+                );
+            } else {
+                children(context, depth, &x.children);
+            }
+        }
+        Node::List(x) => {
+            let mut attributes = Vec::new();
+            if x.ordered {
+                attributes.push(("type", "ordered".into()));
+                attributes.push(("start", x.start.unwrap_or(1).to_string()));
+                // The delimiter is not in mdast, so get it from the source.
+                let delimiter = x
+                    .children
+                    .first()
+                    .and_then(Node::position)
+                    .map_or(b'.', |d| {
+                        context.value[d.start.offset..]
+                            .bytes()
+                            .find(|byte| !matches!(byte, b'\t' | b' ' | b'0'..=b'9'))
+                            .unwrap_or(b'.')
+                    });
+                let delimiter = if delimiter == b')' { "paren" } else { "period" };
+                attributes.push(("delim", delimiter.into()));
+            } else {
+                attributes.push(("type", "bullet".into()));
+            }
+            attributes.push(("tight", (!x.spread).to_string()));
+            element(context, depth, "list", &attributes, &x.children);
+        }
+        Node::ListItem(x) => {
+            let mut attributes = Vec::new();
+            if let Some(checked) = x.checked {
+                attributes.push(("completed", checked.to_string()));
+            }
+            element(context, depth, "item", &attributes, &x.children);
+        }
+        // Not in CommonMark: use a paragraph.
+        Node::LeafDirective(x) => element(context, depth, "paragraph", &[], &x.children),
+        Node::Paragraph(x) => element(context, depth, "paragraph", &[], &x.children),
+        Node::Strong(x) => element(context, depth, "strong", &[], &x.children),
+        Node::Table(x) => {
+            open(context, depth, "table", &[], false);
+
+            for (index, row) in x.children.iter().enumerate() {
+                let name = if index == 0 {
+                    "table_header"
+                } else {
+                    "table_row"
+                };
+                open(context, depth + 1, name, &[], false);
+
+                for (column, cell) in row.children().into_iter().flatten().enumerate() {
+                    let align = match x.align.get(column) {
+                        Some(AlignKind::Left) => "left",
+                        Some(AlignKind::Right) => "right",
+                        Some(AlignKind::Center) => "center",
+                        Some(AlignKind::None) | None => "",
+                    };
+                    let mut attributes = Vec::new();
+                    if !align.is_empty() {
+                        attributes.push(("align", align.into()));
+                    }
+                    element(
+                        context,
+                        depth + 2,
+                        "table_cell",
+                        &attributes,
+                        cell.children().map_or(&[], |d| d.as_slice()),
+                    );
+                }
+
+                close(context, depth + 1, name);
+            }
+
+            close(context, depth, "table");
+        }
+        Node::Text(x) => {
+            for (index, line) in x.value.split('\n').enumerate() {
+                if index > 0 {
+                    element(context, depth, "softbreak", &[], &[]);
+                }
+                if !line.is_empty() {
+                    literal(context, depth, "text", &[], line);
+                }
+            }
+        }
+        Node::ThematicBreak(_) => element(context, depth, "thematic_break", &[], &[]),
+        // Not in CommonMark: use the text.
+        Node::Citation(x) => literal(context, depth, "text", &[], &citation_serialize(x)),
+        Node::GithubReference(x) => {
+            literal(context, depth, "text", &[], &github_reference_serialize(x));
+        }
+        Node::Mention(x) => literal(context, depth, "text", &[], &format!("@{}", x.name)),
+        Node::Ruby(x) => literal(
+            context,
+            depth,
+            "text",
+            &[],
+            &format!("{}({})", x.base, x.text),
+        ),
+        Node::WikiEmbed(x) => literal(
+            context,
+            depth,
+            "text",
+            &[],
+            x.alias.as_ref().unwrap_or(&x.target),
+        ),
+        Node::WikiLink(x) => literal(
+            context,
+            depth,
+            "text",
+            &[],
+            x.alias.as_ref().unwrap_or(&x.target),
+        ),
+        // Not in CommonMark: use the content.
+        Node::Abbreviation(_)
+        | Node::ContainerDirective(_)
+        | Node::Div(_)
+        | Node::CustomBlock(_)
+        | Node::FootnoteDefinition(_)
+        | Node::MdxJsxFlowElement(_)
+        | Node::MdxJsxTextElement(_)
+        | Node::Spoiler(_)
+        | Node::Span(_)
+        | Node::CustomInline(_)
+        | Node::Insert(_)
+        | Node::Mark(_)
+        | Node::Subscript(_)
+        | Node::Superscript(_)
+        | Node::TextDirective(_)
+        | Node::Underline(_) => {
+            if let Some(nodes) = node.children() {
+                children(context, depth, nodes);
+            }
+        }
+        // Not in CommonMark.
+        Node::AbbreviationDefinition(_)
+        | Node::AttributeList(_)
+        | Node::BlockId(_)
+        | Node::CriticMarkupComment(_)
+        | Node::Definition(_)
+        | Node::FootnoteReference(_)
+        | Node::InlineMath(_)
+        | Node::Math(_)
+        | Node::MdxFlowExpression(_)
+        | Node::MdxTextExpression(_)
+        | Node::MdxjsEsm(_)
+        | Node::Root(_)
+        | Node::TableCell(_)
+        | Node::TableRow(_)
+        | Node::Toml(_)
+        | Node::Yaml(_) => {}
+    }
+}
+
+/// Serialize children.
+fn children(context: &mut CompileContext, depth: usize, nodes: &[Node]) {
+    for child in nodes {
+        node(context, depth, child);
+    }
+}
+
+/// Serialize an element with `children`.
+///
+/// Elements without children are self-closing.
+fn element(
+    context: &mut CompileContext,
+    depth: usize,
+    name: &str,
+    attributes: &[(&str, String)],
+    nodes: &[Node],
+) {
+    if nodes.is_empty() {
+        open(context, depth, name, attributes, true);
+    } else {
+        let phrasing = context.phrasing;
+        context.phrasing = matches!(
+            name,
+            "emph" | "heading" | "link" | "paragraph" | "strikethrough" | "strong" | "table_cell"
+        );
+        open(context, depth, name, attributes, false);
+        children(context, depth + 1, nodes);
+        close(context, depth, name);
+        context.phrasing = phrasing;
+    }
+}
+
+/// Serialize an element with a literal `value`.
+fn literal(
+    context: &mut CompileContext,
+    depth: usize,
+    name: &str,
+    attributes: &[(&str, String)],
+    value: &str,
+) {
+    context.buffer.push_str(&"  ".repeat(depth));
+    context.buffer.push('<');
+    context.buffer.push_str(name);
+    push_attributes(context, attributes);
+    context.buffer.push_str(" xml:space=\"preserve\">");
+    context.buffer.push_str(&escape(value));
+    context.buffer.push_str("</");
+    context.buffer.push_str(name);
+    context.buffer.push_str(">\n");
+}
+
+/// Serialize the opening tag of an element.
+fn open(
+    context: &mut CompileContext,
+    depth: usize,
+    name: &str,
+    attributes: &[(&str, String)],
+    self_closing: bool,
+) {
+    context.buffer.push_str(&"  ".repeat(depth));
+    context.buffer.push('<');
+    context.buffer.push_str(name);
+    push_attributes(context, attributes);
+    context
+        .buffer
+        .push_str(if self_closing { " />\n" } else { ">\n" });
+}
+
+/// Serialize the closing tag of an element.
+fn close(context: &mut CompileContext, depth: usize, name: &str) {
+    context.buffer.push_str(&"  ".repeat(depth));
+    context.buffer.push_str("</");
+    context.buffer.push_str(name);
+    context.buffer.push_str(">\n");
+}
+
+/// Serialize attributes.
+fn push_attributes(context: &mut CompileContext, attributes: &[(&str, String)]) {
+    for (key, value) in attributes {
+        context.buffer.push(' ');
+        context.buffer.push_str(key);
+        context.buffer.push_str("=\"");
+        context.buffer.push_str(&escape(value));
+        context.buffer.push('"');
+    }
+}
+
+/// Serialize an image.
+///
+/// `CommonMark` images have phrasing content, but mdast only has the text of
+/// that content (`alt`).
+fn image(context: &mut CompileContext, depth: usize, url: &str, title: Option<&String>, alt: &str) {
+    let attributes = link_attributes(url, title);
+
+    if alt.is_empty() {
+        open(context, depth, "image", &attributes, true);
+    } else {
+        open(context, depth, "image", &attributes, false);
+        literal(context, depth + 1, "text", &[], alt);
+        close(context, depth, "image");
+    }
+}
+
+/// Get the attributes of a link or image.
+fn link_attributes(url: &str, title: Option<&String>) -> Vec<(&'static str, String)> {
+    let title = title.cloned().unwrap_or_default();
+    Vec::from([("destination", url.into()), ("title", title)])
+}
+
+/// Find the definition for `identifier`.
+fn find_definition<'a>(context: &CompileContext<'a>, identifier: &str) -> Option<&'a Definition> {
+    context
+        .definitions
+        .iter()
+        .find(|definition| definition.identifier == identifier)
+        .copied()
+}
+
+/// Escape the special characters of XML.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -15,7 +15,7 @@ use crate::resolve::{call as call_resolve, Name as ResolveName};
 use crate::state::{call, State};
 use crate::subtokenize::Subresult;
 
-#[cfg(feature = "log")]
+#[cfg(any(feature = "log", feature = "trace"))]
 use crate::util::char::format_byte_opt;
 
 use crate::util::{constant::TAB_SIZE, edit_map::EditMap};
@@ -32,6 +32,16 @@ use alloc::{boxed::Box, string::String, vec, vec::Vec};
 pub enum Container {
     /// [Block quote][crate::construct::block_quote].
     BlockQuote,
+    /// [Block quote (fenced)][crate::construct::block_quote_fenced].
+    BlockQuoteFenced,
+    /// [Custom block][crate::construct::custom_block], with the index of
+    /// the construct in
+    /// [`custom_blocks`][crate::ParseOptions::custom_blocks].
+    CustomBlock(usize),
+    /// [Directive (container)][crate::construct::directive_container].
+    Directive,
+    /// [Fenced div][crate::construct::fenced_div].
+    FencedDiv,
     /// [List item][crate::construct::list_item].
     ListItem,
     /// [GFM: Footnote definition][crate::construct::gfm_footnote_definition].
@@ -40,7 +50,9 @@ pub enum Container {
 
 /// Info used to tokenize a container.
 ///
-/// Practically, these fields are only used for list items.
+/// Practically, these fields are only used for list items, and `size` for
+/// custom blocks, fenced block quotes, container directives, and fenced
+/// divs.
 #[derive(Debug)]
 pub struct ContainerState {
     /// Kind.
@@ -104,6 +116,16 @@ pub enum LabelKind {
     ///
     /// Construct: [Label end][crate::construct::label_end].
     GfmUndefinedFootnote,
+    /// Label (link) start, followed by attributes after its label end, so
+    /// handled as a bracketed span.
+    ///
+    /// ```markdown
+    /// > | a [b]{.c} d
+    ///       ^
+    /// ```
+    ///
+    /// Construct: [Label end][crate::construct::label_end].
+    BracketedSpan,
 }
 
 /// Label start, looking for an end.
@@ -156,6 +178,32 @@ struct Attempt {
     progress: Option<Progress>,
 }
 
+/// Memory of a tokenizer that is done, to reuse in a new one.
+#[derive(Debug, Default)]
+pub struct Spare {
+    /// Events.
+    events: Vec<Event>,
+    /// Jumps between line endings.
+    column_start: Vec<(usize, usize)>,
+    /// Attempts.
+    attempts: Vec<Attempt>,
+    /// Names of open events.
+    stack: Vec<Name>,
+    /// Edit map.
+    map: EditMap,
+}
+
+impl Spare {
+    /// Create memory to reuse from a list of events that is no longer used.
+    pub fn new(mut events: Vec<Event>) -> Spare {
+        events.clear();
+        Spare {
+            events,
+            ..Spare::default()
+        }
+    }
+}
+
 /// The internal state of a tokenizer.
 ///
 /// Not to be confused with states from the state machine, this instead is all
@@ -176,6 +224,8 @@ struct Progress {
     current: Option<u8>,
     /// Current place in the file.
     point: Point,
+    /// Place after the last line ending.
+    line_start: Point,
 }
 
 /// A lot of shared fields used to tokenize things.
@@ -201,6 +251,9 @@ pub struct TokenizeState<'a> {
     /// a list item.
     /// Used for GFM task list items.
     pub document_at_first_paragraph_of_list_item: bool,
+    /// Whether this is the first paragraph in a block quote.
+    /// Used for GFM alerts.
+    pub document_at_first_paragraph_of_block_quote: bool,
 
     // Couple of very frequent settings for parsing whitespace.
     pub space_or_tab_eol_content: Option<Content>,
@@ -227,6 +280,8 @@ pub struct TokenizeState<'a> {
     /// Used when tokenizing [text content][crate::construct::text].
     pub labels: Vec<Label>,
 
+    /// List of defined abbreviation labels.
+    pub abbreviations: Vec<String>,
     /// List of defined definition identifiers.
     pub definitions: Vec<String>,
     /// List of defined GFM footnote definition identifiers.
@@ -242,7 +297,7 @@ pub struct TokenizeState<'a> {
     /// Secondary marker.
     pub marker_b: u8,
     /// Several markers.
-    pub markers: &'static [u8],
+    pub markers: &'a [u8],
     /// Whether something was seen.
     pub seen: bool,
     /// Size.
@@ -286,6 +341,8 @@ pub struct Tokenizer<'a> {
     consumed: bool,
     /// Stack of how to handle attempts.
     attempts: Vec<Attempt>,
+    /// Index where the bytes that are fed end.
+    feed_end: usize,
     /// Current byte.
     pub current: Option<u8>,
     /// Previous byte.
@@ -302,6 +359,8 @@ pub struct Tokenizer<'a> {
     pub map: EditMap,
     /// List of resolvers.
     pub resolvers: Vec<ResolveName>,
+    /// List of resolvers to call after all others.
+    pub resolvers_after: Vec<ResolveName>,
     /// Shared parsing state across tokenizers.
     pub parse_state: &'a ParseState<'a>,
     /// A lot of shared fields used to tokenize things.
@@ -323,21 +382,42 @@ pub struct Tokenizer<'a> {
     pub lazy: bool,
 }
 
+impl Drop for Tokenizer<'_> {
+    /// Give memory back, so that other tokenizers can use it.
+    fn drop(&mut self) {
+        let mut spare = Spare {
+            events: core::mem::take(&mut self.events),
+            column_start: core::mem::take(&mut self.column_start),
+            attempts: core::mem::take(&mut self.attempts),
+            stack: core::mem::take(&mut self.stack),
+            map: core::mem::take(&mut self.map),
+        };
+        spare.events.clear();
+        spare.column_start.clear();
+        spare.attempts.clear();
+        spare.stack.clear();
+        spare.map.clear();
+        self.parse_state.spare.borrow_mut().push(spare);
+    }
+}
+
 impl<'a> Tokenizer<'a> {
     /// Create a new tokenizer.
     pub fn new(point: Point, parse_state: &'a ParseState) -> Tokenizer<'a> {
+        let spare = parse_state.spare.borrow_mut().pop().unwrap_or_default();
+
         Tokenizer {
             previous: None,
             current: None,
-            // To do: reserve size when feeding?
-            column_start: vec![],
+            column_start: spare.column_start,
             first_line: point.line,
             line_start: point.clone(),
             consumed: true,
-            attempts: vec![],
+            attempts: spare.attempts,
+            feed_end: 0,
             point,
-            stack: vec![],
-            events: vec![],
+            stack: spare.stack,
+            events: spare.events,
             parse_state,
             tokenize_state: TokenizeState {
                 connect: false,
@@ -349,6 +429,8 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                document_at_first_paragraph_of_block_quote: false,
+                abbreviations: vec![],
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
                 mdx_last_parse_error: None,
@@ -380,12 +462,13 @@ impl<'a> Tokenizer<'a> {
                 token_5: Name::Data,
                 token_6: Name::Data,
             },
-            map: EditMap::new(),
+            map: spare.map,
             interrupt: false,
             pierce: false,
             concrete: false,
             lazy: false,
             resolvers: vec![],
+            resolvers_after: vec![],
         }
     }
 
@@ -403,6 +486,36 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    /// Register a resolver, after others (including those registered later).
+    pub fn register_resolver_after(&mut self, name: ResolveName) {
+        if !self.resolvers_after.contains(&name) {
+            self.resolvers_after.push(name);
+        }
+    }
+
+    /// Add a label start, which will look for a label end.
+    ///
+    /// To safeguard performance, there is a cap on how many label starts are
+    /// tracked at the same time (see `label_start_count_max` in
+    /// [`ParseOptions`][crate::ParseOptions]).
+    /// When that cap is reached, the earliest start is moved to the loose
+    /// starts, which means it is turned into data when resolving.
+    pub fn push_label_start(&mut self, label_start: LabelStart) {
+        let max = self.parse_state.options.label_start_count_max;
+
+        if max == 0 {
+            self.tokenize_state.label_starts_loose.push(label_start);
+            return;
+        }
+
+        if self.tokenize_state.label_starts.len() >= max {
+            let earliest = self.tokenize_state.label_starts.remove(0);
+            self.tokenize_state.label_starts_loose.push(earliest);
+        }
+
+        self.tokenize_state.label_starts.push(label_start);
+    }
+
     /// Define a jump between two places.
     ///
     /// This defines to which future index we move after a line ending.
@@ -457,6 +570,35 @@ impl<'a> Tokenizer<'a> {
         self.consumed = true;
     }
 
+    /// Consume the bytes after the previous byte, as long as `include` is
+    /// true for them.
+    ///
+    /// This is a fast path for constructs that take runs of plain bytes,
+    /// such as data, so that those bytes are not fed one at a time.
+    /// It must be called right after `consume`.
+    /// It stops at line endings and tabs, which affect positional info
+    /// differently, and where the bytes that are fed end.
+    /// The byte it stops at is fed as normal.
+    pub fn consume_while(&mut self, include: impl Fn(u8) -> bool) {
+        debug_assert!(self.consumed, "expected previous byte to be consumed");
+
+        if self.point.vs != 0 {
+            return;
+        }
+
+        let bytes = &self.parse_state.bytes[self.point.index..self.feed_end];
+        let size = bytes
+            .iter()
+            .position(|d| matches!(d, b'\t' | b'\n' | b'\r') || !include(*d))
+            .unwrap_or(bytes.len());
+
+        if size > 0 {
+            self.previous = Some(bytes[size - 1]);
+            self.point.index += size;
+            self.point.column += size;
+        }
+    }
+
     /// Move to the next (virtual) byte.
     fn move_one(&mut self) {
         match byte_action(self.parse_state.bytes, &self.point) {
@@ -546,6 +688,12 @@ impl<'a> Tokenizer<'a> {
         #[cfg(feature = "log")]
         log::debug!("exit:    `{:?}`", name);
 
+        #[cfg(feature = "trace")]
+        self.parse_state.trace(format_args!(
+            "exit:    `{:?}` at {}:{}",
+            name, point.index, point.vs
+        ));
+
         let event = Event {
             kind: Kind::Exit,
             name,
@@ -561,6 +709,7 @@ impl<'a> Tokenizer<'a> {
             previous: self.previous,
             current: self.current,
             point: self.point.clone(),
+            line_start: self.line_start.clone(),
             events_len: self.events.len(),
             stack_len: self.stack.len(),
         }
@@ -571,6 +720,7 @@ impl<'a> Tokenizer<'a> {
         self.previous = previous.previous;
         self.current = previous.current;
         self.point = previous.point;
+        self.line_start = previous.line_start;
         debug_assert!(
             self.events.len() >= previous.events_len,
             "expected to restore less events than before"
@@ -636,17 +786,25 @@ impl<'a> Tokenizer<'a> {
             done: false,
             gfm_footnote_definitions: self.tokenize_state.gfm_footnote_definitions.split_off(0),
             definitions: self.tokenize_state.definitions.split_off(0),
+            abbreviations: self.tokenize_state.abbreviations.split_off(0),
         };
 
         if resolve {
-            let resolvers = self.resolvers.split_off(0);
+            let mut resolvers = self.resolvers.split_off(0);
+            resolvers.append(&mut self.resolvers_after);
             let mut index = 0;
             let defs = &mut value.definitions;
             let fn_defs = &mut value.gfm_footnote_definitions;
+            let abbrs = &mut value.abbreviations;
             while index < resolvers.len() {
+                #[cfg(feature = "trace")]
+                self.parse_state
+                    .trace(format_args!("resolve: `{:?}`", resolvers[index]));
+
                 if let Some(mut result) = call_resolve(self, resolvers[index])? {
                     fn_defs.append(&mut result.gfm_footnote_definitions);
                     defs.append(&mut result.definitions);
+                    abbrs.append(&mut result.abbreviations);
                 }
                 index += 1;
             }
@@ -678,6 +836,12 @@ fn enter_impl(tokenizer: &mut Tokenizer, name: Name, link: Option<Link>) {
     #[cfg(feature = "log")]
     log::debug!("enter:   `{:?}`", name);
 
+    #[cfg(feature = "trace")]
+    tokenizer.parse_state.trace(format_args!(
+        "enter:   `{:?}` at {}:{}",
+        name, point.index, point.vs
+    ));
+
     tokenizer.stack.push(name.clone());
     tokenizer.events.push(Event {
         kind: Kind::Enter,
@@ -702,6 +866,7 @@ fn push_impl(
     );
 
     tokenizer.move_to(from);
+    tokenizer.feed_end = to.0;
 
     loop {
         match state {
@@ -725,6 +890,11 @@ fn push_impl(
                     #[cfg(feature = "log")]
                     log::trace!("attempt: `{:?}` -> `{:?}`", state, next);
 
+                    #[cfg(feature = "trace")]
+                    tokenizer
+                        .parse_state
+                        .trace(format_args!("attempt: `{:?}` -> `{:?}`", state, next));
+
                     state = next;
                 } else {
                     break;
@@ -754,14 +924,28 @@ fn push_impl(
                     #[cfg(feature = "log")]
                     log::trace!("feed:    {} to {:?}", format_byte_opt(byte), name);
 
+                    #[cfg(feature = "trace")]
+                    tokenizer.parse_state.trace(format_args!(
+                        "feed:    {} at {}:{} to `{:?}`",
+                        format_byte_opt(byte),
+                        tokenizer.point.index,
+                        tokenizer.point.vs,
+                        name
+                    ));
+
                     tokenizer.expect(byte);
                     state = call(tokenizer, name);
-                };
+                }
             }
             State::Retry(name) => {
                 #[cfg(feature = "log")]
                 log::trace!("retry:   `{:?}`", name);
 
+                #[cfg(feature = "trace")]
+                tokenizer
+                    .parse_state
+                    .trace(format_args!("retry:   `{:?}`", name));
+
                 state = call(tokenizer, name);
             }
         }
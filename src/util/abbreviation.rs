@@ -0,0 +1,60 @@
+//! Deal with abbreviations.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Get the labels and titles of all abbreviation definitions.
+///
+/// When a label is defined more than once, the first definition wins.
+pub fn definitions(events: &[Event], bytes: &[u8]) -> Vec<(String, String)> {
+    let mut definitions: Vec<(String, String)> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == Kind::Enter && events[index].name == Name::AbbreviationDefinition {
+            let (label, title) = label_and_title(events, bytes, index);
+
+            if !definitions.iter().any(|d| d.0 == label) {
+                definitions.push((label, title));
+            }
+        }
+
+        index += 1;
+    }
+
+    definitions
+}
+
+/// Get the label and title of the abbreviation definition whose enter is at
+/// `index`.
+pub fn label_and_title(events: &[Event], bytes: &[u8], index: usize) -> (String, String) {
+    let mut index = index + 1;
+    let mut label = String::new();
+    let mut title = String::new();
+
+    while !(events[index].kind == Kind::Exit && events[index].name == Name::AbbreviationDefinition)
+    {
+        if events[index].kind == Kind::Enter {
+            match events[index].name {
+                Name::AbbreviationDefinitionLabelString => {
+                    label =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index + 1))
+                            .as_str()
+                            .into();
+                }
+                Name::AbbreviationDefinitionTitle => {
+                    title =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index + 1))
+                            .as_str()
+                            .into();
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    (label, title)
+}
@@ -0,0 +1,246 @@
+//! Deal with attribute lists.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    skip,
+    slice::{Position, Slice},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Flow constructs that attribute lists can apply to.
+const BLOCKS: [Name; 11] = [
+    Name::BlockQuote,
+    Name::CodeFenced,
+    Name::CodeIndented,
+    Name::GfmTable,
+    Name::HeadingAtx,
+    Name::HeadingSetext,
+    Name::ListOrdered,
+    Name::ListUnordered,
+    Name::MathFlow,
+    Name::Paragraph,
+    Name::ThematicBreak,
+];
+
+/// Text constructs that attribute lists can apply to.
+const SPANS: [Name; 13] = [
+    Name::CodeText,
+    Name::Emphasis,
+    Name::GfmStrikethrough,
+    Name::Image,
+    Name::Insert,
+    Name::Link,
+    Name::Mark,
+    Name::Ruby,
+    Name::Spoiler,
+    Name::Strong,
+    Name::Subscript,
+    Name::Superscript,
+    Name::Underline,
+];
+
+/// Containers that attribute lists in flow can occur in.
+const CONTAINERS: [Name; 5] = [
+    Name::BlockQuote,
+    Name::DirectiveContainer,
+    Name::FencedDiv,
+    Name::GfmFootnoteDefinition,
+    Name::ListItem,
+];
+
+/// Things that can occur between an attribute list in flow and the block it
+/// applies to.
+const BETWEEN: [Name; 4] = [
+    Name::BlockQuotePrefix,
+    Name::LineEnding,
+    Name::ListItemPrefix,
+    Name::SpaceOrTab,
+];
+
+/// Find which elements attribute lists apply to.
+///
+/// Returns the indices of the enter and exit of each element, and the index
+/// of the enter of the attribute list that applies to it, sorted by the
+/// first.
+/// Attribute lists that do not apply to anything are not included.
+pub fn targets(events: &[Event]) -> Vec<(usize, usize, usize)> {
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == Kind::Enter && events[index].name == Name::AttributeList {
+            if let Some((enter, exit)) = target(events, index) {
+                result.push((enter, exit, index));
+            }
+        }
+
+        index += 1;
+    }
+
+    result.sort_unstable();
+    result
+}
+
+/// Find the element that the attribute list at `index` applies to.
+///
+/// Returns the indices of the enter and exit of that element.
+pub fn target(events: &[Event], index: usize) -> Option<(usize, usize)> {
+    let in_flow = match parent(events, index) {
+        Some(parent) => CONTAINERS.contains(&events[parent].name),
+        None => true,
+    };
+
+    if !in_flow {
+        // Spans must be directly before.
+        return if span_before(events, index) {
+            Some((enter(events, index - 1), index - 1))
+        } else {
+            None
+        };
+    }
+
+    // The block directly before.
+    if index > 0 {
+        let before = skip::opt_back(events, index - 1, &BETWEEN);
+
+        if events[before].kind == Kind::Exit && BLOCKS.contains(&events[before].name) {
+            return Some((enter(events, before), before));
+        }
+    }
+
+    // Otherwise, the block directly after.
+    let after = skip::opt(events, exit(events, index) + 1, &BETWEEN);
+
+    if after < events.len()
+        && events[after].kind == Kind::Enter
+        && BLOCKS.contains(&events[after].name)
+    {
+        Some((after, exit(events, after)))
+    } else {
+        None
+    }
+}
+
+/// Whether a span that attribute lists can apply to is directly before
+/// `index`.
+pub fn span_before(events: &[Event], index: usize) -> bool {
+    index > 0 && events[index - 1].kind == Kind::Exit && SPANS.contains(&events[index - 1].name)
+}
+
+/// Get the attributes of the attribute list (or the attributes of a
+/// bracketed span, directive, or fenced div) at `index`.
+///
+/// Classes are joined together into one `class` attribute, in the place of
+/// the first class.
+/// When an attribute is defined several times, the last value is used.
+pub fn attributes(events: &[Event], bytes: &[u8], mut index: usize) -> Vec<(String, String)> {
+    let mut result: Vec<(String, String)> = vec![];
+    let mut name = String::new();
+
+    while !(events[index].kind == Kind::Exit
+        && matches!(
+            events[index].name,
+            Name::AttributeList
+                | Name::BracketedSpanAttributes
+                | Name::DirectiveAttributes
+                | Name::FencedDivAttributes
+        ))
+    {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            let value = Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                .as_str()
+                .into();
+
+            match event.name {
+                Name::AttributeListClassValue => add(&mut result, "class".into(), value),
+                Name::AttributeListIdValue => add(&mut result, "id".into(), value),
+                Name::AttributeListPropertyName => name = value,
+                Name::AttributeListPropertyValue => {
+                    add(&mut result, core::mem::take(&mut name), value);
+                }
+                // Empty quoted values.
+                Name::AttributeListProperty if !name.is_empty() => {
+                    add(&mut result, core::mem::take(&mut name), String::new());
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Add an attribute.
+fn add(attributes: &mut Vec<(String, String)>, name: String, value: String) {
+    if let Some(existing) = attributes.iter_mut().find(|d| d.0 == name) {
+        if name == "class" {
+            existing.1.push(' ');
+            existing.1.push_str(&value);
+        } else {
+            existing.1 = value;
+        }
+    } else {
+        attributes.push((name, value));
+    }
+}
+
+/// Find the index of the enter of the element that `index` is in.
+fn parent(events: &[Event], mut index: usize) -> Option<usize> {
+    let mut balance = 0;
+
+    while index > 0 {
+        index -= 1;
+
+        if events[index].kind == Kind::Exit {
+            balance += 1;
+        } else if balance == 0 {
+            return Some(index);
+        } else {
+            balance -= 1;
+        }
+    }
+
+    None
+}
+
+/// Find the index of the enter that matches the exit at `index`.
+pub fn enter(events: &[Event], mut index: usize) -> usize {
+    let mut balance = 0;
+
+    loop {
+        if events[index].kind == Kind::Exit {
+            balance += 1;
+        } else {
+            balance -= 1;
+        }
+
+        if balance == 0 {
+            return index;
+        }
+
+        index -= 1;
+    }
+}
+
+/// Find the index of the exit that matches the enter at `index`.
+pub fn exit(events: &[Event], mut index: usize) -> usize {
+    let mut balance = 0;
+
+    loop {
+        if events[index].kind == Kind::Enter {
+            balance += 1;
+        } else {
+            balance -= 1;
+        }
+
+        if balance == 0 {
+            return index;
+        }
+
+        index += 1;
+    }
+}
@@ -0,0 +1,534 @@
+//! Deal with bibliographies, to format citations with.
+
+use crate::message::{Message, Place};
+use crate::unist::Point;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Name of an author, from CSL-JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReferenceName {
+    /// Family name (`family`), such as `Doe`.
+    pub family: Option<String>,
+    /// Given name (`given`), such as `Jane`.
+    pub given: Option<String>,
+    /// Name as a whole (`literal`), such as an organization.
+    pub literal: Option<String>,
+}
+
+impl ReferenceName {
+    /// Short form of the name, as used in citations.
+    fn short(&self) -> Option<&str> {
+        self.family.as_deref().or(self.literal.as_deref())
+    }
+
+    /// Long form of the name, as used in reference lists, with the family
+    /// name first (`Doe, Jane`) or last (`Jane Doe`).
+    fn long(&self, family_first: bool) -> Option<String> {
+        if let Some(ref literal) = self.literal {
+            return Some(literal.clone());
+        }
+
+        match (&self.family, &self.given) {
+            (Some(family), Some(given)) if family_first => Some(format!("{}, {}", family, given)),
+            (Some(family), Some(given)) => Some(format!("{} {}", given, family)),
+            (Some(value), None) | (None, Some(value)) => Some(value.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Work that can be cited, from CSL-JSON.
+///
+/// Only the fields that are used to format citations and reference lists
+/// are kept.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Reference {
+    /// Key that citations use (`id`), such as `doe99` for `@doe99`.
+    pub id: String,
+    /// Kind of work (`type`), such as `book` or `article-journal`.
+    pub kind: Option<String>,
+    /// Title (`title`).
+    pub title: Option<String>,
+    /// Authors (`author`).
+    pub author: Vec<ReferenceName>,
+    /// Year or date when it was issued (`issued`).
+    pub issued: Option<String>,
+    /// Title of the journal or book it is in (`container-title`).
+    pub container_title: Option<String>,
+    /// Publisher (`publisher`).
+    pub publisher: Option<String>,
+    /// Place of the publisher (`publisher-place`).
+    pub publisher_place: Option<String>,
+    /// Volume (`volume`).
+    pub volume: Option<String>,
+    /// Issue (`issue`).
+    pub issue: Option<String>,
+    /// Pages (`page`).
+    pub page: Option<String>,
+    /// DOI (`DOI`).
+    pub doi: Option<String>,
+    /// URL (`URL`).
+    pub url: Option<String>,
+}
+
+impl Reference {
+    /// Authors, as used in citations (`Doe`, `Doe and Roe`, `Doe et al.`).
+    ///
+    /// Falls back to the title, and then to the key.
+    pub fn author_short(&self) -> String {
+        let names = self
+            .author
+            .iter()
+            .filter_map(ReferenceName::short)
+            .collect::<Vec<_>>();
+
+        match names.len() {
+            0 => self.title.clone().unwrap_or_else(|| self.id.clone()),
+            1 => names[0].into(),
+            2 => format!("{} and {}", names[0], names[1]),
+            _ => format!("{} et al.", names[0]),
+        }
+    }
+
+    /// Authors, as used in reference lists (`Doe, Jane, and John Roe`).
+    pub fn author_long(&self) -> Option<String> {
+        let names = self
+            .author
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| name.long(index == 0))
+            .collect::<Vec<_>>();
+
+        match names.len() {
+            0 => None,
+            1 => Some(names[0].clone()),
+            _ => Some(format!(
+                "{}, and {}",
+                names[..names.len() - 1].join(", "),
+                names[names.len() - 1]
+            )),
+        }
+    }
+
+    /// Year, as used in citations and reference lists (`n.d.` if unknown).
+    pub fn year(&self) -> &str {
+        self.issued.as_deref().unwrap_or("n.d.")
+    }
+}
+
+/// Works that can be cited, from CSL-JSON.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::Bibliography;
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let bibliography = Bibliography::from_csl_json(
+///     r#"[{"id": "doe99", "type": "book", "title": "A", "author": [{"family": "Doe", "given": "Jane"}], "issued": {"date-parts": [[1999]]}}]"#,
+/// )?;
+///
+/// assert_eq!(bibliography.get("doe99").unwrap().author_short(), "Doe");
+/// assert_eq!(bibliography.get("doe99").unwrap().year(), "1999");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bibliography {
+    /// References, in the order of the data.
+    pub references: Vec<Reference>,
+}
+
+impl Bibliography {
+    /// Read a bibliography from CSL-JSON: an array of objects with at least
+    /// an `id`.
+    ///
+    /// ## Errors
+    ///
+    /// `from_csl_json()` errors if `value` is not JSON, or if it is not an
+    /// array of references.
+    pub fn from_csl_json(value: &str) -> Result<Bibliography, Message> {
+        let mut parser = JsonParser {
+            bytes: value.as_bytes(),
+            index: 0,
+        };
+        let json = parser.document()?;
+        let items = if let Json::Array(items) = json {
+            items
+        } else {
+            return Err(error(value, 0, "Expected array of references".into()));
+        };
+        let mut references = vec![];
+
+        for item in items {
+            references.push(reference(value, &item)?);
+        }
+
+        Ok(Bibliography { references })
+    }
+
+    /// Get the reference with the key `id`.
+    pub fn get(&self, id: &str) -> Option<&Reference> {
+        self.references.iter().find(|d| d.id == id)
+    }
+}
+
+/// Value in JSON.
+///
+/// Numbers are kept as they are in the source, booleans are not kept.
+#[derive(Debug)]
+enum Json {
+    Null,
+    Bool,
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Get the field `key` of an object.
+    fn field(&self, key: &str) -> Option<&Json> {
+        if let Json::Object(fields) = self {
+            fields.iter().find(|d| d.0 == key).map(|d| &d.1)
+        } else {
+            None
+        }
+    }
+
+    /// Get a string or number as a string.
+    fn text(&self) -> Option<String> {
+        match self {
+            Json::String(value) | Json::Number(value) if !value.is_empty() => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Turn an object into a reference.
+fn reference(value: &str, item: &Json) -> Result<Reference, Message> {
+    if !matches!(item, Json::Object(_)) {
+        return Err(error(value, 0, "Expected object for reference".into()));
+    }
+
+    let id = item.field("id").and_then(Json::text).ok_or_else(|| {
+        error(
+            value,
+            0,
+            "Expected `id` (string or number) in reference".into(),
+        )
+    })?;
+    let text = |key: &str| item.field(key).and_then(Json::text);
+    let mut author = vec![];
+
+    if let Some(Json::Array(names)) = item.field("author") {
+        for name in names {
+            let name = ReferenceName {
+                family: name.field("family").and_then(Json::text),
+                given: name.field("given").and_then(Json::text),
+                literal: name.field("literal").and_then(Json::text),
+            };
+
+            if name != ReferenceName::default() {
+                author.push(name);
+            }
+        }
+    }
+
+    // Dates are `{"date-parts": [[1999, 1, 2]]}`, or literal.
+    let issued = item.field("issued").and_then(|issued| {
+        issued
+            .field("date-parts")
+            .and_then(|parts| {
+                if let Json::Array(parts) = parts {
+                    if let Some(Json::Array(part)) = parts.first() {
+                        return part.first().and_then(Json::text);
+                    }
+                }
+
+                None
+            })
+            .or_else(|| issued.field("literal").and_then(Json::text))
+            .or_else(|| issued.field("raw").and_then(Json::text))
+            .or_else(|| issued.text())
+    });
+
+    Ok(Reference {
+        kind: text("type"),
+        title: text("title"),
+        author,
+        issued,
+        container_title: text("container-title"),
+        publisher: text("publisher"),
+        publisher_place: text("publisher-place"),
+        volume: text("volume"),
+        issue: text("issue"),
+        page: text("page"),
+        doi: text("DOI"),
+        url: text("URL"),
+        id,
+    })
+}
+
+/// Create an error at byte `index` in `value`.
+fn error(value: &str, index: usize, reason: String) -> Message {
+    let before = &value[..index];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |d| d + 1) + 1;
+
+    Message {
+        place: Some(Box::new(Place::Point(Point::new(line, column, index)))),
+        reason,
+        rule_id: Box::new("csl-json".into()),
+        source: Box::new("markdown-rs".into()),
+        fix: None,
+    }
+}
+
+/// Small JSON parser, for CSL-JSON.
+struct JsonParser<'a> {
+    /// Document.
+    bytes: &'a [u8],
+    /// Current byte index.
+    index: usize,
+}
+
+impl JsonParser<'_> {
+    /// Parse a whole document.
+    fn document(&mut self) -> Result<Json, Message> {
+        let value = self.value()?;
+        self.whitespace();
+
+        if self.index < self.bytes.len() {
+            return Err(self.error("Unexpected content after JSON value"));
+        }
+
+        Ok(value)
+    }
+
+    /// Parse a value.
+    fn value(&mut self) -> Result<Json, Message> {
+        self.whitespace();
+
+        match self.bytes.get(self.index) {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => Ok(Json::String(self.string()?)),
+            Some(b'-' | b'0'..=b'9') => Ok(self.number()),
+            Some(b't') => self.keyword("true", Json::Bool),
+            Some(b'f') => self.keyword("false", Json::Bool),
+            Some(b'n') => self.keyword("null", Json::Null),
+            _ => Err(self.error("Expected JSON value")),
+        }
+    }
+
+    /// Parse an object.
+    fn object(&mut self) -> Result<Json, Message> {
+        let mut fields = vec![];
+        self.index += 1;
+        self.whitespace();
+
+        if self.eat(b'}') {
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            self.whitespace();
+
+            if self.bytes.get(self.index) != Some(&b'"') {
+                return Err(self.error("Expected string for key in object"));
+            }
+
+            let key = self.string()?;
+            self.whitespace();
+
+            if !self.eat(b':') {
+                return Err(self.error("Expected `:` after key in object"));
+            }
+
+            fields.push((key, self.value()?));
+            self.whitespace();
+
+            if self.eat(b'}') {
+                return Ok(Json::Object(fields));
+            }
+
+            if !self.eat(b',') {
+                return Err(self.error("Expected `,` or `}` in object"));
+            }
+        }
+    }
+
+    /// Parse an array.
+    fn array(&mut self) -> Result<Json, Message> {
+        let mut items = vec![];
+        self.index += 1;
+        self.whitespace();
+
+        if self.eat(b']') {
+            return Ok(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+
+            if self.eat(b']') {
+                return Ok(Json::Array(items));
+            }
+
+            if !self.eat(b',') {
+                return Err(self.error("Expected `,` or `]` in array"));
+            }
+        }
+    }
+
+    /// Parse a string.
+    fn string(&mut self) -> Result<String, Message> {
+        let mut result = String::new();
+        self.index += 1;
+        let mut start = self.index;
+
+        loop {
+            match self.bytes.get(self.index) {
+                Some(b'"') => {
+                    result.push_str(self.slice(start, self.index));
+                    self.index += 1;
+                    return Ok(result);
+                }
+                Some(b'\\') => {
+                    result.push_str(self.slice(start, self.index));
+                    self.index += 1;
+                    let byte = self.bytes.get(self.index).copied();
+                    self.index += 1;
+
+                    match byte {
+                        Some(b'"') => result.push('"'),
+                        Some(b'\\') => result.push('\\'),
+                        Some(b'/') => result.push('/'),
+                        Some(b'b') => result.push('\u{8}'),
+                        Some(b'f') => result.push('\u{c}'),
+                        Some(b'n') => result.push('\n'),
+                        Some(b'r') => result.push('\r'),
+                        Some(b't') => result.push('\t'),
+                        Some(b'u') => result.push(self.unicode()?),
+                        _ => {
+                            self.index -= 1;
+                            return Err(self.error("Expected escape in string"));
+                        }
+                    }
+
+                    start = self.index;
+                }
+                Some(0..=0x1F) | None => return Err(self.error("Expected `\"` to end string")),
+                Some(_) => self.index += 1,
+            }
+        }
+    }
+
+    /// Parse the hex digits of a `\u` escape, and of a low surrogate after
+    /// it, if needed.
+    fn unicode(&mut self) -> Result<char, Message> {
+        let high = self.hex()?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if self.bytes.get(self.index) != Some(&b'\\')
+                || self.bytes.get(self.index + 1) != Some(&b'u')
+            {
+                return Err(self.error("Expected low surrogate in string"));
+            }
+
+            self.index += 2;
+            let low = self.hex()?;
+
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("Expected low surrogate in string"));
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("Expected character in string"))
+    }
+
+    /// Parse four hex digits.
+    fn hex(&mut self) -> Result<u32, Message> {
+        let digits = self
+            .bytes
+            .get(self.index..self.index + 4)
+            .and_then(|d| core::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| self.error("Expected four hex digits in escape"))?;
+        self.index += 4;
+        Ok(digits)
+    }
+
+    /// Parse a number, keeping it as it is.
+    fn number(&mut self) -> Json {
+        let start = self.index;
+
+        while matches!(
+            self.bytes.get(self.index),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.index += 1;
+        }
+
+        Json::Number(self.slice(start, self.index).to_string())
+    }
+
+    /// Parse `true`, `false`, or `null`.
+    fn keyword(&mut self, name: &str, value: Json) -> Result<Json, Message> {
+        if self.bytes[self.index..].starts_with(name.as_bytes()) {
+            self.index += name.len();
+            Ok(value)
+        } else {
+            Err(self.error("Expected JSON value"))
+        }
+    }
+
+    /// Skip whitespace.
+    fn whitespace(&mut self) {
+        while matches!(
+            self.bytes.get(self.index),
+            Some(b' ' | b'\t' | b'\n' | b'\r')
+        ) {
+            self.index += 1;
+        }
+    }
+
+    /// Move past `byte`, if it is next.
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.bytes.get(self.index) == Some(&byte) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get a str between two byte indices, which are at ASCII characters.
+    fn slice(&self, start: usize, end: usize) -> &str {
+        core::str::from_utf8(&self.bytes[start..end]).expect("expected valid utf8")
+    }
+
+    /// Create an error at the current byte index.
+    fn error(&self, reason: &str) -> Message {
+        let value = core::str::from_utf8(self.bytes).expect("expected valid utf8");
+        let mut index = self.index.min(value.len());
+
+        while !value.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        error(value, index, reason.into())
+    }
+}
@@ -0,0 +1,62 @@
+//! Deal with block ids.
+
+use crate::event::{Event, Kind, Name};
+
+/// Things that can occur in a list item before its first paragraph.
+const LIST_ITEM_START: [Name; 6] = [
+    Name::BlankLineEnding,
+    Name::LineEnding,
+    Name::ListItemMarker,
+    Name::ListItemPrefix,
+    Name::ListItemValue,
+    Name::SpaceOrTab,
+];
+
+/// Find the element that the block id at `index` applies to.
+///
+/// Returns the index of the enter of that element.
+pub fn target(events: &[Event], index: usize) -> Option<usize> {
+    let mut index = parent(events, index);
+
+    while let Some(enter) = index {
+        match events[enter].name {
+            Name::HeadingAtx | Name::HeadingSetext => return Some(enter),
+            Name::Paragraph => {
+                // The first paragraph in a list item gives its id to the item.
+                if let Some(item) = parent(events, enter) {
+                    if events[item].name == Name::ListItem
+                        && events[item + 1..enter]
+                            .iter()
+                            .all(|d| d.kind == Kind::Exit || LIST_ITEM_START.contains(&d.name))
+                    {
+                        return Some(item);
+                    }
+                }
+
+                return Some(enter);
+            }
+            _ => index = parent(events, enter),
+        }
+    }
+
+    None
+}
+
+/// Find the index of the enter of the element that contains `index`.
+fn parent(events: &[Event], mut index: usize) -> Option<usize> {
+    let mut balance = 0;
+
+    while index > 0 {
+        index -= 1;
+
+        if events[index].kind == Kind::Exit {
+            balance += 1;
+        } else if balance == 0 {
+            return Some(index);
+        } else {
+            balance -= 1;
+        }
+    }
+
+    None
+}
@@ -0,0 +1,12 @@
+//! Deal with bracketed spans.
+
+use crate::event::Event;
+use crate::util::attribute_list::{attributes as attribute_list_attributes, enter, exit};
+use alloc::{string::String, vec::Vec};
+
+/// Get the attributes of the bracketed span whose enter is at `index`.
+pub fn attributes(events: &[Event], bytes: &[u8], index: usize) -> Vec<(String, String)> {
+    // The attributes are the last child.
+    let attributes = enter(events, exit(events, index) - 1);
+    attribute_list_attributes(events, bytes, attributes)
+}
@@ -41,7 +41,7 @@ pub enum Kind {
 /// In most cases, markdown operates on ASCII bytes.
 /// In a few cases, it is unicode aware, so we need to find an actual char.
 pub fn before_index(bytes: &[u8], index: usize) -> Option<char> {
-    let start = if index < 4 { 0 } else { index - 4 };
+    let start = index.saturating_sub(4);
     String::from_utf8_lossy(&bytes[start..index]).chars().last()
 }
 
@@ -115,7 +115,7 @@ pub fn format_opt(char: Option<char>) -> String {
 }
 
 /// Format an optional `byte` (`none` means eof).
-#[cfg(feature = "log")]
+#[cfg(any(feature = "log", feature = "trace"))]
 pub fn format_byte_opt(byte: Option<u8>) -> String {
     byte.map_or("end of file".into(), |byte| {
         format!("byte {}", format_byte(byte))
@@ -192,7 +192,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "log")]
+    #[cfg(any(feature = "log", feature = "trace"))]
     fn test_format_byte_opt() {
         assert_eq!(
             format_byte_opt(None),
@@ -19,7 +19,9 @@ use core::str;
 /// whether the 2125 names from HTML 5 or the 252 names from HTML 4 are
 /// supported.
 ///
-/// The result is `String` instead of `char` because named character references
+/// The lists are sorted by name, so this is a binary search.
+///
+/// The result is `&str` instead of `char` because named character references
 /// can expand into multiple characters.
 ///
 /// ## Examples
@@ -27,22 +29,24 @@ use core::str;
 /// ```rust ignore
 /// use markdown::util::decode_character_reference::decode_named;
 ///
-/// assert_eq!(decode_named("amp", true), "&");
-/// assert_eq!(decode_named("AElig", true), "Æ");
-/// assert_eq!(decode_named("aelig", true), "æ");
+/// assert_eq!(decode_named("amp", true), Some("&"));
+/// assert_eq!(decode_named("AElig", true), Some("Æ"));
+/// assert_eq!(decode_named("aelig", true), Some("æ"));
 /// ```
 ///
 /// ## References
 ///
 /// *   [`wooorm/decode-named-character-reference`](https://github.com/wooorm/decode-named-character-reference)
 /// *   [*§ 2.5 Entity and numeric character references* in `CommonMark`](https://spec.commonmark.org/0.31/#entity-and-numeric-character-references)
-pub fn decode_named(value: &str, html5: bool) -> Option<String> {
-    let mut iter = if html5 {
-        CHARACTER_REFERENCES.iter()
+pub fn decode_named(value: &str, html5: bool) -> Option<&'static str> {
+    let list: &[(&str, &'static str)] = if html5 {
+        &CHARACTER_REFERENCES
     } else {
-        CHARACTER_REFERENCES_HTML_4.iter()
+        &CHARACTER_REFERENCES_HTML_4
     };
-    iter.find(|d| d.0 == value).map(|d| d.1.into())
+    list.binary_search_by(|d| d.0.cmp(value))
+        .ok()
+        .map(|index| list[index].1)
 }
 
 /// Decode numeric character references.
@@ -115,7 +119,7 @@ pub fn decode(value: &str, marker: u8, html5: bool) -> Option<String> {
     match marker {
         b'#' => Some(decode_numeric(value, 10)),
         b'x' => Some(decode_numeric(value, 16)),
-        b'&' => decode_named(value, html5),
+        b'&' => decode_named(value, html5).map(Into::into),
         _ => unreachable!("Unexpected marker `{}`", marker),
     }
 }
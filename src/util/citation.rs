@@ -0,0 +1,136 @@
+//! Deal with citations.
+
+use crate::event::{Event, Kind, Name};
+use crate::mdast::{Citation, CitationItem};
+use crate::unist::{Point, Position};
+use crate::util::slice::{Position as SlicePosition, Slice};
+use alloc::{fmt, rc::Rc, string::String, vec};
+
+/// Signature of a function that turns a citation into HTML.
+pub type Resolve = dyn Fn(&Citation) -> Option<String>;
+
+/// Function to turn citations into HTML.
+///
+/// The function gets the citation, with the key, prefix, locator, and
+/// whether to suppress the author of each cited work.
+/// Return `Some(html)` to show that HTML instead of the citation, such as
+/// the formatted reference from a bibliography.
+/// The HTML is used as it is: it is not sanitized.
+/// Return `None` to compile the citation as it is in the source.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::Citation, CitationResolve};
+/// # fn main() {
+///
+/// // Show the keys in parens:
+/// let resolve = CitationResolve(std::rc::Rc::new(|citation: &Citation| {
+///     let keys = citation.items.iter().map(|d| d.key.as_str()).collect::<Vec<_>>();
+///     Some(format!("({})", keys.join("; ")))
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CitationResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for CitationResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CitationResolve([Function])")
+    }
+}
+
+/// Get the citation whose enter is at `index`.
+pub fn citation(events: &[Event], bytes: &[u8], index: usize) -> Citation {
+    let enter = &events[index];
+    let mut result = Citation {
+        position: None,
+        in_text: bytes[enter.point.index] == b'@',
+        items: vec![],
+    };
+    let mut index = index + 1;
+
+    while !(events[index].kind == Kind::Exit && events[index].name == Name::Citation) {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            if event.name == Name::CitationItem {
+                result.items.push(CitationItem {
+                    key: String::new(),
+                    prefix: None,
+                    locator: None,
+                    suppress_author: false,
+                });
+            } else if let Some(item) = result.items.last_mut() {
+                let slice =
+                    Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index + 1));
+                let value = slice.as_str();
+
+                match event.name {
+                    Name::CitationKey => item.key = value.into(),
+                    Name::CitationLocator => item.locator = Some(value.trim_end().into()),
+                    Name::CitationPrefix => item.prefix = Some(value.trim_end().into()),
+                    Name::CitationSuppressAuthorMarker => item.suppress_author = true,
+                    _ => {}
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    result.position = Some(Position {
+        start: Point::new(enter.point.line, enter.point.column, enter.point.index),
+        end: Point::new(
+            events[index].point.line,
+            events[index].point.column,
+            events[index].point.index,
+        ),
+    });
+
+    result
+}
+
+/// Serialize a citation as markdown.
+pub fn serialize(citation: &Citation) -> String {
+    let mut result = String::new();
+
+    if !citation.in_text {
+        result.push('[');
+    }
+
+    for (index, item) in citation.items.iter().enumerate() {
+        if index > 0 {
+            result.push_str("; ");
+        }
+
+        if let Some(ref prefix) = item.prefix {
+            result.push_str(prefix);
+            result.push(' ');
+        }
+
+        if item.suppress_author {
+            result.push('-');
+        }
+
+        result.push('@');
+        result.push_str(&item.key);
+
+        if let Some(ref locator) = item.locator {
+            if citation.in_text {
+                result.push_str(" [");
+                result.push_str(locator);
+                result.push(']');
+            } else {
+                result.push_str(", ");
+                result.push_str(locator);
+            }
+        }
+    }
+
+    if !citation.in_text {
+        result.push(']');
+    }
+
+    result
+}
@@ -0,0 +1,41 @@
+//! Deal with handling code blocks.
+
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Signature of a function that compiles a code block.
+///
+/// Gets the info string (the `js` in ` ```js eval`), the meta string (the
+/// `eval`), and the code, all as text.
+pub type Handler = dyn Fn(Option<&str>, Option<&str>, &str) -> String;
+
+/// Function to compile code blocks with, such as a syntax highlighter.
+///
+/// The returned HTML is trusted: it is used instead of the
+/// `<pre><code>` that would be generated, and it is not sanitized.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::CodeBlockHandler;
+/// # fn main() {
+///
+/// // Wrap code in a `<figure>`:
+/// let handler = CodeBlockHandler(std::rc::Rc::new(
+///     |lang: Option<&str>, _: Option<&str>, code: &str| {
+///         format!(
+///             "<figure data-lang=\"{}\"><pre>{}</pre></figure>",
+///             lang.unwrap_or("text"),
+///             code.replace('&', "&amp;").replace('<', "&lt;")
+///         )
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CodeBlockHandler(pub Rc<Handler>);
+
+impl fmt::Debug for CodeBlockHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CodeBlockHandler([Function])")
+    }
+}
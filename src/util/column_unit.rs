@@ -0,0 +1,101 @@
+//! Count columns of points in different units.
+
+use crate::mdast::Node;
+use crate::unist::Point;
+use alloc::{vec, vec::Vec};
+
+/// Unit to count the columns of points in.
+///
+/// Columns of points in syntax trees are counted in bytes by default, where
+/// tabs count as the spaces they expand to.
+/// Editors and JavaScript often count columns differently, for example the
+/// language server protocol counts them in UTF-16 code units.
+/// Offsets are always counted in bytes.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ColumnUnit;
+/// # fn main() {
+///
+/// // Count columns in UTF-16 code units, like JavaScript:
+/// let utf16 = ColumnUnit::Utf16;
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ColumnUnit {
+    /// Bytes (UTF-8 code units), where tabs count as the spaces they expand
+    /// to.
+    ///
+    /// The `*` in `é *a*` is at column 4.
+    #[default]
+    Byte,
+    /// UTF-16 code units, where tabs count as one.
+    ///
+    /// The `*` in `é *a*` is at column 3, and in `😀 *a*` at column 4.
+    Utf16,
+    /// Unicode scalar values (Rust `char`s), where tabs count as one.
+    ///
+    /// The `*` in `😀 *a*` is at column 3.
+    Char,
+}
+
+/// Count the columns of all points in `tree` in `unit`.
+///
+/// `tree` must have columns in bytes, as generated by the parser.
+pub fn convert(tree: &mut Node, value: &str, unit: &ColumnUnit) {
+    if *unit == ColumnUnit::Byte {
+        return;
+    }
+
+    let line_starts = line_starts(value);
+    convert_node(tree, value, unit, &line_starts);
+}
+
+/// Get the offsets where lines start in `value`.
+pub fn line_starts(value: &str) -> Vec<usize> {
+    let bytes = value.as_bytes();
+    let mut line_starts = vec![0];
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'\r' && bytes.get(index + 1) != Some(&b'\n') || bytes[index] == b'\n' {
+            line_starts.push(index + 1);
+        }
+
+        index += 1;
+    }
+
+    line_starts
+}
+
+/// Count the columns of the points in `node` and its descendants in `unit`.
+fn convert_node(node: &mut Node, value: &str, unit: &ColumnUnit, line_starts: &[usize]) {
+    if let Some(position) = node.position_mut() {
+        convert_point(&mut position.start, value, unit, line_starts);
+        convert_point(&mut position.end, value, unit, line_starts);
+    }
+
+    if let Some(children) = node.children_mut() {
+        for child in children {
+            convert_node(child, value, unit, line_starts);
+        }
+    }
+}
+
+/// Count the column of `point` in `unit`.
+///
+/// `line_starts` are the offsets where lines start in `value`, see
+/// [`line_starts()`][].
+pub fn convert_point(point: &mut Point, value: &str, unit: &ColumnUnit, line_starts: &[usize]) {
+    let start = line_starts.get(point.line - 1).copied().unwrap_or(0);
+
+    if let Some(before) = value.get(start..point.offset) {
+        let count = match unit {
+            ColumnUnit::Byte => before.len(),
+            ColumnUnit::Utf16 => before.encode_utf16().count(),
+            ColumnUnit::Char => before.chars().count(),
+        };
+        point.column = count + 1;
+    }
+}
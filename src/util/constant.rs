@@ -60,6 +60,23 @@ pub const CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX: usize = 6;
 /// [character_reference]: crate::construct::character_reference
 pub const CHARACTER_REFERENCE_NAMED_SIZE_MAX: usize = 31;
 
+/// The number of markers needed for [block quote (fenced)][block_quote_fenced]
+/// to form.
+///
+/// [block_quote_fenced]: crate::construct::block_quote_fenced
+pub const BLOCK_QUOTE_FENCED_SEQUENCE_SIZE: usize = 3;
+
+/// The number of markers needed for
+/// [directive (container)][directive_container] to form.
+///
+/// [directive_container]: crate::construct::directive_container
+pub const DIRECTIVE_CONTAINER_SEQUENCE_SIZE_MIN: usize = 3;
+
+/// The number of markers needed for [fenced div][fenced_div] to form.
+///
+/// [fenced_div]: crate::construct::fenced_div
+pub const FENCED_DIV_SEQUENCE_SIZE_MIN: usize = 3;
+
 /// The number of markers needed for [code (fenced)][raw_flow] to form.
 ///
 /// Like many things in markdown, the number is `3`.
@@ -74,6 +91,23 @@ pub const CODE_FENCED_SEQUENCE_SIZE_MIN: usize = 3;
 /// [frontmatter]: crate::construct::frontmatter
 pub const FRONTMATTER_SEQUENCE_SIZE: usize = 3;
 
+/// The number of the longest type in [`GFM_ALERT_TYPES`][].
+///
+/// This is currently the size of `important`.
+pub const GFM_ALERT_TYPE_SIZE_MAX: usize = 9;
+
+/// List of types of [GFM alerts][gfm_alert].
+///
+/// Type matching must be performed insensitive to case, and thus this list
+/// includes lowercase types.
+///
+/// ## References
+///
+/// *   [*Alerts* on `docs.github.com`](https://docs.github.com/en/get-started/writing-on-github/getting-started-with-writing-and-formatting-on-github/basic-writing-and-formatting-syntax#alerts)
+///
+/// [gfm_alert]: crate::construct::gfm_alert
+pub const GFM_ALERT_TYPES: [&str; 5] = ["caution", "important", "note", "tip", "warning"];
+
 /// The number of the longest tag name in [`GFM_HTML_TAGFILTER_NAMES`][].
 ///
 /// This is currently the size of `plaintext`.
@@ -99,6 +133,23 @@ pub const GFM_HTML_TAGFILTER_NAMES: [&str; 9] = [
     "xmp",
 ];
 
+/// The number of characters needed for a commit SHA in a
+/// [GitHub reference][github_reference].
+///
+/// To illustrate, this allows `1a2b3c4` and disallows `1a2b3c`.
+///
+/// [github_reference]: crate::construct::github_reference
+pub const GITHUB_REFERENCE_SHA_SIZE_MIN: usize = 7;
+
+/// The number of characters allowed in a commit SHA in a
+/// [GitHub reference][github_reference].
+///
+/// This is the size of a full SHA-1 hash: 40 characters is fine, 41 is too
+/// many.
+///
+/// [github_reference]: crate::construct::github_reference
+pub const GITHUB_REFERENCE_SHA_SIZE_MAX: usize = 40;
+
 /// The number of preceding spaces needed for a [hard break
 /// (trailing)][whitespace] to form.
 ///
@@ -232,6 +283,132 @@ pub const HTML_RAW_NAMES: [&str; 4] = ["pre", "script", "style", "textarea"];
 /// This is currently the size of `textarea`.
 pub const HTML_RAW_SIZE_MAX: usize = 8;
 
+/// List of HTML tag names that are kept by the [HTML sanitizer][sanitize_html].
+///
+/// These are tags for benign formatting.
+/// Tag name matching must be performed insensitive to case, and thus this list
+/// includes lowercase tag names.
+///
+/// [sanitize_html]: crate::util::sanitize_html
+pub const HTML_SANITIZE_TAG_NAMES: [&str; 64] = [
+    "a",
+    "abbr",
+    "b",
+    "bdi",
+    "bdo",
+    "blockquote",
+    "br",
+    "caption",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "kbd",
+    "li",
+    "mark",
+    "ol",
+    "p",
+    "pre",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "small",
+    "span",
+    "strike",
+    "strong",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "tr",
+    "tt",
+    "u",
+    "ul",
+    "var",
+    "wbr",
+];
+
+/// List of HTML attributes that are kept by the [HTML sanitizer][sanitize_html].
+///
+/// Each entry is a tag name and an attribute name.
+/// The tag name `*` means that the attribute is kept on all tags in
+/// [`HTML_SANITIZE_TAG_NAMES`][].
+/// Name matching must be performed insensitive to case, and thus this list
+/// includes lowercase names.
+///
+/// [sanitize_html]: crate::util::sanitize_html
+pub const HTML_SANITIZE_ATTRIBUTES: [(&str, &str); 28] = [
+    ("*", "dir"),
+    ("*", "lang"),
+    ("*", "title"),
+    ("a", "href"),
+    ("blockquote", "cite"),
+    ("col", "span"),
+    ("colgroup", "span"),
+    ("del", "cite"),
+    ("del", "datetime"),
+    ("details", "open"),
+    ("img", "alt"),
+    ("img", "height"),
+    ("img", "src"),
+    ("img", "width"),
+    ("ins", "cite"),
+    ("ins", "datetime"),
+    ("li", "value"),
+    ("ol", "reversed"),
+    ("ol", "start"),
+    ("ol", "type"),
+    ("q", "cite"),
+    ("td", "align"),
+    ("td", "colspan"),
+    ("td", "rowspan"),
+    ("th", "align"),
+    ("th", "colspan"),
+    ("th", "rowspan"),
+    ("time", "datetime"),
+];
+
+/// The default max number of [label starts][label_start_link] that are
+/// tracked at the same time, waiting for a [label end][label_end].
+///
+/// To safeguard performance, label starts are capped at a large number:
+/// `1000`.
+/// When there are more, the earliest ones are turned into data.
+/// Can be configured with `label_start_count_max` in `ParseOptions`.
+///
+/// [label_start_link]: crate::construct::label_start_link
+/// [label_end]: crate::construct::label_end
+pub const LABEL_START_COUNT_MAX: usize = 1000;
+
 /// To safeguard performance, labels are capped at a large number: `999`.
 pub const LINK_REFERENCE_SIZE_MAX: usize = 999;
 
@@ -296,7 +473,7 @@ pub const THEMATIC_BREAK_MARKER_COUNT_MIN: usize = 3;
 
 /// List of names and values that form named [character reference][character_reference]s.
 ///
-/// This list is sensitive to casing.
+/// This list is sensitive to casing, and sorted by name.
 ///
 /// The number of the longest name (`CounterClockwiseContourIntegral`) is also
 /// stored as a constant in [`CHARACTER_REFERENCE_NAMED_SIZE_MAX`][].
@@ -306,7 +483,7 @@ pub const THEMATIC_BREAK_MARKER_COUNT_MIN: usize = 3;
 /// *   [*§ 2.5 Entity and numeric character references* in `CommonMark`](https://spec.commonmark.org/0.31/#entity-and-numeric-character-references)
 ///
 /// [character_reference]: crate::construct::character_reference
-pub const CHARACTER_REFERENCES: [(&str, &str); 2125] = [
+pub static CHARACTER_REFERENCES: [(&str, &str); 2125] = [
     ("AElig", "Æ"),
     ("AMP", "&"),
     ("Aacute", "Á"),
@@ -1264,9 +1441,9 @@ pub const CHARACTER_REFERENCES: [(&str, &str); 2125] = [
     ("empty", "∅"),
     ("emptyset", "∅"),
     ("emptyv", "∅"),
+    ("emsp", " "),
     ("emsp13", " "),
     ("emsp14", " "),
-    ("emsp", " "),
     ("eng", "ŋ"),
     ("ensp", " "),
     ("eogon", "ę"),
@@ -2198,10 +2375,10 @@ pub const CHARACTER_REFERENCES: [(&str, &str); 2125] = [
     ("succsim", "≿"),
     ("sum", "∑"),
     ("sung", "♪"),
+    ("sup", "⊃"),
     ("sup1", "¹"),
     ("sup2", "²"),
     ("sup3", "³"),
-    ("sup", "⊃"),
     ("supE", "⫆"),
     ("supdot", "⪾"),
     ("supdsub", "⫘"),
@@ -2442,7 +2619,7 @@ pub const CHARACTER_REFERENCES: [(&str, &str); 2125] = [
 /// in JSX attribute values, only the old HTML 4 character references are
 /// supported.
 ///
-/// This list is sensitive to casing.
+/// This list is sensitive to casing, and sorted by name.
 ///
 /// ## References
 ///
@@ -2727,6 +2904,24 @@ mod tests {
             "`CHARACTER_REFERENCE_NAMED_SIZE_MAX`"
         );
 
+        assert!(
+            CHARACTER_REFERENCES.windows(2).all(|d| d[0].0 < d[1].0),
+            "`CHARACTER_REFERENCES` should be sorted"
+        );
+
+        assert!(
+            CHARACTER_REFERENCES_HTML_4
+                .windows(2)
+                .all(|d| d[0].0 < d[1].0),
+            "`CHARACTER_REFERENCES_HTML_4` should be sorted"
+        );
+
+        assert_eq!(
+            GFM_ALERT_TYPE_SIZE_MAX,
+            longest(&GFM_ALERT_TYPES).unwrap().len(),
+            "`GFM_ALERT_TYPE_SIZE_MAX`"
+        );
+
         assert_eq!(
             GFM_HTML_TAGFILTER_SIZE_MAX,
             longest(&GFM_HTML_TAGFILTER_NAMES).unwrap().len(),
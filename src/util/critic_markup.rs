@@ -0,0 +1,44 @@
+//! Deal with critic markup.
+
+/// How to compile critic markup.
+///
+/// Critic markup marks changes to a document (additions, deletions, and
+/// substitutions), and adds highlights and comments.
+/// Those changes can be shown, or they can be accepted or rejected to get
+/// the resulting document.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::CriticMarkupMode;
+/// # fn main() {
+///
+/// // Accept all changes:
+/// let accept = CriticMarkupMode::Accept;
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum CriticMarkupMode {
+    /// Show changes.
+    ///
+    /// Additions are compiled to `<ins>`, deletions to `<del>`,
+    /// substitutions to both, highlights to `<mark>`, and comments to
+    /// `<span class="critic-comment">`: `a {~~b~>c~~}` becomes
+    /// `a <del>b</del><ins>c</ins>`.
+    #[default]
+    Visualize,
+    /// Accept changes.
+    ///
+    /// Additions are kept, deletions are dropped, and substitutions are
+    /// replaced by their new text.
+    /// Highlights are kept as plain text, and comments are dropped:
+    /// `a {~~b~>c~~}` becomes `a c`.
+    Accept,
+    /// Reject changes.
+    ///
+    /// Additions are dropped, deletions are kept, and substitutions are
+    /// replaced by their old text.
+    /// Highlights are kept as plain text, and comments are dropped:
+    /// `a {~~b~>c~~}` becomes `a b`.
+    Reject,
+}
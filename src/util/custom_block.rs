@@ -0,0 +1,146 @@
+//! Deal with custom block constructs.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::string::{String, ToString};
+
+/// Kind of custom block construct.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CustomBlockKind {
+    /// Fenced, like a fenced block quote (`>>>`).
+    ///
+    /// An opening fence is the marker, optionally followed by an info
+    /// string, and a closing fence is only the marker.
+    /// Everything between them is the content.
+    ///
+    /// ```markdown
+    /// > | !!! note
+    ///     ^^^^^^^^
+    /// > | a
+    ///     ^
+    /// > | !!!
+    ///     ^^^
+    /// ```
+    Fence,
+    /// Prefixed, like a block quote (`>`).
+    ///
+    /// Each line starts with the marker, optionally followed by a space or
+    /// tab.
+    ///
+    /// ```markdown
+    /// > | % a
+    ///     ^^^
+    /// > | % b
+    ///     ^^^
+    /// ```
+    Prefix,
+}
+
+/// Custom block construct.
+///
+/// A custom block construct is a container, such as a fenced admonition
+/// (`!!! note`), or a prefixed block (`% a`).
+/// The content of the container is parsed as markdown.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{CustomBlock, CustomBlockKind};
+/// # fn main() {
+///
+/// // Support `!!! note` fences for admonitions:
+/// let admonition = CustomBlock {
+///     name: "admonition".into(),
+///     kind: CustomBlockKind::Fence,
+///     marker: "!!!".into(),
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomBlock {
+    /// Name of the construct.
+    ///
+    /// Used as the class name in HTML, and as the name of the
+    /// [`CustomBlock`][crate::mdast::CustomBlock] node in mdast.
+    pub name: String,
+    /// Kind of the construct.
+    pub kind: CustomBlockKind,
+    /// Marker.
+    ///
+    /// Must not be empty, start with whitespace, or contain line endings.
+    pub marker: String,
+}
+
+impl CustomBlock {
+    /// Whether the marker can be used.
+    fn valid(&self) -> bool {
+        !self.marker.is_empty()
+            && !self.marker.starts_with(['\t', ' '])
+            && !self.marker.contains(['\n', '\r'])
+    }
+}
+
+/// Get the index of the construct with the longest marker at `index` in
+/// `bytes`, if any.
+pub fn find(custom_blocks: &[CustomBlock], bytes: &[u8], index: usize) -> Option<usize> {
+    let rest = &bytes[index..];
+    let mut result = None;
+    let mut size = 0;
+
+    for (position, custom_block) in custom_blocks.iter().enumerate() {
+        if custom_block.valid()
+            && custom_block.marker.len() > size
+            && rest.starts_with(custom_block.marker.as_bytes())
+        {
+            result = Some(position);
+            size = custom_block.marker.len();
+        }
+    }
+
+    result
+}
+
+/// Get the custom block construct whose enter is at `index`, and the info
+/// string of its opening fence, if any.
+pub fn custom_block<'a>(
+    custom_blocks: &'a [CustomBlock],
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+) -> (&'a CustomBlock, Option<String>) {
+    let mut marker = index;
+    let mut info = None;
+
+    while events[marker].name != Name::CustomBlockMarker {
+        marker += 1;
+    }
+
+    let kind = if events[marker - 1].name == Name::CustomBlockFence {
+        CustomBlockKind::Fence
+    } else {
+        CustomBlockKind::Prefix
+    };
+    let value = Slice::from_position(bytes, &Position::from_exit_event(events, marker + 1));
+    let mut info_index = marker + 2;
+
+    while events[info_index].name != Name::CustomBlockFence
+        && events[info_index].name != Name::CustomBlockPrefix
+    {
+        if events[info_index].kind == Kind::Enter
+            && events[info_index].name == Name::CustomBlockFenceInfo
+        {
+            let slice =
+                Slice::from_position(bytes, &Position::from_exit_event(events, info_index + 1));
+            info = Some(slice.as_str().trim_end().to_string());
+        }
+
+        info_index += 1;
+    }
+
+    let custom_block = custom_blocks
+        .iter()
+        .find(|d| d.kind == kind && d.marker == value.as_str())
+        .expect("expected custom block to be registered");
+
+    (custom_block, info)
+}
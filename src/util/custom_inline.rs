@@ -0,0 +1,112 @@
+//! Deal with custom inline constructs.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::string::String;
+
+/// Custom inline construct.
+///
+/// A custom inline construct is a pair of delimiters, such as `%%` and
+/// `%%`, or `{{` and `}}`, around text.
+/// The text between them is parsed as markdown.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::CustomInline;
+/// # fn main() {
+///
+/// // Support `++a++` for keyboard keys:
+/// let kbd = CustomInline {
+///     name: "kbd".into(),
+///     open: "++".into(),
+///     close: "++".into(),
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomInline {
+    /// Name of the construct.
+    ///
+    /// Used as the class name in HTML, and as the name of the
+    /// [`CustomInline`][crate::mdast::CustomInline] node in mdast.
+    pub name: String,
+    /// Opening delimiter.
+    ///
+    /// Must not be empty, or contain line endings.
+    pub open: String,
+    /// Closing delimiter.
+    ///
+    /// Must not be empty, or contain line endings.
+    /// Can be the same as the opening delimiter.
+    pub close: String,
+}
+
+impl CustomInline {
+    /// Whether the delimiters can be used.
+    fn valid(&self) -> bool {
+        !self.open.is_empty()
+            && !self.close.is_empty()
+            && !self.open.contains(['\n', '\r'])
+            && !self.close.contains(['\n', '\r'])
+    }
+}
+
+/// Get the first bytes of all delimiters.
+pub fn markers(custom_inlines: &[CustomInline]) -> impl Iterator<Item = u8> + '_ {
+    custom_inlines
+        .iter()
+        .filter(|d| d.valid())
+        .flat_map(|d| [d.open.as_bytes()[0], d.close.as_bytes()[0]])
+}
+
+/// Get the size of the longest delimiter at `index` in `bytes`, if any.
+pub fn sequence_size(custom_inlines: &[CustomInline], bytes: &[u8], index: usize) -> usize {
+    let rest = &bytes[index..];
+    let mut size = 0;
+
+    for custom_inline in custom_inlines.iter().filter(|d| d.valid()) {
+        for delimiter in [&custom_inline.open, &custom_inline.close] {
+            if delimiter.len() > size && rest.starts_with(delimiter.as_bytes()) {
+                size = delimiter.len();
+            }
+        }
+    }
+
+    size
+}
+
+/// Get the custom inline construct whose enter is at `index`.
+pub fn custom_inline<'a>(
+    custom_inlines: &'a [CustomInline],
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+) -> &'a CustomInline {
+    let mut depth = 0;
+    let mut exit = index;
+
+    loop {
+        if events[exit].name == Name::CustomInline {
+            if events[exit].kind == Kind::Enter {
+                depth += 1;
+            } else {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+        }
+
+        exit += 1;
+    }
+
+    let open = Slice::from_position(bytes, &Position::from_exit_event(events, index + 2));
+    let close = Slice::from_position(bytes, &Position::from_exit_event(events, exit - 1));
+
+    custom_inlines
+        .iter()
+        .find(|d| d.open == open.as_str() && d.close == close.as_str())
+        .expect("expected custom inline to be registered")
+}
@@ -0,0 +1,73 @@
+//! Deal with directives.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    attribute_list::attributes as attribute_list_attributes,
+    slice::{Position, Slice},
+};
+use alloc::{string::String, vec::Vec};
+
+/// Get the name and attributes of the directive whose enter is at `index`.
+pub fn name_and_attributes(
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+) -> (String, Vec<(String, String)>) {
+    // The name and attributes of a directive (container) are in its opening
+    // fence.
+    let mut index = if events[index].name == Name::DirectiveContainer {
+        index + 2
+    } else {
+        index + 1
+    };
+    let mut name = String::new();
+    let mut attributes = Vec::new();
+
+    // Walk the children, skipping over the label, which can contain other
+    // directives.
+    while events[index].kind == Kind::Enter {
+        match events[index].name {
+            Name::DirectiveName => {
+                name = Slice::from_position(bytes, &Position::from_exit_event(events, index + 1))
+                    .as_str()
+                    .into();
+            }
+            Name::DirectiveAttributes => {
+                attributes = attribute_list_attributes(events, bytes, index);
+            }
+            _ => {}
+        }
+
+        index = exit(events, index) + 1;
+    }
+
+    (name, attributes)
+}
+
+/// Check whether the directive label string whose enter is at `index` is
+/// the label of a directive (container).
+pub fn container_label(events: &[Event], index: usize) -> bool {
+    // Before the label are its marker, the name, and the sequence.
+    index > 5
+        && events[index - 3].name == Name::DirectiveLabel
+        && events[index - 6].name == Name::DirectiveContainerSequence
+}
+
+/// Find the index of the exit that matches the enter at `index`.
+fn exit(events: &[Event], mut index: usize) -> usize {
+    let mut balance = 0;
+
+    loop {
+        if events[index].kind == Kind::Enter {
+            balance += 1;
+        } else {
+            balance -= 1;
+        }
+
+        if balance == 0 {
+            return index;
+        }
+
+        index += 1;
+    }
+}
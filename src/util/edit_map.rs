@@ -55,16 +55,30 @@ fn shift_links(events: &mut [Event], jumps: &[(usize, usize, usize)]) {
 }
 
 /// Tracks a bunch of edits.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct EditMap {
     /// Record of changes.
-    map: Vec<(usize, usize, Vec<Event>)>,
+    ///
+    /// Each change is where to edit, how many events to remove, which events
+    /// to add, and whether to add them before other additions at that place.
+    /// Changes at the same place are merged when consuming.
+    map: Vec<(usize, usize, Vec<Event>, bool)>,
+    /// List of events that is no longer used, to move events into when
+    /// consuming, instead of allocating a new one each time.
+    spare: Vec<Event>,
 }
 
 impl EditMap {
     /// Create a new edit map.
     pub fn new() -> EditMap {
-        EditMap { map: vec![] }
+        EditMap {
+            map: vec![],
+            spare: vec![],
+        }
+    }
+    /// Drop edits that were not consumed.
+    pub fn clear(&mut self) {
+        self.map.clear();
     }
     /// Create an edit: a remove and/or add at a certain place.
     pub fn add(&mut self, index: usize, remove: usize, add: Vec<Event>) {
@@ -76,20 +90,22 @@ impl EditMap {
     }
     /// Done, change the events.
     pub fn consume(&mut self, events: &mut Vec<Event>) {
-        self.map
-            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
         if self.map.is_empty() {
             return;
         }
 
+        // Stable sort, so that changes at the same place stay in the order
+        // they were added in.
+        self.map.sort_by_key(|d| d.0);
+        merge(&mut self.map);
+
         // Calculate jumps: where items in the current list move to.
         let mut jumps = Vec::with_capacity(self.map.len());
         let mut index = 0;
         let mut add_acc = 0;
         let mut remove_acc = 0;
         while index < self.map.len() {
-            let (at, remove, add) = &self.map[index];
+            let (at, remove, add, _) = &self.map[index];
             remove_acc += remove;
             add_acc += add.len();
             jumps.push((*at, remove_acc, add_acc));
@@ -98,51 +114,67 @@ impl EditMap {
 
         shift_links(events, &jumps);
 
-        let len_before = events.len();
-        let mut index = self.map.len();
-        let mut vecs = Vec::with_capacity(index * 2 + 1);
-        while index > 0 {
-            index -= 1;
-            vecs.push(events.split_off(self.map[index].0 + self.map[index].1));
-            vecs.push(self.map[index].2.split_off(0));
-            events.truncate(self.map[index].0);
-        }
-        vecs.push(events.split_off(0));
-
-        events.reserve(len_before + add_acc - remove_acc);
-
-        while let Some(mut slice) = vecs.pop() {
-            events.append(&mut slice);
+        // Move events into another list in one go, instead of splitting the
+        // current list at every edit, which allocates for each of them.
+        // The current list is then kept, to move events into next time.
+        let mut next = core::mem::take(&mut self.spare);
+        next.reserve(events.len() + add_acc - remove_acc);
+        let mut rest = events.drain(..);
+        let mut start = 0;
+        let mut index = 0;
+        while index < self.map.len() {
+            let (at, remove, add, _) = &mut self.map[index];
+            next.extend(rest.by_ref().take(*at - start));
+            if *remove > 0 {
+                rest.nth(*remove - 1);
+            }
+            next.append(add);
+            start = *at + *remove;
+            index += 1;
         }
+        next.extend(rest);
+        core::mem::swap(events, &mut next);
+        self.spare = next;
 
         self.map.truncate(0);
     }
 }
 
 /// Create an edit.
-fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, mut add: Vec<Event>, before: bool) {
-    let mut index = 0;
-
+fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, add: Vec<Event>, before: bool) {
     if remove == 0 && add.is_empty() {
         return;
     }
 
-    while index < edit_map.map.len() {
-        if edit_map.map[index].0 == at {
-            edit_map.map[index].1 += remove;
+    edit_map.map.push((at, remove, add, before));
+}
 
-            if before {
-                add.append(&mut edit_map.map[index].2);
-                edit_map.map[index].2 = add;
+/// Merge sorted edits at the same place.
+///
+/// This is done when consuming instead of when adding, as looking for an
+/// existing edit at the same place when adding is slow for many edits.
+fn merge(map: &mut Vec<(usize, usize, Vec<Event>, bool)>) {
+    let mut index = 1;
+    let mut last = 0;
+
+    while index < map.len() {
+        if map[index].0 == map[last].0 {
+            let mut add = map[index].2.split_off(0);
+            map[last].1 += map[index].1;
+
+            if map[index].3 {
+                add.append(&mut map[last].2);
+                map[last].2 = add;
             } else {
-                edit_map.map[index].2.append(&mut add);
+                map[last].2.append(&mut add);
             }
-
-            return;
+        } else {
+            last += 1;
+            map.swap(last, index);
         }
 
         index += 1;
     }
 
-    edit_map.map.push((at, remove, add));
+    map.truncate(last + 1);
 }
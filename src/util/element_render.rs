@@ -0,0 +1,86 @@
+//! Deal with rendering elements.
+
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Element that can be rendered with
+/// [`element_render`][crate::CompileOptions::element_render].
+///
+/// Values that are HTML are safe to use as they are: they are encoded and
+/// sanitized like the compiler would do.
+/// Values that are text are not encoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Element<'a> {
+    /// Heading (atx or setext).
+    Heading {
+        /// Rank, from `1` (`<h1>`) to `6` (`<h6>`).
+        rank: u8,
+        /// Id, as HTML, when
+        /// [`heading_ids`][crate::CompileOptions::heading_ids] is turned
+        /// on.
+        id: Option<&'a str>,
+        /// Content, as HTML.
+        children: &'a str,
+    },
+    /// Link (resource, reference, or autolink).
+    Link {
+        /// Destination, as HTML.
+        destination: Option<&'a str>,
+        /// Title, as HTML.
+        title: Option<&'a str>,
+        /// Content, as HTML.
+        children: &'a str,
+    },
+    /// Image (resource or reference).
+    Image {
+        /// Destination, as HTML.
+        destination: Option<&'a str>,
+        /// Title, as HTML.
+        title: Option<&'a str>,
+        /// Alternative text, as HTML.
+        alt: &'a str,
+    },
+    /// Code (fenced or indented).
+    Code {
+        /// Info string (the `js` in ` ```js eval`), as text.
+        lang: Option<&'a str>,
+        /// Meta string (the `eval` in ` ```js eval`), as text.
+        meta: Option<&'a str>,
+        /// Code, as text.
+        value: &'a str,
+    },
+}
+
+/// Signature of a function that renders an element.
+pub type Render = dyn Fn(&Element) -> Option<String>;
+
+/// Function to render elements.
+///
+/// The function gets each [`Element`][] that it can render.
+/// Return `Some(html)` to use that HTML for the element, or `None` to
+/// compile it like normal.
+/// The returned HTML is not sanitized.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{Element, ElementRender};
+/// # fn main() {
+///
+/// // Render headings without `<h1>` and such:
+/// let render = ElementRender(std::rc::Rc::new(|element: &Element| {
+///     if let Element::Heading { rank, children, .. } = element {
+///         Some(format!("<p class=\"h{}\">{}</p>", rank, children))
+///     } else {
+///         None
+///     }
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ElementRender(pub Rc<Render>);
+
+impl fmt::Debug for ElementRender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ElementRender([Function])")
+    }
+}
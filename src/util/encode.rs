@@ -26,30 +26,46 @@ use alloc::string::String;
 pub fn encode(value: &str, encode_html: bool) -> String {
     // It’ll grow a bit bigger for each dangerous character.
     let mut result = String::with_capacity(value.len());
+    encode_to(&mut result, value, encode_html);
+    result
+}
+
+/// Encode dangerous html characters, like [`encode`][], and add the result
+/// to `result`.
+///
+/// This is useful when the result is added to something else anyway: it
+/// does not need a string for the result, and the parts of `value` that
+/// are not dangerous are copied in one go.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::encode_to;
+///
+/// let mut result = String::from("a ");
+/// encode_to(&mut result, "<b>", true);
+/// assert_eq!(result, "a &lt;b&gt;");
+/// ```
+pub fn encode_to(result: &mut String, value: &str, encode_html: bool) {
     let bytes = value.as_bytes();
-    let mut index = 0;
     let mut start = 0;
 
-    while index < bytes.len() {
-        let byte = bytes[index];
-        if matches!(byte, b'\0') || (encode_html && matches!(byte, b'&' | b'"' | b'<' | b'>')) {
-            result.push_str(&value[start..index]);
-            result.push_str(match byte {
-                b'\0' => "�",
-                b'&' => "&amp;",
-                b'"' => "&quot;",
-                b'<' => "&lt;",
-                // `b'>'`
-                _ => "&gt;",
-            });
-
-            start = index + 1;
-        }
-
-        index += 1;
+    while let Some(offset) = bytes[start..]
+        .iter()
+        .position(|d| *d == b'\0' || (encode_html && matches!(d, b'&' | b'"' | b'<' | b'>')))
+    {
+        let index = start + offset;
+        result.push_str(&value[start..index]);
+        result.push_str(match bytes[index] {
+            b'\0' => "�",
+            b'&' => "&amp;",
+            b'"' => "&quot;",
+            b'<' => "&lt;",
+            // `b'>'`
+            _ => "&gt;",
+        });
+        start = index + 1;
     }
 
     result.push_str(&value[start..]);
-
-    result
 }
@@ -0,0 +1,56 @@
+//! Deal with transforming events.
+
+use crate::event::Event;
+use crate::CompileOptions;
+use alloc::{fmt, rc::Rc, vec::Vec};
+
+/// Signature of a function that changes events.
+pub type Transform = dyn Fn(&mut Vec<Event>, &[u8]);
+
+/// Function to change events after parsing, before compiling to HTML.
+///
+/// The function gets the events and the bytes of the document that their
+/// points refer to.
+/// It can drop, retype, or inject events, as long as every enter is
+/// matched by an exit of the same name, and the result is what the compiler
+/// would get from the parser.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::{Event, Kind, Name}, EventTransform};
+/// # fn main() {
+///
+/// // Drop images, and everything in them:
+/// let transform = EventTransform(std::rc::Rc::new(|events: &mut Vec<Event>, _: &[u8]| {
+///     let mut depth = 0;
+///     events.retain(|event| {
+///         if event.name == Name::Image {
+///             if event.kind == Kind::Enter {
+///                 depth += 1;
+///             } else {
+///                 depth -= 1;
+///                 return false;
+///             }
+///         }
+///
+///         depth == 0
+///     });
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EventTransform(pub Rc<Transform>);
+
+impl fmt::Debug for EventTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EventTransform([Function])")
+    }
+}
+
+/// Run the transform in `options`, if there is one, on `events`.
+pub fn transform(events: &mut Vec<Event>, bytes: &[u8], options: &CompileOptions) {
+    if let Some(transform) = &options.event_transform {
+        (transform.0)(events, bytes);
+    }
+}
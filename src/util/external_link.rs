@@ -0,0 +1,50 @@
+//! Deal with external links.
+
+use crate::util::url::host;
+use alloc::{fmt, rc::Rc};
+
+/// Signature of a function that checks whether a URL is external.
+pub type Check = dyn Fn(&str) -> bool;
+
+/// Function to check whether links are external.
+///
+/// The function gets the destination of a link, as it is in the HTML.
+/// Return `true` if it is external, or `false` otherwise.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ExternalLinkCheck;
+/// # fn main() {
+///
+/// // Treat all subdomains of `example.com` as internal:
+/// let check = ExternalLinkCheck(std::rc::Rc::new(|url: &str| {
+///     url.starts_with("https://") && !url[8..].split('/').next().unwrap_or("").ends_with("example.com")
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ExternalLinkCheck(pub Rc<Check>);
+
+impl fmt::Debug for ExternalLinkCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ExternalLinkCheck([Function])")
+    }
+}
+
+/// Whether `url` links to another site than `origin`.
+///
+/// URLs without a host, such as `/a`, `#a`, or `mailto:a@b.c`, are not
+/// external.
+/// Without `origin`, all URLs with a host are external.
+/// Otherwise, URLs whose host is different from the host of `origin` are
+/// external.
+pub fn is_external(url: &str, origin: Option<&str>) -> bool {
+    if let Some(url_host) = host(url) {
+        origin.map_or(true, |origin| {
+            host(origin).map_or(true, |origin_host| origin_host != url_host)
+        })
+    } else {
+        false
+    }
+}
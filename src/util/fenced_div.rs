@@ -0,0 +1,36 @@
+//! Deal with fenced divs.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    attribute_list::attributes as attribute_list_attributes,
+    slice::{Position, Slice},
+};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Get the attributes of the fenced div whose enter is at `index`.
+///
+/// A class on its own is turned into a `class` attribute.
+pub fn attributes(events: &[Event], bytes: &[u8], index: usize) -> Vec<(String, String)> {
+    // The attributes are in the opening fence.
+    let mut index = index + 2;
+
+    while events[index].name != Name::FencedDivFence {
+        if events[index].kind == Kind::Enter {
+            match events[index].name {
+                Name::FencedDivAttributes => {
+                    return attribute_list_attributes(events, bytes, index);
+                }
+                Name::FencedDivClass => {
+                    let class =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index + 1));
+                    return vec![(String::from("class"), class.as_str().into())];
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    vec![]
+}
@@ -0,0 +1,36 @@
+//! Deal with GFM alerts.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    skip,
+    slice::{Position, Slice},
+};
+use alloc::string::String;
+
+/// Get the type of the GFM alert in the block quote whose enter is at
+/// `index`, if there is one.
+///
+/// The type is lowercase, such as `note`.
+pub fn alert_type(events: &[Event], bytes: &[u8], index: usize) -> Option<String> {
+    let paragraph = skip::opt(events, index + 1, &[Name::BlockQuotePrefix]);
+
+    if events[paragraph].kind == Kind::Enter
+        && events[paragraph].name == Name::Paragraph
+        && events[paragraph + 1].name == Name::GfmAlert
+    {
+        let index = skip::to(events, paragraph + 1, &[Name::GfmAlertType]);
+        let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index + 1));
+        Some(slice.as_str().to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Get the title of a GFM alert of `kind` (such as `note`): its capitalized
+/// form (such as `Note`).
+pub fn title(kind: &str) -> String {
+    let mut title = String::new();
+    title.push_str(&kind[0..1].to_ascii_uppercase());
+    title.push_str(&kind[1..]);
+    title
+}
@@ -0,0 +1,91 @@
+//! Deal with GitHub references.
+
+use crate::mdast::{GithubReference, GithubReferenceKind};
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Signature of a function that turns a GitHub reference into a URL.
+pub type Resolve = dyn Fn(&GithubReference) -> Option<String>;
+
+/// Function to turn GitHub references into links.
+///
+/// The function gets the reference, without position info.
+/// Return `Some(url)` to link to the issue, pull request, or commit, or
+/// `None` to leave the reference as plain text.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::GithubReferenceKind, GithubReferenceResolve};
+/// # fn main() {
+///
+/// // Link to GitHub, in `wooorm/markdown-rs` by default:
+/// let resolve = GithubReferenceResolve(std::rc::Rc::new(|reference| {
+///     let repository = reference
+///         .repository
+///         .as_deref()
+///         .unwrap_or("wooorm/markdown-rs");
+///     let kind = match reference.kind {
+///         GithubReferenceKind::Issue => "issues",
+///         GithubReferenceKind::Commit => "commit",
+///     };
+///     Some(format!(
+///         "https://github.com/{}/{}/{}",
+///         repository, kind, reference.value
+///     ))
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GithubReferenceResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for GithubReferenceResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GithubReferenceResolve([Function])")
+    }
+}
+
+/// Turn a reference, as it is in the source, into a node.
+///
+/// The reference is expected to be valid: see
+/// [`github_reference`][crate::construct::github_reference].
+pub fn reference(value: &str) -> GithubReference {
+    let (repository, rest) = if let Some(slash) = value.find('/') {
+        let marker = slash + value[slash..].find(['#', '@']).unwrap();
+        (Some(String::from(&value[..marker])), &value[marker..])
+    } else {
+        (None, value)
+    };
+
+    let (kind, value) = if let Some(number) = rest.strip_prefix('#') {
+        (GithubReferenceKind::Issue, number)
+    } else if let Some(number) = rest.strip_prefix("GH-") {
+        (GithubReferenceKind::Issue, number)
+    } else if let Some(sha) = rest.strip_prefix('@') {
+        (GithubReferenceKind::Commit, sha)
+    } else {
+        (GithubReferenceKind::Commit, rest)
+    };
+
+    GithubReference {
+        position: None,
+        kind,
+        repository,
+        value: value.into(),
+    }
+}
+
+/// Turn a node into a reference, as it could be in the source.
+///
+/// Issues are serialized with `#`, so `GH-1` turns into `#1`.
+pub fn serialize(reference: &GithubReference) -> String {
+    let mut value = reference.repository.clone().unwrap_or_default();
+
+    match reference.kind {
+        GithubReferenceKind::Issue => value.push('#'),
+        GithubReferenceKind::Commit if reference.repository.is_some() => value.push('@'),
+        GithubReferenceKind::Commit => {}
+    }
+
+    value.push_str(&reference.value);
+    value
+}
@@ -0,0 +1,205 @@
+//! Deal with proxying images.
+
+use alloc::{string::String, vec::Vec};
+
+/// Configuration for routing images through a proxy, like GitHub’s camo.
+///
+/// The source of an external image is replaced by
+/// [`url`][ImageProxy::url], where `{digest}` is the HMAC-SHA1 of the
+/// source with [`key`][ImageProxy::key], and `{url}` is the source, both
+/// hex encoded.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ImageProxy;
+/// # fn main() {
+///
+/// // Use camo:
+/// let camo = ImageProxy {
+///     url: "https://camo.example.com/{digest}/{url}".into(),
+///     key: "secret".into(),
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ImageProxy {
+    /// Template of the URL of the proxy.
+    ///
+    /// `{digest}` and `{url}` in it are replaced.
+    pub url: String,
+    /// Key shared with the proxy, to sign sources with.
+    pub key: String,
+}
+
+/// Get the URL of `src` through `proxy`.
+pub fn proxy(proxy: &ImageProxy, src: &str) -> String {
+    let digest = hmac_sha1(proxy.key.as_bytes(), src.as_bytes());
+    proxy
+        .url
+        .replace("{digest}", &hex(&digest))
+        .replace("{url}", &hex(src.as_bytes()))
+}
+
+/// Encode bytes as lowercase hexadecimal.
+fn hex(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 2);
+
+    for byte in bytes {
+        result.push(char::from(b"0123456789abcdef"[usize::from(byte >> 4)]));
+        result.push(char::from(b"0123456789abcdef"[usize::from(byte & 0x0F)]));
+    }
+
+    result
+}
+
+/// Compute the HMAC-SHA1 of `message` with `key`.
+///
+/// ## References
+///
+/// *   [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104)
+fn hmac_sha1(key: &[u8], message: &[u8]) -> [u8; 20] {
+    let mut block = [0; 64];
+
+    if key.len() > 64 {
+        block[..20].copy_from_slice(&sha1(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Vec::with_capacity(64 + message.len());
+    inner.extend(block.iter().map(|d| d ^ 0x36));
+    inner.extend_from_slice(message);
+
+    let mut outer = Vec::with_capacity(64 + 20);
+    outer.extend(block.iter().map(|d| d ^ 0x5C));
+    outer.extend_from_slice(&sha1(&inner));
+
+    sha1(&outer)
+}
+
+/// Compute the SHA-1 of `message`.
+///
+/// ## References
+///
+/// *   [RFC 3174](https://www.rfc-editor.org/rfc/rfc3174)
+// Names match those in the RFC.
+#[allow(clippy::many_single_char_names)]
+fn sha1(message: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+    ];
+    let mut data = message.to_vec();
+    let size = (message.len() as u64).wrapping_mul(8);
+
+    data.push(0x80);
+
+    while data.len() % 64 != 56 {
+        data.push(0);
+    }
+
+    data.extend_from_slice(&size.to_be_bytes());
+
+    for chunk in data.chunks(64) {
+        let mut words = [0u32; 80];
+
+        for (index, word) in chunk.chunks(4).enumerate() {
+            words[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        for index in 16..80 {
+            words[index] =
+                (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16])
+                    .rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+
+        for (index, word) in words.iter().enumerate() {
+            let (f, k) = match index {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let value = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = value;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+    }
+
+    let mut result = [0; 20];
+
+    for (index, value) in state.iter().enumerate() {
+        result[(index * 4)..(index * 4 + 4)].copy_from_slice(&value.to_be_bytes());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha1() {
+        assert_eq!(
+            hex(&sha1(b"")),
+            "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+            "should support an empty message"
+        );
+        assert_eq!(
+            hex(&sha1(b"abc")),
+            "a9993e364706816aba3e25717850c26c9cd0d89d",
+            "should support a short message"
+        );
+        assert_eq!(
+            hex(&sha1(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+            "should support a message of two blocks"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha1() {
+        // See: <https://www.rfc-editor.org/rfc/rfc2202#section-3>.
+        assert_eq!(
+            hex(&hmac_sha1(&[0x0B; 20], b"Hi There")),
+            "b617318655057264e28bc0b6fb378c8ef146be00",
+            "should support test case 1"
+        );
+        assert_eq!(
+            hex(&hmac_sha1(b"Jefe", b"what do ya want for nothing?")),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+            "should support test case 2"
+        );
+        assert_eq!(
+            hex(&hmac_sha1(
+                &[0xAA; 80],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "aa4ae5e15272d00e95705637ce8a3b55ed402112",
+            "should support keys longer than a block"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Deal with resolving image sources.
+
+use alloc::{fmt, rc::Rc, string::String, vec::Vec};
+
+/// What to use for an image, from [`ImageResolve`][].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolvedImage {
+    /// Source, which is sanitized like any other destination.
+    pub src: String,
+    /// Other attributes to add, such as `width`, `height`, or `srcset`.
+    ///
+    /// Names and values are encoded.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Signature of a function that turns an image into a source and
+/// attributes.
+pub type Resolve = dyn Fn(&str, &str, Option<&str>) -> Option<ResolvedImage>;
+
+/// Function to turn images into sources and attributes.
+///
+/// The function gets the source as it is in the source, with character
+/// escapes and references decoded (`b` in `![a](b "c")`), and the
+/// alternative text (`a`) and title (`c`), if there is one, as HTML.
+/// Return `Some(image)` to use another source and add attributes, such as
+/// to point to a fingerprinted file on a CDN, or `None` to compile the image
+/// like normal.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{ImageResolve, ResolvedImage};
+/// # fn main() {
+///
+/// // Serve images from a CDN, with their size:
+/// let resolve = ImageResolve(std::rc::Rc::new(|src: &str, _alt: &str, _title: Option<&str>| {
+///     Some(ResolvedImage {
+///         src: format!("https://cdn.example.com/{}", src),
+///         attributes: vec![("width".into(), "640".into()), ("height".into(), "480".into())],
+///     })
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ImageResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for ImageResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ImageResolve([Function])")
+    }
+}
@@ -62,8 +62,20 @@ pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bo
                         before -= 2;
                     }
 
-                    if events[before].name == Name::BlockQuote
-                        && events[before - 1].name == Name::BlockQuotePrefix
+                    if (events[before].name == Name::BlockQuote
+                        && matches!(
+                            events[before - 1].name,
+                            Name::BlockQuotePrefix | Name::BlockQuoteFencedFence
+                        ))
+                        || (events[before].name == Name::CustomBlock
+                            && matches!(
+                                events[before - 1].name,
+                                Name::CustomBlockFence | Name::CustomBlockPrefix
+                            ))
+                        || (events[before].name == Name::DirectiveContainer
+                            && events[before - 1].name == Name::DirectiveContainerFence)
+                        || (events[before].name == Name::FencedDiv
+                            && events[before - 1].name == Name::FencedDivFence)
                     {
                         at_empty_block_quote = true;
                     } else if events[before].name == Name::ListItemPrefix {
@@ -0,0 +1,66 @@
+//! Deal with resolving link destinations.
+
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Kind of link.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkKind {
+    /// Link (resource), with the destination in it.
+    ///
+    /// ```markdown
+    /// > | [a](b)
+    ///     ^^^^^^
+    /// ```
+    Resource,
+    /// Link (reference), with the destination in a definition.
+    ///
+    /// ```markdown
+    /// > | [a][b]
+    ///     ^^^^^^
+    ///   |
+    ///   | [b]: c
+    /// ```
+    Reference,
+    /// Autolink, including GFM autolink literals.
+    ///
+    /// ```markdown
+    /// > | <https://example.com>
+    ///     ^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Autolink,
+}
+
+/// Signature of a function that turns the destination of a link into a URL.
+pub type Resolve = dyn Fn(&str, LinkKind) -> Option<String>;
+
+/// Function to turn the destinations of links into URLs.
+///
+/// The function gets the destination as it is in the source, with character
+/// escapes and references decoded (`b` in `[a](b)`), and the kind of link.
+/// Return `Some(url)` to link to that URL, which is then sanitized like any
+/// other destination, or `None` to drop the link and keep its content.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{LinkKind, LinkResolve};
+/// # fn main() {
+///
+/// // Link `a.md` to `/a/`:
+/// let resolve = LinkResolve(std::rc::Rc::new(|destination: &str, _: LinkKind| {
+///     if let Some(name) = destination.strip_suffix(".md") {
+///         Some(format!("/{}/", name))
+///     } else {
+///         Some(destination.into())
+///     }
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LinkResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for LinkResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LinkResolve([Function])")
+    }
+}
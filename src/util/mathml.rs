@@ -0,0 +1,372 @@
+//! Turn TeX into `MathML`.
+//!
+//! Only a small subset of TeX is supported: letters, numbers, operators,
+//! groups (`{a}`), superscripts and subscripts (`a^b`, `a_b`), fractions
+//! (`\frac{a}{b}`), roots (`\sqrt{a}`, `\sqrt[n]{a}`), text (`\text{a}`),
+//! spacing (`\,`, `\quad`), delimiters (`\left(`, `\right)`), and common
+//! commands for Greek letters, operators, arrows, and functions.
+//! Unknown commands are turned into `<merror>`.
+//!
+//! The TeX itself is always kept in an `<annotation>`, so that scripts such
+//! as `KaTeX` or `MathJax` can still render it.
+
+use crate::util::encode::encode;
+use alloc::{format, string::String, vec::Vec};
+
+/// Turn `value` (TeX) into the content of a `<math>` element.
+pub fn to_mathml(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut index = 0;
+    let result = expression(&chars, &mut index, false);
+
+    format!(
+        "<semantics><mrow>{}</mrow><annotation encoding=\"application/x-tex\">{}</annotation></semantics>",
+        result,
+        encode(value, true)
+    )
+}
+
+/// Turn a list of atoms into `MathML`, until `}` if `group`, or the end.
+fn expression(chars: &[char], index: &mut usize, group: bool) -> String {
+    let mut result = String::new();
+
+    loop {
+        skip_whitespace(chars, index);
+
+        match chars.get(*index) {
+            None => break,
+            Some('}') if group => {
+                *index += 1;
+                break;
+            }
+            _ => {}
+        }
+
+        let base = atom(chars, index);
+        result.push_str(&scripts(chars, index, base));
+    }
+
+    result
+}
+
+/// Add superscripts and subscripts (`^`, `_`) after `base`, if any.
+fn scripts(chars: &[char], index: &mut usize, base: String) -> String {
+    let mut sub = None;
+    let mut sup = None;
+
+    loop {
+        skip_whitespace(chars, index);
+
+        match chars.get(*index) {
+            Some('_') if sub.is_none() => {
+                *index += 1;
+                sub = Some(argument(chars, index));
+            }
+            Some('^') if sup.is_none() => {
+                *index += 1;
+                sup = Some(argument(chars, index));
+            }
+            _ => break,
+        }
+    }
+
+    match (sub, sup) {
+        (Some(sub), Some(sup)) => format!("<msubsup>{}{}{}</msubsup>", base, sub, sup),
+        (Some(sub), None) => format!("<msub>{}{}</msub>", base, sub),
+        (None, Some(sup)) => format!("<msup>{}{}</msup>", base, sup),
+        (None, None) => base,
+    }
+}
+
+/// Turn one argument (an atom or a group) into one `MathML` element.
+fn argument(chars: &[char], index: &mut usize) -> String {
+    skip_whitespace(chars, index);
+
+    if *index < chars.len() {
+        atom(chars, index)
+    } else {
+        "<mrow></mrow>".into()
+    }
+}
+
+/// Turn one atom into one `MathML` element.
+fn atom(chars: &[char], index: &mut usize) -> String {
+    let char = chars[*index];
+    *index += 1;
+
+    match char {
+        '{' => format!("<mrow>{}</mrow>", expression(chars, index, true)),
+        '\\' => command(chars, index),
+        '0'..='9' | '.' => {
+            let start = *index - 1;
+            while *index < chars.len() && matches!(chars[*index], '0'..='9' | '.') {
+                *index += 1;
+            }
+            let value: String = chars[start..*index].iter().collect();
+            format!("<mn>{}</mn>", value)
+        }
+        '-' => "<mo>\u{2212}</mo>".into(),
+        '\'' => "<mo>\u{2032}</mo>".into(),
+        // Unmatched closing brace.
+        '}' => "<merror><mtext>}</mtext></merror>".into(),
+        _ if char.is_alphabetic() => format!("<mi>{}</mi>", char),
+        _ => format!("<mo>{}</mo>", encode(&String::from(char), true)),
+    }
+}
+
+/// Turn a command (after `\`) into one `MathML` element.
+fn command(chars: &[char], index: &mut usize) -> String {
+    let start = *index;
+
+    while *index < chars.len() && chars[*index].is_ascii_alphabetic() {
+        *index += 1;
+    }
+
+    // A single non-letter, such as `\{` or `\,`.
+    if *index == start {
+        return match chars.get(*index) {
+            None => "<merror><mtext>\\</mtext></merror>".into(),
+            Some(char) => {
+                *index += 1;
+                match char {
+                    ',' => "<mspace width=\"0.1667em\" />".into(),
+                    ':' | '>' => "<mspace width=\"0.2222em\" />".into(),
+                    ';' => "<mspace width=\"0.2778em\" />".into(),
+                    ' ' => "<mspace width=\"0.3333em\" />".into(),
+                    '!' => "<mspace width=\"-0.1667em\" />".into(),
+                    _ => format!("<mo>{}</mo>", encode(&String::from(*char), true)),
+                }
+            }
+        };
+    }
+
+    let name: String = chars[start..*index].iter().collect();
+
+    match name.as_str() {
+        "frac" | "dfrac" | "tfrac" => {
+            let numerator = argument(chars, index);
+            let denominator = argument(chars, index);
+            format!("<mfrac>{}{}</mfrac>", numerator, denominator)
+        }
+        "sqrt" => {
+            skip_whitespace(chars, index);
+
+            if chars.get(*index) == Some(&'[') {
+                *index += 1;
+                let mut degree = String::new();
+                while *index < chars.len() && chars[*index] != ']' {
+                    let base = atom(chars, index);
+                    degree.push_str(&scripts(chars, index, base));
+                }
+                *index += 1;
+                let radicand = argument(chars, index);
+                format!("<mroot>{}<mrow>{}</mrow></mroot>", radicand, degree)
+            } else {
+                format!("<msqrt>{}</msqrt>", argument(chars, index))
+            }
+        }
+        "text" | "textrm" | "mathrm" | "operatorname" => {
+            skip_whitespace(chars, index);
+            let value = if chars.get(*index) == Some(&'{') {
+                *index += 1;
+                let start = *index;
+                while *index < chars.len() && chars[*index] != '}' {
+                    *index += 1;
+                }
+                let value: String = chars[start..*index].iter().collect();
+                *index += 1;
+                value
+            } else if *index < chars.len() {
+                *index += 1;
+                String::from(chars[*index - 1])
+            } else {
+                String::new()
+            };
+            let tag = if name == "text" || name == "textrm" {
+                "mtext"
+            } else {
+                "mi"
+            };
+            format!("<{}>{}</{}>", tag, encode(&value, true), tag)
+        }
+        "left" | "right" | "big" | "Big" | "bigg" | "Bigg" => {
+            skip_whitespace(chars, index);
+            match chars.get(*index) {
+                // `\left.` is an invisible delimiter.
+                Some('.') => {
+                    *index += 1;
+                    String::new()
+                }
+                Some(_) => atom(chars, index),
+                None => String::new(),
+            }
+        }
+        "quad" => "<mspace width=\"1em\" />".into(),
+        "qquad" => "<mspace width=\"2em\" />".into(),
+        _ => {
+            if let Some(value) = symbol(&name) {
+                value.into()
+            } else {
+                format!("<merror><mtext>\\{}</mtext></merror>", name)
+            }
+        }
+    }
+}
+
+/// Get the `MathML` of a command without arguments.
+fn symbol(name: &str) -> Option<&'static str> {
+    let value = match name {
+        // Greek, lowercase.
+        "alpha" => "<mi>\u{3b1}</mi>",
+        "beta" => "<mi>\u{3b2}</mi>",
+        "gamma" => "<mi>\u{3b3}</mi>",
+        "delta" => "<mi>\u{3b4}</mi>",
+        "epsilon" => "<mi>\u{3f5}</mi>",
+        "varepsilon" => "<mi>\u{3b5}</mi>",
+        "zeta" => "<mi>\u{3b6}</mi>",
+        "eta" => "<mi>\u{3b7}</mi>",
+        "theta" => "<mi>\u{3b8}</mi>",
+        "vartheta" => "<mi>\u{3d1}</mi>",
+        "iota" => "<mi>\u{3b9}</mi>",
+        "kappa" => "<mi>\u{3ba}</mi>",
+        "lambda" => "<mi>\u{3bb}</mi>",
+        "mu" => "<mi>\u{3bc}</mi>",
+        "nu" => "<mi>\u{3bd}</mi>",
+        "xi" => "<mi>\u{3be}</mi>",
+        "pi" => "<mi>\u{3c0}</mi>",
+        "varpi" => "<mi>\u{3d6}</mi>",
+        "rho" => "<mi>\u{3c1}</mi>",
+        "varrho" => "<mi>\u{3f1}</mi>",
+        "sigma" => "<mi>\u{3c3}</mi>",
+        "varsigma" => "<mi>\u{3c2}</mi>",
+        "tau" => "<mi>\u{3c4}</mi>",
+        "upsilon" => "<mi>\u{3c5}</mi>",
+        "phi" => "<mi>\u{3d5}</mi>",
+        "varphi" => "<mi>\u{3c6}</mi>",
+        "chi" => "<mi>\u{3c7}</mi>",
+        "psi" => "<mi>\u{3c8}</mi>",
+        "omega" => "<mi>\u{3c9}</mi>",
+        // Greek, uppercase.
+        "Gamma" => "<mi mathvariant=\"normal\">\u{393}</mi>",
+        "Delta" => "<mi mathvariant=\"normal\">\u{394}</mi>",
+        "Theta" => "<mi mathvariant=\"normal\">\u{398}</mi>",
+        "Lambda" => "<mi mathvariant=\"normal\">\u{39b}</mi>",
+        "Xi" => "<mi mathvariant=\"normal\">\u{39e}</mi>",
+        "Pi" => "<mi mathvariant=\"normal\">\u{3a0}</mi>",
+        "Sigma" => "<mi mathvariant=\"normal\">\u{3a3}</mi>",
+        "Upsilon" => "<mi mathvariant=\"normal\">\u{3a5}</mi>",
+        "Phi" => "<mi mathvariant=\"normal\">\u{3a6}</mi>",
+        "Psi" => "<mi mathvariant=\"normal\">\u{3a8}</mi>",
+        "Omega" => "<mi mathvariant=\"normal\">\u{3a9}</mi>",
+        // Other identifiers.
+        "infty" => "<mi>\u{221e}</mi>",
+        "partial" => "<mi>\u{2202}</mi>",
+        "nabla" => "<mi>\u{2207}</mi>",
+        "ell" => "<mi>\u{2113}</mi>",
+        "hbar" => "<mi>\u{210f}</mi>",
+        "emptyset" => "<mi>\u{2205}</mi>",
+        // Functions.
+        "sin" => "<mi>sin</mi>",
+        "cos" => "<mi>cos</mi>",
+        "tan" => "<mi>tan</mi>",
+        "cot" => "<mi>cot</mi>",
+        "sec" => "<mi>sec</mi>",
+        "csc" => "<mi>csc</mi>",
+        "arcsin" => "<mi>arcsin</mi>",
+        "arccos" => "<mi>arccos</mi>",
+        "arctan" => "<mi>arctan</mi>",
+        "sinh" => "<mi>sinh</mi>",
+        "cosh" => "<mi>cosh</mi>",
+        "tanh" => "<mi>tanh</mi>",
+        "log" => "<mi>log</mi>",
+        "ln" => "<mi>ln</mi>",
+        "exp" => "<mi>exp</mi>",
+        "lim" => "<mi>lim</mi>",
+        "max" => "<mi>max</mi>",
+        "min" => "<mi>min</mi>",
+        "sup" => "<mi>sup</mi>",
+        "inf" => "<mi>inf</mi>",
+        "det" => "<mi>det</mi>",
+        "gcd" => "<mi>gcd</mi>",
+        // Large operators.
+        "sum" => "<mo>\u{2211}</mo>",
+        "prod" => "<mo>\u{220f}</mo>",
+        "int" => "<mo>\u{222b}</mo>",
+        "iint" => "<mo>\u{222c}</mo>",
+        "oint" => "<mo>\u{222e}</mo>",
+        "bigcup" => "<mo>\u{22c3}</mo>",
+        "bigcap" => "<mo>\u{22c2}</mo>",
+        // Binary operators.
+        "pm" => "<mo>\u{b1}</mo>",
+        "mp" => "<mo>\u{2213}</mo>",
+        "times" => "<mo>\u{d7}</mo>",
+        "div" => "<mo>\u{f7}</mo>",
+        "cdot" => "<mo>\u{22c5}</mo>",
+        "ast" => "<mo>\u{2217}</mo>",
+        "circ" => "<mo>\u{2218}</mo>",
+        "cup" => "<mo>\u{222a}</mo>",
+        "cap" => "<mo>\u{2229}</mo>",
+        "setminus" => "<mo>\u{2216}</mo>",
+        "wedge" | "land" => "<mo>\u{2227}</mo>",
+        "vee" | "lor" => "<mo>\u{2228}</mo>",
+        "oplus" => "<mo>\u{2295}</mo>",
+        "otimes" => "<mo>\u{2297}</mo>",
+        // Relations.
+        "leq" | "le" => "<mo>\u{2264}</mo>",
+        "geq" | "ge" => "<mo>\u{2265}</mo>",
+        "neq" | "ne" => "<mo>\u{2260}</mo>",
+        "approx" => "<mo>\u{2248}</mo>",
+        "equiv" => "<mo>\u{2261}</mo>",
+        "sim" => "<mo>\u{223c}</mo>",
+        "simeq" => "<mo>\u{2243}</mo>",
+        "propto" => "<mo>\u{221d}</mo>",
+        "ll" => "<mo>\u{226a}</mo>",
+        "gg" => "<mo>\u{226b}</mo>",
+        "in" => "<mo>\u{2208}</mo>",
+        "notin" => "<mo>\u{2209}</mo>",
+        "ni" => "<mo>\u{220b}</mo>",
+        "subset" => "<mo>\u{2282}</mo>",
+        "supset" => "<mo>\u{2283}</mo>",
+        "subseteq" => "<mo>\u{2286}</mo>",
+        "supseteq" => "<mo>\u{2287}</mo>",
+        "mid" => "<mo>\u{2223}</mo>",
+        "parallel" => "<mo>\u{2225}</mo>",
+        "perp" => "<mo>\u{22a5}</mo>",
+        // Arrows.
+        "to" | "rightarrow" => "<mo>\u{2192}</mo>",
+        "leftarrow" | "gets" => "<mo>\u{2190}</mo>",
+        "leftrightarrow" => "<mo>\u{2194}</mo>",
+        "Rightarrow" | "implies" => "<mo>\u{21d2}</mo>",
+        "Leftarrow" => "<mo>\u{21d0}</mo>",
+        "Leftrightarrow" | "iff" => "<mo>\u{21d4}</mo>",
+        "mapsto" => "<mo>\u{21a6}</mo>",
+        // Logic.
+        "forall" => "<mo>\u{2200}</mo>",
+        "exists" => "<mo>\u{2203}</mo>",
+        "neg" | "lnot" => "<mo>\u{ac}</mo>",
+        // Delimiters.
+        "langle" => "<mo>\u{27e8}</mo>",
+        "rangle" => "<mo>\u{27e9}</mo>",
+        "lfloor" => "<mo>\u{230a}</mo>",
+        "rfloor" => "<mo>\u{230b}</mo>",
+        "lceil" => "<mo>\u{2308}</mo>",
+        "rceil" => "<mo>\u{2309}</mo>",
+        "vert" => "<mo>|</mo>",
+        "Vert" => "<mo>\u{2016}</mo>",
+        // Dots.
+        "ldots" | "dots" => "<mo>\u{2026}</mo>",
+        "cdots" => "<mo>\u{22ef}</mo>",
+        "vdots" => "<mo>\u{22ee}</mo>",
+        "ddots" => "<mo>\u{22f1}</mo>",
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Move `index` past whitespace.
+fn skip_whitespace(chars: &[char], index: &mut usize) {
+    while *index < chars.len() && chars[*index].is_whitespace() {
+        *index += 1;
+    }
+}
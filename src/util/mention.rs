@@ -0,0 +1,48 @@
+//! Deal with mentions.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Signature of a function that turns the name of a mention into a URL and
+/// label.
+pub type Resolve = dyn Fn(&str) -> Option<(String, String)>;
+
+/// Function to turn mentions into links.
+///
+/// The function gets the name as it is in the source (`alice` in `@alice`).
+/// Return `Some((url, label))` to link to the user, with the label (such as
+/// their display name) as the content of the link, or `None` if there is no
+/// such user, which compiles the mention as plain text.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::MentionResolve;
+/// # fn main() {
+///
+/// // Link to profiles in `/u/`:
+/// let resolve = MentionResolve(std::rc::Rc::new(|name: &str| {
+///     Some((format!("/u/{}", name), format!("@{}", name)))
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MentionResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for MentionResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MentionResolve([Function])")
+    }
+}
+
+/// Get the name of the mention whose enter is at `index`.
+pub fn name(events: &[Event], bytes: &[u8], mut index: usize) -> String {
+    while !(events[index].kind == Kind::Enter && events[index].name == Name::MentionName) {
+        index += 1;
+    }
+
+    Slice::from_position(bytes, &Position::from_exit_event(events, index + 1))
+        .as_str()
+        .into()
+}
@@ -1,19 +1,54 @@
 //! Utilities used when processing markdown.
 
+pub mod abbreviation;
+pub mod attribute_list;
+pub mod bibliography;
+pub mod block_id;
+pub mod bracketed_span;
 pub mod char;
 pub mod character_reference;
+pub mod citation;
+pub mod code_block_handler;
+pub mod column_unit;
 pub mod constant;
+pub mod critic_markup;
+pub mod custom_block;
+pub mod custom_inline;
+pub mod directive;
 pub mod edit_map;
+pub mod element_render;
 pub mod encode;
+pub mod event_transform;
+pub mod external_link;
+pub mod fenced_div;
+pub mod gfm_alert;
 pub mod gfm_tagfilter;
+pub mod github_reference;
 pub mod identifier;
+pub mod image_proxy;
+pub mod image_resolve;
 pub mod infer;
 pub mod line_ending;
+pub mod link_resolve;
 pub mod location;
+pub mod mathml;
 pub mod mdx;
 pub mod mdx_collect;
+pub mod mention;
 pub mod normalize_identifier;
+pub mod outline;
+pub mod ruby;
+pub mod sanitize_html;
 pub mod sanitize_uri;
+pub mod semantic_token;
 pub mod skip;
 pub mod slice;
+pub mod slug;
+#[cfg(feature = "syntect")]
+pub mod syntax_highlight;
+pub mod task_list;
+pub mod toc;
 pub mod unicode;
+pub mod url;
+pub mod validate;
+pub mod wiki_link;
@@ -0,0 +1,241 @@
+//! Collect the headings, definitions, and footnote definitions in a document
+//! into an outline.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::{Point, Position};
+use crate::util::{attribute_list::exit, slug::heading_text, toc::depth};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Kind of symbol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolKind {
+    /// Heading, with its rank, from `1` (`<h1>`) to `6` (`<h6>`).
+    Heading(u8),
+    /// Definition (`[a]: b`).
+    Definition,
+    /// GFM footnote definition (`[^a]: b`).
+    FootnoteDefinition,
+}
+
+/// Something in an outline of a document.
+///
+/// Symbols are shaped like those of the language server protocol, so that
+/// they can be turned into a response to `textDocument/documentSymbol`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{outline, ParseOptions, SymbolKind};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let symbols = outline("# a\n\n## b\n\n[c]: d", &ParseOptions::default())?;
+///
+/// assert_eq!(symbols[0].kind, SymbolKind::Heading(1));
+/// assert_eq!(symbols[0].children[0].name, "b");
+/// assert_eq!(symbols[0].children[0].children[0].kind, SymbolKind::Definition);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Symbol {
+    /// Kind of symbol.
+    pub kind: SymbolKind,
+    /// Name of the symbol.
+    ///
+    /// This is the plain text of headings, and the label of definitions.
+    pub name: String,
+    /// Positional info of the symbol.
+    ///
+    /// Headings span their section: up to the next heading of the same or a
+    /// higher rank.
+    pub range: Position,
+    /// Positional info of the name of the symbol.
+    pub selection_range: Position,
+    /// Symbols in this symbol.
+    pub children: Vec<Symbol>,
+}
+
+/// Collect the symbols in `events`.
+///
+/// Headings contain the symbols in their section, and footnote definitions
+/// contain the symbols in them.
+pub fn outline(events: &[Event], bytes: &[u8]) -> Vec<Symbol> {
+    let ends = content_ends(events);
+    let mut stack: Vec<Symbol> = vec![];
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter
+            && matches!(
+                event.name,
+                Name::HeadingAtx
+                    | Name::HeadingSetext
+                    | Name::Definition
+                    | Name::GfmFootnoteDefinition
+            )
+        {
+            let end = exit(events, index);
+            let kind = match event.name {
+                Name::Definition => SymbolKind::Definition,
+                Name::GfmFootnoteDefinition => SymbolKind::FootnoteDefinition,
+                _ => SymbolKind::Heading(depth(events, bytes, index, end)),
+            };
+            let name = child(events, index, end, |d| {
+                matches!(
+                    d,
+                    Name::HeadingAtxText
+                        | Name::HeadingSetextText
+                        | Name::DefinitionLabelString
+                        | Name::GfmFootnoteDefinitionLabelString
+                )
+            });
+            let range = position(
+                &events[index].point.to_unist(),
+                &events[end].point.to_unist(),
+            );
+            let symbol = Symbol {
+                kind,
+                name: match (kind, name) {
+                    (SymbolKind::Heading(_), _) => heading_text(events, bytes, index),
+                    (_, Some(name)) => String::from_utf8_lossy(
+                        &bytes[events[name].point.index..events[exit(events, name)].point.index],
+                    )
+                    .into(),
+                    (_, None) => String::new(),
+                },
+                selection_range: match name {
+                    Some(name) => position(
+                        &events[name].point.to_unist(),
+                        &events[exit(events, name)].point.to_unist(),
+                    ),
+                    None => range.clone(),
+                },
+                range,
+                children: vec![],
+            };
+
+            close(&mut stack, &mut result, &ends, event.point.index, kind);
+
+            if kind == SymbolKind::Definition {
+                add(&mut stack, &mut result, symbol);
+            } else {
+                stack.push(symbol);
+            }
+
+            // Definitions and headings contain no symbols.
+            if kind != SymbolKind::FootnoteDefinition {
+                index = end;
+            }
+        }
+
+        index += 1;
+    }
+
+    close(
+        &mut stack,
+        &mut result,
+        &ends,
+        bytes.len(),
+        SymbolKind::Heading(0),
+    );
+
+    result
+}
+
+/// Close the symbols on `stack` that end before a symbol of `kind` at
+/// `offset`.
+///
+/// Headings end at the next heading of the same or a higher rank, or at the
+/// end of the footnote definition they are in.
+/// The end of their range is at the end of the content before `offset`.
+fn close(
+    stack: &mut Vec<Symbol>,
+    result: &mut Vec<Symbol>,
+    ends: &[Point],
+    offset: usize,
+    kind: SymbolKind,
+) {
+    while let Some(last) = stack.last() {
+        let footnote_end = stack
+            .iter()
+            .rev()
+            .find(|d| d.kind == SymbolKind::FootnoteDefinition)
+            .map(|d| d.range.end.clone());
+        let footnote_closed = matches!(&footnote_end, Some(end) if end.offset <= offset);
+        let closed = match (last.kind, kind) {
+            (SymbolKind::FootnoteDefinition, _) => last.range.end.offset <= offset,
+            (SymbolKind::Heading(rank), SymbolKind::Heading(next)) => {
+                footnote_closed || next <= rank
+            }
+            _ => footnote_closed,
+        };
+
+        if !closed {
+            break;
+        }
+
+        let mut symbol = stack.pop().expect("expected symbol");
+
+        if let SymbolKind::Heading(_) = symbol.kind {
+            let limit = match footnote_end {
+                Some(end) if footnote_closed => end.offset,
+                _ => offset,
+            };
+            let index = ends.partition_point(|d| d.offset <= limit);
+
+            if index > 0 && ends[index - 1].offset > symbol.range.end.offset {
+                symbol.range.end = ends[index - 1].clone();
+            }
+        }
+
+        add(stack, result, symbol);
+    }
+}
+
+/// Add `symbol` to the symbol on the top of `stack`, or to `result`.
+fn add(stack: &mut [Symbol], result: &mut Vec<Symbol>, symbol: Symbol) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(symbol),
+        None => result.push(symbol),
+    }
+}
+
+/// Get the first event named with `test` from `enter` to `exit`.
+fn child(
+    events: &[Event],
+    enter: usize,
+    exit: usize,
+    test: impl Fn(&Name) -> bool,
+) -> Option<usize> {
+    (enter..exit).find(|d| events[*d].kind == Kind::Enter && test(&events[*d].name))
+}
+
+/// Get the points where content ends, ordered.
+///
+/// Whitespace and line endings are not content.
+fn content_ends(events: &[Event]) -> Vec<Point> {
+    let mut ends: Vec<Point> = events
+        .iter()
+        .filter(|d| {
+            d.kind == Kind::Exit
+                && !matches!(
+                    d.name,
+                    Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab
+                )
+        })
+        .map(|d| d.point.to_unist())
+        .collect();
+    ends.sort_by_key(|d| d.offset);
+    ends
+}
+
+/// Create a position from two points.
+fn position(start: &Point, end: &Point) -> Position {
+    Position {
+        start: start.clone(),
+        end: end.clone(),
+    }
+}
@@ -0,0 +1,28 @@
+//! Deal with ruby.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::string::String;
+
+/// Get the base and text of the ruby whose enter is at `index`.
+pub fn base_and_text(events: &[Event], bytes: &[u8], index: usize) -> (String, String) {
+    let mut index = index + 1;
+    let mut base = String::new();
+    let mut text = String::new();
+
+    while !(events[index].kind == Kind::Exit && events[index].name == Name::Ruby) {
+        if events[index].kind == Kind::Enter {
+            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index + 1));
+
+            match events[index].name {
+                Name::RubyBase => base = slice.as_str().into(),
+                Name::RubyText => text = slice.as_str().into(),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    (base, text)
+}
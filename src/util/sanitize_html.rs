@@ -0,0 +1,286 @@
+//! Make dangerous HTML safe.
+
+use crate::util::{
+    character_reference::parse as decode_character_references,
+    constant::{
+        GFM_HTML_TAGFILTER_NAMES, HTML_SANITIZE_ATTRIBUTES, HTML_SANITIZE_TAG_NAMES,
+        SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC,
+    },
+    encode::encode,
+    sanitize_uri::sanitize_with_protocols,
+};
+use alloc::{string::String, vec::Vec};
+
+/// Tag, as found in HTML.
+struct Tag {
+    /// Name, lowercase.
+    name: String,
+    /// Whether this is a closing tag (`</a>`).
+    close: bool,
+    /// Whether this is a self-closing tag (`<br />`).
+    self_close: bool,
+    /// Attributes, with lowercase names, and values as they are (character
+    /// references not decoded).
+    attributes: Vec<(String, Option<String>)>,
+}
+
+/// Make dangerous HTML safe.
+///
+/// Tags in [`HTML_SANITIZE_TAG_NAMES`][] are kept, with only the attributes
+/// in [`HTML_SANITIZE_ATTRIBUTES`][].
+/// URLs in attributes are sanitized like the destinations of links and the
+/// sources of images.
+/// Other tags, comments, declarations, and instructions are dropped, but
+/// what is between tags is kept, except for in tags such as `script` and
+/// `style`, which is dropped too.
+/// Other `<` are encoded.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_html::sanitize_html;
+///
+/// assert_eq!(sanitize_html("<b onclick=\"a()\">c</b>"), "<b>c</b>");
+/// assert_eq!(sanitize_html("<script>a()</script>b"), "b");
+/// ```
+pub fn sanitize_html(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let len = bytes.len();
+    // Mostly stays the same size.
+    let mut result = String::with_capacity(len);
+    let mut index = 0;
+    let mut start = 0;
+
+    while index < len {
+        if bytes[index] != b'<' {
+            index += 1;
+            continue;
+        }
+
+        result.push_str(&value[start..index]);
+
+        // Comment, declaration, or instruction: drop it.
+        if index + 1 < len && matches!(bytes[index + 1], b'!' | b'?') {
+            let end = if value[index..].starts_with("<!--") {
+                value[(index + 4)..].find("-->").map(|d| index + 4 + d + 3)
+            } else {
+                value[index..].find('>').map(|d| index + d + 1)
+            };
+            index = end.unwrap_or(len);
+        } else if let Some((end, tag)) = parse_tag(value, index) {
+            index = end;
+
+            if HTML_SANITIZE_TAG_NAMES.contains(&tag.name.as_str()) {
+                serialize_tag(&tag, &mut result);
+            } else if !tag.close && GFM_HTML_TAGFILTER_NAMES.contains(&tag.name.as_str()) {
+                // Drop the content too.
+                index = raw_end(value, index, &tag.name);
+            }
+        } else {
+            result.push_str("&lt;");
+            index += 1;
+        }
+
+        start = index;
+    }
+
+    result.push_str(&value[start..]);
+
+    result
+}
+
+/// Parse a tag, starting at `<` at `index`.
+///
+/// Returns the index after the tag, and the tag, if there is one.
+fn parse_tag(value: &str, index: usize) -> Option<(usize, Tag)> {
+    let bytes = value.as_bytes();
+    let len = bytes.len();
+    let mut index = index + 1;
+    let close = index < len && bytes[index] == b'/';
+
+    if close {
+        index += 1;
+    }
+
+    // Name.
+    let name_start = index;
+
+    if index < len && bytes[index].is_ascii_alphabetic() {
+        index += 1;
+
+        while index < len && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'-') {
+            index += 1;
+        }
+    } else {
+        return None;
+    }
+
+    let name = value[name_start..index].to_ascii_lowercase();
+    let mut attributes = Vec::new();
+
+    loop {
+        index = skip_whitespace(bytes, index);
+
+        if index == len {
+            return None;
+        }
+
+        if bytes[index] == b'>' {
+            return Some((
+                index + 1,
+                Tag {
+                    name,
+                    close,
+                    self_close: false,
+                    attributes,
+                },
+            ));
+        }
+
+        if bytes[index] == b'/' && index + 1 < len && bytes[index + 1] == b'>' {
+            return Some((
+                index + 2,
+                Tag {
+                    name,
+                    close,
+                    self_close: true,
+                    attributes,
+                },
+            ));
+        }
+
+        // Attribute name.
+        let attribute_start = index;
+
+        while index < len
+            && !matches!(
+                bytes[index],
+                b'\t' | b'\n' | 12 /* `\f` */ | b'\r' | b' ' | b'/' | b'>' | b'=' | b'"' | b'\'' | b'<'
+            )
+        {
+            index += 1;
+        }
+
+        if index == attribute_start {
+            return None;
+        }
+
+        let attribute_name = value[attribute_start..index].to_ascii_lowercase();
+        let mut attribute_value = None;
+        let after_name = skip_whitespace(bytes, index);
+
+        // Attribute value.
+        if after_name < len && bytes[after_name] == b'=' {
+            index = skip_whitespace(bytes, after_name + 1);
+
+            if index == len {
+                return None;
+            }
+
+            if matches!(bytes[index], b'"' | b'\'') {
+                let marker = bytes[index];
+                let value_start = index + 1;
+                let value_end =
+                    value_start + bytes[value_start..].iter().position(|d| *d == marker)?;
+                attribute_value = Some(value[value_start..value_end].into());
+                index = value_end + 1;
+            } else {
+                let value_start = index;
+
+                while index < len
+                    && !matches!(
+                        bytes[index],
+                        b'\t' | b'\n' | 12 /* `\f` */ | b'\r' | b' ' | b'>' | b'"' | b'\'' | b'<' | b'=' | b'`'
+                    )
+                {
+                    index += 1;
+                }
+
+                if index == value_start {
+                    return None;
+                }
+
+                attribute_value = Some(value[value_start..index].into());
+            }
+        }
+
+        attributes.push((attribute_name, attribute_value));
+    }
+}
+
+/// Serialize an allowed tag, with its allowed attributes.
+fn serialize_tag(tag: &Tag, result: &mut String) {
+    result.push('<');
+
+    if tag.close {
+        result.push('/');
+        result.push_str(&tag.name);
+        result.push('>');
+        return;
+    }
+
+    result.push_str(&tag.name);
+
+    let mut seen: Vec<&str> = Vec::new();
+
+    for (name, value) in &tag.attributes {
+        if seen.contains(&name.as_str())
+            || !HTML_SANITIZE_ATTRIBUTES
+                .iter()
+                .any(|d| (d.0 == "*" || d.0 == tag.name) && d.1 == name)
+        {
+            continue;
+        }
+
+        seen.push(name);
+        result.push(' ');
+        result.push_str(name);
+
+        if let Some(value) = value {
+            let value = decode_character_references(value);
+            result.push_str("=\"");
+            result.push_str(&match name.as_str() {
+                "href" | "cite" => sanitize_with_protocols(&value, &SAFE_PROTOCOL_HREF),
+                "src" => sanitize_with_protocols(&value, &SAFE_PROTOCOL_SRC),
+                _ => encode(&value, true),
+            });
+            result.push('"');
+        }
+    }
+
+    if tag.self_close {
+        result.push_str(" /");
+    }
+
+    result.push('>');
+}
+
+/// Get the index after the closing tag of raw text element `name`, whose
+/// opening tag ends at `index`, or the end if there is no closing tag.
+fn raw_end(value: &str, index: usize, name: &str) -> usize {
+    let mut index = index;
+
+    while let Some(position) = value[index..].find("</") {
+        let start = index + position;
+        index = start + 2;
+
+        if let Some((end, tag)) = parse_tag(value, start) {
+            if tag.name == name {
+                return end;
+            }
+        }
+    }
+
+    value.len()
+}
+
+/// Move past HTML whitespace.
+fn skip_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len()
+        && matches!(bytes[index], b'\t' | b'\n' | 12 /* `\f` */ | b'\r' | b' ')
+    {
+        index += 1;
+    }
+
+    index
+}
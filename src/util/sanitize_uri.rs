@@ -1,7 +1,7 @@
 //! Make urls safe.
 
 use crate::util::encode::encode;
-use alloc::{format, string::String, vec::Vec};
+use alloc::{fmt, format, rc::Rc, string::String, vec::Vec};
 
 /// Make a value safe for injection as a URL.
 ///
@@ -55,7 +55,7 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     let value = sanitize(value);
 
     let end = value.find(|c| matches!(c, '?' | '#' | '/'));
-    let mut colon = value.find(|c| matches!(c, ':'));
+    let mut colon = value.find(':');
 
     // If the first colon is after `?`, `#`, or `/`, it’s not a protocol.
     if let Some(end) = end {
@@ -78,6 +78,59 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     value
 }
 
+/// Kind of URL.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UrlKind {
+    /// Destination of a link, autolink, mention, or GitHub reference.
+    ///
+    /// ```markdown
+    /// > | [a](b)
+    ///        ^
+    /// ```
+    Link,
+    /// Source of an image.
+    ///
+    /// ```markdown
+    /// > | ![a](b)
+    ///         ^
+    /// ```
+    Image,
+}
+
+/// Signature of a function that makes a URL safe.
+pub type Sanitize = dyn Fn(&str, UrlKind) -> Option<String>;
+
+/// Function to make URLs safe, instead of the default.
+///
+/// The function gets the URL, after it is resolved, and the kind of URL.
+/// Return `Some(url)` to use that URL, which is then encoded but not
+/// checked for protocols, or `None` to sanitize the URL like normal.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{UrlKind, UrlSanitize};
+/// # fn main() {
+///
+/// // Allow `data:` images from a trusted source, and everything else like normal:
+/// let sanitize = UrlSanitize(std::rc::Rc::new(|url: &str, kind: UrlKind| {
+///     if kind == UrlKind::Image && url.starts_with("data:image/png;") {
+///         Some(url.into())
+///     } else {
+///         None
+///     }
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UrlSanitize(pub Rc<Sanitize>);
+
+impl fmt::Debug for UrlSanitize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UrlSanitize([Function])")
+    }
+}
+
 /// Normalize a URL (such as used in [definitions][definition],
 /// [references][label_end]).
 ///
@@ -0,0 +1,204 @@
+//! Collect the syntax of a document into semantic tokens.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::Position;
+use crate::util::attribute_list::exit;
+use alloc::{vec, vec::Vec};
+
+/// Kind of semantic token.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SemanticTokenKind {
+    /// Sequence of an ATX heading (`#`) or underline of a setext heading
+    /// (`===`).
+    HeadingMarker,
+    /// Text of a heading.
+    HeadingText,
+    /// Marker of a block quote (`>`).
+    BlockQuoteMarker,
+    /// Marker (`*`, `.`) or value (`1`) of a list item, or GFM task list item
+    /// check (`[x]`).
+    ListItemMarker,
+    /// Sequence of a thematic break (`***`).
+    ThematicBreak,
+    /// Sequence of emphasis, strong (`*`, `_`), or GFM strikethrough (`~`).
+    EmphasisMarker,
+    /// Fence sequence of fenced code or math (` ``` `, `$$`), or sequence of
+    /// code (text) or math (text) (`` ` ``, `$`).
+    CodeMarker,
+    /// Info or meta of fenced code or math.
+    CodeInfo,
+    /// Code or math.
+    Code,
+    /// Marker of a link, image, definition, autolink, or GFM footnote
+    /// (`!`, `[`, `]`, `(`, `)`, `<`, `>`, `:`, `^`).
+    LinkMarker,
+    /// Label of a reference, definition, or GFM footnote.
+    LinkLabel,
+    /// Destination of a resource or definition, or URL or email of an
+    /// autolink or GFM autolink literal.
+    LinkDestination,
+    /// Title of a resource or definition.
+    LinkTitle,
+    /// Cell divider (`|`) or delimiter (`:`, `-`) of a GFM table.
+    TableMarker,
+    /// Character escape (`\*`), character reference (`&amp;`), or hard
+    /// break (escape) (`\`).
+    Escape,
+    /// HTML (flow) or HTML (text).
+    Html,
+    /// Fence sequence (`---`) or content of frontmatter.
+    Frontmatter,
+}
+
+/// Piece of syntax, to highlight.
+///
+/// Tokens are shaped like those of the language server protocol, so that
+/// they can be turned into a response to `textDocument/semanticTokens/full`.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{semantic_tokens, ParseOptions, SemanticTokenKind};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let tokens = semantic_tokens("# *a*", &ParseOptions::default())?;
+///
+/// assert_eq!(tokens[0].kind, SemanticTokenKind::HeadingMarker);
+/// assert_eq!(tokens[1].kind, SemanticTokenKind::EmphasisMarker);
+/// assert_eq!(tokens[1].position.start.column, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SemanticToken {
+    /// Kind of token.
+    pub kind: SemanticTokenKind,
+    /// Positional info of the token.
+    ///
+    /// Tokens are never empty and never span multiple lines.
+    pub position: Position,
+}
+
+/// Collect the semantic tokens in `events`.
+///
+/// Tokens are in order and do not overlap.
+/// Things that contain other syntax, such as the text of headings or the
+/// titles of resources, are split: their data is in tokens, but syntax in
+/// them, such as line endings, block quote markers, or emphasis, is not.
+pub fn semantic_tokens(events: &[Event]) -> Vec<SemanticToken> {
+    let mut contexts: Vec<SemanticTokenKind> = vec![];
+    let mut tokens = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if let Some(kind) = context(&event.name) {
+            if event.kind == Kind::Enter {
+                contexts.push(kind);
+            } else {
+                contexts.pop();
+            }
+        } else if event.kind == Kind::Enter {
+            let kind = match event.name {
+                Name::Data => contexts.last().copied(),
+                _ => leaf(&event.name),
+            };
+
+            if let Some(kind) = kind {
+                let end = exit(events, index);
+
+                if events[end].point.index > event.point.index {
+                    tokens.push(SemanticToken {
+                        kind,
+                        position: Position {
+                            start: event.point.to_unist(),
+                            end: events[end].point.to_unist(),
+                        },
+                    });
+                }
+
+                index = end;
+            }
+        }
+
+        index += 1;
+    }
+
+    tokens
+}
+
+/// Get the kind of the data in events named `name`.
+fn context(name: &Name) -> Option<SemanticTokenKind> {
+    match name {
+        Name::HeadingAtxText | Name::HeadingSetextText => Some(SemanticTokenKind::HeadingText),
+        // The label of a footnote call is in label text.
+        Name::DefinitionLabelString
+        | Name::GfmFootnoteCall
+        | Name::GfmFootnoteDefinitionLabelString
+        | Name::ReferenceString => Some(SemanticTokenKind::LinkLabel),
+        Name::DefinitionTitleString | Name::ResourceTitleString => {
+            Some(SemanticTokenKind::LinkTitle)
+        }
+        _ => None,
+    }
+}
+
+/// Get the kind of events named `name`, which are tokens themselves.
+fn leaf(name: &Name) -> Option<SemanticTokenKind> {
+    match name {
+        Name::HeadingAtxSequence | Name::HeadingSetextUnderlineSequence => {
+            Some(SemanticTokenKind::HeadingMarker)
+        }
+        Name::BlockQuoteMarker => Some(SemanticTokenKind::BlockQuoteMarker),
+        Name::ListItemMarker | Name::ListItemValue | Name::GfmTaskListItemCheck => {
+            Some(SemanticTokenKind::ListItemMarker)
+        }
+        Name::ThematicBreakSequence => Some(SemanticTokenKind::ThematicBreak),
+        Name::EmphasisSequence | Name::StrongSequence | Name::GfmStrikethroughSequence => {
+            Some(SemanticTokenKind::EmphasisMarker)
+        }
+        Name::CodeFencedFenceSequence
+        | Name::CodeTextSequence
+        | Name::MathFlowFenceSequence
+        | Name::MathTextSequence => Some(SemanticTokenKind::CodeMarker),
+        Name::CodeFencedFenceInfo | Name::CodeFencedFenceMeta | Name::MathFlowFenceMeta => {
+            Some(SemanticTokenKind::CodeInfo)
+        }
+        Name::CodeFlowChunk | Name::CodeTextData | Name::MathFlowChunk | Name::MathTextData => {
+            Some(SemanticTokenKind::Code)
+        }
+        Name::AutolinkMarker
+        | Name::DefinitionDestinationLiteralMarker
+        | Name::DefinitionLabelMarker
+        | Name::DefinitionMarker
+        | Name::DefinitionTitleMarker
+        | Name::GfmFootnoteCallMarker
+        | Name::GfmFootnoteDefinitionLabelMarker
+        | Name::GfmFootnoteDefinitionMarker
+        | Name::LabelImageMarker
+        | Name::LabelMarker
+        | Name::ReferenceMarker
+        | Name::ResourceDestinationLiteralMarker
+        | Name::ResourceMarker
+        | Name::ResourceTitleMarker => Some(SemanticTokenKind::LinkMarker),
+        Name::AutolinkEmail
+        | Name::AutolinkProtocol
+        | Name::DefinitionDestinationString
+        | Name::GfmAutolinkLiteralEmail
+        | Name::GfmAutolinkLiteralMailto
+        | Name::GfmAutolinkLiteralProtocol
+        | Name::GfmAutolinkLiteralWww
+        | Name::GfmAutolinkLiteralXmpp
+        | Name::ResourceDestinationString => Some(SemanticTokenKind::LinkDestination),
+        Name::GfmTableCellDivider
+        | Name::GfmTableDelimiterFiller
+        | Name::GfmTableDelimiterMarker => Some(SemanticTokenKind::TableMarker),
+        Name::CharacterEscape | Name::CharacterReference | Name::HardBreakEscape => {
+            Some(SemanticTokenKind::Escape)
+        }
+        Name::HtmlFlowData | Name::HtmlTextData => Some(SemanticTokenKind::Html),
+        Name::FrontmatterChunk | Name::FrontmatterSequence => Some(SemanticTokenKind::Frontmatter),
+        _ => None,
+    }
+}
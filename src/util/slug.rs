@@ -0,0 +1,207 @@
+//! Turn text into slugs, such as for heading ids.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::{
+    character_reference::decode as decode_character_reference,
+    slice::{Position, Slice},
+};
+use alloc::{collections::BTreeMap, fmt, format, rc::Rc, string::String};
+
+/// Algorithm to turn text into slugs.
+///
+/// Platforms that generate ids for headings all do so differently.
+/// For links to headings (`#some-heading`) to keep working when markdown is
+/// rendered somewhere else, the ids must be generated in the same way.
+///
+/// Repeated slugs get a suffix (`-1`, `-2`, and so on), which is how all
+/// these platforms deal with them.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::SlugKind;
+/// # fn main() {
+///
+/// // Use a custom algorithm:
+/// let custom = SlugKind::Custom(std::rc::Rc::new(|value: &str| value.to_uppercase()));
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub enum SlugKind {
+    /// GitHub (`github-slugger`).
+    ///
+    /// Lowercase, drop punctuation and symbols, and turn each space into a
+    /// dash: `Hello, *World*!` becomes `hello-world`.
+    #[default]
+    GitHub,
+    /// GitLab.
+    ///
+    /// Like GitHub, but also collapse dashes: `a - b` becomes `a-b`.
+    GitLab,
+    /// kramdown.
+    ///
+    /// Drop everything before the first ASCII letter and everything but ASCII
+    /// letters, digits, spaces, and dashes, turn each space into a dash, and
+    /// lowercase: `1. Über uns` becomes `ber-uns`.
+    /// Empty slugs become `section`.
+    Kramdown,
+    /// Custom algorithm.
+    ///
+    /// The function gets the plain text of a heading.
+    Custom(Rc<dyn Fn(&str) -> String>),
+}
+
+impl fmt::Debug for SlugKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SlugKind::GitHub => write!(f, "GitHub"),
+            SlugKind::GitLab => write!(f, "GitLab"),
+            SlugKind::Kramdown => write!(f, "Kramdown"),
+            SlugKind::Custom(_) => write!(f, "Custom([Function])"),
+        }
+    }
+}
+
+/// Turn `value` into a slug with the algorithm `kind`.
+pub fn slug(kind: &SlugKind, value: &str) -> String {
+    match kind {
+        SlugKind::GitHub => value
+            .to_lowercase()
+            .chars()
+            .filter(|char| char.is_alphanumeric() || matches!(char, ' ' | '-' | '_'))
+            .map(|char| if char == ' ' { '-' } else { char })
+            .collect(),
+        SlugKind::GitLab => {
+            let mut result = String::new();
+
+            for char in value.trim().to_lowercase().chars() {
+                if char.is_alphanumeric() || matches!(char, ' ' | '-' | '_') {
+                    let char = if char == ' ' { '-' } else { char };
+
+                    if !(char == '-' && result.ends_with('-')) {
+                        result.push(char);
+                    }
+                }
+            }
+
+            result
+        }
+        SlugKind::Kramdown => {
+            let result: String = value
+                .trim_start_matches(|char: char| !char.is_ascii_alphabetic())
+                .chars()
+                .filter(|char| char.is_ascii_alphanumeric() || matches!(char, ' ' | '-'))
+                .map(|char| {
+                    if char == ' ' {
+                        '-'
+                    } else {
+                        char.to_ascii_lowercase()
+                    }
+                })
+                .collect();
+
+            if result.is_empty() {
+                "section".into()
+            } else {
+                result
+            }
+        }
+        SlugKind::Custom(function) => function(value),
+    }
+}
+
+/// Make `slug` unique, by adding a suffix (`-1`) if it already occurred.
+pub fn unique(slug: &str, occurrences: &mut BTreeMap<String, usize>) -> String {
+    let mut result = String::from(slug);
+
+    while occurrences.contains_key(&result) {
+        let count = occurrences.get_mut(slug).unwrap();
+        *count += 1;
+        result = format!("{}-{}", slug, count);
+    }
+
+    occurrences.insert(result.clone(), 0);
+    result
+}
+
+/// Get the plain text of the heading whose enter is at `index`.
+///
+/// The text is what is visible when rendered: markup, HTML, and the
+/// destinations of links are dropped, and line endings are turned into
+/// spaces.
+pub fn heading_text(events: &[Event], bytes: &[u8], mut index: usize) -> String {
+    let mut result = String::new();
+    let mut hidden = 0;
+    let mut marker = 0;
+    let mut depth = 0;
+
+    loop {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            depth += 1;
+
+            if matches!(
+                event.name,
+                Name::AttributeList
+                    | Name::GfmFootnoteCall
+                    | Name::HtmlText
+                    | Name::MdxJsxTextTag
+                    | Name::MdxTextExpression
+                    | Name::Reference
+                    | Name::Resource
+            ) {
+                hidden += 1;
+            }
+        } else {
+            depth -= 1;
+
+            if depth == 0 {
+                break;
+            }
+
+            match event.name {
+                Name::AttributeList
+                | Name::GfmFootnoteCall
+                | Name::HtmlText
+                | Name::MdxJsxTextTag
+                | Name::MdxTextExpression
+                | Name::Reference
+                | Name::Resource => hidden -= 1,
+                Name::CharacterReferenceMarker => marker = b'&',
+                Name::CharacterReferenceMarkerHexadecimal => marker = b'x',
+                Name::CharacterReferenceMarkerNumeric => marker = b'#',
+                Name::CharacterReferenceValue if hidden == 0 => {
+                    let slice =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                    if let Some(value) = decode_character_reference(slice.as_str(), marker, true) {
+                        result.push_str(&value);
+                    }
+                }
+                Name::LineEnding if hidden == 0 && !result.is_empty() => result.push(' '),
+                Name::AutolinkEmail
+                | Name::AutolinkProtocol
+                | Name::CharacterEscapeValue
+                | Name::CodeTextData
+                | Name::Data
+                | Name::GfmAutolinkLiteralEmail
+                | Name::GfmAutolinkLiteralMailto
+                | Name::GfmAutolinkLiteralProtocol
+                | Name::GfmAutolinkLiteralWww
+                | Name::GfmAutolinkLiteralXmpp
+                | Name::MathTextData
+                    if hidden == 0 =>
+                {
+                    let slice =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                    result.push_str(slice.as_str());
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    result.trim().into()
+}
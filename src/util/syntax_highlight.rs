@@ -0,0 +1,140 @@
+//! Highlight code with syntect.
+//!
+//! syntect needs a newer Rust than the rest of `markdown-rs`, so this module
+//! does too.
+
+#![allow(clippy::incompatible_msrv)]
+
+use crate::util::encode::encode;
+use alloc::string::String;
+use std::sync::OnceLock;
+use syntect::{
+    easy::HighlightLines,
+    highlighting::ThemeSet,
+    html::{styled_line_to_highlighted_html, ClassStyle, ClassedHTMLGenerator, IncludeBackground},
+    parsing::SyntaxSet,
+    util::LinesWithEndings,
+};
+
+/// How to style highlighted code.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SyntaxHighlightStyle {
+    /// Inline styles, with the colors of
+    /// [`theme`][SyntaxHighlight::theme].
+    ///
+    /// ```html
+    /// <span style="font-weight:bold;color:#a71d5d;">fn</span>
+    /// ```
+    #[default]
+    Inline,
+    /// Classes, which can be styled with a stylesheet.
+    ///
+    /// The theme is not used.
+    /// A stylesheet for a theme can be made with
+    /// `syntect::html::css_for_theme_with_class_style` and
+    /// `ClassStyle::Spaced`.
+    ///
+    /// ```html
+    /// <span class="storage type function rust">fn</span>
+    /// ```
+    Classes,
+}
+
+/// Configuration for highlighting code with syntect.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{SyntaxHighlight, SyntaxHighlightStyle};
+/// # fn main() {
+///
+/// // Use the defaults, which are inline styles with the `InspiredGitHub`
+/// // theme:
+/// let default = SyntaxHighlight::default();
+///
+/// // Use classes:
+/// let classes = SyntaxHighlight {
+///     style: SyntaxHighlightStyle::Classes,
+///     ..SyntaxHighlight::default()
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyntaxHighlight {
+    /// Name of a theme that comes with syntect, used for inline styles.
+    ///
+    /// The default is `"InspiredGitHub"`.
+    /// Other themes are `"base16-ocean.dark"`, `"base16-eighties.dark"`,
+    /// `"base16-mocha.dark"`, `"base16-ocean.light"`, `"Solarized (dark)"`,
+    /// and `"Solarized (light)"`.
+    /// Code is not highlighted when the theme does not exist.
+    pub theme: String,
+    /// How to style highlighted code.
+    pub style: SyntaxHighlightStyle,
+}
+
+impl Default for SyntaxHighlight {
+    /// Inline styles with the `InspiredGitHub` theme.
+    fn default() -> Self {
+        Self {
+            theme: "InspiredGitHub".into(),
+            style: SyntaxHighlightStyle::default(),
+        }
+    }
+}
+
+/// Get the syntaxes that come with syntect.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+/// Get the themes that come with syntect.
+fn theme_set() -> &'static ThemeSet {
+    static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+    THEME_SET.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Highlight `code` as `lang`, to HTML.
+///
+/// Returns `None` if there is no syntax for `lang`, if the theme does not
+/// exist, or if syntect fails.
+pub fn highlight(config: &SyntaxHighlight, lang: &str, code: &str) -> Option<String> {
+    let syntax_set = syntax_set();
+    let syntax = syntax_set.find_syntax_by_token(lang)?;
+
+    match config.style {
+        SyntaxHighlightStyle::Inline => {
+            let theme = theme_set().themes.get(&config.theme)?;
+            let mut highlighter = HighlightLines::new(syntax, theme);
+            let mut result = String::new();
+
+            for line in LinesWithEndings::from(code) {
+                let regions = highlighter.highlight_line(line, syntax_set).ok()?;
+                result.push_str(
+                    &styled_line_to_highlighted_html(&regions, IncludeBackground::No).ok()?,
+                );
+            }
+
+            Some(result)
+        }
+        SyntaxHighlightStyle::Classes => {
+            let mut generator =
+                ClassedHTMLGenerator::new_with_class_style(syntax, syntax_set, ClassStyle::Spaced);
+
+            for line in LinesWithEndings::from(code) {
+                generator
+                    .parse_html_for_line_which_includes_newline(line)
+                    .ok()?;
+            }
+
+            Some(generator.finalize())
+        }
+    }
+}
+
+/// Highlight `code` as `lang` if possible, and encode it otherwise.
+pub fn highlight_or_encode(config: &SyntaxHighlight, lang: Option<&str>, code: &str) -> String {
+    lang.and_then(|lang| highlight(config, lang, code))
+        .unwrap_or_else(|| encode(code, true))
+}
@@ -0,0 +1,129 @@
+//! Count checked and unchecked GFM task list items.
+
+use crate::mdast::{List, Node};
+use crate::unist::Position;
+use alloc::{vec, vec::Vec};
+
+/// Counts of GFM task list items in a document.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{task_lists, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let stats = task_lists("* [x] a\n* [ ] b\n* c", &ParseOptions::gfm())?;
+///
+/// assert_eq!(stats.checked, 1);
+/// assert_eq!(stats.unchecked, 1);
+/// assert_eq!(stats.lists.len(), 1);
+/// assert_eq!(stats.lists[0].items.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskListStats {
+    /// Number of checked items in the document.
+    pub checked: usize,
+    /// Number of unchecked items in the document.
+    pub unchecked: usize,
+    /// Lists with at least one task list item, in document order.
+    ///
+    /// Nested lists come after the list they are in.
+    pub lists: Vec<TaskList>,
+}
+
+/// Counts of GFM task list items in a list.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskList {
+    /// Number of checked items in the list.
+    pub checked: usize,
+    /// Number of unchecked items in the list.
+    pub unchecked: usize,
+    /// Task list items in the list.
+    ///
+    /// Items of nested lists are not included.
+    pub items: Vec<TaskListItem>,
+    /// Positional info of the list.
+    pub position: Option<Position>,
+}
+
+/// A GFM task list item.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskListItem {
+    /// Whether the item is checked.
+    pub checked: bool,
+    /// Positional info of the item.
+    pub position: Option<Position>,
+}
+
+impl TaskListStats {
+    /// Number of task list items in the document.
+    pub fn total(&self) -> usize {
+        self.checked + self.unchecked
+    }
+}
+
+impl TaskList {
+    /// Number of task list items in the list.
+    pub fn total(&self) -> usize {
+        self.checked + self.unchecked
+    }
+}
+
+/// Count the task list items in `tree`.
+pub fn stats(tree: &Node) -> TaskListStats {
+    let mut stats = TaskListStats::default();
+    collect(tree, &mut stats);
+    stats
+}
+
+/// Count the task list items in `node` and its descendants.
+fn collect(node: &Node, stats: &mut TaskListStats) {
+    if let Node::List(list) = node {
+        if let Some(list) = task_list(list) {
+            stats.checked += list.checked;
+            stats.unchecked += list.unchecked;
+            stats.lists.push(list);
+        }
+    }
+
+    if let Some(children) = node.children() {
+        for child in children {
+            collect(child, stats);
+        }
+    }
+}
+
+/// Count the task list items in `list`, if there are any.
+fn task_list(list: &List) -> Option<TaskList> {
+    let mut result = TaskList {
+        checked: 0,
+        unchecked: 0,
+        items: vec![],
+        position: list.position.clone(),
+    };
+
+    for child in &list.children {
+        if let Node::ListItem(item) = child {
+            if let Some(checked) = item.checked {
+                if checked {
+                    result.checked += 1;
+                } else {
+                    result.unchecked += 1;
+                }
+
+                result.items.push(TaskListItem {
+                    checked,
+                    position: item.position.clone(),
+                });
+            }
+        }
+    }
+
+    if result.items.is_empty() {
+        None
+    } else {
+        Some(result)
+    }
+}
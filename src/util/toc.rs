@@ -0,0 +1,180 @@
+//! Collect the headings in a document into a table of contents.
+
+use crate::configuration::CompileOptions;
+use crate::event::{Event, Kind, Name};
+use crate::unist::{Point, Position};
+use crate::util::{
+    attribute_list::{attributes, exit, targets},
+    encode::encode,
+    slice::{Position as SlicePosition, Slice},
+    slug::{heading_text, slug, unique},
+};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+
+/// A heading in a table of contents.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{toc, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let entries = toc("# a\n\nb\n-", &Options::default())?;
+///
+/// assert_eq!(entries[1].depth, 2);
+/// assert_eq!(entries[1].position.as_ref().unwrap().start.line, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TocEntry {
+    /// Rank of the heading, from `1` (`<h1>`) to `6` (`<h6>`).
+    pub depth: u8,
+    /// Plain text of the heading.
+    ///
+    /// Markup, HTML, and the destinations of links are dropped.
+    pub text: String,
+    /// Id of the heading.
+    ///
+    /// This is the same id that is added to the heading when
+    /// [`heading_ids`][CompileOptions::heading_ids] is turned on (without
+    /// [`id_prefix`][CompileOptions::id_prefix]).
+    pub slug: String,
+    /// Positional info of the heading.
+    pub position: Option<Position>,
+}
+
+/// Collect the headings in `events`.
+pub fn entries(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Vec<TocEntry> {
+    let attribute_targets = targets(events);
+    let mut occurrences = BTreeMap::new();
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter && matches!(event.name, Name::HeadingAtx | Name::HeadingSetext)
+        {
+            let end = exit(events, index);
+            let text = heading_text(events, bytes, index);
+            let id = attribute_targets
+                .iter()
+                .find(|d| d.0 == index)
+                .and_then(|d| {
+                    attributes(events, bytes, d.2)
+                        .into_iter()
+                        .find(|d| d.0 == "id")
+                })
+                .map(|d| d.1);
+
+            result.push(TocEntry {
+                depth: depth(events, bytes, index, end),
+                slug: match id {
+                    Some(id) => id,
+                    None => unique(&slug(&options.slug, &text), &mut occurrences),
+                },
+                text,
+                position: Some(Position {
+                    start: Point::new(event.point.line, event.point.column, event.point.index),
+                    end: Point::new(
+                        events[end].point.line,
+                        events[end].point.column,
+                        events[end].point.index,
+                    ),
+                }),
+            });
+
+            index = end;
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Serialize `entries` as nested HTML lists of links to headings.
+///
+/// A heading that is deeper than the one before it starts a nested list,
+/// even when ranks are skipped.
+pub fn to_html(entries: &[TocEntry], options: &CompileOptions) -> String {
+    let mut result = String::new();
+    let mut stack: Vec<u8> = vec![];
+
+    for entry in entries {
+        if matches!(stack.last(), Some(last) if entry.depth > *last) {
+            result.push_str("\n<ul>\n");
+        } else {
+            // Close deeper lists.
+            while stack.len() > 1 && entry.depth <= stack[stack.len() - 2] {
+                stack.pop();
+                result.push_str("</li>\n</ul>\n");
+            }
+
+            if stack.pop().is_some() {
+                result.push_str("</li>\n");
+            } else {
+                result.push_str("<ul>\n");
+            }
+        }
+
+        result.push_str("<li><a href=\"#");
+        if let Some(ref value) = options.id_prefix {
+            result.push_str(&encode(value, true));
+        }
+        result.push_str(&encode(&entry.slug, true));
+        result.push_str("\">");
+        result.push_str(&encode(&entry.text, true));
+        result.push_str("</a>");
+        stack.push(entry.depth);
+    }
+
+    if !stack.is_empty() {
+        result.push_str("</li>\n");
+        stack.pop();
+
+        while stack.pop().is_some() {
+            result.push_str("</ul>\n</li>\n");
+        }
+
+        result.push_str("</ul>");
+    }
+
+    result
+}
+
+/// Get the rank of the heading from `enter` to `exit`.
+pub fn depth(events: &[Event], bytes: &[u8], enter: usize, exit: usize) -> u8 {
+    let mut index = enter;
+
+    while index < exit {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                // First fence we see.
+                Name::HeadingAtxSequence => {
+                    let len =
+                        Slice::from_position(bytes, &SlicePosition::from_exit_event(events, index))
+                            .len();
+                    return u8::try_from(len).unwrap_or(6);
+                }
+                Name::HeadingSetextUnderlineSequence => {
+                    let position = SlicePosition::from_exit_event(events, index);
+                    return if bytes[position.start.index] == b'-' {
+                        2
+                    } else {
+                        1
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    unreachable!("expected heading sequence")
+}
@@ -0,0 +1,314 @@
+//! Resolve URLs.
+
+use alloc::string::String;
+
+/// Components of a URL.
+///
+/// ## References
+///
+/// *   [*§ 3 Syntax Components* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3)
+struct Parts<'a> {
+    /// Scheme, without `:`.
+    scheme: Option<&'a str>,
+    /// Authority, without `//`.
+    authority: Option<&'a str>,
+    /// Path.
+    path: &'a str,
+    /// Query, without `?`.
+    query: Option<&'a str>,
+    /// Fragment, without `#`.
+    fragment: Option<&'a str>,
+}
+
+/// Split a URL into its components.
+///
+/// ## References
+///
+/// *   [*Appendix B* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#appendix-B)
+fn parse(value: &str) -> Parts<'_> {
+    let mut rest = value;
+    let mut scheme = None;
+
+    if let Some(index) = rest.find([':', '/', '?', '#']) {
+        let before = &rest[..index];
+
+        // A scheme starts with a letter, followed by letters, digits, `+`,
+        // `-`, or `.`.
+        if rest.as_bytes()[index] == b':'
+            && before.starts_with(|d: char| d.is_ascii_alphabetic())
+            && before
+                .bytes()
+                .all(|d| d.is_ascii_alphanumeric() || matches!(d, b'+' | b'-' | b'.'))
+        {
+            scheme = Some(before);
+            rest = &rest[(index + 1)..];
+        }
+    }
+
+    let mut authority = None;
+
+    if let Some(after) = rest.strip_prefix("//") {
+        let index = after.find(['/', '?', '#']).unwrap_or(after.len());
+        authority = Some(&after[..index]);
+        rest = &after[index..];
+    }
+
+    let mut fragment = None;
+
+    if let Some(index) = rest.find('#') {
+        fragment = Some(&rest[(index + 1)..]);
+        rest = &rest[..index];
+    }
+
+    let mut query = None;
+
+    if let Some(index) = rest.find('?') {
+        query = Some(&rest[(index + 1)..]);
+        rest = &rest[..index];
+    }
+
+    Parts {
+        scheme,
+        authority,
+        path: rest,
+        query,
+        fragment,
+    }
+}
+
+/// Merge a relative path with the path of the base.
+///
+/// ## References
+///
+/// *   [*§ 5.2.3 Merge Paths* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.3)
+fn merge(base: &Parts, path: &str) -> String {
+    let mut result = String::new();
+
+    if base.authority.is_some() && base.path.is_empty() {
+        result.push('/');
+    } else if let Some(index) = base.path.rfind('/') {
+        result.push_str(&base.path[..=index]);
+    }
+
+    result.push_str(path);
+    result
+}
+
+/// Remove `.` and `..` segments from a path.
+///
+/// ## References
+///
+/// *   [*§ 5.2.4 Remove Dot Segments* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4)
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::new();
+
+    while !input.is_empty() {
+        if let Some(rest) = input
+            .strip_prefix("../")
+            .or_else(|| input.strip_prefix("./"))
+        {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input.len() == 3 { "/" } else { &input[3..] };
+            output.truncate(output.rfind('/').unwrap_or(0));
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let start = usize::from(input.starts_with('/'));
+            let end = input[start..].find('/').map_or(input.len(), |d| d + start);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
+
+/// Resolve `reference` against `base`.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::url::resolve;
+///
+/// assert_eq!(resolve("https://a.com/b/c", "../d"), "https://a.com/d");
+/// assert_eq!(resolve("https://a.com/b/c", "https://e.com"), "https://e.com");
+/// ```
+///
+/// ## References
+///
+/// *   [*§ 5.2.2 Transform References* in RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.2)
+pub fn resolve(base: &str, reference: &str) -> String {
+    let base = parse(base);
+    let reference = parse(reference);
+    let scheme;
+    let authority;
+    let path;
+    let query;
+
+    if reference.scheme.is_some() {
+        scheme = reference.scheme;
+        authority = reference.authority;
+        path = remove_dot_segments(reference.path);
+        query = reference.query;
+    } else {
+        scheme = base.scheme;
+
+        if reference.authority.is_some() {
+            authority = reference.authority;
+            path = remove_dot_segments(reference.path);
+            query = reference.query;
+        } else {
+            authority = base.authority;
+
+            if reference.path.is_empty() {
+                path = base.path.into();
+                query = reference.query.or(base.query);
+            } else {
+                path = if reference.path.starts_with('/') {
+                    remove_dot_segments(reference.path)
+                } else {
+                    remove_dot_segments(&merge(&base, reference.path))
+                };
+                query = reference.query;
+            }
+        }
+    }
+
+    // Recompose, see:
+    // <https://www.rfc-editor.org/rfc/rfc3986#section-5.3>.
+    let mut result = String::new();
+
+    if let Some(scheme) = scheme {
+        result.push_str(scheme);
+        result.push(':');
+    }
+
+    if let Some(authority) = authority {
+        result.push_str("//");
+        result.push_str(authority);
+    }
+
+    result.push_str(&path);
+
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+
+    if let Some(fragment) = reference.fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+
+    result
+}
+
+/// Get the host (and port) of a URL, lowercased, if it has one.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::url::host;
+///
+/// assert_eq!(host("https://a@B.com:80/c"), Some("b.com:80".into()));
+/// assert_eq!(host("/c"), None);
+/// ```
+pub fn host(value: &str) -> Option<String> {
+    let authority = parse(value).authority?;
+    let host = authority
+        .rfind('@')
+        .map_or(authority, |index| &authority[(index + 1)..]);
+
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_ascii_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_normal() {
+        // See: <https://www.rfc-editor.org/rfc/rfc3986#section-5.4.1>.
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+
+        for (reference, expected) in cases {
+            assert_eq!(
+                resolve(base, reference),
+                expected,
+                "should resolve `{}`",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_abnormal() {
+        // See: <https://www.rfc-editor.org/rfc/rfc3986#section-5.4.2>.
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g?y/../x", "http://a/b/c/g?y/../x"),
+            ("g#s/./x", "http://a/b/c/g#s/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g"),
+        ];
+
+        for (reference, expected) in cases {
+            assert_eq!(
+                resolve(base, reference),
+                expected,
+                "should resolve `{}`",
+                reference
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Check whether events are well-formed.
+
+use crate::event::{Event, Kind, VOID_EVENTS};
+use crate::message;
+use crate::unist::Point;
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// Check whether events are well-formed.
+///
+/// Events are well-formed when:
+///
+/// *   every enter has a matching exit, and they nest properly
+/// *   void events (such as markers) are exited right after they are entered
+/// *   points never go back: each event is at or after the event before it
+///     (by byte offset, line, and column; virtual spaces in tabs are not
+///     checked)
+///
+/// This is the same as what `markdown-rs` checks internally, exposed so that
+/// authors of code that changes events (such as custom constructs or event
+/// transforms) can check their output in tests.
+///
+/// ## Errors
+///
+/// Errors with the first problem found, placed at the event where it occurs.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_events, validate_events, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut events = to_events("*a*", &ParseOptions::default())?;
+/// assert_eq!(validate_events(&events), Ok(()));
+///
+/// // Remove the last exit:
+/// events.pop();
+/// assert_eq!(
+///     validate_events(&events).unwrap_err().reason,
+///     "Expected exit of `Paragraph`, which was entered at 1:1, before the end"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn validate_events(events: &[Event]) -> Result<(), message::Message> {
+    let mut stack: Vec<usize> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        // Points never go back: each event is at or after the one before.
+        if index > 0 {
+            let previous = &events[index - 1];
+
+            if event.point.index < previous.point.index
+                || event.point.line < previous.point.line
+                || (event.point.line == previous.point.line
+                    && event.point.column < previous.point.column)
+            {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected point of {} `{:?}` ({}), which is before the {} of `{:?}` ({})",
+                        kind_to_str(&event.kind),
+                        event.name,
+                        format_point(&event.point.to_unist()),
+                        kind_to_str(&previous.kind),
+                        previous.name,
+                        format_point(&previous.point.to_unist()),
+                    ),
+                    "point-backwards",
+                ));
+            }
+        }
+
+        if event.kind == Kind::Enter {
+            if let Some(open) = stack.last() {
+                let open = &events[*open];
+
+                if VOID_EVENTS.contains(&open.name) {
+                    return Err(error(
+                        event,
+                        format!(
+                            "Unexpected enter of `{:?}` in void `{:?}`, expected `{:?}` to be exited first",
+                            event.name, open.name, open.name
+                        ),
+                        "enter-in-void",
+                    ));
+                }
+            }
+
+            stack.push(index);
+        } else if let Some(open) = stack.pop() {
+            let open = &events[open];
+
+            if open.name != event.name {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected exit of `{:?}`, expected exit of `{:?}`, which was entered at {}",
+                        event.name,
+                        open.name,
+                        format_point(&open.point.to_unist())
+                    ),
+                    "exit-mismatch",
+                ));
+            }
+        } else {
+            return Err(error(
+                event,
+                format!(
+                    "Unexpected exit of `{:?}`, which was never entered",
+                    event.name
+                ),
+                "exit-without-enter",
+            ));
+        }
+
+        index += 1;
+    }
+
+    if let Some(open) = stack.pop() {
+        let open = &events[open];
+        return Err(error(
+            open,
+            format!(
+                "Expected exit of `{:?}`, which was entered at {}, before the end",
+                open.name,
+                format_point(&open.point.to_unist())
+            ),
+            "enter-without-exit",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Create an error at an event.
+fn error(event: &Event, reason: String, rule_id: &str) -> message::Message {
+    message::Message {
+        place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
+        reason,
+        rule_id: Box::new(rule_id.into()),
+        source: Box::new("markdown-rs".into()),
+        fix: None,
+    }
+}
+
+/// Format a point as `line:column`.
+fn format_point(point: &Point) -> String {
+    format!("{}:{}", point.line, point.column)
+}
+
+/// Format an event kind.
+fn kind_to_str(kind: &Kind) -> &'static str {
+    match kind {
+        Kind::Enter => "enter",
+        Kind::Exit => "exit",
+    }
+}
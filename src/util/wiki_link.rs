@@ -0,0 +1,122 @@
+//! Deal with wiki links and wiki embeds.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::{fmt, rc::Rc, string::String};
+
+/// Signature of a function that turns the target of a wiki link into a URL.
+pub type Resolve = dyn Fn(&str) -> Option<String>;
+
+/// Function to turn the target of a wiki link into a URL.
+///
+/// The function gets the target as it is in the source, including a heading
+/// (`#b`) or block id (`#^b`), if there is one.
+/// Return `Some(url)` to link to a page, or `None` if the page does not
+/// exist, which marks the link as missing.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::WikiLinkResolve;
+/// # fn main() {
+///
+/// // Link to pages in `/wiki/`:
+/// let resolve = WikiLinkResolve(std::rc::Rc::new(|target: &str| {
+///     Some(format!("/wiki/{}", target.replace(' ', "_")))
+/// }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WikiLinkResolve(pub Rc<Resolve>);
+
+impl fmt::Debug for WikiLinkResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WikiLinkResolve([Function])")
+    }
+}
+
+/// Signature of a function that turns a wiki embed into HTML.
+pub type Embed = dyn Fn(&str, Option<&str>, Option<&str>) -> Option<String>;
+
+/// Function to turn wiki embeds into HTML.
+///
+/// The function gets the target (`a` in `![[a#b|c]]`), and the fragment
+/// (`b`, or `^b` for a block id) and alias (`c`), if there are ones.
+/// Return `Some(html)` to show that HTML instead of the embed, such as the
+/// content of a page or an image.
+/// The HTML is used as it is: it is not sanitized.
+/// Return `None` to compile the embed to a placeholder.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::WikiEmbedResolve;
+/// # fn main() {
+///
+/// // Show images, and use placeholders for everything else:
+/// let resolve = WikiEmbedResolve(std::rc::Rc::new(
+///     |target: &str, _fragment: Option<&str>, alias: Option<&str>| {
+///         if target.ends_with(".png") {
+///             Some(format!("<img src=\"/files/{}\" alt=\"{}\" />", target, alias.unwrap_or("")))
+///         } else {
+///             None
+///         }
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WikiEmbedResolve(pub Rc<Embed>);
+
+impl fmt::Debug for WikiEmbedResolve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WikiEmbedResolve([Function])")
+    }
+}
+
+/// Get the target and alias of the wiki link whose enter is at `index`.
+///
+/// The target includes the fragment, if there is one.
+pub fn target_and_alias(events: &[Event], bytes: &[u8], index: usize) -> (String, Option<String>) {
+    let (mut target, fragment, alias) = parts(events, bytes, index);
+
+    if let Some(fragment) = fragment {
+        target.push('#');
+        target.push_str(&fragment);
+    }
+
+    (target, alias)
+}
+
+/// Get the target, fragment (without `#`), and alias of the wiki link or
+/// wiki embed whose enter is at `index`.
+pub fn parts(
+    events: &[Event],
+    bytes: &[u8],
+    index: usize,
+) -> (String, Option<String>, Option<String>) {
+    let mut index = index + 1;
+    let mut target = String::new();
+    let mut fragment = None;
+    let mut alias = None;
+
+    while !(events[index].kind == Kind::Exit
+        && matches!(events[index].name, Name::WikiEmbed | Name::WikiLink))
+    {
+        if events[index].kind == Kind::Enter {
+            let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index + 1));
+            let value = slice.as_str();
+
+            match events[index].name {
+                Name::WikiLinkTarget => target = value.into(),
+                Name::WikiLinkFragment => fragment = Some(value[1..].into()),
+                Name::WikiLinkAlias => alias = Some(value.into()),
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    (target, fragment, alias)
+}
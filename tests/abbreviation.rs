@@ -0,0 +1,142 @@
+use markdown::{
+    mdast::{Abbreviation, AbbreviationDefinition, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn abbreviation() -> Result<(), message::Message> {
+    let abbreviation = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("*[HTML]: Hyper Text Markup Language\n\nHTML"),
+        "<p>*[HTML]: Hyper Text Markup Language</p>\n<p>HTML</p>",
+        "should not support abbreviations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n\nThe HTML spec.",
+            &abbreviation
+        )?,
+        "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> spec.</p>",
+        "should support abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "The HTML spec.\n\n*[HTML]: Hyper Text Markup Language",
+            &abbreviation
+        )?,
+        "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> spec.</p>\n",
+        "should support abbreviations defined after they are used"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nHTMLs, xHTML, HTML5, HTML.", &abbreviation)?,
+        "<p>HTMLs, xHTML, HTML5, <abbr title=\"a\">HTML</abbr>.</p>",
+        "should not support abbreviations in other words"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n\nhtml", &abbreviation)?,
+        "<p>html</p>",
+        "should match labels case-sensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML 5]: b\n\nHTML 5, HTML", &abbreviation)?,
+        "<p><abbr title=\"b\">HTML 5</abbr>, <abbr title=\"a\">HTML</abbr></p>",
+        "should prefer longer labels"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]: b\n*[a]: c\n\na", &abbreviation)?,
+        "<p><abbr title=\"b\">a</abbr></p>",
+        "should prefer the first definition"
+    );
+
+    assert_eq!(
+        to_html_with_options("  *[a]: b  \n*[c]:d\n\na c", &abbreviation)?,
+        "<p><abbr title=\"b\">a</abbr> <abbr title=\"d\">c</abbr></p>",
+        "should support whitespace around definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]: b\n\n    *[c]: d\n\nc", &abbreviation)?,
+        "<pre><code>*[c]: d\n</code></pre>\n<p>c</p>",
+        "should not support definitions indented 4 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]:\n*[]: b\n*[ ]: c\n*[d] e\n\na", &abbreviation)?,
+        "<p>*[a]:\n*[]: b\n*[ ]: c\n*[d] e</p>\n<p>a</p>",
+        "should not support definitions without label or title"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n*[a]: b\nc", &abbreviation)?,
+        "<p><abbr title=\"b\">a</abbr></p>\n<p>c</p>",
+        "should support definitions interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a]: <b> & \"c\"\n\na", &abbreviation)?,
+        "<p><abbr title=\"&lt;b&gt; &amp; &quot;c&quot;\">a</abbr></p>",
+        "should encode titles"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[a]: b\n\n# a\n\n*a* [a](a \"a\") `a` ![a](a)\n\n> - a",
+            &abbreviation
+        )?,
+        "<h1><abbr title=\"b\">a</abbr></h1>\n<p><em><abbr title=\"b\">a</abbr></em> <a href=\"a\" title=\"a\"><abbr title=\"b\">a</abbr></a> <code>a</code> <img src=\"a\" alt=\"a\" /></p>\n<blockquote>\n<ul>\n<li><abbr title=\"b\">a</abbr></li>\n</ul>\n</blockquote>",
+        "should support abbreviations in text, but not in code, destinations, titles, or image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a HTML\n\n*[HTML]: b", &abbreviation.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::Paragraph(Paragraph {
+                    children: vec![
+                        Node::Text(Text {
+                            value: "a ".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        }),
+                        Node::Abbreviation(Abbreviation {
+                            children: vec![Node::Text(Text {
+                                value: "HTML".into(),
+                                position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                            })],
+                            position: Some(Position::new(1, 3, 2, 1, 7, 6)),
+                            title: "b".into()
+                        })
+                    ],
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                }),
+                Node::AbbreviationDefinition(AbbreviationDefinition {
+                    position: Some(Position::new(3, 1, 8, 3, 11, 18)),
+                    label: "HTML".into(),
+                    title: "b".into()
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 11, 18))
+        }),
+        "should support abbreviations as `Abbreviation`s and `AbbreviationDefinition`s in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use markdown::{
+    mdast::{AttributeList, Emphasis, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn attribute_list() -> Result<(), message::Message> {
+    let attribute_list = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n{: .b}"),
+        "<p>a\n{: .b}</p>",
+        "should not support attribute lists by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n{: #b .c d=\"e f\" g=h .i}", &attribute_list)?,
+        "<p id=\"b\" class=\"c i\" d=\"e f\" g=\"h\">a</p>\n",
+        "should support ids, classes, and properties on the block before"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: .a}\n# b", &attribute_list)?,
+        "<h1 class=\"a\">b</h1>",
+        "should support attribute lists on the block after"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: .a .b}\nc", &attribute_list)?,
+        "<p class=\"a b\">c</p>",
+        "should join classes"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: #a #b}\nc", &attribute_list)?,
+        "<p id=\"b\">c</p>",
+        "should use the last value of other attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n{: .b}", &attribute_list)?,
+        "<blockquote class=\"b\">\n<p>a</p>\n</blockquote>\n",
+        "should support attribute lists on block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("> {: .a}\n> b", &attribute_list)?,
+        "<blockquote>\n<p class=\"a\">b</p>\n</blockquote>",
+        "should support attribute lists in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n{: .b}", &attribute_list)?,
+        "<ul class=\"b\">\n<li>a</li>\n</ul>\n",
+        "should support attribute lists on lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\n{: .a}", &attribute_list)?,
+        "<hr class=\"a\" />\n",
+        "should support attribute lists on thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```\n{: .b}", &attribute_list)?,
+        "<pre class=\"b\"><code>a\n</code></pre>\n",
+        "should support attribute lists on fenced code"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n===\n{: .b}", &attribute_list)?,
+        "<h1 class=\"b\">a</h1>\n",
+        "should support attribute lists on setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*{: .b} **c**{: #d} ~e~{: .f}", &attribute_list)?,
+        "<p><em class=\"b\">a</em> <strong id=\"d\">c</strong> <del class=\"f\">e</del></p>",
+        "should support attribute lists on attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b){: .c} ![d](e){: .f} `g`{: .h}", &attribute_list)?,
+        "<p><a href=\"b\" class=\"c\">a</a> <img src=\"e\" alt=\"d\" class=\"f\" /> <code class=\"h\">g</code></p>",
+        "should support attribute lists on links, images, and code"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*{.b #c d=e} [f](g){.h} `i`{#j}", &attribute_list)?,
+        "<p><em class=\"b\" id=\"c\" d=\"e\">a</em> <a href=\"g\" class=\"h\">f</a> <code id=\"j\">i</code></p>",
+        "should support attribute lists w/o colon on spans"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {.b} c\n\n{.d} *e*{.f}\n\n{.g}", &attribute_list)?,
+        "<p>a {.b} c</p>\n<p>{.d} <em class=\"f\">e</em></p>\n<p>{.g}</p>",
+        "should not support attribute lists w/o colon that do not apply to a span"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*{} *b*{ } *c*{.d", &attribute_list)?,
+        "<p><em>a</em>{} <em>b</em>{ } <em>c</em>{.d</p>",
+        "should not support attribute lists w/o colon w/o attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {: .b} c", &attribute_list)?,
+        "<p>a  c</p>",
+        "should drop attribute lists that do not apply to anything"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n{: .b}", &attribute_list)?,
+        "<p>a</p>\n",
+        "should not support a blank line between a block and an attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*{:b}", &attribute_list)?,
+        "<p><em>a</em>{:b}</p>",
+        "should not support bare words"
+    );
+
+    assert_eq!(
+        to_html_with_options("a{: .b", &attribute_list)?,
+        "<p>a{: .b</p>",
+        "should not support unclosed attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a*{: b=\"<&>\"}", &attribute_list)?,
+        "<p><em b=\"&lt;&amp;&gt;\">a</em></p>",
+        "should encode values"
+    );
+
+    assert_eq!(
+        to_mdast("*a*{: .b}", &attribute_list.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 2, 1, 1, 3, 2))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                    }),
+                    Node::AttributeList(AttributeList {
+                        position: Some(Position::new(1, 4, 3, 1, 10, 9)),
+                        attributes: vec![("class".into(), "b".into())]
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support attribute lists as `AttributeList`s in mdast"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Link, Node, Paragraph, Root, Text},
+    mdast::{DestinationKind, Link, Node, Paragraph, Root, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -290,6 +290,8 @@ fn autolink() -> Result<(), message::Message> {
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
                             position: Some(Position::new(1, 4, 3, 1, 21, 20))
@@ -303,6 +305,8 @@ fn autolink() -> Result<(), message::Message> {
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
                             position: Some(Position::new(1, 26, 25, 1, 43, 42))
@@ -323,3 +327,79 @@ fn autolink() -> Result<(), message::Message> {
 
     Ok(())
 }
+
+#[test]
+fn autolink_relaxed() -> Result<(), message::Message> {
+    let relaxed = Options {
+        parse: ParseOptions {
+            autolink_relaxed: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("<a@b_c.d>"),
+        "<p>&lt;a@b_c.d&gt;</p>",
+        "should not support underscores in email autolinks by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b_c.d>", &relaxed)?,
+        "<p><a href=\"mailto:a@b_c.d\">a@b_c.d</a></p>",
+        "should support underscores in email autolinks w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b_-_c>", &relaxed)?,
+        "<p><a href=\"mailto:a@b_-_c\">a@b_-_c</a></p>",
+        "should support underscores next to dashes w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@_b.c>", &relaxed)?,
+        "<p>&lt;a@_b.c&gt;</p>",
+        "should not support an underscore at the start of a label w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b_.c>", &relaxed)?,
+        "<p>&lt;a@b_.c&gt;</p>",
+        "should not support an underscore at the end of a label w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@b_>", &relaxed)?,
+        "<p>&lt;a@b_&gt;</p>",
+        "should not support an underscore at the end of an email w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<asd@0123456789012345678901234567890123456789012345678901234567890123.a>",
+            &relaxed
+        )?,
+        "<p><a href=\"mailto:asd@0123456789012345678901234567890123456789012345678901234567890123.a\">asd@0123456789012345678901234567890123456789012345678901234567890123.a</a></p>",
+        "should support labels longer than 63 characters w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a+b+c@d_e>", &relaxed)?,
+        "<p><a href=\"mailto:a+b+c@d_e\">a+b+c@d_e</a></p>",
+        "should support plus addressing w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<+a@b_c>", &relaxed)?,
+        "<p><a href=\"mailto:+a@b_c\">+a@b_c</a></p>",
+        "should support a plus at the start w/ `autolink_relaxed`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a_b.c>", &relaxed)?,
+        "<p><a href=\"https://a_b.c\">https://a_b.c</a></p>",
+        "should not affect URL autolinks w/ `autolink_relaxed`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use markdown::{
+    mdast::{BlockId, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn block_id() -> Result<(), message::Message> {
+    let block_id = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                block_id: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ^b"),
+        "<p>a ^b</p>",
+        "should not support block ids by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b", &block_id)?,
+        "<p id=\"^b\">a</p>",
+        "should support a block id at the end of a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb ^c-1D", &block_id)?,
+        "<p id=\"^c-1D\">a\nb</p>",
+        "should support a block id at the end of a multiline paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \t ^b \t ", &block_id)?,
+        "<p id=\"^b\">a</p>",
+        "should ignore whitespace around block ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("a   ^b", &block_id)?,
+        "<p id=\"^b\">a</p>",
+        "should not form a hard break before block ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^b", &block_id)?,
+        "<p>a^b</p>",
+        "should not support a block id w/o whitespace before it"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n^b", &block_id)?,
+        "<p>a\n^b</p>",
+        "should not support a block id on its own line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b c", &block_id)?,
+        "<p>a ^b c</p>",
+        "should not support a block id that is not at the end"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b\nc", &block_id)?,
+        "<p>a ^b\nc</p>",
+        "should not support a block id that is not on the last line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^", &block_id)?,
+        "<p>a ^</p>",
+        "should not support a block id w/o value"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b_c", &block_id)?,
+        "<p>a ^b_c</p>",
+        "should not support a block id w/ other characters"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a ^b*", &block_id)?,
+        "<p><em>a ^b</em></p>",
+        "should not support a block id in other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a ^b #", &block_id)?,
+        "<h1 id=\"^b\">a</h1>",
+        "should support a block id in an ATX heading"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b\n---", &block_id)?,
+        "<h2 id=\"^b\">a</h2>",
+        "should support a block id in a setext heading"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a ^b", &block_id)?,
+        "<blockquote>\n<p id=\"^b\">a</p>\n</blockquote>",
+        "should support a block id in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a ^b\n- c ^d", &block_id)?,
+        "<ul>\n<li id=\"^b\">a</li>\n<li id=\"^d\">c</li>\n</ul>",
+        "should add a block id in the first paragraph of a list item to the item"
+    );
+
+    assert_eq!(
+        to_html_with_options("- [x] a ^b", &block_id)?,
+        "<ul>\n<li id=\"^b\"><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a</li>\n</ul>",
+        "should support a block id in a task list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n  b ^c", &block_id)?,
+        "<ul>\n<li>\n<p>a</p>\n<p id=\"^c\">b</p>\n</li>\n</ul>",
+        "should add a block id in other paragraphs of a list item to the paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b ^c |", &block_id)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b ^c</td>\n</tr>\n</tbody>\n</table>",
+        "should show block ids that do not apply to anything"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ^b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attribute_list: true,
+                        block_id: true,
+                        ..Constructs::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p id=\"^b\">a</p>",
+        "should support block ids w/ attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ^b\n{: #c .d}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attribute_list: true,
+                        block_id: true,
+                        ..Constructs::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p id=\"c\" class=\"d\">a</p>\n",
+        "should prefer ids from attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a ^b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        block_id: true,
+                        ..Constructs::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    heading_ids: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<h1 id=\"^b\">a</h1>",
+        "should prefer block ids over generated heading ids"
+    );
+
+    assert_eq!(
+        to_mdast("a ^b", &block_id.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::BlockId(BlockId {
+                        value: "b".into(),
+                        position: Some(Position::new(1, 3, 2, 1, 5, 4))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 5, 4))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 4))
+        }),
+        "should support block ids as `BlockId`s in mdast"
+    );
+
+    Ok(())
+}
@@ -229,7 +229,8 @@ fn block_quote() -> Result<(), message::Message> {
                     }),],
                     position: Some(Position::new(1, 3, 2, 1, 4, 3))
                 })],
-                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                alert: None
             })],
             position: Some(Position::new(1, 1, 0, 1, 4, 3))
         }),
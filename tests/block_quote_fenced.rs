@@ -0,0 +1,132 @@
+use markdown::{
+    mdast::{BlockQuote, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn block_quote_fenced() -> Result<(), message::Message> {
+    let fenced = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                block_quote_fenced: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(">>>\na\n>>>"),
+        "<blockquote>\n<blockquote>\n<blockquote>\n</blockquote>\n</blockquote>\n</blockquote>\n<p>a</p>\n<blockquote>\n<blockquote>\n<blockquote>\n</blockquote>\n</blockquote>\n</blockquote>",
+        "should not support fenced block quotes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\na\n\nb\n>>>\nc", &fenced)?,
+        "<blockquote>\n<p>a</p>\n<p>b</p>\n</blockquote>\n<p>c</p>",
+        "should support fenced block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\na", &fenced)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should support a missing closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\n>>>", &fenced)?,
+        "<blockquote>\n</blockquote>",
+        "should support empty fenced block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(" >>> \na\n   >>>\t\nb", &fenced)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<p>b</p>",
+        "should support whitespace around fences"
+    );
+
+    assert_eq!(
+        to_html_with_options("    >>>\na", &fenced)?,
+        "<pre><code>&gt;&gt;&gt;\n</code></pre>\n<p>a</p>",
+        "should not support four spaces before a fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>>\na", &fenced)?,
+        "<blockquote>\n<blockquote>\n<blockquote>\n<blockquote>\n</blockquote>\n</blockquote>\n</blockquote>\n</blockquote>\n<p>a</p>",
+        "should not support more than three markers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>> a\nb", &fenced)?,
+        "<blockquote>\n<blockquote>\n<blockquote>\n<p>a\nb</p>\n</blockquote>\n</blockquote>\n</blockquote>",
+        "should not support content after an opening fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n>>>\nb\n>>>", &fenced)?,
+        "<p>a</p>\n<blockquote>\n<p>b</p>\n</blockquote>",
+        "should support fenced block quotes interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\n* a\n* b\n>>>", &fenced)?,
+        "<blockquote>\n<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n</blockquote>",
+        "should support containers in fenced block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\n> a\n>>>\nb", &fenced)?,
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n</blockquote>\n<p>b</p>",
+        "should support block quotes in fenced block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\na\n>>>\n>>>\nb\n>>>", &fenced)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<blockquote>\n<p>b</p>\n</blockquote>",
+        "should not nest fenced block quotes (a fence closes)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> >>>\n> a\n> >>>\n> b", &fenced)?,
+        "<blockquote>\n<blockquote>\n<p>a</p>\n</blockquote>\n<p>b</p>\n</blockquote>",
+        "should support fenced block quotes in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("* >>>\n  a\n  >>>\n* b", &fenced)?,
+        "<ul>\n<li>\n<blockquote>\n<p>a</p>\n</blockquote>\n</li>\n<li>b</li>\n</ul>",
+        "should support fenced block quotes in list items, w/o making them loose"
+    );
+
+    assert_eq!(
+        to_html_with_options(">>>\n```\na\n>>>\nb", &fenced)?,
+        "<blockquote>\n<pre><code>a\n\n</code></pre>\n</blockquote>\n<p>b</p>",
+        "should close fenced block quotes in code (fenced)"
+    );
+
+    assert_eq!(
+        to_mdast(">>>\na\n>>>", &fenced.parse)?,
+        Node::Root(Root {
+            children: vec![Node::BlockQuote(BlockQuote {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(2, 1, 4, 2, 2, 5))
+                    })],
+                    position: Some(Position::new(2, 1, 4, 2, 2, 5))
+                })],
+                position: Some(Position::new(1, 1, 0, 3, 4, 9)),
+                alert: None
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 9))
+        }),
+        "should support fenced block quotes as `BlockQuote`s in mdast"
+    );
+
+    Ok(())
+}
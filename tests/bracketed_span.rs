@@ -0,0 +1,108 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Span, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn bracketed_span() -> Result<(), message::Message> {
+    let span = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                bracketed_span: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a [b]{.c} d"),
+        "<p>a [b]{.c} d</p>",
+        "should not support bracketed spans by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [b *c*]{.d #e f=g} h", &span)?,
+        "<p>a <span class=\"d\" id=\"e\" f=\"g\">b <em>c</em></span> h</p>",
+        "should support bracketed spans w/ an attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]{}", &span)?,
+        "<p><span>a</span></p>",
+        "should support an empty attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a] [b] {.c} [d]{.e", &span)?,
+        "<p>[a] [b] {.c} [d]{.e</p>",
+        "should not support brackets w/o attributes, w/ whitespace before them, or w/ an unclosed attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a [b](c) d]{.e}", &span)?,
+        "<p><span class=\"e\">a <a href=\"c\">b</a> d</span></p>",
+        "should support links in bracketed spans"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a [b]{.c} d](e)", &span)?,
+        "<p><a href=\"e\">a <span class=\"c\">b</span> d</a></p>",
+        "should support bracketed spans in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a [b]{.c} d]{.e}", &span)?,
+        "<p><span class=\"e\">a <span class=\"c\">b</span> d</span></p>",
+        "should support bracketed spans in bracketed spans"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]{.b}\n\n[a]: c", &span)?,
+        "<p><span class=\"b\">a</span></p>\n",
+        "should prefer bracketed spans over shortcut references"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a [b]{.c}](d)", &span)?,
+        "<p><img src=\"d\" alt=\"a b\" /></p>",
+        "should support bracketed spans in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options("[<a>]{b=\"&<c>\"}", &span)?,
+        "<p><span b=\"&amp;&lt;c&gt;\">&lt;a&gt;</span></p>",
+        "should encode bracketed spans"
+    );
+
+    assert_eq!(
+        to_mdast("a [b]{.c}", &span.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Span(Span {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 10, 9)),
+                        attributes: vec![("class".into(), "c".into())]
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support bracketed spans as `Span`s in mdast"
+    );
+
+    Ok(())
+}
@@ -16,6 +16,12 @@ fn character_reference() -> Result<(), message::Message> {
         "should support named character references"
     );
 
+    assert_eq!(
+        to_html("&emsp; &emsp13; &sup; &sup3; &supE;"),
+        "<p>\u{2003} \u{2004} ⊃ ³ ⫆</p>",
+        "should support named character references that are prefixes of others"
+    );
+
     assert_eq!(
         to_html("&#35; &#1234; &#992; &#0;"),
         "<p># Ӓ Ϡ �</p>",
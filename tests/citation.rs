@@ -0,0 +1,196 @@
+use markdown::{
+    mdast::{Citation, CitationItem, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CitationResolve, CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn citation() -> Result<(), message::Message> {
+    let citation = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                citation: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a [@b] @c"),
+        "<p>a [@b] @c</p>",
+        "should not support citations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [@b] c", &citation)?,
+        "<p>a <span class=\"citation\" data-cites=\"b\">[@b]</span> c</p>",
+        "should support bracketed citations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[see @a, pp. 33-35; also @b, chap. 1]", &citation)?,
+        "<p><span class=\"citation\" data-cites=\"a b\">[see @a, pp. 33-35; also @b, chap. 1]</span></p>",
+        "should support several items w/ prefixes and locators"
+    );
+
+    assert_eq!(
+        to_html_with_options("[-@a] [@a p. 1] [@a;@b] [@a, ]", &citation)?,
+        "<p><span class=\"citation\" data-cites=\"a\">[-@a]</span> <span class=\"citation\" data-cites=\"a\">[@a p. 1]</span> <span class=\"citation\" data-cites=\"a b\">[@a;@b]</span> <span class=\"citation\" data-cites=\"a\">[@a, ]</span></p>",
+        "should support suppressing authors, locators w/o comma, and missing whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("@a says. @a [p. 1] says.", &citation)?,
+        "<p><span class=\"citation\" data-cites=\"a\">@a</span> says. <span class=\"citation\" data-cites=\"a\">@a [p. 1]</span> says.</p>",
+        "should support bare citations, w/ locators"
+    );
+
+    assert_eq!(
+        to_html_with_options("@a:b.c @d.. @e-f- @_g @1", &citation)?,
+        "<p><span class=\"citation\" data-cites=\"a:b.c\">@a:b.c</span> <span class=\"citation\" data-cites=\"d\">@d</span>.. <span class=\"citation\" data-cites=\"e-f\">@e-f</span>- <span class=\"citation\" data-cites=\"_g\">@_g</span> <span class=\"citation\" data-cites=\"1\">@1</span></p>",
+        "should support internal punctuation in keys, but not trailing"
+    );
+
+    assert_eq!(
+        to_html_with_options("[see a] [@] [@ a] [@a; ] [@a\nb] \\@a [\\@a]", &citation)?,
+        "<p>[see a] [@] [@ a] [<span class=\"citation\" data-cites=\"a\">@a</span>; ] [<span class=\"citation\" data-cites=\"a\">@a</span>\nb] @a [@a]</p>",
+        "should not support bracketed citations w/o keys, w/ line endings, or w/ escaped `@`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[@a](b) @c [d](e)", &citation)?,
+        "<p><a href=\"b\"><span class=\"citation\" data-cites=\"a\">@a</span></a> <span class=\"citation\" data-cites=\"c\">@c</span> <a href=\"e\">d</a></p>",
+        "should not support brackets followed by resources as citations or locators"
+    );
+
+    assert_eq!(
+        to_html_with_options("a@b.com", &citation)?,
+        "<p><a href=\"mailto:a@b.com\">a@b.com</a></p>",
+        "should not interfere with email autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("[^a] [[@b]]\n\n[^a]: c", &citation)?,
+        "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> [<span class=\"citation\" data-cites=\"b\">[@b]</span>]</p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>c <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should not interfere with footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[<a> @b, \"c\"]", &citation)?,
+        "<p><span class=\"citation\" data-cites=\"b\">[&lt;a&gt; @b, &quot;c&quot;]</span></p>",
+        "should encode citations"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a [@b]](c)", &citation)?,
+        "<p><img src=\"c\" alt=\"a [@b]\" /></p>",
+        "should support citations in image alts"
+    );
+
+    let resolve = |citation: &Citation| {
+        if citation.items[0].key == "a" {
+            Some(
+                citation
+                    .items
+                    .iter()
+                    .map(|d| {
+                        format!(
+                            "{}{}{}",
+                            d.prefix
+                                .as_deref()
+                                .map_or(String::new(), |d| d.to_owned() + " "),
+                            if d.suppress_author {
+                                "1999"
+                            } else {
+                                "Doe 1999"
+                            },
+                            d.locator
+                                .as_deref()
+                                .map_or(String::new(), |d| ", ".to_owned() + d)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        } else {
+            None
+        }
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "[see @a, p. 1; -@a] @b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        citation: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    citation_resolve: Some(CitationResolve(Rc::new(resolve))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p>see Doe 1999, p. 1; 1999 <span class=\"citation\" data-cites=\"b\">@b</span></p>",
+        "should support `citation_resolve`"
+    );
+
+    assert_eq!(
+        to_mdast("a [see @b, c; -@d] @e [f]", &citation.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Citation(Citation {
+                        position: Some(Position::new(1, 3, 2, 1, 19, 18)),
+                        in_text: false,
+                        items: vec![
+                            CitationItem {
+                                key: "b".into(),
+                                prefix: Some("see".into()),
+                                locator: Some("c".into()),
+                                suppress_author: false
+                            },
+                            CitationItem {
+                                key: "d".into(),
+                                prefix: None,
+                                locator: None,
+                                suppress_author: true
+                            }
+                        ]
+                    }),
+                    Node::Text(Text {
+                        value: " ".into(),
+                        position: Some(Position::new(1, 19, 18, 1, 20, 19))
+                    }),
+                    Node::Citation(Citation {
+                        position: Some(Position::new(1, 20, 19, 1, 26, 25)),
+                        in_text: true,
+                        items: vec![CitationItem {
+                            key: "e".into(),
+                            prefix: None,
+                            locator: Some("f".into()),
+                            suppress_author: false
+                        }]
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 26, 25))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 26, 25))
+        }),
+        "should support citations as `Citation`s in mdast"
+    );
+
+    Ok(())
+}
@@ -122,6 +122,12 @@ fn code_indented() -> Result<(), message::Message> {
         "should not support lazyness (7)"
     );
 
+    assert_eq!(
+        to_html("- a\n-    >\n\tb\n\n    c"),
+        "<ul>\n<li>a</li>\n<li>\n<blockquote>\n</blockquote>\n</li>\n</ul>\n<pre><code>b\n\nc\n</code></pre>",
+        "should support blank lines in code after closed containers"
+    );
+
     let off = Options {
         parse: ParseOptions {
             constructs: Constructs {
@@ -0,0 +1,155 @@
+use markdown::{
+    mdast::{CriticMarkupComment, Delete, Insert, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, CriticMarkupMode, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn critic_markup() -> Result<(), message::Message> {
+    let critic = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                critic_markup: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let with_mode = |mode: CriticMarkupMode| Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                critic_markup: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            critic_markup_mode: mode,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html("a {++b++} c"),
+        "<p>a {++b++} c</p>",
+        "should not support CriticMarkup by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {++b++} {--c--} {~~d~>e~~} {==f==}{>>g<<}", &critic)?,
+        "<p>a <ins>b</ins> <del>c</del> <del>d</del><ins>e</ins> <mark>f</mark><span class=\"critic-comment\">g</span></p>",
+        "should support additions, deletions, substitutions, highlights, and comments"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a {++b++} {--c--} {~~d~>e~~} {==f==}{>>g<<}",
+            &with_mode(CriticMarkupMode::Accept)
+        )?,
+        "<p>a b  e f</p>",
+        "should support accepting changes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a {++b++} {--c--} {~~d~>e~~} {==f==}{>>g<<}",
+            &with_mode(CriticMarkupMode::Reject)
+        )?,
+        "<p>a  c d f</p>",
+        "should support rejecting changes"
+    );
+
+    assert_eq!(
+        to_html_with_options("{++*a* `b`++} {--c\nd--}", &critic)?,
+        "<p><ins><em>a</em> <code>b</code></ins> <del>c\nd</del></p>",
+        "should support content and line endings in CriticMarkup"
+    );
+
+    assert_eq!(
+        to_html_with_options("{++++} {++a--} {--a++}", &critic)?,
+        "<p>{++++} <ins>a--} {--a</ins></p>",
+        "should not support empty CriticMarkup, and only match sequences of the same kind"
+    );
+
+    assert_eq!(
+        to_html_with_options("{~~a~~} {~~~>b~~} {~~a~>~~} {++a~>b++} a~>b", &critic)?,
+        "<p>{~~a~~} {~~~&gt;b~~} {~~a~&gt;~~} <ins>a~&gt;b</ins> a~&gt;b</p>",
+        "should only support separators w/ something on either side in substitutions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a {++b](c) d++} *e {--f* g--}", &critic)?,
+        "<p><a href=\"c\">a {++b</a> d++} *e <del>f* g</del></p>",
+        "should not support CriticMarkup crossing other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "{~~~~a~~ b~>c~~} {++==d==++} ~~e~~",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        critic_markup: true,
+                        gfm_strikethrough: true,
+                        insert: true,
+                        mark: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><del><del>a</del> b</del><ins>c</ins> <ins><mark>d</mark></ins> <del>e</del></p>",
+        "should support CriticMarkup next to strikethrough, insert, and mark"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <b> <https://c> {: .d}", &critic)?,
+        "<p>a &lt;b&gt; <a href=\"https://c\">https://c</a> {: .d}</p>",
+        "should not interfere with html, autolinks, or braces"
+    );
+
+    assert_eq!(
+        to_mdast("a {~~b~>c~~}{>>d<<}", &critic.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Delete(Delete {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                        })],
+                        position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                    }),
+                    Node::Insert(Insert {
+                        children: vec![Node::Text(Text {
+                            value: "c".into(),
+                            position: Some(Position::new(1, 9, 8, 1, 10, 9))
+                        })],
+                        position: Some(Position::new(1, 9, 8, 1, 10, 9))
+                    }),
+                    Node::CriticMarkupComment(CriticMarkupComment {
+                        children: vec![Node::Text(Text {
+                            value: "d".into(),
+                            position: Some(Position::new(1, 16, 15, 1, 17, 16))
+                        })],
+                        position: Some(Position::new(1, 13, 12, 1, 20, 19))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 20, 19))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 20, 19))
+        }),
+        "should support CriticMarkup as `Delete`s, `Insert`s, and `CriticMarkupComment`s in mdast"
+    );
+
+    Ok(())
+}
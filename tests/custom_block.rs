@@ -0,0 +1,118 @@
+use markdown::{
+    mdast::{self, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CustomBlock, CustomBlockKind, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn custom_block() -> Result<(), message::Message> {
+    let custom_block = Options {
+        parse: ParseOptions {
+            custom_blocks: vec![
+                CustomBlock {
+                    name: "admonition".into(),
+                    kind: CustomBlockKind::Fence,
+                    marker: "!!!".into(),
+                },
+                CustomBlock {
+                    name: "aside".into(),
+                    kind: CustomBlockKind::Prefix,
+                    marker: "%".into(),
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("!!! a\nb\n!!!\n\n% c"),
+        "<p>!!! a\nb\n!!!</p>\n<p>% c</p>",
+        "should not support custom block constructs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("!!! note\na\n!!!\nb", &custom_block)?,
+        "<div class=\"admonition\" data-info=\"note\">\n<p>a</p>\n</div>\n<p>b</p>",
+        "should support fenced custom blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("!!!\n# a\n\n* b\n!!!", &custom_block)?,
+        "<div class=\"admonition\">\n<h1>a</h1>\n<ul>\n<li>b</li>\n</ul>\n</div>",
+        "should support flow in fenced custom blocks, and fences w/o info"
+    );
+
+    assert_eq!(
+        to_html_with_options("!!! a <b> \"c\"  \nd", &custom_block)?,
+        "<div class=\"admonition\" data-info=\"a &lt;b&gt; &quot;c&quot;\">\n<p>d</p>\n</div>",
+        "should support fenced custom blocks w/o closing fence, and encode info strings"
+    );
+
+    assert_eq!(
+        to_html_with_options("!!!a\n\n!!! a\nb\n!!! c\nd\n  !!!  \ne", &custom_block)?,
+        "<p>!!!a</p>\n<div class=\"admonition\" data-info=\"a\">\n<p>b</p>\n<div class=\"admonition\" data-info=\"c\">\n<p>d</p>\n</div>\n</div>\n<p>e</p>",
+        "should not support info strings w/o whitespace, and close at the first closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("% a\nb\n%\n% c\n\nd", &custom_block)?,
+        "<div class=\"aside\">\n<p>a\nb</p>\n<p>c</p>\n</div>\n<p>d</p>",
+        "should support prefixed custom blocks, w/ lazy lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("% % a\n%\n% > b", &custom_block)?,
+        "<div class=\"aside\">\n<div class=\"aside\">\n<p>a</p>\n</div>\n<blockquote>\n<p>b</p>\n</blockquote>\n</div>",
+        "should support nested containers in prefixed custom blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("* !!!\n  a\n\n  b\n  !!!\n* c", &custom_block)?,
+        "<ul>\n<li>\n<div class=\"admonition\">\n<p>a</p>\n<p>b</p>\n</div>\n</li>\n<li>c</li>\n</ul>",
+        "should support custom blocks in other containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ">>> a",
+            &Options {
+                parse: ParseOptions {
+                    custom_blocks: vec![CustomBlock {
+                        name: "b".into(),
+                        kind: CustomBlockKind::Fence,
+                        marker: ">>>".into(),
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div class=\"b\" data-info=\"a\">\n</div>",
+        "should take precedence over built in constructs"
+    );
+
+    assert_eq!(
+        to_mdast("!!! note\na\n!!!", &custom_block.parse)?,
+        Node::Root(Root {
+            children: vec![Node::CustomBlock(mdast::CustomBlock {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(2, 1, 9, 2, 2, 10))
+                    })],
+                    position: Some(Position::new(2, 1, 9, 2, 2, 10))
+                })],
+                position: Some(Position::new(1, 1, 0, 3, 4, 14)),
+                name: "admonition".into(),
+                info: Some("note".into())
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 14))
+        }),
+        "should support custom block constructs as `CustomBlock`s in mdast"
+    );
+
+    Ok(())
+}
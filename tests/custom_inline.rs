@@ -0,0 +1,167 @@
+use markdown::{
+    mdast::{self, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, CustomInline, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn custom_inline() -> Result<(), message::Message> {
+    let custom_inline = Options {
+        parse: ParseOptions {
+            custom_inlines: vec![
+                CustomInline {
+                    name: "kbd".into(),
+                    open: "++".into(),
+                    close: "++".into(),
+                },
+                CustomInline {
+                    name: "variable".into(),
+                    open: "{{".into(),
+                    close: "}}".into(),
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ++b++ {{c}}"),
+        "<p>a ++b++ {{c}}</p>",
+        "should not support custom inline constructs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ++b++ c", &custom_inline)?,
+        "<p>a <span class=\"kbd\">b</span> c</p>",
+        "should support custom inline constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {{b}} c", &custom_inline)?,
+        "<p>a <span class=\"variable\">b</span> c</p>",
+        "should support different opening and closing delimiters"
+    );
+
+    assert_eq!(
+        to_html_with_options("++Ctrl++ + ++*C*++", &custom_inline)?,
+        "<p><span class=\"kbd\">Ctrl</span> + <span class=\"kbd\"><em>C</em></span></p>",
+        "should support several constructs, and phrasing in them"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ++++ b {{}} c }}d{{", &custom_inline)?,
+        "<p>a ++++ b <span class=\"variable\">}} c </span>d{{</p>",
+        "should not support empty constructs, or unmatched sequences, but match the closest opening sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("{{a ++b}} c++", &custom_inline)?,
+        "<p><span class=\"variable\">a ++b</span> c++</p>",
+        "should not support constructs that overlap"
+    );
+
+    assert_eq!(
+        to_html_with_options("[++a](b)++ c", &custom_inline)?,
+        "<p><a href=\"b\">++a</a>++ c</p>",
+        "should not support constructs across links"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\+\\+a++ `++b++`", &custom_inline)?,
+        "<p>++a++ <code>++b++</code></p>",
+        "should not support escaped sequences, or constructs in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ++b++](c)", &custom_inline)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support custom inline constructs in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "++a++",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        insert: true,
+                        ..Default::default()
+                    },
+                    custom_inlines: custom_inline.parse.custom_inlines.clone(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><span class=\"kbd\">a</span></p>",
+        "should take precedence over built in constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a %%b\nc%% d",
+            &Options {
+                parse: ParseOptions {
+                    custom_inlines: vec![CustomInline {
+                        name: "c".into(),
+                        open: "%%".into(),
+                        close: "%%".into(),
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <span class=\"c\">b\nc</span> d</p>",
+        "should support line endings in constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a  b",
+            &Options {
+                parse: ParseOptions {
+                    custom_inlines: vec![CustomInline {
+                        name: "c".into(),
+                        open: String::new(),
+                        close: " ".into(),
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a  b</p>",
+        "should ignore constructs w/ empty delimiters"
+    );
+
+    assert_eq!(
+        to_mdast("a {{b}}", &custom_inline.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::CustomInline(mdast::CustomInline {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7)),
+                        name: "variable".into()
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support custom inline constructs as `CustomInline`s in mdast"
+    );
+
+    Ok(())
+}
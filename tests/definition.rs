@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Definition, Node, Root},
+    mdast::{Definition, DestinationKind, Node, Root, TitleKind},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -515,6 +515,8 @@ fn definition() -> Result<(), message::Message> {
                 identifier: "a".into(),
                 label: Some("a".into()),
                 title: Some("c".into()),
+                destination_kind: DestinationKind::Literal,
+                title_kind: Some(TitleKind::Single),
                 position: Some(Position::new(1, 1, 0, 1, 13, 12))
             })],
             position: Some(Position::new(1, 1, 0, 1, 13, 12))
@@ -0,0 +1,367 @@
+use markdown::{
+    mdast::{ContainerDirective, LeafDirective, Node, Paragraph, Root, Text, TextDirective},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn directive_text() -> Result<(), message::Message> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_text: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a :b[c]{d} e"),
+        "<p>a :b[c]{d} e</p>",
+        "should not support directives (text) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b e", &directive)?,
+        "<p>a <span data-directive=\"b\"></span> e</p>",
+        "should support a name"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c *d*] e", &directive)?,
+        "<p>a <span data-directive=\"b\">c <em>d</em></span> e</p>",
+        "should support a label, as text"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c [d] e] f", &directive)?,
+        "<p>a <span data-directive=\"b\">c [d] e</span> f</p>",
+        "should support balanced brackets in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c\\]d] e", &directive)?,
+        "<p>a <span data-directive=\"b\">c]d</span> e</p>",
+        "should support escaped brackets in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c\nd] e", &directive)?,
+        "<p>a <span data-directive=\"b\">c\nd</span> e</p>",
+        "should support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c\n\nd] e", &directive)?,
+        "<p>a <span data-directive=\"b\"></span>[c</p>\n<p>d] e</p>",
+        "should not support a blank line in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[] c", &directive)?,
+        "<p>a <span data-directive=\"b\"></span> c</p>",
+        "should support an empty label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b{#c .d .e f=g h=\"i j\" k} l", &directive)?,
+        "<p>a <span data-directive=\"b\" id=\"c\" class=\"d e\" f=\"g\" h=\"i j\" k=\"\"></span> l</p>",
+        "should support attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c]{d} e", &directive)?,
+        "<p>a <span data-directive=\"b\" d=\"\">c</span> e</p>",
+        "should support a label and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b{c e", &directive)?,
+        "<p>a <span data-directive=\"b\"></span>{c e</p>",
+        "should end before invalid attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[c e", &directive)?,
+        "<p>a <span data-directive=\"b\"></span>[c e</p>",
+        "should end before an invalid label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :1 :- :b- :b_ c", &directive)?,
+        "<p>a :1 :- :b- :b_ c</p>",
+        "should not support invalid names"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b-c_d1 e", &directive)?,
+        "<p>a <span data-directive=\"b-c_d1\"></span> e</p>",
+        "should support dashes, underscores, and digits in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("a::b c:d: e", &directive)?,
+        "<p>a::b c:d: e</p>",
+        "should not support a colon before or after a directive (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :b[:c[d]] e", &directive)?,
+        "<p>a <span data-directive=\"b\"><span data-directive=\"c\">d</span></span> e</p>",
+        "should support directives (text) in labels"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a :b[c]](d)", &directive)?,
+        "<p><img src=\"d\" alt=\"a c\" /></p>",
+        "should support directives (text) in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a :b[c]{.d}", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::TextDirective(TextDirective {
+                        children: vec![Node::Text(Text {
+                            value: "c".into(),
+                            position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                        })],
+                        position: Some(Position::new(1, 3, 2, 1, 12, 11)),
+                        name: "b".into(),
+                        attributes: vec![("class".into(), "d".into())]
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support directives (text) as `TextDirective`s in mdast"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn directive_leaf() -> Result<(), message::Message> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_leaf: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("::a[b]{c}"),
+        "<p>::a[b]{c}</p>",
+        "should not support directives (leaf) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a", &directive)?,
+        "<div data-directive=\"a\"></div>",
+        "should support a name"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a[b *c*]{#d .e}", &directive)?,
+        "<div data-directive=\"a\" id=\"d\" class=\"e\">b <em>c</em></div>",
+        "should support a label and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("   ::a \t", &directive)?,
+        "<div data-directive=\"a\"></div>",
+        "should support whitespace around directives (leaf)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    ::a", &directive)?,
+        "<pre><code>::a\n</code></pre>",
+        "should not support four spaces before a directive (leaf)"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a b", &directive)?,
+        "<p>::a b</p>",
+        "should not support content after a directive (leaf)"
+    );
+
+    assert_eq!(
+        to_html_with_options("::a[b\nc]", &directive)?,
+        "<p>::a[b\nc]</p>",
+        "should not support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n::b\nc", &directive)?,
+        "<p>a</p>\n<div data-directive=\"b\"></div>\n<p>c</p>",
+        "should support directives (leaf) interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a", &directive)?,
+        "<p>:::a</p>",
+        "should not support three colons"
+    );
+
+    assert_eq!(
+        to_mdast("::a[b]{c=d}", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::LeafDirective(LeafDirective {
+                children: vec![Node::Text(Text {
+                    value: "b".into(),
+                    position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11)),
+                name: "a".into(),
+                attributes: vec![("c".into(), "d".into())]
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support directives (leaf) as `LeafDirective`s in mdast"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn directive_container() -> Result<(), message::Message> {
+    let directive = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                directive_container: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(":::a\nb\n:::"),
+        "<p>:::a\nb\n:::</p>",
+        "should not support directives (container) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb\n\nc\n:::\nd", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n<p>c</p>\n</div>\n<p>d</p>",
+        "should support directives (container)"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a[b *c*]{.d}\ne\n:::", &directive)?,
+        "<div data-directive=\"a\" class=\"d\">\n<p>b <em>c</em></p>\n<p>e</p>\n</div>",
+        "should support a label, as a paragraph, and attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\nb", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>",
+        "should support a missing closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\n:::", &directive)?,
+        "<div data-directive=\"a\">\n</div>",
+        "should support empty directives (container)"
+    );
+
+    assert_eq!(
+        to_html_with_options(" :::a \nb\n   :::\t\nc", &directive)?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>\n<p>c</p>",
+        "should support whitespace around fences"
+    );
+
+    assert_eq!(
+        to_html_with_options("::::a\nb\n:::\nc\n::::\nd", &directive)?,
+        "<div data-directive=\"a\">\n<p>b\n:::\nc</p>\n</div>\n<p>d</p>",
+        "should not close with a shorter sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("::::a\n:::b\nc\n:::\n::::\nd", &directive)?,
+        "<div data-directive=\"a\">\n<div data-directive=\"b\">\n<p>c</p>\n</div>\n</div>\n<p>d</p>",
+        "should support nesting with longer sequences"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::\na\n:::", &directive)?,
+        "<p>:::\na\n:::</p>",
+        "should not support a missing name"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a b\nc", &directive)?,
+        "<p>:::a b\nc</p>",
+        "should not support content after an opening fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:::b\nc\n:::", &directive)?,
+        "<p>a</p>\n<div data-directive=\"b\">\n<p>c</p>\n</div>",
+        "should support directives (container) interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::a\n* b\n* c\n:::", &directive)?,
+        "<div data-directive=\"a\">\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</div>",
+        "should support containers in directives (container)"
+    );
+
+    assert_eq!(
+        to_html_with_options("* :::a\n  b\n  :::\n* c", &directive)?,
+        "<ul>\n<li>\n<div data-directive=\"a\">\n<p>b</p>\n</div>\n</li>\n<li>c</li>\n</ul>",
+        "should support directives (container) in list items, w/o making them loose"
+    );
+
+    assert_eq!(
+        to_html_with_options("> :::a\n> b\n> :::\n> c", &directive)?,
+        "<blockquote>\n<div data-directive=\"a\">\n<p>b</p>\n</div>\n<p>c</p>\n</blockquote>",
+        "should support directives (container) in block quotes"
+    );
+
+    assert_eq!(
+        to_mdast(":::a[b]\nc\n:::", &directive.parse)?,
+        Node::Root(Root {
+            children: vec![Node::ContainerDirective(ContainerDirective {
+                children: vec![
+                    Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                        })],
+                        position: Some(Position::new(1, 6, 5, 1, 7, 6))
+                    }),
+                    Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "c".into(),
+                            position: Some(Position::new(2, 1, 8, 2, 2, 9))
+                        })],
+                        position: Some(Position::new(2, 1, 8, 2, 2, 9))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 4, 13)),
+                name: "a".into(),
+                attributes: vec![]
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 13))
+        }),
+        "should support directives (container) as `ContainerDirective`s in mdast"
+    );
+
+    Ok(())
+}
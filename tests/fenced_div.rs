@@ -0,0 +1,145 @@
+use markdown::{
+    mdast::{Div, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn fenced_div() -> Result<(), message::Message> {
+    let div = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                fenced_div: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("::: a\nb\n:::"),
+        "<p>::: a\nb\n:::</p>",
+        "should not support fenced divs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: a\nb\n\nc\n:::\nd", &div)?,
+        "<div class=\"a\">\n<p>b</p>\n<p>c</p>\n</div>\n<p>d</p>",
+        "should support fenced divs w/ a class"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: {#a .b .c d=\"e\"}\nf\n:::", &div)?,
+        "<div id=\"a\" class=\"b c\" d=\"e\">\n<p>f</p>\n</div>",
+        "should support fenced divs w/ an attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::{.a}\nb\n:::\n\n::: {}\nc\n:::", &div)?,
+        "<div class=\"a\">\n<p>b</p>\n</div>\n<div>\n<p>c</p>\n</div>",
+        "should support fenced divs w/o whitespace, or w/ an empty attribute list"
+    );
+
+    assert_eq!(
+        to_html_with_options("::::: a :::::\nb\n::::::::", &div)?,
+        "<div class=\"a\">\n<p>b</p>\n</div>",
+        "should support more markers, and colons after the attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(":::\na\n:::\n\n::: a b\nc\n\n::: {.a}b\nc\n\n:: a\nb", &div)?,
+        "<p>:::\na\n:::</p>\n<p>::: a b\nc</p>\n<p>::: {.a}b\nc</p>\n<p>:: a\nb</p>",
+        "should not support fenced divs w/o attributes, w/ several words, w/ stuff after the attributes, or w/ too few markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: a\nb", &div)?,
+        "<div class=\"a\">\n<p>b</p>\n</div>",
+        "should support a missing closing fence"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: a\n::: b\nc\n:::\nd\n:::\ne", &div)?,
+        "<div class=\"a\">\n<div class=\"b\">\n<p>c</p>\n</div>\n<p>d</p>\n</div>\n<p>e</p>",
+        "should support nested fenced divs, closing the innermost one first"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: a\n# b\n\n* c\n\n> d\n:::", &div)?,
+        "<div class=\"a\">\n<h1>b</h1>\n<ul>\n<li>c</li>\n</ul>\n<blockquote>\n<p>d</p>\n</blockquote>\n</div>",
+        "should support flow in fenced divs"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ::: a\n> b\n> :::\n> c", &div)?,
+        "<blockquote>\n<div class=\"a\">\n<p>b</p>\n</div>\n<p>c</p>\n</blockquote>",
+        "should support fenced divs in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("* ::: a\n  b\n  :::\n* c", &div)?,
+        "<ul>\n<li>\n<div class=\"a\">\n<p>b</p>\n</div>\n</li>\n<li>c</li>\n</ul>",
+        "should support fenced divs in list items, w/o making them loose"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n::: b\nc\n:::", &div)?,
+        "<p>a</p>\n<div class=\"b\">\n<p>c</p>\n</div>",
+        "should support fenced divs interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("   ::: a\nb\n   :::\n\n    ::: c", &div)?,
+        "<div class=\"a\">\n<p>b</p>\n</div>\n<pre><code>::: c\n</code></pre>",
+        "should support indented fences, but not too much"
+    );
+
+    assert_eq!(
+        to_html_with_options("::: <a&b>\nc\n:::", &div)?,
+        "<div class=\"&lt;a&amp;b&gt;\">\n<p>c</p>\n</div>",
+        "should encode attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":::a\nb\n:::\n\n::: c\nd\n:::",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        directive_container: true,
+                        fenced_div: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div data-directive=\"a\">\n<p>b</p>\n</div>\n<div class=\"c\">\n<p>d</p>\n</div>",
+        "should prefer directives when there is no whitespace before a name"
+    );
+
+    assert_eq!(
+        to_mdast("::: {.a}\nb\n:::", &div.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Div(Div {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "b".into(),
+                        position: Some(Position::new(2, 1, 9, 2, 2, 10))
+                    })],
+                    position: Some(Position::new(2, 1, 9, 2, 2, 10))
+                })],
+                position: Some(Position::new(1, 1, 0, 3, 4, 14)),
+                attributes: vec![("class".into(), "a".into())]
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 14))
+        }),
+        "should support fenced divs as `Div`s in mdast"
+    );
+
+    Ok(())
+}
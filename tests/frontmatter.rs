@@ -151,5 +151,53 @@ fn frontmatter() -> Result<(), message::Message> {
         "should support toml as `Toml`s in mdast"
     );
 
+    let custom = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Default::default()
+            },
+            frontmatter_markers: vec![b'-', b';'],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(";;;\n{}\n;;;\n\n# a", &custom)?,
+        "<h1>a</h1>",
+        "should support custom markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na: b\n---\n\n# c", &custom)?,
+        "<h1>c</h1>",
+        "should support default markers that are passed"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\na\n+++", &custom)?,
+        "<p>+++\na\n+++</p>",
+        "should not support default markers that are not passed"
+    );
+
+    assert_eq!(
+        to_html_with_options(";;;\na\n---", &custom)?,
+        "<h2>;;;\na</h2>",
+        "should not support mixed custom markers"
+    );
+
+    assert_eq!(
+        to_mdast(";;;\n{}\n;;;", &custom.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Yaml(Yaml {
+                value: "{}".into(),
+                position: Some(Position::new(1, 1, 0, 3, 4, 10))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 10))
+        }),
+        "should support custom markers as `Yaml`s in mdast"
+    );
+
     Ok(())
 }
@@ -0,0 +1,133 @@
+use markdown::{
+    mdast::{BlockQuote, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn gfm_alert() -> Result<(), message::Message> {
+    let alert = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                gfm_alert: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("> [!NOTE]\n> a"),
+        "<blockquote>\n<p>[!NOTE]\na</p>\n</blockquote>",
+        "should not support alerts by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [!NOTE]\n> a", &alert)?,
+        "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>a</p>\n</div>",
+        "should support alerts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> [!TIP]\n> a\n\n> [!IMPORTANT]\n> b\n\n> [!WARNING]\n> c\n\n> [!CAUTION]\n> d",
+            &alert
+        )?,
+        "<div class=\"markdown-alert markdown-alert-tip\">\n<p class=\"markdown-alert-title\">Tip</p>\n<p>a</p>\n</div>\n<div class=\"markdown-alert markdown-alert-important\">\n<p class=\"markdown-alert-title\">Important</p>\n<p>b</p>\n</div>\n<div class=\"markdown-alert markdown-alert-warning\">\n<p class=\"markdown-alert-title\">Warning</p>\n<p>c</p>\n</div>\n<div class=\"markdown-alert markdown-alert-caution\">\n<p class=\"markdown-alert-title\">Caution</p>\n<p>d</p>\n</div>",
+        "should support all alert types"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [!note] \t\n> a", &alert)?,
+        "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>a</p>\n</div>",
+        "should support alert types case-insensitively, and trailing whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [!NOTE]\n> a\n> b\n>\n> # c", &alert)?,
+        "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>a\nb</p>\n<h1>c</h1>\n</div>",
+        "should support flow content in alerts"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [!NOTE]", &alert)?,
+        "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n</div>",
+        "should support alerts w/o content"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [!NOTE] a\n\n> [! NOTE]\n\n> [!NOPE]\n\n> [NOTE]", &alert)?,
+        "<blockquote>\n<p>[!NOTE] a</p>\n</blockquote>\n<blockquote>\n<p>[! NOTE]</p>\n</blockquote>\n<blockquote>\n<p>[!NOPE]</p>\n</blockquote>\n<blockquote>\n<p>[NOTE]</p>\n</blockquote>",
+        "should not support other content on the line, whitespace, other types, or no bang"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> [!NOTE]\n\n[!NOTE]\n\n>\n> [!NOTE]", &alert)?,
+        "<blockquote>\n<p>a\n[!NOTE]</p>\n</blockquote>\n<p>[!NOTE]</p>\n<blockquote>\n<p>[!NOTE]</p>\n</blockquote>",
+        "should only support alerts at the start of the first paragraph in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("> > [!NOTE]\n> > a\n\n* > [!TIP]\n  > b", &alert)?,
+        "<blockquote>\n<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>a</p>\n</div>\n</blockquote>\n<ul>\n<li>\n<div class=\"markdown-alert markdown-alert-tip\">\n<p class=\"markdown-alert-title\">Tip</p>\n<p>b</p>\n</div>\n</li>\n</ul>",
+        "should support alerts in other containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> [!NOTE]\n> a\nb",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_alert: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    hard_breaks: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>a<br />\nb</p>\n</div>",
+        "should support lazy lines and hard breaks in alerts"
+    );
+
+    assert_eq!(
+        to_mdast("> [!NOTE]\n> a", &alert.parse)?,
+        Node::Root(Root {
+            children: vec![Node::BlockQuote(BlockQuote {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(2, 3, 12, 2, 4, 13))
+                    }),],
+                    position: Some(Position::new(2, 3, 12, 2, 4, 13))
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 4, 13)),
+                alert: Some("note".into())
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 13))
+        }),
+        "should support alerts as `alert` on `BlockQuote`s in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("> [!TIP]", &alert.parse)?,
+        Node::Root(Root {
+            children: vec![Node::BlockQuote(BlockQuote {
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+                alert: Some("tip".into())
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should drop the paragraph of alerts w/o content in mdast"
+    );
+
+    Ok(())
+}
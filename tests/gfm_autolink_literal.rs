@@ -4,7 +4,7 @@
 // Remove when solved.
 
 use markdown::{
-    mdast::{Link, Node, Paragraph, Root, Text},
+    mdast::{DestinationKind, Link, Node, Paragraph, Root, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     Options, ParseOptions,
@@ -2754,6 +2754,8 @@ www.a/~
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
                             position: Some(Position::new(1, 3, 2, 1, 20, 19))
@@ -2767,6 +2769,8 @@ www.a/~
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
                             position: Some(Position::new(1, 23, 22, 1, 40, 39))
@@ -2780,6 +2784,8 @@ www.a/~
                     Node::Link(Link {
                         url: "http://www.delta.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "www.delta.com".into(),
                             position: Some(Position::new(1, 43, 42, 1, 56, 55))
@@ -2793,6 +2799,8 @@ www.a/~
                     Node::Link(Link {
                         url: "xmpp:echo@foxtrot.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "xmpp:echo@foxtrot.com".into(),
                             position: Some(Position::new(1, 59, 58, 1, 80, 79))
@@ -2806,6 +2814,8 @@ www.a/~
                     Node::Link(Link {
                         url: "mailto:golf@hotel.com".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "mailto:golf@hotel.com".into(),
                             position: Some(Position::new(1, 83, 82, 1, 104, 103))
@@ -1964,5 +1964,179 @@ normal escape: <a href="https://github.com/github/cmark-gfm/issues/277">https://
         "should support weird pipe escapes in code in tables"
     );
 
+    let headless = Options {
+        parse: ParseOptions {
+            gfm_table_headless: true,
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("| - |\n| a |", &Options::gfm())?,
+        "<p>| - |\n| a |</p>",
+        "should not support tables without head row by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("| - |\n| a |", &headless)?,
+        "<table>\n<tbody>\n<tr>\n<td>a</td>\n</tr>\n</tbody>\n</table>",
+        "should support tables without head row w/ `gfm_table_headless`"
+    );
+
+    assert_eq!(
+        to_html_with_options("| :- | -: |\n| a | b | c |\n| d |", &headless)?,
+        "<table>\n<tbody>\n<tr>\n<td align=\"left\">a</td>\n<td align=\"right\">b</td>\n</tr>\n<tr>\n<td align=\"left\">d</td>\n<td align=\"right\"></td>\n</tr>\n</tbody>\n</table>",
+        "should support alignment and the number of columns in tables without head row"
+    );
+
+    assert_eq!(
+        to_html_with_options("| - |", &headless)?,
+        "<table>\n</table>",
+        "should support a table without head row and without body"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b |", &headless)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table>",
+        "should still support tables with head row w/ `gfm_table_headless`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a | b\n| - |\n| c |", &headless)?,
+        "<p>a | b\n| - |\n| c |</p>",
+        "should not support tables without head row interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\n\n- - -", &headless)?,
+        "<hr />\n<hr />",
+        "should not support delimiter rows without pipes or colons as tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("> | - |\n> | a |", &headless)?,
+        "<blockquote>\n<table>\n<tbody>\n<tr>\n<td>a</td>\n</tr>\n</tbody>\n</table>\n</blockquote>",
+        "should support tables without head row in containers"
+    );
+
+    assert_eq!(
+        to_mdast("| - |\n| a |", &headless.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Table(Table {
+                align: vec![AlignKind::None],
+                children: vec![Node::TableRow(TableRow {
+                    children: vec![Node::TableCell(TableCell {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(2, 3, 8, 2, 4, 9))
+                        })],
+                        position: Some(Position::new(2, 1, 6, 2, 6, 11))
+                    })],
+                    position: Some(Position::new(2, 1, 6, 2, 6, 11))
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 6, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 6, 11))
+        }),
+        "should support tables without head row as mdast"
+    );
+
+    let continuation = Options {
+        parse: ParseOptions {
+            gfm_table_row_continuation: true,
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b \\\n| c |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b \\</td>\n</tr>\n<tr>\n<td>c</td>\n</tr>\n</tbody>\n</table>",
+        "should not support row continuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c | d \\\n| e | f |\n| g | h |", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c\ne</td>\n<td>d\nf</td>\n</tr>\n<tr>\n<td>g</td>\n<td>h</td>\n</tr>\n</tbody>\n</table>",
+        "should support row continuation w/ `gfm_table_row_continuation`"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b \\\n| c \\\n| d |", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b\nc\nd</td>\n</tr>\n</tbody>\n</table>",
+        "should support several continued rows"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c | d | \\\n| e |", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c\ne</td>\n<td>d</td>\n</tr>\n</tbody>\n</table>",
+        "should support row continuation after a closing pipe, and continuing rows with less cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c \\\n| d | e |", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c\nd</td>\n<td>e</td>\n</tr>\n</tbody>\n</table>",
+        "should support continuing rows with more cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b\\\\ |\n| c\\- \\ d |", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b\\</td>\n</tr>\n<tr>\n<td>c- \\ d</td>\n</tr>\n</tbody>\n</table>",
+        "should not support escaped backslashes or backslashes not at the end as row continuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| b \\\n\nc", &continuation)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table>\n<p>c</p>",
+        "should support a continued last row"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - \\\n| b |", &continuation)?,
+        "<p>| a |\n| - <br />\n| b |</p>",
+        "should not support row continuation in delimiter rows"
+    );
+
+    assert_eq!(
+        to_html_with_options("> | a |\n> | - |\n> | b \\\n> | c |", &continuation)?,
+        "<blockquote>\n<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b\nc</td>\n</tr>\n</tbody>\n</table>\n</blockquote>",
+        "should support row continuation in containers"
+    );
+
+    assert_eq!(
+        to_mdast("| a |\n| - |\n| b \\\n| c |", &continuation.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Table(Table {
+                align: vec![AlignKind::None],
+                children: vec![
+                    Node::TableRow(TableRow {
+                        children: vec![Node::TableCell(TableCell {
+                            children: vec![Node::Text(Text {
+                                value: "a".into(),
+                                position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                            })],
+                            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                    }),
+                    Node::TableRow(TableRow {
+                        children: vec![Node::TableCell(TableCell {
+                            children: vec![Node::Text(Text {
+                                value: "b\nc".into(),
+                                position: Some(Position::new(3, 3, 14, 4, 4, 21))
+                            })],
+                            position: Some(Position::new(3, 1, 12, 4, 6, 23))
+                        })],
+                        position: Some(Position::new(3, 1, 12, 4, 6, 23))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 4, 6, 23))
+            })],
+            position: Some(Position::new(1, 1, 0, 4, 6, 23))
+        }),
+        "should support row continuation as mdast"
+    );
+
     Ok(())
 }
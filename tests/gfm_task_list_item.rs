@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Emphasis, List, ListItem, Node, Paragraph, Root, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -264,6 +264,7 @@ Text.</li>
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: Some(true),
+                        task_state: Some('x'),
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -276,6 +277,7 @@ Text.</li>
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(false),
+                        task_state: Some(' '),
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -288,6 +290,7 @@ Text.</li>
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -319,6 +322,7 @@ Text.</li>
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: Some(true),
+                        task_state: Some('x'),
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -331,6 +335,7 @@ Text.</li>
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(false),
+                        task_state: Some(' '),
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -343,6 +348,7 @@ Text.</li>
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(true),
+                        task_state: Some('x'),
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Emphasis(Emphasis {
@@ -366,3 +372,114 @@ Text.</li>
 
     Ok(())
 }
+
+#[test]
+fn gfm_task_list_item_states() -> Result<(), message::Message> {
+    let states = Options {
+        parse: ParseOptions {
+            constructs: Constructs::gfm(),
+            gfm_task_list_item_states: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("* [-] a\n* [~] b\n* [>] c", &Options::gfm())?,
+        "<ul>\n<li>[-] a</li>\n<li>[~] b</li>\n<li>[&gt;] c</li>\n</ul>",
+        "should not support other states by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("* [-] a\n* [~] b\n* [>] c", &states)?,
+        "<ul>\n<li class=\"task-list-item-cancelled\"><input type=\"checkbox\" disabled=\"\" /> a</li>\n<li class=\"task-list-item-in-progress\"><input type=\"checkbox\" disabled=\"\" /> b</li>\n<li class=\"task-list-item-deferred\"><input type=\"checkbox\" disabled=\"\" /> c</li>\n</ul>",
+        "should support other states w/ `gfm_task_list_item_states`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* [x] a\n* [ ] b", &states)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a</li>\n<li><input type=\"checkbox\" disabled=\"\" /> b</li>\n</ul>",
+        "should support checked and unchecked items w/ `gfm_task_list_item_states`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* [?] a\n* [--] b", &states)?,
+        "<ul>\n<li>[?] a</li>\n<li>[--] b</li>\n</ul>",
+        "should not support unknown states"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n\n  [-] b", &states)?,
+        "<ul>\n<li>\n<p>a</p>\n<p>[-] b</p>\n</li>\n</ul>",
+        "should not support other states after the first paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("* * [-] a", &states)?,
+        "<ul>\n<li>\n<ul>\n<li class=\"task-list-item-cancelled\"><input type=\"checkbox\" disabled=\"\" /> a</li>\n</ul>\n</li>\n</ul>",
+        "should add the class to the item that contains the check"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "* [-] a",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::gfm(),
+                    gfm_task_list_item_states: true,
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    class_prefix: Some("x-".into()),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<ul>\n<li class=\"x-task-list-item-cancelled\"><input type=\"checkbox\" disabled=\"\" /> a</li>\n</ul>",
+        "should support `class_prefix`"
+    );
+
+    assert_eq!(
+        to_mdast("* [-] a\n* [x] b", &states.parse)?,
+        Node::Root(Root {
+            children: vec![Node::List(List {
+                ordered: false,
+                spread: false,
+                start: None,
+                children: vec![
+                    Node::ListItem(ListItem {
+                        checked: None,
+                        task_state: Some('-'),
+                        spread: false,
+                        children: vec![Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "a".into(),
+                                position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                            }),],
+                            position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                    }),
+                    Node::ListItem(ListItem {
+                        checked: Some(true),
+                        task_state: Some('x'),
+                        spread: false,
+                        children: vec![Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "b".into(),
+                                position: Some(Position::new(2, 7, 14, 2, 8, 15))
+                            }),],
+                            position: Some(Position::new(2, 7, 14, 2, 8, 15))
+                        })],
+                        position: Some(Position::new(2, 1, 8, 2, 8, 15))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 8, 15))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 8, 15))
+        }),
+        "should support other states as `task_state` fields on `ListItem`s in mdast"
+    );
+
+    Ok(())
+}
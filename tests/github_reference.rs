@@ -0,0 +1,165 @@
+use markdown::{
+    mdast::{GithubReference, GithubReferenceKind, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, GithubReferenceResolve, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn github_reference() -> Result<(), message::Message> {
+    let github_reference = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                github_reference: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            github_reference_resolve: Some(GithubReferenceResolve(Rc::new(|reference| {
+                let kind = match reference.kind {
+                    GithubReferenceKind::Issue => "issues",
+                    GithubReferenceKind::Commit => "commit",
+                };
+                Some(format!(
+                    "/{}/{}/{}",
+                    reference.repository.as_deref().unwrap_or("a/b"),
+                    kind,
+                    reference.value
+                ))
+            }))),
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html("a #1 b"),
+        "<p>a #1 b</p>",
+        "should not support GitHub references by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a #1 b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        github_reference: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a #1 b</p>",
+        "should compile GitHub references as plain text w/o `github_reference_resolve`"
+    );
+
+    assert_eq!(
+        to_html_with_options("#1, GH-2, and c/d#3.", &github_reference)?,
+        "<p><a href=\"/a/b/issues/1\" class=\"github-reference\">#1</a>, <a href=\"/a/b/issues/2\" class=\"github-reference\">GH-2</a>, and <a href=\"/c/d/issues/3\" class=\"github-reference\">c/d#3</a>.</p>",
+        "should support issue references"
+    );
+
+    assert_eq!(
+        to_html_with_options("1a2b3c4 (c-d/e.f_g@1a2b3c4)", &github_reference)?,
+        "<p><a href=\"/a/b/commit/1a2b3c4\" class=\"github-reference\">1a2b3c4</a> (<a href=\"/c-d/e.f_g/commit/1a2b3c4\" class=\"github-reference\">c-d/e.f_g@1a2b3c4</a>)</p>",
+        "should support commit references"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "1a2b3c 1234567 abcdefa 1A2B3C4 1a2b3c4d5e6f7a8b9c0d1a2b3c4d5e6f7a8b9c0d1",
+            &github_reference
+        )?,
+        "<p>1a2b3c 1234567 abcdefa 1A2B3C4 1a2b3c4d5e6f7a8b9c0d1a2b3c4d5e6f7a8b9c0d1</p>",
+        "should not support SHAs that are too short, too long, w/o digits or letters, or in uppercase"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a#1 #1a #1_ x1a2b3c4 /c#1 c/#1 c/d# c/d@1a2b3c4x",
+            &github_reference
+        )?,
+        "<p>a#1 #1a #1_ x1a2b3c4 /c#1 c/#1 c/d# c/d@1a2b3c4x</p>",
+        "should not support references in words, or w/o repository parts or values"
+    );
+
+    assert_eq!(
+        to_html_with_options("*#1* [#2](c) ![#3](d) `#4` \\#5", &github_reference)?,
+        "<p><em><a href=\"/a/b/issues/1\" class=\"github-reference\">#1</a></em> <a href=\"c\">#2</a> <img src=\"d\" alt=\"#3\" /> <code>#4</code> #5</p>",
+        "should support references in other phrasing, but not in links, images, code, or escaped"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "#1 #2",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        github_reference: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    github_reference_resolve: Some(GithubReferenceResolve(Rc::new(|reference| {
+                        if reference.value == "1" {
+                            Some("javascript:alert(1)".into())
+                        } else {
+                            None
+                        }
+                    }))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><a href=\"\" class=\"github-reference\">#1</a> #2</p>",
+        "should sanitize URLs, and support leaving references as text"
+    );
+
+    assert_eq!(
+        to_mdast("a GH-1", &github_reference.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::GithubReference(GithubReference {
+                        position: Some(Position::new(1, 3, 2, 1, 7, 6)),
+                        kind: GithubReferenceKind::Issue,
+                        repository: None,
+                        value: "1".into()
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 7, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 7, 6))
+        }),
+        "should support GitHub references as `GithubReference`s in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("c/d@1a2b3c4", &github_reference.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::GithubReference(GithubReference {
+                    position: Some(Position::new(1, 1, 0, 1, 12, 11)),
+                    kind: GithubReferenceKind::Commit,
+                    repository: Some("c/d".into()),
+                    value: "1a2b3c4".into()
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support repositories in mdast"
+    );
+
+    Ok(())
+}
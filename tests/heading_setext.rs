@@ -255,6 +255,12 @@ fn heading_setext() -> Result<(), message::Message> {
         "should support a two setext heading underlines after a definition, as a setext heading"
     );
 
+    assert_eq!(
+        to_html("[a]: b\n=\nc\n="),
+        "<h1>=\nc</h1>",
+        "should support a setext heading underline after a definition and a paragraph, as a setext heading"
+    );
+
     assert_eq!(
         to_html("> ===\na"),
         "<blockquote>\n<p>===\na</p>\n</blockquote>",
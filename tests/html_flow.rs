@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Html, Node, Root},
+    mdast::{Emphasis, Html, Node, Paragraph, Root, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -1139,3 +1139,107 @@ fn html_flow_7_complete() -> Result<(), message::Message> {
 
     Ok(())
 }
+
+#[test]
+fn html_flow_markdown() -> Result<(), message::Message> {
+    let danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let markdown = Options {
+        parse: ParseOptions {
+            html_flow_markdown: true,
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("<div markdown=\"1\">\n*a*\n</div>", &danger)?,
+        "<div markdown=\"1\">\n*a*\n</div>",
+        "should not support markdown in HTML by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div markdown=\"1\">\n*a*\n</div>", &markdown)?,
+        "<div>\n<p><em>a</em></p>\n</div>",
+        "should support markdown in HTML w/ `html_flow_markdown`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<section class=a markdown='1' id=\"b\">\n# c\n\n* d\n</section>\n\ne",
+            &markdown
+        )?,
+        "<section class=a id=\"b\">\n<h1>c</h1>\n<ul>\n<li>d</li>\n</ul>\n</section>\n<p>e</p>",
+        "should support markdown in HTML with other attributes and blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("<DIV MARKDOWN=1 >\n*a*\n</DIV>", &markdown)?,
+        "<DIV >\n<p><em>a</em></p>\n</DIV>",
+        "should support markdown attributes case-insensitively, unquoted, and with trailing whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div markdown=\"0\">\n*a*\n</div>", &markdown)?,
+        "<div markdown=\"0\">\n*a*\n</div>",
+        "should not support other values of the markdown attribute"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div markdown=\"1\">*a*</div>", &markdown)?,
+        "<div markdown=\"1\">*a*</div>",
+        "should not support markdown attributes on tags not alone on their line"
+    );
+
+    assert_eq!(
+        to_html_with_options("<x markdown=\"1\">\n*a*\n</x>", &markdown)?,
+        "<x markdown=\"1\">\n*a*\n</x>",
+        "should not support markdown attributes on complete (non-block) tags"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<div markdown=\"1\">\n*b*\n</div>", &markdown)?,
+        "<p>a</p>\n<div>\n<p><em>b</em></p>\n</div>",
+        "should support markdown in HTML interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("> <div markdown=\"1\">\n> *a*\n> </div>", &markdown)?,
+        "<blockquote>\n<div>\n<p><em>a</em></p>\n</div>\n</blockquote>",
+        "should support markdown in HTML in containers"
+    );
+
+    assert_eq!(
+        to_mdast("<div markdown=\"1\">\n*a*", &markdown.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::Html(Html {
+                    value: "<div>".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 19, 18))
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(2, 2, 20, 2, 3, 21))
+                        })],
+                        position: Some(Position::new(2, 1, 19, 2, 4, 22))
+                    })],
+                    position: Some(Position::new(2, 1, 19, 2, 4, 22))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 2, 4, 22))
+        }),
+        "should support markdown in HTML as `Html` without the attribute in mdast"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use markdown::{
-    mdast::{Definition, Image, ImageReference, Node, Paragraph, ReferenceKind, Root, Text},
+    mdast::{
+        Definition, DestinationKind, Image, ImageReference, Node, Paragraph, ReferenceKind, Root,
+        Text, TitleKind,
+    },
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -251,6 +254,8 @@ fn image() -> Result<(), message::Message> {
                         alt: "alpha".into(),
                         url: String::new(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         position: Some(Position::new(1, 3, 2, 1, 13, 12))
                     }),
                     Node::Text(Text {
@@ -261,6 +266,8 @@ fn image() -> Result<(), message::Message> {
                         alt: "bravo".into(),
                         url: "charlie".into(),
                         title: Some("delta".into()),
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: Some(TitleKind::Single),
                         position: Some(Position::new(1, 16, 15, 1, 41, 40))
                     }),
                     Node::Text(Text {
@@ -287,6 +294,8 @@ fn image() -> Result<(), message::Message> {
                     label: Some("x".into()),
                     url: "y".into(),
                     title: None,
+                    destination_kind: DestinationKind::Raw,
+                    title_kind: None,
                     position: Some(Position::new(1, 1, 0, 1, 7, 6))
                 }),
                 Node::Paragraph(Paragraph {
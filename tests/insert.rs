@@ -0,0 +1,114 @@
+use markdown::{
+    mdast::{Insert, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn insert() -> Result<(), message::Message> {
+    let insert = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                insert: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ++b++ c"),
+        "<p>a ++b++ c</p>",
+        "should not support insert by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ++b++ c", &insert)?,
+        "<p>a <ins>b</ins> c</p>",
+        "should support insert"
+    );
+
+    assert_eq!(
+        to_html_with_options("++a b++, a++b++c", &insert)?,
+        "<p><ins>a b</ins>, a<ins>b</ins>c</p>",
+        "should support insert w/ whitespace and inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("+a+ +++a+++ ++a+", &insert)?,
+        "<p>+a+ +++a+++ ++a+</p>",
+        "should not support insert w/ one or more than two plus signs"
+    );
+
+    assert_eq!(
+        to_html_with_options("++ a++ ++a ++", &insert)?,
+        "<p>++ a++ ++a ++</p>",
+        "should not support insert w/ whitespace on the inside"
+    );
+
+    assert_eq!(
+        to_html_with_options("++*a*++ *++a++*", &insert)?,
+        "<p><ins><em>a</em></ins> <em><ins>a</ins></em></p>",
+        "should support insert and other attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("+ ++a++", &insert)?,
+        "<ul>\n<li><ins>a</ins></li>\n</ul>",
+        "should not interfere with list items"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "~~a~~ ++b++",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_strikethrough: true,
+                        insert: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><del>a</del> <ins>b</ins></p>",
+        "should support insert next to strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ++b++](c)", &insert)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support insert in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a ++b++", &insert.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Insert(Insert {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support insert as `Insert`s in mdast"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use markdown::{
-    mdast::{Definition, LinkReference, Node, Paragraph, ReferenceKind, Root, Text},
+    mdast::{
+        Definition, DestinationKind, LinkReference, Node, Paragraph, ReferenceKind, Root, Text,
+    },
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -434,6 +436,8 @@ fn link_reference() -> Result<(), message::Message> {
                     label: Some("x".into()),
                     url: "y".into(),
                     title: None,
+                    destination_kind: DestinationKind::Raw,
+                    title_kind: None,
                     position: Some(Position::new(1, 1, 0, 1, 7, 6))
                 }),
                 Node::Paragraph(Paragraph {
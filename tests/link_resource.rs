@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Image, Link, Node, Paragraph, Root, Text},
+    mdast::{DestinationKind, Image, Link, Node, Paragraph, Root, Text, TitleKind},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Options,
@@ -480,6 +480,8 @@ fn link_resource() -> Result<(), message::Message> {
                     Node::Link(Link {
                         url: String::new(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         children: vec![Node::Text(Text {
                             value: "alpha".into(),
                             position: Some(Position::new(1, 4, 3, 1, 9, 8))
@@ -493,6 +495,8 @@ fn link_resource() -> Result<(), message::Message> {
                     Node::Link(Link {
                         url: "charlie".into(),
                         title: Some("delta".into()),
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: Some(TitleKind::Single),
                         children: vec![Node::Text(Text {
                             value: "bravo".into(),
                             position: Some(Position::new(1, 16, 15, 1, 21, 20))
@@ -511,6 +515,60 @@ fn link_resource() -> Result<(), message::Message> {
         "should support link (resource) as `Link`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[a](<b> \"c\") [d](e (f)) [g](<> '')", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Link(Link {
+                        url: "b".into(),
+                        title: Some("c".into()),
+                        destination_kind: DestinationKind::Literal,
+                        title_kind: Some(TitleKind::Double),
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 2, 1, 1, 3, 2))
+                        }),],
+                        position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                    }),
+                    Node::Text(Text {
+                        value: " ".into(),
+                        position: Some(Position::new(1, 13, 12, 1, 14, 13))
+                    }),
+                    Node::Link(Link {
+                        url: "e".into(),
+                        title: Some("f".into()),
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: Some(TitleKind::Paren),
+                        children: vec![Node::Text(Text {
+                            value: "d".into(),
+                            position: Some(Position::new(1, 15, 14, 1, 16, 15))
+                        }),],
+                        position: Some(Position::new(1, 14, 13, 1, 24, 23))
+                    }),
+                    Node::Text(Text {
+                        value: " ".into(),
+                        position: Some(Position::new(1, 24, 23, 1, 25, 24))
+                    }),
+                    Node::Link(Link {
+                        url: String::new(),
+                        title: None,
+                        destination_kind: DestinationKind::Literal,
+                        title_kind: Some(TitleKind::Single),
+                        children: vec![Node::Text(Text {
+                            value: "g".into(),
+                            position: Some(Position::new(1, 26, 25, 1, 27, 26))
+                        }),],
+                        position: Some(Position::new(1, 25, 24, 1, 35, 34))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 35, 34))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 35, 34))
+        }),
+        "should support destination and title styles in mdast"
+    );
+
     assert_eq!(
         to_mdast("[![name](image)](url)", &Default::default())?,
         Node::Root(Root {
@@ -520,10 +578,14 @@ fn link_resource() -> Result<(), message::Message> {
                         alt: "name".into(),
                         url: "image".into(),
                         title: None,
+                        destination_kind: DestinationKind::Raw,
+                        title_kind: None,
                         position: Some(Position::new(1, 2, 1, 1, 16, 15)),
                     }),],
                     url: "url".into(),
                     title: None,
+                    destination_kind: DestinationKind::Raw,
+                    title_kind: None,
                     position: Some(Position::new(1, 1, 0, 1, 22, 21)),
                 }),],
                 position: Some(Position::new(1, 1, 0, 1, 22, 21)),
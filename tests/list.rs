@@ -359,6 +359,36 @@ fn list() -> Result<(), message::Message> {
         "should support interrupting a paragraph with a 1 numbered item"
     );
 
+    assert_eq!(
+        to_html("- a\n\nb\n2. c"),
+        "<ul>\n<li>a</li>\n</ul>\n<p>b\n2. c</p>",
+        "should not support interrupting a paragraph after a closed container with a non-1 numbered item"
+    );
+
+    assert_eq!(
+        to_html("- a\n\nb\n1."),
+        "<ul>\n<li>a</li>\n</ul>\n<p>b\n1.</p>",
+        "should not support interrupting a paragraph after a closed container with an empty item"
+    );
+
+    assert_eq!(
+        to_html("- ```\n-"),
+        "<ul>\n<li>\n<pre><code>\n</code></pre>\n</li>\n<li></li>\n</ul>",
+        "should support an item right after fenced code in an item"
+    );
+
+    assert_eq!(
+        to_html("- ```\n1."),
+        "<ul>\n<li>\n<pre><code>\n</code></pre>\n</li>\n</ul>\n<ol>\n<li></li>\n</ol>",
+        "should support a different list right after fenced code in an item"
+    );
+
+    assert_eq!(
+        to_html("- <!--\n1."),
+        "<ul>\n<li>\n&lt;!--\n</li>\n</ul>\n<ol>\n<li></li>\n</ol>",
+        "should support a different list right after html (flow) in an item"
+    );
+
     assert_eq!(
         to_html("- foo\n\n- bar\n\n\n- baz"),
         "<ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>",
@@ -598,6 +628,7 @@ fn list() -> Result<(), message::Message> {
                 start: None,
                 children: vec![Node::ListItem(ListItem {
                     checked: None,
+                    task_state: None,
                     spread: false,
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
@@ -625,6 +656,7 @@ fn list() -> Result<(), message::Message> {
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -637,6 +669,7 @@ fn list() -> Result<(), message::Message> {
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
@@ -665,6 +698,7 @@ fn list() -> Result<(), message::Message> {
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: true,
                         children: vec![
                             Node::Paragraph(Paragraph {
@@ -686,6 +720,7 @@ fn list() -> Result<(), message::Message> {
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
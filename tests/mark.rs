@@ -0,0 +1,95 @@
+use markdown::{
+    mdast::{Mark, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn mark() -> Result<(), message::Message> {
+    let mark = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                mark: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ==b== c"),
+        "<p>a ==b== c</p>",
+        "should not support mark by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b== c", &mark)?,
+        "<p>a <mark>b</mark> c</p>",
+        "should support mark"
+    );
+
+    assert_eq!(
+        to_html_with_options("==a b==, a==b==c", &mark)?,
+        "<p><mark>a b</mark>, a<mark>b</mark>c</p>",
+        "should support mark w/ whitespace and inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("=a= ===a=== ==a=", &mark)?,
+        "<p>=a= ===a=== ==a=</p>",
+        "should not support mark w/ one or more than two equals signs"
+    );
+
+    assert_eq!(
+        to_html_with_options("== a== ==a ==", &mark)?,
+        "<p>== a== ==a ==</p>",
+        "should not support mark w/ whitespace on the inside"
+    );
+
+    assert_eq!(
+        to_html_with_options("==*a*== *==a==*", &mark)?,
+        "<p><mark><em>a</em></mark> <em><mark>a</mark></em></p>",
+        "should support mark and other attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n==", &mark)?,
+        "<h1>a</h1>",
+        "should not interfere with setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ==b==](c)", &mark)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support mark in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a ==b==", &mark.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Mark(Mark {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support mark as `Mark`s in mdast"
+    );
+
+    Ok(())
+}
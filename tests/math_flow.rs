@@ -255,6 +255,51 @@ fn math_flow() -> Result<(), message::Message> {
         "should not support lazyness (3)"
     );
 
+    let gitlab = Options {
+        parse: ParseOptions {
+            math_gitlab: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```math\na\n```"),
+        "<pre><code class=\"language-math\">a\n</code></pre>",
+        "should not support code (fenced) with a `math` info string as math by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```math\na\n```", &gitlab)?,
+        "<pre><code class=\"language-math math-display\">a\n</code></pre>",
+        "should support code (fenced) with a `math` info string as math w/ `math_gitlab`"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~~math b\n  a\n~~~", &gitlab)?,
+        "<pre><code class=\"language-math math-display\">  a\n</code></pre>",
+        "should support tildes and meta w/ `math_gitlab`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```mathx\na\n```\n\n```Math\nb\n```", &gitlab)?,
+        "<pre><code class=\"language-mathx\">a\n</code></pre>\n<pre><code class=\"language-Math\">b\n</code></pre>",
+        "should not support other info strings w/ `math_gitlab`"
+    );
+
+    assert_eq!(
+        to_mdast("```math\na\n```", &gitlab.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Math(Math {
+                meta: None,
+                value: "a".into(),
+                position: Some(Position::new(1, 1, 0, 3, 4, 13))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 13))
+        }),
+        "should support code (fenced) with a `math` info string as `Math`s in mdast w/ `math_gitlab`"
+    );
+
     assert_eq!(
         to_mdast("$$extra\nabc\ndef\n$$", &math.parse)?,
         Node::Root(Root {
@@ -0,0 +1,107 @@
+use markdown::{message, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn math_mathml() -> Result<(), message::Message> {
+    let mathml = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                math_flow: true,
+                math_text: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        compile: CompileOptions {
+            math_mathml: true,
+            ..CompileOptions::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("a $b$ c", &mathml)?,
+        "<p>a <math><semantics><mrow><mi>b</mi></mrow><annotation encoding=\"application/x-tex\">b</annotation></semantics></math> c</p>",
+        "should support math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$\nx = \\frac{1}{2}\n$$\n\na", &mathml)?,
+        "<math display=\"block\"><semantics><mrow><mi>x</mi><mo>=</mo><mfrac><mrow><mn>1</mn></mrow><mrow><mn>2</mn></mrow></mfrac></mrow><annotation encoding=\"application/x-tex\">x = \\frac{1}{2}</annotation></semantics></math>\n<p>a</p>",
+        "should support math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$\na\n\nb\n$$", &mathml)?,
+        "<math display=\"block\"><semantics><mrow><mi>a</mi><mi>b</mi></mrow><annotation encoding=\"application/x-tex\">a\nb</annotation></semantics></math>",
+        "should support blank lines in math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$\na", &mathml)?,
+        "<math display=\"block\"><semantics><mrow><mi>a</mi></mrow><annotation encoding=\"application/x-tex\">a</annotation></semantics></math>",
+        "should support unclosed math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> $$\n> a\n\nb", &mathml)?,
+        "<blockquote>\n<math display=\"block\"><semantics><mrow><mi>a</mi></mrow><annotation encoding=\"application/x-tex\">a</annotation></semantics></math>\n</blockquote>\n<p>b</p>",
+        "should support math (flow) in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("$a\nb$", &mathml)?,
+        "<p><math><semantics><mrow><mi>a</mi><mi>b</mi></mrow><annotation encoding=\"application/x-tex\">a b</annotation></semantics></math></p>",
+        "should turn line endings in math (text) into spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("$ a $", &mathml)?,
+        "<p><math><semantics><mrow><mi>a</mi></mrow><annotation encoding=\"application/x-tex\">a</annotation></semantics></math></p>",
+        "should drop padding in math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![$a^2$](b)", &mathml)?,
+        "<p><img src=\"b\" alt=\"a^2\" /></p>",
+        "should support math (text) in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("$a_i^2 + b_{i+1}$", &mathml)?,
+        "<p><math><semantics><mrow><msubsup><mi>a</mi><mi>i</mi><mn>2</mn></msubsup><mo>+</mo><msub><mi>b</mi><mrow><mi>i</mi><mo>+</mo><mn>1</mn></mrow></msub></mrow><annotation encoding=\"application/x-tex\">a_i^2 + b_{i+1}</annotation></semantics></math></p>",
+        "should support scripts"
+    );
+
+    assert_eq!(
+        to_html_with_options("$\\sqrt{x} \\sqrt[3]{y}$", &mathml)?,
+        "<p><math><semantics><mrow><msqrt><mrow><mi>x</mi></mrow></msqrt><mroot><mrow><mi>y</mi></mrow><mrow><mn>3</mn></mrow></mroot></mrow><annotation encoding=\"application/x-tex\">\\sqrt{x} \\sqrt[3]{y}</annotation></semantics></math></p>",
+        "should support roots"
+    );
+
+    assert_eq!(
+        to_html_with_options("$\\alpha \\leq \\infty \\sin x - 1.5$", &mathml)?,
+        "<p><math><semantics><mrow><mi>α</mi><mo>≤</mo><mi>∞</mi><mi>sin</mi><mi>x</mi><mo>−</mo><mn>1.5</mn></mrow><annotation encoding=\"application/x-tex\">\\alpha \\leq \\infty \\sin x - 1.5</annotation></semantics></math></p>",
+        "should support commands"
+    );
+
+    assert_eq!(
+        to_html_with_options("$\\text{if } a<b \\left( c \\right.$", &mathml)?,
+        "<p><math><semantics><mrow><mtext>if </mtext><mi>a</mi><mo>&lt;</mo><mi>b</mi><mo>(</mo><mi>c</mi></mrow><annotation encoding=\"application/x-tex\">\\text{if } a&lt;b \\left( c \\right.</annotation></semantics></math></p>",
+        "should support text, delimiters, and encode"
+    );
+
+    assert_eq!(
+        to_html_with_options("$\\foo a}$", &mathml)?,
+        "<p><math><semantics><mrow><merror><mtext>\\foo</mtext></merror><mi>a</mi><merror><mtext>}</mtext></merror></mrow><annotation encoding=\"application/x-tex\">\\foo a}</annotation></semantics></math></p>",
+        "should support unknown commands and unmatched braces"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: .b}\n$$\na\n$$", &mathml)?,
+        "<math display=\"block\" class=\"b\"><semantics><mrow><mi>a</mi></mrow><annotation encoding=\"application/x-tex\">a</annotation></semantics></math>",
+        "should support attributes"
+    );
+
+    Ok(())
+}
@@ -207,6 +207,36 @@ fn math_text() -> Result<(), message::Message> {
         "should support an escaped initial dollar"
     );
 
+    let gitlab = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_text: true,
+                ..Default::default()
+            },
+            math_gitlab: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("$`a`$", &math)?,
+        "<p><code class=\"language-math math-inline\">`a`</code></p>",
+        "should not support code (text) in dollars as math by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("$`a`$ $``b`c``$", &gitlab)?,
+        "<p><code class=\"language-math math-inline\">a</code> <code class=\"language-math math-inline\">b`c</code></p>",
+        "should support code (text) in dollars as math w/ `math_gitlab`"
+    );
+
+    assert_eq!(
+        to_html_with_options("$`a` `b`$ c\n\n$`d$`", &gitlab)?,
+        "<p>$<code>a</code> <code>b</code>$ c</p>\n<p>$<code>d$</code></p>",
+        "should not support code (text) w/o both dollars as math w/ `math_gitlab`"
+    );
+
     assert_eq!(
         to_mdast("a $alpha$ b.", &math.parse)?,
         Node::Root(Root {
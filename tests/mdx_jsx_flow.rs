@@ -207,6 +207,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
                     start: None,
                     children: vec![Node::ListItem(ListItem {
                         checked: None,
+                        task_state: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
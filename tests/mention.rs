@@ -0,0 +1,137 @@
+use markdown::{
+    mdast::{Mention, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, MentionResolve, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn mention() -> Result<(), message::Message> {
+    let mention = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                mention: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a @b c"),
+        "<p>a @b c</p>",
+        "should not support mentions by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a @b c", &mention)?,
+        "<p>a <span class=\"mention\" data-mention=\"b\">@b</span> c</p>",
+        "should support mentions"
+    );
+
+    assert_eq!(
+        to_html_with_options("@a-b.c_d @1", &mention)?,
+        "<p><span class=\"mention\" data-mention=\"a-b.c_d\">@a-b.c_d</span> <span class=\"mention\" data-mention=\"1\">@1</span></p>",
+        "should support internal punctuation, underscores, and digits in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("@a. @b-, (@c)", &mention)?,
+        "<p><span class=\"mention\" data-mention=\"a\">@a</span>. <span class=\"mention\" data-mention=\"b\">@b</span>-, (<span class=\"mention\" data-mention=\"c\">@c</span>)</p>",
+        "should not support trailing punctuation in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("@ a @-a @.a \\@a `@a`", &mention)?,
+        "<p>@ a @-a @.a @a <code>@a</code></p>",
+        "should not support mentions w/o name, w/ leading punctuation, w/ an escaped `@`, or in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("a@b.com a+@b.com a.b-@c.d", &mention)?,
+        "<p><a href=\"mailto:a@b.com\">a@b.com</a> <a href=\"mailto:a+@b.com\">a+@b.com</a> <a href=\"mailto:a.b-@c.d\">a.b-@c.d</a></p>",
+        "should not interfere with email autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("*@a* [@b](c) ![@d](e)", &mention)?,
+        "<p><em><span class=\"mention\" data-mention=\"a\">@a</span></em> <a href=\"c\"><span class=\"mention\" data-mention=\"b\">@b</span></a> <img src=\"e\" alt=\"@d\" /></p>",
+        "should support mentions in other phrasing, and as text in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "@a",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        citation: true,
+                        mention: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><span class=\"citation\" data-cites=\"a\">@a</span></p>",
+        "should prefer citations"
+    );
+
+    let resolve = |name: &str| {
+        if name == "a" {
+            Some(("/u/a?b&c".into(), "<Ann>".into()))
+        } else if name == "b" {
+            Some(("javascript:alert(1)".into(), "Bo".into()))
+        } else {
+            None
+        }
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "@a @b @c",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        mention: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    mention_resolve: Some(MentionResolve(Rc::new(resolve))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><a href=\"/u/a?b&amp;c\" class=\"mention\">&lt;Ann&gt;</a> <a href=\"\" class=\"mention\">Bo</a> @c</p>",
+        "should support `mention_resolve`"
+    );
+
+    assert_eq!(
+        to_mdast("a @b", &mention.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Mention(Mention {
+                        position: Some(Position::new(1, 3, 2, 1, 5, 4)),
+                        name: "b".into()
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 5, 4))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 4))
+        }),
+        "should support mentions as `Mention`s in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use markdown::{
+    event::Event, message, to_events, to_html_with_options, Arena, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the events as strings, to compare them.
+fn show(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .map(|d| {
+            format!(
+                "{:?} {:?} {}:{}:{}:{} {:?}",
+                d.kind,
+                d.name,
+                d.point.line,
+                d.point.column,
+                d.point.index,
+                d.point.vs,
+                d.link.as_ref().map(|d| (d.previous, d.next))
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn arena() -> Result<(), message::Message> {
+    let values = [
+        "# a *b*\n\n> c [d](e)\n\n* f\n* g\n\n```h\ni\n```\n\n[j]: k",
+        "",
+        "l",
+        "| m |\n| - |\n| n |\n\n* [x] o ~p~ www.q.com[^r]\n\n[^r]: s",
+        "  \t t *u **v***\n\n    w",
+        "# a *b*\n\n> c [d](e)\n\n* f\n* g\n\n```h\ni\n```\n\n[j]: k",
+    ];
+    let mut arena = Arena::new();
+
+    assert_eq!(arena.events().len(), 0, "should start empty");
+
+    for options in [ParseOptions::default(), ParseOptions::gfm()] {
+        for value in values {
+            assert_eq!(
+                show(arena.parse_into(value, &options)?),
+                show(&to_events(value, &options)?),
+                "should parse `{:?}` like `to_events()`, after other documents",
+                value
+            );
+        }
+    }
+
+    let options = Options::gfm();
+
+    for value in values {
+        assert_eq!(
+            arena.to_html(value, &options)?,
+            to_html_with_options(value, &options)?,
+            "should compile `{:?}` like `to_html_with_options()`, after other documents",
+            value
+        );
+    }
+
+    assert_eq!(
+        show(arena.events()),
+        show(&to_events(values[values.len() - 1], &options.parse)?),
+        "should keep the events of the last document"
+    );
+
+    arena.reset();
+
+    assert_eq!(
+        arena.events().len(),
+        0,
+        "should forget the events of the last document when reset"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, LinkKind, LinkResolve, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn base(url: &str) -> Options {
+    Options {
+        compile: CompileOptions {
+            base_url: Some(url.into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn base_url() -> Result<(), message::Message> {
+    let blog = base("https://example.com/blog/post/?a=b#c");
+
+    assert_eq!(
+        to_html_with_options("[a](b) [c](./d) [e](../f) [g](/h)", &blog)?,
+        "<p><a href=\"https://example.com/blog/post/b\">a</a> <a href=\"https://example.com/blog/post/d\">c</a> <a href=\"https://example.com/blog/f\">e</a> <a href=\"https://example.com/h\">g</a></p>",
+        "should resolve relative paths"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#b) [c](?d) [e]()", &blog)?,
+        "<p><a href=\"https://example.com/blog/post/?a=b#b\">a</a> <a href=\"https://example.com/blog/post/?d\">c</a> <a href=\"https://example.com/blog/post/?a=b\">e</a></p>",
+        "should resolve fragments, queries, and empty destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](//b.com/c) [d](https://e.com/../f) [g](mailto:h@i.j)", &blog)?,
+        "<p><a href=\"https://b.com/c\">a</a> <a href=\"https://e.com/f\">d</a> <a href=\"mailto:h@i.j\">g</a></p>",
+        "should keep absolute URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b.png) [c]\n\n[c]: ../d", &blog)?,
+        "<p><img src=\"https://example.com/blog/post/b.png\" alt=\"a\" /> <a href=\"https://example.com/blog/d\">c</a></p>\n",
+        "should resolve images and references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1)) [b](<c d>)", &blog)?,
+        "<p><a href=\"\">a</a> <a href=\"https://example.com/blog/post/c%20d\">b</a></p>",
+        "should sanitize resolved URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b)", &base("https://example.com"))?,
+        "<p><a href=\"https://example.com/b\">a</a></p>",
+        "should resolve against a base without a path"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "www.a.com <https://b.com>",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: blog.compile.clone()
+            }
+        )?,
+        "<p><a href=\"http://www.a.com\">www.a.com</a> <a href=\"https://b.com\">https://b.com</a></p>",
+        "should not change autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](a.md)",
+            &Options {
+                compile: CompileOptions {
+                    link_resolve: Some(LinkResolve(Rc::new(|destination: &str, _: LinkKind| {
+                        destination
+                            .strip_suffix(".md")
+                            .map(|name| format!("{}/", name))
+                    }))),
+                    ..blog.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com/blog/post/a/\">a</a></p>",
+        "should resolve after `link_resolve`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use markdown::{
+    lint, mdast::Citation, message, to_html_with_options, Bibliography, CitationResolve,
+    CompileOptions, Constructs, LintOptions, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+const DATA: &str = r#"[
+  {
+    "id": "doe99",
+    "type": "book",
+    "title": "Markdown & More",
+    "author": [{"family": "Doe", "given": "Jane"}],
+    "issued": {"date-parts": [[1999, 2]]},
+    "publisher": "Press",
+    "publisher-place": "Paris"
+  },
+  {
+    "id": "roe01",
+    "type": "article-journal",
+    "title": "Parsing",
+    "author": [{"family": "Roe", "given": "John"}, {"family": "Poe", "given": "Ann"}],
+    "issued": {"date-parts": [["2001"]]},
+    "container-title": "Journal",
+    "volume": 3,
+    "issue": "2",
+    "page": "10–20",
+    "DOI": "10.1/x"
+  },
+  {
+    "id": "w3c",
+    "author": [{"literal": "W3C"}, {"family": "A"}, {"family": "B"}],
+    "URL": "https://example.com"
+  }
+]"#;
+
+fn options(bibliography: &Bibliography) -> Options {
+    Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                citation: true,
+                fenced_div: true,
+                attribute_list: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        compile: CompileOptions {
+            bibliography: Some(bibliography.clone()),
+            ..CompileOptions::default()
+        },
+    }
+}
+
+#[test]
+fn bibliography() -> Result<(), message::Message> {
+    let bibliography = Bibliography::from_csl_json(DATA)?;
+    let html = options(&bibliography);
+
+    assert_eq!(
+        to_html_with_options("a [see @doe99, p. 3; @roe01]", &html)?,
+        "<p>a <span class=\"citation\" data-cites=\"doe99 roe01\">(see <a href=\"#ref-doe99\" role=\"doc-biblioref\">Doe 1999</a>, p. 3; <a href=\"#ref-roe01\" role=\"doc-biblioref\">Roe and Poe 2001</a>)</span></p>\n<div id=\"refs\" class=\"references csl-bib-body\" role=\"list\">\n<div id=\"ref-doe99\" class=\"csl-entry\" role=\"listitem\">Doe, Jane. 1999. <em>Markdown &amp; More</em>. Paris: Press.</div>\n<div id=\"ref-roe01\" class=\"csl-entry\" role=\"listitem\">Roe, John, and Ann Poe. 2001. “Parsing.” <em>Journal</em> 3 (2): 10–20. <a href=\"https://doi.org/10.1/x\">https://doi.org/10.1/x</a>.</div>\n</div>\n",
+        "should format citations and add a reference list at the end"
+    );
+
+    assert_eq!(
+        to_html_with_options("@roe01 [p. 4] and [-@w3c] and [@nope]", &html)?,
+        "<p><span class=\"citation\" data-cites=\"roe01\"><a href=\"#ref-roe01\" role=\"doc-biblioref\">Roe and Poe (2001, p. 4)</a></span> and <span class=\"citation\" data-cites=\"w3c\">(<a href=\"#ref-w3c\" role=\"doc-biblioref\">n.d.</a>)</span> and <span class=\"citation\" data-cites=\"nope\">(<strong>nope?</strong>)</span></p>\n<div id=\"refs\" class=\"references csl-bib-body\" role=\"list\">\n<div id=\"ref-roe01\" class=\"csl-entry\" role=\"listitem\">Roe, John, and Ann Poe. 2001. “Parsing.” <em>Journal</em> 3 (2): 10–20. <a href=\"https://doi.org/10.1/x\">https://doi.org/10.1/x</a>.</div>\n<div id=\"ref-w3c\" class=\"csl-entry\" role=\"listitem\">W3C, A, and B. n.d. <a href=\"https://example.com\">https://example.com</a>.</div>\n</div>\n",
+        "should support in-text citations, suppressed authors, and unresolved keys"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [@w3c]\n\n::: {#refs}\n:::\n\nb", &html)?,
+        "<p>a <span class=\"citation\" data-cites=\"w3c\">(<a href=\"#ref-w3c\" role=\"doc-biblioref\">W3C et al. n.d.</a>)</span></p>\n<div id=\"refs\">\n<div id=\"ref-w3c\" class=\"csl-entry\" role=\"listitem\">W3C, A, and B. n.d. <a href=\"https://example.com\">https://example.com</a>.</div>\n</div>\n<p>b</p>",
+        "should add the reference list in a fenced div with the id `refs`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [@nope]", &html)?,
+        "<p>a <span class=\"citation\" data-cites=\"nope\">(<strong>nope?</strong>)</span></p>",
+        "should not add a reference list w/o cited references"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a [@doe99]",
+            &Options {
+                compile: CompileOptions {
+                    id_prefix: Some("b-".into()),
+                    ..html.compile.clone()
+                },
+                parse: options(&bibliography).parse
+            }
+        )?,
+        "<p>a <span class=\"citation\" data-cites=\"doe99\">(<a href=\"#b-ref-doe99\" role=\"doc-biblioref\">Doe 1999</a>)</span></p>\n<div id=\"b-refs\" class=\"references csl-bib-body\" role=\"list\">\n<div id=\"b-ref-doe99\" class=\"csl-entry\" role=\"listitem\">Doe, Jane. 1999. <em>Markdown &amp; More</em>. Paris: Press.</div>\n</div>\n",
+        "should support `id_prefix`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a [@doe99]",
+            &Options {
+                compile: CompileOptions {
+                    citation_resolve: Some(CitationResolve(Rc::new(|_: &Citation| {
+                        Some("c".into())
+                    }))),
+                    ..html.compile.clone()
+                },
+                parse: options(&bibliography).parse
+            }
+        )?,
+        "<p>a c</p>\n<div id=\"refs\" class=\"references csl-bib-body\" role=\"list\">\n<div id=\"ref-doe99\" class=\"csl-entry\" role=\"listitem\">Doe, Jane. 1999. <em>Markdown &amp; More</em>. Paris: Press.</div>\n</div>\n",
+        "should prefer `citation_resolve`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bibliography_csl_json() {
+    assert_eq!(
+        Bibliography::from_csl_json(
+            r#" [{"id": 1, "title": "a\"é😀\n", "x": [true, false, null, -1.5e3, {}]}] "#
+        )
+        .map(|d| d.references[0].title.clone()),
+        Ok(Some("a\"é😀\n".into())),
+        "should support JSON escapes and values"
+    );
+
+    assert_eq!(
+        Bibliography::from_csl_json(r#"[{"id": 1}]"#).map(|d| d.references[0].id.clone()),
+        Ok("1".into()),
+        "should support numeric ids"
+    );
+
+    assert_eq!(
+        Bibliography::from_csl_json("[{\"id\": \"a\",\n \"b\" 1}]")
+            .unwrap_err()
+            .to_string(),
+        "2:6: Expected `:` after key in object (markdown-rs:csl-json)",
+        "should crash on invalid JSON"
+    );
+
+    assert_eq!(
+        Bibliography::from_csl_json(r#"{"id": "a"}"#)
+            .unwrap_err()
+            .reason,
+        "Expected array of references",
+        "should crash on data that is not an array"
+    );
+
+    assert_eq!(
+        Bibliography::from_csl_json(r#"[{"title": "a"}]"#)
+            .unwrap_err()
+            .reason,
+        "Expected `id` (string or number) in reference",
+        "should crash on references w/o `id`"
+    );
+}
+
+#[test]
+fn bibliography_lint() -> Result<(), message::Message> {
+    let bibliography = Bibliography::from_csl_json(DATA)?;
+
+    assert_eq!(
+        lint(
+            "a [@doe99; @nope] @nada\n",
+            &LintOptions {
+                parse: options(&bibliography).parse,
+                bibliography: Some(bibliography.clone()),
+                ..LintOptions::default()
+            }
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>(),
+        vec![
+            "1:13-1:17: Unexpected citation of `nope`, which is not in the bibliography (markdown-rs:citation-keys)",
+            "1:20-1:24: Unexpected citation of `nada`, which is not in the bibliography (markdown-rs:citation-keys)"
+        ],
+        "should report citations of keys that are not in the bibliography"
+    );
+
+    assert_eq!(
+        lint(
+            "a [@nope]\n",
+            &LintOptions {
+                parse: options(&bibliography).parse,
+                ..LintOptions::default()
+            }
+        ),
+        vec![],
+        "should not check citations w/o bibliography"
+    );
+
+    Ok(())
+}
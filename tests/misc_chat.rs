@@ -0,0 +1,93 @@
+use markdown::{message, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn chat() -> Result<(), message::Message> {
+    let chat = Options::chat();
+
+    assert_eq!(
+        to_html_with_options("**a** *b* __c__ ||d|| ~~e~~", &chat)?,
+        "<p><strong>a</strong> <em>b</em> <u>c</u> <span class=\"spoiler\">d</span> <del>e</del></p>",
+        "should support chat phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a~", &chat)?,
+        "<p>~a~</p>",
+        "should not support strikethrough w/ one tilde"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\nb\n===", &chat)?,
+        "<p># a</p>\n<p>b<br />\n===</p>",
+        "should not support headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("<b>a</b>\n\n<div>", &chat)?,
+        "<p>&lt;b&gt;a&lt;/b&gt;</p>\n<p>&lt;div&gt;</p>",
+        "should not support HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n\n```js\nb\n```\n\n* c", &chat)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<pre><code class=\"language-js\">b\n</code></pre>\n<ul>\n<li>c</li>\n</ul>",
+        "should support other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\r\nc", &chat)?,
+        "<p>a<br />\nb<br />\r\nc</p>",
+        "should turn soft line breaks into hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb  \nc", &chat)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should not add breaks after hard breaks"
+    );
+
+    let hard_breaks = Options {
+        compile: CompileOptions {
+            hard_breaks: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\nb\n===\n\nc\n\nd", &hard_breaks)?,
+        "<h1>a<br />\nb</h1>\n<p>c</p>\n<p>d</p>",
+        "should support hard breaks in headings (setext), not between blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb` ![c\nd](e)", &hard_breaks)?,
+        "<p><code>a b</code> <img src=\"e\" alt=\"c\nd\" /></p>",
+        "should not add breaks in code (text) or image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\nb\n```\n\n> c\n> d", &hard_breaks)?,
+        "<pre><code>a\nb\n</code></pre>\n<blockquote>\n<p>c<br />\nd</p>\n</blockquote>",
+        "should not add breaks in code (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <b\nc> d",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    hard_breaks: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <b\nc> d</p>",
+        "should not add breaks in HTML (text)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use markdown::{
+    message, to_html_with_options, CodeBlockHandler, CompileOptions, Element, ElementRender,
+    Options,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn code_block_handler() -> Result<(), message::Message> {
+    let debug = Options {
+        compile: CompileOptions {
+            code_block_handler: Some(CodeBlockHandler(Rc::new(
+                |lang: Option<&str>, meta: Option<&str>, code: &str| {
+                    format!("[{:?} {:?} {:?}]", lang, meta, code)
+                },
+            ))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```js\na\n```", &debug)?,
+        "[Some(\"js\") None \"a\\n\"]",
+        "should handle code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js &amp; <b>\n<c> & d\n\ne\n```", &debug)?,
+        "[Some(\"js\") Some(\"& <b>\") \"<c> & d\\n\\ne\\n\"]",
+        "should pass text, not HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n```", &debug)?,
+        "[None None \"\"]",
+        "should handle empty code"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n\n    b", &debug)?,
+        "[None None \"a\\n\\nb\\n\"]",
+        "should handle code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n```js\nb\n```\n\nc", &debug)?,
+        "<p>a</p>\n[Some(\"js\") None \"b\\n\"]\n<p>c</p>",
+        "should handle code between other blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ```js\n  a\n  ```", &debug)?,
+        "<ul>\n<li>\n[Some(\"js\") None \"a\\n\"]\n</li>\n</ul>",
+        "should handle code in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\na\n$$",
+            &Options {
+                parse: markdown::ParseOptions {
+                    constructs: markdown::Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: debug.compile.clone(),
+            }
+        )?,
+        "<pre><code class=\"language-math math-display\">a\n</code></pre>",
+        "should not handle math (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```a\nb\n```\n\n```c\nd\n```",
+            &Options {
+                compile: CompileOptions {
+                    element_render: Some(ElementRender(Rc::new(|element: &Element| {
+                        if let Element::Code {
+                            lang: Some("a"), ..
+                        } = element
+                        {
+                            Some("<x-a></x-a>".into())
+                        } else {
+                            None
+                        }
+                    }))),
+                    ..debug.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<x-a></x-a>\n[Some(\"c\") None \"d\\n\"]",
+        "should prefer `element_render`, and use the handler if it returns `None`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use markdown::{
+    mdast::{Emphasis, Node, Paragraph, Root, Text},
+    message, to_mdast,
+    unist::Position,
+    ColumnUnit, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn column_unit() -> Result<(), message::Message> {
+    let utf16 = ParseOptions {
+        column_unit: ColumnUnit::Utf16,
+        ..ParseOptions::default()
+    };
+    let char = ParseOptions {
+        column_unit: ColumnUnit::Char,
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        to_mdast("😀 *a*", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "😀 ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                    }),
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                        }),],
+                        position: Some(Position::new(1, 6, 5, 1, 9, 8))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+        }),
+        "should count columns in bytes by default"
+    );
+
+    assert_eq!(
+        to_mdast("😀 *a*", &utf16)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "😀 ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 4, 5))
+                    }),
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 5, 6, 1, 6, 7))
+                        }),],
+                        position: Some(Position::new(1, 4, 5, 1, 7, 8))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 7, 8))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 7, 8))
+        }),
+        "should support counting columns in UTF-16 code units"
+    );
+
+    assert_eq!(
+        to_mdast("😀 *a*", &char)?.children().unwrap()[0].position(),
+        Some(&Position::new(1, 1, 0, 1, 6, 8)),
+        "should support counting columns in characters"
+    );
+
+    assert_eq!(
+        to_mdast("a\r\né\n\t*b*", &utf16)?.children().unwrap()[0]
+            .children()
+            .unwrap()[1]
+            .position(),
+        Some(&Position::new(3, 2, 7, 3, 5, 10)),
+        "should count columns from the start of the line, with tabs as one"
+    );
+
+    Ok(())
+}
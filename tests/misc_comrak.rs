@@ -0,0 +1,141 @@
+use markdown::{message, to_html_with_options, ComrakOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn comrak() -> Result<(), message::Message> {
+    let (options, unsupported) = ComrakOptions::default().to_options();
+
+    assert_eq!(
+        to_html_with_options("~~a~~ <b>c</b>\n===", &options)?,
+        "<h1>~~a~~ c</h1>",
+        "should map default options to `CommonMark`, without HTML"
+    );
+    assert!(unsupported.is_empty(), "should support default options");
+
+    let mut comrak = ComrakOptions::default();
+    comrak.extension.strikethrough = true;
+    comrak.extension.table = true;
+    comrak.extension.autolink = true;
+    comrak.extension.tasklist = true;
+    comrak.extension.tagfilter = true;
+    comrak.render.unsafe_ = true;
+    let (options, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        to_html_with_options(
+            "~~a~~ www.b.com <xmp>\n\n* [x] c\n\n| d |\n| - |",
+            &options
+        )?,
+        "<p><del>a</del> <a href=\"http://www.b.com\">www.b.com</a> &lt;xmp></p>\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> c</li>\n</ul>\n<table>\n<thead>\n<tr>\n<th>d</th>\n</tr>\n</thead>\n</table>",
+        "should map GFM extensions"
+    );
+    assert!(unsupported.is_empty(), "should support GFM extensions");
+
+    let mut comrak = ComrakOptions::default();
+    comrak.extension.front_matter_delimiter = Some("---".into());
+    comrak.extension.multiline_block_quotes = true;
+    comrak.extension.math_dollars = true;
+    comrak.extension.math_code = true;
+    comrak.extension.underline = true;
+    comrak.extension.spoiler = true;
+    comrak.extension.superscript = true;
+    comrak.extension.wikilinks_title_after_pipe = true;
+    comrak.render.hardbreaks = true;
+    comrak.render.ignore_setext = true;
+    let (options, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        to_html_with_options(
+            ">>>\n__c__ ||d||\n$e$ $`f`$\n>>>\n\n```math\ng\n```\n\nh\n===",
+            &options
+        )?,
+        "<blockquote>\n<p><u>c</u> <span class=\"spoiler\">d</span><br />\n<code class=\"language-math math-inline\">e</code> <code class=\"language-math math-inline\">f</code></p>\n</blockquote>\n<pre><code class=\"language-math math-display\">g\n</code></pre>\n<p>h<br />\n===</p>",
+        "should map other extensions and render options"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na: b\n---\nc", &options)?,
+        "<p>c</p>",
+        "should map frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|b]]", &options)?,
+        "<p><a href=\"a\" class=\"wikilink\">b</a></p>",
+        "should map wiki links"
+    );
+
+    assert_eq!(
+        to_html_with_options("2^10^", &options)?,
+        "<p>2<sup>10</sup></p>",
+        "should map superscript"
+    );
+    assert!(unsupported.is_empty(), "should support other extensions");
+
+    let mut comrak = ComrakOptions::default();
+    comrak.render.sourcepos = true;
+    let (options, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        to_html_with_options("a", &options)?,
+        "<p data-sourcepos=\"1:1-1:1\">a</p>",
+        "should map `render.sourcepos`"
+    );
+    assert!(unsupported.is_empty(), "should support `render.sourcepos`");
+
+    let mut comrak = ComrakOptions::default();
+    comrak.render.escape = true;
+    let (options, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        to_html_with_options("<div>\na\n</div>\n\nb <i>c</i>", &options)?,
+        "&lt;div&gt;\na\n&lt;/div&gt;\n<p>b &lt;i&gt;c&lt;/i&gt;</p>",
+        "should map `render.escape` to encoding HTML"
+    );
+    assert!(unsupported.is_empty(), "should support `render.escape`");
+
+    let (options, _) = ComrakOptions::default().to_options();
+
+    assert_eq!(
+        to_html_with_options("<div>\na\n</div>\n\nb <i>c</i>", &options)?,
+        "<p>b c</p>",
+        "should map `render.unsafe_: false` to dropping HTML"
+    );
+
+    let mut comrak = ComrakOptions::default();
+    comrak.extension.header_ids = Some("user-content-".into());
+    let (options, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        to_html_with_options("# a b", &options)?,
+        "<h1 id=\"user-content-a-b\">a b</h1>",
+        "should map `extension.header_ids`"
+    );
+    assert!(
+        unsupported.is_empty(),
+        "should support `extension.header_ids`"
+    );
+
+    let mut comrak = ComrakOptions::default();
+    comrak.extension.description_lists = true;
+    comrak.extension.front_matter_delimiter = Some("+++".into());
+    comrak.parse.smart = true;
+    comrak.render.escaped_char_spans = true;
+    let (_, unsupported) = comrak.to_options();
+
+    assert_eq!(
+        unsupported
+            .iter()
+            .map(|d| d.reason.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "Unexpected unsupported comrak option `extension.front_matter_delimiter`",
+            "Unexpected unsupported comrak option `extension.description_lists`",
+            "Unexpected unsupported comrak option `parse.smart`",
+            "Unexpected unsupported comrak option `render.escaped_char_spans`"
+        ],
+        "should report unsupported options"
+    );
+
+    Ok(())
+}
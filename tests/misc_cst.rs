@@ -0,0 +1,217 @@
+use markdown::{cst::offset, event::Name, message, to_cst, Constructs, ParseOptions};
+use pretty_assertions::assert_eq;
+
+fn leaves(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    Ok(to_cst(value, options)?
+        .leaves()
+        .iter()
+        .map(|d| d.text(value))
+        .collect())
+}
+
+#[test]
+fn cst() -> Result<(), message::Message> {
+    let gfm = ParseOptions::gfm();
+    let frontmatter = ParseOptions {
+        constructs: Constructs {
+            frontmatter: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    for (value, options) in [
+        ("", &ParseOptions::default()),
+        ("a", &ParseOptions::default()),
+        ("\n\n  \n", &ParseOptions::default()),
+        ("# a #  \n\nb\n===", &ParseOptions::default()),
+        ("> a\n>\n> - b\n>   c\n\n1. d", &ParseOptions::default()),
+        ("\t```js\n\tb\n\t```\n    c\n\td", &ParseOptions::default()),
+        (
+            "\u{feff}*a* __b__ `c` [d](e \"f\") ![g][h]\n\n[h]: i",
+            &ParseOptions::default(),
+        ),
+        (
+            "<div>\na\n</div>\n\n<b>c</b> &amp; \\*",
+            &ParseOptions::default(),
+        ),
+        ("a  \nb\\\nc\r\nd\re", &ParseOptions::default()),
+        ("| a | b |\n| - | :-: |\n| c |", &gfm),
+        ("* [x] a ~b~ www.c.com[^d]\n\n[^d]: e", &gfm),
+        ("---\na: b\n---\nc", &frontmatter),
+    ] {
+        assert_eq!(
+            leaves(value, options)?,
+            value,
+            "should be lossless for `{:?}`",
+            value
+        );
+    }
+
+    let value = "> *a*\n";
+    let tree = to_cst(value, &ParseOptions::default())?;
+
+    assert_eq!(
+        (tree.name.clone(), tree.start, tree.end),
+        (None, 0, value.len()),
+        "should span the whole input with the root"
+    );
+
+    let block_quote = &tree.children[0];
+
+    assert_eq!(
+        (block_quote.name.clone(), block_quote.text(value)),
+        (Some(Name::BlockQuote), "> *a*"),
+        "should support the text of nodes"
+    );
+
+    assert_eq!(
+        block_quote.children[0].children[0].text(value),
+        ">",
+        "should include markers"
+    );
+
+    assert_eq!(
+        tree.children[1].text(value),
+        "\n",
+        "should include line endings"
+    );
+
+    let emphasis = &block_quote.children[1].children[0];
+
+    assert_eq!(
+        emphasis
+            .children
+            .iter()
+            .map(|d| (d.name.clone(), d.text(value)))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(Name::EmphasisSequence), "*"),
+            (Some(Name::EmphasisText), "a"),
+            (Some(Name::EmphasisSequence), "*"),
+        ],
+        "should include children in order"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cst_path() -> Result<(), message::Message> {
+    let value = "> a `b` [c](d)\n\n* e";
+    let tree = to_cst(value, &ParseOptions::default())?;
+    let names = |offset: usize| {
+        tree.path(offset)
+            .iter()
+            .map(|d| d.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(5),
+        vec![
+            None,
+            Some(Name::BlockQuote),
+            Some(Name::Paragraph),
+            Some(Name::CodeText),
+            Some(Name::CodeTextData),
+        ],
+        "should get the path to a node"
+    );
+
+    assert_eq!(
+        names(0),
+        vec![
+            None,
+            Some(Name::BlockQuote),
+            Some(Name::BlockQuotePrefix),
+            Some(Name::BlockQuoteMarker)
+        ],
+        "should get the node that starts at an offset"
+    );
+
+    assert_eq!(
+        tree.path(12).last().map(|d| d.text(value)),
+        Some("d"),
+        "should get innermost nodes"
+    );
+
+    assert!(
+        tree.path(12)
+            .iter()
+            .any(|d| d.name == Some(Name::ResourceDestination)),
+        "should get ancestors"
+    );
+
+    assert_eq!(
+        names(15),
+        vec![None, Some(Name::BlankLineEnding)],
+        "should get nodes between blocks"
+    );
+
+    assert_eq!(
+        tree.path(value.len()),
+        Vec::<&markdown::cst::Node>::new(),
+        "should get nothing at the end"
+    );
+
+    assert_eq!(
+        tree.children[0].path(16),
+        Vec::<&markdown::cst::Node>::new(),
+        "should get nothing outside a node"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cst_offset() {
+    assert_eq!(offset("", 1, 1), Some(0), "should support empty values");
+    assert_eq!(offset("ab", 1, 2), Some(1), "should support columns");
+    assert_eq!(
+        offset("ab", 1, 3),
+        Some(2),
+        "should support the end of a line"
+    );
+    assert_eq!(
+        offset("ab", 1, 4),
+        None,
+        "should not support columns after a line"
+    );
+    assert_eq!(
+        offset("a\nb\r\nc\rd", 4, 1),
+        Some(7),
+        "should support all line endings"
+    );
+    assert_eq!(
+        offset("a\nb", 1, 3),
+        None,
+        "should not support columns in line endings"
+    );
+    assert_eq!(
+        offset("a\nb", 3, 1),
+        None,
+        "should not support lines after the end"
+    );
+    assert_eq!(
+        offset("a\tb", 1, 4),
+        Some(1),
+        "should support columns in tabs"
+    );
+    assert_eq!(
+        offset("a\tb", 1, 5),
+        Some(2),
+        "should support columns after tabs"
+    );
+
+    let value = "# a\n\n\t*b*";
+    let tree = to_cst(value, &ParseOptions::default()).unwrap();
+
+    assert_eq!(
+        tree.path(offset(value, 3, 5).unwrap())
+            .last()
+            .map(|d| d.name.clone()),
+        Some(Some(Name::CodeFlowChunk)),
+        "should support finding nodes by line and column"
+    );
+}
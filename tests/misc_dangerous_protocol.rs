@@ -1,4 +1,4 @@
-use markdown::to_html;
+use markdown::{message, to_html, to_html_with_options, CompileOptions, Options};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -195,3 +195,78 @@ fn dangerous_protocol_link() {
         "should allow a colon in a path"
     );
 }
+
+#[test]
+fn dangerous_protocol_allowlist() -> Result<(), message::Message> {
+    let protocols = Options {
+        compile: CompileOptions {
+            image_protocols: Some(vec!["data".into()]),
+            link_protocols: Some(vec!["tel".into()]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](tel:1) [b](https://c)", &protocols)?,
+        "<p><a href=\"tel:1\">a</a> <a href=\"\">b</a></p>",
+        "should support `link_protocols`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<tel:1> <https://c>", &protocols)?,
+        "<p><a href=\"tel:1\">tel:1</a> <a href=\"\">https://c</a></p>",
+        "should support `link_protocols` (autolink)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](data:b) ![c](https://d)", &protocols)?,
+        "<p><img src=\"data:b\" alt=\"a\" /> <img src=\"\" alt=\"c\" /></p>",
+        "should support `image_protocols`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](data:b) ![c](tel:1)", &protocols)?,
+        "<p><a href=\"\">a</a> <img src=\"\" alt=\"c\" /></p>",
+        "should not mix `link_protocols` and `image_protocols`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](TEL:1) [b](./c) ![d](#e)", &protocols)?,
+        "<p><a href=\"TEL:1\">a</a> <a href=\"./c\">b</a> <img src=\"#e\" alt=\"d\" /></p>",
+        "should match protocols case-insensitively, and allow relative URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b) ![c](https://d)",
+            &Options {
+                compile: CompileOptions {
+                    image_protocols: Some(vec![]),
+                    link_protocols: Some(vec![]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"\">a</a> <img src=\"\" alt=\"c\" /></p>",
+        "should support empty lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](javascript:b) ![c](javascript:d)",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    ..protocols.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"javascript:b\">a</a> <img src=\"javascript:d\" alt=\"c\" /></p>",
+        "should prefer `allow_dangerous_protocol`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use markdown::{
+    message, to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn element_classes() -> Result<(), message::Message> {
+    let classes = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        compile: CompileOptions {
+            element_classes: vec![
+                ("table".into(), "table table-striped".into()),
+                ("a".into(), "link".into()),
+                ("code".into(), "code".into()),
+                ("h2".into(), "title".into()),
+            ],
+            ..CompileOptions::default()
+        },
+    };
+
+    assert_eq!(
+        to_html("| a |\n| - |"),
+        "<p>| a |\n| - |</p>",
+        "should not add classes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |", &classes)?,
+        "<table class=\"table table-striped\">\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>",
+        "should add classes to elements"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a\n\n### b", &classes)?,
+        "<h2 class=\"title\">a</h2>\n<h3>b</h3>",
+        "should add classes by tag name"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b) <c@d.e> www.f.com", &classes)?,
+        "<p><a href=\"b\" class=\"link\">a</a> <a href=\"mailto:c@d.e\" class=\"link\">c@d.e</a> <a href=\"http://www.f.com\" class=\"link\">www.f.com</a></p>",
+        "should add classes to links and autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\na\n```", &classes)?,
+        "<pre><code class=\"language-js code\">a\n</code></pre>",
+        "should join classes with the language class"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b){: .c}", &classes)?,
+        "<p><a href=\"b\" class=\"link c\">a</a></p>",
+        "should join classes with classes from attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c", &classes)?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" class=\"link\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-b\">\n<p>c <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref link\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should join classes with the classes of footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a",
+            &Options {
+                compile: CompileOptions {
+                    element_classes: vec![("p".into(), "b\"c".into())],
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p class=\"b&quot;c\">a</p>",
+        "should encode classes"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn class_prefix() -> Result<(), message::Message> {
+    let prefix = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                math_flow: true,
+                math_text: true,
+                spoiler: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        compile: CompileOptions {
+            class_prefix: Some("md-".into()),
+            element_classes: vec![("code".into(), "code".into())],
+            ..CompileOptions::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("```js\na\n```", &prefix)?,
+        "<pre><code class=\"md-language-js code\">a\n</code></pre>",
+        "should prefix language classes, but not element classes"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$\na\n$$\n\n$b$", &prefix)?,
+        "<pre><code class=\"md-language-math md-math-display code\">a\n</code></pre>\n<p><code class=\"md-language-math md-math-inline code\">b</code></p>",
+        "should prefix each math class"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!a!<{: .b}", &prefix)?,
+        "<p><span class=\"md-spoiler b\">a</span></p>",
+        "should prefix the spoiler class, but not classes from attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c", &prefix)?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"md-footnotes\"><h2 id=\"footnote-label\" class=\"md-sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-b\">\n<p>c <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"md-data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should prefix footnote classes"
+    );
+
+    Ok(())
+}
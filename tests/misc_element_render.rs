@@ -0,0 +1,160 @@
+use markdown::{
+    message, to_html, to_html_with_options, CompileOptions, Element, ElementRender, Options,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn render(element_render: ElementRender) -> Options {
+    Options {
+        compile: CompileOptions {
+            element_render: Some(element_render),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn element_render() -> Result<(), message::Message> {
+    let debug = render(ElementRender(Rc::new(|element: &Element| {
+        Some(format!("{:?}", element))
+    })));
+    let nothing = render(ElementRender(Rc::new(|_: &Element| None)));
+    let links = render(ElementRender(Rc::new(|element: &Element| {
+        if let Element::Link {
+            destination,
+            children,
+            ..
+        } = element
+        {
+            Some(format!(
+                "<a href=\"{}\" rel=\"nofollow\">{}</a>",
+                destination.unwrap_or(""),
+                children
+            ))
+        } else {
+            None
+        }
+    })));
+
+    assert_eq!(
+        to_html("# a\n\n[b](c)\n\n```d\ne\n```"),
+        "<h1>a</h1>\n<p><a href=\"c\">b</a></p>\n<pre><code class=\"language-d\">e\n</code></pre>",
+        "should not render elements by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a *b*\n\nc\n===\n\n[d](e \"f\") ![g *h*](i)\n\n<j> www.k.com\n\n```l m\n<n>\n```\n\n    o",
+            &nothing
+        )?,
+        to_html(
+            "# a *b*\n\nc\n===\n\n[d](e \"f\") ![g *h*](i)\n\n<j> www.k.com\n\n```l m\n<n>\n```\n\n    o"
+        ),
+        "should compile like normal if `element_render` returns `None`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a *b*", &debug)?,
+        "Heading { rank: 1, id: None, children: \"a <em>b</em>\" }",
+        "should render headings (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n-", &debug)?,
+        "Heading { rank: 2, id: None, children: \"a\" }",
+        "should render headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a & b",
+            &Options {
+                compile: CompileOptions {
+                    heading_ids: true,
+                    ..debug.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "Heading { rank: 1, id: Some(\"a--b\"), children: \"a &amp; b\" }",
+        "should pass heading ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a *b*](c&d \"e\")", &debug)?,
+        "<p>Link { destination: Some(\"c&amp;d\"), title: Some(\"e\"), children: \"a <em>b</em>\" }</p>",
+        "should render links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: b", &debug)?,
+        "<p>Link { destination: Some(\"b\"), title: None, children: \"a\" }</p>\n",
+        "should render links (reference)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:b)", &debug)?,
+        "<p>Link { destination: Some(\"\"), title: None, children: \"a\" }</p>",
+        "should pass sanitized destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b>", &debug)?,
+        "<p>Link { destination: Some(\"https://a.b\"), title: None, children: \"https://a.b\" }</p>",
+        "should render links (autolink)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a *b*](c \"d\")", &debug)?,
+        "<p>Image { destination: Some(\"c\"), title: Some(\"d\"), alt: \"a b\" }</p>",
+        "should render images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &links)?,
+        "<p><a href=\"c\" rel=\"nofollow\"><img src=\"b\" alt=\"a\" /></a></p>",
+        "should render elements in elements"
+    );
+
+    assert_eq!(
+        to_html_with_options("![[a](b)](c)", &debug)?,
+        "<p>Image { destination: Some(\"c\"), title: None, alt: \"a\" }</p>",
+        "should not render links in images"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js &amp; b\n<c>\n\nd\n```", &debug)?,
+        "Code { lang: Some(\"js\"), meta: Some(\"& b\"), value: \"<c>\\n\\nd\\n\" }",
+        "should render code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a & b", &debug)?,
+        "Code { lang: None, meta: None, value: \"a & b\\n\" }",
+        "should render code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ```a\n> b", &debug)?,
+        "<blockquote>\nCode { lang: Some(\"a\"), meta: None, value: \"b\\n\" }\n</blockquote>",
+        "should render code in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```a\"b\nc\n```",
+            &render(ElementRender(Rc::new(|element: &Element| {
+                if let Element::Heading { .. } = element {
+                    Some(String::new())
+                } else {
+                    None
+                }
+            })))
+        )?,
+        "<pre><code class=\"language-a&quot;b\">c\n</code></pre>",
+        "should encode the class of code if it is not rendered"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use markdown::{message, to_epub, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn epub() -> Result<(), message::Message> {
+    let head = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\">\n<head>\n";
+    let chapters = Options {
+        compile: CompileOptions {
+            epub_chapter_rank: 1,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_epub("", &Options::default())?,
+        vec![format!(
+            "{}<title></title>\n</head>\n<body>\n</body>\n</html>\n",
+            head
+        )],
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_epub("## a\n\n# b", &Options::default())?,
+        vec![format!(
+            "{}<title>a</title>\n</head>\n<body>\n<h2>a</h2>\n<h1>b</h1>\n</body>\n</html>\n",
+            head
+        )],
+        "should not split by default, using the first heading as the title"
+    );
+
+    assert_eq!(
+        to_epub("# a\n\nb\n\n## c\n\nd\n===\n\n# e", &chapters)?,
+        vec![
+            format!(
+                "{}<title>a</title>\n</head>\n<body>\n<h1>a</h1>\n<p>b</p>\n<h2>c</h2>\n</body>\n</html>\n",
+                head
+            ),
+            format!(
+                "{}<title>d</title>\n</head>\n<body>\n<h1>d</h1>\n</body>\n</html>\n",
+                head
+            ),
+            format!(
+                "{}<title>e</title>\n</head>\n<body>\n<h1>e</h1>\n</body>\n</html>\n",
+                head
+            )
+        ],
+        "should split at headings of `epub_chapter_rank`"
+    );
+
+    assert_eq!(
+        to_epub("a\n\n> # b\n\n# *c* & d", &chapters)?,
+        vec![
+            format!(
+                "{}<title></title>\n</head>\n<body>\n<p>a</p>\n<blockquote>\n<h1>b</h1>\n</blockquote>\n</body>\n</html>\n",
+                head
+            ),
+            format!(
+                "{}<title>c &amp; d</title>\n</head>\n<body>\n<h1><em>c</em> &amp; d</h1>\n</body>\n</html>\n",
+                head
+            )
+        ],
+        "should not split at headings in containers, and support content before the first heading"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[^a]\n\n[^a]: b",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    epub: true,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\" epub:type=\"noteref\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\" epub:type=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\" epub:type=\"footnote\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\" epub:type=\"backlink\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should support `epub` in `to_html_with_options`"
+    );
+
+    Ok(())
+}
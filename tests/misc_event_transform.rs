@@ -0,0 +1,131 @@
+use markdown::{
+    event::{Event, Kind, Name},
+    message, to_html, to_html_inline, to_html_with_options, toc, CompileOptions, EventTransform,
+    Options,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn event_transform() -> Result<(), message::Message> {
+    // Drop images, and everything in them.
+    let drop_images = Options {
+        compile: CompileOptions {
+            event_transform: Some(EventTransform(Rc::new(
+                |events: &mut Vec<Event>, _: &[u8]| {
+                    let mut depth = 0;
+                    events.retain(|event| {
+                        if event.name == Name::Image {
+                            if event.kind == Kind::Enter {
+                                depth += 1;
+                            } else {
+                                depth -= 1;
+                                return false;
+                            }
+                        }
+
+                        depth == 0
+                    });
+                },
+            ))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // Drop headings, but keep their content as a paragraph.
+    let demote_headings = Options {
+        compile: CompileOptions {
+            event_transform: Some(EventTransform(Rc::new(
+                |events: &mut Vec<Event>, _: &[u8]| {
+                    events.retain(|event| {
+                        !matches!(event.name, Name::HeadingAtxSequence | Name::SpaceOrTab)
+                    });
+
+                    for event in events.iter_mut() {
+                        if matches!(event.name, Name::HeadingAtx | Name::HeadingAtxText) {
+                            event.name = Name::Paragraph;
+                        }
+                    }
+
+                    events.dedup_by(|a, b| {
+                        a.name == Name::Paragraph && b.name == Name::Paragraph && a.kind == b.kind
+                    });
+                },
+            ))),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ![b](c)"),
+        "<p>a <img src=\"c\" alt=\"b\" /></p>",
+        "should not transform events by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![b *c*](d) e", &drop_images)?,
+        "<p>a  e</p>",
+        "should support dropping events"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a *b*\n\nc", &demote_headings)?,
+        "<p>a <em>b</em></p>\n<p>c</p>",
+        "should support retyping events"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a *secret* b",
+            &Options {
+                compile: CompileOptions {
+                    event_transform: Some(EventTransform(Rc::new(
+                        |events: &mut Vec<Event>, bytes: &[u8]| {
+                            let mut index = 0;
+
+                            while index + 1 < events.len() {
+                                let enter = &events[index];
+                                let exit = &events[index + 1];
+
+                                if enter.kind == Kind::Enter
+                                    && enter.name == Name::Data
+                                    && &bytes[enter.point.index..exit.point.index] == b"secret"
+                                {
+                                    let mut strong_enter = enter.clone();
+                                    let mut strong_exit = exit.clone();
+                                    strong_enter.name = Name::Strong;
+                                    strong_exit.name = Name::Strong;
+                                    events.insert(index + 2, strong_exit);
+                                    events.insert(index, strong_enter);
+                                    index += 3;
+                                }
+
+                                index += 1;
+                            }
+                        },
+                    ))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <em><strong>secret</strong></em> b</p>",
+        "should support injecting events, w/ access to the bytes of the document"
+    );
+
+    assert_eq!(
+        to_html_inline("a ![b](c)", &drop_images)?,
+        "a ",
+        "should transform events when compiling inline markdown"
+    );
+
+    assert_eq!(
+        toc("# a\n\n## b", &demote_headings)?,
+        vec![],
+        "should transform events before making a table of contents"
+    );
+
+    Ok(())
+}
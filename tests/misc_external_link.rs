@@ -0,0 +1,179 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, ExternalLinkCheck, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn external_link_rel() -> Result<(), message::Message> {
+    let rel = Options {
+        compile: CompileOptions {
+            external_link_rel: vec!["nofollow".into(), "noopener".into(), "noreferrer".into()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let origin = Options {
+        compile: CompileOptions {
+            origin: Some("https://Example.com/blog/".into()),
+            ..rel.compile.clone()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://a.com)", &Options::default())?,
+        "<p><a href=\"https://a.com\">a</a></p>",
+        "should not add `rel` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://a.com) [b](//b.com/c)", &rel)?,
+        "<p><a href=\"https://a.com\" rel=\"nofollow noopener noreferrer\">a</a> <a href=\"//b.com/c\" rel=\"nofollow noopener noreferrer\">b</a></p>",
+        "should add `rel` to links with a host"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/a) [b](b) [c](#c) [d](mailto:d@e.f) [g](javascript:g)", &rel)?,
+        "<p><a href=\"/a\">a</a> <a href=\"b\">b</a> <a href=\"#c\">c</a> <a href=\"mailto:d@e.f\">d</a> <a href=\"\">g</a></p>",
+        "should not add `rel` to links without a host"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: https://a.com", &rel)?,
+        "<p><a href=\"https://a.com\" rel=\"nofollow noopener noreferrer\">a</a></p>\n",
+        "should add `rel` to links (reference)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<https://a.com> www.b.com <c@d.e>",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: rel.compile.clone()
+            }
+        )?,
+        "<p><a href=\"https://a.com\" rel=\"nofollow noopener noreferrer\">https://a.com</a> <a href=\"http://www.b.com\" rel=\"nofollow noopener noreferrer\">www.b.com</a> <a href=\"mailto:c@d.e\">c@d.e</a></p>",
+        "should add `rel` to autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://a.com/b.png)", &rel)?,
+        "<p><img src=\"https://a.com/b.png\" alt=\"a\" /></p>",
+        "should not add `rel` to images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://example.com/a) [b](http://EXAMPLE.com) [c](https://a@example.com) [d](https://example.com:8080) [e](https://www.example.com)",
+            &origin
+        )?,
+        "<p><a href=\"https://example.com/a\">a</a> <a href=\"http://EXAMPLE.com\">b</a> <a href=\"https://a@example.com\">c</a> <a href=\"https://example.com:8080\" rel=\"nofollow noopener noreferrer\">d</a> <a href=\"https://www.example.com\" rel=\"nofollow noopener noreferrer\">e</a></p>",
+        "should not add `rel` to links to `origin`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b)",
+            &Options {
+                compile: CompileOptions {
+                    base_url: Some("https://a.com".into()),
+                    ..rel.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://a.com/b\" rel=\"nofollow noopener noreferrer\">a</a></p>",
+        "should decide after resolving against `base_url`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn external_link_target_blank() -> Result<(), message::Message> {
+    let blank = Options {
+        compile: CompileOptions {
+            external_link_target_blank: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://a.com)", &Options::default())?,
+        "<p><a href=\"https://a.com\">a</a></p>",
+        "should not add `target` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://a.com) [b](/b) [c](#c)", &blank)?,
+        "<p><a href=\"https://a.com\" target=\"_blank\">a</a> <a href=\"/b\">b</a> <a href=\"#c\">c</a></p>",
+        "should add `target` to external links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<https://a.com> www.b.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: blank.compile.clone()
+            }
+        )?,
+        "<p><a href=\"https://a.com\" target=\"_blank\">https://a.com</a> <a href=\"http://www.b.com\" target=\"_blank\">www.b.com</a></p>",
+        "should add `target` to autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://a.com) [b](https://b.com)",
+            &Options {
+                compile: CompileOptions {
+                    external_link_rel: vec!["noopener".into()],
+                    origin: Some("https://a.com".into()),
+                    ..blank.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://a.com\">a</a> <a href=\"https://b.com\" rel=\"noopener\" target=\"_blank\">b</a></p>",
+        "should share the decision with `rel`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://a.com) [b](/b.pdf)",
+            &Options {
+                compile: CompileOptions {
+                    external_link_check: Some(ExternalLinkCheck(Rc::new(|url: &str| {
+                        url.ends_with(".pdf")
+                    }))),
+                    external_link_rel: vec!["noopener".into()],
+                    ..blank.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://a.com\">a</a> <a href=\"/b.pdf\" rel=\"noopener\" target=\"_blank\">b</a></p>",
+        "should support `external_link_check`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://a.com)",
+            &Options {
+                compile: CompileOptions {
+                    external_link_check: Some(ExternalLinkCheck(Rc::new(|_: &str| {
+                        panic!("should not be called")
+                    }))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://a.com\">a</a></p>",
+        "should not check links if there is nothing to add"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,360 @@
+use markdown::{
+    format, format_with_options, message, to_html_with_options, to_markdown, to_mdast,
+    CompileOptions, Constructs, FormatOptions, ListNumbering, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn format_canonical() -> Result<(), message::Message> {
+    assert_eq!(
+        format("", &ParseOptions::default())?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        format("a\n===\n\nb\n---\n\n### c ###", &ParseOptions::default())?,
+        "# a\n\n## b\n\n### c\n",
+        "should use ATX headings"
+    );
+
+    assert_eq!(
+        format("_a_ __b__ ***c***", &ParseOptions::default())?,
+        "*a* **b** ***c***\n",
+        "should use asterisks for emphasis and strong"
+    );
+
+    assert_eq!(
+        format("- a\n- b\n\n___", &ParseOptions::default())?,
+        "* a\n* b\n\n***\n",
+        "should use asterisks for bullets and thematic breaks"
+    );
+
+    assert_eq!(
+        format("3) a\n1) b", &ParseOptions::default())?,
+        "3. a\n4. b\n",
+        "should number ordered lists"
+    );
+
+    assert_eq!(
+        format("- a\n\n  b\n- c", &ParseOptions::default())?,
+        "* a\n\n  b\n\n* c\n",
+        "should support spread lists"
+    );
+
+    assert_eq!(
+        format("- a\n- b\n+ c\n+ d\n* e", &ParseOptions::default())?,
+        "* a\n* b\n\n- c\n- d\n\n* e\n",
+        "should alternate bullets in adjacent lists"
+    );
+
+    assert_eq!(
+        format("- ***", &ParseOptions::default())?,
+        "- ***\n",
+        "should use the other bullet for thematic breaks in lists"
+    );
+
+    assert_eq!(
+        format("> a\n>\n> > b", &ParseOptions::default())?,
+        "> a\n>\n> > b\n",
+        "should support block quotes"
+    );
+
+    assert_eq!(
+        format(
+            "    a\n\n~~~js b\n`\n~~~\n\n~~~\n```\n~~~",
+            &ParseOptions::default()
+        )?,
+        "```\na\n```\n\n```js b\n`\n```\n\n````\n```\n````\n",
+        "should use fenced code"
+    );
+
+    assert_eq!(
+        format("``a`b`` ` `` `", &ParseOptions::default())?,
+        "``a`b`` ` `` `\n",
+        "should support code (text)"
+    );
+
+    assert_eq!(
+        format("a  \nb\\\nc", &ParseOptions::default())?,
+        "a\\\nb\\\nc\n",
+        "should use escapes for hard breaks"
+    );
+
+    assert_eq!(
+        format(
+            "[a](<b c> 'd') ![e](f (g)) [h](<>)",
+            &ParseOptions::default()
+        )?,
+        "[a](<b c> 'd') ![e](f (g)) [h](<>)\n",
+        "should support resources"
+    );
+
+    assert_eq!(
+        format(
+            "<https://a.b> <c@d.e> [f](https://a.b)",
+            &ParseOptions::default()
+        )?,
+        "<https://a.b> <c@d.e> [f](https://a.b)\n",
+        "should support autolinks"
+    );
+
+    assert_eq!(
+        format(
+            "[a][] [B] [c][b] ![*d*]\n\n[a]: <> \"e\"\n[b]: f\n[*d*]: g",
+            &ParseOptions::default()
+        )?,
+        "[a][] [B] [c][b] ![d][*d*]\n\n[a]: <> \"e\"\n\n[b]: f\n\n[*d*]: g\n",
+        "should support references and definitions"
+    );
+
+    assert_eq!(
+        format(
+            "[a]: <b> 'c'\n\n[d]: e\n\n[f](<g>) [h](i)\n\n[a] [d]",
+            &ParseOptions::default()
+        )?,
+        "[a]: <b> 'c'\n\n[d]: e\n\n[f](<g>) [h](i)\n\n[a] [d]\n",
+        "should keep destinations in angle brackets"
+    );
+
+    assert_eq!(
+        format(
+            "\\- a\n\\+ b\n1\\. c\n\\# d\n\\> e\n\\*f\\* \\[g\\] \\<h> &amp;i; \\\\",
+            &ParseOptions::default()
+        )?,
+        "\\- a\n\\+ b\n1\\. c\n\\# d\n\\> e\n\\*f\\* \\[g\\] \\<h> \\&i; \\\\\n",
+        "should escape text"
+    );
+
+    assert_eq!(
+        format("&#x20;a&#10;&#10;b", &ParseOptions::default())?,
+        "&#x20;a\n&#xA;b\n",
+        "should encode initial whitespace and blank lines in text"
+    );
+
+    assert_eq!(
+        format("<div>\n*a*\n</div>\n\n<b>c</b>", &ParseOptions::default())?,
+        "<div>\n*a*\n</div>\n\n<b>c</b>\n",
+        "should support HTML"
+    );
+
+    assert_eq!(
+        format(
+            "| a | b | c |\n| :- | :-: | -: |\n| dd | e | f\\|g |\n\n* [ ] h\n* [x] i ~j~ k[^1]\n\n[^1]: l\n\n    m",
+            &ParseOptions::gfm()
+        )?,
+        "| a   |  b  |    c |\n| :-- | :-: | ---: |\n| dd  |  e  | f\\|g |\n\n* [ ] h\n* [x] i ~~j~~ k[^1]\n\n[^1]: l\n\n    m\n",
+        "should support GFM"
+    );
+
+    assert_eq!(
+        format(
+            "---\na: b\n---\n\n$$\nc\n$$\n\n$d$",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    math_flow: true,
+                    math_text: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "---\na: b\n---\n\n$$\nc\n$$\n\n$d$\n",
+        "should support frontmatter and math"
+    );
+
+    assert_eq!(
+        to_markdown(&to_mdast("*a*", &ParseOptions::default())?),
+        "*a*\n",
+        "should support `to_markdown`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_round_trip() -> Result<(), message::Message> {
+    let options = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..Default::default()
+        },
+    };
+
+    for value in [
+        "*_a_*",
+        "_*a*_",
+        "\\![a]\n\n[a]: b",
+        "[Foo*bar\\]]:my_(url) 'title (with parens)'\n\n[Foo*bar\\]]",
+        "[*foo* bar][]\n\n[*foo* bar]: /url \"title\"",
+        "[a]: <b> 'c'\n\n[a] [d](<e>)",
+        "[bar\\\\]: /uri\n\n[bar\\\\]",
+        "a\n-\n\nb\n=",
+        "1. a\n\n   2. b\n\n   1) c",
+        "* a\n\n  ```\n  b\n  ```\n* c\n\n  > d",
+        "[a](b(c)d) [e](f\\)g) [h](i&amp;j;)",
+        "<style\n  type=\"text/css\">\n\nfoo\n",
+        "| a |\n| - |\n| `b\\|c` |",
+    ] {
+        let formatted = format(value, &options.parse)?;
+
+        assert_eq!(
+            to_html_with_options(&formatted, &options)?.trim_end(),
+            to_html_with_options(value, &options)?.trim_end(),
+            "should round trip `{:?}`",
+            value
+        );
+
+        assert_eq!(
+            format(&formatted, &options.parse)?,
+            formatted,
+            "should be idempotent for `{:?}`",
+            value
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn format_options() -> Result<(), message::Message> {
+    let parse = ParseOptions::default();
+    let style = FormatOptions {
+        bullet: '-',
+        emphasis: '_',
+        strong: '_',
+        fence: '~',
+        fence_length: 4,
+        setext: true,
+        ..FormatOptions::default()
+    };
+
+    assert_eq!(
+        format_with_options(
+            "# a\n\n## b\n\n### c\n\n* *d* **e**\n\n```js\nf\n```",
+            &parse,
+            &style
+        )?,
+        "a\n===\n\nb\n---\n\n### c\n\n- _d_ __e__\n\n~~~~js\nf\n~~~~\n",
+        "should support a style"
+    );
+
+    assert_eq!(
+        format_with_options("a*b*c **d**e", &parse, &style)?,
+        "a*b*c **d**e\n",
+        "should use asterisks in words"
+    );
+
+    assert_eq!(
+        format_with_options("*a*\n\n- b\n\n* c", &parse, &style)?,
+        "_a_\n\n- b\n\n* c\n",
+        "should use the other bullet for adjacent lists"
+    );
+
+    assert_eq!(
+        format_with_options(
+            "- ***\n- a",
+            &parse,
+            &FormatOptions {
+                bullet: '*',
+                ..FormatOptions::default()
+            }
+        )?,
+        "- ***\n- a\n",
+        "should not use an asterisk bullet before a thematic break"
+    );
+
+    assert_eq!(
+        format_with_options("~~~ a`b\nc\n~~~", &parse, &style)?,
+        "~~~~a`b\nc\n~~~~\n",
+        "should support info with grave accents in tilde fences"
+    );
+
+    assert_eq!(
+        format_with_options("abcdef\n======", &parse, &style)?,
+        "abcdef\n======\n",
+        "should size setext underlines to the content"
+    );
+
+    assert_eq!(
+        format_with_options(
+            "3. a\n4. b\n5. c",
+            &parse,
+            &FormatOptions {
+                list_numbering: ListNumbering::Same,
+                ..FormatOptions::default()
+            }
+        )?,
+        "3. a\n3. b\n3. c\n",
+        "should support `ListNumbering::Same`"
+    );
+
+    let wrap = FormatOptions {
+        line_width: Some(10),
+        ..FormatOptions::default()
+    };
+
+    assert_eq!(
+        format_with_options("aaa bbb ccc ddd\neee", &parse, &wrap)?,
+        "aaa bbb\nccc ddd\neee\n",
+        "should wrap paragraphs"
+    );
+
+    assert_eq!(
+        format_with_options("aaaaaaaaaaaa bbb", &parse, &wrap)?,
+        "aaaaaaaaaaaa\nbbb\n",
+        "should not break words"
+    );
+
+    assert_eq!(
+        format_with_options(
+            "aaaaaaaaa - b\n\naaaaaaaaa # b\n\naaaaaaaa 1. b",
+            &parse,
+            &wrap
+        )?,
+        "aaaaaaaaa -\nb\n\naaaaaaaaa #\nb\n\naaaaaaaa 1.\nb\n",
+        "should not break before markdown"
+    );
+
+    assert_eq!(
+        format_with_options("> aaa bbb ccc", &parse, &wrap)?,
+        "> aaa bbb\n> ccc\n",
+        "should wrap in containers"
+    );
+
+    assert_eq!(
+        format_with_options("`a b c d e f g`", &parse, &wrap)?,
+        "`a b c d e f g`\n",
+        "should not wrap code"
+    );
+
+    assert_eq!(
+        format_with_options("aaa bbb ccc\nddd\n===", &parse, &wrap)?,
+        "# aaa bbb ccc ddd\n",
+        "should not wrap headings"
+    );
+
+    for value in [
+        "aaa bbb ccc - ddd + eee # fff > ggg 1) hhh",
+        "* aaa bbb ccc ddd\n\n  eee fff ggg",
+        "a *b c d e f g h* i [j k l m n](o) p",
+        "aaa  bbb\\\nccc ddd eee  \nfff",
+    ] {
+        let formatted = format_with_options(value, &parse, &wrap)?;
+
+        assert_eq!(
+            to_markdown(&to_mdast(&formatted, &parse)?)
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            to_markdown(&to_mdast(value, &parse)?)
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            "should keep content when wrapping `{:?}`",
+            value
+        );
+    }
+
+    Ok(())
+}
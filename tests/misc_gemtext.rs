@@ -0,0 +1,61 @@
+use markdown::{message, to_gemtext, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn gemtext() -> Result<(), message::Message> {
+    assert_eq!(
+        to_gemtext("", &ParseOptions::default())?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_gemtext("# a\n\n#### *b*", &ParseOptions::default())?,
+        "# a\n\n### b",
+        "should support headings, up to rank 3"
+    );
+
+    assert_eq!(
+        to_gemtext("a\n*b*  \n`c`", &ParseOptions::default())?,
+        "a b\nc",
+        "should support paragraphs, without formatting"
+    );
+
+    assert_eq!(
+        to_gemtext("a [b](c) ![d](e) [f][]\n\n[f]: g", &ParseOptions::default())?,
+        "a b d f\n=> c b\n=> e d\n=> g f",
+        "should add link lines after paragraphs"
+    );
+
+    assert_eq!(
+        to_gemtext("[a](b) <https://c>", &ParseOptions::default())?,
+        "=> b a\n=> https://c",
+        "should turn paragraphs with only links into link lines"
+    );
+
+    assert_eq!(
+        to_gemtext(
+            "> a\n> [b](c)\n>\n> ```js\n> d\n> ```",
+            &ParseOptions::default()
+        )?,
+        "> a b\n=> c b\n>\n```js\nd\n```",
+        "should support block quotes, but not quote link lines or preformatted blocks"
+    );
+
+    assert_eq!(
+        to_gemtext("* a\n  * [b](c)\n3. d\n*\n", &ParseOptions::default())?,
+        "* a\n* b\n=> c b\n\n3. d\n\n*",
+        "should support lists, flattening nested lists"
+    );
+
+    assert_eq!(
+        to_gemtext(
+            "| a | b |\n| - | - |\n| c | d |\n\n***",
+            &ParseOptions::gfm()
+        )?,
+        "```\na | b\nc | d\n```\n\n---",
+        "should support tables and thematic breaks"
+    );
+
+    Ok(())
+}
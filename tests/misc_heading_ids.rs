@@ -0,0 +1,119 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions, SlugKind,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn with_slug(slug: SlugKind) -> Options {
+    Options {
+        compile: CompileOptions {
+            heading_ids: true,
+            slug,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    }
+}
+
+#[test]
+fn heading_ids() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_with_options("# a", &Options::default())?,
+        "<h1>a</h1>",
+        "should not add ids by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# Hello, world!", &with_slug(SlugKind::GitHub))?,
+        "<h1 id=\"hello-world\">Hello, world!</h1>",
+        "should add ids to atx headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("Hello\nworld\n===", &with_slug(SlugKind::GitHub))?,
+        "<h1 id=\"hello-world\">Hello\nworld</h1>",
+        "should add ids to setext headings, turning line endings into dashes"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n# a\n\n# a-1\n\n# a", &with_slug(SlugKind::GitHub))?,
+        "<h1 id=\"a\">a</h1>\n<h1 id=\"a-1\">a</h1>\n<h1 id=\"a-1-1\">a-1</h1>\n<h1 id=\"a-2\">a</h1>",
+        "should make ids unique"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# *a* `b` [c](d) ![e](f) <g> &amp; \\!",
+            &with_slug(SlugKind::GitHub)
+        )?,
+        "<h1 id=\"a-b-c-e---\"><em>a</em> <code>b</code> <a href=\"d\">c</a> <img src=\"f\" alt=\"e\" /> &lt;g&gt; &amp; !</h1>",
+        "should use the visible text of headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# 1. Über - uns", &with_slug(SlugKind::GitHub))?,
+        "<h1 id=\"1-über---uns\">1. Über - uns</h1>",
+        "should support the GitHub algorithm"
+    );
+
+    assert_eq!(
+        to_html_with_options("# 1. Über - uns", &with_slug(SlugKind::GitLab))?,
+        "<h1 id=\"1-über-uns\">1. Über - uns</h1>",
+        "should support the GitLab algorithm"
+    );
+
+    assert_eq!(
+        to_html_with_options("# 1. Über - uns", &with_slug(SlugKind::Kramdown))?,
+        "<h1 id=\"ber---uns\">1. Über - uns</h1>",
+        "should support the kramdown algorithm"
+    );
+
+    assert_eq!(
+        to_html_with_options("# 123", &with_slug(SlugKind::Kramdown))?,
+        "<h1 id=\"section\">123</h1>",
+        "should support the kramdown algorithm (empty)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a b\n\n# a b",
+            &with_slug(SlugKind::Custom(Rc::new(
+                |value: &str| value.replace(' ', "_")
+            )))
+        )?,
+        "<h1 id=\"a_b\">a b</h1>\n<h1 id=\"a_b-1\">a b</h1>",
+        "should support a custom algorithm"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a\n\n# <\"&>",
+            &with_slug(SlugKind::Custom(Rc::new(|value: &str| value.into())))
+        )?,
+        "<h1 id=\"a\">a</h1>\n<h1 id=\"&lt;&quot;&amp;&gt;\">&lt;&quot;&amp;&gt;</h1>",
+        "should encode ids"
+    );
+
+    let mut attribute_list = with_slug(SlugKind::GitHub);
+    attribute_list.parse = ParseOptions {
+        constructs: Constructs {
+            attribute_list: true,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("{: .b}\n# a", &attribute_list)?,
+        "<h1 id=\"a\" class=\"b\">a</h1>",
+        "should add ids next to other attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: #b}\n# a", &attribute_list)?,
+        "<h1 id=\"b\">a</h1>",
+        "should prefer ids from attribute lists"
+    );
+
+    Ok(())
+}
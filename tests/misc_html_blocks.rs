@@ -0,0 +1,91 @@
+use markdown::{
+    message, to_html_with_blocks, unist::Position, Constructs, HtmlBlock, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn html_blocks() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_with_blocks("", &Options::default())?,
+        (String::new(), vec![]),
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_html_with_blocks("# a\n\nb\nc", &Options::default())?,
+        (
+            "<h1>a</h1>\n<p>b\nc</p>".into(),
+            vec![
+                HtmlBlock {
+                    start: 0,
+                    end: 10,
+                    position: Position::new(1, 1, 0, 1, 4, 3)
+                },
+                HtmlBlock {
+                    start: 11,
+                    end: 21,
+                    position: Position::new(3, 1, 5, 4, 2, 8)
+                }
+            ]
+        ),
+        "should map top-level blocks to their places in the HTML and markdown"
+    );
+
+    assert_eq!(
+        to_html_with_blocks("> a\n> * b", &Options::default())?,
+        (
+            "<blockquote>\n<p>a</p>\n<ul>\n<li>b</li>\n</ul>\n</blockquote>".into(),
+            vec![HtmlBlock {
+                start: 0,
+                end: 57,
+                position: Position::new(1, 1, 0, 2, 6, 9)
+            }],
+        ),
+        "should map containers as a whole"
+    );
+
+    assert_eq!(
+        to_html_with_blocks("a\r\n\r\nb", &Options::default())?,
+        (
+            "<p>a</p>\r\n<p>b</p>".into(),
+            vec![
+                HtmlBlock {
+                    start: 0,
+                    end: 8,
+                    position: Position::new(1, 1, 0, 1, 2, 1)
+                },
+                HtmlBlock {
+                    start: 10,
+                    end: 18,
+                    position: Position::new(3, 1, 5, 3, 2, 6)
+                }
+            ]
+        ),
+        "should not include line endings between blocks"
+    );
+
+    let (html, blocks) = to_html_with_blocks(
+        "---\na: b\n---\n\n[c]: d\n\ne[^f]\n\n[^f]: g",
+        &Options {
+            parse: ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    ..Constructs::gfm()
+                },
+                ..ParseOptions::gfm()
+            },
+            ..Options::gfm()
+        },
+    )?;
+
+    assert_eq!(
+        blocks
+            .iter()
+            .map(|block| (&html[block.start..block.end], block.position.start.line))
+            .collect::<Vec<_>>(),
+        vec![("<p>e<sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>", 7)],
+        "should not include blocks that produce no HTML, or the footnote section"
+    );
+
+    Ok(())
+}
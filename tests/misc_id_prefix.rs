@@ -0,0 +1,75 @@
+use markdown::{message, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+fn with_prefix(id_prefix: Option<&str>) -> Options {
+    Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                block_id: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::default()
+        },
+        compile: CompileOptions {
+            heading_ids: true,
+            id_prefix: id_prefix.map(Into::into),
+            ..CompileOptions::gfm()
+        },
+    }
+}
+
+#[test]
+fn id_prefix() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_with_options("# a", &with_prefix(None))?,
+        "<h1 id=\"a\">a</h1>",
+        "should not namespace ids by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n# a", &with_prefix(Some("x-")))?,
+        "<h1 id=\"x-a\">a</h1>\n<h1 id=\"x-a-1\">a</h1>",
+        "should namespace heading ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^b", &with_prefix(Some("x-")))?,
+        "<p id=\"x-^b\">a</p>",
+        "should namespace block ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("{: #b}\n# a", &with_prefix(Some("x-")))?,
+        "<h1 id=\"b\">a</h1>",
+        "should not namespace ids from attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options("[^a] [^a]\n\n[^a]: b", &with_prefix(Some("x-")))?,
+        "<p><sup><a href=\"#x-user-content-fn-a\" id=\"x-user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"x-footnote-label\">1</a></sup> <sup><a href=\"#x-user-content-fn-a\" id=\"x-user-content-fnref-a-2\" data-footnote-ref=\"\" aria-describedby=\"x-footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"x-footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"x-user-content-fn-a\">
+<p>b <a href=\"#x-user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#x-user-content-fnref-a-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>
+</li>
+</ol>
+</section>
+",
+        "should namespace footnote ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a", &with_prefix(Some("<&>")))?,
+        "<h1 id=\"&lt;&amp;&gt;a\">a</h1>",
+        "should encode the namespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n[^b]\n\n[^b]: c", &with_prefix(Some("x-")))?,
+        to_html_with_options("# a\n\n[^b]\n\n[^b]: c", &with_prefix(Some("x-")))?,
+        "should be deterministic"
+    );
+
+    Ok(())
+}
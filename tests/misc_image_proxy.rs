@@ -0,0 +1,115 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, ImageProxy, ImageResolve, Options, ResolvedImage,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn image_proxy() -> Result<(), message::Message> {
+    let camo = Options {
+        compile: CompileOptions {
+            image_proxy: Some(ImageProxy {
+                url: "https://camo.example.com/{digest}/{url}".into(),
+                key: "k".into(),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("![a](https://a.com/b.png)", &camo)?,
+        "<p><img src=\"https://camo.example.com/c7c734acac4db8db12074d3e8ec8a9d4622cd73b/68747470733a2f2f612e636f6d2f622e706e67\" alt=\"a\" /></p>",
+        "should route external images through the proxy"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a][b]\n\n[b]: //a.com/b.png", &camo)?,
+        "<p><img src=\"https://camo.example.com/1f180d4f5049f021401025ecb1fe20dbb67bf435/2f2f612e636f6d2f622e706e67\" alt=\"a\" /></p>\n",
+        "should route external images (reference) through the proxy"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](/b.png) ![c](d.png) ![e](data:image/png;base64,f)", &camo)?,
+        "<p><img src=\"/b.png\" alt=\"a\" /> <img src=\"d.png\" alt=\"c\" /> <img src=\"\" alt=\"e\" /></p>",
+        "should not route images without a host through the proxy"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://a.com/b.png)", &camo)?,
+        "<p><a href=\"https://a.com/b.png\">a</a></p>",
+        "should not route links through the proxy"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](https://a.com/b.png) ![c](https://x.com/c.png)",
+            &Options {
+                compile: CompileOptions {
+                    origin: Some("https://a.com".into()),
+                    ..camo.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"https://a.com/b.png\" alt=\"a\" /> <img src=\"https://camo.example.com/27e027e2c97e37efa8d939ad5cf5ea8e392a602a/68747470733a2f2f782e636f6d2f632e706e67\" alt=\"c\" /></p>",
+        "should not route images on `origin` through the proxy"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b.png)",
+            &Options {
+                compile: CompileOptions {
+                    base_url: Some("https://a.com".into()),
+                    ..camo.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"https://camo.example.com/c7c734acac4db8db12074d3e8ec8a9d4622cd73b/68747470733a2f2f612e636f6d2f622e706e67\" alt=\"a\" /></p>",
+        "should route images after resolving against `base_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b.png)",
+            &Options {
+                compile: CompileOptions {
+                    image_resolve: Some(ImageResolve(Rc::new(
+                        |_: &str, _: &str, _: Option<&str>| {
+                            Some(ResolvedImage {
+                                src: "https://x.com/c.png".into(),
+                                attributes: vec![],
+                            })
+                        }
+                    ))),
+                    ..camo.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"https://camo.example.com/27e027e2c97e37efa8d939ad5cf5ea8e392a602a/68747470733a2f2f782e636f6d2f632e706e67\" alt=\"a\" /></p>",
+        "should route images after `image_resolve`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](https://a.com/b.png)",
+            &Options {
+                compile: CompileOptions {
+                    image_proxy: Some(ImageProxy {
+                        url: "https://camo.example.com/{digest}?url={url}&a=<b>".into(),
+                        key: "k".into(),
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"https://camo.example.com/c7c734acac4db8db12074d3e8ec8a9d4622cd73b?url=68747470733a2f2f612e636f6d2f622e706e67&amp;a=%3Cb%3E\" alt=\"a\" /></p>",
+        "should sanitize the URL of the proxy"
+    );
+
+    Ok(())
+}
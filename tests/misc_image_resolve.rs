@@ -0,0 +1,125 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, Element, ElementRender, ImageResolve, Options,
+    ResolvedImage,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn resolve(image_resolve: ImageResolve) -> Options {
+    Options {
+        compile: CompileOptions {
+            image_resolve: Some(image_resolve),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn image_resolve() -> Result<(), message::Message> {
+    let debug = resolve(ImageResolve(Rc::new(
+        |src: &str, alt: &str, title: Option<&str>| {
+            Some(ResolvedImage {
+                src: format!("/{}", src),
+                attributes: vec![
+                    ("data-alt".into(), alt.into()),
+                    ("data-title".into(), title.unwrap_or("none").into()),
+                ],
+            })
+        },
+    )));
+    let nothing = resolve(ImageResolve(Rc::new(
+        |_: &str, _: &str, _: Option<&str>| None,
+    )));
+
+    assert_eq!(
+        to_html_with_options("![a](b \"c\")", &debug)?,
+        "<p><img src=\"/b\" alt=\"a\" title=\"c\" data-alt=\"a\" data-title=\"c\" /></p>",
+        "should resolve images (resource)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a]\n\n[a]: b", &debug)?,
+        "<p><img src=\"/b\" alt=\"a\" data-alt=\"a\" data-title=\"none\" /></p>\n",
+        "should resolve images (reference)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a *b*](&lt;c&gt;)", &debug)?,
+        "<p><img src=\"/%3Cc%3E\" alt=\"a b\" data-alt=\"a b\" data-title=\"none\" /></p>",
+        "should pass decoded sources, and sanitize the result"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)", &nothing)?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should compile images like normal if `None` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b)",
+            &resolve(ImageResolve(Rc::new(
+                |_: &str, _: &str, _: Option<&str>| {
+                    Some(ResolvedImage {
+                        src: "javascript:alert(1)".into(),
+                        attributes: vec![("a\"b".into(), "<c>".into())],
+                    })
+                }
+            )))
+        )?,
+        "<p><img src=\"\" alt=\"a\" a&quot;b=\"&lt;c&gt;\" /></p>",
+        "should sanitize sources and encode attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b.png)",
+            &resolve(ImageResolve(Rc::new(
+                |src: &str, _: &str, _: Option<&str>| {
+                    let stem = src.strip_suffix(".png").unwrap_or(src);
+                    Some(ResolvedImage {
+                        src: src.into(),
+                        attributes: vec![(
+                            "srcset".into(),
+                            format!("{}@2x.png 2x, {}@3x.png 3x", stem, stem),
+                        )],
+                    })
+                }
+            )))
+        )?,
+        "<p><img src=\"b.png\" alt=\"a\" srcset=\"b@2x.png 2x, b@3x.png 3x\" /></p>",
+        "should support `srcset`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &debug)?,
+        "<p><a href=\"c\"><img src=\"/b\" alt=\"a\" data-alt=\"a\" data-title=\"none\" /></a></p>",
+        "should resolve images in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b)", &debug)?,
+        "<p><a href=\"b\">a</a></p>",
+        "should not resolve links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b)",
+            &Options {
+                compile: CompileOptions {
+                    element_render: Some(ElementRender(Rc::new(|element: &Element| {
+                        Some(format!("{:?}", element))
+                    }))),
+                    ..debug.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>Image { destination: Some(\"/b\"), title: None, alt: \"a\" }</p>",
+        "should pass resolved sources to `element_render`"
+    );
+
+    Ok(())
+}
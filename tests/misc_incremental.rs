@@ -0,0 +1,298 @@
+use core::ops::Range;
+use markdown::{event::Event, message, to_events, Constructs, Document, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Get the events as strings, to compare them.
+fn show(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .map(|d| {
+            format!(
+                "{:?} {:?} {}:{}:{}:{} {:?}",
+                d.kind,
+                d.name,
+                d.point.line,
+                d.point.column,
+                d.point.index,
+                d.point.vs,
+                d.link.as_ref().map(|d| (d.previous, d.next))
+            )
+        })
+        .collect()
+}
+
+/// Edit `value`, and check that the events are those of the new value.
+fn edit(
+    value: &str,
+    range: Range<usize>,
+    replacement: &str,
+    options: &ParseOptions,
+) -> Result<Range<usize>, message::Message> {
+    let mut document = Document::new(value, options)?;
+    let changed = document.edit(range.clone(), replacement)?;
+    let mut expected = String::from(value);
+    expected.replace_range(range.clone(), replacement);
+
+    assert_eq!(document.value(), expected);
+    assert_eq!(
+        show(document.events()),
+        show(&to_events(&expected, options)?),
+        "should have the events of `{:?}` after replacing {:?} with `{:?}`",
+        value,
+        range,
+        replacement
+    );
+
+    Ok(changed)
+}
+
+#[test]
+fn incremental() -> Result<(), message::Message> {
+    let options = ParseOptions::default();
+
+    assert_eq!(
+        edit("", 0..0, "a", &options)?,
+        0..1,
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd\n\ne", 6..7, "*C*", &options)?,
+        3..11,
+        "should parse the blocks around an edit again"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd\n\ne", 0..1, "# A", &options)?,
+        0..5,
+        "should support edits at the start"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd\n\ne", 12..13, "***", &options)?,
+        9..15,
+        "should support edits at the end"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd", 4..6, "", &options)?,
+        0..7,
+        "should support removing blank lines"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd", 3..3, "x\n\ny\n", &options)?,
+        0..11,
+        "should support adding blocks"
+    );
+
+    assert_eq!(
+        edit("a\n\n- b\n\nc\n\nd\n\ne", 8..8, "  ", &options)?,
+        3..13,
+        "should support continuing containers"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\nc\n\nd\n\ne", 5..6, "-", &options)?,
+        0..8,
+        "should support setext headings"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\nc\n\nd\n\ne", 3..4, "```", &options)?,
+        0..15,
+        "should parse everything again when the edit affects later blocks"
+    );
+
+    assert_eq!(
+        edit("[a]\n\nb\n\nc\n\nd\n\n[a]", 11..11, "[a]: e\n\n", &options)?,
+        0..25,
+        "should parse everything again when definitions change"
+    );
+
+    assert_eq!(
+        edit("[a]\n\nb\n\n[a]: c\n\nd\n\ne", 13..14, "x", &options)?,
+        5..16,
+        "should not parse everything again when definitions stay the same"
+    );
+
+    assert_eq!(
+        edit("a\r\n\r\nb\r\n\r\nc\r\n\r\nd", 5..6, "B\r\nB", &options)?,
+        0..13,
+        "should support CRLF"
+    );
+
+    assert_eq!(
+        edit("a\n\nb\n\n    c\n\n    d\n\ne", 20..20, "    ", &options)?,
+        6..25,
+        "should support code (indented), which continues after blank lines"
+    );
+
+    assert_eq!(
+        edit(
+            "[^a]\n\n[^a]: b\n\n    c\n\nd\n1. \n| a |\n| - |\n| b |\n\nc\n",
+            31..32,
+            "[^a]: q\n",
+            &ParseOptions::gfm()
+        )?,
+        6..54,
+        "should support duplicate footnote definitions, after closed containers"
+    );
+
+    let mut document = Document::new("a\n\nb\n\nc", &options)?;
+    document.edit(3..4, "*b*")?;
+    document.edit(0..1, "# a")?;
+    document.edit(10..11, "1. c")?;
+
+    assert_eq!(
+        show(document.events()),
+        show(&to_events("# a\n\n*b*\n\n1. c", &options)?),
+        "should support several edits"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn incremental_exhaustive() -> Result<(), message::Message> {
+    let options = ParseOptions::gfm();
+    let value = "# a\n\n* b\n\n  c\n\n> d\n> e\n\n[f]: g\n\n| h |\n| - |\n\n    i\n\nj[^k]\n\n[^k]: l\n\n```\nm\n```\n\nn\n";
+    let replacements = [
+        "", "\n", "\n\n", " ", "  ", "*", "-", ">", "`", "[", "]", "#", "x",
+    ];
+    let mut start = 0;
+
+    while start <= value.len() {
+        for end in [start, start + 1, start + 3] {
+            if end <= value.len() {
+                for replacement in replacements {
+                    edit(value, start..end, replacement, &options)?;
+                }
+            }
+        }
+
+        start += 1;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn incremental_random() -> Result<(), message::Message> {
+    /// Pieces of markdown to make documents and edits with.
+    const PIECES: [&str; 55] = [
+        "",
+        "\n",
+        "\n\n",
+        " ",
+        "  ",
+        "    ",
+        "\t",
+        "a",
+        "b c",
+        "*",
+        "-",
+        "- ",
+        "1. ",
+        "2) ",
+        ">",
+        "> ",
+        "`",
+        "'",
+        "\"",
+        "(",
+        ")",
+        "<",
+        "|",
+        "\\",
+        "[",
+        "]",
+        "[a]",
+        "![a]",
+        "*a*",
+        "#",
+        "```\n",
+        "~~~\n",
+        "$$\n",
+        ":::\n",
+        "::: a\n",
+        "::a\n",
+        ">>>\n",
+        "> [!NOTE]\n",
+        "***\n",
+        "---\n",
+        "===\n",
+        "    c\n",
+        "<div>\n",
+        "<!--",
+        "-->",
+        "| a |\n",
+        "| - |\n",
+        "[^a]",
+        "[^a]: q\n",
+        "[a]: b\n",
+        "*[a]: b\n",
+        "{#a}",
+        "^b\n",
+        "\n- a\n",
+        "\n> b\n",
+    ];
+
+    /// Get a pseudo-random number below `max` (xorshift).
+    fn random(seed: &mut u64, max: usize) -> usize {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        (*seed % max as u64) as usize
+    }
+
+    let options = ParseOptions {
+        constructs: Constructs {
+            abbreviation: true,
+            attribute_list: true,
+            block_id: true,
+            block_quote_fenced: true,
+            directive_container: true,
+            directive_leaf: true,
+            fenced_div: true,
+            gfm_alert: true,
+            math_flow: true,
+            ..Constructs::gfm()
+        },
+        ..ParseOptions::gfm()
+    };
+    let mut seed = 0x2545_F491_4F6C_DD1D;
+    let mut count = 0;
+
+    while count < 1000 {
+        let mut value = String::new();
+        let size = random(&mut seed, 24);
+        while value.len() < size * 2 {
+            value.push_str(PIECES[random(&mut seed, PIECES.len())]);
+        }
+
+        let mut document = Document::new(&value, &options)?;
+
+        for _ in 0..8 {
+            let start = random(&mut seed, value.len() + 1);
+            let end = start + random(&mut seed, (value.len() - start).min(4) + 1);
+            let replacement = PIECES[random(&mut seed, PIECES.len())];
+            let before = value.clone();
+            document.edit(start..end, replacement)?;
+            value.replace_range(start..end, replacement);
+
+            assert_eq!(
+                show(document.events()),
+                show(&to_events(&value, &options)?),
+                "should have the events of `{:?}` after replacing {:?} with `{:?}`",
+                before,
+                start..end,
+                replacement
+            );
+        }
+
+        count += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use markdown::{message, to_html_inline, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn inline() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_inline("", &Options::default())?,
+        "",
+        "should support empty input"
+    );
+
+    assert_eq!(
+        to_html_inline("a *b* `c` [d](e)", &Options::default())?,
+        "a <em>b</em> <code>c</code> <a href=\"e\">d</a>",
+        "should support phrasing constructs, w/o paragraph"
+    );
+
+    assert_eq!(
+        to_html_inline("# a", &Options::default())?,
+        "# a",
+        "should not support headings"
+    );
+
+    assert_eq!(
+        to_html_inline("> a\n- b\n1. c\n***\n    d", &Options::default())?,
+        "&gt; a\n- b\n1. c\n***\nd",
+        "should not support other block constructs"
+    );
+
+    assert_eq!(
+        to_html_inline("a\n\nb", &Options::default())?,
+        "a\n\nb",
+        "should not split paragraphs at blank lines"
+    );
+
+    assert_eq!(
+        to_html_inline("`a\n\nb`", &Options::default())?,
+        "`a\n\nb`",
+        "should not support blank lines in code (text), like in paragraphs"
+    );
+
+    assert_eq!(
+        to_html_inline("*a\n  \nb*", &Options::default())?,
+        "*a\n\nb*",
+        "should not support blank lines w/ whitespace in attention"
+    );
+
+    assert_eq!(
+        to_html_inline("`\n\n", &Options::default())?,
+        "`\n\n",
+        "should support blank lines after an unclosed code (text)"
+    );
+
+    assert_eq!(
+        to_html_inline("`a\r\n\r\n", &Options::default())?,
+        "`a\r\n\r\n",
+        "should support blank lines w/ CRLFs after an unclosed code (text)"
+    );
+
+    assert_eq!(
+        to_html_inline("[a](\n", &Options::default())?,
+        "[a](\n",
+        "should support a final line ending after an unclosed resource"
+    );
+
+    assert_eq!(
+        to_html_inline("[a][\n", &Options::default())?,
+        "[a][\n",
+        "should support a final line ending after an unclosed reference"
+    );
+
+    assert_eq!(
+        to_html_inline("[a][\r", &Options::default())?,
+        "[a][\r",
+        "should support a final CR after an unclosed reference"
+    );
+
+    assert_eq!(
+        to_html_inline("x [a](b\n", &Options::default())?,
+        "x [a](b\n",
+        "should support a final line ending after an unclosed destination"
+    );
+
+    assert_eq!(
+        to_html_inline("[a](\n\nb)", &Options::default())?,
+        "[a](\n\nb)",
+        "should not support blank lines in resources"
+    );
+
+    assert_eq!(
+        to_html_inline("[a]\n\n[a]: b", &Options::default())?,
+        "[a]\n\n[a]: b",
+        "should not support definitions"
+    );
+
+    assert_eq!(
+        to_html_inline("  a  \n  b\\\nc  ", &Options::default())?,
+        "a<br />\nb<br />\nc",
+        "should trim whitespace, and support hard breaks"
+    );
+
+    assert_eq!(
+        to_html_inline("<div>a</div>", &Options::default())?,
+        "&lt;div&gt;a&lt;/div&gt;",
+        "should be safe by default"
+    );
+
+    assert_eq!(
+        to_html_inline(
+            "<div>a</div>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div>a</div>",
+        "should support HTML (text) w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_inline("~a~ www.b.com", &Options::gfm())?,
+        "<del>a</del> <a href=\"http://www.b.com\">www.b.com</a>",
+        "should support GFM phrasing constructs"
+    );
+
+    assert_eq!(
+        to_html_inline("* [x] a\n| b |\n| - |", &Options::gfm())?,
+        "* [x] a\n| b |\n| - |",
+        "should not support GFM block constructs"
+    );
+
+    Ok(())
+}
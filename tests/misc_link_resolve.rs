@@ -0,0 +1,132 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, Element, ElementRender, LinkKind, LinkResolve,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn resolve(link_resolve: LinkResolve) -> Options {
+    Options {
+        compile: CompileOptions {
+            link_resolve: Some(link_resolve),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn link_resolve() -> Result<(), message::Message> {
+    let debug = resolve(LinkResolve(Rc::new(|destination: &str, kind: LinkKind| {
+        Some(format!("/{:?}/{}", kind, destination))
+    })));
+    let drop = resolve(LinkResolve(Rc::new(|_: &str, _: LinkKind| None)));
+    let wiki = resolve(LinkResolve(Rc::new(|destination: &str, _: LinkKind| {
+        destination
+            .strip_suffix(".md")
+            .map(|name| format!("/{}/", name))
+            .or_else(|| Some(destination.into()))
+    })));
+
+    assert_eq!(
+        to_html_with_options("[a](b)", &debug)?,
+        "<p><a href=\"/Resource/b\">a</a></p>",
+        "should resolve links (resource)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][b] [b]\n\n[b]: c", &debug)?,
+        "<p><a href=\"/Reference/c\">a</a> <a href=\"/Reference/c\">b</a></p>\n",
+        "should resolve links (reference)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b> <c@d.e>", &debug)?,
+        "<p><a href=\"/Autolink/https://a.b\">https://a.b</a> <a href=\"/Autolink/mailto:c@d.e\">c@d.e</a></p>",
+        "should resolve autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "www.a.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: debug.compile.clone()
+            }
+        )?,
+        "<p><a href=\"/Autolink/http://www.a.com\">www.a.com</a></p>",
+        "should resolve autolinks (GFM literal)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](&lt;b&gt;%20\\*) [c](<>)", &debug)?,
+        "<p><a href=\"/Resource/%3Cb%3E%20*\">a</a> <a href=\"/Resource/\">c</a></p>",
+        "should pass decoded destinations, and sanitize the result"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b)", &debug)?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should not resolve images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &debug)?,
+        "<p><a href=\"/Resource/c\"><img src=\"b\" alt=\"a\" /></a></p>",
+        "should resolve links around images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a *b*](c \"d\") <https://e.f>", &drop)?,
+        "<p>a <em>b</em> https://e.f</p>",
+        "should drop links if `None` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a www.b.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: drop.compile.clone()
+            }
+        )?,
+        "<p>a www.b.com</p>",
+        "should drop autolinks (GFM literal) if `None` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1))", &wiki)?,
+        "<p><a href=\"\">a</a></p>",
+        "should sanitize resolved destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](a.md) [b](b.png)", &wiki)?,
+        "<p><a href=\"/a/\">a</a> <a href=\"b.png\">b</a></p>",
+        "should rewrite destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](a.md) [b](b)",
+            &Options {
+                compile: CompileOptions {
+                    link_resolve: Some(LinkResolve(Rc::new(|destination: &str, _: LinkKind| {
+                        destination
+                            .strip_suffix(".md")
+                            .map(|name| format!("/{}/", name))
+                    }))),
+                    element_render: Some(ElementRender(Rc::new(|element: &Element| {
+                        Some(format!("{:?}", element))
+                    }))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>Link { destination: Some(\"/a/\"), title: None, children: \"a\" } b</p>",
+        "should pass resolved destinations to `element_render`, and not render dropped links"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,443 @@
+use markdown::{
+    lint,
+    message::{Fix, Place},
+    unist::Position,
+    Constructs, LintOptions, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the messages as strings.
+fn messages(value: &str, options: &LintOptions) -> Vec<String> {
+    lint(value, options)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Apply all fixes.
+fn fix(value: &str, options: &LintOptions) -> String {
+    let mut edits = lint(value, options)
+        .into_iter()
+        .filter_map(|d| d.fix)
+        .flat_map(|d| d.edits)
+        .collect::<Vec<_>>();
+    edits.sort_by_key(|d| d.position.start.offset);
+    Fix { edits }.apply(value)
+}
+
+/// Only one rule.
+fn only(rule: &str) -> LintOptions {
+    LintOptions {
+        trailing_spaces: rule == "no-trailing-spaces",
+        hard_tabs: rule == "no-hard-tabs",
+        line_length: if rule == "line-length" {
+            Some(10)
+        } else {
+            None
+        },
+        bare_urls: rule == "no-bare-urls",
+        consistent_markers: rule == "markers",
+        undefined_references: rule == "reference-links-images",
+        unused_definitions: rule == "link-image-reference-definitions",
+        ..LintOptions::default()
+    }
+}
+
+#[test]
+fn lint_general() {
+    assert_eq!(
+        messages("", &LintOptions::default()),
+        Vec::<String>::new(),
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        messages(
+            "# a\n\n* b *c* **d**\n* e <https://f.g>\n\n```h\ni\n```",
+            &LintOptions::default()
+        ),
+        Vec::<String>::new(),
+        "should support fine documents"
+    );
+
+    assert_eq!(
+        messages("- a\t\n* https://b.c ", &LintOptions::default()),
+        vec![
+            "1:4-1:5: Unexpected trailing whitespace (markdown-rs:no-trailing-spaces)",
+            "1:4-1:5: Unexpected hard tab, expected spaces (markdown-rs:no-hard-tabs)",
+            "2:1-2:2: Unexpected bullet `*`, expected `-` (markdown-rs:ul-style)",
+            "2:3-2:14: Unexpected bare URL `https://b.c`, expected an autolink (markdown-rs:no-bare-urls)",
+            "2:14-2:15: Unexpected trailing whitespace (markdown-rs:no-trailing-spaces)",
+        ],
+        "should sort messages by place"
+    );
+
+    let messages = lint("a \r\nb", &LintOptions::default());
+
+    assert_eq!(
+        messages[0].place,
+        Some(Box::new(Place::Position(Position::new(1, 2, 1, 1, 3, 2)))),
+        "should have places with offsets"
+    );
+
+    assert_eq!(
+        lint(
+            "{",
+            &LintOptions {
+                parse: ParseOptions::mdx(),
+                ..LintOptions::default()
+            }
+        )
+        .iter()
+        .map(|d| d.rule_id.as_str())
+        .collect::<Vec<_>>(),
+        vec!["unexpected-eof"],
+        "should return syntax errors in MDX"
+    );
+}
+
+#[test]
+fn lint_trailing_spaces() {
+    let options = only("no-trailing-spaces");
+
+    assert_eq!(
+        messages("a \nb\t\n \nc", &options),
+        vec![
+            "1:2-1:3: Unexpected trailing whitespace (markdown-rs:no-trailing-spaces)",
+            "2:2-2:5: Unexpected trailing whitespace (markdown-rs:no-trailing-spaces)",
+            "3:1-3:2: Unexpected trailing whitespace (markdown-rs:no-trailing-spaces)",
+        ],
+        "should warn for trailing whitespace"
+    );
+
+    assert_eq!(
+        fix("a \nb\t\n \nc  ", &options),
+        "a\nb\n\nc",
+        "should fix trailing whitespace"
+    );
+
+    assert_eq!(
+        messages("a  \nb", &options),
+        Vec::<String>::new(),
+        "should not warn for hard breaks"
+    );
+
+    assert_eq!(
+        fix("a     \nb", &options),
+        "a  \nb",
+        "should fix hard breaks with more than two spaces"
+    );
+
+    assert_eq!(
+        messages("    a  \n\n```\nb \n```", &options),
+        Vec::<String>::new(),
+        "should not warn in code"
+    );
+}
+
+#[test]
+fn lint_hard_tabs() {
+    let options = only("no-hard-tabs");
+
+    assert_eq!(
+        messages("a\tb\n\t\tc", &options),
+        vec![
+            "1:2-1:5: Unexpected hard tab, expected spaces (markdown-rs:no-hard-tabs)",
+            "2:1-2:9: Unexpected hard tab, expected spaces (markdown-rs:no-hard-tabs)",
+        ],
+        "should warn for tabs"
+    );
+
+    assert_eq!(
+        fix("a\tb\nab\tc\n\t\td", &options),
+        "a   b\nab  c\n        d",
+        "should fix tabs with spaces up to the next tab stop"
+    );
+}
+
+#[test]
+fn lint_line_length() {
+    let options = only("line-length");
+
+    assert_eq!(
+        messages("aaaa bbbb cc dd", &options),
+        vec!["1:11-1:16: Unexpected line of 15 characters, expected at most 10 (markdown-rs:line-length)"],
+        "should warn for long lines"
+    );
+
+    assert_eq!(
+        messages("aaaa bbbbb\nééé ééé éé é", &options),
+        vec!["2:19-2:22: Unexpected line of 12 characters, expected at most 10 (markdown-rs:line-length)"],
+        "should count characters"
+    );
+
+    assert_eq!(
+        messages("aaaa bbbb cccc\na https://example.com", &options),
+        Vec::<String>::new(),
+        "should not warn for lines that cannot be wrapped"
+    );
+
+    assert_eq!(
+        messages("```\naaaa bbbb cc dd\n```\n\n    aaaa bbbb cc dd", &options),
+        Vec::<String>::new(),
+        "should not warn in code"
+    );
+
+    assert_eq!(
+        messages(
+            "| aaaa bbbb cc dd |\n| - |",
+            &LintOptions {
+                parse: ParseOptions::gfm(),
+                ..options
+            }
+        ),
+        Vec::<String>::new(),
+        "should not warn in tables"
+    );
+
+    assert_eq!(
+        fix("aaaa bbbb cc dd", &only("line-length")),
+        "aaaa bbbb cc dd",
+        "should not fix long lines"
+    );
+}
+
+#[test]
+fn lint_bare_urls() {
+    let options = only("no-bare-urls");
+
+    assert_eq!(
+        messages("a https://b.c/d, e (http://f.g/(h)).", &options),
+        vec![
+            "1:3-1:16: Unexpected bare URL `https://b.c/d`, expected an autolink (markdown-rs:no-bare-urls)",
+            "1:21-1:35: Unexpected bare URL `http://f.g/(h)`, expected an autolink (markdown-rs:no-bare-urls)",
+        ],
+        "should warn for bare URLs"
+    );
+
+    assert_eq!(
+        fix("a https://b.c.\n\n# d http://e.f", &options),
+        "a <https://b.c>.\n\n# d <http://e.f>",
+        "should fix bare URLs"
+    );
+
+    assert_eq!(
+        messages(
+            "<https://a.b> [https://c.d](https://e.f) `https://g.h` xhttps://i.j https://\n\n[k]: https://l.m\n\n    https://n.o",
+            &options
+        ),
+        Vec::<String>::new(),
+        "should not warn for URLs in links, code, and definitions"
+    );
+
+    assert_eq!(
+        messages("é", &options),
+        Vec::<String>::new(),
+        "should support non-ASCII data"
+    );
+
+    assert_eq!(
+        fix("é https://a.b/ü, ç", &options),
+        "é <https://a.b/ü>, ç",
+        "should fix bare URLs around non-ASCII data"
+    );
+
+    let gfm = LintOptions {
+        parse: ParseOptions::gfm(),
+        ..only("no-bare-urls")
+    };
+
+    assert_eq!(
+        fix("a https://b.c d@e.f www.g.h", &gfm),
+        "a <https://b.c> <d@e.f> www.g.h",
+        "should fix GFM autolink literals, except for `www.`"
+    );
+
+    assert_eq!(
+        messages(
+            "a https://b.c",
+            &LintOptions {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_autolink_literal: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..only("no-bare-urls")
+            }
+        )
+        .len(),
+        1,
+        "should not warn twice for GFM autolink literals"
+    );
+}
+
+#[test]
+fn lint_markers() {
+    let options = only("markers");
+
+    assert_eq!(
+        messages("* a\n\n- b\n\n+ c", &options),
+        vec![
+            "3:1-3:2: Unexpected bullet `-`, expected `*` (markdown-rs:ul-style)",
+            "5:1-5:2: Unexpected bullet `+`, expected `*` (markdown-rs:ul-style)",
+        ],
+        "should warn for inconsistent bullets"
+    );
+
+    assert_eq!(
+        fix("- a\n\n* b\n\n1. c", &options),
+        "- a\n\n- b\n\n1. c",
+        "should fix inconsistent bullets (but not ordered lists)"
+    );
+
+    assert_eq!(
+        messages("_a_ *b* __c__ **d**", &options),
+        vec![
+            "1:5-1:8: Unexpected emphasis marker `*`, expected `_` (markdown-rs:emphasis-style)",
+            "1:15-1:20: Unexpected strong marker `*`, expected `_` (markdown-rs:strong-style)",
+        ],
+        "should warn for inconsistent emphasis and strong"
+    );
+
+    assert_eq!(
+        fix("*a* _b_ **c** __d__ ***e***", &options),
+        "*a* *b* **c** **d** ***e***",
+        "should fix inconsistent emphasis and strong"
+    );
+
+    assert_eq!(
+        fix("_a_ b*c*d *e*", &options),
+        "_a_ b*c*d _e_",
+        "should not fix emphasis in words with underscores"
+    );
+
+    assert_eq!(
+        messages("_a_ b*c*d", &options).len(),
+        1,
+        "should still warn for emphasis in words"
+    );
+
+    assert_eq!(
+        fix("```a\nb\n```\n\n~~~~c\nd\n~~~~\n\n~~~\ne", &options),
+        "```a\nb\n```\n\n````c\nd\n````\n\n```\ne",
+        "should fix inconsistent fences"
+    );
+
+    assert_eq!(
+        fix("```\na\n```\n\n~~~b`c\nd\n~~~", &options),
+        "```\na\n```\n\n~~~b`c\nd\n~~~",
+        "should not fix fences whose info contains grave accents"
+    );
+
+    assert_eq!(
+        messages("```\na\n```\n\n~~~b`c\nd\n~~~", &options),
+        vec!["5:1-5:4: Unexpected code fence marker `~`, expected `` ` `` (markdown-rs:code-fence-style)"],
+        "should still warn for fences whose info contains grave accents"
+    );
+}
+
+#[test]
+fn lint_undefined_references() {
+    let options = only("reference-links-images");
+
+    assert_eq!(
+        messages("[a][b] ![c][] [d][e]\n\n[e]: f", &options),
+        vec![
+            "1:1-1:7: Unexpected reference to undefined definition `b` (markdown-rs:reference-links-images)",
+            "1:9-1:14: Unexpected reference to undefined definition `c` (markdown-rs:reference-links-images)",
+        ],
+        "should warn for references to undefined definitions"
+    );
+
+    assert_eq!(
+        messages("[*a* b][]\n\n# c [D  E][]\n\n[d e]: f", &options),
+        vec!["1:1-1:10: Unexpected reference to undefined definition `*a* b` (markdown-rs:reference-links-images)"],
+        "should support phrasing in labels, and match labels like identifiers"
+    );
+
+    assert_eq!(
+        messages(
+            "[a] \\[b][c] `[d][e]` [f](g[h][i]) [j][ ] [k\n\nl][m]",
+            &options
+        ),
+        Vec::<String>::new(),
+        "should not warn for shortcut references, escapes, code, destinations, blank labels, or across paragraphs"
+    );
+
+    let gfm = LintOptions {
+        parse: ParseOptions::gfm(),
+        ..only("reference-links-images")
+    };
+
+    assert_eq!(
+        messages("a[^b] c[^d] [e][^f]\n\n[^d]: g", &gfm),
+        vec![
+            "1:2-1:6: Unexpected call to undefined footnote definition `b` (markdown-rs:reference-links-images)",
+            "1:13-1:20: Unexpected reference to undefined definition `^f` (markdown-rs:reference-links-images)",
+        ],
+        "should warn for calls to undefined footnote definitions"
+    );
+
+    assert_eq!(
+        messages("a[^b]", &options),
+        Vec::<String>::new(),
+        "should not warn for footnote calls without GFM"
+    );
+
+    assert_eq!(
+        messages("| [a][b] |\n| - |\n| [^c] |", &gfm).len(),
+        2,
+        "should warn in tables"
+    );
+}
+
+#[test]
+fn lint_unused_definitions() {
+    let options = only("link-image-reference-definitions");
+
+    assert_eq!(
+        messages("[a] [B][] [c][d] ![e]\n\n[a]: f\n[b]: g\n[d]: h\n[e]: i\n[j]: k\n[A]: l", &options),
+        vec![
+            "7:1-7:7: Unexpected unused definition `j` (markdown-rs:link-image-reference-definitions)",
+            "8:1-8:7: Unexpected duplicate definition `A` (markdown-rs:link-image-reference-definitions)",
+        ],
+        "should warn for unused and duplicate definitions"
+    );
+
+    assert_eq!(
+        fix("a\n\n[b]: c\n[d]: e\n\n> [f]: g\n\n[d]", &options),
+        "a\n\n[d]: e\n\n> [f]: g\n\n[d]",
+        "should fix definitions on their own lines"
+    );
+
+    assert_eq!(
+        messages("[a](b) [c]\n\n[a]: d\n\n[//]: # (e)", &options),
+        vec!["3:1-3:7: Unexpected unused definition `a` (markdown-rs:link-image-reference-definitions)"],
+        "should not count resources, and ignore comments"
+    );
+
+    assert_eq!(
+        messages("[![a][b]][c]\n\n[b]: d\n[c]: e", &options),
+        Vec::<String>::new(),
+        "should support references in references"
+    );
+
+    let gfm = LintOptions {
+        parse: ParseOptions::gfm(),
+        ..only("link-image-reference-definitions")
+    };
+
+    assert_eq!(
+        messages("a[^b]\n\n[^b]: c[^d]\n\n[^d]: e\n\n[^f]: g\n    h", &gfm),
+        vec!["7:1-8:6: Unexpected unused footnote definition `f` (markdown-rs:link-image-reference-definitions)"],
+        "should warn for unused footnote definitions"
+    );
+
+    assert_eq!(
+        fix("a\n\n[^f]: g\n    h\n\ni", &gfm),
+        "a\n\ni",
+        "should fix footnote definitions"
+    );
+}
@@ -0,0 +1,64 @@
+use markdown::{message, to_man, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn man() -> Result<(), message::Message> {
+    assert_eq!(
+        to_man("", &ParseOptions::default())?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_man("# a(1) -- b-c\n\n## d", &ParseOptions::default())?,
+        ".TH \"A\" \"1\"\n.SH NAME\na \\- b\\-c\n.SH \"d\"\n",
+        "should support a ronn-style title"
+    );
+
+    assert_eq!(
+        to_man("# a \"b\"\n\n### c", &ParseOptions::default())?,
+        ".TH \"a \\(dqb\\(dq\"\n.SS \"c\"\n",
+        "should support other titles, and subsections"
+    );
+
+    assert_eq!(
+        to_man("a\n.b\n'c \\d", &ParseOptions::default())?,
+        ".PP\na\n\\&.b\n\\&'c \\ed\n",
+        "should escape periods and apostrophes at line starts, and backslashes"
+    );
+
+    assert_eq!(
+        to_man("*a **b*** `c`  \nd", &ParseOptions::default())?,
+        ".PP\n\\f[I]a \\f[BI]b\\f[I]\\f[R] \\f[CR]c\\f[R]\n.br\nd\n",
+        "should support fonts and breaks"
+    );
+
+    assert_eq!(
+        to_man(
+            "[a](b) <https://c>\n\n[d]\n\n[d]: e",
+            &ParseOptions::default()
+        )?,
+        ".PP\na <b> https://c\n.PP\nd <e>\n",
+        "should support links"
+    );
+
+    assert_eq!(
+        to_man("* a\n\n  b\n2. c", &ParseOptions::default())?,
+        ".IP \\(bu 2\na\n.RS\n.PP\nb\n.RE\n.IP \"2.\" 4\nc\n",
+        "should support lists"
+    );
+
+    assert_eq!(
+        to_man("> a\n\n    b\n\n***", &ParseOptions::default())?,
+        ".RS\n.PP\na\n.RE\n.IP\n.nf\n\\f[CR]\nb\n\\f[R]\n.fi\n.PP\n.ce\n* * *\n",
+        "should support block quotes, code, and thematic breaks"
+    );
+
+    assert_eq!(
+        to_man("| a | b |\n| - | :-: |\n| c |", &ParseOptions::gfm())?,
+        ".PP\n.TS\ntab(@);\nl c.\nT{\n\\f[B]a\\f[R]\nT}@T{\n\\f[B]b\\f[R]\nT}\n_\nT{\nc\nT}\n.TE\n",
+        "should support tables"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use markdown::{message, to_markdown_it, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn markdown_it() -> Result<(), message::Message> {
+    assert_eq!(
+        to_markdown_it("", &ParseOptions::default())?,
+        "[]",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_markdown_it("*a*", &ParseOptions::default())?,
+        r#"[{"type":"paragraph_open","tag":"p","attrs":null,"map":[0,1],"nesting":1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"inline","tag":"","attrs":null,"map":[0,1],"nesting":0,"level":1,"children":[{"type":"em_open","tag":"em","attrs":null,"map":null,"nesting":1,"level":0,"children":null,"content":"","markup":"*","info":"","meta":null,"block":false,"hidden":false},{"type":"text","tag":"","attrs":null,"map":null,"nesting":0,"level":1,"children":null,"content":"a","markup":"","info":"","meta":null,"block":false,"hidden":false},{"type":"em_close","tag":"em","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":"*","info":"","meta":null,"block":false,"hidden":false}],"content":"*a*","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_close","tag":"p","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false}]"#,
+        "should support paragraphs, with inline tokens as children"
+    );
+
+    assert_eq!(
+        to_markdown_it("- a", &ParseOptions::default())?,
+        r#"[{"type":"bullet_list_open","tag":"ul","attrs":null,"map":[0,1],"nesting":1,"level":0,"children":null,"content":"","markup":"-","info":"","meta":null,"block":true,"hidden":false},{"type":"list_item_open","tag":"li","attrs":null,"map":[0,1],"nesting":1,"level":1,"children":null,"content":"","markup":"-","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_open","tag":"p","attrs":null,"map":[0,1],"nesting":1,"level":2,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":true},{"type":"inline","tag":"","attrs":null,"map":[0,1],"nesting":0,"level":3,"children":[{"type":"text","tag":"","attrs":null,"map":null,"nesting":0,"level":0,"children":null,"content":"a","markup":"","info":"","meta":null,"block":false,"hidden":false}],"content":"a","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_close","tag":"p","attrs":null,"map":null,"nesting":-1,"level":2,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":true},{"type":"list_item_close","tag":"li","attrs":null,"map":null,"nesting":-1,"level":1,"children":null,"content":"","markup":"-","info":"","meta":null,"block":true,"hidden":false},{"type":"bullet_list_close","tag":"ul","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":"-","info":"","meta":null,"block":true,"hidden":false}]"#,
+        "should support lists, hiding paragraphs in tight lists"
+    );
+
+    assert_eq!(
+        to_markdown_it("```js\nb\n```", &ParseOptions::default())?,
+        r#"[{"type":"fence","tag":"code","attrs":null,"map":[0,3],"nesting":0,"level":0,"children":null,"content":"b\n","markup":"```","info":"js","meta":null,"block":true,"hidden":false}]"#,
+        "should support code (fenced)"
+    );
+
+    assert_eq!(
+        to_markdown_it("    a", &ParseOptions::default())?,
+        r#"[{"type":"code_block","tag":"code","attrs":null,"map":[0,1],"nesting":0,"level":0,"children":null,"content":"a\n","markup":"","info":"","meta":null,"block":true,"hidden":false}]"#,
+        "should support code (indented)"
+    );
+
+    assert_eq!(
+        to_markdown_it("[a](b \"c\")", &ParseOptions::default())?,
+        r#"[{"type":"paragraph_open","tag":"p","attrs":null,"map":[0,1],"nesting":1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"inline","tag":"","attrs":null,"map":[0,1],"nesting":0,"level":1,"children":[{"type":"link_open","tag":"a","attrs":[["href","b"],["title","c"]],"map":null,"nesting":1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":false,"hidden":false},{"type":"text","tag":"","attrs":null,"map":null,"nesting":0,"level":1,"children":null,"content":"a","markup":"","info":"","meta":null,"block":false,"hidden":false},{"type":"link_close","tag":"a","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":false,"hidden":false}],"content":"[a](b \"c\")","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_close","tag":"p","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false}]"#,
+        "should support links, escaping JSON strings"
+    );
+
+    assert_eq!(
+        to_markdown_it("> a\n> b", &ParseOptions::default())?,
+        r#"[{"type":"blockquote_open","tag":"blockquote","attrs":null,"map":[0,2],"nesting":1,"level":0,"children":null,"content":"","markup":">","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_open","tag":"p","attrs":null,"map":[0,2],"nesting":1,"level":1,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"inline","tag":"","attrs":null,"map":[0,2],"nesting":0,"level":2,"children":[{"type":"text","tag":"","attrs":null,"map":null,"nesting":0,"level":0,"children":null,"content":"a","markup":"","info":"","meta":null,"block":false,"hidden":false},{"type":"softbreak","tag":"br","attrs":null,"map":null,"nesting":0,"level":0,"children":null,"content":"","markup":"","info":"","meta":null,"block":false,"hidden":false},{"type":"text","tag":"","attrs":null,"map":null,"nesting":0,"level":0,"children":null,"content":"b","markup":"","info":"","meta":null,"block":false,"hidden":false}],"content":"a\nb","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"paragraph_close","tag":"p","attrs":null,"map":null,"nesting":-1,"level":1,"children":null,"content":"","markup":"","info":"","meta":null,"block":true,"hidden":false},{"type":"blockquote_close","tag":"blockquote","attrs":null,"map":null,"nesting":-1,"level":0,"children":null,"content":"","markup":">","info":"","meta":null,"block":true,"hidden":false}]"#,
+        "should support block quotes, without prefixes in inline content"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use markdown::{message, outline, unist::Position, ParseOptions, Symbol, SymbolKind};
+use pretty_assertions::assert_eq;
+
+/// Get the kinds, names, and nesting of symbols, as one string.
+fn shape(symbols: &[Symbol]) -> String {
+    symbols
+        .iter()
+        .map(|d| {
+            let kind = match d.kind {
+                SymbolKind::Heading(rank) => format!("h{}", rank),
+                SymbolKind::Definition => "def".into(),
+                SymbolKind::FootnoteDefinition => "fn".into(),
+            };
+
+            if d.children.is_empty() {
+                format!("{} {}", kind, d.name)
+            } else {
+                format!("{} {} ({})", kind, d.name, shape(&d.children))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[test]
+fn outline_shape() -> Result<(), message::Message> {
+    assert_eq!(
+        outline("", &ParseOptions::default())?,
+        vec![],
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        outline("a\n\n* b", &ParseOptions::default())?,
+        vec![],
+        "should support documents without symbols"
+    );
+
+    assert_eq!(
+        shape(&outline(
+            "# a\n\n## b\n\n### c\n\n## d\n\n# e\n\n### f\n\n## g",
+            &ParseOptions::default()
+        )?),
+        "h1 a (h2 b (h3 c), h2 d), h1 e (h3 f, h2 g)",
+        "should nest headings by rank, also when ranks are skipped"
+    );
+
+    assert_eq!(
+        shape(&outline(
+            "## a\n\n# b\n\nc\n===\n\nd\n-",
+            &ParseOptions::default()
+        )?),
+        "h2 a, h1 b, h1 c (h2 d)",
+        "should support setext headings and headings deeper than later ones"
+    );
+
+    assert_eq!(
+        shape(&outline(
+            "[a]: b\n\n# *c* `d`\n\n> [E f]: g\n\n## h",
+            &ParseOptions::default()
+        )?),
+        "def a, h1 c d (def E f, h2 h)",
+        "should support definitions, with plain text names of headings"
+    );
+
+    assert_eq!(
+        shape(&outline(
+            "# a\n\n[^b]: c\n\n    ## d\n\n    [e]: f\n\n[g]: h\n\n## i",
+            &ParseOptions::gfm()
+        )?),
+        "h1 a (fn b (h2 d (def e)), def g, h2 i)",
+        "should support footnote definitions, and end headings in them"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn outline_ranges() -> Result<(), message::Message> {
+    let symbols = outline("# a\n\nb\n\n## c\n\nd\n\n\n# e", &ParseOptions::default())?;
+
+    assert_eq!(
+        symbols[0].range,
+        Position::new(1, 1, 0, 7, 2, 15),
+        "should span sections, without trailing whitespace"
+    );
+
+    assert_eq!(
+        symbols[0].selection_range,
+        Position::new(1, 3, 2, 1, 4, 3),
+        "should select the text of headings"
+    );
+
+    assert_eq!(
+        symbols[0].children[0].range,
+        Position::new(5, 1, 8, 7, 2, 15),
+        "should span sections of nested headings"
+    );
+
+    assert_eq!(
+        symbols[1].range,
+        Position::new(10, 1, 18, 10, 4, 21),
+        "should span sections until the end"
+    );
+
+    let symbols = outline("a\n===\n\n[b]: c 'd'", &ParseOptions::default())?;
+
+    assert_eq!(
+        symbols[0].selection_range,
+        Position::new(1, 1, 0, 1, 2, 1),
+        "should select the text of setext headings"
+    );
+
+    assert_eq!(
+        (
+            symbols[0].children[0].range.clone(),
+            symbols[0].children[0].selection_range.clone()
+        ),
+        (
+            Position::new(4, 1, 7, 4, 11, 17),
+            Position::new(4, 2, 8, 4, 3, 9)
+        ),
+        "should span definitions, and select their labels"
+    );
+
+    let symbols = outline("#", &ParseOptions::default())?;
+
+    assert_eq!(
+        (symbols[0].name.as_str(), symbols[0].selection_range.clone()),
+        ("", Position::new(1, 1, 0, 1, 2, 1)),
+        "should select empty headings"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use markdown::{message, to_html, to_html_with_options, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pathological() -> Result<(), message::Message> {
+    let size = 50_000;
+
+    assert_eq!(
+        to_html(&format!("{}a", "[".repeat(size))),
+        format!("<p>{}a</p>", "[".repeat(size)),
+        "should support many unmatched label starts"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}{}", "[".repeat(size), "[a](b)".repeat(size))),
+        format!(
+            "<p>{}{}</p>",
+            "[".repeat(size),
+            "<a href=\"b\">a</a>".repeat(size)
+        ),
+        "should support many links after many unmatched label starts"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}a{}", "[".repeat(size), "]".repeat(size))),
+        format!("<p>{}a{}</p>", "[".repeat(size), "]".repeat(size)),
+        "should support many nested labels"
+    );
+
+    assert_eq!(
+        to_html(&format!("{}[a](b)", "![".repeat(size))),
+        format!("<p>{}<a href=\"b\">a</a></p>", "![".repeat(size)),
+        "should support many unmatched label (image) starts"
+    );
+
+    assert_eq!(
+        to_html("[[[a](b)](c)](d)"),
+        "<p>[[<a href=\"b\">a</a>](c)](d)</p>",
+        "should support a few label starts by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a ![b ![c](d)](e)](f)",
+            &Options {
+                parse: ParseOptions {
+                    label_start_count_max: 2,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>[a <img src=\"e\" alt=\"b c\" />](f)</p>",
+        "should turn the earliest label starts into data if there are too many (`label_start_count_max`)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b) ![c](d)",
+            &Options {
+                parse: ParseOptions {
+                    label_start_count_max: 0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>[a](b) ![c](d)</p>",
+        "should not form labels w/ `label_start_count_max: 0`"
+    );
+
+    Ok(())
+}
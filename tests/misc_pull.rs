@@ -0,0 +1,96 @@
+use markdown::{
+    event::{Kind, Name},
+    message,
+    unist::Position,
+    ParseOptions, Parser,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pull() -> Result<(), message::Message> {
+    let parser = Parser::new("# a *b*\n\nc", &ParseOptions::default())?;
+
+    assert_eq!(
+        parser
+            .iter()
+            .map(|d| (d.kind, d.name, d.value))
+            .collect::<Vec<_>>(),
+        vec![
+            (Kind::Enter, Name::HeadingAtx, "# a *b*"),
+            (Kind::Enter, Name::HeadingAtxSequence, "#"),
+            (Kind::Exit, Name::HeadingAtxSequence, "#"),
+            (Kind::Enter, Name::SpaceOrTab, " "),
+            (Kind::Exit, Name::SpaceOrTab, " "),
+            (Kind::Enter, Name::HeadingAtxText, "a *b*"),
+            (Kind::Enter, Name::Data, "a "),
+            (Kind::Exit, Name::Data, "a "),
+            (Kind::Enter, Name::Emphasis, "*b*"),
+            (Kind::Enter, Name::EmphasisSequence, "*"),
+            (Kind::Exit, Name::EmphasisSequence, "*"),
+            (Kind::Enter, Name::EmphasisText, "b"),
+            (Kind::Enter, Name::Data, "b"),
+            (Kind::Exit, Name::Data, "b"),
+            (Kind::Exit, Name::EmphasisText, "b"),
+            (Kind::Enter, Name::EmphasisSequence, "*"),
+            (Kind::Exit, Name::EmphasisSequence, "*"),
+            (Kind::Exit, Name::Emphasis, "*b*"),
+            (Kind::Exit, Name::HeadingAtxText, "a *b*"),
+            (Kind::Exit, Name::HeadingAtx, "# a *b*"),
+            (Kind::Enter, Name::LineEnding, "\n"),
+            (Kind::Exit, Name::LineEnding, "\n"),
+            (Kind::Enter, Name::BlankLineEnding, "\n"),
+            (Kind::Exit, Name::BlankLineEnding, "\n"),
+            (Kind::Enter, Name::Paragraph, "c"),
+            (Kind::Enter, Name::Data, "c"),
+            (Kind::Exit, Name::Data, "c"),
+            (Kind::Exit, Name::Paragraph, "c"),
+        ],
+        "should yield events with the markdown they span"
+    );
+
+    let paragraph = parser.iter().find(|d| d.name == Name::Paragraph).unwrap();
+
+    assert_eq!(
+        paragraph.position,
+        Position::new(3, 1, 9, 3, 2, 10),
+        "should yield positions"
+    );
+
+    assert_eq!(
+        parser.iter().len(),
+        28,
+        "should know how many events there are"
+    );
+
+    assert_eq!(
+        parser.iter().next_back().map(|d| (d.kind, d.name)),
+        Some((Kind::Exit, Name::Paragraph)),
+        "should support iterating from the back"
+    );
+
+    let mut count = 0;
+    for event in &parser {
+        if event.kind == Kind::Enter && event.name == Name::Data {
+            count += 1;
+        }
+    }
+
+    assert_eq!(count, 3, "should support `for` loops");
+
+    assert_eq!(
+        Parser::new("", &ParseOptions::default())?.iter().count(),
+        0,
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        match Parser::new("a <b {...", &ParseOptions::mdx()) {
+            Ok(_) => String::new(),
+            Err(message) => message.to_string(),
+        },
+        "1:10: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "should crash on invalid MDX"
+    );
+
+    Ok(())
+}
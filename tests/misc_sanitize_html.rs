@@ -0,0 +1,136 @@
+use markdown::{message, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sanitize_html() -> Result<(), message::Message> {
+    let sanitize = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            sanitize_html: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "<b>a</b>",
+            &Options {
+                compile: CompileOptions {
+                    sanitize_html: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;b&gt;a&lt;/b&gt;</p>",
+        "should do nothing without `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <b>c</b> <SUB>d</SUB> e<br/>f", &sanitize)?,
+        "<p>a <b>c</b> <sub>d</sub> e<br />f</p>",
+        "should keep allowed tags (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<details open>\n<summary>a</summary>\n\nb\n\n</details>",
+            &sanitize
+        )?,
+        "<details open>\n<summary>a</summary>\n<p>b</p>\n</details>",
+        "should keep allowed tags (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <x-y>b</x-y> <font color=red>c</font>", &sanitize)?,
+        "<p>a b c</p>",
+        "should drop other tags, keeping their content"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n<script>\nalert(1)\n</script><style>a{}</style>b\n</div>",
+            &sanitize
+        )?,
+        "<div>\nb\n</div>",
+        "should drop scripts and styles, with their content"
+    );
+
+    assert_eq!(
+        to_html_with_options("<script>\nalert(1)", &sanitize)?,
+        "",
+        "should drop unclosed scripts"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <!-- b --> <?c?> <!D> <![CDATA[e]]> f", &sanitize)?,
+        "<p>a     f</p>",
+        "should drop comments, instructions, declarations, and cdata"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<b class=a id='b' onclick=\"c()\" title=\"d &amp; &quot;e&quot;\" TITLE=f>g</b>",
+            &sanitize
+        )?,
+        "<p><b title=\"d &amp; &quot;e&quot;\">g</b></p>",
+        "should keep allowed attributes, once"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a href=\"https://a.com\">b</a> <a href=\"javascript:c()\">d</a> <a href=\"javascript&#58;e()\">f</a> <a href=\"./g\">h</a>",
+            &sanitize
+        )?,
+        "<p><a href=\"https://a.com\">b</a> <a href=\"\">d</a> <a href=\"\">f</a> <a href=\"./g\">h</a></p>",
+        "should sanitize `href`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<img src=\"https://a.com/b.png\" alt=\"c\" onerror=\"d()\"> <img src=\"mailto:e\">",
+            &sanitize
+        )?,
+        "<p><img src=\"https://a.com/b.png\" alt=\"c\"> <img src=\"\"></p>",
+        "should sanitize `src`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<blockquote cite=\"vbscript:a\">\n\nb\n\n</blockquote>",
+            &sanitize
+        )?,
+        "<blockquote cite=\"\">\n<p>b</p>\n</blockquote>",
+        "should sanitize `cite`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n<b title=\"a\nc\" x='<'>d</b>\n</div>", &sanitize)?,
+        "<div>\n<b title=\"a\nc\">d</b>\n</div>",
+        "should support attributes across lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\na < b <c\n</div>", &sanitize)?,
+        "<div>\na &lt; b &lt;c\n</div>",
+        "should encode other `<`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<iframe src=\"https://a.com\"></iframe>",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_tagfilter: true,
+                    ..sanitize.compile.clone()
+                }
+            }
+        )?,
+        "&lt;iframe src=\"https://a.com\">&lt;/iframe>",
+        "should work with `gfm_tagfilter`"
+    );
+
+    Ok(())
+}
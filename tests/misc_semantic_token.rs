@@ -0,0 +1,262 @@
+use markdown::{message, semantic_tokens, ColumnUnit, Constructs, ParseOptions, SemanticTokenKind};
+use pretty_assertions::assert_eq;
+
+/// Get the kinds and text of tokens in `value`.
+fn tokens(value: &str, options: &ParseOptions) -> Result<Vec<String>, message::Message> {
+    Ok(semantic_tokens(value, options)?
+        .iter()
+        .map(|d| {
+            format!(
+                "{:?} {}",
+                d.kind,
+                &value[d.position.start.offset..d.position.end.offset]
+            )
+        })
+        .collect())
+}
+
+#[test]
+fn semantic_token() -> Result<(), message::Message> {
+    assert_eq!(
+        tokens("", &ParseOptions::default())?,
+        Vec::<String>::new(),
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        tokens("a b", &ParseOptions::default())?,
+        Vec::<String>::new(),
+        "should not include text"
+    );
+
+    assert_eq!(
+        tokens("## a *b* ##\n\nc\n-", &ParseOptions::default())?,
+        vec![
+            "HeadingMarker ##",
+            "HeadingText a ",
+            "EmphasisMarker *",
+            "HeadingText b",
+            "EmphasisMarker *",
+            "HeadingMarker ##",
+            "HeadingText c",
+            "HeadingMarker -"
+        ],
+        "should support headings"
+    );
+
+    assert_eq!(
+        tokens("> * a\n> 1. **b**\n\n***", &ParseOptions::default())?,
+        vec![
+            "BlockQuoteMarker >",
+            "ListItemMarker *",
+            "BlockQuoteMarker >",
+            "ListItemMarker 1",
+            "ListItemMarker .",
+            "EmphasisMarker **",
+            "EmphasisMarker **",
+            "ThematicBreak ***"
+        ],
+        "should support containers and thematic breaks"
+    );
+
+    assert_eq!(
+        tokens("```js b\nc\n```\n\n    d\n\n`e`", &ParseOptions::default())?,
+        vec![
+            "CodeMarker ```",
+            "CodeInfo js",
+            "CodeInfo b",
+            "Code c",
+            "CodeMarker ```",
+            "Code d",
+            "CodeMarker `",
+            "Code e",
+            "CodeMarker `"
+        ],
+        "should support code"
+    );
+
+    assert_eq!(
+        tokens(
+            "![a](<b> \"c\") [d][e] <ff:g>\n\n[e]: h 'i'",
+            &ParseOptions::default()
+        )?,
+        vec![
+            "LinkMarker !",
+            "LinkMarker [",
+            "LinkMarker ]",
+            "LinkMarker (",
+            "LinkMarker <",
+            "LinkDestination b",
+            "LinkMarker >",
+            "LinkMarker \"",
+            "LinkTitle c",
+            "LinkMarker \"",
+            "LinkMarker )",
+            "LinkMarker [",
+            "LinkMarker ]",
+            "LinkMarker [",
+            "LinkLabel e",
+            "LinkMarker ]",
+            "LinkMarker <",
+            "LinkDestination ff:g",
+            "LinkMarker >",
+            "LinkMarker [",
+            "LinkLabel e",
+            "LinkMarker ]",
+            "LinkMarker :",
+            "LinkDestination h",
+            "LinkMarker '",
+            "LinkTitle i",
+            "LinkMarker '"
+        ],
+        "should support links, images, and definitions"
+    );
+
+    assert_eq!(
+        tokens("\\* &amp; a\\\nb <c>\n\n<d>", &ParseOptions::default())?,
+        vec![
+            "Escape \\*",
+            "Escape &amp;",
+            "Escape \\",
+            "Html <c>",
+            "Html <d>"
+        ],
+        "should support escapes, references, and HTML"
+    );
+
+    assert_eq!(
+        tokens(
+            "* [x] a[^b] www.c.d ~e~\n\n| f |\n| :- |\n\n[^b]: g",
+            &ParseOptions::gfm()
+        )?,
+        vec![
+            "ListItemMarker *",
+            "ListItemMarker [x]",
+            "LinkMarker [",
+            "LinkMarker ^",
+            "LinkLabel b",
+            "LinkMarker ]",
+            "LinkDestination www.c.d",
+            "EmphasisMarker ~",
+            "EmphasisMarker ~",
+            "TableMarker |",
+            "TableMarker |",
+            "TableMarker |",
+            "TableMarker :",
+            "TableMarker -",
+            "TableMarker |",
+            "LinkMarker [",
+            "LinkMarker ^",
+            "LinkLabel b",
+            "LinkMarker ]",
+            "LinkMarker :"
+        ],
+        "should support GFM"
+    );
+
+    assert_eq!(
+        tokens(
+            "---\na: b\n---\n\n$$c\nd\n$$\n\n$e$",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    math_flow: true,
+                    math_text: true,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            }
+        )?,
+        vec![
+            "Frontmatter ---",
+            "Frontmatter a: b",
+            "Frontmatter ---",
+            "CodeMarker $$",
+            "CodeInfo c",
+            "Code d",
+            "CodeMarker $$",
+            "CodeMarker $",
+            "Code e",
+            "CodeMarker $"
+        ],
+        "should support frontmatter and math"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn semantic_token_lines() -> Result<(), message::Message> {
+    assert_eq!(
+        tokens(
+            "> [a\n> b]: c \"d\n> e\"\n> f `g\n> h`",
+            &ParseOptions::default()
+        )?,
+        vec![
+            "BlockQuoteMarker >",
+            "LinkMarker [",
+            "LinkLabel a",
+            "BlockQuoteMarker >",
+            "LinkLabel b",
+            "LinkMarker ]",
+            "LinkMarker :",
+            "LinkDestination c",
+            "LinkMarker \"",
+            "LinkTitle d",
+            "BlockQuoteMarker >",
+            "LinkTitle e",
+            "LinkMarker \"",
+            "BlockQuoteMarker >",
+            "CodeMarker `",
+            "Code g",
+            "BlockQuoteMarker >",
+            "Code h",
+            "CodeMarker `"
+        ],
+        "should split tokens at line endings, without container markers"
+    );
+
+    assert_eq!(
+        tokens("<a\nb>\n\nc <d\ne>", &ParseOptions::default())?,
+        vec!["Html <a", "Html b>", "Html <d", "Html e>"],
+        "should split HTML at line endings"
+    );
+
+    let tokens = semantic_tokens("é *a*\n\t*b*", &ParseOptions::default())?;
+
+    assert_eq!(
+        (tokens[0].kind, tokens[0].position.start.column),
+        (SemanticTokenKind::EmphasisMarker, 4),
+        "should count columns in bytes by default"
+    );
+
+    assert_eq!(
+        tokens[2].position.start.column, 5,
+        "should count tabs as the spaces they expand to by default"
+    );
+
+    let tokens = semantic_tokens(
+        "é *a*\n\t*b*",
+        &ParseOptions {
+            column_unit: ColumnUnit::Utf16,
+            ..ParseOptions::default()
+        },
+    )?;
+
+    assert_eq!(
+        (
+            tokens[0].position.start.column,
+            tokens[0].position.end.column,
+            tokens[0].position.start.offset
+        ),
+        (3, 4, 3),
+        "should support `column_unit`"
+    );
+
+    assert_eq!(
+        tokens[2].position.start.column, 2,
+        "should support `column_unit` with tabs"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use markdown::{
+    mdast::{DestinationKind, Link, Node, Paragraph, Root, Text},
+    message, to_html_with_options, to_mdast, to_mrkdwn,
+    unist::Position,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn slack() -> Result<(), message::Message> {
+    let slack = Options::slack();
+
+    assert_eq!(
+        to_html_with_options("*a* _b_ ~c~", &slack)?,
+        "<p><strong>a</strong> <em>b</em> <del>c</del></p>",
+        "should support slack phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("**a** __b__ ***c***", &slack)?,
+        "<p><strong>a</strong> <strong>b</strong> <strong><strong>c</strong></strong></p>",
+        "should support double markers as strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com|a *b*>", &slack)?,
+        "<p><a href=\"https://example.com\">a *b*</a></p>",
+        "should support labels in autolinks, as text"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com>", &slack)?,
+        "<p><a href=\"https://example.com\">https://example.com</a></p>",
+        "should support autolinks w/o label"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com|>", &slack)?,
+        "<p>&lt;https://example.com|&gt;</p>",
+        "should not support an empty label"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com|a\nb>", &slack)?,
+        "<p>&lt;https://example.com|a<br />\nb&gt;</p>",
+        "should not support a line ending in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://example.com|a<b>", &slack)?,
+        "<p>&lt;https://example.com|a&lt;b&gt;</p>",
+        "should not support a `<` in a label"
+    );
+
+    assert_eq!(
+        to_html_with_options("<a@example.com|b>", &slack)?,
+        "<p>&lt;a@example.com|b&gt;</p>",
+        "should not support labels in email autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n![b](c)", &slack)?,
+        "<p># a</p>\n<p>!<a href=\"c\">b</a></p>",
+        "should not support headings or images"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb", &slack)?,
+        "<p>a<br />\nb</p>",
+        "should turn soft line breaks into hard breaks"
+    );
+
+    assert_eq!(
+        to_mdast("<https://example.com|a>", &ParseOptions::slack())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    url: "https://example.com".into(),
+                    title: None,
+                    destination_kind: DestinationKind::Raw,
+                    title_kind: None,
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 22, 21, 1, 23, 22))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 24, 23))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 24, 23))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 24, 23))
+        }),
+        "should support labels in autolinks (mdast)"
+    );
+
+    assert_eq!(
+        to_mrkdwn(
+            "# a\n\n**b** *c* ~~d~~ `e` [f](https://example.com) <https://example.com>",
+            &ParseOptions::gfm()
+        )?,
+        "*a*\n\n*b* _c_ ~d~ `e` <https://example.com|f> <https://example.com>",
+        "should serialize phrasing and headings as mrkdwn"
+    );
+
+    assert_eq!(
+        to_mrkdwn("a < b & c > d\n\n```js\n<e>\n```", &ParseOptions::default())?,
+        "a &lt; b &amp; c &gt; d\n\n```\n&lt;e&gt;\n```",
+        "should escape control characters, in text and code"
+    );
+
+    assert_eq!(
+        to_mrkdwn(
+            "[a|b](https://example.com) [c & <d>](https://example.com/?e&f|g)",
+            &ParseOptions::default()
+        )?,
+        "<https://example.com|a¦b> <https://example.com/?e&amp;f%7Cg|c &amp; &lt;d&gt;>",
+        "should escape control characters and pipes in links"
+    );
+
+    assert_eq!(
+        to_mrkdwn("> a\n>\n> b\n\n***", &ParseOptions::default())?,
+        "> a\n>\n> b\n\n---",
+        "should serialize block quotes and thematic breaks"
+    );
+
+    assert_eq!(
+        to_mrkdwn(
+            "* a\n* b\n\n  c\n\n3. d\n4. e\n\n* [x] f\n* [ ] g",
+            &ParseOptions::gfm()
+        )?,
+        "• a\n• b\n\n  c\n\n3. d\n4. e\n\n• [x] f\n• [ ] g",
+        "should serialize lists"
+    );
+
+    assert_eq!(
+        to_mrkdwn(
+            "[a][b] ![c][b] ![d](e) [f][g]\n\n[b]: https://example.com",
+            &ParseOptions::default()
+        )?,
+        "<https://example.com|a> <https://example.com|c> <e|d> [f][g]",
+        "should serialize references and images as links, and keep undefined references"
+    );
+
+    assert_eq!(
+        to_mrkdwn("| a | b |\n| - | - |\n| c | d |", &ParseOptions::gfm())?,
+        "a | b\nc | d",
+        "should serialize tables as lines"
+    );
+
+    assert_eq!(
+        to_mrkdwn("a\\\nb", &ParseOptions::default())?,
+        "a\nb",
+        "should serialize hard breaks"
+    );
+
+    assert_eq!(
+        to_mrkdwn("*a* <https://example.com|b>", &ParseOptions::slack())?,
+        "*a* <https://example.com|b>",
+        "should roundtrip mrkdwn"
+    );
+
+    Ok(())
+}
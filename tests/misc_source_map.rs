@@ -0,0 +1,100 @@
+use markdown::{
+    event::Name, message, to_html_with_options, to_html_with_source_map, Options, ParseOptions,
+    SourceMapping,
+};
+use pretty_assertions::assert_eq;
+
+/// Turn a source map into what each event is in markdown and in HTML.
+fn pieces<'a>(
+    value: &'a str,
+    html: &'a str,
+    map: &[SourceMapping],
+) -> Vec<(Name, &'a str, &'a str)> {
+    map.iter()
+        .map(|d| {
+            (
+                d.name.clone(),
+                &value[d.source_start..d.source_end],
+                &html[d.start..d.end],
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn source_map() -> Result<(), message::Message> {
+    let value = "# a\n\nb *c*";
+    let (html, map) = to_html_with_source_map(value, &Options::default())?;
+
+    assert_eq!(
+        html,
+        to_html_with_options(value, &Options::default())?,
+        "should compile like normal"
+    );
+
+    assert_eq!(
+        pieces(value, &html, &map),
+        vec![
+            (Name::HeadingAtxSequence, "#", "<h1>"),
+            (Name::HeadingAtxText, "a", "a"),
+            (Name::HeadingAtx, "# a", "<h1>a</h1>"),
+            (Name::Data, "b ", "b "),
+            (Name::Data, "c", "c"),
+            (Name::EmphasisText, "c", "c"),
+            (Name::Emphasis, "*c*", "<em>c</em>"),
+            (Name::Paragraph, "b *c*", "<p>b <em>c</em></p>"),
+        ],
+        "should map events to HTML, in the order they end"
+    );
+
+    let value = "a\n===\n\n[b](c)\n\n[d]: e\n\n> f";
+    let (html, map) = to_html_with_source_map(value, &Options::default())?;
+
+    assert_eq!(
+        pieces(value, &html, &map),
+        vec![
+            (Name::HeadingSetextUnderlineSequence, "===", "<h1>a</h1>"),
+            (Name::HeadingSetextUnderline, "===", "<h1>a</h1>"),
+            (Name::HeadingSetext, "a\n===", "<h1>a</h1>"),
+            (Name::Link, "[b](c)", "<a href=\"c\">b</a>"),
+            (Name::Paragraph, "[b](c)", "<p><a href=\"c\">b</a></p>"),
+            (Name::Data, "f", "f"),
+            (Name::Paragraph, "f", "<p>f</p>"),
+            (
+                Name::BlockQuote,
+                "> f",
+                "<blockquote>\n<p>f</p>\n</blockquote>"
+            ),
+        ],
+        "should not map events that produce nothing or whose HTML is moved"
+    );
+
+    let value = "| a |\n| - |";
+    let (html, map) = to_html_with_source_map(
+        value,
+        &Options {
+            parse: ParseOptions::gfm(),
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(
+        map.last()
+            .map(|d| (&value[d.source_start..d.source_end], &html[d.start..d.end])),
+        Some((
+            "| a |\n| - |",
+            "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>"
+        )),
+        "should map constructs from extensions"
+    );
+
+    let (html, map) = to_html_with_source_map("", &Options::default())?;
+
+    assert_eq!(
+        (html.as_str(), map),
+        ("", vec![]),
+        "should support empty documents"
+    );
+
+    Ok(())
+}
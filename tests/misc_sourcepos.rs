@@ -0,0 +1,104 @@
+use markdown::{message, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sourcepos() -> Result<(), message::Message> {
+    let sourcepos = Options {
+        compile: CompileOptions {
+            sourcepos: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let inline = Options {
+        compile: CompileOptions {
+            sourcepos: true,
+            sourcepos_inline: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\nb", &Options::default())?,
+        "<p>a\nb</p>",
+        "should not add source positions by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n\n# c\n\nd\n-\n\n***", &sourcepos)?,
+        "<p data-sourcepos=\"1:1-2:1\">a\nb</p>\n<h1 data-sourcepos=\"4:1-4:3\">c</h1>\n<h2 data-sourcepos=\"6:1-7:1\">d</h2>\n<hr data-sourcepos=\"9:1-9:3\" />",
+        "should add source positions to paragraphs, headings, and thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> > b\n\nc", &sourcepos)?,
+        "<blockquote data-sourcepos=\"1:1-2:5\">\n<p data-sourcepos=\"1:3-1:3\">a</p>\n<blockquote data-sourcepos=\"2:3-2:5\">\n<p data-sourcepos=\"2:5-2:5\">b</p>\n</blockquote>\n</blockquote>\n<p data-sourcepos=\"4:1-4:1\">c</p>",
+        "should add source positions to nested block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n* b\n\n1. c\n\n   d", &sourcepos)?,
+        "<ul data-sourcepos=\"1:1-2:3\">\n<li data-sourcepos=\"1:1-1:3\">a</li>\n<li data-sourcepos=\"2:1-2:3\">b</li>\n</ul>\n<ol data-sourcepos=\"4:1-6:4\">\n<li data-sourcepos=\"4:1-6:4\">\n<p data-sourcepos=\"4:4-4:4\">c</p>\n<p data-sourcepos=\"6:4-6:4\">d</p>\n</li>\n</ol>",
+        "should add source positions to lists and list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\na\n```\n\n    b", &sourcepos)?,
+        "<pre data-sourcepos=\"1:1-3:3\"><code class=\"language-js\">a\n</code></pre>\n<pre data-sourcepos=\"5:1-5:5\"><code>b\n</code></pre>",
+        "should add source positions to code"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n| b |",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: sourcepos.compile.clone()
+            }
+        )?,
+        "<table data-sourcepos=\"1:1-3:5\">\n<thead>\n<tr data-sourcepos=\"1:1-1:5\">\n<th data-sourcepos=\"1:1-1:5\">a</th>\n</tr>\n</thead>\n<tbody>\n<tr data-sourcepos=\"3:1-3:5\">\n<td data-sourcepos=\"3:1-3:5\">b</td>\n</tr>\n</tbody>\n</table>",
+        "should add source positions to tables, rows, and cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* **b** `c` [d](e) ![f](g) <https://h>", &sourcepos)?,
+        "<p data-sourcepos=\"1:1-1:40\"><em>a</em> <strong>b</strong> <code>c</code> <a href=\"e\">d</a> <img src=\"g\" alt=\"f\" /> <a href=\"https://h\">https://h</a></p>",
+        "should not add source positions to inline elements by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* **b** `c` [d](e) ![f](g) <https://h>", &inline)?,
+        "<p data-sourcepos=\"1:1-1:40\"><em data-sourcepos=\"1:1-1:3\">a</em> <strong data-sourcepos=\"1:5-1:9\">b</strong> <code data-sourcepos=\"1:11-1:13\">c</code> <a href=\"e\" data-sourcepos=\"1:15-1:20\">d</a> <img src=\"g\" alt=\"f\" data-sourcepos=\"1:22-1:28\" /> <a href=\"https://h\" data-sourcepos=\"1:30-1:40\">https://h</a></p>",
+        "should add source positions to inline elements with `sourcepos_inline`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "~a~ www.b.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: inline.compile.clone()
+            }
+        )?,
+        "<p data-sourcepos=\"1:1-1:13\"><del data-sourcepos=\"1:1-1:3\">a</del> <a href=\"http://www.b.com\" data-sourcepos=\"1:5-1:13\">www.b.com</a></p>",
+        "should add source positions to GFM inline elements"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a*",
+            &Options {
+                compile: CompileOptions {
+                    sourcepos_inline: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><em>a</em></p>",
+        "should do nothing with only `sourcepos_inline`"
+    );
+
+    Ok(())
+}
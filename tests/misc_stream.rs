@@ -0,0 +1,131 @@
+#![cfg(feature = "std")]
+
+use markdown::{event::Name, message, to_events, Chunk, ParseOptions, Stream};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn stream() -> Result<(), message::Message> {
+    /// Reader that reads one byte at a time.
+    struct Slow<'a>(&'a [u8]);
+
+    impl std::io::Read for Slow<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            let size = self.0.len().min(buffer.len()).min(1);
+            buffer[..size].copy_from_slice(&self.0[..size]);
+            self.0 = &self.0[size..];
+            Ok(size)
+        }
+    }
+
+    /// Get the events as strings, to compare them.
+    fn show(events: &[markdown::event::Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|d| {
+                format!(
+                    "{:?} {:?} {}:{}:{}",
+                    d.kind, d.name, d.point.line, d.point.column, d.point.index
+                )
+            })
+            .collect()
+    }
+
+    let options = ParseOptions::gfm();
+
+    assert_eq!(
+        Stream::new(Slow(b""), &options).count(),
+        0,
+        "should support empty input"
+    );
+
+    let value = "# a\n\n* b\n\n  c\n\n> d\ne\n\n[f]: g\n\n```\nh\n\n```\n\n    i\n\n    j\n\n[^k]: l\n\n[f] [^k]\n\nm";
+    let chunks =
+        Stream::new(Slow(value.as_bytes()), &options).collect::<Result<Vec<Chunk>, _>>()?;
+
+    assert_eq!(
+        chunks.iter().map(|d| d.value.as_str()).collect::<Vec<_>>(),
+        vec![
+            "# a\n\n",
+            "* b\n\n  c\n\n",
+            "> d\ne\n\n",
+            "[f]: g\n\n",
+            "```\nh\n\n```\n\n",
+            "    i\n\n    j\n\n",
+            "[^k]: l\n\n",
+            "[f] [^k]\n\nm"
+        ],
+        "should hand out blocks when they are done"
+    );
+
+    assert_eq!(
+        chunks.iter().map(|d| d.offset).collect::<Vec<_>>(),
+        vec![0, 5, 15, 22, 30, 42, 56, 65],
+        "should hand out where chunks are"
+    );
+
+    assert_eq!(
+        show(
+            &chunks
+                .iter()
+                .flat_map(|d| d.events.clone())
+                .collect::<Vec<_>>()
+        ),
+        show(&to_events(value, &options)?),
+        "should hand out the events of the whole document"
+    );
+
+    let chunks = Stream::new(Slow(b"[a]\n\nb\n\n[a]: c\n\nd\n"), &options)
+        .collect::<Result<Vec<Chunk>, _>>()?;
+
+    assert!(
+        !chunks[0].events.iter().any(|d| d.name == Name::Link),
+        "should not find references to definitions in later chunks"
+    );
+
+    assert_eq!(
+        Stream::new(Slow(b"a\n\n\xff\n\nb"), &options)
+            .map(|d| d.map_err(|d| d.to_string()).map(|d| d.value))
+            .collect::<Vec<_>>(),
+        vec![Err(
+            "Cannot read markdown: invalid UTF-8 at offset 3 (markdown-rs:read)".into()
+        )],
+        "should crash on invalid UTF-8"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stream_large() -> Result<(), message::Message> {
+    let value = "2024-01-01 00:00:00 INFO a *b* `c`\n".repeat(8 * 1024);
+    let chunks = Stream::new(value.as_bytes(), &ParseOptions::default())
+        .collect::<Result<Vec<Chunk>, _>>()?;
+
+    assert_eq!(
+        chunks.iter().map(|d| d.value.len()).collect::<Vec<_>>(),
+        vec![value.len()],
+        "should support large blocks w/o blank lines"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stream_io() {
+    /// Reader that always fails.
+    struct Failing;
+
+    impl std::io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("oops"))
+        }
+    }
+
+    assert_eq!(
+        Stream::new(Failing, &ParseOptions::default())
+            .map(|d| d.map(|d| d.value).map_err(|d| d.to_string()))
+            .collect::<Vec<_>>(),
+        vec![Err("Cannot read markdown: oops (markdown-rs:read)".into())],
+        "should crash with the error of the reader"
+    );
+}
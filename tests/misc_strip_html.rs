@@ -0,0 +1,66 @@
+use markdown::{message, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn strip_html() -> Result<(), message::Message> {
+    let strip = Options {
+        compile: CompileOptions {
+            strip_html: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a <b>c</b> <!--d--> e", &strip)?,
+        "<p>a c  e</p>",
+        "should drop HTML (text), keeping text between tags"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n*a*\n</div>", &strip)?,
+        "",
+        "should drop HTML (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n<div>\nb\n</div>\n\nc", &strip)?,
+        "<p>a</p>\n<p>c</p>",
+        "should drop HTML (flow) between other flow"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n\n*a*\n\n</div>", &strip)?,
+        "<p><em>a</em></p>\n",
+        "should keep markdown between HTML (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> <div>\n> a\n\n- <!--b-->", &strip)?,
+        "<blockquote>\n</blockquote>\n<ul>\n<li>\n</li>\n</ul>",
+        "should drop HTML (flow) in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a <b>c</b>](d)", &strip)?,
+        "<p><img src=\"d\" alt=\"a c\" /></p>",
+        "should drop HTML (text) in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <b>c</b>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..strip.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <b>c</b></p>",
+        "should prefer `allow_dangerous_html`"
+    );
+
+    Ok(())
+}
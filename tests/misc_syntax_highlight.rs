@@ -0,0 +1,91 @@
+#![cfg(feature = "syntect")]
+
+use markdown::{
+    message, to_html, to_html_with_options, CodeBlockHandler, CompileOptions, Options,
+    SyntaxHighlight, SyntaxHighlightStyle,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn highlight(syntax_highlight: SyntaxHighlight) -> Options {
+    Options {
+        compile: CompileOptions {
+            syntax_highlight: Some(syntax_highlight),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn syntax_highlight() -> Result<(), message::Message> {
+    let inline = highlight(SyntaxHighlight::default());
+    let classes = highlight(SyntaxHighlight {
+        style: SyntaxHighlightStyle::Classes,
+        ..SyntaxHighlight::default()
+    });
+
+    assert_eq!(
+        to_html("```rust\nfn\n```"),
+        "<pre><code class=\"language-rust\">fn\n</code></pre>",
+        "should not highlight code by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\nfn\n```", &inline)?,
+        "<pre><code class=\"language-rust\"><span style=\"font-weight:bold;color:#a71d5d;\">fn\n</span></code></pre>",
+        "should highlight code with inline styles"
+    );
+
+    assert_eq!(
+        to_html_with_options("```rust\nfn\n```", &classes)?,
+        "<pre><code class=\"language-rust\"><span class=\"source rust\"><span class=\"storage type function rust\">fn</span>\n</span></code></pre>",
+        "should highlight code with classes"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\n\"<a>\"\n```", &classes)?,
+        "<pre><code class=\"language-js\"><span class=\"source js\"><span class=\"string quoted double js\"><span class=\"punctuation definition string begin js\">&quot;</span>&lt;a&gt;<span class=\"punctuation definition string end js\">&quot;</span></span>\n</span></code></pre>",
+        "should encode highlighted code"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n<a>\n```\n\n```xyz\n<b>\n```\n\n    <c>", &inline)?,
+        "<pre><code>&lt;a&gt;\n</code></pre>\n<pre><code class=\"language-xyz\">&lt;b&gt;\n</code></pre>\n<pre><code>&lt;c&gt;\n</code></pre>",
+        "should not highlight code without a known language"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```rust\nfn\n```",
+            &highlight(SyntaxHighlight {
+                theme: "xyz".into(),
+                ..SyntaxHighlight::default()
+            })
+        )?,
+        "<pre><code class=\"language-rust\">fn\n</code></pre>",
+        "should not highlight code with an unknown theme"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```rust\nfn\n```",
+            &Options {
+                compile: CompileOptions {
+                    code_block_handler: Some(CodeBlockHandler(Rc::new(
+                        |_: Option<&str>, _: Option<&str>, code: &str| format!(
+                            "<x-code>{}</x-code>",
+                            code
+                        )
+                    ))),
+                    ..inline.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<x-code>fn\n</x-code>",
+        "should prefer `code_block_handler`"
+    );
+
+    Ok(())
+}
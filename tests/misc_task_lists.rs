@@ -0,0 +1,66 @@
+use markdown::{
+    message, task_lists, unist::Position, ParseOptions, TaskList, TaskListItem, TaskListStats,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn task_list_stats() -> Result<(), message::Message> {
+    assert_eq!(
+        task_lists("* [x] a\n* [ ] b", &ParseOptions::default())?,
+        TaskListStats::default(),
+        "should not count task list items by default"
+    );
+
+    assert_eq!(
+        task_lists("* a\n* b\n\nc", &ParseOptions::gfm())?,
+        TaskListStats::default(),
+        "should not count lists without task list items"
+    );
+
+    assert_eq!(
+        task_lists("* [x] a\n* [ ] b\n* c", &ParseOptions::gfm())?,
+        TaskListStats {
+            checked: 1,
+            unchecked: 1,
+            lists: vec![TaskList {
+                checked: 1,
+                unchecked: 1,
+                items: vec![
+                    TaskListItem {
+                        checked: true,
+                        position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                    },
+                    TaskListItem {
+                        checked: false,
+                        position: Some(Position::new(2, 1, 8, 2, 8, 15))
+                    }
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 4, 19))
+            }]
+        },
+        "should count task list items in a list"
+    );
+
+    let stats = task_lists(
+        "* [x] a\n  1. [ ] b\n  2. [X] c\n\n> * [ ] d",
+        &ParseOptions::gfm(),
+    )?;
+
+    assert_eq!(
+        (stats.checked, stats.unchecked, stats.total()),
+        (2, 2, 4),
+        "should count task list items in a document"
+    );
+
+    assert_eq!(
+        stats
+            .lists
+            .iter()
+            .map(|list| (list.checked, list.unchecked, list.total()))
+            .collect::<Vec<_>>(),
+        vec![(1, 0, 1), (1, 1, 2), (0, 1, 1)],
+        "should count task list items per list, including nested lists and lists in containers"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use markdown::{
+    message, to_html_with_options, toc, toc_to_html, unist::Position, CompileOptions, Constructs,
+    Options, ParseOptions, SlugKind, TocEntry,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn table_of_contents() -> Result<(), message::Message> {
+    assert_eq!(
+        toc("a\n\n* b", &Options::default())?,
+        vec![],
+        "should not find anything w/o headings"
+    );
+
+    assert_eq!(
+        toc("# a *b*\n\nc\n-", &Options::default())?,
+        vec![
+            TocEntry {
+                depth: 1,
+                text: "a b".into(),
+                slug: "a-b".into(),
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            },
+            TocEntry {
+                depth: 2,
+                text: "c".into(),
+                slug: "c".into(),
+                position: Some(Position::new(3, 1, 9, 4, 2, 12))
+            }
+        ],
+        "should find atx and setext headings"
+    );
+
+    assert_eq!(
+        toc(
+            "###### a\n\n> ## [b](c) `d`\n\n* e\n  ===",
+            &Options::default()
+        )?
+        .iter()
+        .map(|d| (d.depth, d.text.as_str()))
+        .collect::<Vec<_>>(),
+        vec![(6, "a"), (2, "b d"), (1, "e")],
+        "should find headings in containers, w/ their visible text"
+    );
+
+    assert_eq!(
+        toc("# a\n\n# a\n\n# a-1", &Options::default())?
+            .iter()
+            .map(|d| d.slug.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "a-1", "a-1-1"],
+        "should make slugs unique"
+    );
+
+    let options = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                attribute_list: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        compile: CompileOptions {
+            heading_ids: true,
+            slug: SlugKind::Kramdown,
+            ..CompileOptions::default()
+        },
+    };
+    let value = "# 1. Über uns\n\n# a\n{: #b}\n\n# a";
+
+    assert_eq!(
+        toc(value, &options)?
+            .iter()
+            .map(|d| d.slug.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ber-uns", "b", "a"],
+        "should use the slug algorithm and ids from attribute lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &options)?,
+        "<h1 id=\"ber-uns\">1. Über uns</h1>\n<h1 id=\"b\">a</h1>\n<h1 id=\"a\">a</h1>",
+        "should match the ids in HTML"
+    );
+
+    assert_eq!(
+        toc_to_html(&[], &Options::default()),
+        "",
+        "should serialize nothing to HTML"
+    );
+
+    assert_eq!(
+        toc_to_html(
+            &toc("# a\n\n## b\n\n### c\n\n# d", &Options::default())?,
+            &Options::default()
+        ),
+        "<ul>\n<li><a href=\"#a\">a</a>\n<ul>\n<li><a href=\"#b\">b</a>\n<ul>\n<li><a href=\"#c\">c</a></li>\n</ul>\n</li>\n</ul>\n</li>\n<li><a href=\"#d\">d</a></li>\n</ul>",
+        "should serialize nested lists to HTML"
+    );
+
+    assert_eq!(
+        toc_to_html(
+            &toc("## a\n\n#### b\n\n### c\n\n# d\n\n## e", &Options::default())?,
+            &Options::default()
+        ),
+        "<ul>\n<li><a href=\"#a\">a</a>\n<ul>\n<li><a href=\"#b\">b</a></li>\n<li><a href=\"#c\">c</a></li>\n</ul>\n</li>\n<li><a href=\"#d\">d</a>\n<ul>\n<li><a href=\"#e\">e</a></li>\n</ul>\n</li>\n</ul>",
+        "should serialize skipped and shallower ranks to HTML"
+    );
+
+    assert_eq!(
+        toc_to_html(
+            &toc("# a&lt;b", &Options::default())?,
+            &Options {
+                compile: CompileOptions {
+                    id_prefix: Some("x-".into()),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        ),
+        "<ul>\n<li><a href=\"#x-ab\">a&lt;b</a></li>\n</ul>",
+        "should encode, and use `id_prefix` in links"
+    );
+
+    Ok(())
+}
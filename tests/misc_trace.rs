@@ -0,0 +1,102 @@
+#![cfg(feature = "trace")]
+
+use core::fmt;
+use markdown::{message, trace, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Writer that fails at its second line.
+struct Failing {
+    writes: usize,
+    lines: usize,
+}
+
+impl fmt::Write for Failing {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.writes += 1;
+
+        if value.ends_with('\n') {
+            self.lines += 1;
+
+            if self.lines == 2 {
+                return Err(fmt::Error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn misc_trace() -> Result<(), message::Message> {
+    let mut value = String::new();
+    trace("a *b*", &ParseOptions::default(), &mut value)?;
+
+    assert!(
+        value.contains("feed:    byte `a` (U+0061) at 0:0 to `DocumentStart`"),
+        "should trace bytes fed to states"
+    );
+
+    assert!(
+        value.contains("enter:   `AttentionSequence` at 2:0"),
+        "should trace enter events w/ places"
+    );
+
+    assert!(
+        value.contains("exit:    `AttentionSequence` at 3:0"),
+        "should trace exit events w/ places"
+    );
+
+    assert!(
+        value.contains("attempt: `Ok` -> `Next(TextBefore)`"),
+        "should trace attempts"
+    );
+
+    assert!(
+        value.contains("event:   Enter `Emphasis` at 2:0"),
+        "should include resolved events"
+    );
+
+    assert!(
+        value.contains("subtokenize: `Text` at 0:0"),
+        "should trace subtokenization"
+    );
+
+    assert!(
+        value.contains("resolve: `Attention`"),
+        "should trace resolvers"
+    );
+
+    assert_eq!(
+        value.lines().last(),
+        Some("event:   Exit `Paragraph` at 5:0"),
+        "should end w/ the resulting events"
+    );
+
+    let mut value = String::new();
+    trace("\ta", &ParseOptions::default(), &mut value)?;
+
+    assert!(
+        value.contains("feed:    byte U+0020 at 0:1 to"),
+        "should trace virtual spaces in tabs"
+    );
+
+    let mut writer = Failing {
+        writes: 0,
+        lines: 0,
+    };
+    let result = trace("a *b*", &ParseOptions::default(), &mut writer);
+
+    assert_eq!(
+        result.map_err(|d| d.reason),
+        Err("Cannot write trace".into()),
+        "should crash when writing fails"
+    );
+
+    assert_eq!(
+        (writer.writes > 2, writer.lines),
+        (true, 2),
+        "should write each line as it is made, and stop at the first error"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use markdown::{
+    message, to_html_with_options, CompileOptions, Options, ParseOptions, UrlKind, UrlSanitize,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+fn sanitize(url_sanitize: UrlSanitize) -> Options {
+    Options {
+        compile: CompileOptions {
+            url_sanitize: Some(url_sanitize),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn url_sanitize() -> Result<(), message::Message> {
+    let debug = sanitize(UrlSanitize(Rc::new(|url: &str, kind: UrlKind| {
+        Some(format!("/{:?}/{}", kind, url))
+    })));
+    let nothing = sanitize(UrlSanitize(Rc::new(|_: &str, _: UrlKind| None)));
+    let strict = sanitize(UrlSanitize(Rc::new(|url: &str, _: UrlKind| {
+        if url.is_ascii() {
+            None
+        } else {
+            Some(String::new())
+        }
+    })));
+
+    assert_eq!(
+        to_html_with_options("[a](b) ![c](d) <https://e.f>", &debug)?,
+        "<p><a href=\"/Link/b\">a</a> <img src=\"/Image/d\" alt=\"c\" /> <a href=\"/Link/https://e.f\">https://e.f</a></p>",
+        "should sanitize URLs of links and images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b) ![c](javascript:d)", &nothing)?,
+        "<p><a href=\"b\">a</a> <img src=\"\" alt=\"c\" /></p>",
+        "should sanitize URLs like normal if `None` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](javascript:b)",
+            &sanitize(UrlSanitize(Rc::new(|url: &str, _: UrlKind| Some(
+                url.into()
+            ))))
+        )?,
+        "<p><a href=\"javascript:b\">a</a></p>",
+        "should not check protocols if `Some` is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b)",
+            &sanitize(UrlSanitize(Rc::new(|_: &str, _: UrlKind| {
+                Some("\"c d\"".into())
+            })))
+        )?,
+        "<p><a href=\"%22c%20d%22\">a</a></p>",
+        "should encode what is returned"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://xn--a.com) [b](https://а.com)", &strict)?,
+        "<p><a href=\"https://xn--a.com\">a</a> <a href=\"\">b</a></p>",
+        "should support stricter checks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "www.a.com",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: debug.compile.clone()
+            }
+        )?,
+        "<p><a href=\"/Link/http://www.a.com\">www.a.com</a></p>",
+        "should sanitize autolinks (GFM literal)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b) ![c](d)",
+            &Options {
+                compile: CompileOptions {
+                    base_url: Some("https://e.com/".into()),
+                    ..debug.compile.clone()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"/Link/https://e.com/b\">a</a> <img src=\"/Image/https://e.com/d\" alt=\"c\" /></p>",
+        "should sanitize resolved URLs"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use markdown::{
+    event::{Kind, Name},
+    message, to_events, validate_events, Constructs, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn validate() -> Result<(), message::Message> {
+    assert_eq!(validate_events(&[]), Ok(()), "should support no events");
+
+    assert_eq!(
+        validate_events(&to_events(
+            "# a\n\n> * b *c* [d](e \"f\")\n\n```g\nh\n```\n\n[e]: f",
+            &ParseOptions::default()
+        )?),
+        Ok(()),
+        "should support events from markdown"
+    );
+
+    assert_eq!(
+        validate_events(&to_events(
+            "| a |\n| - |\n| b |\n\n* [x] c[^d] ~e~ www.f.com\n\n[^d]: g",
+            &ParseOptions::gfm()
+        )?),
+        Ok(()),
+        "should support events from GFM"
+    );
+
+    assert_eq!(
+        validate_events(&to_events(
+            "> ```\n> a\n>\nb\n\n[^]()",
+            &ParseOptions::gfm()
+        )?),
+        Ok(()),
+        "should support events in order after containers close and in links that looked like footnotes"
+    );
+
+    assert_eq!(
+        validate_events(&to_events(
+            "---\na: b\n---\n\n$$\nc\n$$\n\n$d$",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    math_flow: true,
+                    math_text: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?),
+        Ok(()),
+        "should support events from other constructs"
+    );
+
+    let events = to_events("*a*", &ParseOptions::default())?;
+
+    let mut broken = events.clone();
+    broken.pop();
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        (result.reason.as_str(), result.rule_id.as_str()),
+        (
+            "Expected exit of `Paragraph`, which was entered at 1:1, before the end",
+            "enter-without-exit"
+        ),
+        "should crash on a missing exit"
+    );
+
+    let mut broken = events.clone();
+    broken.remove(1);
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        result.rule_id.as_str(),
+        "exit-mismatch",
+        "should crash on a missing enter, when other events are open"
+    );
+
+    let mut broken = events.clone();
+    broken.insert(0, broken[broken.len() - 1].clone());
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        (result.reason.as_str(), result.rule_id.as_str()),
+        (
+            "Unexpected exit of `Paragraph`, which was never entered",
+            "exit-without-enter"
+        ),
+        "should crash on an exit without enter"
+    );
+
+    let mut broken = events.clone();
+    let index = broken
+        .iter()
+        .position(|d| d.kind == Kind::Enter && d.name == Name::EmphasisSequence)
+        .unwrap();
+    let mut enter = broken[index].clone();
+    enter.name = Name::Data;
+    let mut exit = broken[index + 1].clone();
+    exit.name = Name::Data;
+    broken.splice(index + 1..index + 1, [enter, exit]);
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        (result.reason.as_str(), result.rule_id.as_str()),
+        (
+            "Unexpected enter of `Data` in void `EmphasisSequence`, expected `EmphasisSequence` to be exited first",
+            "enter-in-void"
+        ),
+        "should crash on an enter in a void event"
+    );
+
+    let mut broken = events.clone();
+    let index = broken
+        .iter()
+        .position(|d| d.kind == Kind::Exit && d.name == Name::Data)
+        .unwrap();
+    broken[index].point = broken[0].point.clone();
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        result.rule_id.as_str(),
+        "point-backwards",
+        "should crash on points before the enter of their parent"
+    );
+
+    let mut broken = events;
+    let index = broken
+        .iter()
+        .position(|d| d.kind == Kind::Enter && d.name == Name::Data)
+        .unwrap();
+    let data = [broken[index].clone(), broken[index + 1].clone()];
+    let end = broken.len() - 1;
+    broken.splice(end..end, data);
+    let result = validate_events(&broken).unwrap_err();
+    assert_eq!(
+        (result.reason.as_str(), result.rule_id.as_str()),
+        (
+            "Unexpected point of enter `Data` (1:2), which is before the exit of `Emphasis` (1:4)",
+            "point-backwards"
+        ),
+        "should crash on points before earlier siblings"
+    );
+
+    Ok(())
+}
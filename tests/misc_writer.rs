@@ -0,0 +1,115 @@
+use markdown::{message, to_html_fmt_writer, to_html_with_options, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Writer that fails after `left` writes.
+struct Failing {
+    left: usize,
+}
+
+impl core::fmt::Write for Failing {
+    fn write_str(&mut self, _: &str) -> core::fmt::Result {
+        if self.left == 0 {
+            Err(core::fmt::Error)
+        } else {
+            self.left -= 1;
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn writer() -> Result<(), message::Message> {
+    let gfm = Options::gfm();
+    let docs = [
+        "",
+        "a",
+        "# a\n\n> b\n> c\n\n* d\n* e\n\n    f",
+        "a\r\n\r\nb\r\n",
+        "[a]\n\n[a]: b\n\n[^c]\n\n[^c]: d",
+        "<div>\n\n*a*\n\n</div>",
+        "| a |\n| - |\n| b |\n\n~~c~~",
+        "* [ ] a\n\n  b\n* [x] c",
+    ];
+
+    for doc in docs {
+        let mut value = String::new();
+        to_html_fmt_writer(doc, &gfm, &mut value)?;
+
+        assert_eq!(
+            value,
+            to_html_with_options(doc, &gfm)?,
+            "should write the same HTML as `to_html_with_options` for `{:?}`",
+            doc
+        );
+    }
+
+    assert_eq!(
+        to_html_fmt_writer("a\n\nb", &Options::default(), &mut Failing { left: 1 })
+            .err()
+            .unwrap()
+            .to_string(),
+        "Cannot write HTML (markdown-rs:write)",
+        "should crash when the writer crashes"
+    );
+
+    let mut value = String::new();
+
+    assert_eq!(
+        to_html_fmt_writer(
+            "a <b {...",
+            &Options {
+                parse: ParseOptions::mdx(),
+                ..Options::default()
+            },
+            &mut value
+        )
+        .err()
+        .unwrap()
+        .to_string(),
+        "1:10: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "should crash on invalid MDX"
+    );
+
+    assert_eq!(value, "", "should not write anything for invalid MDX");
+
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn writer_io() -> Result<(), message::Message> {
+    use markdown::to_html_writer;
+
+    /// Writer that always fails.
+    struct Failing;
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("oops"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut value = Vec::new();
+    to_html_writer("# a\n\n[^b]\n\n[^b]: c", &Options::gfm(), &mut value)?;
+
+    assert_eq!(
+        String::from_utf8(value).unwrap(),
+        to_html_with_options("# a\n\n[^b]\n\n[^b]: c", &Options::gfm())?,
+        "should write to an `io::Write`"
+    );
+
+    assert_eq!(
+        to_html_writer("a", &Options::default(), &mut Failing)
+            .err()
+            .unwrap()
+            .to_string(),
+        "Cannot write HTML: oops (markdown-rs:write)",
+        "should crash with the error of the writer"
+    );
+
+    Ok(())
+}
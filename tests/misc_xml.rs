@@ -0,0 +1,63 @@
+use markdown::{message, to_xml, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn xml() -> Result<(), message::Message> {
+    let head = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE document SYSTEM \"CommonMark.dtd\">\n";
+
+    assert_eq!(
+        to_xml("", &ParseOptions::default())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\" />\n",
+            head
+        ),
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        to_xml("# a\n\n***\n\n    b", &ParseOptions::default())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <heading level=\"1\">\n    <text xml:space=\"preserve\">a</text>\n  </heading>\n  <thematic_break />\n  <code_block xml:space=\"preserve\">b\n</code_block>\n</document>\n",
+            head
+        ),
+        "should support headings, thematic breaks, and code"
+    );
+
+    assert_eq!(
+        to_xml("> a\nb  \nc", &ParseOptions::default())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <block_quote>\n    <paragraph>\n      <text xml:space=\"preserve\">a</text>\n      <softbreak />\n      <text xml:space=\"preserve\">b</text>\n      <linebreak />\n      <text xml:space=\"preserve\">c</text>\n    </paragraph>\n  </block_quote>\n</document>\n",
+            head
+        ),
+        "should support block quotes and breaks"
+    );
+
+    assert_eq!(
+        to_xml(" 2) a\n\n\n 3) b", &ParseOptions::default())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <list type=\"ordered\" start=\"2\" delim=\"paren\" tight=\"false\">\n    <item>\n      <paragraph>\n        <text xml:space=\"preserve\">a</text>\n      </paragraph>\n    </item>\n    <item>\n      <paragraph>\n        <text xml:space=\"preserve\">b</text>\n      </paragraph>\n    </item>\n  </list>\n</document>\n",
+            head
+        ),
+        "should support lists"
+    );
+
+    assert_eq!(
+        to_xml("<div>\n\n[a][b] ![c](d \"e\") <i>\n\n[b]: <&\">", &ParseOptions::default())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <html_block xml:space=\"preserve\">&lt;div&gt;\n</html_block>\n  <paragraph>\n    <link destination=\"&amp;&quot;\" title=\"\">\n      <text xml:space=\"preserve\">a</text>\n    </link>\n    <text xml:space=\"preserve\"> </text>\n    <image destination=\"d\" title=\"e\">\n      <text xml:space=\"preserve\">c</text>\n    </image>\n    <text xml:space=\"preserve\"> </text>\n    <html_inline xml:space=\"preserve\">&lt;i&gt;</html_inline>\n  </paragraph>\n</document>\n",
+            head
+        ),
+        "should support HTML, links, and images, and escape XML"
+    );
+
+    assert_eq!(
+        to_xml("| a |\n| -: |\n\n* [x] ~b~", &ParseOptions::gfm())?,
+        format!(
+            "{}<document xmlns=\"http://commonmark.org/xml/1.0\">\n  <table>\n    <table_header>\n      <table_cell align=\"right\">\n        <text xml:space=\"preserve\">a</text>\n      </table_cell>\n    </table_header>\n  </table>\n  <list type=\"bullet\" tight=\"true\">\n    <item completed=\"true\">\n      <paragraph>\n        <strikethrough>\n          <text xml:space=\"preserve\">b</text>\n        </strikethrough>\n      </paragraph>\n    </item>\n  </list>\n</document>\n",
+            head
+        ),
+        "should support GFM like `cmark-gfm`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Ruby, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn ruby() -> Result<(), message::Message> {
+    let ruby = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                ruby: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a {漢字|かんじ} b"),
+        "<p>a {漢字|かんじ} b</p>",
+        "should not support ruby by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {漢字|かんじ} b", &ruby)?,
+        "<p>a <ruby><rb>漢字</rb><rt>かんじ</rt></ruby> b</p>",
+        "should support ruby"
+    );
+
+    assert_eq!(
+        to_html_with_options("{a b|c d}", &ruby)?,
+        "<p><ruby><rb>a b</rb><rt>c d</rt></ruby></p>",
+        "should support whitespace in the base and text"
+    );
+
+    assert_eq!(
+        to_html_with_options("{a|} {|b} { |b} {a| } {a|b|c}", &ruby)?,
+        "<p>{a|} {|b} { |b} {a| } {a|b|c}</p>",
+        "should not support ruby w/o base or text, or w/ several pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("{a\nb|c} {a|b\nc}", &ruby)?,
+        "<p>{a\nb|c} {a|b\nc}</p>",
+        "should not support line endings in ruby"
+    );
+
+    assert_eq!(
+        to_html_with_options("{a{b|c}} {*a*|b}", &ruby)?,
+        "<p>{a<ruby><rb>b</rb><rt>c</rt></ruby>} <ruby><rb>*a*</rb><rt>b</rt></ruby></p>",
+        "should not support braces or markdown in ruby"
+    );
+
+    assert_eq!(
+        to_html_with_options("*{a|b}* [{c|d}](e) ![{f|g}](h)", &ruby)?,
+        "<p><em><ruby><rb>a</rb><rt>b</rt></ruby></em> <a href=\"e\"><ruby><rb>c</rb><rt>d</rt></ruby></a> <img src=\"h\" alt=\"f\" /></p>",
+        "should support ruby in other phrasing, and use the base in image alts"
+    );
+
+    assert_eq!(
+        to_html_with_options("{<a>|&b}", &ruby)?,
+        "<p><ruby><rb>&lt;a&gt;</rb><rt>&amp;b</rt></ruby></p>",
+        "should encode ruby"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "{++a++} {b|c}{: .d}",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        attribute_list: true,
+                        critic_markup: true,
+                        ruby: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><ins>a</ins> <ruby class=\"d\"><rb>b</rb><rt>c</rt></ruby></p>",
+        "should support ruby next to critic markup, and attribute lists on ruby"
+    );
+
+    assert_eq!(
+        to_mdast("a {b|c}", &ruby.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Ruby(Ruby {
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7)),
+                        base: "b".into(),
+                        text: "c".into()
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+        }),
+        "should support ruby as `Ruby`s in mdast"
+    );
+
+    Ok(())
+}
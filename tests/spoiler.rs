@@ -0,0 +1,254 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Spoiler, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn spoiler() -> Result<(), message::Message> {
+    let spoiler = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                spoiler: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a >!b!< c"),
+        "<p>a &gt;!b!&lt; c</p>",
+        "should not support spoilers by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a >!b!< c", &spoiler)?,
+        "<p>a <span class=\"spoiler\">b</span> c</p>",
+        "should support spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!a!<", &spoiler)?,
+        "<p><span class=\"spoiler\">a</span></p>",
+        "should support spoilers at the start of a line (not block quotes)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n>!b!<", &spoiler)?,
+        "<blockquote>\n<p>a\n<span class=\"spoiler\">b</span></p>\n</blockquote>",
+        "should support spoilers as lazy lines in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("> >!a!<", &spoiler)?,
+        "<blockquote>\n<p><span class=\"spoiler\">a</span></p>\n</blockquote>",
+        "should support spoilers in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(">! a b !<", &spoiler)?,
+        "<p><span class=\"spoiler\"> a b </span></p>",
+        "should support whitespace in spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!*a* `b` [c](d)!<", &spoiler)?,
+        "<p><span class=\"spoiler\"><em>a</em> <code>b</code> <a href=\"d\">c</a></span></p>",
+        "should support phrasing in spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!a\nb!<", &spoiler)?,
+        "<p><span class=\"spoiler\">a\nb</span></p>",
+        "should support line endings in spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!!<", &spoiler)?,
+        "<p>&gt;!!&lt;</p>",
+        "should not support empty spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options("a >!b", &spoiler)?,
+        "<p>a &gt;!b</p>",
+        "should not support an opening sequence w/o closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("a!< b", &spoiler)?,
+        "<p>a!&lt; b</p>",
+        "should not support a closing sequence w/o opening sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!a>!b!<c!<", &spoiler)?,
+        "<p><span class=\"spoiler\">a<span class=\"spoiler\">b</span>c</span></p>",
+        "should support nested spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options(">!a!<>!b!<", &spoiler)?,
+        "<p><span class=\"spoiler\">a</span><span class=\"spoiler\">b</span></p>",
+        "should support adjacent spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options("[>!a](b)!<", &spoiler)?,
+        "<p><a href=\"b\">&gt;!a</a>!&lt;</p>",
+        "should not support spoilers across links"
+    );
+
+    assert_eq!(
+        to_html_with_options("*>!a*!<", &spoiler)?,
+        "<p><em>&gt;!a</em>!&lt;</p>",
+        "should not support spoilers across attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\>!a!<", &spoiler)?,
+        "<p>&gt;!a!&lt;</p>",
+        "should not support an escaped opening sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("![>!a!<](b)", &spoiler)?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should support spoilers in image alts"
+    );
+
+    let spoiler_pipes = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                spoiler_pipes: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a ||b|| c"),
+        "<p>a ||b|| c</p>",
+        "should not support spoilers w/ pipes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ||b|| c", &spoiler)?,
+        "<p>a ||b|| c</p>",
+        "should not support spoilers w/ pipes w/ `spoiler`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ||b|| c", &spoiler_pipes)?,
+        "<p>a <span class=\"spoiler\">b</span> c</p>",
+        "should support spoilers w/ pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a |b| c", &spoiler_pipes)?,
+        "<p>a |b| c</p>",
+        "should not support spoilers w/ one pipe"
+    );
+
+    assert_eq!(
+        to_html_with_options("a |||b||| c", &spoiler_pipes)?,
+        "<p>a |||b||| c</p>",
+        "should not support spoilers w/ three pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ||b| c", &spoiler_pipes)?,
+        "<p>a ||b| c</p>",
+        "should not support spoilers w/ different sequences"
+    );
+
+    assert_eq!(
+        to_html_with_options("||*a* ~~b~~||", &Options::chat())?,
+        "<p><span class=\"spoiler\"><em>a</em> <del>b</del></span></p>",
+        "should support phrasing in spoilers w/ pipes"
+    );
+
+    let spoiler_both = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                spoiler: true,
+                spoiler_pipes: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(">!a!< ||b||", &spoiler_both)?,
+        "<p><span class=\"spoiler\">a</span> <span class=\"spoiler\">b</span></p>",
+        "should support both kinds of spoilers together"
+    );
+
+    assert_eq!(
+        to_html_with_options("||>!a!<||", &spoiler_both)?,
+        "<p><span class=\"spoiler\"><span class=\"spoiler\">a</span></span></p>",
+        "should support spoilers in spoilers w/ pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("||>!||", &spoiler_both)?,
+        "<p><span class=\"spoiler\">&gt;!</span></p>",
+        "should support an opening sequence in a spoiler w/ pipes, at the end"
+    );
+
+    assert_eq!(
+        to_html_with_options("||!<||", &spoiler_both)?,
+        "<p><span class=\"spoiler\">!&lt;</span></p>",
+        "should support a closing sequence in a spoiler w/ pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("||a||>!b", &spoiler_both)?,
+        "<p><span class=\"spoiler\">a</span>&gt;!b</p>",
+        "should support an opening sequence after a spoiler w/ pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("||a||!<", &spoiler_both)?,
+        "<p><span class=\"spoiler\">a</span>!&lt;</p>",
+        "should support a closing sequence after a spoiler w/ pipes, at the end"
+    );
+
+    assert_eq!(
+        to_mdast("a >!b!< c", &spoiler.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Spoiler(Spoiler {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                    }),
+                    Node::Text(Text {
+                        value: " c".into(),
+                        position: Some(Position::new(1, 8, 7, 1, 10, 9))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support spoilers as `Spoiler`s in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Subscript, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn subscript() -> Result<(), message::Message> {
+    let subscript = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let subscript_gfm = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                subscript: true,
+                ..Constructs::gfm()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("H~2~O"),
+        "<p>H~2~O</p>",
+        "should not support subscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("H~2~O a ~b~ c", &subscript)?,
+        "<p>H<sub>2</sub>O a <sub>b</sub> c</p>",
+        "should support subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a b~ ~ a~ ~a ~", &subscript)?,
+        "<p>~a b~ ~ a~ ~a ~</p>",
+        "should not support whitespace in subscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a~~", &subscript)?,
+        "<p>~~a~~</p>",
+        "should not support two tildes w/o GFM strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a~ ~~b~~", &subscript_gfm)?,
+        "<p><sub>a</sub> <del>b</del></p>",
+        "should support subscript w/ one tilde and strikethrough w/ two tildes"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a~~ ~~b~", &subscript_gfm)?,
+        "<p>~a~~ ~~b~</p>",
+        "should not support mismatched tildes"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~H~2~O~~", &subscript_gfm)?,
+        "<p><del>H<sub>2</sub>O</del></p>",
+        "should support subscript in strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ~b~](c)", &subscript)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support subscript in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a ~b~", &subscript.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Subscript(Subscript {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should support subscript as `Subscript`s in mdast"
+    );
+
+    Ok(())
+}
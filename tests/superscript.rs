@@ -0,0 +1,102 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Superscript, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn superscript() -> Result<(), message::Message> {
+    let superscript = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                superscript: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("2^10^"),
+        "<p>2^10^</p>",
+        "should not support superscript by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("2^10^ a ^b^ c", &superscript)?,
+        "<p>2<sup>10</sup> a <sup>b</sup> c</p>",
+        "should support superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^a b^ ^ a^ ^a ^", &superscript)?,
+        "<p>^a b^ ^ a^ ^a ^</p>",
+        "should not support whitespace in superscript"
+    );
+
+    assert_eq!(
+        to_html_with_options("^^a^^ ^a^^", &superscript)?,
+        "<p>^^a^^ ^a^^</p>",
+        "should not support superscript w/ more than one caret"
+    );
+
+    assert_eq!(
+        to_html_with_options("^*a*^ *^a^*", &superscript)?,
+        "<p><sup><em>a</em></sup> <em><sup>a</sup></em></p>",
+        "should support superscript and other attention"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ^b\n\nc^d^",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        block_id: true,
+                        superscript: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p id=\"^b\">a</p>\n<p>c<sup>d</sup></p>",
+        "should support superscript next to block ids"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ^b^](c)", &superscript)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support superscript in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a ^b^", &superscript.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Superscript(Superscript {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should support superscript as `Superscript`s in mdast"
+    );
+
+    Ok(())
+}
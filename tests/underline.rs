@@ -0,0 +1,93 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text, Underline},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn underline() -> Result<(), message::Message> {
+    let underline = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                underline: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a __b__ c"),
+        "<p>a <strong>b</strong> c</p>",
+        "should support strong w/ underscores by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a __b__ c", &underline)?,
+        "<p>a <u>b</u> c</p>",
+        "should support underline"
+    );
+
+    assert_eq!(
+        to_html_with_options("a **b** c", &underline)?,
+        "<p>a <strong>b</strong> c</p>",
+        "should support strong w/ asterisks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a _b_ c", &underline)?,
+        "<p>a <em>b</em> c</p>",
+        "should support emphasis w/ one underscore"
+    );
+
+    assert_eq!(
+        to_html_with_options("___a___", &underline)?,
+        "<p><em><u>a</u></em></p>",
+        "should support underline and emphasis w/ three underscores"
+    );
+
+    assert_eq!(
+        to_html_with_options("a__b__c", &underline)?,
+        "<p>a__b__c</p>",
+        "should not support underline inside words"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a __b__](c)", &underline)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should support underline in image alts"
+    );
+
+    assert_eq!(
+        to_mdast("a __b__ c", &underline.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::Underline(Underline {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 6, 5))
+                        }),],
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                    }),
+                    Node::Text(Text {
+                        value: " c".into(),
+                        position: Some(Position::new(1, 8, 7, 1, 10, 9))
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should support underline as `Underline`s in mdast"
+    );
+
+    Ok(())
+}
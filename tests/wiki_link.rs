@@ -0,0 +1,343 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text, WikiEmbed, WikiLink},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, Options, ParseOptions, WikiEmbedResolve, WikiLinkResolve,
+};
+use pretty_assertions::assert_eq;
+use std::rc::Rc;
+
+#[test]
+fn wiki_link() -> Result<(), message::Message> {
+    let wiki_link = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                wiki_link: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a [[b]] c"),
+        "<p>a [[b]] c</p>",
+        "should not support wiki links by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a [[b]] c", &wiki_link)?,
+        "<p>a <a href=\"b\" class=\"wikilink\">b</a> c</p>",
+        "should support wiki links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|b]]", &wiki_link)?,
+        "<p><a href=\"a\" class=\"wikilink\">b</a></p>",
+        "should support an alias"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|b|c]]", &wiki_link)?,
+        "<p><a href=\"a\" class=\"wikilink\">b|c</a></p>",
+        "should support pipes in an alias"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a b#c]] [[a#^d|e]]", &wiki_link)?,
+        "<p><a href=\"a%20b#c\" class=\"wikilink\">a b#c</a> <a href=\"a#%5Ed\" class=\"wikilink\">e</a></p>",
+        "should support headings and block ids in targets"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[#a]] [[#]] [[ #a]]", &wiki_link)?,
+        "<p><a href=\"#a\" class=\"wikilink\">#a</a> <a href=\"#\" class=\"wikilink\">#</a> <a href=\"%20#a\" class=\"wikilink\"> #a</a></p>",
+        "should support fragments w/o page"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a|*b*]]", &wiki_link)?,
+        "<p><a href=\"a\" class=\"wikilink\">*b*</a></p>",
+        "should not support markdown in an alias"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[<a>|\"b\"]]", &wiki_link)?,
+        "<p><a href=\"%3Ca%3E\" class=\"wikilink\">&quot;b&quot;</a></p>",
+        "should encode targets and aliases"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[javascript:alert(1)]]", &wiki_link)?,
+        "<p><a href=\"\" class=\"wikilink\">javascript:alert(1)</a></p>",
+        "should not support dangerous protocols in targets"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[]] [[ ]] [[|a]] [[a|]]", &wiki_link)?,
+        "<p>[[]] [[ ]] [[|a]] [[a|]]</p>",
+        "should not support empty or whitespace-only targets or empty aliases"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a\nb]] [[a|b\nc]]", &wiki_link)?,
+        "<p>[[a\nb]] [[a|b\nc]]</p>",
+        "should not support line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]b]] [[a[b]] [[a|b]c]]", &wiki_link)?,
+        "<p>[[a]b]] [[a[b]] [[a|b]c]]</p>",
+        "should not support brackets in targets or aliases"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]]] [[[b]]]", &wiki_link)?,
+        "<p><a href=\"a\" class=\"wikilink\">a</a>] [<a href=\"b\" class=\"wikilink\">b</a>]</p>",
+        "should support brackets around wiki links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[[a]\n\n[a]: b", &wiki_link)?,
+        "<p>[<a href=\"b\">a</a></p>\n",
+        "should fall back to links"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a [[b|c]]](d)", &wiki_link)?,
+        "<p><img src=\"d\" alt=\"a c\" /></p>",
+        "should support wiki links in image alts"
+    );
+
+    let resolve = |target: &str| {
+        if target == "c" {
+            None
+        } else {
+            Some(format!("/wiki/{}", target.replace(' ', "_")))
+        }
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "[[a b]] [[c|d]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wiki_link: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    wiki_link_resolve: Some(WikiLinkResolve(Rc::new(resolve))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><a href=\"/wiki/a_b\" class=\"wikilink\">a b</a> <a class=\"wikilink missing\">d</a></p>",
+        "should support `wiki_link_resolve`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[[a]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wiki_link: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    class_prefix: Some("b-".into()),
+                    wiki_link_resolve: Some(WikiLinkResolve(Rc::new(|_| None))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><a class=\"b-wikilink b-missing\">a</a></p>",
+        "should support `class_prefix` on wiki links"
+    );
+
+    assert_eq!(
+        to_mdast("a [[b|c]] d", &wiki_link.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::WikiLink(WikiLink {
+                        position: Some(Position::new(1, 3, 2, 1, 10, 9)),
+                        target: "b".into(),
+                        alias: Some("c".into())
+                    }),
+                    Node::Text(Text {
+                        value: " d".into(),
+                        position: Some(Position::new(1, 10, 9, 1, 12, 11))
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support wiki links as `WikiLink`s in mdast"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn wiki_embed() -> Result<(), message::Message> {
+    let wiki_embed = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                wiki_embed: true,
+                wiki_link: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "a ![[b]] c",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wiki_link: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a ![[b]] c</p>",
+        "should not support wiki embeds by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![[b]] c", &wiki_embed)?,
+        "<p>a <span class=\"wikiembed\" data-target=\"b\">b</span> c</p>",
+        "should support wiki embeds"
+    );
+
+    assert_eq!(
+        to_html_with_options("![[a#b]] ![[a#^c|d]] ![[#e]]", &wiki_embed)?,
+        "<p><span class=\"wikiembed\" data-target=\"a\" data-fragment=\"b\">a#b</span> <span class=\"wikiembed\" data-target=\"a\" data-fragment=\"^c\">d</span> <span class=\"wikiembed\" data-target=\"\" data-fragment=\"e\">#e</span></p>",
+        "should support fragments and aliases"
+    );
+
+    assert_eq!(
+        to_html_with_options("![[<a>#\"b\"|&]]", &wiki_embed)?,
+        "<p><span class=\"wikiembed\" data-target=\"&lt;a&gt;\" data-fragment=\"&quot;b&quot;\">&amp;</span></p>",
+        "should encode targets, fragments, and aliases"
+    );
+
+    assert_eq!(
+        to_html_with_options("![[]] ![[a\nb]] ![a] !![[b]] ![[c]", &wiki_embed)?,
+        "<p>![[]] ![[a\nb]] ![a] !<span class=\"wikiembed\" data-target=\"b\">b</span> ![[c]</p>",
+        "should not support empty or broken wiki embeds"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](b) >!c!<",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        spoiler: true,
+                        wiki_embed: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><img src=\"b\" alt=\"a\" /> <span class=\"spoiler\">c</span></p>",
+        "should not interfere with images or spoilers"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a ![[b|c]]](d)", &wiki_embed)?,
+        "<p><img src=\"d\" alt=\"a c\" /></p>",
+        "should support wiki embeds in image alts"
+    );
+
+    let resolve = |target: &str, fragment: Option<&str>, alias: Option<&str>| {
+        if target.ends_with(".png") {
+            Some(format!(
+                "<img src=\"/files/{}\" alt=\"{}\" />",
+                target,
+                alias.unwrap_or("")
+            ))
+        } else if target == "a" {
+            Some(format!("<div>{}</div>", fragment.unwrap_or("*")))
+        } else {
+            None
+        }
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "![[a.png|b]] ![[a#c]] ![[d]]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        wiki_embed: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    class_prefix: Some("x-".into()),
+                    wiki_embed_resolve: Some(WikiEmbedResolve(Rc::new(resolve))),
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<p><img src=\"/files/a.png\" alt=\"b\" /> <div>c</div> <span class=\"x-wikiembed\" data-target=\"d\">d</span></p>",
+        "should support `wiki_embed_resolve`"
+    );
+
+    assert_eq!(
+        to_mdast("a ![[b#^c|d]] [[e#f]]", &wiki_embed.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                    }),
+                    Node::WikiEmbed(WikiEmbed {
+                        position: Some(Position::new(1, 3, 2, 1, 14, 13)),
+                        target: "b".into(),
+                        fragment: Some("^c".into()),
+                        alias: Some("d".into())
+                    }),
+                    Node::Text(Text {
+                        value: " ".into(),
+                        position: Some(Position::new(1, 14, 13, 1, 15, 14))
+                    }),
+                    Node::WikiLink(WikiLink {
+                        position: Some(Position::new(1, 15, 14, 1, 22, 21)),
+                        target: "e#f".into(),
+                        alias: None
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 22, 21))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 22, 21))
+        }),
+        "should support wiki embeds as `WikiEmbed`s in mdast"
+    );
+
+    Ok(())
+}